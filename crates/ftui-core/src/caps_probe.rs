@@ -46,7 +46,11 @@ const MAX_RESPONSE_LEN: usize = 256;
 const DEFAULT_TIMEOUT: Duration = Duration::from_millis(500);
 
 /// Configuration for terminal probing.
+///
+/// Non-exhaustive so new probes can be added; start from
+/// [`ProbeConfig::default`] and use the `with_*` builders.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct ProbeConfig {
     /// Timeout per individual probe query.
     pub timeout: Duration,
@@ -59,6 +63,10 @@ pub struct ProbeConfig {
     /// Opt-in because some terminals may show visual artifacts
     /// from the OSC 11 query.
     pub probe_background: bool,
+    /// Whether to query the 16 ANSI palette colors (OSC 4).
+    ///
+    /// Opt-in; costs up to 16 round trips on terminals that answer.
+    pub probe_palette: bool,
 }
 
 impl Default for ProbeConfig {
@@ -68,16 +76,57 @@ impl Default for ProbeConfig {
             probe_da1: true,
             probe_da2: true,
             probe_background: false,
+            probe_palette: false,
         }
     }
 }
 
+impl ProbeConfig {
+    /// Set the per-probe timeout.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Enable or disable the DA1 probe.
+    #[must_use]
+    pub fn with_da1(mut self, enabled: bool) -> Self {
+        self.probe_da1 = enabled;
+        self
+    }
+
+    /// Enable or disable the DA2 probe.
+    #[must_use]
+    pub fn with_da2(mut self, enabled: bool) -> Self {
+        self.probe_da2 = enabled;
+        self
+    }
+
+    /// Enable or disable the OSC 11 background color probe.
+    #[must_use]
+    pub fn with_background(mut self, enabled: bool) -> Self {
+        self.probe_background = enabled;
+        self
+    }
+
+    /// Enable or disable the OSC 4 palette probe.
+    #[must_use]
+    pub fn with_palette(mut self, enabled: bool) -> Self {
+        self.probe_palette = enabled;
+        self
+    }
+}
+
 /// Results from terminal probing.
 ///
 /// Each field is `Option<T>`: `Some` means the probe succeeded and
 /// returned a definitive answer; `None` means the probe timed out
 /// or returned an unrecognizable response (fail-open).
+///
+/// Non-exhaustive so new probes can report results.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct ProbeResult {
     /// DA1 attribute codes reported by the terminal.
     ///
@@ -102,6 +151,14 @@ pub struct ProbeResult {
     /// Determined by probing the background color via OSC 11 and
     /// computing perceived luminance.
     pub dark_background: Option<bool>,
+
+    /// RGB values of ANSI colors 0-15 as configured in the terminal.
+    ///
+    /// Queried via OSC 4; `None` unless every entry was reported. The
+    /// `ftui-runtime` `caps-probe` feature probes and installs it at startup
+    /// (`ftui_style::set_terminal_palette`) so color caches match the
+    /// terminal's real colors.
+    pub palette: Option<[[u8; 3]; 16]>,
 }
 
 /// Probe terminal capabilities at runtime.
@@ -151,6 +208,10 @@ fn probe_capabilities_unix(config: &ProbeConfig) -> ProbeResult {
         result.dark_background = probe_background_color(config.timeout);
     }
 
+    if config.probe_palette {
+        result.palette = probe_palette_colors(config.timeout);
+    }
+
     result
 }
 
@@ -278,7 +339,17 @@ fn probe_background_color(timeout: Duration) -> Option<bool> {
 #[cfg(unix)]
 fn parse_background_response(bytes: &[u8]) -> Option<bool> {
     let s = std::str::from_utf8(bytes).ok()?;
+    let [r_norm, g_norm, b_norm] = parse_rgb_spec(s)?;
+
+    // Perceived luminance (ITU-R BT.601).
+    let luminance = 0.299 * r_norm + 0.587 * g_norm + 0.114 * b_norm;
+
+    Some(luminance < 0.5)
+}
 
+/// Parse the `rgb:R/G/B` part of an OSC color report into `[0, 1]` components.
+#[cfg(unix)]
+fn parse_rgb_spec(s: &str) -> Option<[f64; 3]> {
     let rgb_start = s.find("rgb:")?;
     let rgb_data = &s[rgb_start + 4..];
 
@@ -295,10 +366,6 @@ fn parse_background_response(bytes: &[u8]) -> Option<bool> {
         return None;
     }
 
-    let r = parse_color_component(parts[0])?;
-    let g = parse_color_component(parts[1])?;
-    let b = parse_color_component(parts[2])?;
-
     // Normalize each component based on its hex digit count.
     // X11 color spec supports 1-4 hex digits per component (4/8/12/16-bit).
     fn scale_for_digits(n: usize) -> f64 {
@@ -310,14 +377,60 @@ fn parse_background_response(bytes: &[u8]) -> Option<bool> {
         }
     }
 
-    let r_norm = f64::from(r) / scale_for_digits(parts[0].len());
-    let g_norm = f64::from(g) / scale_for_digits(parts[1].len());
-    let b_norm = f64::from(b) / scale_for_digits(parts[2].len());
+    let mut out = [0.0; 3];
+    for (slot, part) in out.iter_mut().zip(&parts) {
+        *slot = f64::from(parse_color_component(part)?) / scale_for_digits(part.len());
+    }
+    Some(out)
+}
 
-    // Perceived luminance (ITU-R BT.601).
-    let luminance = 0.299 * r_norm + 0.587 * g_norm + 0.114 * b_norm;
+// --- Palette Probe ---
+//
+// Query:    OSC 4 ; n ; ? ST
+// Response: OSC 4 ; n ; rgb:RRRR/GGGG/BBBB ST
+//
+// Used for palette-aware color downgrading on 16/256-color terminals.
 
-    Some(luminance < 0.5)
+/// Query the terminal's ANSI palette (colors 0-15) via OSC 4.
+///
+/// Same requirements as [`probe_capabilities`]. Returns `None` if any entry
+/// is missing, on timeout, or on non-Unix platforms. Stops after the first
+/// unanswered query so unsupporting terminals cost a single timeout.
+pub fn probe_palette(timeout: Duration) -> Option<[[u8; 3]; 16]> {
+    #[cfg(unix)]
+    return probe_palette_colors(timeout);
+
+    #[cfg(not(unix))]
+    {
+        let _ = timeout;
+        None
+    }
+}
+
+#[cfg(unix)]
+fn probe_palette_colors(timeout: Duration) -> Option<[[u8; 3]; 16]> {
+    let mut palette = [[0u8; 3]; 16];
+    for index in 0..16u8 {
+        let query = format!("\x1b]4;{index};?\x1b\\");
+        let response = send_probe(query.as_bytes(), timeout)?;
+        let (reported, rgb) = parse_palette_response(&response)?;
+        if reported != index {
+            return None;
+        }
+        palette[index as usize] = rgb;
+    }
+    Some(palette)
+}
+
+/// Parse an OSC 4 palette color report into `(index, [r, g, b])`.
+#[cfg(unix)]
+fn parse_palette_response(bytes: &[u8]) -> Option<(u8, [u8; 3])> {
+    let s = std::str::from_utf8(bytes).ok()?;
+    let body = &s[s.find("]4;")? + 3..];
+    let index = body[..body.find(';')?].parse().ok()?;
+    let [r, g, b] = parse_rgb_spec(body)?;
+    let to_u8 = |v: f64| (v * 255.0).round().clamp(0.0, 255.0) as u8;
+    Some((index, [to_u8(r), to_u8(g), to_u8(b)]))
 }
 
 /// Parse a hex color component (2- or 4-digit).
@@ -1306,6 +1419,19 @@ mod tests {
         assert_eq!(parse_background_response(response), Some(false));
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn parse_palette_entry() {
+        let response = b"\x1b]4;1;rgb:cdcd/0000/0000\x1b\\";
+        assert_eq!(parse_palette_response(response), Some((1, [205, 0, 0])));
+
+        let response = b"\x1b]4;12;rgb:5c/5c/ff\x07";
+        assert_eq!(parse_palette_response(response), Some((12, [92, 92, 255])));
+
+        assert!(parse_palette_response(b"\x1b]11;rgb:00/00/00\x07").is_none());
+        assert!(parse_palette_response(b"\x1b]4;x;rgb:00/00/00\x07").is_none());
+    }
+
     #[cfg(unix)]
     #[test]
     fn parse_bg_empty_response() {
@@ -1428,14 +1554,22 @@ mod tests {
         assert!(!config.probe_background);
     }
 
+    #[test]
+    fn config_builders_set_flags() {
+        let config = ProbeConfig::default()
+            .with_background(true)
+            .with_palette(true);
+        assert!(config.probe_background);
+        assert!(config.probe_palette);
+        assert!(config.probe_da1);
+    }
+
     #[test]
     fn probe_config_all_disabled_is_noop() {
-        let config = ProbeConfig {
-            timeout: Duration::from_millis(1),
-            probe_da1: false,
-            probe_da2: false,
-            probe_background: false,
-        };
+        let config = ProbeConfig::default()
+            .with_timeout(Duration::from_millis(1))
+            .with_da1(false)
+            .with_da2(false);
         let result = probe_capabilities(&config);
         assert_eq!(result, ProbeResult::default());
     }
@@ -1449,6 +1583,7 @@ mod tests {
         assert!(result.da2_terminal_type.is_none());
         assert!(result.da2_version.is_none());
         assert!(result.dark_background.is_none());
+        assert!(result.palette.is_none());
     }
 
     // --- Refine integration ---
@@ -48,6 +48,9 @@ pub enum Event {
     /// Clipboard content received (optional, from OSC 52 response).
     Clipboard(ClipboardEvent),
 
+    /// Terminal background color (from an OSC 11 response).
+    BackgroundColor(BackgroundColor),
+
     /// A tick event from the runtime.
     ///
     /// Fired when a scheduled tick interval elapses. Applications use this
@@ -389,6 +392,38 @@ pub enum ClipboardSource {
     Unknown,
 }
 
+/// A terminal background color report, the reply to an OSC 11 query.
+///
+/// Send [`BackgroundColor::QUERY`] to the terminal to ask for one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BackgroundColor {
+    /// Red component.
+    pub r: u8,
+    /// Green component.
+    pub g: u8,
+    /// Blue component.
+    pub b: u8,
+}
+
+impl BackgroundColor {
+    /// The OSC 11 query that asks the terminal for its background color.
+    pub const QUERY: &'static [u8] = b"\x1b]11;?\x1b\\";
+
+    /// Create a background color report.
+    #[must_use]
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Whether the background is dark, by perceived luminance (ITU-R BT.601).
+    #[must_use]
+    pub fn is_dark(self) -> bool {
+        let luminance =
+            0.299 * f64::from(self.r) + 0.587 * f64::from(self.g) + 0.114 * f64::from(self.b);
+        luminance < 127.5
+    }
+}
+
 #[cfg(all(not(target_arch = "wasm32"), feature = "crossterm"))]
 fn map_crossterm_event_internal(event: cte::Event) -> Option<Event> {
     match event {
@@ -590,6 +625,7 @@ mod tests {
         let _paste = Event::Paste(PasteEvent::bracketed("test"));
         let _focus = Event::Focus(true);
         let _clipboard = Event::Clipboard(ClipboardEvent::new("test", ClipboardSource::Unknown));
+        let _background = Event::BackgroundColor(BackgroundColor::new(0, 0, 0));
         let _tick = Event::Tick;
     }
 
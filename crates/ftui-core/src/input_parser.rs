@@ -24,8 +24,8 @@
 //! - Paste content: 1MB max
 
 use crate::event::{
-    ClipboardEvent, ClipboardSource, Event, KeyCode, KeyEvent, KeyEventKind, Modifiers,
-    MouseButton, MouseEvent, MouseEventKind, PasteEvent,
+    BackgroundColor, ClipboardEvent, ClipboardSource, Event, KeyCode, KeyEvent, KeyEventKind,
+    Modifiers, MouseButton, MouseEvent, MouseEventKind, PasteEvent,
 };
 
 /// DoS protection: maximum CSI sequence length.
@@ -804,6 +804,12 @@ impl InputParser {
             return self.parse_osc52_clipboard(&seq);
         }
 
+        // OSC 11 background color response: OSC 11 ; rgb:RRRR/GGGG/BBBB BEL/ST
+        if let Some(spec) = seq.strip_prefix(b"11;") {
+            return Self::parse_rgb_spec(spec)
+                .map(|[r, g, b]| Event::BackgroundColor(BackgroundColor::new(r, g, b)));
+        }
+
         // Other OSC sequences (e.g., OSC 8 hyperlinks) are not parsed as events
         None
     }
@@ -833,6 +839,23 @@ impl InputParser {
         )))
     }
 
+    /// Parse an X11 `rgb:R/G/B` color spec with 1-4 hex digits per component.
+    fn parse_rgb_spec(spec: &[u8]) -> Option<[u8; 3]> {
+        let spec = spec.strip_prefix(b"rgb:")?;
+        let mut parts = spec.split(|&b| b == b'/');
+        let mut out = [0u8; 3];
+        for slot in &mut out {
+            let part = parts.next()?;
+            if part.is_empty() || part.len() > 4 {
+                return None;
+            }
+            let value = u32::from_str_radix(std::str::from_utf8(part).ok()?, 16).ok()?;
+            let max = (1u32 << (4 * part.len())) - 1;
+            *slot = ((value * 255 + max / 2) / max) as u8;
+        }
+        parts.next().is_none().then_some(out)
+    }
+
     /// Simple base64 decoder.
     fn decode_base64(&self, input: &[u8]) -> Option<Vec<u8>> {
         const DECODE_TABLE: [i8; 256] = {
@@ -1942,6 +1965,34 @@ mod tests {
         ));
     }
 
+    // ── OSC 11 background color ──────────────────────────────────────
+
+    #[test]
+    fn osc11_background_color_report() {
+        let mut parser = InputParser::new();
+        let events = parser.parse(b"\x1b]11;rgb:ffff/8080/0000\x1b\\");
+        assert_eq!(
+            events,
+            vec![Event::BackgroundColor(BackgroundColor::new(255, 128, 0))]
+        );
+        // Short components scale to the full 8-bit range.
+        let events = parser.parse(b"\x1b]11;rgb:f/8/0\x07");
+        assert_eq!(
+            events,
+            vec![Event::BackgroundColor(BackgroundColor::new(255, 136, 0))]
+        );
+        assert!(BackgroundColor::new(30, 30, 30).is_dark());
+        assert!(!BackgroundColor::new(240, 240, 230).is_dark());
+    }
+
+    #[test]
+    fn osc11_malformed_report_is_dropped() {
+        let mut parser = InputParser::new();
+        assert!(parser.parse(b"\x1b]11;rgb:zz/00/00\x07").is_empty());
+        assert!(parser.parse(b"\x1b]11;rgb:00/00\x07").is_empty());
+        assert!(parser.parse(b"\x1b]11;?\x07").is_empty());
+    }
+
     // ── Control keys ─────────────────────────────────────────────────
 
     #[test]
@@ -3,8 +3,10 @@
 //! Key sequence interpreter for multi-key sequences (bd-2vne.2).
 //!
 //! This module provides a stateful interpreter for detecting key sequences like
-//! Esc Esc, independent of the low-level input parsing. It operates on the
-//! [`KeyEvent`] stream and uses a configurable timeout window to detect sequences.
+//! Esc Esc (or custom prefixes such as emacs' `C-x C-x`, registered with
+//! [`KeySequenceConfig::with_sequence`]), independent of the low-level input
+//! parsing. It operates on the [`KeyEvent`] stream and uses a configurable
+//! timeout window to detect sequences.
 //!
 //! # Design
 //!
@@ -48,6 +50,8 @@
 //! assert!(matches!(action, KeySequenceAction::EmitSequence { .. }));
 //! ```
 
+use std::fmt::{self, Write as _};
+
 use web_time::{Duration, Instant};
 
 use crate::event::{KeyCode, KeyEvent, KeyEventKind, Modifiers};
@@ -67,6 +71,12 @@ pub struct KeySequenceConfig {
 
     /// Whether to detect Esc Esc sequences (default: true).
     pub detect_double_escape: bool,
+
+    /// Additional sequences to detect, as `(id, keys)` pairs (default: none).
+    ///
+    /// Keys match on code and modifiers. A completed sequence is reported as
+    /// [`KeySequenceKind::Custom`] carrying its id.
+    pub custom_sequences: Vec<(u16, Vec<KeyEvent>)>,
 }
 
 impl Default for KeySequenceConfig {
@@ -74,6 +84,7 @@ impl Default for KeySequenceConfig {
         Self {
             sequence_timeout: Duration::from_millis(250),
             detect_double_escape: true,
+            custom_sequences: Vec::new(),
         }
     }
 }
@@ -87,6 +98,58 @@ impl KeySequenceConfig {
             ..Default::default()
         }
     }
+
+    /// Register a custom sequence reported as [`KeySequenceKind::Custom`]`(id)`.
+    #[must_use]
+    pub fn with_sequence(mut self, id: u16, keys: Vec<KeyEvent>) -> Self {
+        self.custom_sequences.push((id, keys));
+        self
+    }
+
+    /// Describe a sequence by its keys for display, e.g. `"Ctrl+x Ctrl+x"`.
+    ///
+    /// Custom ids that are not registered fall back to the
+    /// [`Display`](fmt::Display) form of `kind`.
+    #[must_use]
+    pub fn describe(&self, kind: KeySequenceKind) -> String {
+        let KeySequenceKind::Custom(id) = kind else {
+            return kind.to_string();
+        };
+        self.custom_sequences
+            .iter()
+            .find(|(custom, _)| *custom == id)
+            .map_or_else(
+                || kind.to_string(),
+                |(_, keys)| keys.iter().map(describe_key).collect::<Vec<_>>().join(" "),
+            )
+    }
+}
+
+/// Render a key as `Ctrl+Alt+x`-style text.
+fn describe_key(key: &KeyEvent) -> String {
+    let mut out = String::new();
+    for (flag, label) in [
+        (Modifiers::CTRL, "Ctrl+"),
+        (Modifiers::ALT, "Alt+"),
+        (Modifiers::SHIFT, "Shift+"),
+        (Modifiers::SUPER, "Super+"),
+    ] {
+        if key.modifiers.contains(flag) {
+            out.push_str(label);
+        }
+    }
+    match key.code {
+        KeyCode::Char(' ') => out.push_str("Space"),
+        KeyCode::Char(c) => out.push(c),
+        KeyCode::Escape => out.push_str("Esc"),
+        KeyCode::F(n) => {
+            let _ = write!(out, "F{n}");
+        }
+        code => {
+            let _ = write!(out, "{code:?}");
+        }
+    }
+    out
 }
 
 // ---------------------------------------------------------------------------
@@ -94,18 +157,37 @@ impl KeySequenceConfig {
 // ---------------------------------------------------------------------------
 
 /// Recognized key sequence patterns.
+///
+/// Non-exhaustive: adding [`Custom`](Self::Custom) already broke exhaustive
+/// matches, and marking the enum keeps further patterns from doing so again.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
 pub enum KeySequenceKind {
     /// Double Escape (Esc Esc) - typically used for tree view toggle.
     DoubleEscape,
+    /// A sequence registered with [`KeySequenceConfig::with_sequence`].
+    Custom(u16),
 }
 
 impl KeySequenceKind {
     /// Human-readable name for this sequence.
+    ///
+    /// Custom sequences share a generic name; use the [`Display`](fmt::Display)
+    /// form to include the id, or [`KeySequenceConfig::describe`] to list the keys.
     #[must_use]
     pub const fn name(&self) -> &'static str {
         match self {
             Self::DoubleEscape => "Esc Esc",
+            Self::Custom(_) => "Custom sequence",
+        }
+    }
+}
+
+impl fmt::Display for KeySequenceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Custom(id) => write!(f, "Custom sequence #{id}"),
+            _ => f.write_str(self.name()),
         }
     }
 }
@@ -157,6 +239,7 @@ impl KeySequenceAction {
 ///
 /// Feed key events via [`feed`](Self::feed) and periodically call
 /// [`check_timeout`](Self::check_timeout) to handle expired sequences.
+#[derive(Clone)]
 pub struct KeySequenceInterpreter {
     config: KeySequenceConfig,
 
@@ -357,6 +440,23 @@ impl KeySequenceInterpreter {
             }
         }
 
+        // Custom sequences: complete on an exact match, continue on a prefix
+        let same = |a: &KeyEvent, b: &KeyEvent| a.code == b.code && a.modifiers == b.modifiers;
+        let len = self.buffer.len() + 1;
+        let mut is_prefix = false;
+        for (id, keys) in &self.config.custom_sequences {
+            let matches = keys.len() >= len
+                && self.buffer.iter().zip(keys).all(|(a, b)| same(a, b))
+                && same(event, &keys[len - 1]);
+            if matches && keys.len() == len {
+                return SequenceResult::Complete(KeySequenceKind::Custom(*id));
+            }
+            is_prefix |= matches;
+        }
+        if is_prefix {
+            return SequenceResult::Continue;
+        }
+
         // No sequence pattern matched
         SequenceResult::NoMatch
     }
@@ -530,6 +630,67 @@ mod tests {
         assert!(!interp.has_pending());
     }
 
+    // --- Custom sequence tests ---
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent {
+            code: KeyCode::Char(c),
+            modifiers: Modifiers::CTRL,
+            kind: KeyEventKind::Press,
+        }
+    }
+
+    #[test]
+    fn custom_sequence_completes() {
+        let config = KeySequenceConfig::default().with_sequence(7, vec![ctrl('x'), ctrl('x')]);
+        let mut interp = KeySequenceInterpreter::new(config);
+        let t = now();
+
+        assert!(interp.feed(&ctrl('x'), t).is_pending());
+        let action = interp.feed(&ctrl('x'), t + MS_50);
+        assert!(matches!(
+            action,
+            KeySequenceAction::EmitSequence {
+                kind: KeySequenceKind::Custom(7),
+                ..
+            }
+        ));
+        assert!(!interp.has_pending());
+    }
+
+    #[test]
+    fn custom_sequences_share_prefix() {
+        let config = KeySequenceConfig::default()
+            .with_sequence(1, vec![ctrl('x'), key('u')])
+            .with_sequence(2, vec![ctrl('x'), key('h')]);
+        let mut interp = KeySequenceInterpreter::new(config);
+        let t = now();
+
+        assert!(interp.feed(&ctrl('x'), t).is_pending());
+        let action = interp.feed(&key('h'), t);
+        assert!(matches!(
+            action,
+            KeySequenceAction::EmitSequence {
+                kind: KeySequenceKind::Custom(2),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn custom_sequence_mismatch_emits_key() {
+        let config = KeySequenceConfig::default().with_sequence(1, vec![ctrl('x'), key('u')]);
+        let mut interp = KeySequenceInterpreter::new(config);
+        let t = now();
+
+        assert!(interp.feed(&ctrl('x'), t).is_pending());
+        let action = interp.feed(&key('q'), t);
+        assert_eq!(action, KeySequenceAction::Emit(key('q')));
+        assert!(!interp.has_pending());
+        // Plain keys that start no sequence pass straight through.
+        assert_eq!(interp.feed(&key('u'), t), KeySequenceAction::Emit(key('u')));
+    }
+
     // --- Helper method tests ---
 
     #[test]
@@ -633,6 +794,22 @@ mod tests {
     #[test]
     fn sequence_kind_name() {
         assert_eq!(KeySequenceKind::DoubleEscape.name(), "Esc Esc");
+        assert_eq!(KeySequenceKind::DoubleEscape.to_string(), "Esc Esc");
+        assert_eq!(KeySequenceKind::Custom(7).to_string(), "Custom sequence #7");
+    }
+
+    #[test]
+    fn describe_lists_custom_keys() {
+        let config = KeySequenceConfig::default()
+            .with_sequence(1, vec![ctrl('x'), key('u')])
+            .with_sequence(2, vec![esc(), key(' ')]);
+        assert_eq!(config.describe(KeySequenceKind::Custom(1)), "Ctrl+x u");
+        assert_eq!(config.describe(KeySequenceKind::Custom(2)), "Esc Space");
+        assert_eq!(
+            config.describe(KeySequenceKind::Custom(3)),
+            "Custom sequence #3"
+        );
+        assert_eq!(config.describe(KeySequenceKind::DoubleEscape), "Esc Esc");
     }
 
     // --- Default config tests ---
@@ -277,6 +277,14 @@ impl ActionTimeline {
                 ];
                 (summary, fields, Severity::Debug)
             }
+            Event::BackgroundColor(color) => {
+                let summary = "Background color reported".to_string();
+                let fields = vec![(
+                    "rgb".to_string(),
+                    format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b),
+                )];
+                (summary, fields, Severity::Debug)
+            }
             Event::Tick => {
                 let summary = "Runtime tick".to_string();
                 let fields = vec![("tick".to_string(), tick.to_string())];
@@ -452,6 +452,8 @@ impl FormsInput {
                 options, selected, ..
             } => !options.is_empty() && *selected < options.len(),
             FormField::Number { .. } => true,
+            FormField::Date { value, .. } => value.is_some(),
+            _ => true,
         }
     }
 
@@ -14,6 +14,7 @@ use ftui_layout::{Constraint, Flex, FlowDirection};
 use ftui_render::frame::Frame;
 use ftui_runtime::Cmd;
 use ftui_style::Style;
+use ftui_text::bidi::ParagraphDirection;
 use ftui_text::{
     WrapMode, display_width, grapheme_count, grapheme_width, graphemes,
     truncate_to_width_with_info, truncate_with_ellipsis, wrap_text,
@@ -852,8 +853,10 @@ impl I18nDemo {
             }
         }
 
+        // Table rows keep their column order even when a grapheme is RTL.
         Paragraph::new(lines.join("\n"))
             .style(Style::new().fg(theme::fg::PRIMARY))
+            .direction(ParagraphDirection::Ltr)
             .block(
                 Block::new()
                     .title("Grapheme Inspector")
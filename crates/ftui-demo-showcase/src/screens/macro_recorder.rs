@@ -1288,6 +1288,7 @@ fn format_event(event: &Event) -> String {
         Event::Resize { width, height } => format!("Resize {}x{}", width, height),
         Event::Focus(focus) => format!("Focus({:?})", focus),
         Event::Clipboard(_) => "Clipboard".to_string(),
+        Event::BackgroundColor(c) => format!("Background(#{:02x}{:02x}{:02x})", c.r, c.g, c.b),
         Event::Tick => "Tick".to_string(),
     }
 }
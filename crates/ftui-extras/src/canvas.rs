@@ -313,6 +313,16 @@ impl Painter {
         }
     }
 
+    /// Draw a filled rectangle with color.
+    #[inline]
+    pub fn rect_filled_colored(&mut self, x: i32, y: i32, w: i32, h: i32, color: PackedRgba) {
+        for dy in 0..h {
+            for dx in 0..w {
+                self.point_colored(x + dx, y + dy, color);
+            }
+        }
+    }
+
     /// Draw a filled convex polygon.
     #[inline]
     pub fn polygon_filled(&mut self, points: &[(i32, i32)]) {
@@ -1130,6 +1140,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn colored_rect_filled() {
+        let mut p = Painter::new(6, 6, Mode::Block);
+        let green = PackedRgba::rgb(0, 255, 0);
+        p.rect_filled_colored(1, 2, 3, 2, green);
+        assert!(p.get(1, 2));
+        assert!(p.get(3, 3));
+        assert!(!p.get(4, 3));
+        assert_eq!(p.color_at(2, 3), Some(green));
+    }
+
     #[test]
     fn clear_resets_all() {
         let mut p = Painter::new(10, 10, Mode::Braille);
@@ -3,7 +3,13 @@
 //! Chart widgets for data visualization.
 //!
 //! Provides [`Sparkline`], [`BarChart`], and [`LineChart`] widgets for
-//! rendering data in the terminal. Feature-gated behind `charts`.
+//! rendering data in the terminal, plus [`ScatterChart`], [`Histogram`],
+//! [`Heatmap`], [`CandlestickChart`], and [`PieChart`]. The latter share the
+//! [`Painter`] and the axis/legend layout in [`axis`], whose [`Axis`]
+//! generates nice linear, logarithmic, or time ticks for every chart type
+//! (including [`LineChart`] and [`BarChart::value_axis`]).
+//! [`TimeSeriesChart`] owns ring-buffered series for streaming metrics and
+//! downsamples them to the plot resolution. Feature-gated behind `charts`.
 //!
 //! # Example
 //!
@@ -26,6 +32,26 @@ use unicode_segmentation::UnicodeSegmentation;
 
 use crate::canvas::{Mode, Painter};
 
+pub mod axis;
+mod candlestick;
+mod heatmap;
+mod histogram;
+mod pie;
+mod scatter;
+mod timeseries;
+
+pub use axis::{Axis, AxisLayout, Legend, LegendEntry, LegendPosition, PlacedTick, Scale, Tick};
+
+use axis::{PixelMap, draw_x_ticks, draw_y_ticks, y_gutter_width};
+pub use candlestick::{Candle, CandlestickChart};
+pub use heatmap::{ColorScale, Heatmap};
+pub use histogram::{Bin, Binning, Histogram, histogram_bins};
+pub use pie::{PieChart, PieSlice};
+pub use scatter::{Marker, ScatterChart, ScatterSeries};
+pub use timeseries::{
+    Downsample, TimeSeries, TimeSeriesChart, TimeSeriesState, lttb, min_max_buckets,
+};
+
 // ===== Helpers =====
 
 /// Bar characters for sparkline/vertical-bar rendering (9 levels: empty through full).
@@ -142,6 +168,34 @@ fn style_cell(cell: &mut Cell, style: Style) {
     }
 }
 
+/// Draw `text` grapheme-by-grapheme starting at (`x`, `y`), stopping before
+/// `max_x`. Returns the x position after the last drawn grapheme.
+fn draw_text(frame: &mut Frame, x: u16, y: u16, text: &str, style: Style, max_x: u16) -> u16 {
+    let mut x = x;
+    for grapheme in text.graphemes(true) {
+        let g_width = grapheme_width(grapheme);
+        if g_width == 0 {
+            continue;
+        }
+        if x as u32 + g_width as u32 > max_x as u32 {
+            break;
+        }
+        let content = if g_width > 1 || grapheme.chars().count() > 1 {
+            let id = frame.intern_with_width(grapheme, u8::try_from(g_width).unwrap_or(u8::MAX));
+            CellContent::from_grapheme(id)
+        } else if let Some(c) = grapheme.chars().next() {
+            CellContent::from_char(c)
+        } else {
+            continue;
+        };
+        let mut cell = Cell::new(content);
+        style_cell(&mut cell, style);
+        frame.buffer.set_fast(x, y, cell);
+        x = x.saturating_add(u16::try_from(g_width).unwrap_or(u16::MAX));
+    }
+    x
+}
+
 // ===== Sparkline =====
 
 /// Compact single-line data visualization using block characters (`▁▂▃▄▅▆▇█`).
@@ -291,6 +345,7 @@ pub struct BarChart<'a> {
     colors: Vec<PackedRgba>,
     style: Style,
     max: Option<f64>,
+    value_axis: Option<Axis>,
 }
 
 impl<'a> BarChart<'a> {
@@ -310,6 +365,7 @@ impl<'a> BarChart<'a> {
             ],
             style: Style::new(),
             max: None,
+            value_axis: None,
         }
     }
 
@@ -361,6 +417,15 @@ impl<'a> BarChart<'a> {
         self
     }
 
+    /// Draw value ticks (left of vertical bars, below horizontal ones) and
+    /// measure bars against the axis range, fitted from zero to the maximum
+    /// unless the axis has explicit bounds.
+    #[must_use]
+    pub fn value_axis(mut self, axis: Axis) -> Self {
+        self.value_axis = Some(axis);
+        self
+    }
+
     fn compute_max(&self) -> f64 {
         if let Some(m) = self.max {
             return m;
@@ -400,15 +465,58 @@ impl Widget for BarChart<'_> {
             return;
         }
 
+        let linear = Axis::new();
+        let (value_axis, range) = match &self.value_axis {
+            Some(axis) => (axis, axis.resolve(0.0, max_val)),
+            None => (&linear, (0.0, max_val)),
+        };
+        let bars = match (&self.value_axis, self.direction) {
+            (None, _) => area,
+            (Some(_), BarDirection::Vertical) => {
+                // Tick labels and an axis line left of the bars, above the
+                // group label row.
+                let rows = area.height.saturating_sub(1);
+                let ticks = value_axis.place_ticks(range, rows, false);
+                let gutter = y_gutter_width(&ticks).min(area.width);
+                let line_x = area.x + gutter - 1;
+                draw_y_ticks(frame, &ticks, (area.x, line_x), area.y + rows, self.style);
+                for y in area.y..area.y + rows {
+                    let mut cell = Cell::from_char('│');
+                    style_cell(&mut cell, self.style);
+                    frame.buffer.set_fast(line_x, y, cell);
+                }
+                Rect::new(area.x + gutter, area.y, area.width - gutter, area.height)
+            }
+            (Some(_), BarDirection::Horizontal) => {
+                // Tick labels on the bottom row, under the bars.
+                let label_width = 2_u16;
+                let ticks =
+                    value_axis.place_ticks(range, area.width.saturating_sub(label_width), true);
+                draw_x_ticks(
+                    frame,
+                    &ticks,
+                    area.x.saturating_add(label_width),
+                    area.bottom() - 1,
+                    (area.x, area.right()),
+                    self.style,
+                );
+                Rect::new(area.x, area.y, area.width, area.height - 1)
+            }
+        };
+
         match self.direction {
-            BarDirection::Vertical => self.render_vertical(area, &mut frame.buffer, max_val),
-            BarDirection::Horizontal => self.render_horizontal(area, &mut frame.buffer, max_val),
+            BarDirection::Vertical => {
+                self.render_vertical(bars, &mut frame.buffer, value_axis, range)
+            }
+            BarDirection::Horizontal => {
+                self.render_horizontal(bars, &mut frame.buffer, value_axis, range);
+            }
         }
     }
 }
 
 impl BarChart<'_> {
-    fn render_vertical(&self, area: Rect, buf: &mut Buffer, max_val: f64) {
+    fn render_vertical(&self, area: Rect, buf: &mut Buffer, axis: &Axis, range: (f64, f64)) {
         // Reserve 1 row at bottom for labels.
         let chart_height = area.height.saturating_sub(1) as f64;
         if chart_height <= 0.0 {
@@ -432,7 +540,7 @@ impl BarChart<'_> {
                         if si > 0 {
                             x_cursor += self.bar_gap;
                         }
-                        let h = (val / max_val) * chart_height;
+                        let h = axis.normalize(val, range) * chart_height;
                         let h = if h.is_nan() { 0.0 } else { h };
                         let full = h.floor() as u16;
                         let frac_idx = ((h - h.floor()) * 8.0).round().min(8.0) as usize;
@@ -479,9 +587,11 @@ impl BarChart<'_> {
                     // Use cumulative heights to avoid fractional gaps.
                     let mut cumulative = 0.0_f64;
                     for (si, &val) in group.values.iter().enumerate() {
-                        let prev_rows = (cumulative / max_val * chart_height).round() as u16;
+                        let prev_rows =
+                            (axis.normalize(cumulative, range) * chart_height).round() as u16;
                         cumulative += val;
-                        let curr_rows = (cumulative / max_val * chart_height).round() as u16;
+                        let curr_rows =
+                            (axis.normalize(cumulative, range) * chart_height).round() as u16;
                         let segment = curr_rows.saturating_sub(prev_rows);
                         let color = self.get_color(si);
 
@@ -518,7 +628,7 @@ impl BarChart<'_> {
         }
     }
 
-    fn render_horizontal(&self, area: Rect, buf: &mut Buffer, max_val: f64) {
+    fn render_horizontal(&self, area: Rect, buf: &mut Buffer, axis: &Axis, range: (f64, f64)) {
         // Reserve 2 columns at left for labels.
         let label_width = 2_u16;
         let chart_width = area.width.saturating_sub(label_width) as f64;
@@ -539,7 +649,7 @@ impl BarChart<'_> {
                         if si > 0 {
                             y_cursor += self.bar_gap;
                         }
-                        let bar_len_f = (val / max_val) * chart_width;
+                        let bar_len_f = axis.normalize(val, range) * chart_width;
                         let bar_len = if bar_len_f.is_nan() {
                             0
                         } else {
@@ -568,7 +678,7 @@ impl BarChart<'_> {
                 BarMode::Stacked => {
                     let mut left_col = 0_u16;
                     for (si, &val) in group.values.iter().enumerate() {
-                        let bar_len_f = (val / max_val) * chart_width;
+                        let bar_len_f = axis.normalize(val, range) * chart_width;
                         let bar_len = if bar_len_f.is_nan() {
                             0
                         } else {
@@ -650,40 +760,52 @@ impl<'a> Series<'a> {
 /// Line chart with multi-series support, axis rendering, and legend.
 ///
 /// Uses [`Canvas`](crate::canvas::Canvas) internally with Braille mode for
-/// sub-cell line resolution.
+/// sub-cell line resolution. Axes default to auto-fitted bounds without tick
+/// labels; pass an [`Axis`] to [`LineChart::x_axis`] or
+/// [`LineChart::y_axis`] for generated ticks and other scales.
 #[derive(Debug, Clone)]
 pub struct LineChart<'a> {
     series: Vec<Series<'a>>,
-    x_bounds: Option<(f64, f64)>,
-    y_bounds: Option<(f64, f64)>,
+    x_axis: Axis,
+    y_axis: Axis,
     style: Style,
-    x_labels: Vec<&'a str>,
-    y_labels: Vec<&'a str>,
     show_legend: bool,
+    legend_position: LegendPosition,
 }
 
 impl<'a> LineChart<'a> {
     pub fn new(series: Vec<Series<'a>>) -> Self {
         Self {
             series,
-            x_bounds: None,
-            y_bounds: None,
+            x_axis: Axis::new().ticks(0),
+            y_axis: Axis::new().ticks(0),
             style: Style::new(),
-            x_labels: Vec::new(),
-            y_labels: Vec::new(),
             show_legend: false,
+            legend_position: LegendPosition::default(),
         }
     }
 
+    #[must_use]
+    pub fn x_axis(mut self, axis: Axis) -> Self {
+        self.x_axis = axis;
+        self
+    }
+
+    #[must_use]
+    pub fn y_axis(mut self, axis: Axis) -> Self {
+        self.y_axis = axis;
+        self
+    }
+
     #[must_use]
     pub fn x_bounds(mut self, min: f64, max: f64) -> Self {
-        self.x_bounds = Some((min, max));
+        self.x_axis = self.x_axis.bounds(min, max);
         self
     }
 
     #[must_use]
     pub fn y_bounds(mut self, min: f64, max: f64) -> Self {
-        self.y_bounds = Some((min, max));
+        self.y_axis = self.y_axis.bounds(min, max);
         self
     }
 
@@ -693,15 +815,17 @@ impl<'a> LineChart<'a> {
         self
     }
 
+    /// Fixed x labels, left to right.
     #[must_use]
     pub fn x_labels(mut self, labels: Vec<&'a str>) -> Self {
-        self.x_labels = labels;
+        self.x_axis = self.x_axis.labels(labels);
         self
     }
 
+    /// Fixed y labels, top to bottom.
     #[must_use]
     pub fn y_labels(mut self, labels: Vec<&'a str>) -> Self {
-        self.y_labels = labels;
+        self.y_axis = self.y_axis.labels(labels.into_iter().rev());
         self
     }
 
@@ -711,24 +835,17 @@ impl<'a> LineChart<'a> {
         self
     }
 
-    fn auto_bounds(&self) -> ((f64, f64), (f64, f64)) {
-        let mut x_min = f64::INFINITY;
-        let mut x_max = f64::NEG_INFINITY;
-        let mut y_min = f64::INFINITY;
-        let mut y_max = f64::NEG_INFINITY;
-
-        for series in &self.series {
-            for &(x, y) in series.data {
-                x_min = x_min.min(x);
-                x_max = x_max.max(x);
-                y_min = y_min.min(y);
-                y_max = y_max.max(y);
-            }
-        }
+    #[must_use]
+    pub fn legend_position(mut self, position: LegendPosition) -> Self {
+        self.legend_position = position;
+        self
+    }
 
+    fn ranges(&self) -> ((f64, f64), (f64, f64)) {
+        let points = || self.series.iter().flat_map(|s| s.data.iter());
         (
-            self.x_bounds.unwrap_or((x_min, x_max)),
-            self.y_bounds.unwrap_or((y_min, y_max)),
+            self.x_axis.fit(points().map(|p| p.0)),
+            self.y_axis.fit(points().map(|p| p.1)),
         )
     }
 }
@@ -739,81 +856,53 @@ impl Widget for LineChart<'_> {
             return;
         }
 
-        // Reserve space for axes.
-        let y_axis_width: u16 = if self.y_labels.is_empty() {
-            1
-        } else {
-            self.y_labels
-                .iter()
-                .map(|l| display_width(l))
-                .max()
-                .unwrap_or(0) as u16
-                + 1
-        };
-        let x_axis_height: u16 = if self.x_labels.is_empty() { 1 } else { 2 };
-
-        let chart_area = Rect::new(
-            area.x.saturating_add(y_axis_width),
-            area.y,
-            area.width.saturating_sub(y_axis_width),
-            area.height.saturating_sub(x_axis_height),
-        );
-
-        if chart_area.width < 2 || chart_area.height < 2 {
+        let legend = self.show_legend.then(|| {
+            Legend::new(
+                self.series
+                    .iter()
+                    .map(|s| LegendEntry::new(s.name, s.color))
+                    .collect(),
+            )
+            .position(self.legend_position)
+            .style(self.style)
+        });
+        let chart_area = legend.as_ref().map_or(area, |l| l.reserve(area));
+
+        let (x_range, y_range) = self.ranges();
+        let layout = AxisLayout::new(chart_area, &self.x_axis, x_range, &self.y_axis, y_range);
+        if !layout.is_drawable() {
             return;
         }
-
-        let ((mut x_min, mut x_max), (mut y_min, mut y_max)) = self.auto_bounds();
-
-        if (x_max - x_min).abs() < f64::EPSILON {
-            x_min -= 1.0;
-            x_max += 1.0;
-        }
-        if (y_max - y_min).abs() < f64::EPSILON {
-            y_min -= 1.0;
-            y_max += 1.0;
-        }
-
-        let x_range = x_max - x_min;
-        let y_range = y_max - y_min;
+        let plot = layout.plot;
 
         // Draw data using Canvas/Painter with Braille mode.
-        let mut painter = Painter::for_area(chart_area, Mode::Braille);
-        let px_w = (chart_area.width * Mode::Braille.cols_per_cell()) as f64;
-        let px_h = (chart_area.height * Mode::Braille.rows_per_cell()) as f64;
-
-        let to_px = |x: f64, y: f64| -> (i32, i32) {
-            let px = (x - x_min) / x_range * (px_w - 1.0);
-            let py = (y_max - y) / y_range * (px_h - 1.0);
-
-            let px = if px.is_nan() { 0.0 } else { px };
-            let py = if py.is_nan() { 0.0 } else { py };
-
-            (px.round() as i32, py.round() as i32)
-        };
+        let mut painter = Painter::for_area(plot, Mode::Braille);
+        let map = PixelMap::new(&self.x_axis, x_range, &self.y_axis, y_range, painter.size());
 
         for series in &self.series {
-            if series.data.is_empty() {
-                continue;
-            }
-
             // Draw lines between consecutive points.
             for window in series.data.windows(2) {
-                let (x0, y0) = to_px(window[0].0, window[0].1);
-                let (x1, y1) = to_px(window[1].0, window[1].1);
-                painter.line_colored(x0, y0, x1, y1, Some(series.color));
+                if let (Some((x0, y0)), Some((x1, y1))) = (
+                    map.point(window[0].0, window[0].1),
+                    map.point(window[1].0, window[1].1),
+                ) {
+                    painter.line_colored(x0, y0, x1, y1, Some(series.color));
+                }
             }
 
             // Single point: just a dot.
-            if series.data.len() == 1 {
-                let (px, py) = to_px(series.data[0].0, series.data[0].1);
+            if let [(x, y)] = series.data
+                && let Some((px, py)) = map.point(*x, *y)
+            {
                 painter.point_colored(px, py, series.color);
             }
 
             // Optional markers.
             if series.show_markers {
                 for &(x, y) in series.data {
-                    let (px, py) = to_px(x, y);
+                    let Some((px, py)) = map.point(x, y) else {
+                        continue;
+                    };
                     for d in -1..=1 {
                         painter.point_colored(px + d, py, series.color);
                         painter.point_colored(px, py + d, series.color);
@@ -823,161 +912,10 @@ impl Widget for LineChart<'_> {
         }
 
         let canvas = crate::canvas::Canvas::from_painter(&painter).style(self.style);
-        canvas.render(chart_area, frame);
-
-        // Y axis line.
-        let axis_x = chart_area.x.saturating_sub(1);
-        if axis_x >= area.x {
-            for y in chart_area.y..chart_area.bottom() {
-                let mut cell = Cell::from_char('│');
-                style_cell(&mut cell, self.style);
-                frame.buffer.set_fast(axis_x, y, cell);
-            }
-        }
-
-        // X axis line.
-        let axis_y = chart_area.bottom();
-        if axis_y < area.bottom() {
-            for x in chart_area.x..chart_area.right() {
-                let mut cell = Cell::from_char('─');
-                style_cell(&mut cell, self.style);
-                frame.buffer.set_fast(x, axis_y, cell);
-            }
-            // Corner.
-            if axis_x >= area.x {
-                let mut cell = Cell::from_char('└');
-                style_cell(&mut cell, self.style);
-                frame.buffer.set_fast(axis_x, axis_y, cell);
-            }
-        }
-
-        // Y labels (top-to-bottom order).
-        if !self.y_labels.is_empty() {
-            let n = self.y_labels.len();
-            for (i, label) in self.y_labels.iter().enumerate() {
-                let y = if n == 1 {
-                    chart_area.y
-                } else {
-                    chart_area.y
-                        + (i as u32 * chart_area.height.saturating_sub(1) as u32
-                            / (n as u32 - 1).max(1)) as u16
-                };
-                let max_len = y_axis_width.saturating_sub(1) as usize;
-                let label_width = display_width(label).min(max_len);
-                let start_x = area.x.saturating_add(
-                    (y_axis_width.saturating_sub(1)).saturating_sub(label_width as u16),
-                );
-                let mut col = 0u16;
-                for grapheme in label.graphemes(true) {
-                    let g_width = grapheme_width(grapheme);
-                    if g_width == 0 {
-                        continue;
-                    }
-                    if col as usize + g_width > max_len {
-                        break;
-                    }
-                    let content = if g_width > 1 || grapheme.chars().count() > 1 {
-                        let id = frame
-                            .intern_with_width(grapheme, u8::try_from(g_width).unwrap_or(u8::MAX));
-                        CellContent::from_grapheme(id)
-                    } else if let Some(c) = grapheme.chars().next() {
-                        CellContent::from_char(c)
-                    } else {
-                        continue;
-                    };
-                    let mut cell = Cell::new(content);
-                    style_cell(&mut cell, self.style);
-                    frame.buffer.set_fast(start_x.saturating_add(col), y, cell);
-                    col = col.saturating_add(u16::try_from(g_width).unwrap_or(u16::MAX));
-                }
-            }
-        }
-
-        // X labels.
-        if !self.x_labels.is_empty() && axis_y.saturating_add(1) < area.bottom() {
-            let text_y = axis_y.saturating_add(1);
-            let n = self.x_labels.len();
-            for (i, label) in self.x_labels.iter().enumerate() {
-                let x = if n == 1 {
-                    chart_area.x
-                } else {
-                    chart_area.x.saturating_add(
-                        (i as u32 * chart_area.width.saturating_sub(1) as u32
-                            / (n as u32 - 1).max(1)) as u16,
-                    )
-                };
-                let mut col = 0u16;
-                for grapheme in label.graphemes(true) {
-                    let lx = x.saturating_add(col);
-                    let g_width = grapheme_width(grapheme);
-                    if g_width == 0 {
-                        continue;
-                    }
-                    if lx as u32 + g_width as u32 > area.right() as u32 {
-                        break;
-                    }
-                    let content = if g_width > 1 || grapheme.chars().count() > 1 {
-                        let id = frame
-                            .intern_with_width(grapheme, u8::try_from(g_width).unwrap_or(u8::MAX));
-                        CellContent::from_grapheme(id)
-                    } else if let Some(c) = grapheme.chars().next() {
-                        CellContent::from_char(c)
-                    } else {
-                        continue;
-                    };
-                    let mut cell = Cell::new(content);
-                    style_cell(&mut cell, self.style);
-                    frame.buffer.set_fast(lx, text_y, cell);
-                    col = col.saturating_add(u16::try_from(g_width).unwrap_or(u16::MAX));
-                }
-            }
-        }
-
-        // Legend.
-        if self.show_legend && !self.series.is_empty() {
-            let max_name = self
-                .series
-                .iter()
-                .map(|s| display_width(s.name))
-                .max()
-                .unwrap_or(0);
-            let legend_width = (max_name as u16).saturating_add(3); // "■ name"
-            let legend_x = chart_area.right().saturating_sub(legend_width);
-
-            for (i, series) in self.series.iter().enumerate() {
-                let y = chart_area.y.saturating_add(i as u16);
-                if y >= chart_area.bottom() {
-                    break;
-                }
-                let mut marker = Cell::from_char('■');
-                marker.fg = series.color;
-                frame.buffer.set_fast(legend_x, y, marker);
-
-                let mut col = 0u16;
-                for grapheme in series.name.graphemes(true) {
-                    let x = legend_x.saturating_add(2).saturating_add(col);
-                    let g_width = grapheme_width(grapheme);
-                    if g_width == 0 {
-                        continue;
-                    }
-                    if x as u32 + g_width as u32 > area.right() as u32 {
-                        break;
-                    }
-                    let content = if g_width > 1 || grapheme.chars().count() > 1 {
-                        let id = frame
-                            .intern_with_width(grapheme, u8::try_from(g_width).unwrap_or(u8::MAX));
-                        CellContent::from_grapheme(id)
-                    } else if let Some(c) = grapheme.chars().next() {
-                        CellContent::from_char(c)
-                    } else {
-                        continue;
-                    };
-                    let mut cell = Cell::new(content);
-                    style_cell(&mut cell, self.style);
-                    frame.buffer.set_fast(x, y, cell);
-                    col = col.saturating_add(u16::try_from(g_width).unwrap_or(u16::MAX));
-                }
-            }
+        canvas.render(plot, frame);
+        layout.render(frame, self.style);
+        if let Some(legend) = legend {
+            legend.render(legend.placement(area, plot), frame);
         }
     }
 }
@@ -1812,6 +1750,68 @@ mod tests {
         assert_eq!(char_at(&frame.buffer, 0, 0), Some('0'));
     }
 
+    #[test]
+    fn linechart_generated_ticks() {
+        let data: Vec<(f64, f64)> = vec![(0.0, 0.0), (100.0, 100.0)];
+        let series = vec![Series::new("s", &data, PackedRgba::WHITE)];
+        let area = Rect::new(0, 0, 30, 10);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(30, 10, &mut pool);
+        LineChart::new(series)
+            .x_axis(Axis::new())
+            .y_axis(Axis::new().ticks(3))
+            .render(area, &mut frame);
+
+        let row = |y: u16| -> String {
+            (0..30)
+                .map(|x| char_at(&frame.buffer, x, y).unwrap_or(' '))
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        };
+        // Y ticks 0/50/100 in a 4-column gutter; the plot is rows 0..8.
+        assert!(row(0).starts_with("100│"));
+        assert!(row(7).starts_with("  0│"));
+        assert_eq!(row(9), "    0    25     50    75   100");
+    }
+
+    #[test]
+    fn barchart_value_axis_vertical() {
+        let groups = vec![BarGroup::new("A", vec![10.0])];
+        let area = Rect::new(0, 0, 6, 6);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(6, 6, &mut pool);
+        BarChart::new(groups)
+            .value_axis(Axis::new().ticks(3))
+            .render(area, &mut frame);
+
+        // Ticks 0/5/10 over the 5 bar rows, then the axis line at x=2.
+        assert_eq!(char_at(&frame.buffer, 0, 0), Some('1'));
+        assert_eq!(char_at(&frame.buffer, 1, 2), Some('5'));
+        assert_eq!(char_at(&frame.buffer, 1, 4), Some('0'));
+        assert_eq!(char_at(&frame.buffer, 2, 0), Some('│'));
+        assert_eq!(char_at(&frame.buffer, 3, 0), Some('█'));
+        assert_eq!(char_at(&frame.buffer, 3, 5), Some('A'));
+    }
+
+    #[test]
+    fn barchart_value_axis_horizontal() {
+        let groups = vec![BarGroup::new("A", vec![5.0])];
+        let area = Rect::new(0, 0, 12, 3);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(12, 3, &mut pool);
+        BarChart::new(groups)
+            .direction(BarDirection::Horizontal)
+            .value_axis(Axis::new().bounds(0.0, 10.0).ticks(2))
+            .render(area, &mut frame);
+
+        // Half-length bar against the 0..10 axis, ticks on the bottom row.
+        assert_eq!(char_at(&frame.buffer, 6, 0), Some('█'));
+        assert_eq!(char_at(&frame.buffer, 7, 0), None);
+        assert_eq!(char_at(&frame.buffer, 2, 2), Some('0'));
+        assert_eq!(char_at(&frame.buffer, 10, 2), Some('1'));
+    }
+
     // ===== display_width edge cases =====
 
     #[test]
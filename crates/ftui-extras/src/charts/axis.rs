@@ -0,0 +1,1320 @@
+//! Shared axis and legend layout for chart widgets.
+//!
+//! [`Axis`] fits a displayed range to the data and generates "nice" tick
+//! labels on a linear, logarithmic, or time [`Scale`]. [`AxisLayout`] splits a
+//! chart area into the plot rectangle plus the gutters for tick labels and
+//! titles, thinning ticks until their labels no longer collide, and draws the
+//! axis lines and labels. [`Legend`] draws a list of colored entries either
+//! overlaid in a corner of the plot or in a column to its right.
+
+use ftui_core::geometry::Rect;
+use ftui_render::cell::{Cell, PackedRgba};
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+use ftui_widgets::Widget;
+
+use super::{display_width, draw_text, style_cell};
+
+/// Default number of auto-generated ticks per axis.
+const DEFAULT_TICKS: usize = 5;
+
+/// Tolerance for treating a value as lying on a tick step.
+const STEP_EPSILON: f64 = 1e-9;
+
+/// Upper bound on generated ticks, guarding against degenerate steps.
+const MAX_TICKS: f64 = 1000.0;
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+const SECONDS_PER_YEAR: f64 = 365.0 * SECONDS_PER_DAY;
+/// Mean Gregorian month, used to pick month steps and label precision.
+const SECONDS_PER_MONTH: f64 = 30.436_875 * SECONDS_PER_DAY;
+
+/// Candidate tick steps for time axes, in seconds: 1 s up to two weeks.
+const TIME_STEPS: [f64; 21] = [
+    1.0,
+    2.0,
+    5.0,
+    10.0,
+    15.0,
+    30.0,
+    60.0,
+    120.0,
+    300.0,
+    600.0,
+    900.0,
+    1800.0,
+    3600.0,
+    7200.0,
+    10800.0,
+    21600.0,
+    43200.0,
+    86400.0,
+    172_800.0,
+    604_800.0,
+    1_209_600.0,
+];
+
+/// Candidate calendar-month steps beyond [`TIME_STEPS`]; longer spans step
+/// in whole years.
+const MONTH_STEPS: [i64; 3] = [1, 3, 6];
+
+// ===== Scale =====
+
+/// How data values map onto an axis.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Scale {
+    /// Evenly spaced values.
+    #[default]
+    Linear,
+    /// Logarithmic with the given base (non-positive or invalid bases fall
+    /// back to 10). Values `<= 0` cannot be shown.
+    Log(f64),
+    /// Unix epoch seconds, with ticks on whole seconds, minutes, hours,
+    /// days or weeks, or on the first of every 1, 3 or 6 months or N years,
+    /// and UTC labels that adapt to the tick step.
+    Time,
+}
+
+impl Scale {
+    /// Position of `value` along an axis showing `range`, from `0.0` at the
+    /// minimum to `1.0` at the maximum. NaN when the scale cannot show it.
+    pub fn normalize(self, value: f64, range: (f64, f64)) -> f64 {
+        let (min, max) = range;
+        match self {
+            Scale::Log(_) if value <= 0.0 => f64::NAN,
+            Scale::Log(_) => (value.ln() - min.ln()) / (max.ln() - min.ln()),
+            Scale::Linear | Scale::Time => (value - min) / (max - min),
+        }
+    }
+}
+
+/// The usable base of a [`Scale::Log`].
+fn log_base(base: f64) -> f64 {
+    if base.is_finite() && base > 1.0 {
+        base
+    } else {
+        10.0
+    }
+}
+
+// ===== Axis =====
+
+/// A tick mark: a data value and its label.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tick {
+    /// Data value the tick marks, in axis units.
+    pub value: f64,
+    /// Text shown at the tick.
+    pub label: String,
+}
+
+/// A tick label positioned along an axis.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlacedTick {
+    /// Cells from the axis minimum (the left end, or the bottom row).
+    pub offset: u16,
+    /// Text shown at the offset.
+    pub label: String,
+}
+
+/// Configuration for one chart axis: scale, bounds, tick labels, and title.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Axis {
+    scale: Scale,
+    bounds: Option<(f64, f64)>,
+    padding: f64,
+    nice: bool,
+    labels: Option<Vec<String>>,
+    ticks: usize,
+    title: Option<String>,
+}
+
+impl Default for Axis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Axis {
+    /// A linear axis with auto bounds and nice numeric ticks.
+    pub fn new() -> Self {
+        Self {
+            scale: Scale::default(),
+            bounds: None,
+            padding: 0.0,
+            nice: false,
+            labels: None,
+            ticks: DEFAULT_TICKS,
+            title: None,
+        }
+    }
+
+    /// Set the scale.
+    #[must_use]
+    pub fn scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// The configured scale.
+    pub fn scale_type(&self) -> Scale {
+        self.scale
+    }
+
+    /// Set explicit bounds (otherwise fitted to the data).
+    #[must_use]
+    pub fn bounds(mut self, min: f64, max: f64) -> Self {
+        self.bounds = Some((min, max));
+        self
+    }
+
+    /// Widen auto-fitted bounds by `fraction` of the data span on each side
+    /// (measured in decades on a log scale).
+    #[must_use]
+    pub fn padding(mut self, fraction: f64) -> Self {
+        self.padding = if fraction.is_finite() {
+            fraction.max(0.0)
+        } else {
+            0.0
+        };
+        self
+    }
+
+    /// Extend auto-fitted bounds outward to the nearest tick.
+    #[must_use]
+    pub fn nice(mut self, nice: bool) -> Self {
+        self.nice = nice;
+        self
+    }
+
+    /// Use fixed labels, ordered from the axis minimum to its maximum and
+    /// spread evenly along the axis.
+    #[must_use]
+    pub fn labels<I, S>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.labels = Some(labels.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the maximum number of auto-generated ticks (`0` hides tick
+    /// labels). Fewer are drawn when the labels would collide.
+    #[must_use]
+    pub fn ticks(mut self, count: usize) -> Self {
+        self.ticks = count;
+        self
+    }
+
+    /// Set the axis title.
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Resolve the displayed range for `values`, ignoring those the scale
+    /// cannot show.
+    pub fn fit(&self, values: impl IntoIterator<Item = f64>) -> (f64, f64) {
+        let log = matches!(self.scale, Scale::Log(_));
+        let (min, max) = data_extent(values.into_iter().filter(|&v| !log || v > 0.0));
+        self.resolve(min, max)
+    }
+
+    /// Resolve the displayed range from explicit bounds or the data extent.
+    ///
+    /// Invalid extents fall back to `0..1` (`1..base` on a log scale) and
+    /// degenerate ones are widened. Padding and nice rounding only apply to
+    /// auto-fitted bounds.
+    pub fn resolve(&self, data_min: f64, data_max: f64) -> (f64, f64) {
+        if let Some((min, max)) = self.bounds {
+            return self.sanitize(min, max);
+        }
+        let (min, max) = self.pad(self.sanitize(data_min, data_max));
+        if self.nice {
+            self.round_out(min, max)
+        } else {
+            (min, max)
+        }
+    }
+
+    /// Position of `value` along this axis when showing `range` (see
+    /// [`Scale::normalize`]).
+    pub fn normalize(&self, value: f64, range: (f64, f64)) -> f64 {
+        self.scale.normalize(value, range)
+    }
+
+    fn sanitize(&self, min: f64, max: f64) -> (f64, f64) {
+        let valid = min.is_finite() && max.is_finite() && min <= max;
+        match self.scale {
+            Scale::Log(base) => {
+                let base = log_base(base);
+                if !valid || max <= 0.0 {
+                    return (1.0, base);
+                }
+                let min = if min <= 0.0 { max / base } else { min };
+                if (max / min - 1.0).abs() < f64::EPSILON {
+                    (min / base, max * base)
+                } else {
+                    (min, max)
+                }
+            }
+            Scale::Linear | Scale::Time => {
+                if !valid {
+                    (0.0, 1.0)
+                } else if (max - min).abs() < f64::EPSILON {
+                    (min - 1.0, max + 1.0)
+                } else {
+                    (min, max)
+                }
+            }
+        }
+    }
+
+    fn pad(&self, (min, max): (f64, f64)) -> (f64, f64) {
+        if self.padding == 0.0 {
+            return (min, max);
+        }
+        match self.scale {
+            Scale::Log(_) => {
+                let factor = (max / min).powf(self.padding);
+                (min / factor, max * factor)
+            }
+            Scale::Linear | Scale::Time => {
+                let pad = (max - min) * self.padding;
+                (min - pad, max + pad)
+            }
+        }
+    }
+
+    fn round_out(&self, min: f64, max: f64) -> (f64, f64) {
+        let count = if self.ticks == 0 {
+            DEFAULT_TICKS
+        } else {
+            self.ticks
+        };
+        let step = match self.scale {
+            Scale::Log(base) => {
+                let base = log_base(base);
+                return (
+                    base.powf((min.log(base) + STEP_EPSILON).floor()),
+                    base.powf((max.log(base) - STEP_EPSILON).ceil()),
+                );
+            }
+            Scale::Linear => nice_step((max - min) / intervals(count)),
+            Scale::Time => {
+                let step = time_step((max - min) / intervals(count));
+                return (step.floor(min), step.ceil(max));
+            }
+        };
+        (
+            (min / step + STEP_EPSILON).floor() * step,
+            (max / step - STEP_EPSILON).ceil() * step,
+        )
+    }
+
+    /// Whether this axis draws a row or column of tick labels.
+    fn has_tick_labels(&self) -> bool {
+        self.labels
+            .as_ref()
+            .map_or(self.ticks > 0, |labels| !labels.is_empty())
+    }
+
+    /// Up to `count` ticks inside `range`, from the minimum to the maximum.
+    /// Fixed labels are spread evenly regardless of `count`.
+    pub fn generate_ticks(&self, range: (f64, f64), count: usize) -> Vec<Tick> {
+        let (min, max) = range;
+        if let Some(labels) = &self.labels {
+            let n = labels.len();
+            return labels
+                .iter()
+                .enumerate()
+                .map(|(i, label)| Tick {
+                    value: if n > 1 {
+                        min + (max - min) * i as f64 / (n - 1) as f64
+                    } else {
+                        min
+                    },
+                    label: label.clone(),
+                })
+                .collect();
+        }
+        match self.scale {
+            Scale::Linear => linear_ticks(min, max, count),
+            Scale::Log(base) => log_ticks(min, max, count, log_base(base)),
+            Scale::Time => time_ticks(min, max, count),
+        }
+    }
+
+    /// Ticks positioned along an axis `extent` cells long, thinned until
+    /// neighbouring labels no longer overlap.
+    ///
+    /// Fixed labels are never thinned; a single fixed label sits at the
+    /// start of the axis as read (left, or top).
+    pub fn place_ticks(&self, range: (f64, f64), extent: u16, horizontal: bool) -> Vec<PlacedTick> {
+        if extent == 0 {
+            return Vec::new();
+        }
+        if let Some(labels) = &self.labels {
+            let n = labels.len();
+            return labels
+                .iter()
+                .enumerate()
+                .map(|(i, label)| PlacedTick {
+                    offset: if n == 1 && !horizontal {
+                        extent - 1
+                    } else {
+                        spread(i, n, extent)
+                    },
+                    label: label.clone(),
+                })
+                .collect();
+        }
+
+        let cells = f64::from(extent - 1);
+        let mut count = self.ticks;
+        loop {
+            let placed: Vec<PlacedTick> = self
+                .generate_ticks(range, count)
+                .into_iter()
+                .filter_map(|tick| {
+                    let pos = self.normalize(tick.value, range);
+                    (-STEP_EPSILON..=1.0 + STEP_EPSILON)
+                        .contains(&pos)
+                        .then(|| PlacedTick {
+                            offset: (pos.clamp(0.0, 1.0) * cells).round() as u16,
+                            label: tick.label,
+                        })
+                })
+                .collect();
+            if count <= 2 || labels_fit(&placed, horizontal) {
+                return placed;
+            }
+            count -= 1;
+        }
+    }
+}
+
+/// Number of intervals between `count` ticks (at least one).
+fn intervals(count: usize) -> f64 {
+    count.saturating_sub(1).max(1) as f64
+}
+
+/// The smallest of 1, 2, 2.5, 5 or 10 times a power of ten that is at least
+/// `raw`.
+fn nice_step(raw: f64) -> f64 {
+    if !raw.is_finite() || raw <= 0.0 {
+        return 1.0;
+    }
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let normalized = raw / magnitude;
+    let nice = [1.0, 2.0, 2.5, 5.0]
+        .into_iter()
+        .find(|&n| normalized <= n + STEP_EPSILON)
+        .unwrap_or(10.0);
+    nice * magnitude
+}
+
+/// A tick step on a time axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TimeStep {
+    /// A fixed number of seconds (see [`TIME_STEPS`]).
+    Seconds(f64),
+    /// Whole calendar months, counted from January of year 0, so 3 months
+    /// are quarters and 12 or more are whole years.
+    Months(i64),
+}
+
+impl TimeStep {
+    /// Nominal length in seconds.
+    fn seconds(self) -> f64 {
+        match self {
+            TimeStep::Seconds(step) => step,
+            TimeStep::Months(months) => months as f64 * SECONDS_PER_MONTH,
+        }
+    }
+
+    /// The last step boundary at or before `t`.
+    fn floor(self, t: f64) -> f64 {
+        match self {
+            TimeStep::Seconds(step) => (t / step + STEP_EPSILON).floor() * step,
+            TimeStep::Months(months) => month_start(month_index(t).div_euclid(months) * months),
+        }
+    }
+
+    /// The first step boundary at or after `t`.
+    fn ceil(self, t: f64) -> f64 {
+        match self {
+            TimeStep::Seconds(step) => (t / step - STEP_EPSILON).ceil() * step,
+            TimeStep::Months(months) => {
+                let floor = self.floor(t);
+                if floor >= t - STEP_EPSILON {
+                    floor
+                } else {
+                    month_start((month_index(t).div_euclid(months) + 1) * months)
+                }
+            }
+        }
+    }
+
+    /// Step boundaries inside `min..=max`.
+    fn ticks(self, min: f64, max: f64) -> Vec<Tick> {
+        let months = match self {
+            TimeStep::Seconds(step) => {
+                return stepped_ticks(min, max, step, |v| format_time(v, step));
+            }
+            TimeStep::Months(months) => months,
+        };
+        if !(min.is_finite() && max.is_finite()) || (max - min) / self.seconds() > MAX_TICKS {
+            return Vec::new();
+        }
+        let first = month_index(self.ceil(min));
+        std::iter::successors(Some(first), |index| Some(index + months))
+            .map(month_start)
+            .take_while(|&value| value <= max + STEP_EPSILON)
+            .map(|value| Tick {
+                value,
+                label: format_time(value, self.seconds()),
+            })
+            .collect()
+    }
+}
+
+/// The smallest calendar-friendly step of at least `raw` seconds.
+fn time_step(raw: f64) -> TimeStep {
+    if let Some(step) = TIME_STEPS.into_iter().find(|&step| step >= raw) {
+        return TimeStep::Seconds(step);
+    }
+    if let Some(months) = MONTH_STEPS
+        .into_iter()
+        .find(|&months| months as f64 * SECONDS_PER_MONTH >= raw)
+    {
+        return TimeStep::Months(months);
+    }
+    // 2.5 years would alternate between January and July.
+    let years = nice_step(raw / SECONDS_PER_YEAR).max(1.0);
+    let years = if years.fract() == 0.0 {
+        years
+    } else {
+        years * 2.0
+    };
+    TimeStep::Months(years as i64 * 12)
+}
+
+/// Months since January of year 0 for the month containing `t`.
+fn month_index(t: f64) -> i64 {
+    let days = (t / SECONDS_PER_DAY).floor() as i64;
+    let (year, month, _) = civil_from_days(days);
+    year * 12 + month - 1
+}
+
+/// Epoch seconds at midnight UTC on the first of month `index` (see
+/// [`month_index`]).
+fn month_start(index: i64) -> f64 {
+    let days = days_from_civil(index.div_euclid(12), index.rem_euclid(12) + 1, 1);
+    days as f64 * SECONDS_PER_DAY
+}
+
+/// Multiples of `step` inside `min..=max`, labelled by `label`.
+fn stepped_ticks(min: f64, max: f64, step: f64, label: impl Fn(f64) -> String) -> Vec<Tick> {
+    let first = (min / step - STEP_EPSILON).ceil();
+    let last = (max / step + STEP_EPSILON).floor();
+    if !(first.is_finite() && last.is_finite()) || last - first > MAX_TICKS {
+        return Vec::new();
+    }
+    (first as i64..=last as i64)
+        .map(|k| {
+            let value = k as f64 * step;
+            Tick {
+                value,
+                label: label(value),
+            }
+        })
+        .collect()
+}
+
+fn linear_ticks(min: f64, max: f64, count: usize) -> Vec<Tick> {
+    match count {
+        0 => Vec::new(),
+        1 => vec![Tick {
+            value: min,
+            label: format_tick(min, nice_step(max - min)),
+        }],
+        n => {
+            let step = nice_step((max - min) / intervals(n));
+            let ticks = stepped_ticks(min, max, step, |v| format_tick(v, step));
+            if ticks.len() >= 2 {
+                return ticks;
+            }
+            let fine = nice_step((max - min) / 100.0);
+            endpoint_ticks(min, max, |v| format_tick(v, fine))
+        }
+    }
+}
+
+/// Ticks on whole powers of `base`, falling back to linear ticks when the
+/// range spans fewer than two of them.
+fn log_ticks(min: f64, max: f64, count: usize, base: f64) -> Vec<Tick> {
+    if count == 0 {
+        return Vec::new();
+    }
+    let low = (min.log(base) - STEP_EPSILON).ceil();
+    let high = (max.log(base) + STEP_EPSILON).floor();
+    if !(low.is_finite() && high.is_finite()) || high - low > MAX_TICKS {
+        return Vec::new();
+    }
+    let (low, high) = (low as i32, high as i32);
+    if high <= low {
+        return linear_ticks(min, max, count);
+    }
+    let powers = (high - low + 1) as usize;
+    (low..=high)
+        .step_by(powers.div_ceil(count))
+        .map(|exponent| {
+            let value = base.powi(exponent);
+            let label = if base == 10.0 && !(-3..=4).contains(&exponent) {
+                format!("1e{exponent}")
+            } else {
+                format_tick(value, value.min(1.0))
+            };
+            Tick { value, label }
+        })
+        .collect()
+}
+
+fn time_ticks(min: f64, max: f64, count: usize) -> Vec<Tick> {
+    if count == 0 {
+        return Vec::new();
+    }
+    let ticks = time_step((max - min) / intervals(count)).ticks(min, max);
+    if ticks.len() >= 2 || count < 2 {
+        return ticks;
+    }
+    let fine = time_step((max - min) / 100.0).seconds();
+    endpoint_ticks(min, max, |v| format_time(v, fine))
+}
+
+/// Ticks at both ends of the range, for when no two round values fit.
+fn endpoint_ticks(min: f64, max: f64, label: impl Fn(f64) -> String) -> Vec<Tick> {
+    [min, max]
+        .into_iter()
+        .map(|value| Tick {
+            value,
+            label: label(value),
+        })
+        .collect()
+}
+
+/// Format a tick value with the decimals needed to show `step` exactly
+/// (at most six).
+pub fn format_tick(value: f64, step: f64) -> String {
+    let decimals = if step.is_finite() && step > 0.0 {
+        (0..6)
+            .find(|&d| {
+                let scaled = step * 10f64.powi(d);
+                (scaled - scaled.round()).abs() < 1e-6 * scaled.max(1.0)
+            })
+            .unwrap_or(6) as usize
+    } else {
+        0
+    };
+    let text = format!("{value:.decimals$}");
+    // Avoid "-0" for values that round to zero.
+    if text.starts_with('-') && text[1..].chars().all(|c| c == '0' || c == '.') {
+        text[1..].to_string()
+    } else {
+        text
+    }
+}
+
+/// Format epoch seconds in UTC at a precision suited to `step`: seconds
+/// below a minute, hours and minutes below a day (midnight shows the
+/// date), month and day below four weeks, year and month below a year, and
+/// the year beyond.
+pub fn format_time(seconds: f64, step: f64) -> String {
+    let total = seconds.round() as i64;
+    let days = total.div_euclid(SECONDS_PER_DAY as i64);
+    let rem = total.rem_euclid(SECONDS_PER_DAY as i64);
+    let (year, month, day) = civil_from_days(days);
+    if step >= SECONDS_PER_YEAR {
+        format!("{year}")
+    } else if step >= 28.0 * SECONDS_PER_DAY {
+        format!("{year}-{month:02}")
+    } else if step >= SECONDS_PER_DAY || rem == 0 {
+        format!("{month:02}-{day:02}")
+    } else if step >= 60.0 {
+        format!("{:02}:{:02}", rem / 3600, rem % 3600 / 60)
+    } else {
+        format!("{:02}:{:02}:{:02}", rem / 3600, rem % 3600 / 60, rem % 60)
+    }
+}
+
+/// Gregorian (year, month, day) for days since 1970-01-01 (Howard
+/// Hinnant's algorithm).
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097); // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365; // [0, 399]
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11]
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Days since 1970-01-01 for a Gregorian date; the inverse of
+/// [`civil_from_days`].
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400); // [0, 399]
+    let mp = (month + 9) % 12; // March = 0
+    let doy = (153 * mp + 2) / 5 + day - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+    era * 146_097 + doe - 719_468
+}
+
+/// Whether labels placed at `ticks` leave room between neighbours.
+fn labels_fit(ticks: &[PlacedTick], horizontal: bool) -> bool {
+    if horizontal {
+        x_label_spans(ticks).windows(2).all(|w| w[1].0 > w[0].1)
+    } else {
+        ticks.windows(2).all(|w| w[1].offset > w[0].offset)
+    }
+}
+
+/// Start and (exclusive) end column of each x label relative to the axis
+/// start: the first label starts at its tick, the last ends on its tick,
+/// and the rest are centered.
+fn x_label_spans(ticks: &[PlacedTick]) -> Vec<(i32, i32)> {
+    let n = ticks.len();
+    ticks
+        .iter()
+        .enumerate()
+        .map(|(i, tick)| {
+            let width = display_width(&tick.label) as i32;
+            let offset = i32::from(tick.offset);
+            let start = if i == 0 {
+                offset
+            } else if i + 1 == n {
+                offset + 1 - width
+            } else {
+                offset - width / 2
+            };
+            (start, start + width)
+        })
+        .collect()
+}
+
+/// Draw x tick labels on row `y` for an axis starting at column `axis_x`,
+/// clamped to `min_x..max_x` and skipping labels that would overlap.
+pub(super) fn draw_x_ticks(
+    frame: &mut Frame,
+    ticks: &[PlacedTick],
+    axis_x: u16,
+    y: u16,
+    (min_x, max_x): (u16, u16),
+    style: Style,
+) {
+    let mut next_free = min_x;
+    for (tick, (start, end)) in ticks.iter().zip(x_label_spans(ticks)) {
+        let width = (end - start) as u16;
+        let x = (i32::from(axis_x) + start)
+            .min(i32::from(max_x.saturating_sub(width)))
+            .max(i32::from(min_x)) as u16;
+        if x < next_free {
+            continue;
+        }
+        next_free = draw_text(frame, x, y, &tick.label, style, max_x) + 1;
+    }
+}
+
+/// Draw y tick labels right-aligned against column `right` (exclusive), for
+/// an axis whose minimum is on row `bottom - 1`.
+pub(super) fn draw_y_ticks(
+    frame: &mut Frame,
+    ticks: &[PlacedTick],
+    (left, right): (u16, u16),
+    bottom: u16,
+    style: Style,
+) {
+    for tick in ticks {
+        let y = bottom.saturating_sub(1).saturating_sub(tick.offset);
+        let width = (display_width(&tick.label) as u16).min(right.saturating_sub(left));
+        draw_text(frame, right - width, y, &tick.label, style, right);
+    }
+}
+
+/// Width of a y tick gutter: the widest label plus one column for the axis
+/// line (just the line when there are no labels).
+pub(super) fn y_gutter_width(ticks: &[PlacedTick]) -> u16 {
+    ticks
+        .iter()
+        .map(|t| display_width(&t.label))
+        .max()
+        .map_or(1, |w| w as u16 + 1)
+}
+
+// ===== AxisLayout =====
+
+/// Plot rectangle and axis gutters computed for a chart area.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AxisLayout {
+    /// The full chart area.
+    pub area: Rect,
+    /// The data area inside the axes.
+    pub plot: Rect,
+    x_ticks: Vec<PlacedTick>,
+    y_ticks: Vec<PlacedTick>,
+    x_title: Option<String>,
+    y_title: Option<String>,
+}
+
+impl AxisLayout {
+    /// Lay out `area` for the given axes and resolved ranges.
+    pub fn new(area: Rect, x: &Axis, x_range: (f64, f64), y: &Axis, y_range: (f64, f64)) -> Self {
+        let top = u16::from(y.title.is_some());
+        let x_axis_height = 1 + u16::from(x.has_tick_labels()) + u16::from(x.title.is_some());
+        let plot_height = area.height.saturating_sub(top + x_axis_height);
+        let y_ticks = y.place_ticks(y_range, plot_height, false);
+        let y_axis_width = y_gutter_width(&y_ticks);
+        let plot = Rect::new(
+            area.x.saturating_add(y_axis_width),
+            area.y.saturating_add(top),
+            area.width.saturating_sub(y_axis_width),
+            plot_height,
+        );
+        let x_ticks = x.place_ticks(x_range, plot.width, true);
+        Self {
+            area,
+            plot,
+            x_ticks,
+            y_ticks,
+            x_title: x.title.clone(),
+            y_title: y.title.clone(),
+        }
+    }
+
+    /// Lay out `area` with fixed category labels (each ordered min to max).
+    pub fn with_labels(
+        area: Rect,
+        x_labels: Vec<String>,
+        x_title: Option<String>,
+        y_labels: Vec<String>,
+        y_title: Option<String>,
+    ) -> Self {
+        let axis = |labels, title| Axis {
+            labels: Some(labels),
+            title,
+            ..Axis::new()
+        };
+        Self::new(
+            area,
+            &axis(x_labels, x_title),
+            (0.0, 1.0),
+            &axis(y_labels, y_title),
+            (0.0, 1.0),
+        )
+    }
+
+    /// Whether the plot area is large enough to draw into.
+    pub fn is_drawable(&self) -> bool {
+        self.plot.width >= 2 && self.plot.height >= 2
+    }
+
+    /// Draw axis lines, tick labels, and titles.
+    pub fn render(&self, frame: &mut Frame, style: Style) {
+        let area = self.area;
+        let plot = self.plot;
+        let axis_x = plot.x.saturating_sub(1);
+        let axis_y = plot.bottom();
+        let put = |frame: &mut Frame, x: u16, y: u16, ch: char| {
+            let mut cell = Cell::from_char(ch);
+            style_cell(&mut cell, style);
+            frame.buffer.set_fast(x, y, cell);
+        };
+
+        if axis_x >= area.x {
+            for y in plot.y..plot.bottom() {
+                put(frame, axis_x, y, '│');
+            }
+        }
+        if axis_y < area.bottom() {
+            for x in plot.x..plot.right() {
+                put(frame, x, axis_y, '─');
+            }
+            if axis_x >= area.x {
+                put(frame, axis_x, axis_y, '└');
+            }
+        }
+
+        draw_y_ticks(frame, &self.y_ticks, (area.x, axis_x), plot.bottom(), style);
+
+        let mut text_y = axis_y.saturating_add(1);
+        if !self.x_ticks.is_empty() && text_y < area.bottom() {
+            draw_x_ticks(
+                frame,
+                &self.x_ticks,
+                plot.x,
+                text_y,
+                (area.x, area.right()),
+                style,
+            );
+            text_y = text_y.saturating_add(1);
+        }
+
+        if let Some(title) = &self.x_title
+            && text_y < area.bottom()
+        {
+            let width = display_width(title) as u16;
+            let x = plot.x + plot.width.saturating_sub(width) / 2;
+            draw_text(frame, x, text_y, title, style, area.right());
+        }
+        if let Some(title) = &self.y_title {
+            draw_text(frame, area.x, area.y, title, style, area.right());
+        }
+    }
+}
+
+/// Offset of item `i` of `n` spread evenly over `extent` cells.
+fn spread(i: usize, n: usize, extent: u16) -> u16 {
+    if n <= 1 {
+        return 0;
+    }
+    (i as u32 * u32::from(extent.saturating_sub(1)) / (n as u32 - 1)) as u16
+}
+
+/// Minimum and maximum of the finite values (infinite bounds when none).
+pub(super) fn data_extent(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    values
+        .filter(|v| v.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+            (lo.min(v), hi.max(v))
+        })
+}
+
+/// Maps data coordinates onto a [`Painter`](crate::canvas::Painter)'s
+/// sub-pixel grid.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct PixelMap {
+    x_scale: Scale,
+    y_scale: Scale,
+    x_range: (f64, f64),
+    y_range: (f64, f64),
+    width: f64,
+    height: f64,
+}
+
+impl PixelMap {
+    /// A map for axes showing `x_range` and `y_range` on a painter of
+    /// `size` sub-pixels.
+    pub fn new(
+        x: &Axis,
+        x_range: (f64, f64),
+        y: &Axis,
+        y_range: (f64, f64),
+        size: (u16, u16),
+    ) -> Self {
+        Self {
+            x_scale: x.scale,
+            y_scale: y.scale,
+            x_range,
+            y_range,
+            width: f64::from(size.0),
+            height: f64::from(size.1),
+        }
+    }
+
+    /// Fractional pixel column for data `x` (NaN when not representable).
+    pub fn x(&self, x: f64) -> f64 {
+        self.x_scale.normalize(x, self.x_range) * (self.width - 1.0)
+    }
+
+    /// Fractional pixel row for data `y` (row 0 is the maximum; NaN when
+    /// not representable).
+    pub fn y(&self, y: f64) -> f64 {
+        (1.0 - self.y_scale.normalize(y, self.y_range)) * (self.height - 1.0)
+    }
+
+    /// Rounded pixel position for a data point, if both coordinates can be
+    /// shown.
+    pub fn point(&self, x: f64, y: f64) -> Option<(i32, i32)> {
+        let (px, py) = (self.x(x), self.y(y));
+        (px.is_finite() && py.is_finite()).then(|| (px.round() as i32, py.round() as i32))
+    }
+}
+
+// ===== Legend =====
+
+/// Where a [`Legend`] is drawn relative to the plot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LegendPosition {
+    /// Overlaid in the plot's top-right corner.
+    #[default]
+    TopRight,
+    /// Overlaid in the plot's top-left corner.
+    TopLeft,
+    /// Overlaid in the plot's bottom-right corner.
+    BottomRight,
+    /// Overlaid in the plot's bottom-left corner.
+    BottomLeft,
+    /// In a column to the right of the chart, vertically centered.
+    Right,
+}
+
+/// One legend row: a colored symbol followed by a label.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LegendEntry {
+    pub label: String,
+    pub color: PackedRgba,
+    pub symbol: char,
+}
+
+impl LegendEntry {
+    /// An entry drawn with the `■` symbol.
+    pub fn new(label: impl Into<String>, color: PackedRgba) -> Self {
+        Self {
+            label: label.into(),
+            color,
+            symbol: '■',
+        }
+    }
+
+    /// Set the symbol drawn before the label.
+    #[must_use]
+    pub fn symbol(mut self, symbol: char) -> Self {
+        self.symbol = symbol;
+        self
+    }
+}
+
+/// A list of colored entries identifying chart series.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Legend {
+    entries: Vec<LegendEntry>,
+    position: LegendPosition,
+    style: Style,
+}
+
+impl Legend {
+    pub fn new(entries: Vec<LegendEntry>) -> Self {
+        Self {
+            entries,
+            position: LegendPosition::default(),
+            style: Style::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn position(mut self, position: LegendPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Width in cells: symbol, space, and the longest label.
+    pub fn width(&self) -> u16 {
+        let label = self
+            .entries
+            .iter()
+            .map(|e| display_width(&e.label))
+            .max()
+            .unwrap_or(0);
+        (label as u16).saturating_add(2)
+    }
+
+    /// Height in cells (one row per entry).
+    pub fn height(&self) -> u16 {
+        self.entries.len().min(u16::MAX as usize) as u16
+    }
+
+    /// The part of `area` left for the chart. Only [`LegendPosition::Right`]
+    /// takes space away; the corner positions overlay the plot.
+    pub fn reserve(&self, area: Rect) -> Rect {
+        if self.position != LegendPosition::Right || self.entries.is_empty() {
+            return area;
+        }
+        let taken = self.width().saturating_add(1).min(area.width / 2);
+        Rect::new(area.x, area.y, area.width - taken, area.height)
+    }
+
+    /// Where the legend is drawn for a chart occupying `area` with the given
+    /// `plot` rectangle.
+    pub fn placement(&self, area: Rect, plot: Rect) -> Rect {
+        let width = self.width();
+        let height = self.height();
+        let (x, y) = match self.position {
+            LegendPosition::TopRight => (plot.right().saturating_sub(width), plot.y),
+            LegendPosition::TopLeft => (plot.x, plot.y),
+            LegendPosition::BottomRight => (
+                plot.right().saturating_sub(width),
+                plot.bottom().saturating_sub(height),
+            ),
+            LegendPosition::BottomLeft => (plot.x, plot.bottom().saturating_sub(height)),
+            LegendPosition::Right => (
+                area.right().saturating_sub(width),
+                area.y + area.height.saturating_sub(height) / 2,
+            ),
+        };
+        Rect::new(x, y, width, height).intersection(&area)
+    }
+}
+
+impl Widget for Legend {
+    /// Render the legend rows into `area` (see [`Legend::placement`]).
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        for (entry, y) in self.entries.iter().zip(area.y..area.bottom()) {
+            let mut marker = Cell::from_char(entry.symbol);
+            style_cell(&mut marker, self.style);
+            marker.fg = entry.color;
+            frame.buffer.set_fast(area.x, y, marker);
+            draw_text(
+                frame,
+                area.x.saturating_add(2),
+                y,
+                &entry.label,
+                self.style,
+                area.right(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn row(frame: &Frame, y: u16) -> String {
+        (0..frame.buffer.width())
+            .map(|x| {
+                frame
+                    .buffer
+                    .get(x, y)
+                    .and_then(|c| c.content.as_char())
+                    .unwrap_or(' ')
+            })
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    #[test]
+    fn format_tick_adapts_decimals() {
+        assert_eq!(format_tick(10.0, 5.0), "10");
+        assert_eq!(format_tick(7.5, 2.5), "7.5");
+        assert_eq!(format_tick(0.26, 0.25), "0.26");
+        assert_eq!(format_tick(0.126, 0.05), "0.13");
+        assert_eq!(format_tick(-0.0001, 0.5), "0.0");
+    }
+
+    fn labels(ticks: &[Tick]) -> Vec<&str> {
+        ticks.iter().map(|t| t.label.as_str()).collect()
+    }
+
+    #[test]
+    fn linear_ticks_use_nice_steps() {
+        let axis = Axis::new();
+        // No two round values fit, so the bounds are labelled instead.
+        assert_eq!(
+            labels(&axis.generate_ticks((0.3, 9.7), 2)),
+            vec!["0.3", "9.7"]
+        );
+        assert_eq!(
+            labels(&axis.generate_ticks((0.0, 100.0), 5)),
+            vec!["0", "25", "50", "75", "100"]
+        );
+        assert_eq!(
+            labels(&axis.generate_ticks((0.3, 9.7), 5)),
+            vec!["2.5", "5.0", "7.5"]
+        );
+        assert_eq!(
+            labels(&axis.generate_ticks((0.0, 0.3), 4)),
+            vec!["0.0", "0.1", "0.2", "0.3"]
+        );
+    }
+
+    #[test]
+    fn log_ticks_land_on_powers() {
+        let axis = Axis::new().scale(Scale::Log(10.0));
+        assert_eq!(
+            labels(&axis.generate_ticks((1.0, 1e6), 4)),
+            vec!["1", "100", "10000", "1e6"]
+        );
+        assert_eq!(
+            labels(&axis.generate_ticks((0.001, 0.1), 5)),
+            vec!["0.001", "0.01", "0.1"]
+        );
+        // Less than a decade falls back to linear ticks.
+        assert_eq!(
+            labels(&axis.generate_ticks((2.0, 8.0), 4)),
+            vec!["2", "4", "6", "8"]
+        );
+        assert!((Scale::Log(10.0).normalize(10.0, (1.0, 100.0)) - 0.5).abs() < 1e-12);
+        assert!(Scale::Log(10.0).normalize(0.0, (1.0, 100.0)).is_nan());
+    }
+
+    #[test]
+    fn time_labels_adapt_to_step() {
+        assert_eq!(format_time(45.0, 15.0), "00:00:45");
+        assert_eq!(format_time(13.0 * 3600.0 + 300.0, 300.0), "13:05");
+        assert_eq!(format_time(0.0, 3600.0), "01-01");
+        // 2024-02-29 is day 19782 after the epoch.
+        let leap_day = 19_782.0 * SECONDS_PER_DAY;
+        assert_eq!(format_time(leap_day, SECONDS_PER_DAY), "02-29");
+        assert_eq!(format_time(leap_day, SECONDS_PER_YEAR), "2024");
+
+        let axis = Axis::new().scale(Scale::Time);
+        assert_eq!(
+            labels(&axis.generate_ticks((0.0, 3600.0), 5)),
+            vec!["01-01", "00:15", "00:30", "00:45", "01:00"]
+        );
+    }
+
+    #[test]
+    fn month_ranges_tick_on_calendar_boundaries() {
+        let axis = Axis::new().scale(Scale::Time);
+        // 2023-01-15 09:30 UTC.
+        let start = days_from_civil(2023, 1, 15) as f64 * SECONDS_PER_DAY + 34_200.0;
+        for months in 1..=12 {
+            let end = start + f64::from(months) * 30.0 * SECONDS_PER_DAY;
+            let ticks = axis.generate_ticks((start, end), 6);
+            assert!(ticks.len() >= 2, "{months} months: {ticks:?}");
+            assert!(ticks.iter().all(|t| t.value >= start && t.value <= end));
+            // Every tick is at midnight.
+            assert!(ticks.iter().all(|t| t.value % SECONDS_PER_DAY == 0.0));
+            if months >= 3 {
+                // Ticks fall on the first of the month, a fixed number of
+                // months apart.
+                let indices: Vec<i64> = ticks.iter().map(|t| month_index(t.value)).collect();
+                assert!(
+                    ticks
+                        .iter()
+                        .all(|t| month_start(month_index(t.value)) == t.value)
+                );
+                let gap = indices[1] - indices[0];
+                assert!(MONTH_STEPS.contains(&gap), "{months} months: gap {gap}");
+                assert!(indices.windows(2).all(|w| w[1] - w[0] == gap));
+                assert_eq!(indices[0] % gap, 0, "{months} months: unaligned");
+            } else {
+                let gap = ticks[1].value - ticks[0].value;
+                assert!(ticks.windows(2).all(|w| w[1].value - w[0].value == gap));
+            }
+        }
+        assert_eq!(
+            labels(&axis.generate_ticks((start, start + 365.0 * SECONDS_PER_DAY), 6)),
+            vec!["2023-04", "2023-07", "2023-10", "2024-01"]
+        );
+        // Multi-year spans land on January 1st.
+        let decade = axis.generate_ticks((start, start + 3650.0 * SECONDS_PER_DAY), 6);
+        assert_eq!(
+            labels(&decade),
+            vec!["2024", "2026", "2028", "2030", "2032"]
+        );
+        assert_eq!(
+            axis.clone()
+                .nice(true)
+                .fit([start, start + 200.0 * SECONDS_PER_DAY]),
+            (month_start(2023 * 12), month_start(2023 * 12 + 9))
+        );
+    }
+
+    #[test]
+    fn fit_pads_and_rounds_auto_bounds() {
+        let values = [0.0, 10.0, f64::NAN];
+        assert_eq!(Axis::new().padding(0.1).fit(values), (-1.0, 11.0));
+        assert_eq!(
+            Axis::new().padding(0.1).nice(true).fit(values),
+            (-5.0, 15.0)
+        );
+        // Explicit bounds are never padded.
+        assert_eq!(
+            Axis::new().bounds(0.0, 1.0).padding(0.5).fit(values),
+            (0.0, 1.0)
+        );
+        let log = Axis::new().scale(Scale::Log(10.0));
+        assert_eq!(log.fit([-1.0, 0.0, 5.0, 500.0]), (5.0, 500.0));
+        assert_eq!(log.clone().nice(true).fit([5.0, 500.0]), (1.0, 1000.0));
+        assert_eq!(log.fit([-3.0]), (1.0, 10.0));
+    }
+
+    #[test]
+    fn place_ticks_thins_colliding_labels() {
+        let axis = Axis::new();
+        let placed = axis.place_ticks((0.0, 1000.0), 10, true);
+        let text: Vec<&str> = placed.iter().map(|t| t.label.as_str()).collect();
+        assert_eq!(text, vec!["0", "1000"]);
+        assert_eq!(placed[1].offset, 9);
+
+        let rows = axis.place_ticks((0.0, 100.0), 3, false);
+        let offsets: Vec<u16> = rows.iter().map(|t| t.offset).collect();
+        assert_eq!(offsets, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn pixel_map_follows_axis_scales() {
+        let x = Axis::new();
+        let y = Axis::new().scale(Scale::Log(10.0));
+        let map = PixelMap::new(&x, (0.0, 1.0), &y, (1.0, 100.0), (11, 11));
+        assert_eq!(map.point(0.5, 10.0), Some((5, 5)));
+        assert_eq!(map.point(1.0, 100.0), Some((10, 0)));
+        assert_eq!(map.point(0.5, 0.0), None);
+    }
+
+    #[test]
+    fn resolve_handles_degenerate_ranges() {
+        let axis = Axis::new();
+        assert_eq!(axis.resolve(3.0, 3.0), (2.0, 4.0));
+        assert_eq!(axis.resolve(f64::INFINITY, f64::NEG_INFINITY), (0.0, 1.0));
+        assert_eq!(Axis::new().bounds(0.0, 10.0).resolve(2.0, 3.0), (0.0, 10.0));
+        assert_eq!(
+            labels(&Axis::new().generate_ticks((0.0, 10.0), 3)),
+            vec!["0", "5", "10"]
+        );
+    }
+
+    #[test]
+    fn layout_reserves_label_gutters() {
+        let x = Axis::new().ticks(3).title("time");
+        let y = Axis::new().labels(["lo", "high"]);
+        let layout = AxisLayout::new(Rect::new(0, 0, 20, 10), &x, (0.0, 10.0), &y, (0.0, 1.0));
+        assert_eq!(layout.plot, Rect::new(5, 0, 15, 7));
+
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(20, 10, &mut pool);
+        layout.render(&mut frame, Style::new());
+        assert_eq!(row(&frame, 0), "high│");
+        assert_eq!(row(&frame, 6), "  lo│");
+        assert_eq!(row(&frame, 7), "    └───────────────");
+        assert_eq!(row(&frame, 8), "     0      5     10");
+        assert_eq!(row(&frame, 9), "          time");
+    }
+
+    #[test]
+    fn legend_right_reserves_column() {
+        let legend = Legend::new(vec![
+            LegendEntry::new("cpu", PackedRgba::RED),
+            LegendEntry::new("memory", PackedRgba::GREEN),
+        ])
+        .position(LegendPosition::Right);
+        let area = Rect::new(0, 0, 30, 6);
+        let chart = legend.reserve(area);
+        assert_eq!(chart, Rect::new(0, 0, 21, 6));
+        let placed = legend.placement(area, chart);
+        assert_eq!(placed, Rect::new(22, 2, 8, 2));
+
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(30, 6, &mut pool);
+        legend.render(placed, &mut frame);
+        assert_eq!(row(&frame, 2), "                      ■ cpu");
+        assert_eq!(row(&frame, 3), "                      ■ memory");
+    }
+}
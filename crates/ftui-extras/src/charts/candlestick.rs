@@ -0,0 +1,199 @@
+//! OHLC candlestick chart.
+
+use ftui_core::geometry::Rect;
+use ftui_render::cell::PackedRgba;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+use ftui_widgets::Widget;
+
+use super::axis::{Axis, AxisLayout, PixelMap};
+use crate::canvas::{CanvasRef, Mode, Painter};
+
+/// One open/high/low/close sample.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Candle {
+    pub time: f64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
+impl Candle {
+    pub fn new(time: f64, open: f64, high: f64, low: f64, close: f64) -> Self {
+        Self {
+            time,
+            open,
+            high,
+            low,
+            close,
+        }
+    }
+
+    /// Whether the candle closed at or above its open.
+    pub fn is_bullish(&self) -> bool {
+        self.close >= self.open
+    }
+}
+
+/// Candlestick chart: one evenly spaced slot per candle, with a body from
+/// open to close and a wick from low to high.
+#[derive(Debug, Clone)]
+pub struct CandlestickChart<'a> {
+    candles: &'a [Candle],
+    up_color: PackedRgba,
+    down_color: PackedRgba,
+    x_axis: Axis,
+    y_axis: Axis,
+    style: Style,
+}
+
+impl<'a> CandlestickChart<'a> {
+    pub fn new(candles: &'a [Candle]) -> Self {
+        Self {
+            candles,
+            up_color: PackedRgba::rgb(80, 200, 120),
+            down_color: PackedRgba::rgb(230, 80, 80),
+            x_axis: Axis::new(),
+            y_axis: Axis::new(),
+            style: Style::new(),
+        }
+    }
+
+    /// Set the colors for rising and falling candles.
+    #[must_use]
+    pub fn colors(mut self, up: PackedRgba, down: PackedRgba) -> Self {
+        self.up_color = up;
+        self.down_color = down;
+        self
+    }
+
+    /// Set the time axis. Auto ticks span the first to the last candle.
+    #[must_use]
+    pub fn x_axis(mut self, axis: Axis) -> Self {
+        self.x_axis = axis;
+        self
+    }
+
+    #[must_use]
+    pub fn y_axis(mut self, axis: Axis) -> Self {
+        self.y_axis = axis;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl Widget for CandlestickChart<'_> {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        if area.is_empty() || self.candles.is_empty() {
+            return;
+        }
+
+        let first = self.candles[0].time;
+        let last = self.candles[self.candles.len() - 1].time;
+        let x_range = self.x_axis.resolve(first, last);
+        let y_range = self
+            .y_axis
+            .fit(self.candles.iter().flat_map(|c| [c.low, c.high]));
+        let layout = AxisLayout::new(area, &self.x_axis, x_range, &self.y_axis, y_range);
+        if !layout.is_drawable() {
+            return;
+        }
+        let plot = layout.plot;
+
+        let mut painter = Painter::for_area(plot, Mode::Block);
+        let (px_w, _) = painter.size();
+        let map = PixelMap::new(&self.x_axis, x_range, &self.y_axis, y_range, painter.size());
+        let slot = f64::from(px_w) / self.candles.len() as f64;
+        let body_width = (slot * 0.6).round().max(1.0) as i32;
+
+        for (i, candle) in self.candles.iter().enumerate() {
+            let values = [candle.open, candle.high, candle.low, candle.close];
+            if values.iter().any(|&v| !map.y(v).is_finite()) {
+                continue;
+            }
+            let color = if candle.is_bullish() {
+                self.up_color
+            } else {
+                self.down_color
+            };
+            let center = ((i as f64 + 0.5) * slot) as i32;
+            let row = |v: f64| map.y(v).round() as i32;
+
+            painter.line_colored(
+                center,
+                row(candle.high),
+                center,
+                row(candle.low),
+                Some(color),
+            );
+            let top = row(candle.open.max(candle.close));
+            let bottom = row(candle.open.min(candle.close));
+            painter.rect_filled_colored(
+                center - body_width / 2,
+                top,
+                body_width,
+                bottom - top + 1,
+                color,
+            );
+        }
+
+        CanvasRef::from_painter(&painter)
+            .style(self.style)
+            .render(plot, frame);
+        layout.render(frame, self.style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    #[test]
+    fn candles_are_colored_by_direction() {
+        let candles = [
+            Candle::new(0.0, 10.0, 20.0, 0.0, 15.0),
+            Candle::new(1.0, 15.0, 20.0, 0.0, 5.0),
+        ];
+        assert!(candles[0].is_bullish());
+        assert!(!candles[1].is_bullish());
+
+        let up = PackedRgba::GREEN;
+        let down = PackedRgba::RED;
+        let chart = CandlestickChart::new(&candles)
+            .colors(up, down)
+            .x_axis(Axis::new().ticks(0))
+            .y_axis(Axis::new().ticks(0));
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(9, 11, &mut pool);
+        chart.render(Rect::new(0, 0, 9, 11), &mut frame);
+
+        // Plot is 8×10 cells (16×20 px); slots are 8 px wide, centered at
+        // px 4 and 12, i.e. cell columns 3 and 7 after the axis.
+        let fg_at = |x: u16, y: u16| frame.buffer.get(x, y).unwrap().fg;
+        assert_eq!(fg_at(3, 0), up);
+        assert_eq!(fg_at(7, 0), down);
+        // The up body spans 10..15 (rows 5..7.5), the down body 5..15.
+        let ch = |x: u16, y: u16| frame.buffer.get(x, y).unwrap().content.as_char();
+        assert_eq!(ch(3, 0), Some('▌'));
+        assert_eq!(ch(3, 4), Some('█'));
+        assert_eq!(ch(7, 6), Some('█'));
+    }
+
+    #[test]
+    fn y_range_covers_wicks() {
+        let candles = [Candle::new(0.0, 5.0, 9.0, 1.0, 6.0)];
+        let chart = CandlestickChart::new(&candles).y_axis(Axis::new().ticks(2));
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(10, 6, &mut pool);
+        chart.render(Rect::new(0, 0, 10, 6), &mut frame);
+        assert_eq!(frame.buffer.get(0, 0).unwrap().content.as_char(), Some('9'));
+        assert_eq!(frame.buffer.get(0, 3).unwrap().content.as_char(), Some('1'));
+    }
+}
@@ -0,0 +1,242 @@
+//! 2D heatmap with configurable color scales.
+
+use ftui_core::geometry::Rect;
+use ftui_render::cell::PackedRgba;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+use ftui_widgets::Widget;
+
+use super::axis::{AxisLayout, data_extent};
+use super::{heatmap_gradient, lerp_color};
+use crate::canvas::{CanvasRef, Mode, Painter};
+
+/// Maps normalized values (0.0 to 1.0) to colors.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum ColorScale {
+    /// The built-in cold-to-hot [`heatmap_gradient`].
+    #[default]
+    Heat,
+    /// Evenly spaced color stops, interpolated linearly.
+    Gradient(Vec<PackedRgba>),
+}
+
+impl ColorScale {
+    /// Color for a normalized value; out-of-range values are clamped.
+    pub fn sample(&self, t: f64) -> PackedRgba {
+        match self {
+            ColorScale::Heat => heatmap_gradient(t),
+            ColorScale::Gradient(stops) => match stops.as_slice() {
+                [] => PackedRgba::WHITE,
+                [only] => *only,
+                stops => {
+                    let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+                    let pos = t * (stops.len() - 1) as f64;
+                    let idx = (pos.floor() as usize).min(stops.len() - 2);
+                    lerp_color(stops[idx], stops[idx + 1], pos - idx as f64)
+                }
+            },
+        }
+    }
+
+    /// A scale running from the palette's surface color through its info,
+    /// success, warning, and error accents.
+    #[cfg(feature = "theme")]
+    pub fn from_palette(palette: &crate::theme::ThemePalette) -> Self {
+        ColorScale::Gradient(vec![
+            palette.bg_surface,
+            palette.accent_info,
+            palette.accent_success,
+            palette.accent_warning,
+            palette.accent_error,
+        ])
+    }
+
+    /// [`ColorScale::from_palette`] for the active theme.
+    #[cfg(feature = "theme")]
+    pub fn themed() -> Self {
+        Self::from_palette(crate::theme::current_palette())
+    }
+}
+
+/// Grid of values drawn as colored half-block cells (two rows per cell).
+///
+/// `data` is row-major with `columns` values per row; row 0 is drawn at the
+/// top. Non-finite values are left blank.
+#[derive(Debug, Clone)]
+pub struct Heatmap<'a> {
+    data: &'a [f64],
+    columns: usize,
+    range: Option<(f64, f64)>,
+    scale: ColorScale,
+    row_labels: Vec<String>,
+    column_labels: Vec<String>,
+    style: Style,
+}
+
+impl<'a> Heatmap<'a> {
+    pub fn new(data: &'a [f64], columns: usize) -> Self {
+        Self {
+            data,
+            columns,
+            range: None,
+            scale: ColorScale::default(),
+            row_labels: Vec::new(),
+            column_labels: Vec::new(),
+            style: Style::new(),
+        }
+    }
+
+    /// Set the value range mapped onto the color scale (default: data extent).
+    #[must_use]
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    #[must_use]
+    pub fn scale(mut self, scale: ColorScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Label rows, first row first.
+    #[must_use]
+    pub fn row_labels<I, S>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.row_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Label columns, first column first.
+    #[must_use]
+    pub fn column_labels<I, S>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.column_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    fn rows(&self) -> usize {
+        self.data.len().checked_div(self.columns).unwrap_or(0)
+    }
+}
+
+impl Widget for Heatmap<'_> {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        let rows = self.rows();
+        if area.is_empty() || rows == 0 {
+            return;
+        }
+
+        // Axis labels run from the minimum upward, so rows are reversed.
+        let layout = AxisLayout::with_labels(
+            area,
+            self.column_labels.clone(),
+            None,
+            self.row_labels.iter().rev().cloned().collect(),
+            None,
+        );
+        if !layout.is_drawable() {
+            return;
+        }
+        let plot = layout.plot;
+
+        let (min, max) = self
+            .range
+            .unwrap_or_else(|| data_extent(self.data.iter().copied()));
+        let span = max - min;
+
+        let mut painter = Painter::for_area(plot, Mode::HalfBlock);
+        let (px_w, px_h) = painter.size();
+        let (px_w, px_h) = (usize::from(px_w), usize::from(px_h));
+        for py in 0..px_h {
+            let row = py * rows / px_h;
+            for px in 0..px_w {
+                let column = px * self.columns / px_w;
+                let value = self.data[row * self.columns + column];
+                if !value.is_finite() {
+                    continue;
+                }
+                let t = if span > 0.0 {
+                    (value - min) / span
+                } else {
+                    0.5
+                };
+                painter.point_colored_in_bounds(px, py, self.scale.sample(t));
+            }
+        }
+
+        CanvasRef::from_painter(&painter)
+            .style(self.style)
+            .render(plot, frame);
+        layout.render(frame, self.style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    #[test]
+    fn gradient_scale_interpolates_stops() {
+        let scale = ColorScale::Gradient(vec![
+            PackedRgba::rgb(0, 0, 0),
+            PackedRgba::rgb(200, 0, 0),
+            PackedRgba::rgb(200, 200, 0),
+        ]);
+        assert_eq!(scale.sample(0.0), PackedRgba::rgb(0, 0, 0));
+        assert_eq!(scale.sample(0.25), PackedRgba::rgb(100, 0, 0));
+        assert_eq!(scale.sample(1.0), PackedRgba::rgb(200, 200, 0));
+        assert_eq!(scale.sample(7.0), PackedRgba::rgb(200, 200, 0));
+        assert_eq!(ColorScale::Heat.sample(0.3), heatmap_gradient(0.3));
+    }
+
+    #[test]
+    fn cells_use_half_blocks_per_row_pair() {
+        // 2 rows × 2 columns mapped onto a 2×2 cell plot (4 pixel rows).
+        let data = [0.0, 1.0, 2.0, 3.0];
+        let black = PackedRgba::rgb(0, 0, 0);
+        let white = PackedRgba::rgb(255, 255, 255);
+        let chart = Heatmap::new(&data, 2).scale(ColorScale::Gradient(vec![black, white]));
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(3, 3, &mut pool);
+        chart.render(Rect::new(0, 0, 3, 3), &mut frame);
+
+        let plot = Rect::new(1, 0, 2, 2);
+        let first = frame.buffer.get(plot.x, plot.y).unwrap();
+        assert_eq!(first.content.as_char(), Some('▀'));
+        assert_eq!(first.fg, black);
+        let last = frame.buffer.get(plot.x + 1, plot.y + 1).unwrap();
+        assert_eq!(last.bg, white);
+    }
+
+    #[test]
+    fn labels_reserve_gutters() {
+        let data = [1.0; 6];
+        let chart = Heatmap::new(&data, 3)
+            .row_labels(["mon", "tue"])
+            .column_labels(["a", "b", "c"]);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(12, 6, &mut pool);
+        chart.render(Rect::new(0, 0, 12, 6), &mut frame);
+        let row = |y: u16| -> String {
+            (0..4)
+                .filter_map(|x| frame.buffer.get(x, y).and_then(|c| c.content.as_char()))
+                .collect()
+        };
+        assert_eq!(row(0), "mon│");
+        assert_eq!(row(3), "tue│");
+    }
+}
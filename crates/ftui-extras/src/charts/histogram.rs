@@ -0,0 +1,268 @@
+//! Histogram with automatic binning.
+
+use ftui_core::geometry::Rect;
+use ftui_render::cell::PackedRgba;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+use ftui_widgets::Widget;
+
+use super::axis::{Axis, AxisLayout, data_extent};
+use crate::canvas::{CanvasRef, Mode, Painter};
+
+/// Upper bound on the number of bins produced by [`Binning::Auto`].
+const MAX_AUTO_BINS: usize = 512;
+
+/// How values are grouped into bins.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Binning {
+    /// Freedman–Diaconis bin width, falling back to Sturges' rule when the
+    /// interquartile range is zero.
+    #[default]
+    Auto,
+    /// A fixed number of equal-width bins.
+    Count(usize),
+    /// Bins of a fixed width.
+    Width(f64),
+}
+
+/// One histogram bin covering `start..end` (the last bin includes `end`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bin {
+    pub start: f64,
+    pub end: f64,
+    pub count: usize,
+}
+
+/// Group the finite values of `data` inside `range` (default: the data
+/// extent) into bins.
+pub fn histogram_bins(data: &[f64], binning: Binning, range: Option<(f64, f64)>) -> Vec<Bin> {
+    let (min, max) = range.unwrap_or_else(|| data_extent(data.iter().copied()));
+    if !min.is_finite() || !max.is_finite() || min > max {
+        return Vec::new();
+    }
+    let values: Vec<f64> = data
+        .iter()
+        .copied()
+        .filter(|v| v.is_finite() && *v >= min && *v <= max)
+        .collect();
+    let span = max - min;
+
+    let count = match binning {
+        _ if span <= 0.0 => 1,
+        Binning::Count(n) => n.max(1),
+        Binning::Width(width) if width > 0.0 => (span / width).ceil().max(1.0) as usize,
+        Binning::Width(_) => 1,
+        Binning::Auto => auto_bin_count(&values, span),
+    };
+    let width = if span > 0.0 { span / count as f64 } else { 1.0 };
+
+    let mut bins: Vec<Bin> = (0..count)
+        .map(|i| Bin {
+            start: min + width * i as f64,
+            end: if i + 1 == count {
+                min + width * count as f64
+            } else {
+                min + width * (i + 1) as f64
+            },
+            count: 0,
+        })
+        .collect();
+    for v in values {
+        let idx = (((v - min) / width) as usize).min(count - 1);
+        bins[idx].count += 1;
+    }
+    bins
+}
+
+fn auto_bin_count(values: &[f64], span: f64) -> usize {
+    let n = values.len();
+    if n < 2 {
+        return 1;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let quantile = |q: f64| sorted[((n - 1) as f64 * q).round() as usize];
+    let iqr = quantile(0.75) - quantile(0.25);
+    let count = if iqr > 0.0 {
+        let width = 2.0 * iqr / (n as f64).cbrt();
+        (span / width).ceil() as usize
+    } else {
+        (n as f64).log2().ceil() as usize + 1
+    };
+    count.clamp(1, MAX_AUTO_BINS)
+}
+
+/// Histogram of a sample, drawn as solid quarter-block bars.
+#[derive(Debug, Clone)]
+pub struct Histogram<'a> {
+    data: &'a [f64],
+    binning: Binning,
+    range: Option<(f64, f64)>,
+    color: PackedRgba,
+    x_axis: Axis,
+    y_axis: Axis,
+    style: Style,
+}
+
+impl<'a> Histogram<'a> {
+    pub fn new(data: &'a [f64]) -> Self {
+        Self {
+            data,
+            binning: Binning::default(),
+            range: None,
+            color: PackedRgba::WHITE,
+            x_axis: Axis::new(),
+            y_axis: Axis::new(),
+            style: Style::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn binning(mut self, binning: Binning) -> Self {
+        self.binning = binning;
+        self
+    }
+
+    /// Restrict binning to a value range (values outside are ignored).
+    #[must_use]
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    #[must_use]
+    pub fn color(mut self, color: PackedRgba) -> Self {
+        self.color = color;
+        self
+    }
+
+    #[must_use]
+    pub fn x_axis(mut self, axis: Axis) -> Self {
+        self.x_axis = axis;
+        self
+    }
+
+    #[must_use]
+    pub fn y_axis(mut self, axis: Axis) -> Self {
+        self.y_axis = axis;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The bins this histogram draws.
+    pub fn bins(&self) -> Vec<Bin> {
+        histogram_bins(self.data, self.binning, self.range)
+    }
+}
+
+impl Widget for Histogram<'_> {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        let bins = self.bins();
+        if area.is_empty() || bins.is_empty() {
+            return;
+        }
+
+        let x_range = (bins[0].start, bins[bins.len() - 1].end);
+        let max_count = bins.iter().map(|b| b.count).max().unwrap_or(0);
+        let x_range = self.x_axis.resolve(x_range.0, x_range.1);
+        let y_range = self.y_axis.resolve(0.0, max_count.max(1) as f64);
+        let layout = AxisLayout::new(area, &self.x_axis, x_range, &self.y_axis, y_range);
+        if !layout.is_drawable() {
+            return;
+        }
+        let plot = layout.plot;
+
+        let mut painter = Painter::for_area(plot, Mode::Block);
+        let (px_w, px_h) = painter.size();
+        let (px_w, px_h) = (f64::from(px_w), f64::from(px_h));
+        let to_x = |v: f64| (self.x_axis.normalize(v, x_range) * px_w).round() as i32;
+
+        for bin in &bins {
+            let left = to_x(bin.start);
+            let mut right = to_x(bin.end);
+            // Leave a one-pixel gutter between bars that are wide enough.
+            if right - left >= 3 {
+                right -= 1;
+            }
+            let height = (self.y_axis.normalize(bin.count as f64, y_range) * px_h)
+                .round()
+                .clamp(0.0, px_h) as i32;
+            painter.rect_filled_colored(
+                left,
+                px_h as i32 - height,
+                (right - left).max(1),
+                height,
+                self.color,
+            );
+        }
+
+        CanvasRef::from_painter(&painter)
+            .style(self.style)
+            .render(plot, frame);
+        layout.render(frame, self.style);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    #[test]
+    fn fixed_count_bins_cover_range() {
+        let data = [0.0, 1.0, 2.0, 3.0, 4.0, 10.0];
+        let bins = histogram_bins(&data, Binning::Count(5), None);
+        assert_eq!(bins.len(), 5);
+        assert_eq!(bins[0].start, 0.0);
+        assert_eq!(bins[4].end, 10.0);
+        let counts: Vec<usize> = bins.iter().map(|b| b.count).collect();
+        // The maximum lands in the last bin.
+        assert_eq!(counts, vec![2, 2, 1, 0, 1]);
+    }
+
+    #[test]
+    fn width_binning_and_range_filter() {
+        let data = [-5.0, 0.5, 1.5, 1.7, 2.5, f64::NAN, 99.0];
+        let bins = histogram_bins(&data, Binning::Width(1.0), Some((0.0, 3.0)));
+        let counts: Vec<usize> = bins.iter().map(|b| b.count).collect();
+        assert_eq!(counts, vec![1, 2, 1]);
+    }
+
+    #[test]
+    fn auto_binning_uses_freedman_diaconis() {
+        let data: Vec<f64> = (0..1000).map(f64::from).collect();
+        let bins = histogram_bins(&data, Binning::Auto, None);
+        // IQR = 499, width = 2·499/10 = 99.8 → 11 bins over 0..999.
+        assert_eq!(bins.len(), 11);
+        assert_eq!(bins.iter().map(|b| b.count).sum::<usize>(), 1000);
+
+        let constant = [3.0; 8];
+        assert_eq!(histogram_bins(&constant, Binning::Auto, None).len(), 1);
+    }
+
+    #[test]
+    fn tallest_bin_fills_plot_height() {
+        let data = [0.0, 1.0, 1.0, 1.0];
+        let chart = Histogram::new(&data)
+            .binning(Binning::Count(2))
+            .color(PackedRgba::GREEN)
+            .x_axis(Axis::new().ticks(0))
+            .y_axis(Axis::new().ticks(0));
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(9, 5, &mut pool);
+        chart.render(Rect::new(0, 0, 9, 5), &mut frame);
+
+        // Plot is 8×4 cells at x=1; the second bin (count 3) is full height.
+        let top = frame.buffer.get(6, 0).unwrap();
+        assert_eq!(top.content.as_char(), Some('█'));
+        assert_eq!(top.fg, PackedRgba::GREEN);
+        // The first bin (count 1) reaches about a third of the way up.
+        assert!(frame.buffer.get(2, 0).unwrap().content.is_empty());
+        assert_eq!(frame.buffer.get(2, 3).unwrap().content.as_char(), Some('█'));
+    }
+}
@@ -0,0 +1,237 @@
+//! Pie and donut charts with a legend.
+
+use std::f64::consts::TAU;
+
+use ftui_core::geometry::Rect;
+use ftui_render::cell::PackedRgba;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+use ftui_widgets::Widget;
+
+use super::axis::{Legend, LegendEntry, LegendPosition};
+use crate::canvas::{CanvasRef, Mode, Painter};
+
+/// One slice of a pie chart.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PieSlice<'a> {
+    pub label: &'a str,
+    pub value: f64,
+    pub color: PackedRgba,
+}
+
+impl<'a> PieSlice<'a> {
+    pub fn new(label: &'a str, value: f64, color: PackedRgba) -> Self {
+        Self {
+            label,
+            value,
+            color,
+        }
+    }
+}
+
+/// Pie chart, or a donut when [`PieChart::inner_radius`] is non-zero.
+///
+/// Slices run clockwise from twelve o'clock. Negative and non-finite values
+/// are treated as zero.
+#[derive(Debug, Clone)]
+pub struct PieChart<'a> {
+    slices: Vec<PieSlice<'a>>,
+    mode: Mode,
+    inner_radius: f64,
+    show_legend: bool,
+    legend_position: LegendPosition,
+    style: Style,
+}
+
+impl<'a> PieChart<'a> {
+    pub fn new(slices: Vec<PieSlice<'a>>) -> Self {
+        Self {
+            slices,
+            mode: Mode::Braille,
+            inner_radius: 0.0,
+            show_legend: true,
+            legend_position: LegendPosition::Right,
+            style: Style::new(),
+        }
+    }
+
+    /// Fill mode. [`Mode::HalfBlock`] gives solid colors with one slice per
+    /// half cell; [`Mode::Braille`] gives finer edges with one color per cell.
+    #[must_use]
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Hole radius as a fraction of the outer radius (`0.0` draws a pie).
+    #[must_use]
+    pub fn inner_radius(mut self, ratio: f64) -> Self {
+        self.inner_radius = if ratio.is_finite() {
+            ratio.clamp(0.0, 0.95)
+        } else {
+            0.0
+        };
+        self
+    }
+
+    #[must_use]
+    pub fn legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+
+    #[must_use]
+    pub fn legend_position(mut self, position: LegendPosition) -> Self {
+        self.legend_position = position;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Each slice's share of the total (all zero when the total is zero).
+    pub fn fractions(&self) -> Vec<f64> {
+        let value = |s: &PieSlice<'_>| {
+            if s.value.is_finite() {
+                s.value.max(0.0)
+            } else {
+                0.0
+            }
+        };
+        let total: f64 = self.slices.iter().map(value).sum();
+        self.slices
+            .iter()
+            .map(|s| if total > 0.0 { value(s) / total } else { 0.0 })
+            .collect()
+    }
+}
+
+impl Widget for PieChart<'_> {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        if area.is_empty() || self.slices.is_empty() {
+            return;
+        }
+
+        let legend = self.show_legend.then(|| {
+            Legend::new(
+                self.slices
+                    .iter()
+                    .map(|s| LegendEntry::new(s.label, s.color))
+                    .collect(),
+            )
+            .position(self.legend_position)
+            .style(self.style)
+        });
+        let chart_area = legend.as_ref().map_or(area, |l| l.reserve(area));
+
+        // Cumulative end angle of each slice, as a fraction of a full turn.
+        let mut ends = self.fractions();
+        if ends.iter().all(|&f| f == 0.0) {
+            return;
+        }
+        let mut acc = 0.0;
+        for end in &mut ends {
+            acc += *end;
+            *end = acc;
+        }
+
+        // Sub-pixels in all modes are roughly square, so the pie is a circle
+        // in pixel space centered in the chart area.
+        let mut painter = Painter::for_area(chart_area, self.mode);
+        let (px_w, px_h) = painter.size();
+        let cx = f64::from(px_w) / 2.0;
+        let cy = f64::from(px_h) / 2.0;
+        let outer = cx.min(cy);
+        let inner = outer * self.inner_radius;
+
+        for py in 0..usize::from(px_h) {
+            for px in 0..usize::from(px_w) {
+                let dx = px as f64 + 0.5 - cx;
+                let dy = py as f64 + 0.5 - cy;
+                let dist = dx.hypot(dy);
+                if dist > outer || dist < inner {
+                    continue;
+                }
+                let turn = dx.atan2(-dy).rem_euclid(TAU) / TAU;
+                let slice = ends.iter().position(|&end| turn < end).unwrap_or_else(|| {
+                    // Rounding can leave the total just under 1.0; the gap
+                    // belongs to the last non-empty slice.
+                    ends.iter().rposition(|&end| end > 0.0).unwrap_or(0)
+                });
+                painter.point_colored_in_bounds(px, py, self.slices[slice].color);
+            }
+        }
+
+        CanvasRef::from_painter(&painter)
+            .style(self.style)
+            .render(chart_area, frame);
+        if let Some(legend) = legend {
+            legend.render(legend.placement(area, chart_area), frame);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    #[test]
+    fn fractions_ignore_invalid_values() {
+        let chart = PieChart::new(vec![
+            PieSlice::new("a", 3.0, PackedRgba::RED),
+            PieSlice::new("b", -2.0, PackedRgba::GREEN),
+            PieSlice::new("c", f64::NAN, PackedRgba::BLUE),
+            PieSlice::new("d", 1.0, PackedRgba::WHITE),
+        ]);
+        assert_eq!(chart.fractions(), vec![0.75, 0.0, 0.0, 0.25]);
+    }
+
+    #[test]
+    fn half_block_pie_colors_quadrants() {
+        // Right half red, left half green, on a 10×5 cell area (10×10 px).
+        let chart = PieChart::new(vec![
+            PieSlice::new("r", 1.0, PackedRgba::RED),
+            PieSlice::new("g", 1.0, PackedRgba::GREEN),
+        ])
+        .mode(Mode::HalfBlock)
+        .legend(false);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(10, 5, &mut pool);
+        chart.render(Rect::new(0, 0, 10, 5), &mut frame);
+
+        let right = frame.buffer.get(7, 2).unwrap();
+        let left = frame.buffer.get(2, 2).unwrap();
+        assert_eq!(right.fg, PackedRgba::RED);
+        assert_eq!(left.fg, PackedRgba::GREEN);
+        // Corners fall outside the circle.
+        assert!(frame.buffer.get(0, 0).unwrap().content.is_empty());
+    }
+
+    #[test]
+    fn donut_leaves_center_empty_and_draws_legend() {
+        let chart = PieChart::new(vec![PieSlice::new("all", 1.0, PackedRgba::RED)])
+            .mode(Mode::HalfBlock)
+            .inner_radius(0.5);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(20, 10, &mut pool);
+        chart.render(Rect::new(0, 0, 20, 10), &mut frame);
+
+        // The legend takes 6 columns on the right; the 14×10 chart area is
+        // 14×20 px: a radius-7 ring centered on cell (7, 5).
+        let center = frame.buffer.get(7, 5).unwrap();
+        assert!(center.content.is_empty());
+        assert_eq!(frame.buffer.get(7, 2).unwrap().fg, PackedRgba::RED);
+        assert_eq!(
+            frame.buffer.get(15, 4).unwrap().content.as_char(),
+            Some('■')
+        );
+        assert_eq!(
+            frame.buffer.get(17, 4).unwrap().content.as_char(),
+            Some('a')
+        );
+    }
+}
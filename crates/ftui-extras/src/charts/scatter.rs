@@ -0,0 +1,247 @@
+//! Scatter plot with Braille markers and per-point colors.
+
+use ftui_core::geometry::Rect;
+use ftui_render::cell::PackedRgba;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+use ftui_widgets::Widget;
+
+use super::axis::{Axis, AxisLayout, Legend, LegendEntry, LegendPosition, PixelMap};
+use crate::canvas::{CanvasRef, Mode, Painter};
+
+/// Marker shape drawn at each point, in Braille dots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Marker {
+    /// A single dot.
+    #[default]
+    Dot,
+    /// A 3×3 plus sign.
+    Plus,
+    /// A 3×3 diagonal cross.
+    Cross,
+    /// A 2×2 square.
+    Square,
+}
+
+impl Marker {
+    fn offsets(self) -> &'static [(i32, i32)] {
+        match self {
+            Marker::Dot => &[(0, 0)],
+            Marker::Plus => &[(0, 0), (-1, 0), (1, 0), (0, -1), (0, 1)],
+            Marker::Cross => &[(0, 0), (-1, -1), (1, -1), (-1, 1), (1, 1)],
+            Marker::Square => &[(0, 0), (1, 0), (0, 1), (1, 1)],
+        }
+    }
+}
+
+/// A data series for the scatter chart.
+#[derive(Debug, Clone)]
+pub struct ScatterSeries<'a> {
+    pub name: &'a str,
+    pub data: &'a [(f64, f64)],
+    pub color: PackedRgba,
+    /// Per-point colors, index-aligned with `data`; missing entries use `color`.
+    pub colors: &'a [PackedRgba],
+    pub marker: Marker,
+}
+
+impl<'a> ScatterSeries<'a> {
+    pub fn new(name: &'a str, data: &'a [(f64, f64)], color: PackedRgba) -> Self {
+        Self {
+            name,
+            data,
+            color,
+            colors: &[],
+            marker: Marker::default(),
+        }
+    }
+
+    /// Color individual points (index-aligned with the data).
+    #[must_use]
+    pub fn colors(mut self, colors: &'a [PackedRgba]) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    #[must_use]
+    pub fn marker(mut self, marker: Marker) -> Self {
+        self.marker = marker;
+        self
+    }
+}
+
+/// Scatter plot with shared axes and an optional legend.
+#[derive(Debug, Clone)]
+pub struct ScatterChart<'a> {
+    series: Vec<ScatterSeries<'a>>,
+    x_axis: Axis,
+    y_axis: Axis,
+    style: Style,
+    show_legend: bool,
+    legend_position: LegendPosition,
+}
+
+impl<'a> ScatterChart<'a> {
+    pub fn new(series: Vec<ScatterSeries<'a>>) -> Self {
+        Self {
+            series,
+            x_axis: Axis::new(),
+            y_axis: Axis::new(),
+            style: Style::new(),
+            show_legend: false,
+            legend_position: LegendPosition::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn x_axis(mut self, axis: Axis) -> Self {
+        self.x_axis = axis;
+        self
+    }
+
+    #[must_use]
+    pub fn y_axis(mut self, axis: Axis) -> Self {
+        self.y_axis = axis;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    #[must_use]
+    pub fn legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+
+    #[must_use]
+    pub fn legend_position(mut self, position: LegendPosition) -> Self {
+        self.legend_position = position;
+        self
+    }
+
+    fn ranges(&self) -> ((f64, f64), (f64, f64)) {
+        let points = || self.series.iter().flat_map(|s| s.data.iter());
+        (
+            self.x_axis.fit(points().map(|p| p.0)),
+            self.y_axis.fit(points().map(|p| p.1)),
+        )
+    }
+}
+
+impl Widget for ScatterChart<'_> {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        if area.is_empty() || self.series.is_empty() {
+            return;
+        }
+
+        let legend = self.show_legend.then(|| {
+            Legend::new(
+                self.series
+                    .iter()
+                    .map(|s| LegendEntry::new(s.name, s.color))
+                    .collect(),
+            )
+            .position(self.legend_position)
+            .style(self.style)
+        });
+        let chart_area = legend.as_ref().map_or(area, |l| l.reserve(area));
+
+        let (x_range, y_range) = self.ranges();
+        let layout = AxisLayout::new(chart_area, &self.x_axis, x_range, &self.y_axis, y_range);
+        if !layout.is_drawable() {
+            return;
+        }
+        let plot = layout.plot;
+
+        let mut painter = Painter::for_area(plot, Mode::Braille);
+        let map = PixelMap::new(&self.x_axis, x_range, &self.y_axis, y_range, painter.size());
+        let in_range = |v: f64, (min, max): (f64, f64)| v >= min && v <= max;
+
+        for series in &self.series {
+            for (i, &(x, y)) in series.data.iter().enumerate() {
+                if !in_range(x, x_range) || !in_range(y, y_range) {
+                    continue;
+                }
+                let Some((px, py)) = map.point(x, y) else {
+                    continue;
+                };
+                let color = series.colors.get(i).copied().unwrap_or(series.color);
+                for &(dx, dy) in series.marker.offsets() {
+                    painter.point_colored(px + dx, py + dy, color);
+                }
+            }
+        }
+
+        CanvasRef::from_painter(&painter)
+            .style(self.style)
+            .render(plot, frame);
+        layout.render(frame, self.style);
+        if let Some(legend) = legend {
+            legend.render(legend.placement(area, plot), frame);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    #[test]
+    fn points_land_in_expected_cells() {
+        let data = [(0.0, 0.0), (10.0, 10.0)];
+        let chart = ScatterChart::new(vec![ScatterSeries::new("s", &data, PackedRgba::RED)])
+            .x_axis(Axis::new().ticks(0))
+            .y_axis(Axis::new().ticks(0));
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(11, 6, &mut pool);
+        chart.render(Rect::new(0, 0, 11, 6), &mut frame);
+
+        // Plot is 10×5 cells starting at x=1; (0,0) is bottom-left, (10,10) top-right.
+        let bottom_left = frame.buffer.get(1, 4).unwrap();
+        let top_right = frame.buffer.get(10, 0).unwrap();
+        assert_eq!(bottom_left.content.as_char(), Some('\u{2840}'));
+        assert_eq!(top_right.content.as_char(), Some('\u{2808}'));
+        assert_eq!(top_right.fg, PackedRgba::RED);
+    }
+
+    #[test]
+    fn per_point_colors_override_series_color() {
+        let data = [(0.0, 0.0), (1.0, 1.0)];
+        let colors = [PackedRgba::GREEN];
+        let chart = ScatterChart::new(vec![
+            ScatterSeries::new("s", &data, PackedRgba::RED)
+                .colors(&colors)
+                .marker(Marker::Plus),
+        ])
+        .x_axis(Axis::new().ticks(0))
+        .y_axis(Axis::new().ticks(0));
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(21, 11, &mut pool);
+        chart.render(Rect::new(0, 0, 21, 11), &mut frame);
+        assert_eq!(frame.buffer.get(1, 9).unwrap().fg, PackedRgba::GREEN);
+        assert_eq!(frame.buffer.get(20, 0).unwrap().fg, PackedRgba::RED);
+    }
+
+    #[test]
+    fn out_of_bounds_points_are_skipped() {
+        let data = [(5.0, 5.0), (50.0, 5.0), (f64::NAN, 1.0)];
+        let chart = ScatterChart::new(vec![ScatterSeries::new("s", &data, PackedRgba::RED)])
+            .x_axis(Axis::new().bounds(0.0, 10.0).ticks(0))
+            .y_axis(Axis::new().bounds(0.0, 10.0).ticks(0));
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(11, 6, &mut pool);
+        chart.render(Rect::new(0, 0, 11, 6), &mut frame);
+        let lit = (1..11)
+            .flat_map(|x| (0..5).map(move |y| (x, y)))
+            .filter(|&(x, y)| {
+                matches!(frame.buffer.get(x, y).unwrap().content.as_char(), Some(c) if c != ' ')
+            })
+            .count();
+        assert_eq!(lit, 1);
+    }
+}
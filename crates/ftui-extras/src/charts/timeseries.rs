@@ -0,0 +1,902 @@
+//! Streaming time-series chart with bounded per-series storage.
+//!
+//! [`TimeSeriesChart`] owns a ring buffer per series, so dashboards can
+//! [`push`](TimeSeriesChart::push) samples every tick without re-plotting
+//! their whole history. Each frame only the samples inside the visible
+//! window are downsampled to the Braille resolution of the plot.
+//! [`TimeSeriesState`] holds the view: following the newest sample or
+//! paused, zoom, pan, and the crosshair under the mouse.
+
+use std::collections::VecDeque;
+
+use ftui_core::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ftui_core::geometry::Rect;
+use ftui_render::cell::{Cell, PackedRgba};
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+use ftui_widgets::{StatefulWidget, Widget};
+
+use super::axis::{
+    Axis, AxisLayout, Legend, LegendEntry, LegendPosition, PixelMap, Scale, format_tick,
+    format_time,
+};
+use super::{display_width, draw_text, style_cell};
+use crate::canvas::{CanvasRef, Mode, Painter};
+
+/// Zoom change per wheel notch or key press.
+const ZOOM_STEP: f64 = 1.25;
+
+/// Zoom limits, as multiples of the configured window.
+const MIN_ZOOM: f64 = 1.0 / 1024.0;
+const MAX_ZOOM: f64 = 1024.0;
+
+/// Fraction of the window moved by one pan key press or horizontal wheel
+/// notch.
+const PAN_STEP: f64 = 0.1;
+
+// ===== Downsampling =====
+
+/// How visible samples are reduced to the plot's horizontal resolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Downsample {
+    /// Largest-Triangle-Three-Buckets: keeps the visually significant shape.
+    #[default]
+    Lttb,
+    /// Minimum and maximum of each bucket: keeps every spike.
+    MinMax,
+    /// Draw every visible sample.
+    None,
+}
+
+/// Reduce `points` to at most `threshold` points with the
+/// Largest-Triangle-Three-Buckets algorithm. The first and last points are
+/// always kept.
+pub fn lttb(points: &[(f64, f64)], threshold: usize) -> Vec<(f64, f64)> {
+    let n = points.len();
+    if threshold >= n || n < 3 {
+        return points.to_vec();
+    }
+    if threshold < 3 {
+        return vec![points[0], points[n - 1]];
+    }
+
+    let every = (n - 2) as f64 / (threshold - 2) as f64;
+    let mut out = Vec::with_capacity(threshold);
+    out.push(points[0]);
+    let mut a = 0;
+    for i in 0..threshold - 2 {
+        // Average of the next bucket is the third triangle vertex.
+        let next_start = ((i + 1) as f64 * every) as usize + 1;
+        let next_end = (((i + 2) as f64 * every) as usize + 1).min(n);
+        let next = &points[next_start.min(n - 1)..next_end.max(next_start.min(n - 1) + 1)];
+        let avg_x = next.iter().map(|p| p.0).sum::<f64>() / next.len() as f64;
+        let avg_y = next.iter().map(|p| p.1).sum::<f64>() / next.len() as f64;
+
+        let start = (i as f64 * every) as usize + 1;
+        let end = ((i + 1) as f64 * every) as usize + 1;
+        let (ax, ay) = points[a];
+        let mut best = start;
+        let mut best_area = -1.0;
+        for (j, &(x, y)) in points.iter().enumerate().take(end).skip(start) {
+            let area = ((ax - avg_x) * (y - ay) - (ax - x) * (avg_y - ay)).abs();
+            if area > best_area {
+                best_area = area;
+                best = j;
+            }
+        }
+        out.push(points[best]);
+        a = best;
+    }
+    out.push(points[n - 1]);
+    out
+}
+
+/// Reduce `points` to the minimum and maximum of each of `buckets`
+/// equal-width time buckets, in time order. The first and last points are
+/// always kept.
+pub fn min_max_buckets(points: &[(f64, f64)], buckets: usize) -> Vec<(f64, f64)> {
+    let n = points.len();
+    if buckets == 0 || n <= 2 * buckets + 2 {
+        return points.to_vec();
+    }
+    let (start, end) = (points[0].0, points[n - 1].0);
+    let span = end - start;
+    let mut out = Vec::with_capacity(2 * buckets + 2);
+    out.push(points[0]);
+
+    let interior = &points[1..n - 1];
+    let mut i = 0;
+    for bucket in 0..buckets {
+        let limit = start + span * (bucket + 1) as f64 / buckets as f64;
+        let from = i;
+        while i < interior.len() && (interior[i].0 < limit || bucket + 1 == buckets) {
+            i += 1;
+        }
+        let slice = &interior[from..i];
+        let Some(lo) = (0..slice.len()).min_by(|&a, &b| slice[a].1.total_cmp(&slice[b].1)) else {
+            continue;
+        };
+        let hi = (0..slice.len())
+            .max_by(|&a, &b| slice[a].1.total_cmp(&slice[b].1))
+            .unwrap_or(lo);
+        out.push(slice[lo.min(hi)]);
+        if lo != hi {
+            out.push(slice[lo.max(hi)]);
+        }
+    }
+
+    out.push(points[n - 1]);
+    out
+}
+
+/// Tooltip value with up to four significant decimals and no trailing
+/// zeros.
+fn format_value(value: f64) -> String {
+    let text = format_tick(value, value.abs().max(1e-3) / 1000.0);
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        text
+    }
+}
+
+// ===== TimeSeries =====
+
+/// A named series backed by a ring buffer of `(time, value)` samples.
+#[derive(Debug, Clone)]
+pub struct TimeSeries {
+    name: String,
+    color: PackedRgba,
+    capacity: usize,
+    samples: VecDeque<(f64, f64)>,
+}
+
+impl TimeSeries {
+    /// An empty series keeping at most `capacity` samples.
+    pub fn new(name: impl Into<String>, color: PackedRgba, capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            name: name.into(),
+            color,
+            capacity,
+            samples: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Series name, shown in the legend and tooltip.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Color the series is drawn in.
+    pub fn color(&self) -> PackedRgba {
+        self.color
+    }
+
+    /// Maximum number of samples kept (at least 1).
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of samples currently held.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Whether the series holds no samples.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Append a sample, evicting the oldest when full.
+    ///
+    /// Samples must arrive in time order; one older than the newest sample
+    /// or with a non-finite time is dropped and `false` is returned.
+    pub fn push(&mut self, time: f64, value: f64) -> bool {
+        if !time.is_finite() || self.last().is_some_and(|(t, _)| time < t) {
+            return false;
+        }
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back((time, value));
+        true
+    }
+
+    /// Remove all samples.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// The oldest sample.
+    pub fn first(&self) -> Option<(f64, f64)> {
+        self.samples.front().copied()
+    }
+
+    /// The newest sample.
+    pub fn last(&self) -> Option<(f64, f64)> {
+        self.samples.back().copied()
+    }
+
+    /// Samples from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = (f64, f64)> + '_ {
+        self.samples.iter().copied()
+    }
+
+    /// Samples with times inside `range`, plus the nearest sample on either
+    /// side so lines run to the plot edges.
+    pub fn window(&self, range: (f64, f64)) -> Vec<(f64, f64)> {
+        let start = self.samples.partition_point(|s| s.0 < range.0);
+        let end = self.samples.partition_point(|s| s.0 <= range.1);
+        let start = start.saturating_sub(1);
+        let end = (end + 1).min(self.samples.len());
+        self.samples.range(start..end).copied().collect()
+    }
+
+    /// The sample nearest to `time`.
+    pub fn nearest(&self, time: f64) -> Option<(f64, f64)> {
+        let idx = self.samples.partition_point(|s| s.0 < time);
+        let after = self.samples.get(idx).copied();
+        let before = idx
+            .checked_sub(1)
+            .and_then(|i| self.samples.get(i).copied());
+        match (before, after) {
+            (Some(b), Some(a)) => Some(if time - b.0 <= a.0 - time { b } else { a }),
+            (b, a) => b.or(a),
+        }
+    }
+}
+
+// ===== TimeSeriesState =====
+
+/// View state for a [`TimeSeriesChart`]: live or paused, zoom, pan, and the
+/// crosshair.
+#[derive(Debug, Clone)]
+pub struct TimeSeriesState {
+    paused_end: Option<f64>,
+    zoom: f64,
+    cursor: Option<(u16, u16)>,
+    drag_x: Option<u16>,
+    last_plot: Rect,
+    last_range: (f64, f64),
+}
+
+impl Default for TimeSeriesState {
+    fn default() -> Self {
+        Self {
+            paused_end: None,
+            zoom: 1.0,
+            cursor: None,
+            drag_x: None,
+            last_plot: Rect::default(),
+            last_range: (0.0, 1.0),
+        }
+    }
+}
+
+impl TimeSeriesState {
+    /// Whether the view is frozen instead of following the newest sample.
+    pub fn is_paused(&self) -> bool {
+        self.paused_end.is_some()
+    }
+
+    /// Freeze the view at its last rendered position.
+    pub fn pause(&mut self) {
+        if self.paused_end.is_none() {
+            self.paused_end = Some(self.last_range.1);
+        }
+    }
+
+    /// Follow the newest sample again.
+    pub fn resume(&mut self) {
+        self.paused_end = None;
+    }
+
+    /// Toggle between paused and live.
+    pub fn toggle_pause(&mut self) {
+        if self.is_paused() {
+            self.resume();
+        } else {
+            self.pause();
+        }
+    }
+
+    /// The visible window as a multiple of the chart's configured window.
+    pub fn zoom(&self) -> f64 {
+        self.zoom
+    }
+
+    /// Reset zoom and return to live view.
+    pub fn reset(&mut self) {
+        self.zoom = 1.0;
+        self.paused_end = None;
+    }
+
+    /// The time range shown by the last render.
+    pub fn visible_range(&self) -> (f64, f64) {
+        self.last_range
+    }
+
+    /// Time under screen column `x` in the last render.
+    pub fn time_at(&self, x: u16) -> Option<f64> {
+        let plot = self.last_plot;
+        if plot.width < 2 || x < plot.x || x >= plot.right() {
+            return None;
+        }
+        let (start, end) = self.last_range;
+        let t = f64::from(x - plot.x) / f64::from(plot.width - 1);
+        Some(start + (end - start) * t)
+    }
+
+    /// The crosshair position, if the mouse is over the plot.
+    pub fn crosshair(&self) -> Option<(u16, u16)> {
+        self.cursor
+    }
+
+    /// Zoom by `factor` (above 1 shows more time) keeping `anchor` in place.
+    /// While live the right edge stays on the newest sample.
+    fn zoom_by(&mut self, factor: f64, anchor: Option<f64>) -> bool {
+        let zoom = (self.zoom * factor).clamp(MIN_ZOOM, MAX_ZOOM);
+        if zoom == self.zoom {
+            return false;
+        }
+        let applied = zoom / self.zoom;
+        self.zoom = zoom;
+        if let (Some(end), Some(anchor)) = (self.paused_end, anchor) {
+            self.paused_end = Some(anchor + (end - anchor) * applied);
+        }
+        true
+    }
+
+    /// Shift the view by `seconds` (negative is earlier), pausing it.
+    fn pan_by(&mut self, seconds: f64) {
+        let end = self.paused_end.unwrap_or(self.last_range.1);
+        self.paused_end = Some(end + seconds);
+    }
+
+    fn seconds_per_column(&self) -> f64 {
+        let (start, end) = self.last_range;
+        (end - start) / f64::from(self.last_plot.width.max(2) - 1)
+    }
+
+    /// Handle a key press. Returns `true` if the view changed.
+    ///
+    /// Space toggles pause, `+`/`-` zoom, Left/Right pan (pausing), and End
+    /// or Escape reset to the live, unzoomed view.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        let window = self.last_range.1 - self.last_range.0;
+        match key.code {
+            KeyCode::Char(' ') => self.toggle_pause(),
+            KeyCode::Char('+') | KeyCode::Char('=') => return self.zoom_by(1.0 / ZOOM_STEP, None),
+            KeyCode::Char('-') => return self.zoom_by(ZOOM_STEP, None),
+            KeyCode::Left => self.pan_by(-window * PAN_STEP),
+            KeyCode::Right => self.pan_by(window * PAN_STEP),
+            KeyCode::End | KeyCode::Escape => {
+                let changed = self.is_paused() || self.zoom != 1.0;
+                self.reset();
+                return changed;
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Handle a mouse event against the last render: moving shows the
+    /// crosshair, the wheel zooms around the pointer, horizontal wheel or
+    /// left-drag pans (pausing the view), and a right click resumes live
+    /// view.
+    pub fn handle_mouse(&mut self, event: &MouseEvent) -> bool {
+        let inside = self.last_plot.contains(event.x, event.y);
+        if !inside && !matches!(event.kind, MouseEventKind::Drag(_) | MouseEventKind::Up(_)) {
+            let had_cursor = self.cursor.take().is_some();
+            self.drag_x = None;
+            return had_cursor;
+        }
+        let window = self.last_range.1 - self.last_range.0;
+        match event.kind {
+            MouseEventKind::Moved => {
+                let cursor = Some((event.x, event.y));
+                let changed = self.cursor != cursor;
+                self.cursor = cursor;
+                changed
+            }
+            MouseEventKind::ScrollUp => self.zoom_by(1.0 / ZOOM_STEP, self.time_at(event.x)),
+            MouseEventKind::ScrollDown => self.zoom_by(ZOOM_STEP, self.time_at(event.x)),
+            MouseEventKind::ScrollLeft => {
+                self.pan_by(-window * PAN_STEP);
+                true
+            }
+            MouseEventKind::ScrollRight => {
+                self.pan_by(window * PAN_STEP);
+                true
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                self.drag_x = Some(event.x);
+                false
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let Some(from) = self.drag_x else {
+                    return false;
+                };
+                self.drag_x = Some(event.x);
+                if from == event.x {
+                    return false;
+                }
+                // Dragging right reveals earlier samples.
+                let columns = f64::from(from) - f64::from(event.x);
+                self.pan_by(columns * self.seconds_per_column());
+                self.cursor = inside.then_some((event.x, event.y));
+                true
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                self.drag_x = None;
+                false
+            }
+            MouseEventKind::Down(MouseButton::Right) => {
+                let changed = self.is_paused();
+                self.resume();
+                changed
+            }
+            _ => false,
+        }
+    }
+}
+
+// ===== TimeSeriesChart =====
+
+/// Line chart over streaming data with a scrolling time window.
+///
+/// The x axis shows `window` seconds ending at the newest sample (or where
+/// the [`TimeSeriesState`] is paused), on a [`Scale::Time`] axis by default.
+#[derive(Debug, Clone)]
+pub struct TimeSeriesChart {
+    series: Vec<TimeSeries>,
+    window: f64,
+    downsample: Downsample,
+    x_axis: Axis,
+    y_axis: Axis,
+    style: Style,
+    crosshair_style: Style,
+    tooltip_style: Style,
+    show_legend: bool,
+    legend_position: LegendPosition,
+}
+
+impl TimeSeriesChart {
+    /// A chart showing the last `window` seconds.
+    pub fn new(window: f64) -> Self {
+        Self {
+            series: Vec::new(),
+            window: if window.is_finite() && window > 0.0 {
+                window
+            } else {
+                60.0
+            },
+            downsample: Downsample::default(),
+            x_axis: Axis::new().scale(Scale::Time),
+            y_axis: Axis::new().padding(0.05),
+            style: Style::new(),
+            crosshair_style: Style::new().bg(PackedRgba::rgb(60, 60, 80)),
+            tooltip_style: Style::new()
+                .fg(PackedRgba::WHITE)
+                .bg(PackedRgba::rgb(40, 40, 60)),
+            show_legend: false,
+            legend_position: LegendPosition::default(),
+        }
+    }
+
+    /// Add a series and return its index.
+    pub fn add_series(&mut self, series: TimeSeries) -> usize {
+        self.series.push(series);
+        self.series.len() - 1
+    }
+
+    /// Builder form of [`TimeSeriesChart::add_series`].
+    #[must_use]
+    pub fn with_series(mut self, series: TimeSeries) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Append a sample to series `index` (see [`TimeSeries::push`]).
+    /// Returns `false` when the index is out of range or the sample is
+    /// dropped.
+    pub fn push(&mut self, index: usize, time: f64, value: f64) -> bool {
+        self.series
+            .get_mut(index)
+            .is_some_and(|series| series.push(time, value))
+    }
+
+    pub fn series(&self) -> &[TimeSeries] {
+        &self.series
+    }
+
+    pub fn series_mut(&mut self, index: usize) -> Option<&mut TimeSeries> {
+        self.series.get_mut(index)
+    }
+
+    /// Seconds shown at zoom 1.
+    pub fn window(&self) -> f64 {
+        self.window
+    }
+
+    #[must_use]
+    pub fn downsample(mut self, downsample: Downsample) -> Self {
+        self.downsample = downsample;
+        self
+    }
+
+    /// Set the time axis (its bounds are replaced by the view window).
+    #[must_use]
+    pub fn x_axis(mut self, axis: Axis) -> Self {
+        self.x_axis = axis;
+        self
+    }
+
+    #[must_use]
+    pub fn y_axis(mut self, axis: Axis) -> Self {
+        self.y_axis = axis;
+        self
+    }
+
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Style for the crosshair column.
+    #[must_use]
+    pub fn crosshair_style(mut self, style: Style) -> Self {
+        self.crosshair_style = style;
+        self
+    }
+
+    /// Style for the value tooltip next to the crosshair.
+    #[must_use]
+    pub fn tooltip_style(mut self, style: Style) -> Self {
+        self.tooltip_style = style;
+        self
+    }
+
+    #[must_use]
+    pub fn legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+
+    #[must_use]
+    pub fn legend_position(mut self, position: LegendPosition) -> Self {
+        self.legend_position = position;
+        self
+    }
+
+    /// Time of the newest sample across all series.
+    pub fn latest(&self) -> Option<f64> {
+        self.series
+            .iter()
+            .filter_map(|s| s.last())
+            .map(|(t, _)| t)
+            .reduce(f64::max)
+    }
+
+    /// The time range the chart shows for `state`.
+    pub fn view_range(&self, state: &TimeSeriesState) -> Option<(f64, f64)> {
+        let end = state.paused_end.or_else(|| self.latest())?;
+        Some((end - self.window * state.zoom, end))
+    }
+
+    fn reduce(&self, points: Vec<(f64, f64)>, columns: usize) -> Vec<(f64, f64)> {
+        match self.downsample {
+            Downsample::Lttb => lttb(&points, columns),
+            Downsample::MinMax => min_max_buckets(&points, columns / 2),
+            Downsample::None => points,
+        }
+    }
+
+    fn format_time(&self, time: f64, span: f64) -> String {
+        if self.x_axis.scale_type() == Scale::Time {
+            format_time(time, 1.0)
+        } else {
+            format_tick(time, 10f64.powf((span / 100.0).log10().floor()))
+        }
+    }
+
+    /// Draw the tooltip for the crosshair at column `x`.
+    fn render_tooltip(&self, frame: &mut Frame, plot: Rect, x: u16, time: f64, span: f64) {
+        let mut lines = vec![self.format_time(time, span)];
+        for series in &self.series {
+            if let Some((_, value)) = series.nearest(time) {
+                lines.push(format!("{}: {}", series.name, format_value(value)));
+            }
+        }
+        let width = lines.iter().map(|l| display_width(l)).max().unwrap_or(0) as u16 + 2;
+        let height = lines.len() as u16;
+        if width > plot.width || height > plot.height {
+            return;
+        }
+        let left = if x + 2 + width <= plot.right() {
+            x + 2
+        } else {
+            x.saturating_sub(width + 1).max(plot.x)
+        };
+        let tooltip = Rect::new(left, plot.y, width, height);
+        for y in tooltip.y..tooltip.bottom() {
+            for x in tooltip.x..tooltip.right() {
+                let mut cell = Cell::from_char(' ');
+                style_cell(&mut cell, self.tooltip_style);
+                frame.buffer.set_fast(x, y, cell);
+            }
+        }
+        for (line, y) in lines.iter().zip(tooltip.y..) {
+            draw_text(
+                frame,
+                tooltip.x + 1,
+                y,
+                line,
+                self.tooltip_style,
+                tooltip.right(),
+            );
+        }
+    }
+}
+
+impl StatefulWidget for TimeSeriesChart {
+    type State = TimeSeriesState;
+
+    fn render(&self, area: Rect, frame: &mut Frame, state: &mut Self::State) {
+        state.last_plot = Rect::default();
+        let Some(x_range) = self.view_range(state) else {
+            return;
+        };
+        state.last_range = x_range;
+        if area.is_empty() {
+            return;
+        }
+
+        let legend = self.show_legend.then(|| {
+            Legend::new(
+                self.series
+                    .iter()
+                    .map(|s| LegendEntry::new(s.name.clone(), s.color))
+                    .collect(),
+            )
+            .position(self.legend_position)
+            .style(self.style)
+        });
+        let chart_area = legend.as_ref().map_or(area, |l| l.reserve(area));
+
+        let visible: Vec<Vec<(f64, f64)>> = self.series.iter().map(|s| s.window(x_range)).collect();
+        let y_range = self.y_axis.fit(
+            visible
+                .iter()
+                .flatten()
+                .filter(|p| p.0 >= x_range.0 && p.0 <= x_range.1)
+                .map(|p| p.1),
+        );
+        let x_axis = self.x_axis.clone().bounds(x_range.0, x_range.1);
+        let layout = AxisLayout::new(chart_area, &x_axis, x_range, &self.y_axis, y_range);
+        if !layout.is_drawable() {
+            return;
+        }
+        let plot = layout.plot;
+        state.last_plot = plot;
+
+        let mut painter = Painter::for_area(plot, Mode::Braille);
+        let (px_w, _) = painter.size();
+        let map = PixelMap::new(&x_axis, x_range, &self.y_axis, y_range, painter.size());
+        for (series, points) in self.series.iter().zip(visible) {
+            let points = self.reduce(points, usize::from(px_w));
+            let mut previous = None;
+            for &(x, y) in &points {
+                let Some(point) = map.point(x, y) else {
+                    previous = None;
+                    continue;
+                };
+                match previous {
+                    Some((x0, y0)) => {
+                        painter.line_colored(x0, y0, point.0, point.1, Some(series.color));
+                    }
+                    None => painter.point_colored(point.0, point.1, series.color),
+                }
+                previous = Some(point);
+            }
+        }
+
+        CanvasRef::from_painter(&painter)
+            .style(self.style)
+            .render(plot, frame);
+
+        if let Some((x, _)) = state.cursor
+            && let Some(time) = state.time_at(x)
+        {
+            for y in plot.y..plot.bottom() {
+                if let Some(cell) = frame.buffer.get_mut(x, y) {
+                    if let Some(bg) = self.crosshair_style.bg {
+                        cell.bg = bg;
+                    }
+                    if cell.content.is_empty() {
+                        cell.content = Cell::from_char('│').content;
+                        if let Some(fg) = self.crosshair_style.fg {
+                            cell.fg = fg;
+                        }
+                    }
+                }
+            }
+            self.render_tooltip(frame, plot, x, time, x_range.1 - x_range.0);
+        }
+
+        layout.render(frame, self.style);
+        if let Some(legend) = legend {
+            legend.render(legend.placement(area, plot), frame);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn row(frame: &Frame, y: u16) -> String {
+        (0..frame.buffer.width())
+            .map(|x| {
+                frame
+                    .buffer
+                    .get(x, y)
+                    .and_then(|c| c.content.as_char())
+                    .unwrap_or(' ')
+            })
+            .collect()
+    }
+
+    #[test]
+    fn ring_buffer_evicts_oldest_and_rejects_out_of_order() {
+        let mut series = TimeSeries::new("cpu", PackedRgba::RED, 3);
+        for t in 0..5 {
+            assert!(series.push(f64::from(t), f64::from(t * 10)));
+        }
+        assert_eq!(series.len(), 3);
+        assert_eq!(series.first(), Some((2.0, 20.0)));
+        assert!(!series.push(1.0, 0.0));
+        assert!(!series.push(f64::NAN, 0.0));
+        assert_eq!(
+            series.window((3.0, 3.5)),
+            vec![(2.0, 20.0), (3.0, 30.0), (4.0, 40.0)]
+        );
+        assert_eq!(series.nearest(3.4), Some((3.0, 30.0)));
+        assert_eq!(series.nearest(100.0), Some((4.0, 40.0)));
+    }
+
+    #[test]
+    fn tooltip_values_drop_trailing_zeros() {
+        assert_eq!(format_value(42.0), "42");
+        assert_eq!(format_value(1000.0), "1000");
+        assert_eq!(format_value(0.5), "0.5");
+    }
+
+    #[test]
+    fn lttb_keeps_endpoints_and_peaks() {
+        let mut points: Vec<(f64, f64)> = (0..100).map(|i| (f64::from(i), 0.0)).collect();
+        points[37].1 = 50.0;
+        let reduced = lttb(&points, 10);
+        assert_eq!(reduced.len(), 10);
+        assert_eq!(reduced[0], points[0]);
+        assert_eq!(reduced[9], points[99]);
+        assert!(reduced.contains(&(37.0, 50.0)));
+        assert_eq!(lttb(&points[..5], 10).len(), 5);
+    }
+
+    #[test]
+    fn min_max_keeps_every_extreme() {
+        let points: Vec<(f64, f64)> = (0..1000)
+            .map(|i| {
+                (
+                    f64::from(i),
+                    if i == 500 { -7.0 } else { f64::from(i % 10) },
+                )
+            })
+            .collect();
+        let reduced = min_max_buckets(&points, 20);
+        assert!(reduced.len() <= 42);
+        assert!(reduced.contains(&(500.0, -7.0)));
+        assert!(reduced.windows(2).all(|w| w[0].0 <= w[1].0));
+        assert_eq!(reduced.last(), points.last());
+    }
+
+    #[test]
+    fn live_view_follows_newest_sample_until_paused() {
+        let mut chart =
+            TimeSeriesChart::new(10.0).with_series(TimeSeries::new("a", PackedRgba::RED, 100));
+        let mut state = TimeSeriesState::default();
+        for t in 0..=20 {
+            chart.push(0, f64::from(t), f64::from(t));
+        }
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(40, 10, &mut pool);
+        chart.render(Rect::new(0, 0, 40, 10), &mut frame, &mut state);
+        assert_eq!(state.visible_range(), (10.0, 20.0));
+
+        state.pause();
+        chart.push(0, 25.0, 1.0);
+        chart.render(Rect::new(0, 0, 40, 10), &mut frame, &mut state);
+        assert_eq!(state.visible_range(), (10.0, 20.0));
+
+        state.resume();
+        chart.render(Rect::new(0, 0, 40, 10), &mut frame, &mut state);
+        assert_eq!(state.visible_range(), (15.0, 25.0));
+    }
+
+    #[test]
+    fn mouse_zoom_pan_and_crosshair() {
+        let mut chart = TimeSeriesChart::new(100.0)
+            .x_axis(Axis::new().ticks(0))
+            .y_axis(Axis::new().ticks(0))
+            .with_series(TimeSeries::new("v", PackedRgba::GREEN, 1000));
+        for t in 0..=100 {
+            chart.push(0, f64::from(t), 42.0);
+        }
+        let mut state = TimeSeriesState::default();
+        let area = Rect::new(0, 0, 41, 11);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(41, 11, &mut pool);
+        chart.render(area, &mut frame, &mut state);
+        // Plot is x 1..41 (40 columns) over 0..100 s.
+        assert_eq!(state.time_at(1), Some(0.0));
+        assert_eq!(state.time_at(40), Some(100.0));
+
+        // Wheel zooms in while staying live.
+        let wheel = MouseEvent::new(MouseEventKind::ScrollUp, 20, 5);
+        assert!(state.handle_mouse(&wheel));
+        assert!((state.zoom() - 0.8).abs() < 1e-12);
+        assert!(!state.is_paused());
+
+        // Dragging right by 4 columns pans 10 s earlier and pauses.
+        chart.render(area, &mut frame, &mut state);
+        state.handle_mouse(&MouseEvent::new(
+            MouseEventKind::Down(MouseButton::Left),
+            10,
+            5,
+        ));
+        assert!(state.handle_mouse(&MouseEvent::new(
+            MouseEventKind::Drag(MouseButton::Left),
+            14,
+            5
+        )));
+        assert!(state.is_paused());
+        let (start, end) = chart.view_range(&state).unwrap();
+        assert!((end - (100.0 - 4.0 * 80.0 / 39.0)).abs() < 1e-9);
+        assert!((end - start - 80.0).abs() < 1e-9);
+
+        // Moving shows the crosshair and a tooltip with the series value.
+        assert!(state.handle_mouse(&MouseEvent::new(MouseEventKind::Moved, 10, 5)));
+        chart.render(area, &mut frame, &mut state);
+        assert!(row(&frame, 1).contains("v: 42"));
+        assert_eq!(
+            frame.buffer.get(10, 8).unwrap().bg,
+            PackedRgba::rgb(60, 60, 80)
+        );
+
+        // Leaving the plot hides it; right click returns to live view.
+        assert!(state.handle_mouse(&MouseEvent::new(MouseEventKind::Moved, 0, 10)));
+        assert_eq!(state.crosshair(), None);
+        assert!(state.handle_mouse(&MouseEvent::new(
+            MouseEventKind::Down(MouseButton::Right),
+            20,
+            5
+        )));
+        assert!(!state.is_paused());
+    }
+
+    #[test]
+    fn keys_toggle_pause_and_reset() {
+        let mut state = TimeSeriesState::default();
+        assert!(state.handle_key(&KeyEvent::new(KeyCode::Char(' '))));
+        assert!(state.is_paused());
+        assert!(state.handle_key(&KeyEvent::new(KeyCode::Char('-'))));
+        assert!((state.zoom() - ZOOM_STEP).abs() < 1e-12);
+        assert!(state.handle_key(&KeyEvent::new(KeyCode::End)));
+        assert!(!state.is_paused());
+        assert_eq!(state.zoom(), 1.0);
+    }
+}
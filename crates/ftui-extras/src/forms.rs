@@ -2,26 +2,44 @@
 
 //! Form and picker widgets for interactive data entry.
 //!
-//! Provides a `Form` widget with field types (text, checkbox, radio, select, number),
-//! validation, tab navigation, and submit/cancel actions. Also includes a `ConfirmDialog`
-//! for simple yes/no prompts.
+//! Provides a `Form` widget with field types (text, checkbox, radio, select, number,
+//! date, time), validation, tab navigation, and submit/cancel actions. Also includes a
+//! `ConfirmDialog` for simple yes/no prompts.
+//!
+//! Date fields open a [`Calendar`] popup on Space; time fields edit hours and minutes
+//! as separate segments. Both honour optional min/max bounds.
+//!
+//! With the `forms-serde` feature, [`SchemaForm`] derives a form from any serde
+//! struct and deserializes the submitted values back into it.
 //!
 //! Feature-gated under `forms`.
 
 use ftui_core::event::{Event, KeyCode, KeyEvent, KeyEventKind, Modifiers};
-use ftui_core::geometry::Rect;
+use ftui_core::geometry::{Rect, Size};
+use ftui_layout::{AnchorAlign, AnchorPlacement, AnchorSide};
 use ftui_render::buffer::Buffer;
 use ftui_render::cell::Cell;
 use ftui_render::frame::Frame;
 use ftui_style::Style;
+use ftui_widgets::calendar::{Calendar, CalendarState, Date, Weekday};
 use ftui_widgets::{StatefulWidget, ValidationErrorDisplay, ValidationErrorState, Widget};
 
+#[cfg(feature = "forms-serde")]
+mod schema;
+
+#[cfg(feature = "forms-serde")]
+pub use schema::{FieldAttrs, FormSchema, SchemaError, SchemaForm};
+
 // ---------------------------------------------------------------------------
 // FormField – the individual field types
 // ---------------------------------------------------------------------------
 
 /// A single form field definition.
+///
+/// Non-exhaustive: the `Date` and `Time` pickers were added after the
+/// original field set, so matches outside this crate need a wildcard arm.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub enum FormField {
     /// Single-line text input.
     Text {
@@ -51,6 +69,21 @@ pub enum FormField {
         max: Option<i64>,
         step: i64,
     },
+    /// Calendar date with optional bounds.
+    Date {
+        label: String,
+        value: Option<Date>,
+        min: Option<Date>,
+        max: Option<Date>,
+    },
+    /// Time of day with optional bounds.
+    Time {
+        label: String,
+        value: Time,
+        min: Option<Time>,
+        max: Option<Time>,
+        step_minutes: u8,
+    },
 }
 
 impl FormField {
@@ -129,6 +162,53 @@ impl FormField {
         }
     }
 
+    /// Create a date field.
+    pub fn date(label: impl Into<String>, value: Option<Date>) -> Self {
+        Self::Date {
+            label: label.into(),
+            value,
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Create a date field with inclusive bounds.
+    pub fn date_bounded(
+        label: impl Into<String>,
+        value: Option<Date>,
+        min: Option<Date>,
+        max: Option<Date>,
+    ) -> Self {
+        Self::Date {
+            label: label.into(),
+            value: value.map(|d| d.clamp_to(min, max)),
+            min,
+            max,
+        }
+    }
+
+    /// Create a time field.
+    pub fn time(label: impl Into<String>, value: Time) -> Self {
+        Self::Time {
+            label: label.into(),
+            value,
+            min: None,
+            max: None,
+            step_minutes: 1,
+        }
+    }
+
+    /// Create a time field with inclusive bounds.
+    pub fn time_bounded(label: impl Into<String>, value: Time, min: Time, max: Time) -> Self {
+        Self::Time {
+            label: label.into(),
+            value: value.clamp(min, max),
+            min: Some(min),
+            max: Some(max),
+            step_minutes: 1,
+        }
+    }
+
     /// Get the label for this field.
     pub fn label(&self) -> &str {
         match self {
@@ -136,22 +216,92 @@ impl FormField {
             | Self::Checkbox { label, .. }
             | Self::Radio { label, .. }
             | Self::Select { label, .. }
-            | Self::Number { label, .. } => label,
+            | Self::Number { label, .. }
+            | Self::Date { label, .. }
+            | Self::Time { label, .. } => label,
         }
     }
 }
 
+// ---------------------------------------------------------------------------
+// Time – time-of-day value for time fields
+// ---------------------------------------------------------------------------
+
+/// A time of day with minute precision (no time zone).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+}
+
+impl Time {
+    /// Midnight, 00:00.
+    pub const MIDNIGHT: Self = Self { hour: 0, minute: 0 };
+
+    /// Create a time, returning `None` if out of range.
+    pub fn new(hour: u8, minute: u8) -> Option<Self> {
+        (hour < 24 && minute < 60).then_some(Self { hour, minute })
+    }
+
+    /// Hour (`0..24`).
+    pub const fn hour(self) -> u8 {
+        self.hour
+    }
+
+    /// Minute (`0..60`).
+    pub const fn minute(self) -> u8 {
+        self.minute
+    }
+
+    /// Minutes since midnight.
+    pub const fn minutes(self) -> u16 {
+        self.hour as u16 * 60 + self.minute as u16
+    }
+
+    /// Time from minutes since midnight, saturating at 23:59.
+    pub const fn from_minutes(minutes: u16) -> Self {
+        let minutes = if minutes > 1439 { 1439 } else { minutes };
+        Self {
+            hour: (minutes / 60) as u8,
+            minute: (minutes % 60) as u8,
+        }
+    }
+
+    /// Shift by a number of minutes, saturating within the day.
+    pub fn add_minutes(self, delta: i32) -> Self {
+        let minutes = (i32::from(self.minutes()) + delta).clamp(0, 1439);
+        Self::from_minutes(minutes as u16)
+    }
+
+    /// Parse `HH:MM`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let (hour, minute) = s.trim().split_once(':')?;
+        Self::new(hour.parse().ok()?, minute.parse().ok()?)
+    }
+}
+
+impl std::fmt::Display for Time {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // FormData – collected values after submit
 // ---------------------------------------------------------------------------
 
 /// A single value extracted from a form field.
+///
+/// Non-exhaustive, like [`FormField`].
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum FormValue {
     Text(String),
     Bool(bool),
     Choice { index: usize, label: String },
     Number(i64),
+    Date(Option<Date>),
+    Time(Time),
 }
 
 /// Collected data from all form fields.
@@ -202,6 +352,7 @@ pub struct Form {
     label_width: u16,
     required: Vec<bool>,
     disabled: Vec<bool>,
+    first_weekday: Weekday,
 }
 
 impl Form {
@@ -221,6 +372,7 @@ impl Form {
             label_width: 0, // auto-detect
             required: vec![false; count],
             disabled: vec![false; count],
+            first_weekday: Weekday::Monday,
         }
     }
 
@@ -353,6 +505,13 @@ impl Form {
         self
     }
 
+    /// Set the first day of the week for date picker popups.
+    #[must_use]
+    pub fn first_weekday(mut self, weekday: Weekday) -> Self {
+        self.first_weekday = weekday;
+        self
+    }
+
     /// Attach a validator to a field by index.
     #[must_use]
     pub fn validate(mut self, field_index: usize, f: ValidateFn) -> Self {
@@ -400,6 +559,8 @@ impl Form {
                         label: options.get(*selected).cloned().unwrap_or_default(),
                     },
                     FormField::Number { value, .. } => FormValue::Number(*value),
+                    FormField::Date { value, .. } => FormValue::Date(*value),
+                    FormField::Time { value, .. } => FormValue::Time(*value),
                 };
                 (label, value)
             })
@@ -474,6 +635,10 @@ pub struct FormState {
     initial_values: Option<Vec<FormValue>>,
     /// Per-field validation error display state (for animation/accessibility).
     error_states: Vec<ValidationErrorState>,
+    /// Editing the minute segment (rather than the hour) of a time field.
+    time_minute_segment: bool,
+    /// Open calendar popup for the focused date field.
+    date_popup: Option<CalendarState>,
 }
 
 impl FormState {
@@ -524,6 +689,8 @@ impl FormState {
                         label: options.get(*selected).cloned().unwrap_or_default(),
                     },
                     FormField::Number { value, .. } => FormValue::Number(*value),
+                    FormField::Date { value, .. } => FormValue::Date(*value),
+                    FormField::Time { value, .. } => FormValue::Time(*value),
                 })
                 .collect(),
         );
@@ -588,6 +755,8 @@ impl FormState {
                 label: options.get(*selected).cloned().unwrap_or_default(),
             },
             FormField::Number { value, .. } => FormValue::Number(*value),
+            FormField::Date { value, .. } => FormValue::Date(*value),
+            FormField::Time { value, .. } => FormValue::Time(*value),
         };
 
         if field_idx < self.dirty.len() {
@@ -681,6 +850,27 @@ impl FormState {
                 _ => return false,
             }
         }
+        // An open date popup captures keys; Tab closes it and moves on.
+        if let Some(mut popup) = self.date_popup.take() {
+            match key.code {
+                KeyCode::Escape => return true,
+                KeyCode::Enter | KeyCode::Char(' ') => {
+                    if let Some(FormField::Date { value, .. }) = form.fields.get_mut(self.focused)
+                        && popup.in_bounds(popup.cursor)
+                    {
+                        *value = Some(popup.cursor);
+                        self.update_dirty(form, self.focused);
+                    }
+                    return true;
+                }
+                KeyCode::Tab | KeyCode::BackTab => {}
+                _ => {
+                    let changed = popup.handle_key(key);
+                    self.date_popup = Some(popup);
+                    return changed;
+                }
+            }
+        }
         match key.code {
             // Tab / Shift+Tab: navigate fields
             KeyCode::Tab => {
@@ -720,6 +910,9 @@ impl FormState {
             // Left/Right for number fields and select
             KeyCode::Left => self.handle_left(form),
             KeyCode::Right => self.handle_right(form),
+            // PageUp/PageDown: move date fields by month
+            KeyCode::PageUp => self.shift_date(form, |d| d.add_months(-1)),
+            KeyCode::PageDown => self.shift_date(form, |d| d.add_months(1)),
             // Character input for text fields
             KeyCode::Char(c) if !key.modifiers.contains(Modifiers::CTRL) => {
                 self.handle_text_char(form, c)
@@ -765,6 +958,8 @@ impl FormState {
                     self.update_dirty(form, self.focused);
                     return true;
                 }
+                FormField::Date { .. } => return self.shift_date(form, |d| d.add_days(1)),
+                FormField::Time { .. } => return self.adjust_time(form, 1),
                 _ => {}
             }
         }
@@ -804,6 +999,8 @@ impl FormState {
                     self.update_dirty(form, self.focused);
                     return true;
                 }
+                FormField::Date { .. } => return self.shift_date(form, |d| d.add_days(-1)),
+                FormField::Time { .. } => return self.adjust_time(form, -1),
                 _ => {}
             }
         }
@@ -829,6 +1026,15 @@ impl FormState {
                     self.update_dirty(form, self.focused);
                     return true;
                 }
+                FormField::Date {
+                    value, min, max, ..
+                } => {
+                    let cursor = value.unwrap_or_else(Date::today_utc).clamp_to(*min, *max);
+                    let mut popup = CalendarState::new(cursor).with_bounds(*min, *max);
+                    popup.selected = *value;
+                    self.date_popup = Some(popup);
+                    return true;
+                }
                 _ => {}
             }
         }
@@ -864,6 +1070,12 @@ impl FormState {
                     }
                     return true;
                 }
+                FormField::Date { .. } => return self.shift_date(form, |d| d.add_days(-1)),
+                FormField::Time { .. } => {
+                    let changed = self.time_minute_segment;
+                    self.time_minute_segment = false;
+                    return changed;
+                }
                 _ => {}
             }
         }
@@ -898,6 +1110,12 @@ impl FormState {
                     }
                     return true;
                 }
+                FormField::Date { .. } => return self.shift_date(form, |d| d.add_days(1)),
+                FormField::Time { .. } => {
+                    let changed = !self.time_minute_segment;
+                    self.time_minute_segment = true;
+                    return changed;
+                }
                 _ => {}
             }
         }
@@ -965,8 +1183,70 @@ impl FormState {
         false
     }
 
-    /// Sync the text cursor when switching to a text field.
+    /// Move a date field, clamped to its bounds. An empty field starts at
+    /// today (clamped) instead of shifting.
+    fn shift_date(&mut self, form: &mut Form, shift: impl Fn(Date) -> Date) -> bool {
+        let Some(FormField::Date {
+            value, min, max, ..
+        }) = form.fields.get_mut(self.focused)
+        else {
+            return false;
+        };
+        let next = match *value {
+            Some(date) => shift(date),
+            None => Date::today_utc(),
+        }
+        .clamp_to(*min, *max);
+        if *value == Some(next) {
+            return false;
+        }
+        *value = Some(next);
+        self.update_dirty(form, self.focused);
+        true
+    }
+
+    /// Step the active segment of a time field up (`1`) or down (`-1`).
+    fn adjust_time(&mut self, form: &mut Form, direction: i32) -> bool {
+        let Some(FormField::Time {
+            value,
+            min,
+            max,
+            step_minutes,
+            ..
+        }) = form.fields.get_mut(self.focused)
+        else {
+            return false;
+        };
+        let step = if self.time_minute_segment {
+            i32::from((*step_minutes).max(1))
+        } else {
+            60
+        };
+        let mut next = value.add_minutes(direction * step);
+        if let Some(max) = max {
+            next = next.min(*max);
+        }
+        if let Some(min) = min {
+            next = next.max(*min);
+        }
+        if next == *value {
+            return false;
+        }
+        *value = next;
+        self.update_dirty(form, self.focused);
+        true
+    }
+
+    /// Whether a date field's calendar popup is open.
+    pub fn is_date_popup_open(&self) -> bool {
+        self.date_popup.is_some()
+    }
+
+    /// Sync the text cursor when switching fields, and reset per-field
+    /// picker state.
     fn sync_text_cursor(&mut self, form: &Form) {
+        self.time_minute_segment = false;
+        self.date_popup = None;
         if let Some(FormField::Text { value, .. }) = form.fields.get(self.focused) {
             let count = grapheme_count(value);
             self.text_cursor = self.text_cursor.min(count);
@@ -1026,6 +1306,7 @@ impl StatefulWidget for Form {
         let max_scroll = total_rows.saturating_sub(visible_rows);
         state.scroll = state.scroll.min(max_scroll);
 
+        let mut focused_y = None;
         let mut row_cursor = 0usize;
         for (i, field) in self.fields.iter().enumerate() {
             let row_start = row_cursor;
@@ -1065,6 +1346,9 @@ impl StatefulWidget for Form {
             // Draw label + value on the primary line if visible
             if row_start >= state.scroll {
                 let y = area.y.saturating_add((row_start - state.scroll) as u16);
+                if is_focused {
+                    focused_y = Some(y);
+                }
 
                 let label_style = if is_disabled {
                     self.disabled_style
@@ -1155,6 +1439,10 @@ impl StatefulWidget for Form {
                 }
             }
         }
+
+        if let Some(y) = focused_y {
+            self.render_date_popup(area, frame, state, value_x, y);
+        }
     }
 }
 
@@ -1227,7 +1515,74 @@ impl Form {
                 };
                 draw_str(frame, x, y, &display, style, width);
             }
+            FormField::Date { value, .. } => match value {
+                Some(date) => {
+                    let display = if is_focused {
+                        format!("< {date} >")
+                    } else {
+                        format!("  {date}  ")
+                    };
+                    draw_str(frame, x, y, &display, style, width);
+                }
+                None => draw_str(frame, x, y, "  YYYY-MM-DD", placeholder_style, width),
+            },
+            FormField::Time { value, .. } => {
+                draw_str(frame, x, y, &value.to_string(), style, width);
+                // Highlight the segment being edited
+                if is_focused {
+                    let start = if state.time_minute_segment { 3 } else { 0 };
+                    for col in start..(start + 2).min(width) {
+                        if let Some(cell) = frame.buffer.get_mut(x.saturating_add(col), y) {
+                            use ftui_render::cell::StyleFlags;
+                            let flags = cell.attrs.flags();
+                            cell.attrs = cell.attrs.with_flags(flags ^ StyleFlags::REVERSE);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Draw the calendar popup for the focused date field, below its row when
+    /// it fits and above otherwise.
+    fn render_date_popup(
+        &self,
+        area: Rect,
+        frame: &mut Frame,
+        state: &mut FormState,
+        x: u16,
+        field_y: u16,
+    ) {
+        let Some(mut popup) = state.date_popup.take() else {
+            return;
+        };
+        if !matches!(self.fields.get(state.focused), Some(FormField::Date { .. })) {
+            return;
+        }
+        // Open below the value, flipping above when there is more room there.
+        let popup_area = AnchorPlacement::new(AnchorSide::Bottom)
+            .align(AnchorAlign::Start)
+            .solve(
+                Rect::new(x, field_y, 1, 1),
+                Size::new(Calendar::WIDTH, Calendar::HEIGHT),
+                area,
+            )
+            .rect;
+        if !popup_area.is_empty() {
+            for py in popup_area.y..popup_area.bottom() {
+                for px in popup_area.x..popup_area.right() {
+                    frame.buffer.set_fast(px, py, Cell::from_char(' '));
+                }
+            }
+            set_style_area(&mut frame.buffer, popup_area, self.style);
+            let calendar = Calendar::new()
+                .first_weekday(self.first_weekday)
+                .style(self.style)
+                .header_style(self.label_style)
+                .disabled_style(self.disabled_style);
+            StatefulWidget::render(&calendar, popup_area, frame, &mut popup);
         }
+        state.date_popup = Some(popup);
     }
 }
 
@@ -3089,4 +3444,140 @@ mod tests {
         // "AB" = 2, + " *" = 2, + ": " = 2 → 6
         assert_eq!(form.effective_label_width(), 6);
     }
+
+    // -- Date / Time fields --
+
+    fn date(y: i32, m: u8, d: u8) -> Date {
+        Date::new(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn date_field_navigation_clamps_to_bounds() {
+        let mut form = Form::new(vec![FormField::date_bounded(
+            "When",
+            Some(date(2026, 10, 30)),
+            Some(date(2026, 10, 1)),
+            Some(date(2026, 10, 31)),
+        )]);
+        let mut state = FormState::default();
+        state.init_tracking(&form);
+
+        assert!(state.handle_event(&mut form, &press(KeyCode::Right)));
+        assert!(!state.handle_event(&mut form, &press(KeyCode::Right)));
+        assert!(state.handle_event(&mut form, &press(KeyCode::PageUp)));
+        assert_eq!(
+            form.data().get("When"),
+            Some(&FormValue::Date(Some(date(2026, 10, 1))))
+        );
+        assert!(state.handle_event(&mut form, &press(KeyCode::Up)));
+        assert!(state.is_dirty(0));
+    }
+
+    #[test]
+    fn date_popup_selects_and_escape_keeps_form_open() {
+        let mut form = Form::new(vec![FormField::date("When", Some(date(2026, 10, 18)))]);
+        let mut state = FormState::default();
+
+        assert!(state.handle_event(&mut form, &press(KeyCode::Char(' '))));
+        assert!(state.is_date_popup_open());
+        state.handle_event(&mut form, &press(KeyCode::Down));
+        state.handle_event(&mut form, &press(KeyCode::Enter));
+        assert!(!state.is_date_popup_open());
+        assert!(!state.submitted);
+        assert_eq!(
+            form.data().get("When"),
+            Some(&FormValue::Date(Some(date(2026, 10, 25))))
+        );
+
+        state.handle_event(&mut form, &press(KeyCode::Char(' ')));
+        state.handle_event(&mut form, &press(KeyCode::Escape));
+        assert!(!state.is_date_popup_open());
+        assert!(!state.cancelled);
+    }
+
+    #[test]
+    fn date_popup_renders_below_field() {
+        let mut form = Form::new(vec![FormField::date("D", Some(date(2026, 10, 18)))])
+            .first_weekday(Weekday::Sunday);
+        let mut state = FormState::default();
+        state.handle_event(&mut form, &press(KeyCode::Char(' ')));
+
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(30, 10, &mut pool);
+        StatefulWidget::render(&form, Rect::new(0, 0, 30, 10), &mut frame, &mut state);
+        assert_eq!(
+            row_to_string(&frame.buffer, 0, 30).trim_end(),
+            "D: < 2026-10-18 >"
+        );
+        assert_eq!(
+            row_to_string(&frame.buffer, 2, 30).trim_end(),
+            "   Su Mo Tu We Th Fr Sa"
+        );
+    }
+
+    #[test]
+    fn date_popup_flips_above_field_near_bottom() {
+        let mut fields: Vec<FormField> = (0..9).map(|i| FormField::text(format!("T{i}"))).collect();
+        fields.push(FormField::date("D", Some(date(2026, 10, 18))));
+        let mut form = Form::new(fields).first_weekday(Weekday::Sunday);
+        let mut state = FormState {
+            focused: 9,
+            ..FormState::default()
+        };
+        state.handle_event(&mut form, &press(KeyCode::Char(' ')));
+        assert!(state.is_date_popup_open());
+
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(30, 10, &mut pool);
+        StatefulWidget::render(&form, Rect::new(0, 0, 30, 10), &mut frame, &mut state);
+        assert!(row_to_string(&frame.buffer, 9, 30).starts_with("D: "));
+        assert_eq!(
+            row_to_string(&frame.buffer, 2, 30).trim_end(),
+            "T2: Su Mo Tu We Th Fr Sa"
+        );
+    }
+
+    #[test]
+    fn time_field_segments_and_bounds() {
+        let mut form = Form::new(vec![FormField::time_bounded(
+            "At",
+            Time::new(9, 30).unwrap(),
+            Time::new(8, 0).unwrap(),
+            Time::new(17, 45).unwrap(),
+        )]);
+        let mut state = FormState::default();
+
+        assert!(state.handle_event(&mut form, &press(KeyCode::Up)));
+        assert!(state.handle_event(&mut form, &press(KeyCode::Right)));
+        assert!(state.handle_event(&mut form, &press(KeyCode::Down)));
+        assert_eq!(
+            form.data().get("At"),
+            Some(&FormValue::Time(Time::new(10, 29).unwrap()))
+        );
+        for _ in 0..10 {
+            state.handle_event(&mut form, &press(KeyCode::Left));
+            state.handle_event(&mut form, &press(KeyCode::Up));
+        }
+        assert_eq!(
+            form.data().get("At"),
+            Some(&FormValue::Time(Time::new(17, 45).unwrap()))
+        );
+        assert_eq!(Time::parse("07:05").unwrap().to_string(), "07:05");
+        assert!(Time::new(24, 0).is_none());
+    }
+
+    #[test]
+    fn date_validator_hook_runs_on_submit() {
+        let mut form = Form::new(vec![FormField::date("When", None)]).validate(
+            0,
+            Box::new(|f| match f {
+                FormField::Date { value: None, .. } => Some("Pick a date".into()),
+                _ => None,
+            }),
+        );
+        let mut state = FormState::default();
+        state.handle_event(&mut form, &press(KeyCode::Enter));
+        assert!(!state.submitted);
+        assert_eq!(state.errors[0].message, "Pick a date");
+    }
 }
@@ -0,0 +1,1543 @@
+#![forbid(unsafe_code)]
+
+//! Forms generated from serde types.
+//!
+//! [`SchemaForm`] traces the `Deserialize` impl of a type to discover its
+//! shape, then lays the fields out as a regular [`Form`]:
+//!
+//! | Rust type | Form field |
+//! |-----------|------------|
+//! | `bool` | checkbox |
+//! | integers | number, bounded by the integer type |
+//! | floats | text, parsed on submit |
+//! | `String`, `char` | text |
+//! | unit-only enums | select over the variant names |
+//! | `Option<T>` | checkbox toggle; the inner fields are disabled while off |
+//! | nested structs | a group whose labels are prefixed with the struct's label |
+//! | `Vec<T>` | an item-count number followed by one section per item |
+//!
+//! Serde attributes such as `rename` and `rename_all` are honoured because the
+//! schema is read through serde itself. Labels, bounds and validators come from
+//! [`FormSchema::field_attrs`], keyed by the dotted serde path of each field.
+//! List items use `*` as their path segment, so `hosts.*.port` addresses the
+//! port of every entry in `hosts`.
+//!
+//! Submitting runs the validators, converts the field values back into the
+//! type, and reports per-field errors through [`FormState::errors`], which the
+//! form renders with [`ValidationErrorDisplay`](ftui_widgets::ValidationErrorDisplay).
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use ftui_core::event::Event;
+use ftui_core::geometry::Rect;
+use ftui_render::frame::Frame;
+use ftui_widgets::StatefulWidget;
+use serde::Serialize;
+use serde::de::value::StrDeserializer;
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess, SeqAccess,
+    VariantAccess, Visitor,
+};
+use serde_json::{Map, Value};
+
+use super::{Form, FormField, FormState, ValidateFn, ValidationError};
+
+/// Deepest type nesting the tracer follows before assuming a recursive type.
+const MAX_DEPTH: usize = 32;
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// A type that can be edited through a [`SchemaForm`].
+///
+/// Implement [`field_attrs`](Self::field_attrs) to attach labels, bounds and
+/// validators to individual fields.
+pub trait FormSchema: Serialize + DeserializeOwned {
+    /// Attributes keyed by dotted serde path (e.g. `server.port`, `hosts.*.name`).
+    fn field_attrs() -> Vec<(&'static str, FieldAttrs)> {
+        Vec::new()
+    }
+}
+
+/// Shared custom validator.
+type CustomValidator = Rc<dyn Fn(&FormField) -> Option<String>>;
+
+/// Per-field metadata for [`SchemaForm`].
+#[derive(Clone, Default)]
+pub struct FieldAttrs {
+    label: Option<String>,
+    required: bool,
+    min: Option<i64>,
+    max: Option<i64>,
+    min_len: Option<usize>,
+    max_len: Option<usize>,
+    validators: Vec<CustomValidator>,
+}
+
+impl fmt::Debug for FieldAttrs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FieldAttrs")
+            .field("label", &self.label)
+            .field("required", &self.required)
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .field("min_len", &self.min_len)
+            .field("max_len", &self.max_len)
+            .field("validators", &self.validators.len())
+            .finish()
+    }
+}
+
+impl FieldAttrs {
+    /// Empty attributes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the label derived from the field name.
+    #[must_use]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Require a non-empty value (or at least one item for lists).
+    #[must_use]
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Bound a number field, or the item count of a list.
+    #[must_use]
+    pub fn range(mut self, min: i64, max: i64) -> Self {
+        self.min = Some(min);
+        self.max = Some(max);
+        self
+    }
+
+    /// Lower bound for a number field, or the item count of a list.
+    #[must_use]
+    pub fn min(mut self, min: i64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Upper bound for a number field, or the item count of a list.
+    #[must_use]
+    pub fn max(mut self, max: i64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Bound the length of a text field, in characters.
+    #[must_use]
+    pub fn length(mut self, min: usize, max: usize) -> Self {
+        self.min_len = Some(min);
+        self.max_len = Some(max);
+        self
+    }
+
+    /// Attach a custom validator. Returns `Some(message)` when invalid.
+    #[must_use]
+    pub fn validate(mut self, f: impl Fn(&FormField) -> Option<String> + 'static) -> Self {
+        self.validators.push(Rc::new(f));
+        self
+    }
+
+    /// Overlay `other` onto `self`; set values in `other` win.
+    fn merge(&mut self, other: FieldAttrs) {
+        if other.label.is_some() {
+            self.label = other.label;
+        }
+        self.required |= other.required;
+        self.min = other.min.or(self.min);
+        self.max = other.max.or(self.max);
+        self.min_len = other.min_len.or(self.min_len);
+        self.max_len = other.max_len.or(self.max_len);
+        self.validators.extend(other.validators);
+    }
+}
+
+/// Error raised when a type cannot be represented as a form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaError(String);
+
+impl SchemaError {
+    /// Human-readable description, prefixed with the offending field path.
+    pub fn message(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+/// A [`Form`] generated from a serde type.
+///
+/// Route events through [`handle_event`](Self::handle_event) rather than
+/// [`FormState::handle_event`] so toggles and list sections stay in sync and
+/// submission is checked against the type.
+pub struct SchemaForm<T> {
+    form: Form,
+    root: Node,
+    attrs: Vec<(String, FieldAttrs)>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: FormSchema> SchemaForm<T> {
+    /// Build a form pre-filled with `value`.
+    pub fn new(value: &T) -> Result<Self, SchemaError> {
+        let shape = trace::<T>()?;
+        if !matches!(shape, Shape::Struct(_)) {
+            return Err(SchemaError("top-level type must be a struct".into()));
+        }
+        let value = serde_json::to_value(value).map_err(|err| SchemaError(err.to_string()))?;
+        let mut fields = Vec::new();
+        let root = instantiate(&shape, &value, &mut fields);
+        let mut form = Self {
+            form: Form::new(fields),
+            root,
+            attrs: Vec::new(),
+            _marker: PhantomData,
+        };
+        for (path, attrs) in T::field_attrs() {
+            form.merge_attrs(path, attrs);
+        }
+        form.decorate();
+        Ok(form)
+    }
+
+    /// Add or extend the attributes of the field at `path`.
+    #[must_use]
+    pub fn attrs(mut self, path: &str, attrs: FieldAttrs) -> Self {
+        self.merge_attrs(path, attrs);
+        self.decorate();
+        self
+    }
+
+    /// The generated form, for styling and rendering.
+    pub fn form(&self) -> &Form {
+        &self.form
+    }
+
+    /// Mutable access to the generated form, for styling.
+    pub fn form_mut(&mut self) -> &mut Form {
+        &mut self.form
+    }
+
+    /// Index of the form field at `path`.
+    ///
+    /// Paths use concrete list indices (`hosts.1.name`). An `Option` path
+    /// resolves to its toggle and a list path to its item-count field.
+    pub fn field_index(&self, path: &str) -> Option<usize> {
+        locate(&self.root, &split_path(path), 0).map(|(_, offset)| offset)
+    }
+
+    /// Handle an event, then sync toggles, list sections and submission.
+    pub fn handle_event(&mut self, state: &mut FormState, event: &Event) -> bool {
+        let changed = state.handle_event(&mut self.form, event);
+        if changed {
+            self.sync(state);
+        }
+        if state.submitted
+            && let Err(errors) = self.value()
+        {
+            state.errors = errors;
+            state.submitted = false;
+        }
+        changed
+    }
+
+    /// Append a default item to the list at `path`.
+    pub fn add_item(&mut self, state: &mut FormState, path: &str) -> bool {
+        let Some((Node::List { items, .. }, offset)) = locate(&self.root, &split_path(path), 0)
+        else {
+            return false;
+        };
+        let count = items.len() as i64 + 1;
+        let Some(FormField::Number { value, max, .. }) = self.form.fields.get_mut(offset) else {
+            return false;
+        };
+        if max.is_some_and(|max| count > max) {
+            return false;
+        }
+        *value = count;
+        self.sync(state);
+        true
+    }
+
+    /// Remove item `index` from the list at `path`.
+    pub fn remove_item(&mut self, state: &mut FormState, path: &str, index: usize) -> bool {
+        let Some((node, offset)) = locate_mut(&mut self.root, &split_path(path), 0) else {
+            return false;
+        };
+        let Node::List { items, .. } = node else {
+            return false;
+        };
+        if index >= items.len() {
+            return false;
+        }
+        let start = offset + 1 + items[..index].iter().map(Node::len).sum::<usize>();
+        let end = start + items[index].len();
+        items.remove(index);
+        let count = items.len() as i64;
+        self.form.fields.drain(start..end);
+        if let Some(FormField::Number { value, .. }) = self.form.fields.get_mut(offset) {
+            *value = count;
+        }
+        self.decorate();
+        self.structure_changed(state);
+        true
+    }
+
+    /// Validate the fields and convert them back into `T`.
+    ///
+    /// Errors raised by `T`'s own `Deserialize` impl cannot be traced to a
+    /// field and are reported against the first field.
+    pub fn value(&self) -> Result<T, Vec<ValidationError>> {
+        let mut errors = self.form.validate_all();
+        let mut index = 0;
+        let value = collect(&self.root, &self.form.fields, &mut index, true, &mut errors);
+        if !errors.is_empty() {
+            errors.sort_by_key(|err| err.field);
+            return Err(errors);
+        }
+        serde_json::from_value(value).map_err(|err| {
+            vec![ValidationError {
+                field: 0,
+                message: err.to_string(),
+            }]
+        })
+    }
+
+    fn merge_attrs(&mut self, path: &str, attrs: FieldAttrs) {
+        match self.attrs.iter_mut().find(|(p, _)| p == path) {
+            Some((_, existing)) => existing.merge(attrs),
+            None => self.attrs.push((path.to_string(), attrs)),
+        }
+    }
+
+    /// Apply list resizes requested through count fields, then refresh labels,
+    /// validators and disabled flags.
+    fn sync(&mut self, state: &mut FormState) {
+        let mut resized = false;
+        loop {
+            let mut offset = 0;
+            if !resize_lists(&mut self.root, &mut self.form.fields, &mut offset) {
+                break;
+            }
+            resized = true;
+        }
+        self.decorate();
+        if resized {
+            self.structure_changed(state);
+        }
+    }
+
+    /// Re-baseline state that is indexed by field position.
+    fn structure_changed(&self, state: &mut FormState) {
+        state.errors.clear();
+        let count = self.form.field_count();
+        if state.focused >= count {
+            state.focused = count.saturating_sub(1);
+        }
+        if state.initial_values.is_some() {
+            state.init_tracking(&self.form);
+        }
+        state.sync_text_cursor(&self.form);
+    }
+
+    fn decorate(&mut self) {
+        let count = self.form.fields.len();
+        self.form.validators = (0..count).map(|_| None).collect();
+        self.form.required = vec![false; count];
+        self.form.disabled = vec![false; count];
+        let mut decorator = Decorator {
+            form: &mut self.form,
+            attrs: &self.attrs,
+            index: 0,
+        };
+        let mut path = Vec::new();
+        decorator.node(&self.root, &mut path, "", true);
+    }
+}
+
+impl<T> StatefulWidget for SchemaForm<T> {
+    type State = FormState;
+
+    fn render(&self, area: Rect, frame: &mut Frame, state: &mut Self::State) {
+        self.form.render(area, frame, state);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Shapes and nodes
+// ---------------------------------------------------------------------------
+
+/// Scalar kinds that map onto a single form field.
+#[derive(Debug, Clone, PartialEq)]
+enum Leaf {
+    Bool,
+    Int { min: i64, max: i64 },
+    Float,
+    Text,
+    Char,
+    Enum(Vec<&'static str>),
+}
+
+/// The traced structure of a type.
+#[derive(Debug, Clone, PartialEq)]
+enum Shape {
+    Leaf(Leaf),
+    Option(Box<Shape>),
+    Seq(Box<Shape>),
+    Struct(Vec<(&'static str, Shape)>),
+}
+
+impl Shape {
+    /// Value used for absent options and newly added list items.
+    fn sample(&self) -> Value {
+        match self {
+            Self::Leaf(Leaf::Bool) => Value::Bool(false),
+            Self::Leaf(Leaf::Int { min, max }) => Value::from(0i64.clamp(*min, *max)),
+            Self::Leaf(Leaf::Float) => Value::from(0.0),
+            Self::Leaf(Leaf::Text) => Value::from(""),
+            Self::Leaf(Leaf::Char) => Value::from(" "),
+            Self::Leaf(Leaf::Enum(variants)) => Value::from(variants[0]),
+            Self::Option(_) => Value::Null,
+            Self::Seq(_) => Value::Array(Vec::new()),
+            Self::Struct(fields) => Value::Object(
+                fields
+                    .iter()
+                    .map(|(name, shape)| ((*name).to_string(), shape.sample()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// A shape instantiated for a concrete value; mirrors the form field layout.
+#[derive(Debug, Clone)]
+enum Node {
+    Leaf(Leaf),
+    /// Checkbox followed by the inner fields.
+    Toggle(Box<Node>),
+    Group(Vec<(&'static str, Node)>),
+    /// Item-count field followed by each item's fields.
+    List {
+        element: Shape,
+        items: Vec<Node>,
+    },
+}
+
+impl Node {
+    /// Number of form fields this node occupies.
+    fn len(&self) -> usize {
+        match self {
+            Self::Leaf(_) => 1,
+            Self::Toggle(inner) => 1 + inner.len(),
+            Self::Group(children) => children.iter().map(|(_, node)| node.len()).sum(),
+            Self::List { items, .. } => 1 + items.iter().map(Node::len).sum::<usize>(),
+        }
+    }
+}
+
+/// Build the node for `value`, appending its fields (with empty labels).
+fn instantiate(shape: &Shape, value: &Value, fields: &mut Vec<FormField>) -> Node {
+    match shape {
+        Shape::Leaf(leaf) => {
+            fields.push(leaf_field(leaf, value));
+            Node::Leaf(leaf.clone())
+        }
+        Shape::Option(inner) => {
+            fields.push(FormField::checkbox("", !value.is_null()));
+            let node = if value.is_null() {
+                instantiate(inner, &inner.sample(), fields)
+            } else {
+                instantiate(inner, value, fields)
+            };
+            Node::Toggle(Box::new(node))
+        }
+        Shape::Seq(element) => {
+            let values = value.as_array().map(Vec::as_slice).unwrap_or_default();
+            fields.push(FormField::number("", values.len() as i64));
+            let items = values
+                .iter()
+                .map(|item| instantiate(element, item, fields))
+                .collect();
+            Node::List {
+                element: (**element).clone(),
+                items,
+            }
+        }
+        Shape::Struct(members) => Node::Group(
+            members
+                .iter()
+                .map(|(name, shape)| {
+                    let node = match value.get(*name) {
+                        Some(v) => instantiate(shape, v, fields),
+                        None => instantiate(shape, &shape.sample(), fields),
+                    };
+                    (*name, node)
+                })
+                .collect(),
+        ),
+    }
+}
+
+fn leaf_field(leaf: &Leaf, value: &Value) -> FormField {
+    match leaf {
+        Leaf::Bool => FormField::checkbox("", value.as_bool().unwrap_or(false)),
+        Leaf::Int { min, max } => {
+            let n = value
+                .as_i64()
+                .or_else(|| value.as_u64().map(|_| i64::MAX))
+                .unwrap_or(0);
+            FormField::number_bounded("", n, *min, *max)
+        }
+        Leaf::Float => FormField::text_with_value(
+            "",
+            value.as_f64().map(|f| f.to_string()).unwrap_or_default(),
+        ),
+        Leaf::Text | Leaf::Char => FormField::text_with_value("", value.as_str().unwrap_or("")),
+        Leaf::Enum(variants) => {
+            let selected = value
+                .as_str()
+                .and_then(|name| variants.iter().position(|v| *v == name))
+                .unwrap_or(0);
+            FormField::Select {
+                label: String::new(),
+                options: variants.iter().map(|v| (*v).to_string()).collect(),
+                selected,
+            }
+        }
+    }
+}
+
+/// Grow or shrink the first list whose count field disagrees with its items.
+/// Returns `true` if the field layout changed.
+fn resize_lists(node: &mut Node, fields: &mut Vec<FormField>, offset: &mut usize) -> bool {
+    match node {
+        Node::Leaf(_) => {
+            *offset += 1;
+            false
+        }
+        Node::Toggle(inner) => {
+            *offset += 1;
+            resize_lists(inner, fields, offset)
+        }
+        Node::Group(children) => children
+            .iter_mut()
+            .any(|(_, child)| resize_lists(child, fields, offset)),
+        Node::List { element, items } => {
+            let count_at = *offset;
+            *offset += 1;
+            let wanted = match fields.get(count_at) {
+                Some(FormField::Number { value, .. }) => (*value).max(0) as usize,
+                _ => items.len(),
+            };
+            let end = *offset + items.iter().map(Node::len).sum::<usize>();
+            if wanted > items.len() {
+                let mut added = Vec::new();
+                let sample = element.sample();
+                for _ in items.len()..wanted {
+                    items.push(instantiate(element, &sample, &mut added));
+                }
+                fields.splice(end..end, added);
+                return true;
+            }
+            if wanted < items.len() {
+                let start = *offset + items[..wanted].iter().map(Node::len).sum::<usize>();
+                fields.drain(start..end);
+                items.truncate(wanted);
+                return true;
+            }
+            items
+                .iter_mut()
+                .any(|item| resize_lists(item, fields, offset))
+        }
+    }
+}
+
+fn split_path(path: &str) -> Vec<&str> {
+    path.split('.').filter(|seg| !seg.is_empty()).collect()
+}
+
+/// Find the node at `path` and the index of its first field.
+fn locate<'n>(node: &'n Node, path: &[&str], offset: usize) -> Option<(&'n Node, usize)> {
+    let Some((seg, rest)) = path.split_first() else {
+        return Some((node, offset));
+    };
+    match node {
+        Node::Leaf(_) => None,
+        Node::Toggle(inner) => locate(inner, path, offset + 1),
+        Node::Group(children) => {
+            let mut offset = offset;
+            for (name, child) in children {
+                if name == seg {
+                    return locate(child, rest, offset);
+                }
+                offset += child.len();
+            }
+            None
+        }
+        Node::List { items, .. } => {
+            let index: usize = seg.parse().ok()?;
+            let item = items.get(index)?;
+            let offset = offset + 1 + items[..index].iter().map(Node::len).sum::<usize>();
+            locate(item, rest, offset)
+        }
+    }
+}
+
+fn locate_mut<'n>(
+    node: &'n mut Node,
+    path: &[&str],
+    offset: usize,
+) -> Option<(&'n mut Node, usize)> {
+    let Some((seg, rest)) = path.split_first() else {
+        return Some((node, offset));
+    };
+    match node {
+        Node::Leaf(_) => None,
+        Node::Toggle(inner) => locate_mut(inner, path, offset + 1),
+        Node::Group(children) => {
+            let mut offset = offset;
+            for (name, child) in children {
+                if name == seg {
+                    return locate_mut(child, rest, offset);
+                }
+                offset += child.len();
+            }
+            None
+        }
+        Node::List { items, .. } => {
+            let index: usize = seg.parse().ok()?;
+            let offset = offset + 1 + items.get(..index)?.iter().map(Node::len).sum::<usize>();
+            locate_mut(items.get_mut(index)?, rest, offset)
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Labels, validators and disabled flags
+// ---------------------------------------------------------------------------
+
+struct Decorator<'a> {
+    form: &'a mut Form,
+    attrs: &'a [(String, FieldAttrs)],
+    index: usize,
+}
+
+impl Decorator<'_> {
+    fn attrs(&self, path: &[&str]) -> Option<&FieldAttrs> {
+        let key = path.join(".");
+        self.attrs.iter().find(|(p, _)| *p == key).map(|(_, a)| a)
+    }
+
+    fn node(&mut self, node: &Node, path: &mut Vec<&'static str>, label: &str, enabled: bool) {
+        match node {
+            Node::Leaf(leaf) => self.leaf(leaf, path, label, enabled),
+            Node::Toggle(inner) => {
+                let i = self.next_field(label, enabled);
+                let checked = matches!(
+                    self.form.fields.get(i),
+                    Some(FormField::Checkbox { checked: true, .. })
+                );
+                let inner_label = match **inner {
+                    Node::Group(_) => label.to_string(),
+                    _ => format!("{label} value"),
+                };
+                self.node(inner, path, &inner_label, enabled && checked);
+            }
+            Node::Group(children) => {
+                for (name, child) in children {
+                    path.push(name);
+                    let own = self
+                        .attrs(path)
+                        .and_then(|a| a.label.clone())
+                        .unwrap_or_else(|| humanize(name));
+                    let child_label = if label.is_empty() {
+                        own
+                    } else {
+                        format!("{label} \u{203a} {own}")
+                    };
+                    self.node(child, path, &child_label, enabled);
+                    path.pop();
+                }
+            }
+            Node::List { items, .. } => {
+                let attrs = self.attrs(path);
+                let min = attrs
+                    .and_then(|a| a.min.or(a.required.then_some(1)))
+                    .unwrap_or(0)
+                    .max(0);
+                let max = attrs.and_then(|a| a.max);
+                let required = attrs.is_some_and(|a| a.required);
+                let i = self.next_field(label, enabled);
+                self.form.required[i] = required;
+                if let Some(FormField::Number {
+                    value,
+                    min: field_min,
+                    max: field_max,
+                    ..
+                }) = self.form.fields.get_mut(i)
+                {
+                    *value = items.len() as i64;
+                    *field_min = Some(min);
+                    *field_max = max;
+                }
+                path.push("*");
+                for (n, item) in items.iter().enumerate() {
+                    self.node(item, path, &format!("{label} #{}", n + 1), enabled);
+                }
+                path.pop();
+            }
+        }
+    }
+
+    fn leaf(&mut self, leaf: &Leaf, path: &[&str], label: &str, enabled: bool) {
+        let attrs = self.attrs(path).cloned().unwrap_or_default();
+        let i = self.next_field(label, enabled);
+        self.form.required[i] = attrs.required;
+        if let (
+            Leaf::Int { min, max },
+            Some(FormField::Number {
+                value,
+                min: field_min,
+                max: field_max,
+                ..
+            }),
+        ) = (leaf, self.form.fields.get_mut(i))
+        {
+            let lo = attrs.min.map_or(*min, |m| m.clamp(*min, *max));
+            let hi = attrs.max.map_or(*max, |m| m.clamp(lo, *max));
+            *field_min = Some(lo);
+            *field_max = Some(hi);
+            *value = (*value).clamp(lo, hi);
+        }
+        self.form.validators[i] = leaf_validator(leaf, attrs);
+    }
+
+    /// Label the next field and set its disabled flag; returns its index.
+    fn next_field(&mut self, label: &str, enabled: bool) -> usize {
+        let i = self.index;
+        self.index += 1;
+        if let Some(field) = self.form.fields.get_mut(i) {
+            *label_mut(field) = label.to_string();
+        }
+        self.form.disabled[i] = !enabled;
+        i
+    }
+}
+
+fn label_mut(field: &mut FormField) -> &mut String {
+    match field {
+        FormField::Text { label, .. }
+        | FormField::Checkbox { label, .. }
+        | FormField::Radio { label, .. }
+        | FormField::Select { label, .. }
+        | FormField::Number { label, .. }
+        | FormField::Date { label, .. }
+        | FormField::Time { label, .. } => label,
+    }
+}
+
+fn leaf_validator(leaf: &Leaf, attrs: FieldAttrs) -> Option<ValidateFn> {
+    let is_text = matches!(leaf, Leaf::Text | Leaf::Float | Leaf::Char);
+    let parse_check = matches!(leaf, Leaf::Float | Leaf::Char);
+    let length_check = attrs.min_len.is_some() || attrs.max_len.is_some();
+    if !(attrs.required && is_text) && !parse_check && !length_check && attrs.validators.is_empty()
+    {
+        return None;
+    }
+    let leaf = leaf.clone();
+    Some(Box::new(move |field: &FormField| {
+        if let FormField::Text { value, .. } = field {
+            let trimmed = value.trim();
+            if attrs.required && trimmed.is_empty() {
+                return Some("This field is required".into());
+            }
+            let chars = value.chars().count();
+            if let Some(min) = attrs.min_len
+                && chars < min
+            {
+                return Some(format!("Must be at least {min} characters"));
+            }
+            if let Some(max) = attrs.max_len
+                && chars > max
+            {
+                return Some(format!("Must be at most {max} characters"));
+            }
+            if let Err(message) = parse_leaf(&leaf, value) {
+                return Some(message);
+            }
+        }
+        attrs.validators.iter().find_map(|v| v(field))
+    }))
+}
+
+/// Convert a text field value for a text-backed leaf.
+fn parse_leaf(leaf: &Leaf, text: &str) -> Result<Value, String> {
+    match leaf {
+        Leaf::Float => text
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number)
+            .ok_or_else(|| "Expected a number".to_string()),
+        Leaf::Char => {
+            let mut chars = text.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(Value::from(c.to_string())),
+                _ => Err("Expected a single character".to_string()),
+            }
+        }
+        _ => Ok(Value::from(text)),
+    }
+}
+
+/// `max_connections` / `maxConnections` / `max-connections` -> `Max connections`.
+fn humanize(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    let mut prev_lower = false;
+    for c in name.chars() {
+        if c == '_' || c == '-' {
+            if !out.is_empty() && !out.ends_with(' ') {
+                out.push(' ');
+            }
+            prev_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && prev_lower {
+            out.push(' ');
+        }
+        if out.is_empty() {
+            out.extend(c.to_uppercase());
+        } else {
+            out.extend(c.to_lowercase());
+        }
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+    }
+    out.trim_end().to_string()
+}
+
+// ---------------------------------------------------------------------------
+// Collecting submitted values
+// ---------------------------------------------------------------------------
+
+/// Convert the fields under `node` into a JSON value. Conversion errors are
+/// recorded only when `report` is set and the field has no error yet.
+fn collect(
+    node: &Node,
+    fields: &[FormField],
+    index: &mut usize,
+    report: bool,
+    errors: &mut Vec<ValidationError>,
+) -> Value {
+    match node {
+        Node::Leaf(leaf) => {
+            let i = *index;
+            *index += 1;
+            let Some(field) = fields.get(i) else {
+                return Value::Null;
+            };
+            match (leaf, field) {
+                (Leaf::Bool, FormField::Checkbox { checked, .. }) => Value::Bool(*checked),
+                (Leaf::Int { .. }, FormField::Number { value, .. }) => Value::from(*value),
+                (Leaf::Enum(variants), FormField::Select { selected, .. }) => {
+                    Value::from(variants.get(*selected).copied().unwrap_or_default())
+                }
+                (_, FormField::Text { value, .. }) => match parse_leaf(leaf, value) {
+                    Ok(value) => value,
+                    Err(message) => {
+                        if report && !errors.iter().any(|e| e.field == i) {
+                            errors.push(ValidationError { field: i, message });
+                        }
+                        Value::Null
+                    }
+                },
+                _ => Value::Null,
+            }
+        }
+        Node::Toggle(inner) => {
+            let checked = matches!(
+                fields.get(*index),
+                Some(FormField::Checkbox { checked: true, .. })
+            );
+            *index += 1;
+            let value = collect(inner, fields, index, report && checked, errors);
+            if checked { value } else { Value::Null }
+        }
+        Node::Group(children) => {
+            let mut map = Map::new();
+            for (name, child) in children {
+                map.insert(
+                    (*name).to_string(),
+                    collect(child, fields, index, report, errors),
+                );
+            }
+            Value::Object(map)
+        }
+        Node::List { items, .. } => {
+            *index += 1;
+            Value::Array(
+                items
+                    .iter()
+                    .map(|item| collect(item, fields, index, report, errors))
+                    .collect(),
+            )
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tracing deserializer
+// ---------------------------------------------------------------------------
+
+/// Discover the shape of `T` by driving its `Deserialize` impl.
+fn trace<T: DeserializeOwned>() -> Result<Shape, SchemaError> {
+    let mut slot = None;
+    T::deserialize(Tracer {
+        slot: &mut slot,
+        depth: 0,
+    })
+    .map_err(|err| SchemaError(err.0))?;
+    slot.ok_or_else(|| SchemaError("type did not describe its shape".into()))
+}
+
+#[derive(Debug)]
+struct TraceError(String);
+
+impl fmt::Display for TraceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for TraceError {}
+
+impl de::Error for TraceError {
+    fn custom<M: fmt::Display>(msg: M) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+fn unsupported(what: &str) -> TraceError {
+    TraceError(format!("{what} are not supported in forms"))
+}
+
+/// Deserializer that records the requested shape and yields sample values.
+struct Tracer<'a> {
+    slot: &'a mut Option<Shape>,
+    depth: usize,
+}
+
+impl Tracer<'_> {
+    fn nested_depth(&self) -> Result<usize, TraceError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(TraceError(format!(
+                "type nesting exceeds {MAX_DEPTH} levels (recursive type?)"
+            )));
+        }
+        Ok(self.depth + 1)
+    }
+
+    fn leaf(self, leaf: Leaf) {
+        *self.slot = Some(Shape::Leaf(leaf));
+    }
+}
+
+macro_rules! trace_int {
+    ($($method:ident => $visit:ident: $ty:ty),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                self.leaf(Leaf::Int {
+                    min: i64::try_from(<$ty>::MIN).unwrap_or(i64::MIN),
+                    max: i64::try_from(<$ty>::MAX).unwrap_or(i64::MAX),
+                });
+                visitor.$visit(0)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Tracer<'_> {
+    type Error = TraceError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(unsupported(
+            "self-describing types (untagged enums, flatten)",
+        ))
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.leaf(Leaf::Bool);
+        visitor.visit_bool(false)
+    }
+
+    trace_int! {
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_i128 => visit_i128: i128,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_u128 => visit_u128: u128,
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.leaf(Leaf::Float);
+        visitor.visit_f32(0.0)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.leaf(Leaf::Float);
+        visitor.visit_f64(0.0)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.leaf(Leaf::Char);
+        visitor.visit_char(' ')
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.leaf(Leaf::Text);
+        visitor.visit_str("")
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.leaf(Leaf::Text);
+        visitor.visit_string(String::new())
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(unsupported("byte strings"))
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(unsupported("byte strings"))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let depth = self.nested_depth()?;
+        let mut inner = None;
+        let value = visitor.visit_some(Tracer {
+            slot: &mut inner,
+            depth,
+        })?;
+        let inner = inner.ok_or_else(|| unsupported("opaque option contents"))?;
+        *self.slot = Some(Shape::Option(Box::new(inner)));
+        Ok(value)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(unsupported("unit values"))
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(unsupported("unit structs"))
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let depth = self.nested_depth()?;
+        visitor.visit_newtype_struct(Tracer {
+            slot: self.slot,
+            depth,
+        })
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let mut access = SeqTrace {
+            element: None,
+            done: false,
+            depth: self.nested_depth()?,
+        };
+        let value = visitor.visit_seq(&mut access)?;
+        let element = access
+            .element
+            .ok_or_else(|| unsupported("sequences without elements"))?;
+        *self.slot = Some(Shape::Seq(Box::new(element)));
+        Ok(value)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(unsupported("tuples and arrays"))
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(unsupported("tuple structs"))
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(unsupported("maps"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let mut access = StructTrace {
+            fields,
+            index: 0,
+            shapes: Vec::with_capacity(fields.len()),
+            depth: self.nested_depth()?,
+        };
+        let value = visitor.visit_map(&mut access)?;
+        *self.slot = Some(Shape::Struct(access.shapes));
+        Ok(value)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let first = *variants
+            .first()
+            .ok_or_else(|| unsupported("enums without variants"))?;
+        self.leaf(Leaf::Enum(variants.to_vec()));
+        visitor.visit_enum(UnitVariant(first))
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+}
+
+/// Yields every declared field once, tracing each value.
+struct StructTrace {
+    fields: &'static [&'static str],
+    index: usize,
+    shapes: Vec<(&'static str, Shape)>,
+    depth: usize,
+}
+
+impl<'de> MapAccess<'de> for StructTrace {
+    type Error = TraceError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let Some(&name) = self.fields.get(self.index) else {
+            return Ok(None);
+        };
+        let key: StrDeserializer<'_, TraceError> = name.into_deserializer();
+        seed.deserialize(key).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let name = self.fields[self.index];
+        self.index += 1;
+        let mut slot = None;
+        let value = seed
+            .deserialize(Tracer {
+                slot: &mut slot,
+                depth: self.depth,
+            })
+            .map_err(|err| TraceError(prefix_path(name, &err.0)))?;
+        let shape = slot.ok_or_else(|| TraceError(prefix_path(name, "opaque field type")))?;
+        self.shapes.push((name, shape));
+        Ok(value)
+    }
+}
+
+fn prefix_path(name: &str, message: &str) -> String {
+    match message.strip_prefix('`') {
+        Some(rest) => format!("`{name}.{rest}"),
+        None => format!("`{name}`: {message}"),
+    }
+}
+
+/// Yields a single traced element.
+struct SeqTrace {
+    element: Option<Shape>,
+    done: bool,
+    depth: usize,
+}
+
+impl<'de> SeqAccess<'de> for SeqTrace {
+    type Error = TraceError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        if self.done {
+            return Ok(None);
+        }
+        self.done = true;
+        seed.deserialize(Tracer {
+            slot: &mut self.element,
+            depth: self.depth,
+        })
+        .map(Some)
+    }
+}
+
+/// Selects the first variant of an enum, which must be a unit variant.
+struct UnitVariant(&'static str);
+
+impl<'de> EnumAccess<'de> for UnitVariant {
+    type Error = TraceError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Self::Error> {
+        let name: StrDeserializer<'_, TraceError> = self.0.into_deserializer();
+        Ok((seed.deserialize(name)?, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for UnitVariant {
+    type Error = TraceError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        _seed: T,
+    ) -> Result<T::Value, Self::Error> {
+        Err(unsupported("enum variants with data"))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(unsupported("enum variants with data"))
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        Err(unsupported("enum variants with data"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_core::event::{KeyCode, KeyEvent, KeyEventKind, Modifiers};
+    use serde::Deserialize;
+
+    fn press(code: KeyCode) -> Event {
+        Event::Key(KeyEvent {
+            code,
+            modifiers: Modifiers::empty(),
+            kind: KeyEventKind::Press,
+        })
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum Level {
+        Debug,
+        Info,
+        Warn,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Host {
+        name: String,
+        port: u16,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Proxy {
+        url: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Config {
+        app_name: String,
+        verbose: bool,
+        ratio: f64,
+        level: Level,
+        timeout: Option<u32>,
+        proxy: Option<Proxy>,
+        hosts: Vec<Host>,
+    }
+
+    impl FormSchema for Config {
+        fn field_attrs() -> Vec<(&'static str, FieldAttrs)> {
+            vec![
+                ("appName", FieldAttrs::new().label("Name").required()),
+                ("hosts", FieldAttrs::new().max(3)),
+                ("hosts.*.port", FieldAttrs::new().range(1, 9999)),
+            ]
+        }
+    }
+
+    fn config() -> Config {
+        Config {
+            app_name: "demo".into(),
+            verbose: true,
+            ratio: 0.5,
+            level: Level::Info,
+            timeout: None,
+            proxy: Some(Proxy {
+                url: "http://proxy".into(),
+            }),
+            hosts: vec![Host {
+                name: "a".into(),
+                port: 80,
+            }],
+        }
+    }
+
+    fn labels<T: FormSchema>(form: &SchemaForm<T>) -> Vec<String> {
+        (0..form.form().field_count())
+            .map(|i| form.form().field(i).unwrap().label().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn maps_fields_to_form_fields() {
+        let form = SchemaForm::new(&config()).unwrap();
+        assert_eq!(
+            labels(&form),
+            [
+                "Name",
+                "Verbose",
+                "Ratio",
+                "Level",
+                "Timeout",
+                "Timeout value",
+                "Proxy",
+                "Proxy \u{203a} Url",
+                "Hosts",
+                "Hosts #1 \u{203a} Name",
+                "Hosts #1 \u{203a} Port",
+            ]
+        );
+        let f = form.form();
+        assert!(matches!(
+            f.field(1),
+            Some(FormField::Checkbox { checked: true, .. })
+        ));
+        assert!(matches!(f.field(2), Some(FormField::Text { value, .. }) if value == "0.5"));
+        assert!(matches!(
+            f.field(3),
+            Some(FormField::Select { options, selected: 1, .. }) if options.len() == 3
+        ));
+        assert!(f.is_required(0));
+        assert!(f.is_disabled(5), "unset option disables its value");
+        assert!(!f.is_disabled(7));
+        assert!(matches!(
+            f.field(10),
+            Some(FormField::Number {
+                value: 80,
+                min: Some(1),
+                max: Some(9999),
+                ..
+            })
+        ));
+        assert!(matches!(
+            f.field(8),
+            Some(FormField::Number {
+                value: 1,
+                max: Some(3),
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn round_trips_unchanged_value() {
+        let form = SchemaForm::new(&config()).unwrap();
+        assert_eq!(form.value().unwrap(), config());
+    }
+
+    #[test]
+    fn edits_flow_back_into_the_struct() {
+        let mut form = SchemaForm::new(&config()).unwrap();
+        let mut state = FormState::default();
+        let timeout = form.field_index("timeout").unwrap();
+        state.focused = timeout;
+        form.handle_event(&mut state, &press(KeyCode::Char(' ')));
+        assert!(!form.form().is_disabled(timeout + 1));
+        if let Some(FormField::Number { value, .. }) = form.form_mut().field_mut(timeout + 1) {
+            *value = 30;
+        }
+        state.focused = form.field_index("proxy").unwrap();
+        form.handle_event(&mut state, &press(KeyCode::Char(' ')));
+
+        let value = form.value().unwrap();
+        assert_eq!(value.timeout, Some(30));
+        assert_eq!(value.proxy, None);
+    }
+
+    #[test]
+    fn list_count_field_adds_and_removes_items() {
+        let mut form = SchemaForm::new(&config()).unwrap();
+        let mut state = FormState {
+            focused: form.field_index("hosts").unwrap(),
+            ..Default::default()
+        };
+        form.handle_event(&mut state, &press(KeyCode::Right));
+        assert_eq!(form.form().field_count(), 13);
+        assert_eq!(labels(&form)[12], "Hosts #2 \u{203a} Port");
+        // New items start at the lowest allowed value.
+        assert!(matches!(
+            form.form().field(12),
+            Some(FormField::Number { value: 1, .. })
+        ));
+        assert_eq!(form.value().unwrap().hosts.len(), 2);
+
+        assert!(form.remove_item(&mut state, "hosts", 0));
+        assert_eq!(form.form().field_count(), 11);
+        assert_eq!(form.value().unwrap().hosts[0].name, "");
+
+        assert!(form.add_item(&mut state, "hosts"));
+        assert!(form.add_item(&mut state, "hosts"));
+        assert!(!form.add_item(&mut state, "hosts"), "max of 3 items");
+    }
+
+    #[test]
+    fn submit_reports_per_field_errors() {
+        let mut form = SchemaForm::new(&config()).unwrap();
+        let mut state = FormState::default();
+        if let Some(FormField::Text { value, .. }) = form.form_mut().field_mut(0) {
+            value.clear();
+        }
+        if let Some(FormField::Text { value, .. }) = form.form_mut().field_mut(2) {
+            *value = "half".into();
+        }
+        form.handle_event(&mut state, &press(KeyCode::Enter));
+        assert!(!state.submitted);
+        let messages: Vec<_> = state
+            .errors
+            .iter()
+            .map(|e| (e.field, e.message.as_str()))
+            .collect();
+        assert_eq!(
+            messages,
+            [(0, "This field is required"), (2, "Expected a number")]
+        );
+
+        if let Some(FormField::Text { value, .. }) = form.form_mut().field_mut(0) {
+            *value = "ok".into();
+        }
+        if let Some(FormField::Text { value, .. }) = form.form_mut().field_mut(2) {
+            *value = "2.5".into();
+        }
+        form.handle_event(&mut state, &press(KeyCode::Enter));
+        assert!(state.submitted);
+        assert_eq!(form.value().unwrap().ratio, 2.5);
+    }
+
+    #[test]
+    fn custom_validators_run_on_submit() {
+        let form = SchemaForm::new(&config()).unwrap().attrs(
+            "hosts.*.name",
+            FieldAttrs::new().validate(|field| match field {
+                FormField::Text { value, .. } if value.contains(' ') => {
+                    Some("No spaces".to_string())
+                }
+                _ => None,
+            }),
+        );
+        let mut form = form;
+        let name = form.field_index("hosts.0.name").unwrap();
+        if let Some(FormField::Text { value, .. }) = form.form_mut().field_mut(name) {
+            *value = "a b".into();
+        }
+        let errors = form.value().unwrap_err();
+        assert_eq!(errors[0].field, name);
+        assert_eq!(errors[0].message, "No spaces");
+    }
+
+    #[test]
+    fn unsupported_types_are_rejected() {
+        #[derive(Serialize, Deserialize)]
+        struct WithMap {
+            inner: Inner,
+        }
+        #[derive(Serialize, Deserialize)]
+        struct Inner {
+            map: std::collections::BTreeMap<String, String>,
+        }
+        impl FormSchema for WithMap {}
+
+        let err = SchemaForm::new(&WithMap {
+            inner: Inner {
+                map: Default::default(),
+            },
+        })
+        .err()
+        .unwrap();
+        assert_eq!(
+            err.message(),
+            "`inner.map`: maps are not supported in forms"
+        );
+    }
+
+    #[test]
+    fn recursive_types_are_rejected() {
+        #[derive(Serialize, Deserialize)]
+        struct Tree {
+            child: Option<Box<Tree>>,
+        }
+        impl FormSchema for Tree {}
+
+        let err = SchemaForm::new(&Tree { child: None }).err().unwrap();
+        assert!(err.message().contains("recursive"), "{err}");
+    }
+
+    #[test]
+    fn humanize_field_names() {
+        assert_eq!(humanize("max_connections"), "Max connections");
+        assert_eq!(humanize("maxConnections"), "Max connections");
+        assert_eq!(humanize("max-connections"), "Max connections");
+        assert_eq!(humanize("url"), "Url");
+    }
+}
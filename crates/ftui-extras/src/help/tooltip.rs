@@ -20,6 +20,7 @@
 //! ```
 
 use ftui_core::geometry::{Rect, Size};
+use ftui_layout::{AnchorAlign, AnchorPlacement, AnchorSide, FlipStrategy, Placement};
 use ftui_render::cell::CellContent;
 use ftui_render::frame::Frame;
 use ftui_style::Style;
@@ -276,83 +277,53 @@ impl Tooltip {
         Size::new(width as u16, height as u16)
     }
 
-    /// Calculate optimal position for the tooltip, avoiding screen edges.
+    /// Calculate optimal placement for the tooltip, avoiding screen edges.
     ///
     /// Decision rule (for `Auto`):
     /// 1. Try below target (most natural reading position)
     /// 2. Try above if no space below
     /// 3. Try right if no vertical space
     /// 4. Try left as last resort
-    /// 5. If still doesn't fit, clamp to screen bounds
+    /// 5. If still doesn't fit, use the side with the most room, shrunk and
+    ///    shifted to stay within the screen
     ///
-    /// Returns (x, y) position.
+    /// Explicit positions never flip; they are shrunk and shifted instead.
+    fn placement(&self, screen: Rect) -> Placement {
+        let (side, flip) = match self.config.position {
+            TooltipPosition::Auto => (AnchorSide::Bottom, FlipStrategy::Any),
+            TooltipPosition::Below => (AnchorSide::Bottom, FlipStrategy::Never),
+            TooltipPosition::Above => (AnchorSide::Top, FlipStrategy::Never),
+            TooltipPosition::Right => (AnchorSide::Right, FlipStrategy::Never),
+            TooltipPosition::Left => (AnchorSide::Left, FlipStrategy::Never),
+        };
+        AnchorPlacement::new(side)
+            .align(AnchorAlign::Start)
+            .gap(1)
+            .flip(flip)
+            .solve(self.target_bounds, self.content_size(), screen)
+    }
+
+    /// Top-left (x, y) position of [`bounds`](Self::bounds).
+    #[cfg(test)]
     fn calculate_position(&self, screen: Rect) -> (u16, u16) {
         let size = self.content_size();
         if size.width == 0 || size.height == 0 {
             return (self.target_bounds.x, self.target_bounds.y);
         }
-
-        let target = self.target_bounds;
-        let gap = 1u16; // Gap between tooltip and target
-
-        // Helper to check if position fits
-        let fits = |x: i32, y: i32| -> bool {
-            x >= screen.x as i32
-                && y >= screen.y as i32
-                && x + size.width as i32 <= screen.right() as i32
-                && y + size.height as i32 <= screen.bottom() as i32
-        };
-
-        // Calculate positions for each strategy
-        let below = (target.x as i32, target.bottom() as i32 + gap as i32);
-        let above = (
-            target.x as i32,
-            target.y as i32 - size.height as i32 - gap as i32,
-        );
-        let right = (target.right() as i32 + gap as i32, target.y as i32);
-        let left = (
-            target.x as i32 - size.width as i32 - gap as i32,
-            target.y as i32,
-        );
-
-        let (x, y) = match self.config.position {
-            TooltipPosition::Auto => {
-                if fits(below.0, below.1) {
-                    below
-                } else if fits(above.0, above.1) {
-                    above
-                } else if fits(right.0, right.1) {
-                    right
-                } else if fits(left.0, left.1) {
-                    left
-                } else {
-                    // Doesn't fit anywhere; use below and clamp
-                    below
-                }
-            }
-            TooltipPosition::Below => below,
-            TooltipPosition::Above => above,
-            TooltipPosition::Right => right,
-            TooltipPosition::Left => left,
-        };
-
-        // Clamp to screen bounds
-        let clamped_x = x
-            .max(screen.x as i32)
-            .min((screen.right() as i32).saturating_sub(size.width as i32));
-        let clamped_y = y
-            .max(screen.y as i32)
-            .min((screen.bottom() as i32).saturating_sub(size.height as i32));
-
-        (clamped_x.max(0) as u16, clamped_y.max(0) as u16)
+        let rect = self.placement(screen).rect;
+        (rect.x, rect.y)
     }
 
     /// Get the bounding rect for this tooltip within the given screen area.
+    ///
+    /// The rect is shrunk when the tooltip does not fit on any side.
     #[must_use]
     pub fn bounds(&self, screen: Rect) -> Rect {
-        let (x, y) = self.calculate_position(screen);
         let size = self.content_size();
-        Rect::new(x, y, size.width, size.height)
+        if size.width == 0 || size.height == 0 {
+            return Rect::new(self.target_bounds.x, self.target_bounds.y, 0, 0);
+        }
+        self.placement(screen).rect
     }
 }
 
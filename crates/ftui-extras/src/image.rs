@@ -176,6 +176,12 @@ impl Image {
         Ok(Self { image })
     }
 
+    /// Width and height in pixels.
+    #[must_use]
+    pub fn dimensions(&self) -> (u32, u32) {
+        self.image.dimensions()
+    }
+
     /// Convert the image to PNG bytes, optionally resizing with a fit strategy.
     pub fn to_png_bytes(
         &self,
@@ -26,17 +26,21 @@
 //! | `filesize` | [`filesize`] | Human-readable file size formatting |
 //! | `filepicker` | [`filepicker`] | File picker state utilities |
 //! | `forms` | [`forms`] | Form layout and input widgets |
+//! | `forms-serde` | [`forms`] | Forms generated from serde types |
 //! | `validation` | [`validation`] | Form validation framework with composable validators |
 //! | `image` | [`image`] | Terminal image protocols (iTerm2/Kitty) |
 //! | `live` | [`live`] | Live-updating display (depends on console) |
 //! | `logging` | [`logging`] | Tracing subscriber for TUI logging |
 //! | `markdown` | [`markdown`] | Markdown to styled text rendering |
+//! | `markdown-view` | [`markdown`] | Interactive Markdown viewer (links, folding, TOC, search) |
 //! | `pty-capture` | [`pty_capture`] | PTY session capture |
 //! | `stopwatch` | [`stopwatch`] | Stopwatch timing utility |
 //! | `syntax` | [`syntax`] | Syntax highlighting spans |
+//! | `syntax-textarea` | [`syntax`] | Incremental highlighting adapter for `TextArea` |
 //! | `timer` | [`timer`] | Countdown timer utility |
 //! | `traceback` | [`traceback`] | Error/stacktrace display |
 //! | `theme` | [`theme`] | Color themes + palette tokens |
+//! | `theme-files` | [`theme`] | Register themes from TOML/JSON files and terminal color schemes |
 //! | `terminal` | [`terminal`] | ANSI escape sequence parser for terminal emulation |
 //! | `text-effects` | [`text_effects`] | Animated text effects (gradients, fades, ASCII art) |
 //! | `visual-fx` | [`visual_fx`] | Feature-gated visual FX primitives (backdrops, CPU/GPU adapters) |
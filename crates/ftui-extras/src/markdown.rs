@@ -9,6 +9,16 @@
 //! - Math expressions (`$inline$` and `$$block$$`) rendered as Unicode
 //! - Footnotes with `[^id]` syntax
 //! - Admonitions (`[!NOTE]`, `[!WARNING]`, etc.)
+//! - Definition lists and a safe HTML subset (`<kbd>`, `<sub>`, `<sup>`,
+//!   `<details>`, `<img>`)
+//! - Images as alt text, or drawn with a terminal graphics protocol via
+//!   `MarkdownRenderer::with_images` (feature `image`)
+//!
+//! # Interactive Viewing
+//!
+//! [`MarkdownRenderer::render_document`] also returns the heading outline.
+//! With the `markdown-view` feature, `MarkdownView` displays such a document
+//! with link navigation, folding, a table of contents, and search.
 //!
 //! # Auto-Detection
 //!
@@ -40,6 +50,8 @@
 
 #[cfg(feature = "diagram")]
 use crate::diagram;
+#[cfg(feature = "image")]
+use crate::image::{Image, ImageFit, ImageProtocol, Iterm2Dimension, Iterm2Options};
 #[cfg(feature = "diagram")]
 use crate::mermaid::{MermaidCompatibilityMatrix, MermaidConfig, MermaidFallbackPolicy};
 #[cfg(feature = "syntax")]
@@ -58,9 +70,16 @@ use ftui_text::text::{Line, Span, Text};
 use pulldown_cmark::{
     Alignment, BlockQuoteKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd,
 };
-#[cfg(feature = "syntax")]
+use std::ops::Range;
+#[cfg(any(feature = "syntax", feature = "image"))]
 use std::sync::Arc;
 
+#[cfg(feature = "markdown-view")]
+mod view;
+
+#[cfg(feature = "markdown-view")]
+pub use view::{MarkdownView, MarkdownViewAction, MarkdownViewState};
+
 // ---------------------------------------------------------------------------
 // GFM Auto-Detection
 // ---------------------------------------------------------------------------
@@ -675,6 +694,101 @@ fn find_matching_brace(s: &str) -> Option<usize> {
 }
 
 /// Convert text to Unicode subscript characters where possible.
+/// Byte length of the HTML tag at the start of `text`, honoring quoted
+/// attribute values.
+fn html_tag_end(text: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in text.char_indices().skip(1) {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '>') => return Some(i + 1),
+            (None, '<') => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Value of attribute `name` in an HTML tag's attribute text. Attributes
+/// without a value yield an empty string.
+fn html_attr(attrs: &str, name: &str) -> Option<String> {
+    let mut rest = attrs.trim_start();
+    while !rest.is_empty() {
+        let key_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=')
+            .unwrap_or(rest.len());
+        let key = &rest[..key_end];
+        rest = rest[key_end..].trim_start();
+        let mut value = String::new();
+        if let Some(after_eq) = rest.strip_prefix('=') {
+            let after_eq = after_eq.trim_start();
+            let (raw, remaining) = match after_eq.chars().next() {
+                Some(q @ ('"' | '\'')) => {
+                    let body = &after_eq[1..];
+                    let end = body.find(q).unwrap_or(body.len());
+                    (&body[..end], body.get(end + 1..).unwrap_or(""))
+                }
+                _ => {
+                    let end = after_eq.find(char::is_whitespace).unwrap_or(after_eq.len());
+                    (&after_eq[..end], &after_eq[end..])
+                }
+            };
+            value = decode_html_entities(raw);
+            rest = remaining.trim_start();
+        }
+        if key.eq_ignore_ascii_case(name) {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// Decode the common named and numeric HTML character references.
+fn decode_html_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some('\u{a0}'),
+                _ => entity.strip_prefix('#').and_then(|n| {
+                    let code = match n.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => n.parse().ok()?,
+                    };
+                    char::from_u32(code)
+                }),
+            };
+            c.map(|c| (c, end + 1))
+        });
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
 fn to_unicode_subscript(text: &str) -> String {
     text.chars()
         .map(|c| match c {
@@ -917,6 +1031,8 @@ pub struct MarkdownRenderer {
     table_effect_phase: Option<f32>,
     #[cfg(feature = "syntax")]
     syntax_highlighter: Option<Arc<SyntaxHighlighter>>,
+    #[cfg(feature = "image")]
+    images: Option<ImageSupport>,
 }
 
 impl MarkdownRenderer {
@@ -930,6 +1046,8 @@ impl MarkdownRenderer {
             table_effect_phase: None,
             #[cfg(feature = "syntax")]
             syntax_highlighter: None,
+            #[cfg(feature = "image")]
+            images: None,
         }
     }
 
@@ -972,20 +1090,68 @@ impl MarkdownRenderer {
         self
     }
 
+    /// Draw images with a terminal graphics protocol.
+    ///
+    /// `loader` returns the bytes for an image URL or path. Images that load
+    /// and decode reserve rows in the output and are listed in
+    /// [`MarkdownDocument::images`] with the escape sequence to emit; the rest
+    /// (and all images when `protocol` has no graphics support) render as
+    /// their alt text.
+    #[cfg(feature = "image")]
+    #[must_use]
+    pub fn with_images(
+        mut self,
+        protocol: ImageProtocol,
+        loader: impl Fn(&str) -> Option<Vec<u8>> + Send + Sync + 'static,
+    ) -> Self {
+        self.images = Some(ImageSupport {
+            protocol,
+            max_width: 60,
+            max_rows: 20,
+            loader: Arc::new(loader),
+        });
+        self
+    }
+
+    /// Limit the size of images drawn with [`MarkdownRenderer::with_images`],
+    /// in cells.
+    #[cfg(feature = "image")]
+    #[must_use]
+    pub fn image_max_size(mut self, columns: u16, rows: u16) -> Self {
+        if let Some(images) = self.images.as_mut() {
+            images.max_width = columns.max(1);
+            images.max_rows = rows.max(1);
+        }
+        self
+    }
+
     /// Render a Markdown string into styled [`Text`].
     ///
     /// Parses the input as GitHub-Flavored Markdown with all extensions enabled:
     /// tables, strikethrough, task lists, math, footnotes, and admonitions.
     #[must_use]
     pub fn render(&self, markdown: &str) -> Text {
+        self.build(markdown).finish()
+    }
+
+    /// Render a Markdown string into a [`MarkdownDocument`]: the styled text
+    /// plus the heading outline used for navigation (see `MarkdownView`
+    /// behind the `markdown-view` feature).
+    #[must_use]
+    pub fn render_document(&self, markdown: &str) -> MarkdownDocument {
+        self.build(markdown).finish_document()
+    }
+
+    fn build<'s>(&'s self, markdown: &str) -> RenderState<'s> {
         let options = Options::ENABLE_STRIKETHROUGH
             | Options::ENABLE_TABLES
             | Options::ENABLE_HEADING_ATTRIBUTES
             | Options::ENABLE_MATH
             | Options::ENABLE_TASKLISTS
             | Options::ENABLE_FOOTNOTES
-            | Options::ENABLE_GFM;
-        let parser = Parser::new_ext(markdown, options);
+            | Options::ENABLE_GFM
+            | Options::ENABLE_DEFINITION_LIST;
+        let parser = Parser::new_ext(markdown, options).into_offset_iter();
 
         let mut builder = RenderState::new(
             &self.theme,
@@ -997,8 +1163,12 @@ impl MarkdownRenderer {
         {
             builder.syntax_highlighter = self.syntax_highlighter.as_deref();
         }
+        #[cfg(feature = "image")]
+        {
+            builder.image_support = self.images.as_ref();
+        }
         builder.process(parser);
-        builder.finish()
+        builder
     }
 
     /// Render a potentially incomplete markdown fragment.
@@ -1056,6 +1226,78 @@ impl Default for MarkdownRenderer {
     }
 }
 
+/// Approximate cell size in pixels, used to size kitty images.
+#[cfg(feature = "image")]
+const CELL_PIXELS: (u32, u32) = (10, 20);
+
+/// Returns the bytes for an image URL or path.
+#[cfg(feature = "image")]
+type ImageLoader = Arc<dyn Fn(&str) -> Option<Vec<u8>> + Send + Sync>;
+
+/// Image loading and protocol settings for [`MarkdownRenderer::with_images`].
+#[cfg(feature = "image")]
+#[derive(Clone)]
+struct ImageSupport {
+    protocol: ImageProtocol,
+    max_width: u16,
+    max_rows: u16,
+    loader: ImageLoader,
+}
+
+#[cfg(feature = "image")]
+impl std::fmt::Debug for ImageSupport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ImageSupport")
+            .field("protocol", &self.protocol)
+            .field("max_width", &self.max_width)
+            .field("max_rows", &self.max_rows)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "image")]
+impl ImageSupport {
+    /// Load `url` and encode it to fit the size limits. Returns the size in
+    /// cells and the escape sequence, or `None` to fall back to alt text.
+    fn place(&self, url: &str) -> Option<(u16, u16, String)> {
+        let bytes = (self.loader)(url)?;
+        let image = Image::from_bytes(&bytes).ok()?;
+        let (width, height) = image.dimensions();
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let columns = u16::try_from(width.div_ceil(CELL_PIXELS.0))
+            .unwrap_or(u16::MAX)
+            .clamp(1, self.max_width);
+        // Cells are about twice as tall as they are wide.
+        let rows = (f64::from(columns) * f64::from(height) / f64::from(width) / 2.0)
+            .ceil()
+            .clamp(1.0, f64::from(self.max_rows)) as u16;
+        let sequence = match self.protocol {
+            ImageProtocol::Kitty => image
+                .encode_kitty(
+                    Some(u32::from(columns) * CELL_PIXELS.0),
+                    Some(u32::from(rows) * CELL_PIXELS.1),
+                    ImageFit::Contain,
+                )
+                .ok()?
+                .concat(),
+            ImageProtocol::Iterm2 => {
+                let options = Iterm2Options {
+                    width: Some(Iterm2Dimension::Cells(u32::from(columns))),
+                    height: Some(Iterm2Dimension::Cells(u32::from(rows))),
+                    ..Iterm2Options::default()
+                };
+                image
+                    .encode_iterm2(None, None, ImageFit::None, &options)
+                    .ok()?
+            }
+            ImageProtocol::Sixel | ImageProtocol::Ascii => return None,
+        };
+        Some((columns, rows, sequence))
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Internal render state machine
 // ---------------------------------------------------------------------------
@@ -1073,6 +1315,18 @@ enum StyleContext {
     FootnoteDefinition,
 }
 
+/// Inline HTML element whose content is being rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InlineHtml {
+    Kbd,
+    Sub,
+    Sup,
+}
+
+/// Marker opening a definition, and the indent of its continuation lines.
+const DEFINITION_MARKER: &str = "  : ";
+const DEFINITION_INDENT: &str = "    ";
+
 /// Tracks list nesting and numbering.
 #[derive(Debug, Clone)]
 struct ListState {
@@ -1182,8 +1436,8 @@ struct RenderState<'t> {
     current_admonition: Option<AdmonitionKind>,
     /// Track if we need a blank line separator.
     needs_blank: bool,
-    /// Pending task list marker (checked state).
-    pending_task_marker: Option<bool>,
+    /// Pending task list marker (checked state and source range).
+    pending_task_marker: Option<(bool, Range<usize>)>,
     /// Whether we're waiting to emit a list item prefix.
     /// Deferred so task markers can replace the bullet.
     pending_list_prefix: bool,
@@ -1195,6 +1449,26 @@ struct RenderState<'t> {
     current_footnote_lines: Vec<Line>,
     /// Table state (if currently parsing a table).
     table_state: Option<TableState>,
+    /// Explicit `{#id}` of the heading being collected.
+    heading_id: Option<String>,
+    /// Headings emitted so far, in document order.
+    headings: Vec<MarkdownHeading>,
+    /// Task list checkboxes emitted so far.
+    tasks: Vec<MarkdownTask>,
+    /// Images placed with a graphics protocol.
+    images: Vec<MarkdownImage>,
+    /// URL and alt text of the image being collected.
+    pending_image: Option<(String, String)>,
+    /// Open inline HTML elements.
+    inline_html: Vec<InlineHtml>,
+    /// Inside `<script>` or `<style>`, whose content is dropped.
+    in_html_raw: bool,
+    /// A `<details>` marker waits for its `<summary>` on the same line.
+    awaiting_summary: bool,
+    /// Prefix for the next line of a definition list definition.
+    definition_prefix: Option<&'static str>,
+    #[cfg(feature = "image")]
+    image_support: Option<&'t ImageSupport>,
 }
 
 #[cfg(feature = "diagram")]
@@ -1234,11 +1508,22 @@ impl<'t> RenderState<'t> {
             current_footnote: None,
             current_footnote_lines: Vec::new(),
             table_state: None,
+            heading_id: None,
+            headings: Vec::new(),
+            tasks: Vec::new(),
+            images: Vec::new(),
+            pending_image: None,
+            inline_html: Vec::new(),
+            in_html_raw: false,
+            awaiting_summary: false,
+            definition_prefix: None,
+            #[cfg(feature = "image")]
+            image_support: None,
         }
     }
 
-    fn process<'a>(&mut self, parser: impl Iterator<Item = Event<'a>>) {
-        for event in parser {
+    fn process<'a>(&mut self, parser: impl Iterator<Item = (Event<'a>, Range<usize>)>) {
+        for (event, range) in parser {
             match event {
                 Event::Start(tag) => self.start_tag(tag),
                 Event::End(tag) => self.end_tag(tag),
@@ -1247,7 +1532,7 @@ impl<'t> RenderState<'t> {
                 Event::SoftBreak => self.soft_break(),
                 Event::HardBreak => self.hard_break(),
                 Event::Rule => self.horizontal_rule(),
-                Event::TaskListMarker(checked) => self.task_list_marker(checked),
+                Event::TaskListMarker(checked) => self.task_list_marker(checked, range),
                 Event::FootnoteReference(label) => self.footnote_reference(&label),
                 Event::InlineMath(latex) => self.inline_math(&latex),
                 Event::DisplayMath(latex) => self.display_math(&latex),
@@ -1261,8 +1546,9 @@ impl<'t> RenderState<'t> {
 
     fn start_tag(&mut self, tag: Tag) {
         match tag {
-            Tag::Heading { level, .. } => {
+            Tag::Heading { level, id, .. } => {
                 self.flush_blank();
+                self.heading_id = id.map(|id| id.to_string());
                 self.style_stack.push(StyleContext::Heading(level));
             }
             Tag::Paragraph => {
@@ -1314,6 +1600,23 @@ impl<'t> RenderState<'t> {
                 self.style_stack
                     .push(StyleContext::Link(dest_url.to_string()));
             }
+            Tag::Image { dest_url, .. } => {
+                self.pending_image = Some((dest_url.to_string(), String::new()));
+            }
+            Tag::HtmlBlock => {
+                self.flush_blank();
+            }
+            Tag::DefinitionList => {
+                self.flush_blank();
+            }
+            Tag::DefinitionListTitle => {
+                self.flush_line();
+                self.style_stack.push(StyleContext::Strong);
+            }
+            Tag::DefinitionListDefinition => {
+                self.flush_line();
+                self.definition_prefix = Some(DEFINITION_MARKER);
+            }
             Tag::List(start) => match start {
                 Some(n) => self.list_stack.push(ListState {
                     ordered: true,
@@ -1362,9 +1665,21 @@ impl<'t> RenderState<'t> {
 
     fn end_tag(&mut self, tag: TagEnd) {
         match tag {
-            TagEnd::Heading(_) => {
+            TagEnd::Heading(level) => {
                 self.style_stack.pop();
+                let id = self.heading_id.take();
+                let emits_line = !self.current_spans.is_empty()
+                    && !self.in_table()
+                    && !self.in_footnote_definition();
                 self.flush_line();
+                if emits_line && let Some(line) = self.lines.last() {
+                    self.headings.push(MarkdownHeading {
+                        level: level as u8,
+                        title: line.to_plain_text(),
+                        anchor: id.unwrap_or_default(),
+                        line: self.lines.len() - 1,
+                    });
+                }
                 self.needs_blank = true;
             }
             TagEnd::Paragraph => {
@@ -1398,6 +1713,30 @@ impl<'t> RenderState<'t> {
             TagEnd::Link => {
                 self.style_stack.pop();
             }
+            TagEnd::Image => {
+                if let Some((url, alt)) = self.pending_image.take() {
+                    self.image(&url, &alt);
+                }
+            }
+            TagEnd::HtmlBlock => {
+                self.inline_html.clear();
+                self.in_html_raw = false;
+                self.awaiting_summary = false;
+                self.flush_line();
+                self.needs_blank = true;
+            }
+            TagEnd::DefinitionList => {
+                self.flush_line();
+                self.needs_blank = true;
+            }
+            TagEnd::DefinitionListTitle => {
+                self.style_stack.pop();
+                self.flush_line();
+            }
+            TagEnd::DefinitionListDefinition => {
+                self.flush_line();
+                self.definition_prefix = None;
+            }
             TagEnd::List(_) => {
                 self.list_stack.pop();
                 if self.list_stack.is_empty() {
@@ -1475,47 +1814,24 @@ impl<'t> RenderState<'t> {
             self.code_block_lines.push(text.to_string());
             return;
         }
-
-        self.push_blockquote_prefix_if_needed();
-
-        // Handle deferred list item prefix
-        // Task markers take precedence over bullet points
-        if self.pending_list_prefix {
-            self.pending_list_prefix = false;
-            let indent = "  ".repeat(self.list_stack.len().saturating_sub(1));
-
-            if let Some(checked) = self.pending_task_marker.take() {
-                // Task list item - use checkbox instead of bullet
-                let (marker, style) = if checked {
-                    ("✓ ", self.theme.task_done)
-                } else {
-                    ("☐ ", self.theme.task_todo)
-                };
-                self.current_spans
-                    .push(Span::styled(format!("{indent}{marker}"), style));
-            } else {
-                // Regular list item - use bullet
-                let prefix = self.list_prefix();
-                self.current_spans.push(Span::styled(
-                    format!("{indent}{prefix}"),
-                    self.theme.list_bullet,
-                ));
-            }
-        } else if let Some(checked) = self.pending_task_marker.take() {
-            // Task marker without pending prefix (shouldn't happen normally)
-            let indent = "  ".repeat(self.list_stack.len().saturating_sub(1));
-            let (marker, style) = if checked {
-                ("✓ ", self.theme.task_done)
-            } else {
-                ("☐ ", self.theme.task_todo)
-            };
-            self.current_spans
-                .push(Span::styled(format!("{indent}{marker}"), style));
+        if let Some((_, alt)) = self.pending_image.as_mut() {
+            alt.push_str(text);
+            return;
         }
 
-        let style = self.current_style();
+        self.begin_inline();
+
+        let content = match self.inline_html.last() {
+            Some(InlineHtml::Sub) => to_unicode_subscript(text),
+            Some(InlineHtml::Sup) => to_unicode_superscript(text),
+            _ => text.to_string(),
+        };
+        let style = if self.inline_html.contains(&InlineHtml::Kbd) {
+            Some(self.theme.code_inline)
+        } else {
+            self.current_style()
+        };
         let link = self.current_link();
-        let content = text.to_string();
 
         let mut span = match style {
             Some(s) => Span::styled(content, s),
@@ -1529,6 +1845,94 @@ impl<'t> RenderState<'t> {
         self.current_spans.push(span);
     }
 
+    /// Emit the prefixes owed at the start of inline content: blockquote
+    /// bars, definition indents, and deferred list bullets or task markers.
+    fn begin_inline(&mut self) {
+        let line_start = self.current_spans.is_empty();
+        self.push_blockquote_prefix_if_needed();
+
+        if line_start && let Some(prefix) = self.definition_prefix {
+            self.current_spans
+                .push(Span::styled(prefix.to_string(), self.theme.list_bullet));
+            self.definition_prefix = Some(DEFINITION_INDENT);
+        }
+
+        // Task markers take precedence over bullet points
+        let indent = "  ".repeat(self.list_stack.len().saturating_sub(1));
+        if let Some((checked, source)) = self.pending_task_marker.take() {
+            self.pending_list_prefix = false;
+            self.push_task_marker(indent, checked, source);
+        } else if self.pending_list_prefix {
+            self.pending_list_prefix = false;
+            let prefix = self.list_prefix();
+            self.current_spans.push(Span::styled(
+                format!("{indent}{prefix}"),
+                self.theme.list_bullet,
+            ));
+        }
+    }
+
+    fn push_task_marker(&mut self, indent: String, checked: bool, source: Range<usize>) {
+        let (marker, style) = if checked {
+            ("✓ ", self.theme.task_done)
+        } else {
+            ("☐ ", self.theme.task_todo)
+        };
+        if !self.in_table() && !self.in_footnote_definition() {
+            let column = self.current_spans.iter().map(Span::width).sum::<usize>() + indent.len();
+            self.tasks.push(MarkdownTask {
+                checked,
+                line: self.lines.len(),
+                column,
+                source,
+            });
+        }
+        self.current_spans
+            .push(Span::styled(format!("{indent}{marker}"), style));
+    }
+
+    /// Emit an image: placed graphics when an image protocol is configured
+    /// and the image loads, otherwise its alt text.
+    fn image(&mut self, url: &str, alt: &str) {
+        let label = if alt.is_empty() {
+            String::from("[image]")
+        } else {
+            format!("[{alt}]")
+        };
+
+        #[cfg(feature = "image")]
+        if !self.in_table()
+            && !self.in_footnote_definition()
+            && let Some((columns, rows, sequence)) =
+                self.image_support.and_then(|images| images.place(url))
+        {
+            self.flush_line();
+            let line = self.lines.len();
+            self.lines.push(Line::styled(label, self.theme.emphasis));
+            for _ in 1..rows {
+                self.lines.push(Line::new());
+            }
+            self.images.push(MarkdownImage {
+                url: url.to_string(),
+                alt: alt.to_string(),
+                line,
+                columns,
+                rows,
+                sequence,
+            });
+            return;
+        }
+        #[cfg(not(feature = "image"))]
+        let _ = url;
+
+        self.begin_inline();
+        let mut span = Span::styled(label, self.theme.emphasis);
+        if let Some(link) = self.current_link() {
+            span = span.link(link);
+        }
+        self.current_spans.push(span);
+    }
+
     fn push_blockquote_prefix_if_needed(&mut self) {
         if self.blockquote_depth == 0 || !self.current_spans.is_empty() {
             return;
@@ -1547,6 +1951,11 @@ impl<'t> RenderState<'t> {
     }
 
     fn inline_code(&mut self, code: &str) {
+        if let Some((_, alt)) = self.pending_image.as_mut() {
+            alt.push_str(code);
+            return;
+        }
+        self.begin_inline();
         let mut span = Span::styled(format!("`{code}`"), self.theme.code_inline);
         if let Some(url) = self.current_link() {
             span = span.link(url);
@@ -1574,9 +1983,9 @@ impl<'t> RenderState<'t> {
         self.needs_blank = true;
     }
 
-    fn task_list_marker(&mut self, checked: bool) {
+    fn task_list_marker(&mut self, checked: bool, source: Range<usize>) {
         // Defer until we get the text content
-        self.pending_task_marker = Some(checked);
+        self.pending_task_marker = Some((checked, source));
     }
 
     fn footnote_reference(&mut self, label: &str) {
@@ -1586,6 +1995,7 @@ impl<'t> RenderState<'t> {
     }
 
     fn inline_math(&mut self, latex: &str) {
+        self.begin_inline();
         let unicode = latex_to_unicode(latex);
         self.current_spans
             .push(Span::styled(unicode, self.theme.math_inline));
@@ -1608,101 +2018,129 @@ impl<'t> RenderState<'t> {
         self.needs_blank = true;
     }
 
+    /// Render the safe HTML subset: `<br>`, `<hr>`, `<kbd>`, `<sub>`,
+    /// `<sup>`, `<details>`/`<summary>`, and `<img>`. Other tags are dropped
+    /// and their text kept, except inside `<script>` and `<style>`.
     fn html(&mut self, html: &str) {
-        // Handle a subset of HTML tags that make sense in terminal
-        let html_lower = html.to_ascii_lowercase();
-        let html_trimmed = html_lower.trim();
-
-        // Line breaks
-        if html_trimmed == "<br>" || html_trimmed == "<br/>" || html_trimmed == "<br />" {
-            self.hard_break();
-            return;
-        }
-
-        // Horizontal rule
-        if html_trimmed == "<hr>" || html_trimmed == "<hr/>" || html_trimmed == "<hr />" {
-            self.horizontal_rule();
-            return;
+        let mut rest = html;
+        while !rest.is_empty() {
+            let (text, tag) = match rest.find('<') {
+                Some(0) => match html_tag_end(rest) {
+                    Some(end) => {
+                        let tag = &rest[..end];
+                        rest = &rest[end..];
+                        ("", Some(tag))
+                    }
+                    None => (std::mem::take(&mut rest), None),
+                },
+                Some(start) => {
+                    let text = &rest[..start];
+                    rest = &rest[start..];
+                    (text, None)
+                }
+                None => (std::mem::take(&mut rest), None),
+            };
+            if !text.is_empty() && !self.in_html_raw {
+                self.html_text(text);
+            }
+            if let Some(tag) = tag {
+                self.html_tag(tag);
+            }
         }
+    }
 
-        // Keyboard key styling
-        if html_trimmed.starts_with("<kbd>") {
-            // Extract content between <kbd> and </kbd> if on same line
-            if let Some(end_pos) = html_lower.find("</kbd>") {
-                let content = &html[5..end_pos];
-                let styled = format!("[{content}]");
-                self.current_spans
-                    .push(Span::styled(styled, self.theme.code_inline));
-                return;
+    fn html_text(&mut self, text: &str) {
+        let mut lines = text.split('\n').peekable();
+        while let Some(line) = lines.next() {
+            let line = line.trim();
+            if !line.is_empty() {
+                self.text(&decode_html_entities(line));
+            }
+            if lines.peek().is_some() && !self.awaiting_summary {
+                self.flush_line();
             }
         }
+    }
 
-        // Details/Summary - show as collapsible indicator
-        if html_trimmed.starts_with("<details") {
-            self.flush_blank();
-            self.current_spans
-                .push(Span::styled(String::from("▶ "), self.theme.strong));
-            return;
-        }
-        if html_trimmed == "</details>" {
-            self.flush_line();
-            self.needs_blank = true;
+    fn html_tag(&mut self, tag: &str) {
+        let inner = tag[1..tag.len() - 1].trim();
+        if inner.starts_with('!') {
+            // Comment or doctype.
             return;
         }
-        if html_trimmed.starts_with("<summary>") {
-            // Extract summary text if present
-            if let Some(end_pos) = html_lower.find("</summary>") {
-                let content = &html[9..end_pos];
+        let closing = inner.starts_with('/');
+        let inner = inner.trim_start_matches('/').trim_end_matches('/');
+        let name_end = inner
+            .find(|c: char| c.is_whitespace())
+            .unwrap_or(inner.len());
+        let name = inner[..name_end].to_ascii_lowercase();
+        let attrs = &inner[name_end..];
+
+        match (name.as_str(), closing) {
+            ("script" | "style", closing) => self.in_html_raw = !closing,
+            _ if self.in_html_raw => {}
+            ("br", _) => self.hard_break(),
+            ("hr", _) => self.horizontal_rule(),
+            ("kbd", false) => {
+                self.begin_inline();
+                self.current_spans
+                    .push(Span::styled(String::from("["), self.theme.code_inline));
+                self.inline_html.push(InlineHtml::Kbd);
+            }
+            ("kbd", true) => {
+                if self.close_inline_html(InlineHtml::Kbd) {
+                    self.current_spans
+                        .push(Span::styled(String::from("]"), self.theme.code_inline));
+                }
+            }
+            ("sub", false) => self.inline_html.push(InlineHtml::Sub),
+            ("sup", false) => self.inline_html.push(InlineHtml::Sup),
+            ("sub", true) => {
+                self.close_inline_html(InlineHtml::Sub);
+            }
+            ("sup", true) => {
+                self.close_inline_html(InlineHtml::Sup);
+            }
+            ("details", false) => {
+                self.flush_blank();
+                let open = html_attr(attrs, "open").is_some();
+                let marker = if open { "▼ " } else { "▶ " };
+                self.begin_inline();
                 self.current_spans
-                    .push(Span::styled(content.trim().to_string(), self.theme.strong));
+                    .push(Span::styled(String::from(marker), self.theme.strong));
+                self.awaiting_summary = true;
+            }
+            ("details", true) => {
+                self.awaiting_summary = false;
                 self.flush_line();
+                self.needs_blank = true;
             }
-            return;
-        }
-        if html_trimmed == "</summary>" {
-            return;
-        }
-
-        // Image - show alt text
-        if html_trimmed.starts_with("<img ") {
-            // Try to extract alt text
-            if let Some(alt_start) = html_lower.find("alt=\"") {
-                let after_alt = &html[alt_start + 5..];
-                if let Some(alt_end) = after_alt.find('"') {
-                    let alt_text = &after_alt[..alt_end];
-                    if !alt_text.is_empty() {
-                        self.current_spans
-                            .push(Span::styled(format!("[{alt_text}]"), self.theme.emphasis));
-                        return;
-                    }
+            ("summary", false) => self.style_stack.push(StyleContext::Strong),
+            ("summary", true) => {
+                self.awaiting_summary = false;
+                if matches!(self.style_stack.last(), Some(StyleContext::Strong)) {
+                    self.style_stack.pop();
                 }
+                self.flush_line();
             }
-            // Fallback: show [image]
-            self.current_spans
-                .push(Span::styled(String::from("[image]"), self.theme.emphasis));
-            return;
-        }
-
-        // Subscript - convert to Unicode subscript if possible
-        if html_trimmed.starts_with("<sub>")
-            && let Some(end_pos) = html_lower.find("</sub>")
-        {
-            let content = &html[5..end_pos];
-            let subscript = to_unicode_subscript(content);
-            self.current_spans.push(Span::raw(subscript));
-            return;
+            ("img", false) => {
+                let src = html_attr(attrs, "src").unwrap_or_default();
+                let alt = html_attr(attrs, "alt").unwrap_or_default();
+                self.image(&src, &alt);
+            }
+            _ => {}
         }
+    }
 
-        // Superscript - convert to Unicode superscript if possible
-        if html_trimmed.starts_with("<sup>")
-            && let Some(end_pos) = html_lower.find("</sup>")
-        {
-            let content = &html[5..end_pos];
-            let superscript = to_unicode_superscript(content);
-            self.current_spans.push(Span::raw(superscript));
+    /// Close the innermost open `element`. Returns whether it was open.
+    fn close_inline_html(&mut self, element: InlineHtml) -> bool {
+        match self.inline_html.iter().rposition(|&e| e == element) {
+            Some(index) => {
+                self.inline_html.remove(index);
+                true
+            }
+            None => false,
         }
-
-        // Other HTML is ignored in terminal output
     }
 
     // -- helpers --
@@ -2316,6 +2754,34 @@ impl<'t> RenderState<'t> {
         }
         Text::from_lines(self.lines)
     }
+
+    fn finish_document(mut self) -> MarkdownDocument {
+        let mut headings = std::mem::take(&mut self.headings);
+        // Explicit ids win; the rest get unique GitHub-style slugs.
+        let mut used: std::collections::HashSet<String> = headings
+            .iter()
+            .filter(|h| !h.anchor.is_empty())
+            .map(|h| h.anchor.clone())
+            .collect();
+        for heading in headings.iter_mut().filter(|h| h.anchor.is_empty()) {
+            let base = slugify(&heading.title);
+            let mut anchor = base.clone();
+            let mut n = 1;
+            while !used.insert(anchor.clone()) {
+                anchor = format!("{base}-{n}");
+                n += 1;
+            }
+            heading.anchor = anchor;
+        }
+        let tasks = std::mem::take(&mut self.tasks);
+        let images = std::mem::take(&mut self.images);
+        MarkdownDocument {
+            text: self.finish(),
+            headings,
+            tasks,
+            images,
+        }
+    }
 }
 
 #[cfg(feature = "diagram")]
@@ -2378,6 +2844,192 @@ fn mermaid_style_from_cell(cell: &Cell) -> Style {
     style
 }
 
+// ---------------------------------------------------------------------------
+// Document outline
+// ---------------------------------------------------------------------------
+
+/// A heading in a [`MarkdownDocument`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownHeading {
+    /// Heading level, 1 to 6.
+    pub level: u8,
+    /// Plain heading text.
+    pub title: String,
+    /// Anchor for `#anchor` links: the explicit `{#id}` if given, otherwise a
+    /// unique GitHub-style slug of the title.
+    pub anchor: String,
+    /// Index of the heading's line in [`MarkdownDocument::text`].
+    pub line: usize,
+}
+
+/// A link span in a [`MarkdownDocument`], in display columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownLink {
+    pub url: String,
+    /// Line index in [`MarkdownDocument::text`].
+    pub line: usize,
+    /// First display column of the link text.
+    pub column: usize,
+    /// Display width of the link text.
+    pub width: usize,
+}
+
+impl MarkdownLink {
+    /// The anchor this link targets within the document (`#section`).
+    pub fn anchor(&self) -> Option<&str> {
+        self.url.strip_prefix('#')
+    }
+}
+
+/// A task list checkbox (`- [ ]` / `- [x]`) in a [`MarkdownDocument`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownTask {
+    pub checked: bool,
+    /// Line index in [`MarkdownDocument::text`].
+    pub line: usize,
+    /// Display column of the checkbox glyph.
+    pub column: usize,
+    /// Byte range of the `[ ]` marker in the Markdown source.
+    pub source: Range<usize>,
+}
+
+/// An image drawn with a terminal graphics protocol (see
+/// `MarkdownRenderer::with_images`).
+///
+/// The rendered text reserves `rows` lines starting at `line`, showing the
+/// alt text until the application writes `sequence` with the cursor at the
+/// first reserved cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownImage {
+    pub url: String,
+    pub alt: String,
+    /// First reserved line in [`MarkdownDocument::text`].
+    pub line: usize,
+    pub columns: u16,
+    pub rows: u16,
+    /// Escape sequence that draws the image at the cursor.
+    pub sequence: String,
+}
+
+/// Rendered Markdown together with its heading outline, task checkboxes,
+/// and placed images.
+#[derive(Debug, Clone, Default)]
+pub struct MarkdownDocument {
+    pub text: Text,
+    pub headings: Vec<MarkdownHeading>,
+    pub tasks: Vec<MarkdownTask>,
+    pub images: Vec<MarkdownImage>,
+}
+
+impl MarkdownDocument {
+    /// Number of rendered lines.
+    pub fn height(&self) -> usize {
+        self.text.height()
+    }
+
+    /// All links in document order. Adjacent spans with the same URL (for
+    /// example `[**bold** text](url)`) form one link.
+    pub fn links(&self) -> Vec<MarkdownLink> {
+        let mut links: Vec<MarkdownLink> = Vec::new();
+        for (index, line) in self.text.lines().iter().enumerate() {
+            let mut column = 0;
+            let mut previous_url: Option<&str> = None;
+            for span in line.spans() {
+                let width = span.width();
+                match span.link.as_deref() {
+                    Some(url) if previous_url == Some(url) => {
+                        if let Some(link) = links.last_mut() {
+                            link.width += width;
+                        }
+                    }
+                    Some(url) => links.push(MarkdownLink {
+                        url: url.to_string(),
+                        line: index,
+                        column,
+                        width,
+                    }),
+                    None => {}
+                }
+                previous_url = span.link.as_deref();
+                column += width;
+            }
+        }
+        links
+    }
+
+    /// Index of the heading with the given anchor (a leading `#` is
+    /// ignored).
+    pub fn heading_for_anchor(&self, anchor: &str) -> Option<usize> {
+        let anchor = anchor.strip_prefix('#').unwrap_or(anchor);
+        self.headings
+            .iter()
+            .position(|h| h.anchor == anchor)
+            .or_else(|| {
+                let slug = slugify(anchor);
+                self.headings.iter().position(|h| h.anchor == slug)
+            })
+    }
+
+    /// Line range of heading `index`'s section body: from the line after the
+    /// heading up to the next heading of the same or a higher level.
+    pub fn section_body(&self, index: usize) -> std::ops::Range<usize> {
+        let Some(heading) = self.headings.get(index) else {
+            return 0..0;
+        };
+        let end = self.headings[index + 1..]
+            .iter()
+            .find(|h| h.level <= heading.level)
+            .map_or(self.height(), |h| h.line);
+        heading.line + 1..end
+    }
+
+    /// `markdown` (the source this document was rendered from) with task
+    /// `index` checked or unchecked. Returns `None` if the task does not
+    /// match the source.
+    pub fn set_task_in_source(
+        &self,
+        markdown: &str,
+        index: usize,
+        checked: bool,
+    ) -> Option<String> {
+        let task = self.tasks.get(index)?;
+        let marker = markdown.get(task.source.clone())?;
+        let open = marker.find('[')?;
+        let state = task.source.start + open + 1;
+        let current = markdown.get(state..state + 1)?;
+        if !matches!(current, " " | "x" | "X") {
+            return None;
+        }
+        let mut out = String::with_capacity(markdown.len());
+        out.push_str(&markdown[..state]);
+        out.push(if checked { 'x' } else { ' ' });
+        out.push_str(&markdown[state + 1..]);
+        Some(out)
+    }
+
+    /// Index of the innermost heading whose section contains `line`.
+    pub fn section_at(&self, line: usize) -> Option<usize> {
+        self.headings.iter().rposition(|h| h.line <= line)
+    }
+}
+
+/// GitHub-style heading slug: lowercase, punctuation dropped, spaces turned
+/// into hyphens.
+#[must_use]
+pub fn slugify(title: &str) -> String {
+    title
+        .trim()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            '-' | '_' => Some(c),
+            c if c.is_alphanumeric() => Some(c),
+            _ => None,
+        })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
 // ---------------------------------------------------------------------------
 // Convenience function
 // ---------------------------------------------------------------------------
@@ -3083,6 +3735,75 @@ The end.
         assert!(content.contains("Also not done"));
     }
 
+    #[test]
+    fn task_markers_are_recorded_with_source_ranges() {
+        let md = "- [ ] Todo\n- [x] Done\n- `code` first";
+        let doc = MarkdownRenderer::default().render_document(md);
+        assert_eq!(doc.tasks.len(), 2);
+        assert!(!doc.tasks[0].checked && doc.tasks[1].checked);
+        assert_eq!(&md[doc.tasks[0].source.clone()], "[ ]");
+        assert_eq!(doc.tasks[1].line, 1);
+        assert_eq!(doc.tasks[1].column, 0);
+
+        let toggled = doc.set_task_in_source(md, 0, true).unwrap();
+        assert!(toggled.starts_with("- [x] Todo\n- [x] Done"));
+        // List items starting with inline code keep their bullet.
+        assert!(plain(&doc.text).contains("• `code` first"));
+    }
+
+    #[test]
+    fn inline_html_kbd_sub_sup() {
+        let text = render_markdown("Press <kbd>Ctrl</kbd>, H<sub>2</sub>O, x<sup>2</sup>");
+        assert_eq!(plain(&text).trim(), "Press [Ctrl], H₂O, x²");
+    }
+
+    #[test]
+    fn html_details_and_unsafe_content() {
+        let md = "<details open>\n<summary>More &amp; less</summary>\n\nBody text\n\n</details>\n\n<div>\n<script>alert(1)</script>\nkept\n</div>";
+        let content = plain(&render_markdown(md));
+        assert!(content.contains("▼ More & less"));
+        assert!(content.contains("Body text"));
+        assert!(content.contains("kept"));
+        assert!(!content.contains("alert"));
+        assert!(!content.contains('<'));
+    }
+
+    #[test]
+    fn definition_lists() {
+        let text = render_markdown("Term\n: First definition\n\nOther\n: Second");
+        let lines: Vec<String> = text.lines().iter().map(|l| l.to_plain_text()).collect();
+        assert_eq!(
+            lines,
+            ["Term", "  : First definition", "Other", "  : Second"]
+        );
+    }
+
+    #[test]
+    fn image_alt_text_fallback() {
+        let content = plain(&render_markdown("Look: ![a *cat*](cat.png) ![](x.png)"));
+        assert!(content.contains("Look: [a cat] [image]"));
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn images_placed_with_graphics_protocol() {
+        let mut png = std::io::Cursor::new(Vec::new());
+        image::RgbImage::new(40, 80)
+            .write_to(&mut png, image::ImageFormat::Png)
+            .unwrap();
+        let png = png.into_inner();
+        let renderer = MarkdownRenderer::default().with_images(ImageProtocol::Iterm2, move |url| {
+            (url == "cat.png").then(|| png.clone())
+        });
+        let doc = renderer.render_document("Intro\n\n![cat](cat.png)\n\n![dog](dog.png)");
+        assert_eq!(doc.images.len(), 1);
+        let image = &doc.images[0];
+        assert_eq!((image.columns, image.rows), (4, 4));
+        assert!(image.sequence.starts_with("\x1b]1337;File="));
+        assert_eq!(doc.text.lines()[image.line].to_plain_text(), "[cat]");
+        assert!(plain(&doc.text).contains("[dog]"));
+    }
+
     // =========================================================================
     // Math tests
     // =========================================================================
@@ -0,0 +1,1144 @@
+//! Interactive viewer for rendered Markdown documents.
+//!
+//! [`MarkdownView`] displays a [`MarkdownDocument`] with an optional table of
+//! contents sidebar. [`MarkdownViewState`] tracks scrolling, folded heading
+//! sections, the focused link or task checkbox, and in-document search.
+//! Activating a link either scrolls to its `#anchor` or returns
+//! [`MarkdownViewAction::OpenLink`]; activating a checkbox returns
+//! [`MarkdownViewAction::ToggleTask`] for the application to handle.
+//!
+//! Lines are shown as rendered, without re-wrapping; render the document
+//! with a [`MarkdownRenderer`](super::MarkdownRenderer) whose rule and table
+//! widths suit the viewport.
+
+use std::collections::BTreeSet;
+
+use ftui_core::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ftui_core::geometry::Rect;
+use ftui_render::cell::{Cell, CellContent, PackedRgba};
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+use ftui_text::{grapheme_width, graphemes, search_ascii_case_insensitive};
+use ftui_widgets::StatefulWidget;
+
+use super::{MarkdownDocument, MarkdownLink};
+
+/// Lines scrolled per mouse wheel notch.
+const WHEEL_LINES: usize = 3;
+
+/// Narrowest body kept when the table of contents is shown.
+const MIN_BODY_WIDTH: u16 = 20;
+
+/// Result of routing input through a [`MarkdownViewState`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkdownViewAction {
+    /// Input not relevant to the view.
+    Ignored,
+    /// Input consumed; the view changed or needs no further handling.
+    Handled,
+    /// A link to outside the document was activated.
+    OpenLink(String),
+    /// A task checkbox was activated. Update the source (for example with
+    /// [`MarkdownDocument::set_task_in_source`]) and re-render.
+    ToggleTask { index: usize, checked: bool },
+}
+
+/// A focusable item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Focus {
+    Link(usize),
+    Task(usize),
+}
+
+/// A focusable item and where it is drawn.
+#[derive(Debug, Clone, Copy)]
+struct Target {
+    focus: Focus,
+    line: usize,
+    column: usize,
+    width: usize,
+}
+
+/// Links and task checkboxes in reading order.
+fn targets(doc: &MarkdownDocument) -> Vec<Target> {
+    let links = doc.links().into_iter().enumerate().map(|(i, link)| Target {
+        focus: Focus::Link(i),
+        line: link.line,
+        column: link.column,
+        width: link.width,
+    });
+    let tasks = doc.tasks.iter().enumerate().map(|(i, task)| Target {
+        focus: Focus::Task(i),
+        line: task.line,
+        column: task.column,
+        width: 1,
+    });
+    let mut targets: Vec<Target> = links.chain(tasks).collect();
+    targets.sort_by_key(|t| (t.line, t.column));
+    targets
+}
+
+/// A search hit, in display columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SearchMatch {
+    line: usize,
+    column: usize,
+    width: usize,
+}
+
+/// View state for a [`MarkdownView`].
+#[derive(Debug, Clone, Default)]
+pub struct MarkdownViewState {
+    /// First visible row (an index into the unfolded lines).
+    scroll: usize,
+    /// Indices of folded headings.
+    folded: BTreeSet<usize>,
+    focus: Option<Focus>,
+    query: String,
+    matches: Vec<SearchMatch>,
+    current_match: Option<usize>,
+    /// Whether keys are being typed into the search prompt.
+    search_input: bool,
+    last_body: Rect,
+    last_toc: Rect,
+    last_toc_offset: usize,
+}
+
+impl MarkdownViewState {
+    /// First visible row.
+    pub fn scroll(&self) -> usize {
+        self.scroll
+    }
+
+    /// Lines currently shown, in order: folded sections are skipped.
+    pub fn visible_lines(&self, doc: &MarkdownDocument) -> Vec<usize> {
+        let mut lines = Vec::with_capacity(doc.height());
+        let mut skip_until = 0;
+        let mut headings = doc.headings.iter().enumerate().peekable();
+        for line in 0..doc.height() {
+            if line < skip_until {
+                continue;
+            }
+            lines.push(line);
+            while let Some((index, heading)) = headings.next_if(|(_, h)| h.line <= line) {
+                if heading.line == line && self.folded.contains(&index) {
+                    skip_until = doc.section_body(index).end;
+                }
+            }
+        }
+        lines
+    }
+
+    fn viewport_height(&self) -> usize {
+        usize::from(self.last_body.height.max(1))
+    }
+
+    /// Scroll so row `row` is at the top.
+    pub fn scroll_to(&mut self, row: usize) {
+        self.scroll = row;
+    }
+
+    /// Scroll by `delta` rows, clamped to the document.
+    pub fn scroll_by(&mut self, doc: &MarkdownDocument, delta: isize) -> bool {
+        let max = self
+            .visible_lines(doc)
+            .len()
+            .saturating_sub(self.viewport_height());
+        let scroll = self.scroll.saturating_add_signed(delta).min(max);
+        let changed = scroll != self.scroll;
+        self.scroll = scroll;
+        changed
+    }
+
+    // ----- Folding -----
+
+    /// Whether heading `index`'s section is collapsed.
+    pub fn is_folded(&self, heading: usize) -> bool {
+        self.folded.contains(&heading)
+    }
+
+    /// Collapse or expand heading `index`'s section.
+    pub fn toggle_fold(&mut self, doc: &MarkdownDocument, heading: usize) -> bool {
+        if heading >= doc.headings.len() || doc.section_body(heading).is_empty() {
+            return false;
+        }
+        if !self.folded.remove(&heading) {
+            self.folded.insert(heading);
+        }
+        self.drop_hidden_focus(doc);
+        true
+    }
+
+    /// Collapse every section that has a body.
+    pub fn fold_all(&mut self, doc: &MarkdownDocument) {
+        self.folded = (0..doc.headings.len())
+            .filter(|&i| !doc.section_body(i).is_empty())
+            .collect();
+        self.scroll = 0;
+        self.drop_hidden_focus(doc);
+    }
+
+    /// Expand every section.
+    pub fn unfold_all(&mut self) {
+        self.folded.clear();
+    }
+
+    /// Expand any folded sections hiding `line`.
+    fn unfold_to(&mut self, doc: &MarkdownDocument, line: usize) {
+        self.folded
+            .retain(|&h| !doc.section_body(h).contains(&line));
+    }
+
+    /// Unfold to `line` and scroll it into view; with `to_top`, scroll so
+    /// it's the first row.
+    fn reveal(&mut self, doc: &MarkdownDocument, line: usize, to_top: bool) {
+        self.unfold_to(doc, line);
+        let rows = self.visible_lines(doc);
+        let Some(row) = rows.iter().position(|&l| l == line) else {
+            return;
+        };
+        let height = self.viewport_height();
+        if to_top || row < self.scroll {
+            self.scroll = row;
+        } else if row >= self.scroll + height {
+            self.scroll = row + 1 - height;
+        }
+    }
+
+    /// Scroll heading `index` to the top, unfolding its parents.
+    pub fn jump_to_heading(&mut self, doc: &MarkdownDocument, heading: usize) -> bool {
+        let Some(line) = doc.headings.get(heading).map(|h| h.line) else {
+            return false;
+        };
+        self.reveal(doc, line, true);
+        true
+    }
+
+    /// Scroll to the heading with `anchor` (as in a `#anchor` link).
+    pub fn jump_to_anchor(&mut self, doc: &MarkdownDocument, anchor: &str) -> bool {
+        doc.heading_for_anchor(anchor)
+            .is_some_and(|heading| self.jump_to_heading(doc, heading))
+    }
+
+    // ----- Links and tasks -----
+
+    /// The focused link, if any.
+    pub fn focused_link(&self, doc: &MarkdownDocument) -> Option<MarkdownLink> {
+        match self.focus {
+            Some(Focus::Link(i)) => doc.links().into_iter().nth(i),
+            _ => None,
+        }
+    }
+
+    /// Index of the focused task checkbox in [`MarkdownDocument::tasks`].
+    pub fn focused_task(&self) -> Option<usize> {
+        match self.focus {
+            Some(Focus::Task(i)) => Some(i),
+            _ => None,
+        }
+    }
+
+    fn drop_hidden_focus(&mut self, doc: &MarkdownDocument) {
+        let rows = self.visible_lines(doc);
+        if let Some(focus) = self.focus
+            && !targets(doc)
+                .iter()
+                .any(|t| t.focus == focus && rows.binary_search(&t.line).is_ok())
+        {
+            self.focus = None;
+        }
+    }
+
+    /// Move focus forward (or backward) through the visible links and task
+    /// checkboxes, wrapping around. Without a focused item, starts from the
+    /// top of the viewport.
+    pub fn focus_next(&mut self, doc: &MarkdownDocument, forward: bool) -> bool {
+        let rows = self.visible_lines(doc);
+        let visible: Vec<(Target, usize)> = targets(doc)
+            .into_iter()
+            .filter_map(|t| rows.binary_search(&t.line).ok().map(|row| (t, row)))
+            .collect();
+        if visible.is_empty() {
+            return false;
+        }
+
+        let current = self
+            .focus
+            .and_then(|focus| visible.iter().position(|(t, _)| t.focus == focus));
+        let next = match current {
+            Some(pos) if forward => (pos + 1) % visible.len(),
+            Some(pos) => (pos + visible.len() - 1) % visible.len(),
+            None if forward => visible
+                .iter()
+                .position(|&(_, row)| row >= self.scroll)
+                .unwrap_or(0),
+            None => visible
+                .iter()
+                .rposition(|&(_, row)| row < self.scroll + self.viewport_height())
+                .unwrap_or(visible.len() - 1),
+        };
+        let (target, _) = visible[next];
+        self.focus = Some(target.focus);
+        self.reveal(doc, target.line, false);
+        true
+    }
+
+    /// Activate the focused item: `#anchor` links scroll the view, other
+    /// links are returned as [`MarkdownViewAction::OpenLink`], and task
+    /// checkboxes as [`MarkdownViewAction::ToggleTask`].
+    pub fn activate(&mut self, doc: &MarkdownDocument) -> MarkdownViewAction {
+        match self.focus {
+            Some(focus) => self.activate_target(doc, focus),
+            None => MarkdownViewAction::Ignored,
+        }
+    }
+
+    fn activate_target(&mut self, doc: &MarkdownDocument, focus: Focus) -> MarkdownViewAction {
+        match focus {
+            Focus::Link(index) => {
+                let Some(link) = doc.links().into_iter().nth(index) else {
+                    return MarkdownViewAction::Ignored;
+                };
+                match link.anchor() {
+                    Some(anchor) => {
+                        self.jump_to_anchor(doc, anchor);
+                        MarkdownViewAction::Handled
+                    }
+                    None => MarkdownViewAction::OpenLink(link.url),
+                }
+            }
+            Focus::Task(index) => match doc.tasks.get(index) {
+                Some(task) => MarkdownViewAction::ToggleTask {
+                    index,
+                    checked: !task.checked,
+                },
+                None => MarkdownViewAction::Ignored,
+            },
+        }
+    }
+
+    /// Screen positions of the document's placed images (see
+    /// [`MarkdownDocument::images`]) that are fully visible in the last
+    /// render, as `(image index, x, y)`. Write each image's escape sequence
+    /// with the cursor there after the frame is presented.
+    pub fn image_placements(&self, doc: &MarkdownDocument) -> Vec<(usize, u16, u16)> {
+        let body = self.last_body;
+        let rows = self.visible_lines(doc);
+        let shown = rows
+            .get(self.scroll..)
+            .unwrap_or_default()
+            .iter()
+            .take(usize::from(body.height));
+        let shown: Vec<usize> = shown.copied().collect();
+        doc.images
+            .iter()
+            .enumerate()
+            .filter_map(|(index, image)| {
+                let first = shown.iter().position(|&l| l == image.line)?;
+                let last = image.line + usize::from(image.rows) - 1;
+                let fits = shown.get(first + usize::from(image.rows) - 1) == Some(&last)
+                    && image.columns <= body.width;
+                fits.then(|| (index, body.x, body.y + first as u16))
+            })
+            .collect()
+    }
+
+    // ----- Search -----
+
+    /// The active search query (empty when not searching).
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Number of search matches and the 1-based current one.
+    pub fn match_status(&self) -> (usize, Option<usize>) {
+        (self.matches.len(), self.current_match.map(|i| i + 1))
+    }
+
+    /// Search the document (ASCII case-insensitive) and jump to the first
+    /// match at or after the top of the viewport. Returns the match count.
+    pub fn search(&mut self, doc: &MarkdownDocument, query: &str) -> usize {
+        self.query = query.to_string();
+        self.matches.clear();
+        self.current_match = None;
+        if query.is_empty() {
+            return 0;
+        }
+        for (index, line) in doc.text.lines().iter().enumerate() {
+            let plain = line.to_plain_text();
+            for hit in search_ascii_case_insensitive(&plain, query) {
+                self.matches.push(SearchMatch {
+                    line: index,
+                    column: ftui_text::display_width(&plain[..hit.range.start]),
+                    width: ftui_text::display_width(&plain[hit.range]),
+                });
+            }
+        }
+        let top = self
+            .visible_lines(doc)
+            .get(self.scroll)
+            .copied()
+            .unwrap_or(0);
+        if !self.matches.is_empty() {
+            let first = self.matches.partition_point(|m| m.line < top);
+            self.select_match(doc, first % self.matches.len());
+        }
+        self.matches.len()
+    }
+
+    /// Clear the search query and highlights.
+    pub fn clear_search(&mut self) {
+        self.query.clear();
+        self.matches.clear();
+        self.current_match = None;
+        self.search_input = false;
+    }
+
+    /// Move to the next (or previous) match, wrapping around.
+    pub fn next_match(&mut self, doc: &MarkdownDocument, forward: bool) -> bool {
+        let count = self.matches.len();
+        if count == 0 {
+            return false;
+        }
+        let next = match self.current_match {
+            Some(i) if forward => (i + 1) % count,
+            Some(i) => (i + count - 1) % count,
+            None => 0,
+        };
+        self.select_match(doc, next);
+        true
+    }
+
+    fn select_match(&mut self, doc: &MarkdownDocument, index: usize) {
+        self.current_match = Some(index);
+        let line = self.matches[index].line;
+        self.reveal(doc, line, false);
+    }
+
+    // ----- Input -----
+
+    /// Handle a key press.
+    ///
+    /// Arrows/PageUp/PageDown/Home/End scroll, Tab and Shift+Tab cycle links
+    /// and task checkboxes, Enter activates the focused one, `z` folds the section at the top of
+    /// the viewport (`Z` folds all, `O` opens all), `[`/`]` jump between
+    /// headings, `/` starts a search (Enter confirms, Escape cancels), and
+    /// `n`/`N` step through matches.
+    pub fn handle_key(&mut self, doc: &MarkdownDocument, key: &KeyEvent) -> MarkdownViewAction {
+        if self.search_input {
+            return self.handle_search_key(doc, key);
+        }
+        let page = self.viewport_height() as isize;
+        let handled = match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.scroll_by(doc, -1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_by(doc, 1),
+            KeyCode::PageUp => self.scroll_by(doc, -page),
+            KeyCode::PageDown | KeyCode::Char(' ') => self.scroll_by(doc, page),
+            KeyCode::Home => self.scroll_by(doc, isize::MIN),
+            KeyCode::End => self.scroll_by(doc, isize::MAX),
+            KeyCode::Tab => self.focus_next(doc, true),
+            KeyCode::BackTab => self.focus_next(doc, false),
+            KeyCode::Enter => return self.activate(doc),
+            KeyCode::Char('z') => self
+                .top_line(doc)
+                .and_then(|line| doc.section_at(line))
+                .is_some_and(|heading| {
+                    let changed = self.toggle_fold(doc, heading);
+                    if changed {
+                        self.reveal(doc, doc.headings[heading].line, false);
+                    }
+                    changed
+                }),
+            KeyCode::Char('Z') => {
+                self.fold_all(doc);
+                true
+            }
+            KeyCode::Char('O') => {
+                self.unfold_all();
+                true
+            }
+            KeyCode::Char(']') => self.jump_heading(doc, true),
+            KeyCode::Char('[') => self.jump_heading(doc, false),
+            KeyCode::Char('/') => {
+                self.search_input = true;
+                self.query.clear();
+                self.matches.clear();
+                self.current_match = None;
+                true
+            }
+            KeyCode::Char('n') => self.next_match(doc, true),
+            KeyCode::Char('N') => self.next_match(doc, false),
+            KeyCode::Escape if self.focus.is_some() || !self.query.is_empty() => {
+                self.focus = None;
+                self.clear_search();
+                true
+            }
+            _ => false,
+        };
+        if handled {
+            MarkdownViewAction::Handled
+        } else {
+            MarkdownViewAction::Ignored
+        }
+    }
+
+    fn handle_search_key(&mut self, doc: &MarkdownDocument, key: &KeyEvent) -> MarkdownViewAction {
+        match key.code {
+            KeyCode::Char(c) => {
+                let mut query = std::mem::take(&mut self.query);
+                query.push(c);
+                self.search(doc, &query);
+            }
+            KeyCode::Backspace => {
+                let mut query = std::mem::take(&mut self.query);
+                query.pop();
+                self.search(doc, &query);
+            }
+            KeyCode::Enter => self.search_input = false,
+            KeyCode::Escape => self.clear_search(),
+            _ => return MarkdownViewAction::Ignored,
+        }
+        MarkdownViewAction::Handled
+    }
+
+    fn top_line(&self, doc: &MarkdownDocument) -> Option<usize> {
+        self.visible_lines(doc).get(self.scroll).copied()
+    }
+
+    fn jump_heading(&mut self, doc: &MarkdownDocument, forward: bool) -> bool {
+        let top = self.top_line(doc).unwrap_or(0);
+        let target = if forward {
+            doc.headings.iter().position(|h| h.line > top)
+        } else {
+            doc.headings.iter().rposition(|h| h.line < top)
+        };
+        target.is_some_and(|heading| self.jump_to_heading(doc, heading))
+    }
+
+    /// Handle a mouse event against the last render: the wheel scrolls,
+    /// clicking a link or task checkbox activates it, clicking a heading folds its section,
+    /// and clicking a table of contents entry jumps to that heading.
+    pub fn handle_mouse(
+        &mut self,
+        doc: &MarkdownDocument,
+        event: &MouseEvent,
+    ) -> MarkdownViewAction {
+        let in_body = self.last_body.contains(event.x, event.y);
+        let in_toc = self.last_toc.contains(event.x, event.y);
+        if !in_body && !in_toc {
+            return MarkdownViewAction::Ignored;
+        }
+        let handled = match event.kind {
+            MouseEventKind::ScrollUp => self.scroll_by(doc, -(WHEEL_LINES as isize)),
+            MouseEventKind::ScrollDown => self.scroll_by(doc, WHEEL_LINES as isize),
+            MouseEventKind::Down(MouseButton::Left) if in_toc => {
+                let heading = self.last_toc_offset + usize::from(event.y - self.last_toc.y);
+                self.jump_to_heading(doc, heading)
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                let row = self.scroll + usize::from(event.y - self.last_body.y);
+                let column = usize::from(event.x - self.last_body.x);
+                let Some(line) = self.visible_lines(doc).get(row).copied() else {
+                    return MarkdownViewAction::Ignored;
+                };
+                if let Some(target) = targets(doc)
+                    .into_iter()
+                    .find(|t| t.line == line && (t.column..t.column + t.width).contains(&column))
+                {
+                    self.focus = Some(target.focus);
+                    return self.activate_target(doc, target.focus);
+                }
+                doc.headings
+                    .iter()
+                    .position(|h| h.line == line)
+                    .is_some_and(|heading| self.toggle_fold(doc, heading))
+            }
+            _ => false,
+        };
+        if handled {
+            MarkdownViewAction::Handled
+        } else {
+            MarkdownViewAction::Ignored
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Widget
+// ---------------------------------------------------------------------------
+
+/// Scrollable, interactive view of a [`MarkdownDocument`].
+#[derive(Debug, Clone)]
+pub struct MarkdownView<'a> {
+    doc: &'a MarkdownDocument,
+    style: Style,
+    show_toc: bool,
+    toc_width: u16,
+    toc_style: Style,
+    toc_active_style: Style,
+    focus_style: Style,
+    match_style: Style,
+    current_match_style: Style,
+    fold_marker: &'a str,
+    hyperlinks: bool,
+}
+
+impl<'a> MarkdownView<'a> {
+    pub fn new(doc: &'a MarkdownDocument) -> Self {
+        Self {
+            doc,
+            style: Style::new(),
+            show_toc: false,
+            toc_width: 24,
+            toc_style: Style::new().fg(PackedRgba::rgb(150, 150, 170)),
+            toc_active_style: Style::new().fg(PackedRgba::WHITE).bold(),
+            focus_style: Style::new().reverse(),
+            match_style: Style::new()
+                .fg(PackedRgba::BLACK)
+                .bg(PackedRgba::rgb(200, 180, 60)),
+            current_match_style: Style::new()
+                .fg(PackedRgba::BLACK)
+                .bg(PackedRgba::rgb(255, 140, 0)),
+            fold_marker: " …",
+            hyperlinks: false,
+        }
+    }
+
+    /// Base style for the whole area.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Show a table of contents sidebar of the given width (hidden when the
+    /// area is too narrow or the document has no headings).
+    #[must_use]
+    pub fn toc(mut self, show: bool) -> Self {
+        self.show_toc = show;
+        self
+    }
+
+    #[must_use]
+    pub fn toc_width(mut self, width: u16) -> Self {
+        self.toc_width = width;
+        self
+    }
+
+    #[must_use]
+    pub fn toc_style(mut self, style: Style) -> Self {
+        self.toc_style = style;
+        self
+    }
+
+    /// Style for the table of contents entry of the section at the top of
+    /// the viewport.
+    #[must_use]
+    pub fn toc_active_style(mut self, style: Style) -> Self {
+        self.toc_active_style = style;
+        self
+    }
+
+    /// Style layered over the focused link.
+    #[must_use]
+    pub fn focus_style(mut self, style: Style) -> Self {
+        self.focus_style = style;
+        self
+    }
+
+    #[must_use]
+    pub fn match_style(mut self, style: Style) -> Self {
+        self.match_style = style;
+        self
+    }
+
+    #[must_use]
+    pub fn current_match_style(mut self, style: Style) -> Self {
+        self.current_match_style = style;
+        self
+    }
+
+    /// Text appended to folded headings.
+    #[must_use]
+    pub fn fold_marker(mut self, marker: &'a str) -> Self {
+        self.fold_marker = marker;
+        self
+    }
+
+    /// Emit OSC 8 hyperlinks for external links through the frame's
+    /// `LinkRegistry`. Enable when the terminal supports them.
+    #[must_use]
+    pub fn hyperlinks(mut self, enabled: bool) -> Self {
+        self.hyperlinks = enabled;
+        self
+    }
+
+    fn render_line(&self, frame: &mut Frame, area: Rect, y: u16, line: usize, folded: bool) {
+        let Some(text_line) = self.doc.text.lines().get(line) else {
+            return;
+        };
+        let max_x = area.right();
+        let mut x = area.x;
+        for span in text_line.spans() {
+            let style = span.style.unwrap_or_default();
+            let link_id = match span.link.as_deref() {
+                Some(url) if self.hyperlinks && !url.starts_with('#') => frame.register_link(url),
+                _ => 0,
+            };
+            x = draw_str(frame, x, y, &span.content, style, max_x, link_id);
+        }
+        if folded {
+            let style = text_line
+                .spans()
+                .first()
+                .and_then(|s| s.style)
+                .unwrap_or_default();
+            draw_str(frame, x, y, self.fold_marker, style, max_x, 0);
+        }
+    }
+
+    fn render_toc(&self, frame: &mut Frame, area: Rect, active: Option<usize>, offset: usize) {
+        let min_level = self.doc.headings.iter().map(|h| h.level).min().unwrap_or(1);
+        for (index, y) in (offset..self.doc.headings.len()).zip(area.y..area.bottom()) {
+            let heading = &self.doc.headings[index];
+            let style = if active == Some(index) {
+                self.toc_active_style
+            } else {
+                self.toc_style
+            };
+            let indent = u16::from(heading.level - min_level) * 2;
+            if active == Some(index) {
+                style_area(frame, Rect::new(area.x, y, area.width, 1), style);
+            }
+            draw_str(
+                frame,
+                area.x.saturating_add(indent).min(area.right()),
+                y,
+                &heading.title,
+                style,
+                area.right(),
+                0,
+            );
+        }
+    }
+
+    fn render_search_bar(&self, frame: &mut Frame, area: Rect, state: &MarkdownViewState) {
+        let (count, current) = state.match_status();
+        let status = match current {
+            Some(current) => format!("  {current}/{count}"),
+            None if state.query.is_empty() => String::new(),
+            None => "  no matches".to_string(),
+        };
+        let text = format!("/{}{status}", state.query);
+        style_area(frame, area, self.toc_style);
+        let end = draw_str(
+            frame,
+            area.x,
+            area.y,
+            &text,
+            self.toc_style,
+            area.right(),
+            0,
+        );
+        if state.search_input {
+            let cursor = area.x + 1 + ftui_text::display_width(&state.query) as u16;
+            frame.cursor_position = Some((cursor.min(end), area.y));
+        }
+    }
+}
+
+impl StatefulWidget for MarkdownView<'_> {
+    type State = MarkdownViewState;
+
+    fn render(&self, area: Rect, frame: &mut Frame, state: &mut Self::State) {
+        state.last_body = Rect::default();
+        state.last_toc = Rect::default();
+        if area.is_empty() {
+            return;
+        }
+        style_area(frame, area, self.style);
+
+        let mut body = area;
+        if state.search_input || !state.query.is_empty() {
+            body.height -= 1;
+            let bar = Rect::new(area.x, body.bottom(), area.width, 1);
+            self.render_search_bar(frame, bar, state);
+        }
+        let toc_width = self.toc_width.min(area.width);
+        let toc = (self.show_toc
+            && !self.doc.headings.is_empty()
+            && toc_width > 0
+            && area.width >= toc_width + 1 + MIN_BODY_WIDTH)
+            .then(|| {
+                let toc = Rect::new(body.x, body.y, toc_width, body.height);
+                body.x += toc_width + 1;
+                body.width -= toc_width + 1;
+                toc
+            });
+        if body.is_empty() {
+            return;
+        }
+        state.last_body = body;
+
+        let rows = state.visible_lines(self.doc);
+        let height = usize::from(body.height);
+        state.scroll = state.scroll.min(rows.len().saturating_sub(height));
+
+        let focused = state
+            .focus
+            .and_then(|focus| targets(self.doc).into_iter().find(|t| t.focus == focus));
+        for (&line, y) in rows[state.scroll..].iter().zip(body.y..body.bottom()) {
+            let folded = self
+                .doc
+                .headings
+                .iter()
+                .position(|h| h.line == line)
+                .is_some_and(|h| state.folded.contains(&h));
+            self.render_line(frame, body, y, line, folded);
+
+            for (index, hit) in state.matches.iter().enumerate() {
+                if hit.line == line {
+                    let style = if state.current_match == Some(index) {
+                        self.current_match_style
+                    } else {
+                        self.match_style
+                    };
+                    style_columns(frame, body, y, hit.column, hit.width, style);
+                }
+            }
+            if let Some(target) = focused.filter(|t| t.line == line) {
+                style_columns(
+                    frame,
+                    body,
+                    y,
+                    target.column,
+                    target.width,
+                    self.focus_style,
+                );
+            }
+        }
+
+        if let Some(toc) = toc {
+            let separator = toc.right();
+            for y in toc.y..toc.bottom() {
+                let mut cell = Cell::from_char('│');
+                apply_style(&mut cell, self.toc_style);
+                frame.buffer.set_fast(separator, y, cell);
+            }
+            let active = rows
+                .get(state.scroll)
+                .and_then(|&line| self.doc.section_at(line));
+            let visible = usize::from(toc.height);
+            let offset = active.map_or(0, |a| (a + 1).saturating_sub(visible));
+            self.render_toc(frame, toc, active, offset);
+            state.last_toc = Rect::new(
+                toc.x,
+                toc.y,
+                toc.width,
+                toc.height
+                    .min(u16::try_from(self.doc.headings.len() - offset).unwrap_or(u16::MAX)),
+            );
+            state.last_toc_offset = offset;
+        }
+    }
+}
+
+fn apply_style(cell: &mut Cell, style: Style) {
+    if let Some(fg) = style.fg {
+        cell.fg = fg;
+    }
+    if let Some(bg) = style.bg {
+        cell.bg = bg;
+    }
+    if let Some(attrs) = style.attrs {
+        let flags: ftui_render::cell::StyleFlags = attrs.into();
+        cell.attrs = cell.attrs.with_flags(flags);
+    }
+}
+
+/// Layer `style` over the existing cells of `area`.
+fn style_area(frame: &mut Frame, area: Rect, style: Style) {
+    if style.is_empty() {
+        return;
+    }
+    for y in area.y..area.bottom() {
+        for x in area.x..area.right() {
+            if let Some(cell) = frame.buffer.get_mut(x, y) {
+                apply_style(cell, style);
+            }
+        }
+    }
+}
+
+/// Layer `style` over `width` columns starting at display `column` of `area`.
+fn style_columns(frame: &mut Frame, area: Rect, y: u16, column: usize, width: usize, style: Style) {
+    let start = usize::from(area.x) + column;
+    let end = (start + width).min(usize::from(area.right()));
+    for x in start..end {
+        if let Some(cell) = frame.buffer.get_mut(x as u16, y) {
+            apply_style(cell, style);
+        }
+    }
+}
+
+/// Draw `text` from (`x`, `y`), stopping before `max_x`, with an optional
+/// hyperlink id. Returns the x position after the last drawn grapheme.
+fn draw_str(
+    frame: &mut Frame,
+    mut x: u16,
+    y: u16,
+    text: &str,
+    style: Style,
+    max_x: u16,
+    link_id: u32,
+) -> u16 {
+    for grapheme in graphemes(text) {
+        let width = grapheme_width(grapheme);
+        if width == 0 {
+            continue;
+        }
+        if usize::from(x) + width > usize::from(max_x) {
+            break;
+        }
+        let content = if width > 1 || grapheme.chars().count() > 1 {
+            let id = frame.intern_with_width(grapheme, u8::try_from(width).unwrap_or(u8::MAX));
+            CellContent::from_grapheme(id)
+        } else if let Some(c) = grapheme.chars().next() {
+            CellContent::from_char(c)
+        } else {
+            continue;
+        };
+        let mut cell = Cell::new(content);
+        apply_style(&mut cell, style);
+        if link_id != 0 {
+            cell.attrs = cell.attrs.with_link(link_id);
+        }
+        frame.buffer.set_fast(x, y, cell);
+        x = x.saturating_add(width as u16);
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::markdown::MarkdownRenderer;
+    use ftui_render::grapheme_pool::GraphemePool;
+    use ftui_render::link_registry::LinkRegistry;
+
+    const DOC: &str = "\
+# Intro
+
+See [usage](#usage) or [the site](https://example.com).
+
+## Usage
+
+Run the tool.
+
+Then run it again.
+
+## Details {#more}
+
+Fine print mentions usage twice: usage.
+";
+
+    fn doc() -> MarkdownDocument {
+        MarkdownRenderer::default().render_document(DOC)
+    }
+
+    fn row(frame: &Frame, y: u16) -> String {
+        (0..frame.buffer.width())
+            .map(|x| {
+                frame
+                    .buffer
+                    .get(x, y)
+                    .and_then(|c| c.content.as_char())
+                    .unwrap_or(' ')
+            })
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    fn render(
+        doc: &MarkdownDocument,
+        state: &mut MarkdownViewState,
+        w: u16,
+        h: u16,
+    ) -> Vec<String> {
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(w, h, &mut pool);
+        MarkdownView::new(doc).render(Rect::new(0, 0, w, h), &mut frame, state);
+        (0..h).map(|y| row(&frame, y)).collect()
+    }
+
+    #[test]
+    fn document_outline_and_links() {
+        let doc = doc();
+        let anchors: Vec<&str> = doc.headings.iter().map(|h| h.anchor.as_str()).collect();
+        assert_eq!(anchors, ["intro", "usage", "more"]);
+        assert_eq!(doc.heading_for_anchor("#usage"), Some(1));
+        assert_eq!(doc.section_body(0), 1..doc.height());
+        assert_eq!(doc.section_body(1).end, doc.headings[2].line);
+
+        let links = doc.links();
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].anchor(), Some("usage"));
+        assert_eq!(links[0].column, 4);
+        assert_eq!(links[0].width, 5);
+        assert_eq!(links[1].url, "https://example.com");
+    }
+
+    #[test]
+    fn tab_cycles_links_and_enter_opens() {
+        let doc = doc();
+        let mut state = MarkdownViewState::default();
+        render(&doc, &mut state, 60, 4);
+        let tab = KeyEvent::new(KeyCode::Tab);
+        let enter = KeyEvent::new(KeyCode::Enter);
+
+        assert_eq!(state.handle_key(&doc, &tab), MarkdownViewAction::Handled);
+        assert_eq!(state.handle_key(&doc, &tab), MarkdownViewAction::Handled);
+        assert_eq!(
+            state.handle_key(&doc, &enter),
+            MarkdownViewAction::OpenLink("https://example.com".into())
+        );
+
+        // Wraps to the anchor link, which scrolls to its heading.
+        state.handle_key(&doc, &tab);
+        assert_eq!(state.handle_key(&doc, &enter), MarkdownViewAction::Handled);
+        let lines = render(&doc, &mut state, 60, 4);
+        assert_eq!(lines[0], "Usage");
+    }
+
+    #[test]
+    fn folding_hides_section_body() {
+        let doc = doc();
+        let mut state = MarkdownViewState::default();
+        assert!(state.toggle_fold(&doc, 1));
+        let lines = render(&doc, &mut state, 60, 10);
+        assert!(lines.iter().any(|l| l == "Usage …"));
+        assert!(!lines.iter().any(|l| l.contains("Run the tool")));
+        assert!(lines.iter().any(|l| l == "Details"));
+
+        // Jumping into the folded section unfolds it.
+        state.search(&doc, "again");
+        assert!(!state.is_folded(1));
+    }
+
+    #[test]
+    fn search_highlights_and_steps_through_matches() {
+        let doc = doc();
+        let mut state = MarkdownViewState::default();
+        render(&doc, &mut state, 60, 20);
+        for c in "/usage".chars() {
+            state.handle_key(&doc, &KeyEvent::new(KeyCode::Char(c)));
+        }
+        state.handle_key(&doc, &KeyEvent::new(KeyCode::Enter));
+        assert_eq!(state.query(), "usage");
+        assert_eq!(state.match_status(), (4, Some(1)));
+        state.handle_key(&doc, &KeyEvent::new(KeyCode::Char('n')));
+        assert_eq!(state.match_status(), (4, Some(2)));
+
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(60, 20, &mut pool);
+        MarkdownView::new(&doc).render(Rect::new(0, 0, 60, 20), &mut frame, &mut state);
+        assert_eq!(row(&frame, 19), "/usage  2/4");
+        // "Usage" heading on line 4 is the current match.
+        assert_eq!(
+            frame.buffer.get(0, 4).unwrap().bg,
+            PackedRgba::rgb(255, 140, 0)
+        );
+    }
+
+    #[test]
+    fn toc_lists_headings_and_click_jumps() {
+        let doc = doc();
+        let mut state = MarkdownViewState::default();
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(50, 4, &mut pool);
+        MarkdownView::new(&doc).toc(true).toc_width(12).render(
+            Rect::new(0, 0, 50, 4),
+            &mut frame,
+            &mut state,
+        );
+        assert_eq!(row(&frame, 0), "Intro       │Intro");
+        assert!(row(&frame, 1).starts_with("  Usage     │"));
+
+        let click = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 3, 2);
+        assert_eq!(
+            state.handle_mouse(&doc, &click),
+            MarkdownViewAction::Handled
+        );
+        assert_eq!(state.top_line(&doc), Some(doc.headings[2].line));
+    }
+
+    #[test]
+    fn task_checkboxes_are_focusable_and_clickable() {
+        let md = "- [ ] one [link](https://a.example)\n- [x] two";
+        let doc = MarkdownRenderer::default().render_document(md);
+        let mut state = MarkdownViewState::default();
+        render(&doc, &mut state, 40, 4);
+        let tab = KeyEvent::new(KeyCode::Tab);
+        let enter = KeyEvent::new(KeyCode::Enter);
+
+        state.handle_key(&doc, &tab);
+        assert_eq!(state.focused_task(), Some(0));
+        assert_eq!(
+            state.handle_key(&doc, &enter),
+            MarkdownViewAction::ToggleTask {
+                index: 0,
+                checked: true
+            }
+        );
+        state.handle_key(&doc, &tab);
+        assert!(state.focused_link(&doc).is_some());
+
+        let click = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 0, 1);
+        assert_eq!(
+            state.handle_mouse(&doc, &click),
+            MarkdownViewAction::ToggleTask {
+                index: 1,
+                checked: false
+            }
+        );
+    }
+
+    #[test]
+    fn image_placements_only_report_fully_visible_images() {
+        let mut doc = doc();
+        doc.images.push(crate::markdown::MarkdownImage {
+            url: "a.png".into(),
+            alt: "a".into(),
+            line: 2,
+            columns: 10,
+            rows: 2,
+            sequence: String::new(),
+        });
+        let mut state = MarkdownViewState::default();
+        render(&doc, &mut state, 60, 4);
+        assert_eq!(state.image_placements(&doc), vec![(0, 0, 2)]);
+        render(&doc, &mut state, 60, 3);
+        assert!(state.image_placements(&doc).is_empty());
+    }
+
+    #[test]
+    fn hyperlinks_register_external_links_only() {
+        let doc = doc();
+        let mut state = MarkdownViewState::default();
+        let mut pool = GraphemePool::new();
+        let mut links = LinkRegistry::new();
+        let mut frame = Frame::with_links(60, 4, &mut pool, &mut links);
+        MarkdownView::new(&doc).hyperlinks(true).render(
+            Rect::new(0, 0, 60, 4),
+            &mut frame,
+            &mut state,
+        );
+        let anchor_cell = frame.buffer.get(4, 2).unwrap().attrs.link_id();
+        let site_cell = frame.buffer.get(17, 2).unwrap().attrs.link_id();
+        assert_eq!(anchor_cell, 0);
+        assert_ne!(site_cell, 0);
+        drop(frame);
+        assert_eq!(links.get(site_cell), Some("https://example.com"));
+    }
+}
@@ -13,6 +13,9 @@ use std::fmt;
 use std::ops::Range;
 use std::sync::Arc;
 
+#[cfg(feature = "syntax-textarea")]
+use ftui_widgets::textarea::{HighlightSpan, HighlightState, LineHighlighter};
+
 // ---------------------------------------------------------------------------
 // Token kinds
 // ---------------------------------------------------------------------------
@@ -187,6 +190,48 @@ pub enum CommentKind {
     Nested(u8),
 }
 
+impl LineState {
+    /// Pack the state into a `u64` (e.g. for a `TextArea` `HighlightState`).
+    ///
+    /// [`LineState::Normal`] always packs to `0`.
+    #[must_use]
+    pub const fn to_bits(self) -> u64 {
+        let (tag, payload): (u64, u64) = match self {
+            Self::Normal => (0, 0),
+            Self::InString(StringKind::Double) => (1, 0),
+            Self::InString(StringKind::Single) => (1, 1),
+            Self::InString(StringKind::Backtick) => (1, 2),
+            Self::InString(StringKind::Triple) => (1, 3),
+            Self::InComment(CommentKind::Block) => (2, 0),
+            Self::InComment(CommentKind::Doc) => (3, 0),
+            Self::InComment(CommentKind::Nested(depth)) => (4, depth as u64),
+            Self::InRawString(hashes) => (5, hashes as u64),
+        };
+        tag | (payload << 8)
+    }
+
+    /// Unpack a state produced by [`LineState::to_bits`].
+    ///
+    /// Unknown encodings fall back to [`LineState::Normal`].
+    #[must_use]
+    pub const fn from_bits(bits: u64) -> Self {
+        let payload = (bits >> 8) as u8;
+        match bits & 0xFF {
+            1 => Self::InString(match payload {
+                1 => StringKind::Single,
+                2 => StringKind::Backtick,
+                3 => StringKind::Triple,
+                _ => StringKind::Double,
+            }),
+            2 => Self::InComment(CommentKind::Block),
+            3 => Self::InComment(CommentKind::Doc),
+            4 => Self::InComment(CommentKind::Nested(payload)),
+            5 => Self::InRawString(payload),
+            _ => Self::Normal,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tokenizer trait
 // ---------------------------------------------------------------------------
@@ -2485,6 +2530,31 @@ impl SyntaxHighlighter {
         self.registry.for_extension(lang).is_some() || self.registry.by_name(lang).is_some()
     }
 
+    /// Build an incremental per-line highlighter for [`TextArea`] and
+    /// [`TextDiff::highlight`].
+    ///
+    /// Falls back to plain text if the language is not recognized. Returns
+    /// `None` if neither the language nor the plain-text tokenizer is
+    /// registered.
+    ///
+    /// [`TextArea`]: ftui_widgets::textarea::TextArea
+    /// [`TextDiff::highlight`]: ftui_widgets::diff_view::TextDiff::highlight
+    #[cfg(feature = "syntax-textarea")]
+    #[must_use]
+    pub fn line_highlighter(&self, lang: &str) -> Option<TokenizerLineHighlighter> {
+        let registry = &self.registry;
+        let index = registry
+            .by_extension
+            .get(&lang.trim_start_matches('.').to_ascii_lowercase())
+            .or_else(|| registry.by_name.get(&lang.to_ascii_lowercase()))
+            .or_else(|| registry.by_extension.get("txt"))
+            .copied()?;
+        Some(TokenizerLineHighlighter::new(
+            Arc::clone(&registry.tokenizers[index]),
+            self.theme.clone(),
+        ))
+    }
+
     /// Highlight code using a language identifier (extension or name).
     ///
     /// Falls back to plain text if the language is not recognized.
@@ -3146,6 +3216,72 @@ impl Tokenizer for MarkdownTokenizer {
     }
 }
 
+// ---------------------------------------------------------------------------
+// TextArea integration
+// ---------------------------------------------------------------------------
+
+/// Adapts a [`Tokenizer`] + [`HighlightTheme`] to [`LineHighlighter`], so a
+/// `TextArea` can re-lex edited lines incrementally.
+///
+/// The tokenizer's [`LineState`] is carried between lines via
+/// [`LineState::to_bits`].
+#[cfg(feature = "syntax-textarea")]
+#[derive(Clone)]
+pub struct TokenizerLineHighlighter {
+    tokenizer: Arc<dyn Tokenizer>,
+    theme: HighlightTheme,
+}
+
+#[cfg(feature = "syntax-textarea")]
+impl fmt::Debug for TokenizerLineHighlighter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenizerLineHighlighter")
+            .field("tokenizer", &self.tokenizer.name())
+            .field("theme", &self.theme)
+            .finish()
+    }
+}
+
+#[cfg(feature = "syntax-textarea")]
+impl TokenizerLineHighlighter {
+    /// Create an adapter from a shared tokenizer and theme.
+    #[must_use]
+    pub fn new(tokenizer: Arc<dyn Tokenizer>, theme: HighlightTheme) -> Self {
+        Self { tokenizer, theme }
+    }
+
+    /// Name of the wrapped tokenizer.
+    #[must_use]
+    pub fn language(&self) -> &'static str {
+        self.tokenizer.name()
+    }
+}
+
+#[cfg(feature = "syntax-textarea")]
+impl LineHighlighter for TokenizerLineHighlighter {
+    fn highlight_line(
+        &self,
+        line: &str,
+        state: HighlightState,
+    ) -> (Vec<HighlightSpan>, HighlightState) {
+        let (tokens, state_after) = self
+            .tokenizer
+            .tokenize_line(line, LineState::from_bits(state));
+        let spans = tokens
+            .into_iter()
+            .filter(|token| !token.is_empty() && token.range.end <= line.len())
+            .map(|token| {
+                HighlightSpan::new(
+                    token.range.start,
+                    token.range.end,
+                    self.theme.style_for(token.kind),
+                )
+            })
+            .collect();
+        (spans, state_after.to_bits())
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -5250,4 +5386,69 @@ fn main() {
             assert_eq!(a, b);
         }
     }
+
+    // -----------------------------------------------------------------------
+    // TextArea integration
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn line_state_bits_roundtrip() {
+        let states = [
+            LineState::Normal,
+            LineState::InString(StringKind::Double),
+            LineState::InString(StringKind::Single),
+            LineState::InString(StringKind::Backtick),
+            LineState::InString(StringKind::Triple),
+            LineState::InComment(CommentKind::Block),
+            LineState::InComment(CommentKind::Doc),
+            LineState::InComment(CommentKind::Nested(7)),
+            LineState::InRawString(3),
+        ];
+        for state in states {
+            assert_eq!(LineState::from_bits(state.to_bits()), state);
+        }
+        assert_eq!(LineState::Normal.to_bits(), 0);
+    }
+
+    #[cfg(feature = "syntax-textarea")]
+    #[test]
+    fn line_highlighter_carries_block_comment_state() {
+        let hl = SyntaxHighlighter::new();
+        let lh = hl.line_highlighter("rs").unwrap();
+        assert_eq!(lh.language(), "Rust");
+
+        let (_, state) = lh.highlight_line("let a = 1; /* open", 0);
+        assert_ne!(state, 0);
+        let (spans, state) = lh.highlight_line("still comment */ let", state);
+        assert_eq!(state, 0);
+        assert_eq!(spans[0].start, 0);
+        assert_eq!(
+            spans[0].style,
+            hl.theme().style_for(TokenKind::CommentBlock)
+        );
+    }
+
+    #[cfg(feature = "syntax-textarea")]
+    #[test]
+    fn line_highlighter_unknown_language_is_plain() {
+        let lh = SyntaxHighlighter::new()
+            .line_highlighter("no-such-lang")
+            .unwrap();
+        assert_eq!(lh.language(), "Plain");
+    }
+
+    #[cfg(feature = "syntax-textarea")]
+    #[test]
+    fn line_highlighter_drives_textarea() {
+        use ftui_widgets::textarea::TextArea;
+
+        let hl = SyntaxHighlighter::new();
+        let ta = TextArea::new()
+            .with_text("fn main() {}")
+            .with_highlighter(Arc::new(hl.line_highlighter("rs").unwrap()));
+        let spans = ta.line_highlights(0);
+        assert_eq!(spans[0].start, 0);
+        assert_eq!(spans[0].end, 2);
+        assert_eq!(spans[0].style, hl.theme().style_for(TokenKind::Keyword));
+    }
 }
@@ -4,11 +4,14 @@
 //!
 //! This module provides a small set of coherent, high-contrast themes and
 //! color tokens that resolve against the current theme at runtime.
+//! Additional themes can be registered at runtime with [`register_theme`]
+//! (or loaded from disk with `register_theme_file` under the `theme-files`
+//! feature); they join the [`cycle_theme`] rotation after the built-ins.
 
 use std::cell::Cell;
 use std::sync::OnceLock;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::{Mutex, MutexGuard};
+use std::sync::{Mutex, MutexGuard, RwLock};
 
 // Thread-local flag to track if current thread holds THEME_TEST_LOCK.
 // Used for reentrant-style locking in set_theme() when called from within ScopedThemeLock.
@@ -19,7 +22,7 @@ thread_local! {
 #[cfg(feature = "syntax")]
 use crate::syntax::HighlightTheme;
 use ftui_render::cell::PackedRgba;
-use ftui_style::Style;
+use ftui_style::{Color, Style, Theme};
 
 /// Built-in theme identifiers.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -126,6 +129,75 @@ pub struct ThemePalette {
     pub syntax_punctuation: PackedRgba,
 }
 
+impl ThemePalette {
+    /// Derive a palette from semantic [`Theme`] slots (e.g. one loaded from a
+    /// theme file), resolving adaptive colors for `is_dark`.
+    #[must_use]
+    pub fn from_theme(theme: &Theme, is_dark: bool) -> Self {
+        let resolved = theme.resolve(is_dark);
+        let rgb = |color: Color| {
+            let rgb = color.to_rgb();
+            PackedRgba::rgb(rgb.r, rgb.g, rgb.b)
+        };
+        let bg = rgb(resolved.background);
+        let text = rgb(resolved.text);
+        let primary = rgb(resolved.primary);
+        let secondary = rgb(resolved.secondary);
+        let accent = rgb(resolved.accent);
+        let success = rgb(resolved.success);
+        let warning = rgb(resolved.warning);
+        let error = rgb(resolved.error);
+        let info = rgb(resolved.info);
+        let muted = rgb(resolved.text_muted);
+        let subtle = rgb(resolved.text_subtle);
+        let shade = if is_dark {
+            PackedRgba::rgb(0, 0, 0)
+        } else {
+            PackedRgba::rgb(255, 255, 255)
+        };
+        Self {
+            bg_deep: blend_colors(shade, bg, 0.3),
+            bg_base: bg,
+            bg_surface: rgb(resolved.surface),
+            bg_overlay: rgb(resolved.overlay),
+            bg_highlight: rgb(resolved.selection_bg),
+            fg_primary: text,
+            fg_secondary: muted,
+            fg_muted: subtle,
+            fg_disabled: blend_colors(subtle, bg, 0.6),
+            accent_primary: primary,
+            accent_secondary: secondary,
+            accent_success: success,
+            accent_warning: warning,
+            accent_error: error,
+            accent_info: info,
+            accent_link: accent,
+            accent_slots: [
+                primary,
+                secondary,
+                accent,
+                info,
+                success,
+                warning,
+                error,
+                rgb(resolved.border_focused),
+                blend_colors(primary, secondary, 0.5),
+                blend_colors(accent, success, 0.5),
+                blend_colors(warning, error, 0.5),
+                blend_colors(info, primary, 0.5),
+            ],
+            syntax_keyword: secondary,
+            syntax_string: success,
+            syntax_number: warning,
+            syntax_comment: subtle,
+            syntax_function: primary,
+            syntax_type: accent,
+            syntax_operator: muted,
+            syntax_punctuation: muted,
+        }
+    }
+}
+
 const THEMES: [ThemePalette; 5] = [
     ThemePalette {
         bg_deep: PackedRgba::rgb(10, 14, 20),
@@ -171,7 +243,7 @@ const THEMES: [ThemePalette; 5] = [
         bg_deep: PackedRgba::rgb(43, 43, 43),
         bg_base: PackedRgba::rgb(50, 50, 50),
         bg_surface: PackedRgba::rgb(60, 63, 65),
-        bg_overlay: PackedRgba::rgb(75, 80, 82),
+        bg_overlay: PackedRgba::rgb(68, 72, 74), // Darkened for WCAG 4.5:1 text
         bg_highlight: PackedRgba::rgb(90, 96, 98),
         fg_primary: PackedRgba::rgb(169, 183, 198),
         fg_secondary: PackedRgba::rgb(146, 161, 177),
@@ -332,10 +404,17 @@ const THEMES: [ThemePalette; 5] = [
 
 static CURRENT_THEME: AtomicUsize = AtomicUsize::new(0);
 
+/// Active registered theme as `index + 1`, or 0 while a built-in is active.
+static CURRENT_REGISTERED: AtomicUsize = AtomicUsize::new(0);
+
 /// Internal: set theme without acquiring the lock.
 /// Used by `ScopedThemeLock::new()` which already holds the lock.
 fn set_theme_internal(theme: ThemeId) {
-    CURRENT_THEME.store(theme.index(), Ordering::Relaxed);
+    let builtin_changed = CURRENT_THEME.swap(theme.index(), Ordering::Relaxed) != theme.index();
+    let left_registered = CURRENT_REGISTERED.swap(0, Ordering::Relaxed) != 0;
+    if builtin_changed || left_registered {
+        ftui_style::bump_theme_epoch();
+    }
 }
 
 /// Set the active theme.
@@ -355,21 +434,35 @@ pub fn set_theme(theme: ThemeId) {
     }
 }
 
-/// Get the active theme.
+/// Get the active built-in theme.
+///
+/// While a registered theme is active this is the built-in that was selected
+/// before it; see [`current_registered_theme`].
 pub fn current_theme() -> ThemeId {
     ThemeId::from_index(CURRENT_THEME.load(Ordering::Relaxed))
 }
 
-/// Get the active theme name.
+/// Get the active theme name, including registered theme names.
 pub fn current_theme_name() -> &'static str {
-    current_theme().name()
+    active_registered().map_or_else(|| current_theme().name(), |entry| &entry.name)
 }
 
-/// Cycle to the next theme.
+/// Cycle to the next theme: the built-ins, then registered themes, then wrap.
+///
+/// Returns the active built-in theme, as [`current_theme`] does.
 pub fn cycle_theme() -> ThemeId {
-    let next = current_theme().next();
-    set_theme(next);
-    next
+    let count = registered_count();
+    match current_registered_theme() {
+        Some(id) if id.index() + 1 < count => {
+            set_registered_theme(RegisteredThemeId(id.0 + 1));
+        }
+        Some(_) => set_theme(ThemeId::CyberpunkAurora),
+        None if current_theme() == ThemeId::HighContrast && count > 0 => {
+            set_registered_theme(RegisteredThemeId(0));
+        }
+        None => set_theme(current_theme().next()),
+    }
+    current_theme()
 }
 
 /// Return the palette for a theme.
@@ -377,16 +470,199 @@ pub fn palette(theme: ThemeId) -> &'static ThemePalette {
     &THEMES[theme.index()]
 }
 
-/// Return the current palette.
+/// Return the current palette, following the active registered theme if any.
 pub fn current_palette() -> &'static ThemePalette {
-    palette(current_theme())
+    active_registered().map_or_else(|| palette(current_theme()), |entry| &entry.palette)
 }
 
-/// Return the total number of themes.
+/// Return the number of built-in themes.
 pub const fn theme_count() -> usize {
     ThemeId::ALL.len()
 }
 
+/// Id of a theme registered at runtime with [`register_theme`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RegisteredThemeId(u16);
+
+impl RegisteredThemeId {
+    /// Position in the registration order.
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Error returned when a theme cannot be registered.
+#[derive(Debug)]
+pub enum RegisterThemeError {
+    /// More than `u16::MAX` distinct theme names were registered.
+    TooManyThemes,
+    /// The theme file could not be loaded.
+    #[cfg(feature = "theme-files")]
+    File(ftui_style::ThemeFileError),
+}
+
+impl std::fmt::Display for RegisterThemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooManyThemes => write!(f, "too many registered themes (max {})", u16::MAX),
+            #[cfg(feature = "theme-files")]
+            Self::File(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for RegisterThemeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::TooManyThemes => None,
+            #[cfg(feature = "theme-files")]
+            Self::File(err) => Some(err),
+        }
+    }
+}
+
+#[cfg(feature = "theme-files")]
+impl From<ftui_style::ThemeFileError> for RegisterThemeError {
+    fn from(err: ftui_style::ThemeFileError) -> Self {
+        Self::File(err)
+    }
+}
+
+/// A runtime-registered theme.
+///
+/// Entries are leaked so [`current_palette`] and [`semantic_styles_cached`]
+/// can hand out `'static` borrows without locking; each registration retains
+/// one entry for the rest of the process.
+struct RegisteredTheme {
+    name: String,
+    palette: ThemePalette,
+    styles: SemanticStyles,
+}
+
+static REGISTERED_THEMES: RwLock<Vec<&'static RegisteredTheme>> = RwLock::new(Vec::new());
+
+fn registered(id: RegisteredThemeId) -> Option<&'static RegisteredTheme> {
+    let themes = REGISTERED_THEMES.read().unwrap_or_else(|e| e.into_inner());
+    themes.get(id.index()).copied()
+}
+
+fn registered_count() -> usize {
+    REGISTERED_THEMES
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .len()
+}
+
+/// The active registered theme. Returns early without locking while a
+/// built-in theme is active.
+fn active_registered() -> Option<&'static RegisteredTheme> {
+    current_registered_theme().and_then(registered)
+}
+
+/// Register a theme so it can be selected and joins the [`cycle_theme`] rotation.
+///
+/// Registering a name again replaces that theme's palette and keeps its id.
+/// Register themes at startup or on an explicit reload: every call retains
+/// its palette for the lifetime of the process.
+pub fn register_theme(
+    name: &str,
+    palette: ThemePalette,
+) -> Result<RegisteredThemeId, RegisterThemeError> {
+    let mut themes = REGISTERED_THEMES.write().unwrap_or_else(|e| e.into_inner());
+    let pos = themes.iter().position(|entry| entry.name == name);
+    let index = pos.unwrap_or(themes.len());
+    let id =
+        RegisteredThemeId(u16::try_from(index).map_err(|_| RegisterThemeError::TooManyThemes)?);
+    let entry: &'static RegisteredTheme = Box::leak(Box::new(RegisteredTheme {
+        name: name.to_owned(),
+        palette,
+        styles: semantic_styles_in(&palette),
+    }));
+    if pos.is_some() {
+        themes[index] = entry;
+        if current_registered_theme() == Some(id) {
+            ftui_style::bump_theme_epoch();
+        }
+    } else {
+        themes.push(entry);
+    }
+    Ok(id)
+}
+
+/// Registered themes, in rotation order.
+pub fn registered_themes() -> Vec<RegisteredThemeId> {
+    (0..registered_count() as u16)
+        .map(RegisteredThemeId)
+        .collect()
+}
+
+/// Palette of a registered theme, or `None` for an unknown id.
+pub fn registered_palette(id: RegisteredThemeId) -> Option<&'static ThemePalette> {
+    registered(id).map(|entry| &entry.palette)
+}
+
+/// Display name of a registered theme, or `None` for an unknown id.
+pub fn registered_theme_name(id: RegisteredThemeId) -> Option<&'static str> {
+    registered(id).map(|entry| entry.name.as_str())
+}
+
+/// Look up a registered theme by display name (ASCII case-insensitive).
+pub fn registered_theme_by_name(name: &str) -> Option<RegisteredThemeId> {
+    let themes = REGISTERED_THEMES.read().unwrap_or_else(|e| e.into_inner());
+    themes
+        .iter()
+        .position(|entry| entry.name.eq_ignore_ascii_case(name))
+        .map(|pos| RegisteredThemeId(pos as u16))
+}
+
+/// Activate a registered theme. Unknown ids are ignored.
+///
+/// [`set_theme`] switches back to a built-in theme.
+pub fn set_registered_theme(id: RegisteredThemeId) {
+    if registered(id).is_none() {
+        return;
+    }
+    if CURRENT_REGISTERED.swap(id.index() + 1, Ordering::Relaxed) != id.index() + 1 {
+        ftui_style::bump_theme_epoch();
+    }
+}
+
+/// The active registered theme, or `None` while a built-in theme is active.
+pub fn current_registered_theme() -> Option<RegisteredThemeId> {
+    match CURRENT_REGISTERED.load(Ordering::Relaxed) {
+        0 => None,
+        n => Some(RegisteredThemeId((n - 1) as u16)),
+    }
+}
+
+/// Remove all registered themes, returning to the active built-in theme if
+/// one of them was active.
+pub fn clear_registered_themes() {
+    REGISTERED_THEMES
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .clear();
+    if CURRENT_REGISTERED.swap(0, Ordering::Relaxed) != 0 {
+        ftui_style::bump_theme_epoch();
+    }
+}
+
+/// Load a theme file or terminal color scheme and register it.
+///
+/// See [`ftui_style::load_theme_file`] for the accepted formats. `is_dark`
+/// picks the side of adaptive light/dark colors.
+#[cfg(feature = "theme-files")]
+pub fn register_theme_file(
+    path: impl AsRef<std::path::Path>,
+    is_dark: bool,
+) -> Result<RegisteredThemeId, RegisterThemeError> {
+    let loaded = ftui_style::load_theme_file(path)?;
+    register_theme(
+        &loaded.name,
+        ThemePalette::from_theme(&loaded.theme, is_dark),
+    )
+}
+
 /// Mutex for serializing theme access in tests.
 ///
 /// Used by `ScopedThemeLock` to prevent race conditions when multiple
@@ -1110,7 +1386,10 @@ pub struct BadgeSpec {
 static SEMANTIC_STYLES_ALL: OnceLock<[SemanticStyles; ThemeId::ALL.len()]> = OnceLock::new();
 
 fn semantic_styles_for(theme: ThemeId) -> SemanticStyles {
-    let palette = palette(theme);
+    semantic_styles_in(palette(theme))
+}
+
+fn semantic_styles_in(palette: &ThemePalette) -> SemanticStyles {
     let base_bg = palette.bg_base;
     let opacity = SEMANTIC_TINT_OPACITY;
     SemanticStyles {
@@ -1204,8 +1483,12 @@ pub fn priority_badge(priority: PriorityBadge) -> BadgeSpec {
     }
 }
 
-/// Borrow pre-computed semantic styles for the current theme (cached per built-in theme).
+/// Borrow pre-computed semantic styles for the current theme (cached per
+/// built-in theme and per registered theme).
 pub fn semantic_styles_cached() -> &'static SemanticStyles {
+    if let Some(entry) = active_registered() {
+        return &entry.styles;
+    }
     let all = SEMANTIC_STYLES_ALL.get_or_init(|| ThemeId::ALL.map(semantic_styles_for));
     &all[current_theme().index()]
 }
@@ -2172,6 +2455,45 @@ mod tests {
 
     // ── Edge-case: high contrast theme ───────────────────────────────
 
+    /// Semantic [`Theme`] slots for a palette, mirroring [`ThemePalette::from_theme`].
+    fn audit_theme_of(pal: &ThemePalette) -> Theme {
+        let color = |c: PackedRgba| Color::rgb(c.r(), c.g(), c.b());
+        Theme::builder()
+            .background(color(pal.bg_base))
+            .surface(color(pal.bg_surface))
+            .overlay(color(pal.bg_overlay))
+            .text(color(pal.fg_primary))
+            .text_muted(color(pal.fg_secondary))
+            .text_subtle(color(pal.fg_muted))
+            .primary(color(pal.accent_primary))
+            .secondary(color(pal.accent_secondary))
+            .accent(color(pal.accent_link))
+            .success(color(pal.accent_success))
+            .warning(color(pal.accent_warning))
+            .error(color(pal.accent_error))
+            .info(color(pal.accent_info))
+            .selection_bg(color(pal.bg_highlight))
+            .selection_fg(color(pal.fg_primary))
+            .build()
+    }
+
+    #[test]
+    fn builtin_palettes_meet_wcag_aa_for_body_text() {
+        use ftui_style::theme_audit::{ColorVision, ThemeAudit, WcagLevel};
+
+        let audit = ThemeAudit::new(WcagLevel::Aa).with_visions([ColorVision::Normal]);
+        for theme in ThemeId::ALL {
+            let report = audit.audit_theme(theme.name(), &audit_theme_of(palette(theme)), true);
+            assert!(
+                report
+                    .failures
+                    .iter()
+                    .all(|f| !f.pair.starts_with("text on")),
+                "{report}"
+            );
+        }
+    }
+
     #[test]
     fn high_contrast_theme_has_pure_black_bg() {
         let pal = palette(ThemeId::HighContrast);
@@ -2194,4 +2516,98 @@ mod tests {
             "HighContrast fg/bg should be ~21:1, got {ratio}"
         );
     }
+
+    #[test]
+    fn registered_themes_join_rotation() {
+        let _guard = ScopedThemeLock::new(ThemeId::HighContrast);
+        let ocean = ftui_style::theme::themes::nord();
+        let id = register_theme("Ocean", ThemePalette::from_theme(&ocean, true)).unwrap();
+        assert_eq!(id.index(), 0);
+        assert_eq!(registered_theme_name(id), Some("Ocean"));
+        assert_eq!(registered_theme_by_name("ocean"), Some(id));
+        assert_eq!(registered_themes(), vec![id]);
+        assert_eq!(current_registered_theme(), None);
+
+        // HighContrast -> Ocean -> CyberpunkAurora
+        assert_eq!(cycle_theme(), ThemeId::HighContrast);
+        assert_eq!(current_registered_theme(), Some(id));
+        assert_eq!(current_theme_name(), "Ocean");
+        assert_eq!(
+            current_palette().bg_base,
+            PackedRgba::rgb(46, 52, 64),
+            "palette should follow the registered theme"
+        );
+        assert_eq!(
+            *semantic_styles_cached(),
+            semantic_styles_in(registered_palette(id).unwrap())
+        );
+        assert_eq!(cycle_theme(), ThemeId::CyberpunkAurora);
+        assert_eq!(current_registered_theme(), None);
+
+        // Re-registering a name keeps its id.
+        let light = ThemePalette::from_theme(&ftui_style::theme::themes::light(), false);
+        assert_eq!(register_theme("Ocean", light).unwrap(), id);
+        assert_eq!(registered_palette(id).unwrap().bg_base, light.bg_base);
+        assert_eq!(registered_themes(), vec![id]);
+
+        set_registered_theme(id);
+        set_theme(ThemeId::Darcula);
+        assert_eq!(current_registered_theme(), None);
+        assert_eq!(current_palette().bg_base, palette(ThemeId::Darcula).bg_base);
+
+        set_registered_theme(id);
+        clear_registered_themes();
+        assert_eq!(current_registered_theme(), None);
+        assert_eq!(current_theme(), ThemeId::Darcula);
+        assert_eq!(registered_theme_name(id), None);
+        set_registered_theme(id);
+        assert_eq!(current_registered_theme(), None);
+        set_theme(ThemeId::HighContrast);
+        assert_eq!(cycle_theme(), ThemeId::CyberpunkAurora);
+    }
+
+    #[test]
+    fn palette_from_theme_maps_semantic_slots() {
+        let theme = ftui_style::theme::themes::dracula().resolve(true);
+        let pal = ThemePalette::from_theme(&ftui_style::theme::themes::dracula(), true);
+        let packed = |color: Color| {
+            let rgb = color.to_rgb();
+            PackedRgba::rgb(rgb.r, rgb.g, rgb.b)
+        };
+        assert_eq!(pal.bg_base, packed(theme.background));
+        assert_eq!(pal.fg_primary, packed(theme.text));
+        assert_eq!(pal.accent_error, packed(theme.error));
+        assert_eq!(pal.accent_slots[0], pal.accent_primary);
+        assert!(
+            contrast::contrast_ratio(pal.fg_primary, pal.bg_base)
+                > contrast::contrast_ratio(pal.fg_disabled, pal.bg_base)
+        );
+    }
+
+    #[cfg(feature = "theme-files")]
+    #[test]
+    fn register_theme_file_loads_and_registers() {
+        let _guard = ScopedThemeLock::new(ThemeId::CyberpunkAurora);
+        let dir = std::env::temp_dir().join(format!("ftui-extras-theme-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("harbor.toml");
+        std::fs::write(
+            &path,
+            "name = \"Harbor\"\n[colors]\nbackground = { light = \"#ffffff\", dark = \"#101820\" }\n",
+        )
+        .unwrap();
+        let id = register_theme_file(&path, true).unwrap();
+        assert_eq!(registered_theme_name(id), Some("Harbor"));
+        assert_eq!(
+            registered_palette(id).unwrap().bg_base,
+            PackedRgba::rgb(0x10, 0x18, 0x20)
+        );
+
+        std::fs::write(&path, "[colors]\ntext = \"#12\"\n").unwrap();
+        let err = register_theme_file(&path, true).unwrap_err();
+        assert!(err.to_string().contains("colors.text"), "{err}");
+
+        clear_registered_themes();
+        std::fs::remove_dir_all(&dir).ok();
+    }
 }
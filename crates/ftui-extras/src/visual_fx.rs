@@ -22,6 +22,7 @@
 use ftui_core::geometry::Rect;
 use ftui_render::cell::PackedRgba;
 use ftui_render::frame::Frame;
+use ftui_style::{ColorCache, ColorProfile, Palette, Rgb};
 use ftui_widgets::Widget;
 use std::cell::RefCell;
 use std::fmt;
@@ -1015,6 +1016,89 @@ impl ScrimOpacity {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Ordered dithering
+// ---------------------------------------------------------------------------
+
+/// 4x4 Bayer threshold matrix (values 0..16).
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Ordered (Bayer) dithering for gradient fills on 256/16-color terminals.
+///
+/// Each cell's color is nudged by a position-dependent offset before being
+/// quantized to the palette, so smooth gradients become fine patterns instead
+/// of wide bands. Output colors are exact palette entries, which the final
+/// downgrade maps losslessly. Quantization goes through a [`ColorCache`], so
+/// repeated colors stay cheap.
+///
+/// Deterministic: the pattern depends only on absolute cell coordinates.
+#[derive(Debug)]
+pub struct OrderedDither {
+    profile: ColorProfile,
+    spread: f32,
+    cache: RefCell<ColorCache>,
+}
+
+impl OrderedDither {
+    /// Dither against the installed terminal palette
+    /// ([`ftui_style::set_terminal_palette`]), or the xterm default palette.
+    #[must_use]
+    pub fn new(profile: ColorProfile) -> Self {
+        let palette = ftui_style::terminal_palette().unwrap_or_else(Palette::xterm);
+        Self::with_palette(profile, palette)
+    }
+
+    /// Dither against a specific (e.g. probed) palette.
+    #[must_use]
+    pub fn with_palette(profile: ColorProfile, palette: Palette) -> Self {
+        let spread = match profile {
+            ColorProfile::TrueColor => 0.0,
+            ColorProfile::Ansi256 => 40.0,
+            ColorProfile::Ansi16 => 96.0,
+            ColorProfile::Mono => 255.0,
+        };
+        Self {
+            profile,
+            spread,
+            cache: RefCell::new(ColorCache::with_palette(profile, palette)),
+        }
+    }
+
+    /// Override the per-channel offset range (default depends on the profile).
+    #[must_use]
+    pub fn with_spread(mut self, spread: f32) -> Self {
+        self.spread = spread.max(0.0);
+        self
+    }
+
+    /// Target color profile.
+    #[inline]
+    pub const fn profile(&self) -> ColorProfile {
+        self.profile
+    }
+
+    /// Quantize `color` for the cell at `(x, y)`. Alpha is preserved.
+    ///
+    /// True-color profiles pass colors through unchanged.
+    pub fn apply(&self, color: PackedRgba, x: u16, y: u16) -> PackedRgba {
+        if self.profile == ColorProfile::TrueColor {
+            return color;
+        }
+        let threshold = BAYER_4X4[y as usize % 4][x as usize % 4];
+        let offset = ((f32::from(threshold) + 0.5) / 16.0 - 0.5) * self.spread;
+        let nudge = |c: u8| (f32::from(c) + offset).round().clamp(0.0, 255.0) as u8;
+        let target = Rgb::new(nudge(color.r()), nudge(color.g()), nudge(color.b()));
+
+        let mut cache = self.cache.borrow_mut();
+        let quantized = cache.downgrade_rgb(target);
+        let rgb = match cache.palette() {
+            Some(palette) => palette.resolve(quantized),
+            None => quantized.to_rgb(),
+        };
+        PackedRgba::rgba(rgb.r, rgb.g, rgb.b, color.a())
+    }
+}
+
 /// Backdrop widget: renders a [`BackdropFx`] into **cell backgrounds only**.
 ///
 /// The Backdrop:
@@ -1033,6 +1117,8 @@ pub struct Backdrop {
     /// Optional quality override for demos/testing.
     /// When `None`, quality is derived from `frame.buffer.degradation`.
     quality_override: Option<FxQuality>,
+    /// Optional ordered dithering for limited-color terminals.
+    dither: Option<OrderedDither>,
     frame: u64,
     time_seconds: f64,
 }
@@ -1049,6 +1135,7 @@ impl Backdrop {
             effect_opacity: 0.35,
             scrim: Scrim::Off,
             quality_override: None,
+            dither: None,
             frame: 0,
             time_seconds: 0.0,
         }
@@ -1085,6 +1172,12 @@ impl Backdrop {
         self.scrim = scrim;
     }
 
+    /// Enable ordered dithering of the final backgrounds (`None` disables).
+    #[inline]
+    pub fn set_dither(&mut self, dither: Option<OrderedDither>) {
+        self.dither = dither;
+    }
+
     // -----------------------------------------------------------------------
     // Builder-style chained methods (bd-l8x9.2.3)
     // -----------------------------------------------------------------------
@@ -1132,6 +1225,14 @@ impl Backdrop {
         self
     }
 
+    /// Dither backgrounds for a limited-color terminal and return self for chaining.
+    #[must_use]
+    #[inline]
+    pub fn with_dither(mut self, dither: OrderedDither) -> Self {
+        self.dither = Some(dither);
+        self
+    }
+
     /// Preset: subtle backdrop with low opacity and no scrim.
     ///
     /// Good for backgrounds where legibility of foreground content is paramount.
@@ -1260,12 +1361,16 @@ impl Widget for Backdrop {
                 let mut bg = fx_color.over(base);
                 bg = self.scrim.overlay_at(&self.theme, dx, dy, w, h).over(bg);
 
-                if let Some(cell) = frame.buffer.get_mut(clipped.x + dx, clipped.y + dy) {
+                let (x, y) = (clipped.x + dx, clipped.y + dy);
+                if let Some(cell) = frame.buffer.get_mut(x, y) {
                     if region_opacity < 1.0 {
                         cell.bg = bg.with_opacity(region_opacity).over(cell.bg);
                     } else {
                         cell.bg = bg;
                     }
+                    if let Some(dither) = &self.dither {
+                        cell.bg = dither.apply(cell.bg, x, y);
+                    }
                 }
             }
         }
@@ -3158,4 +3263,59 @@ mod tests {
         // base_fill should be updated to light theme's bg_surface
         assert_eq!(backdrop.base_fill, light.bg_surface);
     }
+
+    #[test]
+    fn dither_truecolor_passthrough() {
+        let dither = OrderedDither::new(ColorProfile::TrueColor);
+        let color = PackedRgba::rgb(12, 34, 56);
+        assert_eq!(dither.apply(color, 3, 7), color);
+    }
+
+    #[test]
+    fn dither_outputs_palette_entries_and_mixes_levels() {
+        let palette = Palette::xterm();
+        let dither = OrderedDither::new(ColorProfile::Ansi256);
+        // Halfway between two gray-ramp steps: a flat fill should mix both.
+        let color = PackedRgba::rgb(113, 113, 113);
+        let mut seen = std::collections::HashSet::new();
+        for y in 0..4 {
+            for x in 0..4 {
+                let out = dither.apply(color, x, y);
+                let rgb = Rgb::new(out.r(), out.g(), out.b());
+                assert!((16..=255).any(|i| palette.get(i) == rgb), "{rgb:?}");
+                seen.insert(rgb);
+            }
+        }
+        assert!(seen.len() >= 2, "expected a mixed pattern, got {seen:?}");
+        // Deterministic per position.
+        assert_eq!(dither.apply(color, 1, 2), dither.apply(color, 5, 6));
+    }
+
+    #[test]
+    fn dither_preserves_alpha() {
+        let dither = OrderedDither::new(ColorProfile::Ansi16).with_spread(0.0);
+        let out = dither.apply(PackedRgba::rgba(250, 5, 5, 128), 0, 0);
+        assert_eq!(out.a(), 128);
+        assert_eq!((out.r(), out.g(), out.b()), (255, 0, 0));
+    }
+
+    #[test]
+    fn backdrop_with_dither_quantizes_backgrounds() {
+        let theme = ThemeInputs::default_dark();
+        let backdrop = Backdrop::new(Box::new(SolidBg), theme)
+            .with_dither(OrderedDither::new(ColorProfile::Ansi16));
+
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(4, 2, &mut pool);
+        backdrop.render(Rect::new(0, 0, 4, 2), &mut frame);
+
+        let palette = Palette::xterm();
+        for y in 0..2 {
+            for x in 0..4 {
+                let bg = frame.buffer.get(x, y).unwrap().bg;
+                let rgb = Rgb::new(bg.r(), bg.g(), bg.b());
+                assert!((0..16).any(|i| palette.get(i) == rgb), "{rgb:?}");
+            }
+        }
+    }
 }
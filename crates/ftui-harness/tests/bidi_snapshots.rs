@@ -0,0 +1,124 @@
+#![forbid(unsafe_code)]
+
+//! Golden snapshot tests for bidirectional text in widgets.
+//!
+//! Covers mixed Hebrew/Arabic/Latin content in Paragraph (plain and
+//! wrapped), TextInput and TextArea, including cursor placement after
+//! visual movement and a selection spanning a direction change.
+//!
+//! Run `BLESS=1 cargo test --package ftui-harness bidi_snapshots` to
+//! create/update snapshots.
+
+use ftui_core::event::{Event, KeyCode, KeyEvent, Modifiers};
+use ftui_core::geometry::Rect;
+use ftui_harness::{assert_snapshot, assert_snapshot_ansi};
+use ftui_render::frame::Frame;
+use ftui_render::grapheme_pool::GraphemePool;
+use ftui_style::Style;
+use ftui_text::{Text, WrapMode};
+use ftui_widgets::input::TextInput;
+use ftui_widgets::paragraph::Paragraph;
+use ftui_widgets::textarea::{TextArea, TextAreaState};
+use ftui_widgets::{StatefulWidget, Widget};
+
+/// "shalom" in Hebrew.
+const SHALOM: &str = "\u{05E9}\u{05DC}\u{05D5}\u{05DD}";
+/// "salam" in Arabic.
+const SALAM: &str = "\u{0633}\u{0644}\u{0627}\u{0645}";
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code))
+}
+
+fn shift_key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code).with_modifiers(Modifiers::SHIFT))
+}
+
+// ============================================================================
+// Paragraph
+// ============================================================================
+
+#[test]
+fn snapshot_bidi_paragraph_mixed() {
+    let text = Text::raw(format!(
+        "Say {SHALOM} twice.\n{SHALOM} (123) abc\n{SALAM}, {SHALOM}!"
+    ));
+    let para = Paragraph::new(text);
+    let area = Rect::new(0, 0, 24, 3);
+    let mut pool = GraphemePool::new();
+    let mut frame = Frame::new(24, 3, &mut pool);
+    para.render(area, &mut frame);
+    assert_snapshot!("bidi_paragraph_mixed", &frame.buffer);
+}
+
+#[test]
+fn snapshot_bidi_paragraph_wrapped() {
+    let text = Text::raw(format!("{SHALOM} {SALAM} {SHALOM} end {SALAM}"));
+    let para = Paragraph::new(text).wrap(WrapMode::Word);
+    let area = Rect::new(0, 0, 10, 4);
+    let mut pool = GraphemePool::new();
+    let mut frame = Frame::new(10, 4, &mut pool);
+    para.render(area, &mut frame);
+    assert_snapshot!("bidi_paragraph_wrapped", &frame.buffer);
+}
+
+// ============================================================================
+// TextInput
+// ============================================================================
+
+#[test]
+fn snapshot_bidi_input_cursor_in_rtl_run() {
+    let mut input = TextInput::new()
+        .with_value(format!("id {SHALOM} ok"))
+        .with_focused(true);
+    // Walk left from the end into the Hebrew word.
+    for _ in 0..5 {
+        input.handle_event(&key(KeyCode::Left));
+    }
+    let area = Rect::new(0, 0, 16, 1);
+    let mut pool = GraphemePool::new();
+    let mut frame = Frame::new(16, 1, &mut pool);
+    input.render(area, &mut frame);
+    assert_snapshot!("bidi_input_cursor_in_rtl_run", &frame.buffer);
+}
+
+// ============================================================================
+// TextArea
+// ============================================================================
+
+#[test]
+fn snapshot_bidi_editor_selection_across_runs() {
+    let mut ta = TextArea::new()
+        .with_text(format!("abc {SHALOM} def\n{SALAM} 42 xyz").as_str())
+        .with_selection_style(Style::new().reverse())
+        .with_focus(true);
+    ta.move_to_document_start();
+    // Select from after "ab" rightwards across the start of the Hebrew run.
+    ta.handle_event(&key(KeyCode::Right));
+    ta.handle_event(&key(KeyCode::Right));
+    for _ in 0..4 {
+        ta.handle_event(&shift_key(KeyCode::Right));
+    }
+    let area = Rect::new(0, 0, 20, 3);
+    let mut pool = GraphemePool::new();
+    let mut frame = Frame::new(20, 3, &mut pool);
+    let mut state = TextAreaState::default();
+    StatefulWidget::render(&ta, area, &mut frame, &mut state);
+    assert_snapshot_ansi!("bidi_editor_selection_across_runs", &frame.buffer);
+}
+
+#[test]
+fn snapshot_bidi_editor_soft_wrap() {
+    let mut ta = TextArea::new()
+        .with_text(format!("{SALAM} {SHALOM} {SALAM} ok").as_str())
+        .with_soft_wrap(true)
+        .with_line_numbers(true)
+        .with_focus(true);
+    ta.move_to_document_end();
+    let area = Rect::new(0, 0, 14, 4);
+    let mut pool = GraphemePool::new();
+    let mut frame = Frame::new(14, 4, &mut pool);
+    let mut state = TextAreaState::default();
+    StatefulWidget::render(&ta, area, &mut frame, &mut state);
+    assert_snapshot!("bidi_editor_soft_wrap", &frame.buffer);
+}
@@ -0,0 +1,167 @@
+//! Locale calendar conventions.
+//!
+//! Provides the [`Weekday`] type and CLDR first-day-of-week data so calendar
+//! widgets can lay out month grids the way a locale expects, without pulling
+//! in a full date/time or timezone library.
+//!
+//! # Invariants
+//!
+//! 1. `Weekday::from_index(d.index()) == d` for every weekday.
+//! 2. `first_for_locale` always returns a weekday (Monday is the fallback).
+
+use core::fmt;
+
+/// Day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Weekday {
+    /// Monday (index 0).
+    Monday,
+    /// Tuesday (index 1).
+    Tuesday,
+    /// Wednesday (index 2).
+    Wednesday,
+    /// Thursday (index 3).
+    Thursday,
+    /// Friday (index 4).
+    Friday,
+    /// Saturday (index 5).
+    Saturday,
+    /// Sunday (index 6).
+    Sunday,
+}
+
+impl Weekday {
+    /// All weekdays, Monday first.
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// Days since Monday (`0..7`).
+    #[must_use]
+    pub const fn index(self) -> u8 {
+        self as u8
+    }
+
+    /// Weekday from days since Monday; wraps modulo 7.
+    #[must_use]
+    pub const fn from_index(index: u8) -> Self {
+        Self::ALL[(index % 7) as usize]
+    }
+
+    /// The following day.
+    #[must_use]
+    pub const fn succ(self) -> Self {
+        Self::from_index(self.index() + 1)
+    }
+
+    /// Days from `start` forward to `self` (`0..7`).
+    #[must_use]
+    pub const fn days_since(self, start: Weekday) -> u8 {
+        (self.index() + 7 - start.index()) % 7
+    }
+
+    /// Two-letter English abbreviation (`"Mo"`, `"Tu"`, ...).
+    #[must_use]
+    pub const fn short_name(self) -> &'static str {
+        match self {
+            Self::Monday => "Mo",
+            Self::Tuesday => "Tu",
+            Self::Wednesday => "We",
+            Self::Thursday => "Th",
+            Self::Friday => "Fr",
+            Self::Saturday => "Sa",
+            Self::Sunday => "Su",
+        }
+    }
+
+    /// First day of the week for a locale tag (e.g. `"en-US"`, `"de"`, `"ar-EG"`).
+    ///
+    /// Uses the region subtag when present, otherwise the language's most
+    /// common region. Falls back to Monday (ISO 8601) when unknown.
+    #[must_use]
+    pub fn first_for_locale(locale: &str) -> Self {
+        let mut subtags = locale.split(['-', '_']);
+        let language = subtags.next().unwrap_or(locale).to_ascii_lowercase();
+        let region = subtags
+            .find(|tag| tag.len() == 2 && tag.bytes().all(|b| b.is_ascii_alphabetic()))
+            .map(str::to_ascii_uppercase);
+
+        if let Some(region) = region {
+            return first_for_region(&region);
+        }
+        match language.as_str() {
+            "en" | "ja" | "ko" | "he" | "hi" | "pt" | "th" | "zu" | "fil" | "km" | "lo" | "my"
+            | "ne" | "am" | "bn" | "mr" | "ta" | "te" | "gu" | "kn" | "ml" | "pa" => Self::Sunday,
+            "ar" | "fa" | "ps" => Self::Saturday,
+            _ => Self::Monday,
+        }
+    }
+}
+
+/// CLDR `firstDay` territory data (subset; unlisted regions use Monday).
+fn first_for_region(region: &str) -> Weekday {
+    match region {
+        "AG" | "AS" | "BD" | "BR" | "BS" | "BT" | "BW" | "BZ" | "CA" | "CN" | "CO" | "DM"
+        | "DO" | "ET" | "GT" | "GU" | "HK" | "HN" | "ID" | "IL" | "IN" | "JM" | "JP" | "KE"
+        | "KH" | "KR" | "LA" | "MH" | "MM" | "MO" | "MT" | "MX" | "MZ" | "NI" | "NP" | "PA"
+        | "PE" | "PH" | "PK" | "PR" | "PT" | "PY" | "SA" | "SG" | "SV" | "TH" | "TT" | "TW"
+        | "UM" | "US" | "VE" | "VI" | "WS" | "YE" | "ZA" | "ZW" => Weekday::Sunday,
+        "AE" | "AF" | "BH" | "DJ" | "DZ" | "EG" | "IQ" | "IR" | "JO" | "KW" | "LY" | "OM"
+        | "QA" | "SD" | "SY" => Weekday::Saturday,
+        "MV" => Weekday::Friday,
+        _ => Weekday::Monday,
+    }
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Monday => "Monday",
+            Self::Tuesday => "Tuesday",
+            Self::Wednesday => "Wednesday",
+            Self::Thursday => "Thursday",
+            Self::Friday => "Friday",
+            Self::Saturday => "Saturday",
+            Self::Sunday => "Sunday",
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn index_round_trip() {
+        for day in Weekday::ALL {
+            assert_eq!(Weekday::from_index(day.index()), day);
+        }
+        assert_eq!(Weekday::Sunday.succ(), Weekday::Monday);
+        assert_eq!(Weekday::Monday.days_since(Weekday::Sunday), 1);
+        assert_eq!(Weekday::Sunday.days_since(Weekday::Monday), 6);
+    }
+
+    #[test]
+    fn first_day_by_region() {
+        assert_eq!(Weekday::first_for_locale("en-US"), Weekday::Sunday);
+        assert_eq!(Weekday::first_for_locale("en-GB"), Weekday::Monday);
+        assert_eq!(Weekday::first_for_locale("de_DE"), Weekday::Monday);
+        assert_eq!(Weekday::first_for_locale("ar-EG"), Weekday::Saturday);
+        assert_eq!(Weekday::first_for_locale("zh-Hant-TW"), Weekday::Sunday);
+    }
+
+    #[test]
+    fn first_day_by_language_fallback() {
+        assert_eq!(Weekday::first_for_locale("en"), Weekday::Sunday);
+        assert_eq!(Weekday::first_for_locale("fr"), Weekday::Monday);
+        assert_eq!(Weekday::first_for_locale("fa"), Weekday::Saturday);
+        assert_eq!(Weekday::first_for_locale(""), Weekday::Monday);
+    }
+}
@@ -15,9 +15,11 @@
 //! into localized text before rendering. It does not depend on rendering or
 //! runtime, keeping the localization layer reusable and testable.
 
+pub mod calendar;
 pub mod catalog;
 pub mod plural;
 
+pub use calendar::Weekday;
 pub use catalog::{
     CoverageReport, I18nError, LocaleCoverage, LocaleStrings, StringCatalog, StringEntry,
 };
@@ -0,0 +1,558 @@
+//! Anchored popover positioning.
+//!
+//! Tooltips, dropdowns, popovers and other floating layers are positioned
+//! relative to an *anchor* rect (the widget that triggered them) and must
+//! stay inside the *viewport*. [`AnchorPlacement`] solves this in one place:
+//!
+//! 1. Try the preferred [`AnchorSide`]; if the popup does not fit, try the
+//!    fallback sides allowed by the [`FlipStrategy`].
+//! 2. If no side fits, use the side with the most room.
+//! 3. Constrain the popup size to the room available on that side.
+//! 4. Align along the anchor edge ([`AnchorAlign`]) and shift back into the
+//!    viewport if it would overflow.
+//! 5. Report where an arrow pointing at the anchor should be drawn.
+//!
+//! [`place_within`] covers the simpler case of layers pinned inside an area
+//! (toasts, modals, palettes).
+//!
+//! # Example
+//!
+//! ```
+//! use ftui_layout::{AnchorAlign, AnchorPlacement, AnchorSide, Rect, Size};
+//!
+//! let viewport = Rect::new(0, 0, 40, 12);
+//! let button = Rect::new(30, 10, 8, 1);
+//!
+//! // No room below the button, so the menu flips above it and shifts left.
+//! let placement = AnchorPlacement::new(AnchorSide::Bottom)
+//!     .align(AnchorAlign::Start)
+//!     .solve(button, Size::new(16, 5), viewport);
+//!
+//! assert_eq!(placement.side, AnchorSide::Top);
+//! assert!(placement.flipped);
+//! assert_eq!(placement.rect, Rect::new(24, 5, 16, 5));
+//! assert_eq!(placement.arrow, Some(9));
+//! ```
+
+use ftui_core::geometry::{Rect, Size};
+
+/// Side of the anchor on which the popup is placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AnchorSide {
+    /// Above the anchor.
+    Top,
+    /// Below the anchor.
+    #[default]
+    Bottom,
+    /// Left of the anchor.
+    Left,
+    /// Right of the anchor.
+    Right,
+}
+
+impl AnchorSide {
+    /// The side across the anchor.
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    /// Whether the popup is stacked vertically relative to the anchor.
+    #[must_use]
+    pub const fn is_vertical(self) -> bool {
+        matches!(self, Self::Top | Self::Bottom)
+    }
+
+    /// The two sides perpendicular to this one, in fallback order.
+    const fn perpendicular(self) -> [Self; 2] {
+        if self.is_vertical() {
+            [Self::Right, Self::Left]
+        } else {
+            [Self::Bottom, Self::Top]
+        }
+    }
+}
+
+/// Alignment of the popup along the anchor edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AnchorAlign {
+    /// Align the popup's start edge with the anchor's start edge.
+    Start,
+    /// Center the popup on the anchor.
+    #[default]
+    Center,
+    /// Align the popup's end edge with the anchor's end edge.
+    End,
+}
+
+/// Which sides may be tried when the preferred side does not fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FlipStrategy {
+    /// Always use the preferred side.
+    Never,
+    /// Try the opposite side.
+    #[default]
+    Opposite,
+    /// Try the opposite side, then both perpendicular sides.
+    Any,
+}
+
+/// Placement request for a popup anchored to a rect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AnchorPlacement {
+    side: AnchorSide,
+    align: AnchorAlign,
+    gap: u16,
+    flip: FlipStrategy,
+}
+
+/// Result of [`AnchorPlacement::solve`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement {
+    /// Final popup rect, always inside the viewport.
+    pub rect: Rect,
+    /// Side of the anchor the popup ended up on.
+    pub side: AnchorSide,
+    /// Whether a side other than the preferred one was chosen.
+    pub flipped: bool,
+    /// Cross-axis displacement applied to keep the popup in the viewport.
+    pub shift: i32,
+    /// Whether the popup was shrunk below the requested size.
+    pub constrained: bool,
+    /// Arrow offset along the popup edge facing the anchor, measured from
+    /// the popup's left (for top/bottom) or top (for left/right) edge.
+    /// `None` when the popup no longer overlaps the anchor on that axis.
+    pub arrow: Option<u16>,
+}
+
+impl AnchorPlacement {
+    /// Create a placement on the given side, centered, with no gap.
+    #[must_use]
+    pub fn new(side: AnchorSide) -> Self {
+        Self {
+            side,
+            ..Default::default()
+        }
+    }
+
+    /// Set the preferred side.
+    #[must_use]
+    pub fn side(mut self, side: AnchorSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Set the alignment along the anchor edge.
+    #[must_use]
+    pub fn align(mut self, align: AnchorAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Set the gap between anchor and popup.
+    #[must_use]
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set the flip strategy.
+    #[must_use]
+    pub fn flip(mut self, flip: FlipStrategy) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    /// Candidate sides in the order they are tried.
+    fn candidates(&self) -> impl Iterator<Item = AnchorSide> {
+        let [p0, p1] = self.side.perpendicular();
+        let (n, order) = match self.flip {
+            FlipStrategy::Never => (1, [self.side, self.side, self.side, self.side]),
+            FlipStrategy::Opposite => (2, [self.side, self.side.opposite(), p0, p1]),
+            FlipStrategy::Any => (4, [self.side, self.side.opposite(), p0, p1]),
+        };
+        order.into_iter().take(n)
+    }
+
+    /// Room between the anchor (plus gap) and the viewport edge on `side`,
+    /// capped at the viewport's extent on that axis.
+    fn room(&self, side: AnchorSide, anchor: Rect, viewport: Rect) -> u16 {
+        let gap = self.gap as i32;
+        let room = match side {
+            AnchorSide::Top => anchor.y as i32 - gap - viewport.y as i32,
+            AnchorSide::Bottom => viewport.bottom() as i32 - (anchor.bottom() as i32 + gap),
+            AnchorSide::Left => anchor.x as i32 - gap - viewport.x as i32,
+            AnchorSide::Right => viewport.right() as i32 - (anchor.right() as i32 + gap),
+        };
+        let extent = if side.is_vertical() {
+            viewport.height
+        } else {
+            viewport.width
+        };
+        room.clamp(0, extent as i32) as u16
+    }
+
+    /// Solve the placement of a popup of `size` next to `anchor` within `viewport`.
+    #[must_use]
+    pub fn solve(&self, anchor: Rect, size: Size, viewport: Rect) -> Placement {
+        let main_size = |side: AnchorSide| {
+            if side.is_vertical() {
+                size.height
+            } else {
+                size.width
+            }
+        };
+        let cross_size = |side: AnchorSide| {
+            if side.is_vertical() {
+                size.width
+            } else {
+                size.height
+            }
+        };
+
+        let mut best: Option<(AnchorSide, u16)> = None;
+        let mut chosen = None;
+        for side in self.candidates() {
+            let room = self.room(side, anchor, viewport);
+            let cross_room = if side.is_vertical() {
+                viewport.width
+            } else {
+                viewport.height
+            };
+            if room >= main_size(side) && cross_room >= cross_size(side) {
+                chosen = Some(side);
+                break;
+            }
+            if best.is_none_or(|(_, r)| room > r) {
+                best = Some((side, room));
+            }
+        }
+        let side = chosen.unwrap_or_else(|| best.map_or(self.side, |(s, _)| s));
+        let room = self.room(side, anchor, viewport);
+
+        // Constrain to the room on the chosen side and the viewport's cross extent.
+        let (width, height) = if side.is_vertical() {
+            (size.width.min(viewport.width), size.height.min(room))
+        } else {
+            (size.width.min(room), size.height.min(viewport.height))
+        };
+        let constrained = width != size.width || height != size.height;
+
+        let gap = self.gap as i32;
+        let (w, h) = (width as i32, height as i32);
+        let (ax, ay) = (anchor.x as i32, anchor.y as i32);
+        let (aw, ah) = (anchor.width as i32, anchor.height as i32);
+        let align = |start: i32, extent: i32, len: i32| match self.align {
+            AnchorAlign::Start => start,
+            AnchorAlign::Center => start + (extent - len).div_euclid(2),
+            AnchorAlign::End => start + extent - len,
+        };
+        let (x, y) = match side {
+            AnchorSide::Top => (align(ax, aw, w), ay - gap - h),
+            AnchorSide::Bottom => (align(ax, aw, w), ay + ah + gap),
+            AnchorSide::Left => (ax - gap - w, align(ay, ah, h)),
+            AnchorSide::Right => (ax + aw + gap, align(ay, ah, h)),
+        };
+
+        let clamp = |v: i32, lo: u16, hi: u16, len: i32| v.min(hi as i32 - len).max(lo as i32);
+        let cx = clamp(x, viewport.x, viewport.right(), w);
+        let cy = clamp(y, viewport.y, viewport.bottom(), h);
+        let rect = Rect::new(cx as u16, cy as u16, width, height);
+
+        let (shift, arrow) = if side.is_vertical() {
+            (
+                cx - x,
+                arrow_offset(anchor.x, anchor.right(), rect.x, rect.right()),
+            )
+        } else {
+            (
+                cy - y,
+                arrow_offset(anchor.y, anchor.bottom(), rect.y, rect.bottom()),
+            )
+        };
+
+        Placement {
+            rect,
+            side,
+            flipped: side != self.side,
+            shift,
+            constrained,
+            arrow,
+        }
+    }
+}
+
+/// Midpoint of the overlap between the anchor span and the popup span,
+/// relative to the popup start.
+fn arrow_offset(anchor_start: u16, anchor_end: u16, start: u16, end: u16) -> Option<u16> {
+    let lo = anchor_start.max(start);
+    let hi = anchor_end.min(end);
+    (lo < hi).then(|| (lo + (hi - lo - 1) / 2) - start)
+}
+
+/// Place a rect of `size` inside `area`, aligned on both axes.
+///
+/// `margin` is kept from the aligned edges when there is room. Rects that do
+/// not fit are shifted back into `area` first and only then shrunk, so the
+/// result never extends past `area`.
+#[must_use]
+pub fn place_within(
+    area: Rect,
+    size: Size,
+    horizontal: AnchorAlign,
+    vertical: AnchorAlign,
+    margin: u16,
+) -> Rect {
+    let width = size.width.min(area.width);
+    let height = size.height.min(area.height);
+    let place = |start: u16, extent: u16, requested: u16, len: u16, align: AnchorAlign| {
+        let start = start as i32;
+        let end = start + extent as i32;
+        let pos = match align {
+            AnchorAlign::Start => start + margin as i32,
+            AnchorAlign::Center => start + (extent as i32 - requested as i32).div_euclid(2),
+            AnchorAlign::End => end - requested as i32 - margin as i32,
+        };
+        pos.min(end - len as i32).max(start) as u16
+    };
+    Rect::new(
+        place(area.x, area.width, size.width, width, horizontal),
+        place(area.y, area.height, size.height, height, vertical),
+        width,
+        height,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: Rect = Rect::new(0, 0, 80, 24);
+
+    #[test]
+    fn preferred_side_when_it_fits() {
+        let p = AnchorPlacement::new(AnchorSide::Bottom)
+            .align(AnchorAlign::Start)
+            .gap(1)
+            .solve(Rect::new(10, 5, 10, 2), Size::new(20, 4), VIEWPORT);
+        assert_eq!(p.rect, Rect::new(10, 8, 20, 4));
+        assert_eq!(p.side, AnchorSide::Bottom);
+        assert!(!p.flipped);
+        assert!(!p.constrained);
+        assert_eq!(p.shift, 0);
+    }
+
+    #[test]
+    fn each_side_positions_adjacent_to_anchor() {
+        let anchor = Rect::new(30, 10, 6, 2);
+        let size = Size::new(4, 2);
+        let solve = |side| {
+            AnchorPlacement::new(side)
+                .flip(FlipStrategy::Never)
+                .solve(anchor, size, VIEWPORT)
+                .rect
+        };
+        assert_eq!(solve(AnchorSide::Top), Rect::new(31, 8, 4, 2));
+        assert_eq!(solve(AnchorSide::Bottom), Rect::new(31, 12, 4, 2));
+        assert_eq!(solve(AnchorSide::Left), Rect::new(26, 10, 4, 2));
+        assert_eq!(solve(AnchorSide::Right), Rect::new(36, 10, 4, 2));
+    }
+
+    #[test]
+    fn alignment_along_anchor_edge() {
+        let anchor = Rect::new(20, 5, 10, 1);
+        let size = Size::new(4, 1);
+        let solve = |align| {
+            AnchorPlacement::new(AnchorSide::Bottom)
+                .align(align)
+                .solve(anchor, size, VIEWPORT)
+                .rect
+                .x
+        };
+        assert_eq!(solve(AnchorAlign::Start), 20);
+        assert_eq!(solve(AnchorAlign::Center), 23);
+        assert_eq!(solve(AnchorAlign::End), 26);
+    }
+
+    #[test]
+    fn flips_to_opposite_side() {
+        let p = AnchorPlacement::new(AnchorSide::Bottom).solve(
+            Rect::new(10, 20, 4, 1),
+            Size::new(8, 5),
+            VIEWPORT,
+        );
+        assert_eq!(p.side, AnchorSide::Top);
+        assert!(p.flipped);
+        assert_eq!(p.rect.bottom(), 20);
+    }
+
+    #[test]
+    fn never_flip_constrains_instead() {
+        let p = AnchorPlacement::new(AnchorSide::Bottom)
+            .flip(FlipStrategy::Never)
+            .solve(Rect::new(10, 20, 4, 1), Size::new(8, 5), VIEWPORT);
+        assert_eq!(p.side, AnchorSide::Bottom);
+        assert!(p.constrained);
+        assert_eq!(p.rect, Rect::new(8, 21, 8, 3));
+    }
+
+    #[test]
+    fn flip_any_tries_perpendicular_sides() {
+        // Anchor spans almost the full height: only horizontal sides have room.
+        let p = AnchorPlacement::new(AnchorSide::Bottom)
+            .flip(FlipStrategy::Any)
+            .solve(Rect::new(0, 0, 10, 23), Size::new(15, 6), VIEWPORT);
+        assert_eq!(p.side, AnchorSide::Right);
+        assert_eq!(p.rect.x, 10);
+
+        let p = AnchorPlacement::new(AnchorSide::Bottom)
+            .flip(FlipStrategy::Any)
+            .solve(Rect::new(70, 0, 10, 23), Size::new(15, 6), VIEWPORT);
+        assert_eq!(p.side, AnchorSide::Left);
+        assert_eq!(p.rect.right(), 70);
+    }
+
+    #[test]
+    fn picks_side_with_most_room_when_nothing_fits() {
+        let viewport = Rect::new(0, 0, 20, 10);
+        let p = AnchorPlacement::new(AnchorSide::Bottom).solve(
+            Rect::new(5, 2, 5, 1),
+            Size::new(10, 12),
+            viewport,
+        );
+        assert_eq!(p.side, AnchorSide::Bottom);
+        assert_eq!(p.rect, Rect::new(2, 3, 10, 7));
+        assert!(p.constrained);
+
+        let p = AnchorPlacement::new(AnchorSide::Bottom).solve(
+            Rect::new(5, 7, 5, 1),
+            Size::new(10, 12),
+            viewport,
+        );
+        assert_eq!(p.side, AnchorSide::Top);
+        assert_eq!(p.rect, Rect::new(2, 0, 10, 7));
+    }
+
+    #[test]
+    fn shifts_into_viewport_and_reports_arrow() {
+        let p = AnchorPlacement::new(AnchorSide::Bottom).solve(
+            Rect::new(76, 5, 2, 1),
+            Size::new(20, 3),
+            VIEWPORT,
+        );
+        assert_eq!(p.rect, Rect::new(60, 6, 20, 3));
+        assert_eq!(p.shift, -7);
+        assert_eq!(p.arrow, Some(16));
+
+        let p = AnchorPlacement::new(AnchorSide::Right).solve(
+            Rect::new(5, 1, 2, 1),
+            Size::new(10, 6),
+            VIEWPORT,
+        );
+        assert_eq!(p.rect.y, 0);
+        assert_eq!(p.shift, 2);
+        assert_eq!(p.arrow, Some(1));
+    }
+
+    #[test]
+    fn arrow_is_none_without_overlap() {
+        // Anchor entirely left of the viewport.
+        let viewport = Rect::new(10, 0, 30, 10);
+        let p = AnchorPlacement::new(AnchorSide::Bottom).solve(
+            Rect::new(0, 2, 4, 1),
+            Size::new(6, 2),
+            viewport,
+        );
+        assert_eq!(p.rect.x, 10);
+        assert_eq!(p.arrow, None);
+    }
+
+    #[test]
+    fn result_always_inside_viewport() {
+        let viewports = [
+            Rect::new(0, 0, 80, 24),
+            Rect::new(5, 3, 12, 6),
+            Rect::new(0, 0, 3, 2),
+            Rect::new(0, 0, 0, 0),
+        ];
+        let anchors = [
+            Rect::new(0, 0, 1, 1),
+            Rect::new(79, 23, 1, 1),
+            Rect::new(40, 12, 10, 3),
+            Rect::new(200, 200, 5, 5),
+        ];
+        let sides = [
+            AnchorSide::Top,
+            AnchorSide::Bottom,
+            AnchorSide::Left,
+            AnchorSide::Right,
+        ];
+        for viewport in viewports {
+            for anchor in anchors {
+                for side in sides {
+                    for flip in [
+                        FlipStrategy::Never,
+                        FlipStrategy::Opposite,
+                        FlipStrategy::Any,
+                    ] {
+                        let p = AnchorPlacement::new(side).gap(1).flip(flip).solve(
+                            anchor,
+                            Size::new(30, 8),
+                            viewport,
+                        );
+                        assert!(p.rect.width <= viewport.width);
+                        assert!(p.rect.height <= viewport.height);
+                        if !p.rect.is_empty() {
+                            assert_eq!(p.rect.intersection(&viewport), p.rect);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn place_within_corners_and_center() {
+        let size = Size::new(20, 3);
+        let at = |h, v| place_within(VIEWPORT, size, h, v, 1);
+        assert_eq!(
+            at(AnchorAlign::Start, AnchorAlign::Start),
+            Rect::new(1, 1, 20, 3)
+        );
+        assert_eq!(
+            at(AnchorAlign::End, AnchorAlign::End),
+            Rect::new(59, 20, 20, 3)
+        );
+        assert_eq!(
+            at(AnchorAlign::Center, AnchorAlign::Center),
+            Rect::new(30, 10, 20, 3)
+        );
+    }
+
+    #[test]
+    fn place_within_shifts_before_shrinking() {
+        let area = Rect::new(0, 0, 20, 10);
+        let r = place_within(
+            area,
+            Size::new(19, 3),
+            AnchorAlign::End,
+            AnchorAlign::Start,
+            2,
+        );
+        assert_eq!(r, Rect::new(0, 2, 19, 3));
+
+        let r = place_within(
+            area,
+            Size::new(30, 12),
+            AnchorAlign::End,
+            AnchorAlign::End,
+            1,
+        );
+        assert_eq!(r, area);
+    }
+}
@@ -0,0 +1,956 @@
+//! Wrapping flex layout with grow/shrink weights and cross-axis alignment.
+//!
+//! [`Flex`](crate::Flex) solves a single row or column of constraints. This
+//! module provides [`FlexBox`], a CSS-flexbox style container for content
+//! such as tag clouds, button bars and card galleries, where the number of
+//! items per line depends on their intrinsic sizes.
+//!
+//! # Model
+//!
+//! Each child is described by a [`FlexItem`]:
+//!
+//! - **basis** — the hypothetical main-axis size. `None` uses the measured
+//!   preferred size ([`IntrinsicSize`]).
+//! - **grow** / **shrink** — weights used to distribute free space or
+//!   overflow within a line. Shrinking is weighted by `shrink * basis`.
+//! - **align_self** — overrides the container's [`AlignItems`].
+//! - **baseline** — offset of the item's baseline from its cross-start edge,
+//!   used by [`AlignItems::Baseline`].
+//!
+//! Items are broken into lines greedily when [`FlexWrap::Wrap`] is set. Each
+//! line then resolves grow/shrink, positions items along the main axis with
+//! [`Alignment`] (the equivalent of `justify-content`) and aligns them on the
+//! cross axis.
+//!
+//! # Example
+//!
+//! ```
+//! use ftui_layout::{AlignItems, FlexBox, FlexItem, FlexWrap, IntrinsicSize, Rect};
+//!
+//! let tags = ["rust", "tui", "layout", "flexbox"];
+//! let flex = FlexBox::horizontal()
+//!     .wrap(FlexWrap::Wrap)
+//!     .gap(1)
+//!     .align_items(AlignItems::Start)
+//!     .items(tags.iter().map(|_| FlexItem::new()));
+//!
+//! let rects = flex.split_with_measurer(Rect::new(0, 0, 12, 4), |i, _available| {
+//!     IntrinsicSize::exact(tags[i].len() as u16 + 2, 1)
+//! });
+//! assert_eq!(rects[0], Rect::new(0, 0, 6, 1));
+//! assert_eq!(rects[1], Rect::new(7, 0, 5, 1));
+//! assert_eq!(rects[2], Rect::new(0, 1, 8, 1));
+//! ```
+
+use std::hash::{Hash, Hasher};
+
+use ftui_core::geometry::{Rect, Sides, Size};
+use rustc_hash::FxHasher;
+
+use crate::cache::{LayoutCache, LayoutCacheKey};
+use crate::direction::{self, FlowDirection};
+use crate::{Alignment, Direction, Flex, LayoutSizeHint};
+
+/// Whether items may wrap onto additional lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FlexWrap {
+    /// All items share a single line; overflow is resolved by shrinking.
+    #[default]
+    NoWrap,
+    /// Items wrap onto new lines stacked from the cross-start edge.
+    Wrap,
+    /// Items wrap onto new lines stacked from the cross-end edge.
+    WrapReverse,
+}
+
+/// Cross-axis alignment of items within their line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum AlignItems {
+    /// Align to the cross-start edge (top for rows, left for columns).
+    Start,
+    /// Center within the line.
+    Center,
+    /// Align to the cross-end edge.
+    End,
+    /// Fill the line's cross size, up to the item's maximum.
+    #[default]
+    Stretch,
+    /// Align item baselines; falls back to `Start` for vertical layouts.
+    Baseline,
+}
+
+/// Intrinsic size of a flex item on both axes.
+///
+/// This is the two-dimensional counterpart of [`LayoutSizeHint`] and is what
+/// [`FlexBox`] measurers return. Widgets implementing `MeasurableWidget`
+/// convert their `SizeConstraints` into this type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct IntrinsicSize {
+    /// Width hint.
+    pub width: LayoutSizeHint,
+    /// Height hint.
+    pub height: LayoutSizeHint,
+}
+
+impl IntrinsicSize {
+    /// No intrinsic size on either axis.
+    pub const ZERO: Self = Self {
+        width: LayoutSizeHint::ZERO,
+        height: LayoutSizeHint::ZERO,
+    };
+
+    /// Create an exact intrinsic size (min = preferred = max on both axes).
+    #[inline]
+    pub const fn exact(width: u16, height: u16) -> Self {
+        Self {
+            width: LayoutSizeHint::exact(width),
+            height: LayoutSizeHint::exact(height),
+        }
+    }
+
+    /// Create an intrinsic size from per-axis hints.
+    #[inline]
+    pub const fn new(width: LayoutSizeHint, height: LayoutSizeHint) -> Self {
+        Self { width, height }
+    }
+
+    /// Preferred size on both axes.
+    #[inline]
+    pub const fn preferred(&self) -> Size {
+        Size::new(self.width.preferred, self.height.preferred)
+    }
+
+    fn main(&self, direction: Direction) -> LayoutSizeHint {
+        match direction {
+            Direction::Horizontal => self.width,
+            Direction::Vertical => self.height,
+        }
+    }
+
+    fn cross(&self, direction: Direction) -> LayoutSizeHint {
+        match direction {
+            Direction::Horizontal => self.height,
+            Direction::Vertical => self.width,
+        }
+    }
+}
+
+/// Per-item flex parameters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlexItem {
+    basis: Option<u16>,
+    grow: f32,
+    shrink: f32,
+    align_self: Option<AlignItems>,
+    baseline: u16,
+}
+
+impl Default for FlexItem {
+    fn default() -> Self {
+        Self {
+            basis: None,
+            grow: 0.0,
+            shrink: 1.0,
+            align_self: None,
+            baseline: 0,
+        }
+    }
+}
+
+impl FlexItem {
+    /// Create an item sized by its measured preferred size (`grow: 0`, `shrink: 1`).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an item with a fixed main-axis basis.
+    #[must_use]
+    pub fn fixed(basis: u16) -> Self {
+        Self::default().basis(basis)
+    }
+
+    /// Set the main-axis basis, overriding the measured preferred size.
+    #[must_use]
+    pub fn basis(mut self, basis: u16) -> Self {
+        self.basis = Some(basis);
+        self
+    }
+
+    /// Set the grow weight. Negative and non-finite values are treated as zero.
+    #[must_use]
+    pub fn grow(mut self, grow: f32) -> Self {
+        self.grow = grow;
+        self
+    }
+
+    /// Set the shrink weight. Negative and non-finite values are treated as zero.
+    #[must_use]
+    pub fn shrink(mut self, shrink: f32) -> Self {
+        self.shrink = shrink;
+        self
+    }
+
+    /// Override the container's cross-axis alignment for this item.
+    #[must_use]
+    pub fn align_self(mut self, align: AlignItems) -> Self {
+        self.align_self = Some(align);
+        self
+    }
+
+    /// Set the baseline offset from the item's cross-start edge.
+    #[must_use]
+    pub fn baseline(mut self, baseline: u16) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    fn grow_weight(&self) -> f64 {
+        sanitize_weight(self.grow)
+    }
+
+    fn shrink_weight(&self) -> f64 {
+        sanitize_weight(self.shrink)
+    }
+}
+
+fn sanitize_weight(w: f32) -> f64 {
+    if w.is_finite() && w > 0.0 {
+        w as f64
+    } else {
+        0.0
+    }
+}
+
+/// A wrapping flex container.
+///
+/// See the [module documentation](self) for the layout model.
+#[derive(Debug, Clone, Default)]
+pub struct FlexBox {
+    direction: Direction,
+    items: Vec<FlexItem>,
+    wrap: FlexWrap,
+    margin: Sides,
+    gap: u16,
+    line_gap: u16,
+    justify: Alignment,
+    align_items: AlignItems,
+    flow_direction: FlowDirection,
+}
+
+impl FlexBox {
+    /// Create a new horizontal (row) flex box.
+    #[must_use]
+    pub fn horizontal() -> Self {
+        Self {
+            direction: Direction::Horizontal,
+            ..Default::default()
+        }
+    }
+
+    /// Create a new vertical (column) flex box.
+    #[must_use]
+    pub fn vertical() -> Self {
+        Self {
+            direction: Direction::Vertical,
+            ..Default::default()
+        }
+    }
+
+    /// Set the main-axis direction.
+    #[must_use]
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Set the items.
+    #[must_use]
+    pub fn items(mut self, items: impl IntoIterator<Item = FlexItem>) -> Self {
+        self.items = items.into_iter().collect();
+        self
+    }
+
+    /// Set the wrapping mode.
+    #[must_use]
+    pub fn wrap(mut self, wrap: FlexWrap) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Set the margin.
+    #[must_use]
+    pub fn margin(mut self, margin: Sides) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Set the gap between items on the same line.
+    #[must_use]
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set the gap between wrapped lines.
+    #[must_use]
+    pub fn line_gap(mut self, gap: u16) -> Self {
+        self.line_gap = gap;
+        self
+    }
+
+    /// Set main-axis alignment of items within each line.
+    #[must_use]
+    pub fn justify(mut self, alignment: Alignment) -> Self {
+        self.justify = alignment;
+        self
+    }
+
+    /// Set the default cross-axis alignment of items.
+    #[must_use]
+    pub fn align_items(mut self, align: AlignItems) -> Self {
+        self.align_items = align;
+        self
+    }
+
+    /// Set the horizontal flow direction (LTR or RTL).
+    ///
+    /// RTL mirrors the final rects horizontally: rows start at the right
+    /// edge, and wrapped columns are stacked right to left.
+    #[must_use]
+    pub fn flow_direction(mut self, flow: FlowDirection) -> Self {
+        self.flow_direction = flow;
+        self
+    }
+
+    /// Number of items (and thus output rects).
+    #[must_use]
+    pub fn item_count(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Split the area using item bases only (no intrinsic sizes).
+    pub fn split(&self, area: Rect) -> Vec<Rect> {
+        self.split_with_measurer(area, |_, _| IntrinsicSize::ZERO)
+    }
+
+    /// Split the area using intrinsic sizes from a measurer callback.
+    ///
+    /// The measurer receives the item index and the inner (post-margin) size
+    /// of the container, matching the `available` argument of
+    /// `MeasurableWidget::measure`.
+    pub fn split_with_measurer<F>(&self, area: Rect, measurer: F) -> Vec<Rect>
+    where
+        F: Fn(usize, Size) -> IntrinsicSize,
+    {
+        let inner = area.inner(self.margin);
+        if inner.is_empty() {
+            return self.items.iter().map(|_| Rect::default()).collect();
+        }
+        let hints = self.measure(inner, &measurer);
+        self.layout(inner, &hints)
+    }
+
+    /// Like [`split_with_measurer`](Self::split_with_measurer), memoized in a [`LayoutCache`].
+    ///
+    /// Items are measured on every call; the key covers the container
+    /// configuration and the measured sizes, so content changes that alter
+    /// intrinsic sizes never return stale rects.
+    pub fn split_cached<F>(&self, area: Rect, cache: &mut LayoutCache, measurer: F) -> Vec<Rect>
+    where
+        F: Fn(usize, Size) -> IntrinsicSize,
+    {
+        let inner = area.inner(self.margin);
+        if inner.is_empty() {
+            return self.items.iter().map(|_| Rect::default()).collect();
+        }
+        let hints = self.measure(inner, &measurer);
+        let key = self.cache_key(area, &hints);
+        cache.get_or_compute(key, || self.layout(inner, &hints))
+    }
+
+    fn measure<F>(&self, inner: Rect, measurer: &F) -> Vec<IntrinsicSize>
+    where
+        F: Fn(usize, Size) -> IntrinsicSize,
+    {
+        let available = Size::new(inner.width, inner.height);
+        (0..self.items.len())
+            .map(|i| measurer(i, available))
+            .collect()
+    }
+
+    fn cache_key(&self, area: Rect, hints: &[IntrinsicSize]) -> LayoutCacheKey {
+        let mut hasher = FxHasher::default();
+        self.wrap.hash(&mut hasher);
+        let m = self.margin;
+        (m.top, m.right, m.bottom, m.left).hash(&mut hasher);
+        self.gap.hash(&mut hasher);
+        self.line_gap.hash(&mut hasher);
+        std::mem::discriminant(&self.justify).hash(&mut hasher);
+        self.align_items.hash(&mut hasher);
+        self.flow_direction.is_rtl().hash(&mut hasher);
+        for item in &self.items {
+            item.basis.hash(&mut hasher);
+            item.grow.to_bits().hash(&mut hasher);
+            item.shrink.to_bits().hash(&mut hasher);
+            item.align_self.hash(&mut hasher);
+            item.baseline.hash(&mut hasher);
+        }
+        let config_hash = hasher.finish();
+
+        let mut hasher = FxHasher::default();
+        hints.hash(&mut hasher);
+
+        LayoutCacheKey {
+            area_x: area.x,
+            area_y: area.y,
+            area_width: area.width,
+            area_height: area.height,
+            constraints_hash: config_hash,
+            direction: self.direction,
+            intrinsics_hash: Some(hasher.finish()),
+        }
+    }
+
+    fn main_cross(&self, inner: Rect) -> (u16, u16) {
+        match self.direction {
+            Direction::Horizontal => (inner.width, inner.height),
+            Direction::Vertical => (inner.height, inner.width),
+        }
+    }
+
+    fn layout(&self, inner: Rect, hints: &[IntrinsicSize]) -> Vec<Rect> {
+        let count = self.items.len();
+        if count == 0 {
+            return Vec::new();
+        }
+        let (main_avail, cross_avail) = self.main_cross(inner);
+
+        // Hypothetical main sizes, clamped to the item's own bounds and the line.
+        let bases: Vec<u16> = self
+            .items
+            .iter()
+            .zip(hints)
+            .map(|(item, hint)| {
+                let main = hint.main(self.direction);
+                main.clamp(item.basis.unwrap_or(main.preferred))
+                    .min(main_avail)
+            })
+            .collect();
+
+        let lines = self.break_lines(&bases, main_avail);
+        let wrapping = self.wrap != FlexWrap::NoWrap;
+        let baseline_axis = self.direction == Direction::Horizontal;
+
+        let mut rects = vec![Rect::default(); count];
+        let mut cross_pos: u32 = 0;
+        for (line_idx, line) in lines.iter().enumerate() {
+            if line_idx > 0 {
+                cross_pos += self.line_gap as u32;
+            }
+            let remaining = (cross_avail as u32).saturating_sub(cross_pos) as u16;
+
+            let sizes = self.resolve_main(line.clone(), &bases, hints, main_avail);
+
+            // Baseline extents: max ascent above and descent below the baseline.
+            let mut ascent = 0u16;
+            let mut descent = 0u16;
+            let mut content_cross = 0u16;
+            for i in line.clone() {
+                let cross = hints[i].cross(self.direction);
+                let size = cross.clamp(cross.preferred).min(cross_avail);
+                if baseline_axis && self.align_of(i) == AlignItems::Baseline {
+                    let above = self.items[i].baseline.min(size);
+                    ascent = ascent.max(above);
+                    descent = descent.max(size - above);
+                } else {
+                    content_cross = content_cross.max(size);
+                }
+            }
+            content_cross = content_cross.max(ascent.saturating_add(descent));
+
+            // A single unwrapped line spans the whole cross axis.
+            let line_cross = if wrapping {
+                content_cross.min(remaining)
+            } else {
+                remaining
+            };
+
+            let line_start = if self.wrap == FlexWrap::WrapReverse {
+                (cross_avail as u32).saturating_sub(cross_pos + line_cross as u32) as u16
+            } else {
+                cross_pos as u16
+            };
+            let line_rect = match self.direction {
+                Direction::Horizontal => Rect::new(
+                    inner.x,
+                    inner.y.saturating_add(line_start),
+                    inner.width,
+                    line_cross,
+                ),
+                Direction::Vertical => Rect::new(
+                    inner.x.saturating_add(line_start),
+                    inner.y,
+                    line_cross,
+                    inner.height,
+                ),
+            };
+
+            let placer = Flex::default()
+                .direction(self.direction)
+                .gap(self.gap)
+                .alignment(self.justify);
+            let slots = placer.sizes_to_rects(line_rect, &sizes);
+
+            for (i, slot) in line.clone().zip(slots) {
+                let cross = hints[i].cross(self.direction);
+                let align = self.align_of(i);
+                let (offset, size) = match align {
+                    AlignItems::Stretch => (0, cross.clamp(line_cross).min(line_cross)),
+                    AlignItems::Baseline if baseline_axis => {
+                        let size = cross.clamp(cross.preferred).min(line_cross);
+                        let above = self.items[i].baseline.min(size);
+                        let offset = (ascent - above).min(line_cross - size);
+                        (offset, size)
+                    }
+                    AlignItems::Start | AlignItems::Baseline => {
+                        (0, cross.clamp(cross.preferred).min(line_cross))
+                    }
+                    AlignItems::Center => {
+                        let size = cross.clamp(cross.preferred).min(line_cross);
+                        ((line_cross - size) / 2, size)
+                    }
+                    AlignItems::End => {
+                        let size = cross.clamp(cross.preferred).min(line_cross);
+                        (line_cross - size, size)
+                    }
+                };
+                let rect = match self.direction {
+                    Direction::Horizontal => {
+                        Rect::new(slot.x, slot.y.saturating_add(offset), slot.width, size)
+                    }
+                    Direction::Vertical => {
+                        Rect::new(slot.x.saturating_add(offset), slot.y, size, slot.height)
+                    }
+                };
+                rects[i] = rect.intersection(&inner);
+            }
+
+            cross_pos += line_cross as u32;
+        }
+
+        if self.flow_direction.is_rtl() {
+            direction::mirror_rects_horizontal(&mut rects, inner);
+        }
+
+        rects
+    }
+
+    fn align_of(&self, index: usize) -> AlignItems {
+        self.items[index].align_self.unwrap_or(self.align_items)
+    }
+
+    /// Greedily break items into lines of at most `main_avail` cells.
+    fn break_lines(&self, bases: &[u16], main_avail: u16) -> Vec<std::ops::Range<usize>> {
+        let limit = if self.wrap == FlexWrap::NoWrap {
+            u32::MAX
+        } else {
+            main_avail as u32
+        };
+        let mut lines = Vec::new();
+        let mut start = 0;
+        let mut used: u32 = 0;
+        for (i, &basis) in bases.iter().enumerate() {
+            if i > start && used + self.gap as u32 + basis as u32 > limit {
+                lines.push(start..i);
+                start = i;
+                used = 0;
+            }
+            if i > start {
+                used += self.gap as u32;
+            }
+            used += basis as u32;
+        }
+        lines.push(start..bases.len());
+        lines
+    }
+
+    /// Resolve grow/shrink for one line, returning final main sizes.
+    fn resolve_main(
+        &self,
+        line: std::ops::Range<usize>,
+        bases: &[u16],
+        hints: &[IntrinsicSize],
+        main_avail: u16,
+    ) -> Vec<u16> {
+        let mut sizes: Vec<u16> = bases[line.clone()].to_vec();
+        let gaps = (line.len().saturating_sub(1) as u32) * self.gap as u32;
+        let used = sizes.iter().map(|&s| s as u32).sum::<u32>() + gaps;
+        let avail = main_avail as u32;
+
+        if used < avail {
+            let weights: Vec<f64> = line.clone().map(|i| self.items[i].grow_weight()).collect();
+            let room: Vec<u16> = line
+                .clone()
+                .zip(&sizes)
+                .map(|(i, &s)| {
+                    let max = hints[i].main(self.direction).max.unwrap_or(u16::MAX);
+                    max.saturating_sub(s)
+                })
+                .collect();
+            let delta = distribute(&weights, &room, avail - used);
+            for (s, d) in sizes.iter_mut().zip(delta) {
+                *s = s.saturating_add(d);
+            }
+        } else if used > avail {
+            let weights: Vec<f64> = line
+                .clone()
+                .zip(&sizes)
+                .map(|(i, &s)| self.items[i].shrink_weight() * s as f64)
+                .collect();
+            let room: Vec<u16> = line
+                .clone()
+                .zip(&sizes)
+                .map(|(i, &s)| s.saturating_sub(hints[i].main(self.direction).min))
+                .collect();
+            let delta = distribute(&weights, &room, used - avail);
+            for (s, d) in sizes.iter_mut().zip(delta) {
+                *s = s.saturating_sub(d);
+            }
+        }
+        sizes
+    }
+}
+
+/// Distribute `amount` cells across items proportionally to `weights`.
+///
+/// No item receives more than its `room`. Items whose room is exhausted are
+/// frozen and the remainder is redistributed among the rest; leftover single
+/// cells go to the heaviest items first (ties broken by index), so the result
+/// is deterministic.
+fn distribute(weights: &[f64], room: &[u16], mut amount: u32) -> Vec<u16> {
+    let mut room = room.to_vec();
+    let mut delta = vec![0u16; weights.len()];
+    loop {
+        let total: f64 = (0..weights.len())
+            .filter(|&i| weights[i] > 0.0 && room[i] > 0)
+            .map(|i| weights[i])
+            .sum();
+        if amount == 0 || total <= 0.0 {
+            break;
+        }
+
+        let mut given = 0u32;
+        for i in 0..weights.len() {
+            if weights[i] <= 0.0 || room[i] == 0 {
+                continue;
+            }
+            let share = ((amount as f64 * weights[i] / total).floor() as u32).min(room[i] as u32);
+            delta[i] += share as u16;
+            room[i] -= share as u16;
+            given += share;
+        }
+
+        if given == 0 {
+            let mut order: Vec<usize> = (0..weights.len())
+                .filter(|&i| weights[i] > 0.0 && room[i] > 0)
+                .collect();
+            order.sort_by(|&a, &b| weights[b].total_cmp(&weights[a]).then(a.cmp(&b)));
+            for i in order {
+                if amount == 0 {
+                    break;
+                }
+                delta[i] += 1;
+                room[i] -= 1;
+                amount -= 1;
+            }
+        } else {
+            amount -= given;
+        }
+    }
+    delta
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(width: u16) -> IntrinsicSize {
+        IntrinsicSize::exact(width, 1)
+    }
+
+    fn label() -> IntrinsicSize {
+        IntrinsicSize::new(LayoutSizeHint::ZERO, LayoutSizeHint::exact(1))
+    }
+
+    #[test]
+    fn empty_items_produce_no_rects() {
+        assert!(
+            FlexBox::horizontal()
+                .split(Rect::new(0, 0, 10, 10))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn empty_area_produces_default_rects() {
+        let flex = FlexBox::horizontal().items([FlexItem::fixed(3), FlexItem::fixed(4)]);
+        assert_eq!(
+            flex.split(Rect::new(0, 0, 0, 5)),
+            vec![Rect::default(), Rect::default()]
+        );
+    }
+
+    #[test]
+    fn nowrap_stretches_cross_axis() {
+        let flex = FlexBox::horizontal()
+            .gap(1)
+            .items([FlexItem::fixed(3), FlexItem::fixed(4)]);
+        let rects = flex.split(Rect::new(0, 0, 20, 3));
+        assert_eq!(rects, vec![Rect::new(0, 0, 3, 3), Rect::new(4, 0, 4, 3)]);
+    }
+
+    #[test]
+    fn wrap_breaks_lines_greedily() {
+        let widths = [4u16, 5, 3, 6, 2];
+        let flex = FlexBox::horizontal()
+            .wrap(FlexWrap::Wrap)
+            .gap(1)
+            .line_gap(1)
+            .items(widths.iter().map(|_| FlexItem::new()));
+        let rects = flex.split_with_measurer(Rect::new(0, 0, 10, 10), |i, _| tag(widths[i]));
+        assert_eq!(
+            rects,
+            vec![
+                Rect::new(0, 0, 4, 1),
+                Rect::new(5, 0, 5, 1),
+                Rect::new(0, 2, 3, 1),
+                Rect::new(4, 2, 6, 1),
+                Rect::new(0, 4, 2, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn wrap_reverse_stacks_from_cross_end() {
+        let flex = FlexBox::horizontal()
+            .wrap(FlexWrap::WrapReverse)
+            .items([FlexItem::new(), FlexItem::new()]);
+        let rects = flex.split_with_measurer(Rect::new(0, 0, 5, 6), |_, _| tag(4));
+        assert_eq!(rects, vec![Rect::new(0, 5, 4, 1), Rect::new(0, 4, 4, 1)]);
+    }
+
+    #[test]
+    fn oversized_item_is_clamped_to_line() {
+        let flex = FlexBox::horizontal()
+            .wrap(FlexWrap::Wrap)
+            .items([FlexItem::fixed(3), FlexItem::fixed(50)]);
+        let rects = flex.split_with_measurer(Rect::new(0, 0, 10, 4), |_, _| label());
+        assert_eq!(rects[1], Rect::new(0, 1, 10, 1));
+    }
+
+    #[test]
+    fn grow_distributes_free_space_by_weight() {
+        let flex = FlexBox::horizontal().items([
+            FlexItem::fixed(2).grow(1.0),
+            FlexItem::fixed(2).grow(3.0),
+            FlexItem::fixed(2),
+        ]);
+        let rects = flex.split(Rect::new(0, 0, 14, 1));
+        let widths: Vec<u16> = rects.iter().map(|r| r.width).collect();
+        assert_eq!(widths, vec![4, 8, 2]);
+    }
+
+    #[test]
+    fn grow_respects_max_and_redistributes() {
+        let flex =
+            FlexBox::horizontal().items([FlexItem::new().grow(1.0), FlexItem::new().grow(1.0)]);
+        let rects = flex.split_with_measurer(Rect::new(0, 0, 20, 1), |i, _| {
+            let width = if i == 0 {
+                LayoutSizeHint {
+                    min: 0,
+                    preferred: 2,
+                    max: Some(4),
+                }
+            } else {
+                LayoutSizeHint::at_least(0, 2)
+            };
+            IntrinsicSize::new(width, LayoutSizeHint::exact(1))
+        });
+        assert_eq!(rects[0].width, 4);
+        assert_eq!(rects[1].width, 16);
+    }
+
+    #[test]
+    fn grow_handles_remainders_deterministically() {
+        let flex = FlexBox::horizontal().items([
+            FlexItem::fixed(0).grow(1.0),
+            FlexItem::fixed(0).grow(1.0),
+            FlexItem::fixed(0).grow(1.0),
+        ]);
+        let rects = flex.split(Rect::new(0, 0, 10, 1));
+        let widths: Vec<u16> = rects.iter().map(|r| r.width).collect();
+        assert_eq!(widths.iter().sum::<u16>(), 10);
+        assert_eq!(widths, vec![4, 3, 3]);
+    }
+
+    #[test]
+    fn shrink_weighted_by_basis_and_respects_min() {
+        let flex = FlexBox::horizontal().items([
+            FlexItem::fixed(10),
+            FlexItem::fixed(10).shrink(0.0),
+            FlexItem::fixed(20),
+        ]);
+        let rects = flex.split(Rect::new(0, 0, 34, 1));
+        let widths: Vec<u16> = rects.iter().map(|r| r.width).collect();
+        assert_eq!(widths, vec![8, 10, 16]);
+
+        let flex = FlexBox::horizontal().items([FlexItem::new(), FlexItem::new()]);
+        let rects = flex.split_with_measurer(Rect::new(0, 0, 12, 1), |_, _| {
+            IntrinsicSize::new(
+                LayoutSizeHint {
+                    min: 5,
+                    preferred: 10,
+                    max: None,
+                },
+                LayoutSizeHint::exact(1),
+            )
+        });
+        assert_eq!(rects[0].width, 6);
+        assert_eq!(rects[1].width, 6);
+    }
+
+    #[test]
+    fn cross_alignment_and_align_self() {
+        let flex = FlexBox::horizontal()
+            .align_items(AlignItems::Center)
+            .items([
+                FlexItem::fixed(2),
+                FlexItem::fixed(2).align_self(AlignItems::End),
+                FlexItem::fixed(2).align_self(AlignItems::Start),
+                FlexItem::fixed(2).align_self(AlignItems::Stretch),
+            ]);
+        let rects = flex.split_with_measurer(Rect::new(0, 0, 8, 5), |_, _| {
+            IntrinsicSize::new(LayoutSizeHint::ZERO, LayoutSizeHint::at_least(1, 1))
+        });
+        assert_eq!(rects[0], Rect::new(0, 2, 2, 1));
+        assert_eq!(rects[1], Rect::new(2, 4, 2, 1));
+        assert_eq!(rects[2], Rect::new(4, 0, 2, 1));
+        assert_eq!(rects[3], Rect::new(6, 0, 2, 5));
+    }
+
+    #[test]
+    fn stretch_respects_cross_max() {
+        let flex = FlexBox::horizontal().items([FlexItem::fixed(2)]);
+        let rects = flex.split_with_measurer(Rect::new(0, 0, 8, 5), |_, _| {
+            IntrinsicSize::new(
+                LayoutSizeHint::ZERO,
+                LayoutSizeHint {
+                    min: 1,
+                    preferred: 1,
+                    max: Some(3),
+                },
+            )
+        });
+        assert_eq!(rects[0].height, 3);
+    }
+
+    #[test]
+    fn baseline_alignment_lines_up_baselines() {
+        // A bordered card (baseline 1, height 3) next to a bare label (baseline 0).
+        let flex = FlexBox::horizontal()
+            .wrap(FlexWrap::Wrap)
+            .align_items(AlignItems::Baseline)
+            .items([FlexItem::fixed(6).baseline(1), FlexItem::fixed(4)]);
+        let heights = [3u16, 1];
+        let rects = flex.split_with_measurer(Rect::new(0, 0, 20, 10), |i, _| {
+            IntrinsicSize::new(LayoutSizeHint::ZERO, LayoutSizeHint::exact(heights[i]))
+        });
+        assert_eq!(rects[0], Rect::new(0, 0, 6, 3));
+        assert_eq!(rects[1], Rect::new(6, 1, 4, 1));
+    }
+
+    #[test]
+    fn justify_applies_per_line() {
+        let flex = FlexBox::horizontal()
+            .wrap(FlexWrap::Wrap)
+            .justify(Alignment::Center)
+            .items([FlexItem::fixed(6), FlexItem::fixed(6), FlexItem::fixed(2)]);
+        let rects = flex.split_with_measurer(Rect::new(0, 0, 10, 4), |_, _| label());
+        assert_eq!(rects[0], Rect::new(2, 0, 6, 1));
+        assert_eq!(rects[1], Rect::new(1, 1, 6, 1));
+        assert_eq!(rects[2], Rect::new(7, 1, 2, 1));
+    }
+
+    #[test]
+    fn vertical_wrap_builds_columns() {
+        let flex = FlexBox::vertical().wrap(FlexWrap::Wrap).gap(1).items([
+            FlexItem::fixed(2),
+            FlexItem::fixed(2),
+            FlexItem::fixed(2),
+        ]);
+        let rects = flex.split_with_measurer(Rect::new(0, 0, 10, 5), |_, _| {
+            IntrinsicSize::new(LayoutSizeHint::exact(3), LayoutSizeHint::ZERO)
+        });
+        assert_eq!(rects[0], Rect::new(0, 0, 3, 2));
+        assert_eq!(rects[1], Rect::new(0, 3, 3, 2));
+        assert_eq!(rects[2], Rect::new(3, 0, 3, 2));
+    }
+
+    #[test]
+    fn rtl_mirrors_rows() {
+        let flex = FlexBox::horizontal()
+            .flow_direction(FlowDirection::Rtl)
+            .items([FlexItem::fixed(3), FlexItem::fixed(2)]);
+        let rects = flex.split(Rect::new(0, 0, 10, 1));
+        assert_eq!(rects, vec![Rect::new(7, 0, 3, 1), Rect::new(5, 0, 2, 1)]);
+    }
+
+    #[test]
+    fn margin_is_applied() {
+        let flex = FlexBox::horizontal()
+            .margin(Sides::all(1))
+            .items([FlexItem::fixed(3)]);
+        let rects = flex.split(Rect::new(0, 0, 10, 5));
+        assert_eq!(rects, vec![Rect::new(1, 1, 3, 3)]);
+    }
+
+    #[test]
+    fn lines_beyond_cross_space_are_clipped() {
+        let flex = FlexBox::horizontal().wrap(FlexWrap::Wrap).items([
+            FlexItem::fixed(5),
+            FlexItem::fixed(5),
+            FlexItem::fixed(5),
+        ]);
+        let rects = flex.split_with_measurer(Rect::new(0, 0, 5, 2), |_, _| label());
+        assert_eq!(rects[1], Rect::new(0, 1, 5, 1));
+        assert!(rects[2].is_empty());
+    }
+
+    #[test]
+    fn split_cached_hits_and_tracks_intrinsics() {
+        let mut cache = LayoutCache::new(8);
+        let flex = FlexBox::horizontal()
+            .wrap(FlexWrap::Wrap)
+            .items([FlexItem::new(), FlexItem::new()]);
+        let area = Rect::new(0, 0, 10, 4);
+
+        let a = flex.split_cached(area, &mut cache, |_, _| tag(4));
+        let b = flex.split_cached(area, &mut cache, |_, _| tag(4));
+        assert_eq!(a, b);
+        assert_eq!(cache.stats().hits, 1);
+
+        let c = flex.split_cached(area, &mut cache, |_, _| tag(6));
+        assert_eq!(cache.stats().misses, 2);
+        assert_eq!(c, flex.split_with_measurer(area, |_, _| tag(6)));
+        assert_eq!(c[1].y, 1);
+    }
+
+    #[test]
+    fn distribute_is_exhaustive_within_room() {
+        let delta = distribute(&[1.0, 1.0, 0.0], &[3, 100, 100], 10);
+        assert_eq!(delta, vec![3, 7, 0]);
+        let delta = distribute(&[1.0], &[2], 10);
+        assert_eq!(delta, vec![2]);
+    }
+}
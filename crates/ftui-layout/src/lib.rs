@@ -5,10 +5,13 @@
 //! This crate provides layout components for terminal UIs:
 //!
 //! - [`Flex`] - 1D constraint-based layout (rows or columns)
+//! - [`FlexBox`] - Wrapping flex layout with grow/shrink and cross-axis alignment
 //! - [`Grid`] - 2D constraint-based layout with cell spanning
+//! - [`AnchorPlacement`] - Collision-aware popover placement next to an anchor rect
 //! - [`Constraint`] - Size constraints (Fixed, Percentage, Min, Max, Ratio, FitContent)
 //! - [`debug`] - Layout constraint debugging and introspection
 //! - [`cache`] - Layout result caching for memoization
+//! - [`transition`] - Animated interpolation between layout solutions
 //!
 //! # Role in FrankenTUI
 //! `ftui-layout` is the geometry solver for widgets and screens. It converts
@@ -39,9 +42,11 @@
 //! });
 //! ```
 
+pub mod anchor;
 pub mod cache;
 pub mod debug;
 pub mod direction;
+pub mod flexbox;
 pub mod grid;
 pub mod pane;
 #[cfg(test)]
@@ -50,10 +55,13 @@ mod repro_max_constraint;
 mod repro_space_around;
 pub mod responsive;
 pub mod responsive_layout;
+pub mod transition;
 pub mod visibility;
 
+pub use anchor::{AnchorAlign, AnchorPlacement, AnchorSide, FlipStrategy, Placement, place_within};
 pub use cache::{CoherenceCache, CoherenceId, LayoutCache, LayoutCacheKey, LayoutCacheStats};
 pub use direction::{FlowDirection, LogicalAlignment, LogicalSides, mirror_rects_horizontal};
+pub use flexbox::{AlignItems, FlexBox, FlexItem, FlexWrap, IntrinsicSize};
 pub use ftui_core::geometry::{Rect, Sides, Size};
 pub use grid::{Grid, GridArea, GridLayout};
 pub use pane::{
@@ -70,6 +78,7 @@ pub use pane::{
 pub use responsive::Responsive;
 pub use responsive_layout::{ResponsiveLayout, ResponsiveSplit};
 use std::cmp::min;
+pub use transition::{LayoutTransitions, TransitionCurve};
 pub use visibility::Visibility;
 
 /// A constraint on the size of a layout area.
@@ -130,7 +139,7 @@ pub enum Constraint {
 /// assert_eq!(hint.clamp(3), 5);   // Below min
 /// assert_eq!(hint.clamp(30), 20); // Above max
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct LayoutSizeHint {
     /// Minimum size (widget clips below this).
     pub min: u16,
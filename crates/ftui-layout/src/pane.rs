@@ -733,6 +733,11 @@ pub enum PaneOperation {
     },
     /// Swap two non-ancestor subtrees.
     SwapNodes { first: PaneId, second: PaneId },
+    /// Replace the ratio of one split node (interactive splitter resize).
+    SetSplitRatio {
+        split: PaneId,
+        ratio: PaneSplitRatio,
+    },
     /// Canonicalize all split ratios to reduced form and validate positivity.
     NormalizeRatios,
 }
@@ -746,6 +751,7 @@ impl PaneOperation {
             Self::CloseNode { .. } => PaneOperationKind::CloseNode,
             Self::MoveSubtree { .. } => PaneOperationKind::MoveSubtree,
             Self::SwapNodes { .. } => PaneOperationKind::SwapNodes,
+            Self::SetSplitRatio { .. } => PaneOperationKind::SetSplitRatio,
             Self::NormalizeRatios => PaneOperationKind::NormalizeRatios,
         }
     }
@@ -754,6 +760,7 @@ impl PaneOperation {
     fn referenced_nodes(&self) -> Vec<PaneId> {
         match self {
             Self::SplitLeaf { target, .. } | Self::CloseNode { target } => vec![*target],
+            Self::SetSplitRatio { split, .. } => vec![*split],
             Self::MoveSubtree { source, target, .. }
             | Self::SwapNodes {
                 first: source,
@@ -774,6 +781,7 @@ pub enum PaneOperationKind {
     CloseNode,
     MoveSubtree,
     SwapNodes,
+    SetSplitRatio,
     NormalizeRatios,
 }
 
@@ -1383,6 +1391,9 @@ impl PaneTree {
             PaneOperation::SwapNodes { first, second } => {
                 self.apply_swap_nodes(first, second, touched)
             }
+            PaneOperation::SetSplitRatio { split, ratio } => {
+                self.apply_set_split_ratio(split, ratio, touched)
+            }
             PaneOperation::NormalizeRatios => self.apply_normalize_ratios(touched),
         }
     }
@@ -1666,6 +1677,24 @@ impl PaneTree {
         Ok(())
     }
 
+    fn apply_set_split_ratio(
+        &mut self,
+        split_id: PaneId,
+        ratio: PaneSplitRatio,
+        touched: &mut BTreeSet<PaneId>,
+    ) -> Result<(), PaneOperationFailure> {
+        let node = self
+            .nodes
+            .get_mut(&split_id)
+            .ok_or(PaneOperationFailure::MissingNode { node_id: split_id })?;
+        let PaneNodeKind::Split(split) = &mut node.kind else {
+            return Err(PaneOperationFailure::ParentNotSplit { node_id: split_id });
+        };
+        split.ratio = ratio;
+        let _ = touched.insert(split_id);
+        Ok(())
+    }
+
     fn apply_normalize_ratios(
         &mut self,
         touched: &mut BTreeSet<PaneId>,
@@ -3493,6 +3522,44 @@ mod tests {
         assert_eq!(split.ratio.denominator(), 2);
     }
 
+    #[test]
+    fn set_split_ratio_updates_split_and_rejects_leaves() {
+        let mut tree = PaneTree::from_snapshot(make_valid_snapshot()).expect("valid tree");
+        let outcome = tree
+            .apply_operation(
+                15,
+                PaneOperation::SetSplitRatio {
+                    split: id(1),
+                    ratio: PaneSplitRatio::new(2, 6).expect("valid ratio"),
+                },
+            )
+            .expect("set ratio should succeed");
+        assert_eq!(outcome.kind, PaneOperationKind::SetSplitRatio);
+        assert_eq!(outcome.touched_nodes, vec![id(1)]);
+
+        let PaneNodeKind::Split(split) = &tree.node(id(1)).expect("root exists").kind else {
+            unreachable!("root should be split");
+        };
+        assert_eq!(split.ratio.numerator(), 1);
+        assert_eq!(split.ratio.denominator(), 3);
+
+        let before = tree.state_hash();
+        let err = tree
+            .apply_operation(
+                16,
+                PaneOperation::SetSplitRatio {
+                    split: id(2),
+                    ratio: PaneSplitRatio::default(),
+                },
+            )
+            .expect_err("leaf has no ratio");
+        assert_eq!(
+            err.reason,
+            PaneOperationFailure::ParentNotSplit { node_id: id(2) }
+        );
+        assert_eq!(tree.state_hash(), before);
+    }
+
     #[test]
     fn transaction_commit_persists_mutations_and_journal_order() {
         let tree = PaneTree::singleton("root");
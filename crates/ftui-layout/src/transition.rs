@@ -0,0 +1,545 @@
+//! Animated layout transitions between frames.
+//!
+//! [`Flex::split`](crate::Flex::split) and friends produce new rects
+//! instantly, so a sidebar toggle or breakpoint switch makes panels jump.
+//! [`LayoutTransitions`] is an opt-in layer that remembers the last rect
+//! handed out for each stable widget key and interpolates towards newly
+//! solved rects using [`ftui_core::animation`] easings or springs.
+//!
+//! # Usage
+//!
+//! ```ignore
+//! // In the model:
+//! transitions: LayoutTransitions<&'static str>,
+//!
+//! // In view():
+//! transitions.set_degradation(frame.degradation);
+//! let rects = Flex::horizontal()
+//!     .constraints([Constraint::Fixed(sidebar_width), Constraint::Fill])
+//!     .split(area);
+//! let [sidebar, main] = transitions.resolve_all(["sidebar", "main"], &rects)[..] else { .. };
+//!
+//! // On every tick:
+//! transitions.tick(dt);
+//! if transitions.is_animating() { /* request another frame */ }
+//! ```
+//!
+//! # Budget Integration
+//!
+//! Pass the frame's [`DegradationLevel`] to
+//! [`set_degradation`](LayoutTransitions::set_degradation) each frame. Once
+//! the budget no longer renders decorative elements, in-flight transitions
+//! snap to their targets and new targets are applied instantly; animation
+//! resumes when the budget recovers.
+//!
+//! # Lifecycle
+//!
+//! The first [`resolve`](LayoutTransitions::resolve) after a
+//! [`tick`](LayoutTransitions::tick) starts a new frame and forgets keys that
+//! were not resolved during the previous frame, so widgets that disappear do
+//! not leak state. Ticks without a render in between keep every key. A key
+//! that reappears starts from its target (or grows from its center with
+//! [`enter_from_center`](LayoutTransitions::enter_from_center)).
+
+use std::hash::Hash;
+use std::time::Duration;
+
+use ftui_core::animation::{Animation, EasingFn, Fade, Spring, ease_out_cubic};
+use ftui_core::geometry::Rect;
+use ftui_render::budget::DegradationLevel;
+use rustc_hash::FxHashMap;
+
+/// Timing curve for layout transitions.
+#[derive(Debug, Clone, Copy)]
+pub enum TransitionCurve {
+    /// Fixed-duration interpolation with an easing function.
+    Eased {
+        /// Transition duration.
+        duration: Duration,
+        /// Easing applied to linear progress.
+        easing: EasingFn,
+    },
+    /// Physically based spring; may overshoot slightly before settling.
+    Spring {
+        /// Spring stiffness (see [`Spring::with_stiffness`]).
+        stiffness: f64,
+        /// Spring damping (see [`Spring::with_damping`]).
+        damping: f64,
+    },
+}
+
+impl Default for TransitionCurve {
+    fn default() -> Self {
+        Self::Eased {
+            duration: Duration::from_millis(180),
+            easing: ease_out_cubic,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Progress {
+    Eased(Fade),
+    Spring(Spring),
+}
+
+impl Progress {
+    fn start(curve: TransitionCurve) -> Self {
+        match curve {
+            TransitionCurve::Eased { duration, easing } => {
+                Self::Eased(Fade::new(duration).easing(easing))
+            }
+            TransitionCurve::Spring { stiffness, damping } => Self::Spring(
+                Spring::normalized()
+                    .with_stiffness(stiffness)
+                    .with_damping(damping),
+            ),
+        }
+    }
+
+    fn tick(&mut self, dt: Duration) {
+        match self {
+            Self::Eased(fade) => fade.tick(dt),
+            Self::Spring(spring) => spring.tick(dt),
+        }
+    }
+
+    /// Interpolation factor; springs are not clamped so they can overshoot.
+    fn value(&self) -> f64 {
+        match self {
+            Self::Eased(fade) => fade.value() as f64,
+            Self::Spring(spring) => spring.position(),
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        match self {
+            Self::Eased(fade) => fade.is_complete(),
+            Self::Spring(spring) => spring.is_at_rest(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    from: Rect,
+    to: Rect,
+    progress: Option<Progress>,
+    seen: bool,
+}
+
+impl Entry {
+    fn current(&self) -> Rect {
+        match &self.progress {
+            Some(progress) => lerp_rect(self.from, self.to, progress.value()),
+            None => self.to,
+        }
+    }
+}
+
+/// Keyed rect interpolation between layout solutions.
+///
+/// See the [module documentation](self) for usage.
+#[derive(Debug, Clone)]
+pub struct LayoutTransitions<K> {
+    entries: FxHashMap<K, Entry>,
+    curve: TransitionCurve,
+    enabled: bool,
+    enter_from_center: bool,
+    /// Set by `tick`; the next `resolve` sweeps keys unseen last frame.
+    frame_pending: bool,
+}
+
+impl<K: Hash + Eq> Default for LayoutTransitions<K> {
+    fn default() -> Self {
+        Self::new(TransitionCurve::default())
+    }
+}
+
+impl<K: Hash + Eq> LayoutTransitions<K> {
+    /// Create a transition layer with the given timing curve.
+    #[must_use]
+    pub fn new(curve: TransitionCurve) -> Self {
+        Self {
+            entries: FxHashMap::default(),
+            curve,
+            enabled: true,
+            enter_from_center: false,
+            frame_pending: false,
+        }
+    }
+
+    /// Create an eased transition layer.
+    #[must_use]
+    pub fn eased(duration: Duration, easing: EasingFn) -> Self {
+        Self::new(TransitionCurve::Eased { duration, easing })
+    }
+
+    /// Create a spring-driven transition layer.
+    #[must_use]
+    pub fn spring(stiffness: f64, damping: f64) -> Self {
+        Self::new(TransitionCurve::Spring { stiffness, damping })
+    }
+
+    /// Animate newly seen keys growing from the center of their target rect.
+    #[must_use]
+    pub fn enter_from_center(mut self, enabled: bool) -> Self {
+        self.enter_from_center = enabled;
+        self
+    }
+
+    /// The timing curve used for new transitions.
+    #[must_use]
+    pub fn curve(&self) -> TransitionCurve {
+        self.curve
+    }
+
+    /// Change the timing curve. In-flight transitions keep their curve.
+    pub fn set_curve(&mut self, curve: TransitionCurve) {
+        self.curve = curve;
+    }
+
+    /// Whether transitions are currently animated.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enable or disable animation. Disabling snaps all rects to their targets.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.snap_all();
+        }
+    }
+
+    /// Animate only while `level` renders decorative elements.
+    ///
+    /// Degrading past that snaps all rects to their targets, as
+    /// [`set_enabled(false)`](Self::set_enabled) does.
+    pub fn set_degradation(&mut self, level: DegradationLevel) {
+        self.set_enabled(level.render_decorative());
+    }
+
+    /// Finish all in-flight transitions immediately.
+    pub fn snap_all(&mut self) {
+        for entry in self.entries.values_mut() {
+            entry.from = entry.to;
+            entry.progress = None;
+        }
+    }
+
+    /// Forget all remembered rects.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Number of tracked keys.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no keys are tracked.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Whether any transition is still in flight.
+    ///
+    /// Use this to decide whether another frame should be scheduled.
+    #[must_use]
+    pub fn is_animating(&self) -> bool {
+        self.entries.values().any(|e| e.progress.is_some())
+    }
+
+    /// Report the solved `target` rect for `key` and get the rect to draw.
+    ///
+    /// When the target differs from the previous one, a transition starts
+    /// from the currently displayed rect, so retargeting mid-flight is smooth.
+    pub fn resolve(&mut self, key: K, target: Rect) -> Rect {
+        if std::mem::take(&mut self.frame_pending) {
+            self.entries
+                .retain(|_, entry| std::mem::take(&mut entry.seen));
+        }
+        let curve = self.curve;
+        let animate = self.enabled;
+        let enter = self.enter_from_center && animate;
+
+        let entry = self.entries.entry(key).or_insert_with(|| {
+            let from = if enter { center_point(target) } else { target };
+            Entry {
+                from,
+                to: from,
+                progress: None,
+                seen: true,
+            }
+        });
+        entry.seen = true;
+
+        if entry.to != target {
+            let current = entry.current();
+            entry.to = target;
+            if animate && current != target {
+                entry.from = current;
+                entry.progress = Some(Progress::start(curve));
+            } else {
+                entry.from = target;
+                entry.progress = None;
+            }
+        }
+        entry.current()
+    }
+
+    /// Resolve a batch of rects, e.g. the output of [`Flex::split`](crate::Flex::split).
+    ///
+    /// Keys are paired with rects positionally; extra keys or rects are ignored.
+    pub fn resolve_all(
+        &mut self,
+        keys: impl IntoIterator<Item = K>,
+        targets: &[Rect],
+    ) -> Vec<Rect> {
+        keys.into_iter()
+            .zip(targets)
+            .map(|(key, &target)| self.resolve(key, target))
+            .collect()
+    }
+
+    /// The rect currently displayed for `key`, if tracked.
+    #[must_use]
+    pub fn current(&self, key: &K) -> Option<Rect> {
+        self.entries.get(key).map(Entry::current)
+    }
+
+    /// Advance all transitions by `dt`.
+    ///
+    /// Keys not resolved during the frame before this tick are dropped when
+    /// the next frame starts resolving.
+    pub fn tick(&mut self, dt: Duration) {
+        self.frame_pending = true;
+        for entry in self.entries.values_mut() {
+            if let Some(progress) = &mut entry.progress {
+                progress.tick(dt);
+                if progress.is_complete() {
+                    entry.from = entry.to;
+                    entry.progress = None;
+                }
+            }
+        }
+    }
+}
+
+/// Zero-size rect at the center of `rect`.
+fn center_point(rect: Rect) -> Rect {
+    Rect::new(
+        rect.x.saturating_add(rect.width / 2),
+        rect.y.saturating_add(rect.height / 2),
+        0,
+        0,
+    )
+}
+
+/// Interpolate edges (not origin + size) so adjacent panels stay seamless.
+fn lerp_rect(from: Rect, to: Rect, t: f64) -> Rect {
+    let lerp = |a: u16, b: u16| -> i32 { (a as f64 + (b as f64 - a as f64) * t).round() as i32 };
+    let left = lerp(from.x, to.x);
+    let top = lerp(from.y, to.y);
+    let right = lerp(from.right(), to.right()).max(left);
+    let bottom = lerp(from.bottom(), to.bottom()).max(top);
+    let clamp = |v: i32| v.clamp(0, u16::MAX as i32) as u16;
+    let (x, y) = (clamp(left), clamp(top));
+    Rect::new(x, y, clamp(right) - x, clamp(bottom) - y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_core::animation::linear;
+
+    const MS_50: Duration = Duration::from_millis(50);
+
+    fn linear_100ms() -> LayoutTransitions<&'static str> {
+        LayoutTransitions::eased(Duration::from_millis(100), linear)
+    }
+
+    #[test]
+    fn first_resolve_returns_target() {
+        let mut t = linear_100ms();
+        let r = Rect::new(0, 0, 20, 10);
+        assert_eq!(t.resolve("a", r), r);
+        assert!(!t.is_animating());
+    }
+
+    #[test]
+    fn interpolates_towards_new_target() {
+        let mut t = linear_100ms();
+        t.resolve("a", Rect::new(0, 0, 20, 10));
+        t.tick(MS_50);
+
+        let target = Rect::new(0, 0, 40, 10);
+        assert_eq!(t.resolve("a", target), Rect::new(0, 0, 20, 10));
+        assert!(t.is_animating());
+
+        t.tick(MS_50);
+        assert_eq!(t.resolve("a", target), Rect::new(0, 0, 30, 10));
+        t.tick(MS_50);
+        assert_eq!(t.resolve("a", target), target);
+        assert!(!t.is_animating());
+    }
+
+    #[test]
+    fn adjacent_panels_stay_seamless() {
+        let mut t = LayoutTransitions::eased(Duration::from_millis(90), linear);
+        let keys = ["sidebar", "main"];
+        t.resolve_all(keys, &[Rect::new(0, 0, 10, 5), Rect::new(10, 0, 70, 5)]);
+        t.tick(Duration::ZERO);
+
+        let targets = [Rect::new(0, 0, 25, 5), Rect::new(25, 0, 55, 5)];
+        for _ in 0..4 {
+            let rects = t.resolve_all(keys, &targets);
+            assert_eq!(rects[0].right(), rects[1].x);
+            assert_eq!(rects[1].right(), 80);
+            t.tick(Duration::from_millis(30));
+        }
+        assert_eq!(t.resolve_all(keys, &targets), targets.to_vec());
+    }
+
+    #[test]
+    fn retarget_mid_flight_starts_from_current() {
+        let mut t = linear_100ms();
+        t.resolve("a", Rect::new(0, 0, 10, 1));
+        t.tick(Duration::ZERO);
+        t.resolve("a", Rect::new(0, 0, 30, 1));
+        t.tick(MS_50);
+        assert_eq!(t.current(&"a"), Some(Rect::new(0, 0, 20, 1)));
+
+        // New target: the transition restarts from width 20.
+        assert_eq!(
+            t.resolve("a", Rect::new(0, 0, 0, 1)),
+            Rect::new(0, 0, 20, 1)
+        );
+        t.tick(MS_50);
+        assert_eq!(
+            t.resolve("a", Rect::new(0, 0, 0, 1)),
+            Rect::new(0, 0, 10, 1)
+        );
+    }
+
+    #[test]
+    fn disabled_snaps_and_skips_animation() {
+        let mut t = linear_100ms();
+        t.resolve("a", Rect::new(0, 0, 10, 1));
+        t.tick(Duration::ZERO);
+        t.resolve("a", Rect::new(0, 0, 30, 1));
+        assert!(t.is_animating());
+
+        t.set_enabled(false);
+        assert!(!t.is_animating());
+        assert_eq!(t.current(&"a"), Some(Rect::new(0, 0, 30, 1)));
+        assert_eq!(
+            t.resolve("a", Rect::new(5, 0, 10, 1)),
+            Rect::new(5, 0, 10, 1)
+        );
+        assert!(!t.is_animating());
+    }
+
+    #[test]
+    fn degraded_budget_snaps_until_recovered() {
+        let mut t = linear_100ms();
+        t.resolve("a", Rect::new(0, 0, 10, 1));
+        t.tick(Duration::ZERO);
+        t.resolve("a", Rect::new(0, 0, 30, 1));
+        assert!(t.is_animating());
+
+        t.set_degradation(DegradationLevel::SimpleBorders);
+        assert!(t.is_animating(), "decorations still render");
+
+        t.set_degradation(DegradationLevel::EssentialOnly);
+        assert!(!t.is_enabled());
+        assert!(!t.is_animating());
+        assert_eq!(t.current(&"a"), Some(Rect::new(0, 0, 30, 1)));
+
+        t.set_degradation(DegradationLevel::Full);
+        assert_eq!(
+            t.resolve("a", Rect::new(0, 0, 10, 1)),
+            Rect::new(0, 0, 30, 1)
+        );
+        assert!(t.is_animating());
+    }
+
+    #[test]
+    fn unseen_keys_are_dropped_next_frame() {
+        let mut t = linear_100ms();
+        t.resolve("a", Rect::new(0, 0, 10, 1));
+        t.resolve("b", Rect::new(10, 0, 10, 1));
+        t.tick(MS_50);
+        assert_eq!(t.len(), 2);
+
+        t.resolve("a", Rect::new(0, 0, 10, 1));
+        t.tick(MS_50);
+        assert_eq!(t.len(), 2);
+        t.resolve("a", Rect::new(0, 0, 10, 1));
+        assert_eq!(t.len(), 1);
+        assert_eq!(t.current(&"b"), None);
+    }
+
+    #[test]
+    fn ticks_without_resolve_keep_keys() {
+        let mut t = linear_100ms();
+        t.resolve("a", Rect::new(0, 0, 10, 1));
+        t.resolve("b", Rect::new(10, 0, 10, 1));
+        t.tick(Duration::ZERO);
+        t.resolve("a", Rect::new(0, 0, 30, 1));
+        t.resolve("b", Rect::new(30, 0, 10, 1));
+
+        // Several ticks between renders: nothing is forgotten.
+        t.tick(MS_50);
+        t.tick(Duration::from_millis(25));
+        assert_eq!(t.len(), 2);
+        assert_eq!(t.current(&"a"), Some(Rect::new(0, 0, 25, 1)));
+        assert!(t.is_animating());
+
+        assert_eq!(
+            t.resolve("b", Rect::new(30, 0, 10, 1)),
+            Rect::new(25, 0, 10, 1)
+        );
+        assert_eq!(t.len(), 2);
+    }
+
+    #[test]
+    fn enter_from_center_grows_new_keys() {
+        let mut t = linear_100ms().enter_from_center(true);
+        let target = Rect::new(0, 0, 20, 10);
+        assert_eq!(t.resolve("a", target), Rect::new(10, 5, 0, 0));
+        t.tick(MS_50);
+        assert_eq!(t.resolve("a", target), Rect::new(5, 3, 10, 5));
+        t.tick(MS_50);
+        assert_eq!(t.resolve("a", target), target);
+    }
+
+    #[test]
+    fn spring_settles_on_target() {
+        let mut t: LayoutTransitions<u32> = LayoutTransitions::spring(170.0, 26.0);
+        t.resolve(1, Rect::new(0, 0, 10, 1));
+        t.tick(Duration::ZERO);
+        let target = Rect::new(0, 0, 50, 1);
+        t.resolve(1, target);
+        for _ in 0..200 {
+            t.tick(Duration::from_millis(16));
+            t.resolve(1, target);
+            if !t.is_animating() {
+                break;
+            }
+        }
+        assert!(!t.is_animating());
+        assert_eq!(t.current(&1), Some(target));
+    }
+
+    #[test]
+    fn lerp_rect_clamps_overshoot() {
+        let r = lerp_rect(Rect::new(5, 0, 10, 1), Rect::new(0, 0, 5, 1), 2.0);
+        assert_eq!(r.x, 0);
+        assert_eq!(r.width, 0);
+    }
+}
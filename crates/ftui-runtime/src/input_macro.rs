@@ -842,6 +842,7 @@ impl RecordingFilter {
             Event::Paste(_) => self.paste,
             Event::Focus(_) => self.focus,
             Event::Clipboard(_) => true, // Always record clipboard responses
+            Event::BackgroundColor(_) => true, // Terminal replies, like clipboard
             Event::Tick => false,        // Internal timing, not recorded
         }
     }
@@ -14,6 +14,7 @@
 //! - [`Cmd`] - Commands for side effects
 //! - [`Subscription`] - Trait for continuous event sources
 //! - [`Every`] - Built-in tick subscription
+//! - [`ThemeProvider`] - Live theme swaps with animated cross-fades
 //!
 //! # Role in FrankenTUI
 //! `ftui-runtime` is the orchestrator. It consumes input events from
@@ -54,6 +55,7 @@ pub mod stdio_capture;
 pub mod string_model;
 pub mod subscription;
 pub mod terminal_writer;
+pub mod theme_provider;
 pub mod undo;
 pub mod validation_pipeline;
 pub mod voi_sampling;
@@ -80,7 +82,8 @@ pub use input_macro::{
     RecordingFilter, RecordingState, TimedEvent,
 };
 pub use locale::{
-    Locale, LocaleContext, LocaleOverride, current_locale, detect_system_locale, set_locale,
+    Locale, LocaleContext, LocaleOverride, Weekday, current_locale, detect_system_locale,
+    set_locale,
 };
 pub use log_sink::LogSink;
 #[cfg(feature = "crossterm-compat")]
@@ -97,6 +100,7 @@ pub use simulator::ProgramSimulator;
 pub use string_model::{StringModel, StringModelAdapter};
 pub use subscription::{Every, StopSignal, SubId, Subscription};
 pub use terminal_writer::{ScreenMode, TerminalWriter, UiAnchor};
+pub use theme_provider::{ThemeEvent, ThemeProvider, ThemeWatch};
 pub use voi_telemetry::{
     clear_inline_auto_voi_snapshot, inline_auto_voi_snapshot, set_inline_auto_voi_snapshot,
 };
@@ -7,6 +7,7 @@
 //! trigger re-renders when the active locale changes.
 
 use crate::reactive::{Observable, Subscription};
+pub use ftui_i18n::Weekday;
 pub use ftui_i18n::catalog::Locale;
 use std::cell::RefCell;
 use std::env;
@@ -84,6 +85,12 @@ impl LocaleContext {
         }
     }
 
+    /// First day of the week for the active locale (CLDR data).
+    #[must_use]
+    pub fn first_weekday(&self) -> Weekday {
+        Weekday::first_for_locale(&self.current_locale())
+    }
+
     /// Current version counter for the base locale.
     #[must_use]
     pub fn version(&self) -> u64 {
@@ -255,6 +262,14 @@ mod tests {
         assert_eq!(ctx.version(), v0);
     }
 
+    #[test]
+    fn first_weekday_follows_active_locale() {
+        let ctx = LocaleContext::new("en_US.UTF-8");
+        assert_eq!(ctx.first_weekday(), Weekday::Sunday);
+        let _guard = ctx.push_override("de-DE");
+        assert_eq!(ctx.first_weekday(), Weekday::Monday);
+    }
+
     #[test]
     fn normalize_empty_falls_back_to_en() {
         let locale = normalize_locale("".to_string());
@@ -69,7 +69,9 @@ use crate::terminal_writer::{RuntimeDiffConfig, ScreenMode, TerminalWriter, UiAn
 use crate::voi_sampling::{VoiConfig, VoiSampler};
 use crate::{BucketKey, ConformalConfig, ConformalPrediction, ConformalPredictor};
 use ftui_backend::{BackendEventSource, BackendFeatures};
-use ftui_core::event::Event;
+#[cfg(feature = "caps-probe")]
+use ftui_core::caps_probe::{ProbeConfig, ProbeResult, probe_capabilities};
+use ftui_core::event::{BackgroundColor, Event};
 #[cfg(feature = "crossterm-compat")]
 use ftui_core::terminal_capabilities::TerminalCapabilities;
 #[cfg(feature = "crossterm-compat")]
@@ -261,6 +263,11 @@ pub enum Cmd<M> {
     /// Instructs the terminal session to enable or disable mouse event capture.
     /// No-op in test simulators.
     SetMouseCapture(bool),
+    /// Ask the terminal for its background color (OSC 11).
+    ///
+    /// The reply arrives later as [`Event::BackgroundColor`]; terminals that
+    /// do not support the query never answer. No-op in test simulators.
+    QueryBackground,
 }
 
 impl<M: std::fmt::Debug> std::fmt::Debug for Cmd<M> {
@@ -277,6 +284,7 @@ impl<M: std::fmt::Debug> std::fmt::Debug for Cmd<M> {
             Self::SaveState => write!(f, "SaveState"),
             Self::RestoreState => write!(f, "RestoreState"),
             Self::SetMouseCapture(b) => write!(f, "SetMouseCapture({b})"),
+            Self::QueryBackground => write!(f, "QueryBackground"),
         }
     }
 }
@@ -346,6 +354,7 @@ impl<M> Cmd<M> {
             Self::SaveState => "SaveState",
             Self::RestoreState => "RestoreState",
             Self::SetMouseCapture(_) => "SetMouseCapture",
+            Self::QueryBackground => "QueryBackground",
         }
     }
 
@@ -418,6 +427,14 @@ impl<M> Cmd<M> {
         Self::SetMouseCapture(enabled)
     }
 
+    /// Create a background color query command.
+    ///
+    /// The terminal's reply is delivered as [`Event::BackgroundColor`].
+    #[inline]
+    pub fn query_background() -> Self {
+        Self::QueryBackground
+    }
+
     /// Count the number of atomic commands in this command.
     ///
     /// Returns 0 for None, 1 for atomic commands, and recursively counts for Batch/Sequence.
@@ -1770,6 +1787,8 @@ impl<M: Model> Program<M, CrosstermEventSource, Stdout> {
             focus_events: initial_features.focus_events,
             kitty_keyboard: initial_features.kitty_keyboard,
         })?;
+        #[cfg(feature = "caps-probe")]
+        probe_terminal_palette();
         let events = CrosstermEventSource::new(session, initial_features);
 
         let mut writer = TerminalWriter::with_diff_config(
@@ -1994,6 +2013,32 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
     }
 }
 
+// =============================================================================
+// Terminal palette probe (feature-gated)
+// =============================================================================
+
+/// Query the terminal's ANSI palette (OSC 4) and install it for
+/// palette-aware color downgrades.
+///
+/// Runs once the session is in raw mode and before any event source reads
+/// input, as the `caps_probe` one-writer rule requires.
+#[cfg(feature = "caps-probe")]
+fn probe_terminal_palette() {
+    let config = ProbeConfig::default()
+        .with_da1(false)
+        .with_da2(false)
+        .with_palette(true);
+    install_probed_palette(&probe_capabilities(&config));
+}
+
+/// Install the palette from a probe result, if the terminal reported one.
+#[cfg(feature = "caps-probe")]
+fn install_probed_palette(result: &ProbeResult) {
+    if let Some(ansi) = result.palette {
+        ftui_style::set_terminal_palette(Some(ftui_style::Palette::from_probe(ansi)));
+    }
+}
+
 // =============================================================================
 // Native TTY backend constructor (feature-gated)
 // =============================================================================
@@ -2020,6 +2065,8 @@ impl<M: Model> Program<M, ftui_tty::TtyBackend, Stdout> {
             features,
         };
         let backend = ftui_tty::TtyBackend::open(0, 0, options)?;
+        #[cfg(feature = "caps-probe")]
+        probe_terminal_palette();
 
         let capabilities = ftui_core::terminal_capabilities::TerminalCapabilities::detect();
         let writer = TerminalWriter::with_diff_config(
@@ -2319,7 +2366,8 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
             | Event::Mouse(_)
             | Event::Paste(_)
             | Event::Focus(_)
-            | Event::Clipboard(_) => FairnessEventType::Input,
+            | Event::Clipboard(_)
+            | Event::BackgroundColor(_) => FairnessEventType::Input,
             Event::Resize { .. } => FairnessEventType::Resize,
             Event::Tick => FairnessEventType::Tick,
         }
@@ -2487,6 +2535,9 @@ impl<M: Model, E: BackendEventSource<Error = io::Error>, W: Write + Send> Progra
                 self.backend_features.mouse_capture = enabled;
                 self.events.set_features(self.backend_features)?;
             }
+            Cmd::QueryBackground => {
+                self.writer.write_query(BackgroundColor::QUERY)?;
+            }
         }
         Ok(())
     }
@@ -7278,4 +7329,20 @@ mod tests {
         assert_eq!(program.width, 1);
         assert_eq!(program.height, 1);
     }
+
+    #[cfg(feature = "caps-probe")]
+    #[test]
+    fn probed_palette_is_installed() {
+        let mut result = ProbeResult::default();
+        install_probed_palette(&result);
+        assert!(ftui_style::terminal_palette().is_none());
+
+        let mut ansi = [[0u8; 3]; 16];
+        ansi[4] = [38, 139, 210];
+        result.palette = Some(ansi);
+        install_probed_palette(&result);
+        let installed = ftui_style::terminal_palette().expect("palette installed");
+        assert_eq!(installed.get(4), ftui_style::Rgb::new(38, 139, 210));
+        ftui_style::set_terminal_palette(None);
+    }
 }
@@ -49,6 +49,8 @@ pub enum CmdRecord {
     Task,
     /// Mouse capture toggle (no-op in simulator).
     MouseCapture(bool),
+    /// Background color query (no-op in simulator).
+    QueryBackground,
 }
 
 /// Deterministic simulator for [`Model`] testing.
@@ -268,6 +270,9 @@ impl<M: Model> ProgramSimulator<M> {
             Cmd::SetMouseCapture(enabled) => {
                 self.command_log.push(CmdRecord::MouseCapture(enabled));
             }
+            Cmd::QueryBackground => {
+                self.command_log.push(CmdRecord::QueryBackground);
+            }
             Cmd::Task(_, f) => {
                 self.command_log.push(CmdRecord::Task);
                 let msg = f();
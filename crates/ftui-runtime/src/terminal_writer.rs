@@ -2212,6 +2212,14 @@ impl<W: Write> TerminalWriter<W> {
         self.writer().flush()
     }
 
+    /// Write a terminal query (e.g. OSC 11) and flush it.
+    ///
+    /// The reply comes back through the input stream, not this writer.
+    pub fn write_query(&mut self, query: &[u8]) -> io::Result<()> {
+        self.writer().write_all(query)?;
+        self.writer().flush()
+    }
+
     /// Flush any buffered output.
     pub fn flush(&mut self) -> io::Result<()> {
         self.writer().flush()
@@ -0,0 +1,628 @@
+#![forbid(unsafe_code)]
+
+//! Runtime theme provider with hot-reload and animated cross-fades.
+//!
+//! [`ThemeProvider`] owns the active [`Theme`] and light/dark mode and exposes
+//! the [`ResolvedTheme`] to render with. Swapping the theme or mode optionally
+//! cross-fades from the old colors to the new ones over a few frames; drive
+//! the fade from your tick handler with [`ThemeProvider::tick`].
+//!
+//! Every change to the displayed colors advances the theme epoch
+//! ([`ftui_style::theme_epoch`]), which invalidates widget caches that baked
+//! colors (`CachedWidget`, `MeasureCache`).
+//!
+//! [`ThemeWatch`] is a [`Subscription`] that reports theme file edits and
+//! light/dark switches as [`ThemeEvent`]s for [`ThemeProvider::apply`].
+//!
+//! To follow the terminal's own background, return [`Cmd::query_background`]
+//! (e.g. on a tick) and pass the [`Event::BackgroundColor`] reply through
+//! [`ThemeEvent::from_event`].
+//!
+//! # Example
+//!
+//! ```ignore
+//! fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+//!     match msg {
+//!         Msg::Theme(event) => {
+//!             self.theme.apply(event);
+//!         }
+//!         Msg::Tick(dt) => {
+//!             self.theme.tick(dt);
+//!         }
+//!         Msg::Event(event) => {
+//!             if let Some(theme_event) = ThemeEvent::from_event(&event) {
+//!                 self.theme.apply(theme_event);
+//!             }
+//!         }
+//!     }
+//!     Cmd::none()
+//! }
+//!
+//! fn subscriptions(&self) -> Vec<Box<dyn Subscription<Msg>>> {
+//!     vec![Box::new(
+//!         ThemeWatch::new(Msg::Theme).file("theme.toml").env_dark_mode(),
+//!     )]
+//! }
+//! ```
+//!
+//! [`Cmd::query_background`]: crate::Cmd::query_background
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+#[cfg(feature = "theme-files")]
+use std::path::PathBuf;
+use std::sync::mpsc;
+
+use ftui_core::animation::{Animation, EasingFn, Fade, ease_in_out};
+use ftui_core::event::Event;
+use ftui_render::cell::PackedRgba;
+use ftui_style::{Color, ResolvedTheme, Theme};
+use web_time::Duration;
+
+use crate::reactive::{Observable, Subscription as CallbackSubscription};
+use crate::subscription::{StopSignal, SubId, Subscription};
+
+/// Default cross-fade duration.
+pub const DEFAULT_THEME_FADE: Duration = Duration::from_millis(200);
+
+/// A theme change reported by [`ThemeWatch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ThemeEvent {
+    /// The watched theme file changed and parsed successfully.
+    Reloaded {
+        /// Theme name from the file (or its file stem).
+        name: String,
+        /// The loaded theme, ready for [`ThemeProvider::set_theme`].
+        theme: Theme,
+    },
+    /// The watched theme file changed but could not be loaded.
+    ReloadFailed(String),
+    /// The terminal or OS switched between light (`false`) and dark (`true`).
+    DarkMode(bool),
+}
+
+impl ThemeEvent {
+    /// Map a terminal event to a theme event.
+    ///
+    /// An [`Event::BackgroundColor`] reply (see [`Cmd::query_background`])
+    /// becomes [`DarkMode`](Self::DarkMode); other events give `None`.
+    ///
+    /// [`Cmd::query_background`]: crate::Cmd::query_background
+    #[must_use]
+    pub fn from_event(event: &Event) -> Option<Self> {
+        match event {
+            Event::BackgroundColor(color) => Some(Self::DarkMode(color.is_dark())),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CrossFade {
+    from: ResolvedTheme,
+    fade: Fade,
+}
+
+/// Owner of the active theme, with cross-faded swaps.
+#[derive(Debug)]
+pub struct ThemeProvider {
+    theme: Theme,
+    is_dark: bool,
+    target: ResolvedTheme,
+    current: Observable<ResolvedTheme>,
+    transition: Option<CrossFade>,
+    fade_duration: Duration,
+    easing: EasingFn,
+}
+
+impl ThemeProvider {
+    /// Create a provider for `theme` in the given mode.
+    #[must_use]
+    pub fn new(theme: Theme, is_dark: bool) -> Self {
+        let target = theme.resolve(is_dark);
+        Self {
+            theme,
+            is_dark,
+            target,
+            current: Observable::new(target),
+            transition: None,
+            fade_duration: DEFAULT_THEME_FADE,
+            easing: ease_in_out,
+        }
+    }
+
+    /// Create a provider using [`Theme::detect_dark_mode`].
+    #[must_use]
+    pub fn detect(theme: Theme) -> Self {
+        Self::new(theme, Theme::detect_dark_mode())
+    }
+
+    /// Set the cross-fade duration; `Duration::ZERO` swaps instantly.
+    #[must_use]
+    pub fn with_fade(mut self, duration: Duration) -> Self {
+        self.fade_duration = duration;
+        self
+    }
+
+    /// Set the cross-fade easing curve.
+    #[must_use]
+    pub fn with_easing(mut self, easing: EasingFn) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// The active (target) theme.
+    #[must_use]
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Whether dark mode is active.
+    #[must_use]
+    pub fn is_dark(&self) -> bool {
+        self.is_dark
+    }
+
+    /// Colors to render with this frame (mid-fade while transitioning).
+    #[must_use]
+    pub fn resolved(&self) -> ResolvedTheme {
+        self.current.get()
+    }
+
+    /// Colors the provider is fading toward.
+    #[must_use]
+    pub fn target(&self) -> ResolvedTheme {
+        self.target
+    }
+
+    /// Whether a cross-fade is in progress.
+    #[must_use]
+    pub fn is_transitioning(&self) -> bool {
+        self.transition.is_some()
+    }
+
+    /// Version counter, bumped whenever the displayed colors change.
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.current.version()
+    }
+
+    /// Subscribe to displayed color changes (including fade frames).
+    pub fn subscribe(&self, callback: impl Fn(&ResolvedTheme) + 'static) -> CallbackSubscription {
+        self.current.subscribe(callback)
+    }
+
+    /// Swap to a new theme. Returns `true` if the colors change.
+    pub fn set_theme(&mut self, theme: Theme) -> bool {
+        self.theme = theme;
+        self.retarget()
+    }
+
+    /// Switch between light and dark mode. Returns `true` if the colors change.
+    pub fn set_dark(&mut self, is_dark: bool) -> bool {
+        self.is_dark = is_dark;
+        self.retarget()
+    }
+
+    /// Apply a [`ThemeEvent`]. Returns `true` if the colors change.
+    ///
+    /// [`ThemeEvent::ReloadFailed`] keeps the current theme.
+    pub fn apply(&mut self, event: ThemeEvent) -> bool {
+        match event {
+            ThemeEvent::Reloaded { theme, .. } => self.set_theme(theme),
+            ThemeEvent::DarkMode(is_dark) => self.set_dark(is_dark),
+            ThemeEvent::ReloadFailed(_) => false,
+        }
+    }
+
+    /// Advance the cross-fade by `dt`. Returns `true` if a redraw is needed.
+    pub fn tick(&mut self, dt: Duration) -> bool {
+        let Some(transition) = &mut self.transition else {
+            return false;
+        };
+        transition.fade.tick(dt);
+        if transition.fade.is_complete() {
+            self.transition = None;
+            self.show(self.target);
+        } else {
+            let blended = blend_themes(&transition.from, &self.target, transition.fade.value());
+            self.show(blended);
+        }
+        true
+    }
+
+    /// Jump to the end of any running cross-fade.
+    pub fn finish(&mut self) {
+        if self.transition.take().is_some() {
+            self.show(self.target);
+        }
+    }
+
+    fn retarget(&mut self) -> bool {
+        let target = self.theme.resolve(self.is_dark);
+        if target == self.target {
+            return false;
+        }
+        self.target = target;
+        let from = self.resolved();
+        if self.fade_duration.is_zero() {
+            self.transition = None;
+            self.show(target);
+        } else {
+            // Retargeting mid-fade starts from the colors currently on screen.
+            self.transition = Some(CrossFade {
+                from,
+                fade: Fade::new(self.fade_duration).easing(self.easing),
+            });
+        }
+        true
+    }
+
+    fn show(&self, resolved: ResolvedTheme) {
+        if self.current.with(|current| *current != resolved) {
+            self.current.set(resolved);
+            ftui_style::bump_theme_epoch();
+        }
+    }
+}
+
+/// Blend two resolved themes slot by slot (`t = 0` is `from`, `t = 1` is `to`).
+#[must_use]
+pub fn blend_themes(from: &ResolvedTheme, to: &ResolvedTheme, t: f32) -> ResolvedTheme {
+    macro_rules! blend {
+        ($($slot:ident),*) => {
+            ResolvedTheme {
+                $($slot: blend_color(from.$slot, to.$slot, t),)*
+            }
+        };
+    }
+    blend!(
+        primary,
+        secondary,
+        accent,
+        background,
+        surface,
+        overlay,
+        text,
+        text_muted,
+        text_subtle,
+        success,
+        warning,
+        error,
+        info,
+        border,
+        border_focused,
+        selection_bg,
+        selection_fg,
+        scrollbar_track,
+        scrollbar_thumb
+    )
+}
+
+fn blend_color(from: Color, to: Color, t: f32) -> Color {
+    if from == to || t >= 1.0 {
+        return to;
+    }
+    if t <= 0.0 {
+        return from;
+    }
+    let pack = |color: Color| {
+        let rgb = color.to_rgb();
+        PackedRgba::rgb(rgb.r, rgb.g, rgb.b)
+    };
+    Color::from(pack(to).with_opacity(t).over(pack(from)))
+}
+
+type DarkModeSource = Box<dyn Fn() -> Option<bool> + Send + Sync>;
+
+/// Subscription reporting theme file edits and light/dark switches.
+///
+/// Polls at a fixed interval (default 500ms). Only changes are reported;
+/// the state at subscription start is taken as the baseline.
+pub struct ThemeWatch<M: Send + 'static> {
+    interval: Duration,
+    #[cfg(feature = "theme-files")]
+    path: Option<PathBuf>,
+    dark_mode: Option<(&'static str, DarkModeSource)>,
+    make_msg: Box<dyn Fn(ThemeEvent) -> M + Send + Sync>,
+}
+
+impl<M: Send + 'static> ThemeWatch<M> {
+    /// Create a watcher that wraps events with `make_msg`.
+    pub fn new(make_msg: impl Fn(ThemeEvent) -> M + Send + Sync + 'static) -> Self {
+        Self {
+            interval: Duration::from_millis(500),
+            #[cfg(feature = "theme-files")]
+            path: None,
+            dark_mode: None,
+            make_msg: Box::new(make_msg),
+        }
+    }
+
+    /// Set the polling interval.
+    #[must_use]
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Watch a theme file or terminal color scheme for changes.
+    ///
+    /// See [`ftui_style::load_theme_file`] for the accepted formats.
+    #[cfg(feature = "theme-files")]
+    #[must_use]
+    pub fn file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Poll a custom light/dark source (`None` means "unknown, keep current").
+    #[must_use]
+    pub fn dark_mode_source(
+        mut self,
+        source: impl Fn() -> Option<bool> + Send + Sync + 'static,
+    ) -> Self {
+        self.dark_mode = Some(("custom", Box::new(source)));
+        self
+    }
+
+    /// Poll [`Theme::detect_dark_mode`] (the `COLORFGBG` convention).
+    #[must_use]
+    pub fn env_dark_mode(mut self) -> Self {
+        self.dark_mode = Some(("env", Box::new(|| Some(Theme::detect_dark_mode()))));
+        self
+    }
+
+    #[cfg(feature = "theme-files")]
+    fn modified(&self) -> Option<std::time::SystemTime> {
+        let path = self.path.as_ref()?;
+        std::fs::metadata(path).and_then(|m| m.modified()).ok()
+    }
+
+    #[cfg(feature = "theme-files")]
+    fn reload(&self) -> Option<ThemeEvent> {
+        let path = self.path.as_ref()?;
+        Some(match ftui_style::load_theme_file(path) {
+            Ok(loaded) => ThemeEvent::Reloaded {
+                name: loaded.name,
+                theme: loaded.theme,
+            },
+            Err(err) => ThemeEvent::ReloadFailed(format!("{}: {err}", path.display())),
+        })
+    }
+}
+
+impl<M: Send + 'static> Subscription<M> for ThemeWatch<M> {
+    fn id(&self) -> SubId {
+        let mut hasher = DefaultHasher::new();
+        "ThemeWatch".hash(&mut hasher);
+        self.interval.hash(&mut hasher);
+        #[cfg(feature = "theme-files")]
+        self.path.hash(&mut hasher);
+        self.dark_mode
+            .as_ref()
+            .map(|(kind, _)| *kind)
+            .hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn run(&self, sender: mpsc::Sender<M>, stop: StopSignal) {
+        #[cfg(feature = "theme-files")]
+        let mut last_modified = self.modified();
+        let mut last_dark = self.dark_mode.as_ref().and_then(|(_, source)| source());
+
+        while !stop.wait_timeout(self.interval) {
+            let mut events = Vec::new();
+
+            #[cfg(feature = "theme-files")]
+            {
+                let modified = self.modified();
+                if modified.is_some() && modified != last_modified {
+                    last_modified = modified;
+                    events.extend(self.reload());
+                }
+            }
+
+            if let Some((_, source)) = &self.dark_mode
+                && let Some(dark) = source()
+                && Some(dark) != last_dark
+            {
+                last_dark = Some(dark);
+                events.push(ThemeEvent::DarkMode(dark));
+            }
+
+            for event in events {
+                crate::debug_trace!("ThemeWatch event: {:?}", event);
+                if sender.send((self.make_msg)(event)).is_err() {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_style::theme::themes;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU8, Ordering};
+
+    #[test]
+    fn zero_duration_swaps_instantly() {
+        let mut provider = ThemeProvider::new(themes::dark(), true).with_fade(Duration::ZERO);
+        assert!(provider.set_theme(themes::nord()));
+        assert!(!provider.is_transitioning());
+        assert_eq!(provider.resolved(), themes::nord().resolve(true));
+        assert!(!provider.tick(Duration::from_millis(16)));
+    }
+
+    #[test]
+    fn cross_fade_blends_then_settles() {
+        let from = themes::dark().resolve(true);
+        let to = themes::light().resolve(true);
+        let mut provider = ThemeProvider::new(themes::dark(), true)
+            .with_fade(Duration::from_millis(100))
+            .with_easing(ftui_core::animation::linear);
+        let epoch = ftui_style::theme_epoch();
+
+        assert!(provider.set_theme(themes::light()));
+        assert!(provider.is_transitioning());
+        assert_eq!(provider.resolved(), from);
+        assert_eq!(provider.target(), to);
+
+        assert!(provider.tick(Duration::from_millis(50)));
+        let mid = provider.resolved();
+        assert_ne!(mid, from);
+        assert_ne!(mid, to);
+        assert_eq!(mid, blend_themes(&from, &to, 0.5));
+        assert!(ftui_style::theme_epoch() > epoch);
+
+        assert!(provider.tick(Duration::from_millis(60)));
+        assert!(!provider.is_transitioning());
+        assert_eq!(provider.resolved(), to);
+    }
+
+    #[test]
+    fn unchanged_theme_is_a_no_op() {
+        let mut provider = ThemeProvider::new(themes::dark(), true);
+        let version = provider.version();
+        assert!(!provider.set_theme(themes::dark()));
+        assert!(!provider.is_transitioning());
+        assert_eq!(provider.version(), version);
+    }
+
+    #[test]
+    fn dark_mode_switch_resolves_adaptive_slots() {
+        let adaptive = Theme::builder()
+            .background(ftui_style::AdaptiveColor::adaptive(
+                Color::rgb(250, 250, 250),
+                Color::rgb(10, 10, 10),
+            ))
+            .build();
+        let mut provider = ThemeProvider::new(adaptive, true).with_fade(Duration::ZERO);
+        assert!(provider.apply(ThemeEvent::DarkMode(false)));
+        assert!(!provider.is_dark());
+        assert_eq!(provider.resolved().background, Color::rgb(250, 250, 250));
+        assert!(!provider.apply(ThemeEvent::ReloadFailed("bad".into())));
+    }
+
+    #[test]
+    fn retarget_mid_fade_starts_from_screen_colors() {
+        let mut provider = ThemeProvider::new(themes::dark(), true)
+            .with_fade(Duration::from_millis(100))
+            .with_easing(ftui_core::animation::linear);
+        provider.set_theme(themes::light());
+        provider.tick(Duration::from_millis(50));
+        let on_screen = provider.resolved();
+
+        provider.set_theme(themes::nord());
+        assert_eq!(provider.resolved(), on_screen);
+        provider.finish();
+        assert_eq!(provider.resolved(), themes::nord().resolve(true));
+    }
+
+    #[test]
+    fn subscribers_see_fade_frames() {
+        let mut provider = ThemeProvider::new(themes::dark(), true)
+            .with_fade(Duration::from_millis(30))
+            .with_easing(ftui_core::animation::linear);
+        let frames = std::rc::Rc::new(std::cell::Cell::new(0));
+        let seen = frames.clone();
+        let _sub = provider.subscribe(move |_| seen.set(seen.get() + 1));
+        provider.set_theme(themes::monokai());
+        for _ in 0..3 {
+            provider.tick(Duration::from_millis(10));
+        }
+        assert_eq!(frames.get(), 3);
+    }
+
+    #[test]
+    fn background_reply_maps_to_dark_mode() {
+        use ftui_core::event::BackgroundColor;
+
+        let dark = Event::BackgroundColor(BackgroundColor::new(0x1e, 0x1e, 0x2e));
+        let light = Event::BackgroundColor(BackgroundColor::new(0xfa, 0xfa, 0xf0));
+        assert_eq!(
+            ThemeEvent::from_event(&dark),
+            Some(ThemeEvent::DarkMode(true))
+        );
+        assert_eq!(
+            ThemeEvent::from_event(&light),
+            Some(ThemeEvent::DarkMode(false))
+        );
+        assert_eq!(ThemeEvent::from_event(&Event::Tick), None);
+
+        let mut provider = ThemeProvider::new(themes::dark(), true).with_fade(Duration::ZERO);
+        provider.apply(ThemeEvent::from_event(&light).unwrap());
+        assert!(!provider.is_dark());
+    }
+
+    #[test]
+    fn watch_reports_dark_mode_changes_only() {
+        // 0 = light, 1 = dark, 2 = unknown
+        let state = Arc::new(AtomicU8::new(1));
+        let source = state.clone();
+        let watch = ThemeWatch::new(|event| event)
+            .interval(Duration::from_millis(5))
+            .dark_mode_source(move || match source.load(Ordering::SeqCst) {
+                0 => Some(false),
+                1 => Some(true),
+                _ => None,
+            });
+
+        let (tx, rx) = mpsc::channel();
+        let (signal, trigger) = StopSignal::new();
+        let handle = std::thread::spawn(move || watch.run(tx, signal));
+
+        std::thread::sleep(Duration::from_millis(20));
+        state.store(2, Ordering::SeqCst);
+        std::thread::sleep(Duration::from_millis(20));
+        state.store(0, Ordering::SeqCst);
+        let event = rx.recv_timeout(Duration::from_secs(2)).unwrap();
+        assert_eq!(event, ThemeEvent::DarkMode(false));
+
+        trigger.stop();
+        handle.join().unwrap();
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[cfg(feature = "theme-files")]
+    #[test]
+    fn watch_reloads_edited_theme_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("live.toml");
+        std::fs::write(&path, "[colors]\nprimary = \"#102030\"\n").unwrap();
+        let watch = ThemeWatch::new(|event| event)
+            .interval(Duration::from_millis(5))
+            .file(&path);
+
+        let (tx, rx) = mpsc::channel();
+        let (signal, trigger) = StopSignal::new();
+        let handle = std::thread::spawn(move || watch.run(tx, signal));
+
+        // Let the watcher record its baseline, then edit with a distinct mtime.
+        std::thread::sleep(Duration::from_millis(30));
+        std::fs::write(&path, "[colors]\nprimary = \"#405060\"\n").unwrap();
+        let later = std::time::SystemTime::now() + Duration::from_secs(2);
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+
+        match rx.recv_timeout(Duration::from_secs(2)).unwrap() {
+            ThemeEvent::Reloaded { name, theme } => {
+                assert_eq!(name, "live");
+                assert_eq!(
+                    theme.primary,
+                    ftui_style::AdaptiveColor::fixed(Color::rgb(0x40, 0x50, 0x60))
+                );
+            }
+            other => panic!("unexpected event {other:?}"),
+        }
+        trigger.stop();
+        handle.join().unwrap();
+    }
+}
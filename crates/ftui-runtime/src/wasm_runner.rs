@@ -403,6 +403,9 @@ impl<M: Model> WasmRunner<M> {
             Cmd::SetMouseCapture(_) => {
                 // No-op: mouse capture is managed by the JS host.
             }
+            Cmd::QueryBackground => {
+                // No-op: the JS host knows its own background.
+            }
             Cmd::SaveState | Cmd::RestoreState => {
                 // No-op: state persistence is managed by the JS host
                 // (localStorage / IndexedDB).
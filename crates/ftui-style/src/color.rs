@@ -1,6 +1,7 @@
 //! Color types, profiles, and downgrade utilities.
 
 use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
 
 use ftui_render::cell::PackedRgba;
 
@@ -130,7 +131,7 @@ pub const WCAG_AAA_LARGE_TEXT: f64 = 4.5;
 ///
 /// Per WCAG 2.0 specification for relative luminance calculation.
 #[inline]
-fn srgb_to_linear(c: f64) -> f64 {
+pub(crate) fn srgb_to_linear(c: f64) -> f64 {
     if c <= 0.04045 {
         c / 12.92
     } else {
@@ -138,6 +139,17 @@ fn srgb_to_linear(c: f64) -> f64 {
     }
 }
 
+/// Convert a linear RGB channel value back to sRGB, clamped to `[0, 1]`.
+#[inline]
+pub(crate) fn linear_to_srgb(c: f64) -> f64 {
+    let c = c.clamp(0.0, 1.0);
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
 /// Compute relative luminance of a color per WCAG 2.0.
 ///
 /// Returns a value in the range [0, 1] where:
@@ -403,32 +415,17 @@ impl Color {
     }
 
     /// Downgrade this color to fit the given color profile.
+    ///
+    /// Colors are matched perceptually (OKLab) against the xterm default
+    /// palette; see [`Color::downgrade_with`] to target another palette.
+    /// For per-cell use, go through a [`ColorCache`], which memoizes results
+    /// and picks up the palette installed with [`set_terminal_palette`].
     #[must_use]
     pub fn downgrade(self, profile: ColorProfile) -> Self {
-        match profile {
-            ColorProfile::TrueColor => self,
-            ColorProfile::Ansi256 => match self {
-                Self::Rgb(rgb) => Self::Ansi256(rgb_to_256(rgb.r, rgb.g, rgb.b)),
-                _ => self,
-            },
-            ColorProfile::Ansi16 => match self {
-                Self::Rgb(rgb) => Self::Ansi16(rgb_to_ansi16(rgb.r, rgb.g, rgb.b)),
-                Self::Ansi256(idx) => Self::Ansi16(rgb_to_ansi16_from_ansi256(idx)),
-                _ => self,
-            },
-            ColorProfile::Mono => match self {
-                Self::Rgb(rgb) => Self::Mono(rgb_to_mono(rgb.r, rgb.g, rgb.b)),
-                Self::Ansi256(idx) => {
-                    let rgb = ansi256_to_rgb(idx);
-                    Self::Mono(rgb_to_mono(rgb.r, rgb.g, rgb.b))
-                }
-                Self::Ansi16(color) => {
-                    let rgb = ansi16_to_rgb(color);
-                    Self::Mono(rgb_to_mono(rgb.r, rgb.g, rgb.b))
-                }
-                Self::Mono(_) => self,
-            },
+        if profile == ColorProfile::TrueColor {
+            return self;
         }
+        self.downgrade_with(profile, xterm_palette())
     }
 }
 
@@ -452,9 +449,16 @@ pub struct CacheStats {
 }
 
 /// Simple hash cache for downgrade results (bounded; clears on overflow).
+///
+/// This is the fast path for downgrading many colors, e.g. per cell. The
+/// palette it matches against is fixed when the cache is created: the one
+/// given to [`ColorCache::with_palette`], else the one installed with
+/// [`set_terminal_palette`] at that moment, else xterm defaults. Lookups
+/// never touch global state.
 #[derive(Debug)]
 pub struct ColorCache {
     profile: ColorProfile,
+    palette: Option<Box<Palette>>,
     max_entries: usize,
     map: HashMap<u32, Color>,
     hits: u64,
@@ -474,6 +478,7 @@ impl ColorCache {
         let max_entries = max_entries.max(1);
         Self {
             profile,
+            palette: terminal_palette().map(Box::new),
             max_entries,
             map: HashMap::with_capacity(max_entries.min(2048)),
             hits: 0,
@@ -481,6 +486,22 @@ impl ColorCache {
         }
     }
 
+    /// Create a cache that matches perceptually against `palette`.
+    ///
+    /// See [`Color::downgrade_with`].
+    #[must_use]
+    pub fn with_palette(profile: ColorProfile, palette: Palette) -> Self {
+        let mut cache = Self::new(profile);
+        cache.palette = Some(Box::new(palette));
+        cache
+    }
+
+    /// Palette used for perceptual matching, if any.
+    #[must_use]
+    pub fn palette(&self) -> Option<&Palette> {
+        self.palette.as_deref()
+    }
+
     /// Downgrade an RGB color through the cache, returning the cached result.
     #[must_use]
     pub fn downgrade_rgb(&mut self, rgb: Rgb) -> Color {
@@ -490,7 +511,10 @@ impl ColorCache {
             return *cached;
         }
         self.misses += 1;
-        let downgraded = Color::Rgb(rgb).downgrade(self.profile);
+        let downgraded = match &self.palette {
+            Some(palette) => Color::Rgb(rgb).downgrade_with(self.profile, palette),
+            None => Color::Rgb(rgb).downgrade(self.profile),
+        };
         if self.map.len() >= self.max_entries {
             self.map.clear();
         }
@@ -638,6 +662,363 @@ fn weighted_distance(a: Rgb, b: Rgb) -> u64 {
     2126 * dr2 + 7152 * dg2 + 722 * db2
 }
 
+// =============================================================================
+// Perceptual matching
+// =============================================================================
+
+/// A color in the OKLab perceptual space.
+///
+/// Euclidean distance in OKLab tracks perceived difference far better than
+/// RGB distance, which keeps grays gray and gradients monotonic when
+/// quantizing to a small palette.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Oklab {
+    /// Perceived lightness (0 = black, 1 = white).
+    pub l: f32,
+    /// Green (negative) to red (positive) axis.
+    pub a: f32,
+    /// Blue (negative) to yellow (positive) axis.
+    pub b: f32,
+}
+
+impl Oklab {
+    /// Convert an sRGB color to OKLab.
+    #[must_use]
+    pub fn from_rgb(rgb: Rgb) -> Self {
+        let r = srgb_to_linear(f64::from(rgb.r) / 255.0);
+        let g = srgb_to_linear(f64::from(rgb.g) / 255.0);
+        let b = srgb_to_linear(f64::from(rgb.b) / 255.0);
+
+        let l = (0.412_221_470_8 * r + 0.536_332_536_3 * g + 0.051_445_992_9 * b).cbrt();
+        let m = (0.211_903_498_2 * r + 0.680_699_545_1 * g + 0.107_396_956_6 * b).cbrt();
+        let s = (0.088_302_461_9 * r + 0.281_718_837_6 * g + 0.629_978_700_5 * b).cbrt();
+
+        Self {
+            l: (0.210_454_255_3 * l + 0.793_617_785 * m - 0.004_072_046_8 * s) as f32,
+            a: (1.977_998_495_1 * l - 2.428_592_205 * m + 0.450_593_709_9 * s) as f32,
+            b: (0.025_904_037_1 * l + 0.782_771_766_2 * m - 0.808_675_766 * s) as f32,
+        }
+    }
+
+    /// Convert back to sRGB, clipping out-of-gamut channels.
+    #[must_use]
+    pub fn to_rgb(self) -> Rgb {
+        let (l, a, b) = (f64::from(self.l), f64::from(self.a), f64::from(self.b));
+        let l_ = (l + 0.396_337_777_4 * a + 0.215_803_757_3 * b).powi(3);
+        let m_ = (l - 0.105_561_345_8 * a - 0.063_854_172_8 * b).powi(3);
+        let s_ = (l - 0.089_484_177_5 * a - 1.291_485_548 * b).powi(3);
+
+        let r = 4.076_741_662_1 * l_ - 3.307_711_591_3 * m_ + 0.230_969_929_2 * s_;
+        let g = -1.268_438_004_6 * l_ + 2.609_757_401_1 * m_ - 0.341_319_396_5 * s_;
+        let b = -0.004_196_086_3 * l_ - 0.703_418_614_7 * m_ + 1.707_614_701 * s_;
+
+        let to_u8 = |c: f64| (linear_to_srgb(c) * 255.0).round() as u8;
+        Rgb::new(to_u8(r), to_u8(g), to_u8(b))
+    }
+
+    /// Squared Euclidean distance (ΔE_OK²) to another color.
+    #[must_use]
+    pub fn distance_sq(self, other: Self) -> f32 {
+        let dl = self.l - other.l;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+        dl * dl + da * da + db * db
+    }
+}
+
+impl From<Rgb> for Oklab {
+    fn from(rgb: Rgb) -> Self {
+        Self::from_rgb(rgb)
+    }
+}
+
+/// Weight of chroma error relative to lightness error when matching against
+/// a palette. On sparse palettes a hue shift (gray turning cyan) is far more
+/// visible than a lightness step, so chroma counts double.
+const PALETTE_CHROMA_WEIGHT: f32 = 2.0;
+
+/// The 256 colors a terminal actually displays for indexed SGR codes.
+///
+/// Defaults to the xterm palette. When the terminal's ANSI colors have been
+/// probed (OSC 4), build it with [`Palette::from_probe`] and install it with
+/// [`set_terminal_palette`] so 16-color downgrades target what the user
+/// really sees.
+///
+/// Matching is perceptual (OKLab, chroma-weighted); entry coordinates are
+/// precomputed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    rgb: [Rgb; 256],
+    lab: [Oklab; 256],
+}
+
+impl Palette {
+    /// The xterm default palette.
+    #[must_use]
+    pub fn xterm() -> Self {
+        let mut rgb = [Rgb::new(0, 0, 0); 256];
+        for (index, slot) in rgb.iter_mut().enumerate() {
+            *slot = ansi256_to_rgb(index as u8);
+        }
+        Self::from_entries(rgb)
+    }
+
+    /// The xterm palette with ANSI colors 0-15 replaced by `ansi`.
+    #[must_use]
+    pub fn from_ansi16(ansi: [Rgb; 16]) -> Self {
+        let mut palette = Self::xterm();
+        for (index, rgb) in ansi.into_iter().enumerate() {
+            palette.set(index as u8, rgb);
+        }
+        palette
+    }
+
+    /// Build from the ANSI colors reported by an OSC 4 probe
+    /// (`ftui_core::caps_probe::ProbeResult::palette`).
+    #[must_use]
+    pub fn from_probe(ansi: [[u8; 3]; 16]) -> Self {
+        Self::from_ansi16(ansi.map(|[r, g, b]| Rgb::new(r, g, b)))
+    }
+
+    fn from_entries(rgb: [Rgb; 256]) -> Self {
+        let mut lab = [Oklab::default(); 256];
+        for (slot, color) in lab.iter_mut().zip(rgb) {
+            *slot = Oklab::from_rgb(color);
+        }
+        Self { rgb, lab }
+    }
+
+    /// RGB value of an indexed color.
+    #[must_use]
+    pub fn get(&self, index: u8) -> Rgb {
+        self.rgb[index as usize]
+    }
+
+    /// Override one indexed color.
+    pub fn set(&mut self, index: u8, rgb: Rgb) {
+        self.rgb[index as usize] = rgb;
+        self.lab[index as usize] = Oklab::from_rgb(rgb);
+    }
+
+    /// RGB value a color will display as on this palette.
+    #[must_use]
+    pub fn resolve(&self, color: Color) -> Rgb {
+        match color {
+            Color::Ansi256(index) => self.get(index),
+            Color::Ansi16(color) => self.get(color.as_u8()),
+            other => other.to_rgb(),
+        }
+    }
+
+    /// Perceptually nearest ANSI 16 color.
+    #[must_use]
+    pub fn nearest_ansi16(&self, rgb: Rgb) -> Ansi16 {
+        let index = self.nearest_in(rgb, 0..16);
+        Ansi16::from_u8(index).unwrap_or(Ansi16::Black)
+    }
+
+    /// Perceptually nearest 256-color index.
+    ///
+    /// Only the fixed cube and gray ramp (16-255) are considered; indices
+    /// 0-15 are commonly remapped by themes and would make output depend on
+    /// terminal configuration.
+    #[must_use]
+    pub fn nearest_256(&self, rgb: Rgb) -> u8 {
+        self.nearest_in(rgb, 16..256)
+    }
+
+    fn nearest_in(&self, rgb: Rgb, range: std::ops::Range<usize>) -> u8 {
+        let target = Oklab::from_rgb(rgb);
+        let mut best = range.start;
+        let mut best_dist = f32::INFINITY;
+        for index in range {
+            let entry = self.lab[index];
+            let dl = target.l - entry.l;
+            let da = target.a - entry.a;
+            let db = target.b - entry.b;
+            let dist = dl * dl + PALETTE_CHROMA_WEIGHT * (da * da + db * db);
+            if dist < best_dist {
+                best = index;
+                best_dist = dist;
+            }
+        }
+        best as u8
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::xterm()
+    }
+}
+
+/// The xterm palette used by [`Color::downgrade`], built once.
+fn xterm_palette() -> &'static Palette {
+    static XTERM: OnceLock<Palette> = OnceLock::new();
+    XTERM.get_or_init(Palette::xterm)
+}
+
+/// Palette picked up by new [`ColorCache`]s; `None` means xterm defaults.
+static TERMINAL_PALETTE: RwLock<Option<Palette>> = RwLock::new(None);
+
+/// Install the palette the terminal actually displays, usually
+/// [`Palette::from_probe`] on a probe result. `None` restores the xterm
+/// defaults.
+///
+/// Read once by each [`ColorCache`] (and palette-aware helpers built on it)
+/// when it is created; existing caches keep their palette. The program
+/// runtime installs the probed palette itself with its `caps-probe`
+/// feature. [`Color::downgrade`] always targets xterm defaults.
+pub fn set_terminal_palette(palette: Option<Palette>) {
+    *TERMINAL_PALETTE.write().unwrap_or_else(|e| e.into_inner()) = palette;
+}
+
+/// The palette installed with [`set_terminal_palette`], if any.
+#[must_use]
+pub fn terminal_palette() -> Option<Palette> {
+    TERMINAL_PALETTE
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+impl Color {
+    /// Downgrade this color for `profile` using perceptual matching against `palette`.
+    ///
+    /// Unlike [`Color::downgrade`], which uses closed-form RGB-space rules
+    /// against the xterm palette, this picks the entry with the smallest
+    /// OKLab distance to the color the source actually displays as.
+    #[must_use]
+    pub fn downgrade_with(self, profile: ColorProfile, palette: &Palette) -> Self {
+        match (profile, self) {
+            (ColorProfile::TrueColor, _) => self,
+            (ColorProfile::Ansi256, Self::Rgb(rgb)) => Self::Ansi256(palette.nearest_256(rgb)),
+            (ColorProfile::Ansi256, _) => self,
+            (ColorProfile::Ansi16, Self::Rgb(_) | Self::Ansi256(_)) => {
+                Self::Ansi16(palette.nearest_ansi16(palette.resolve(self)))
+            }
+            (ColorProfile::Ansi16, _) => self,
+            (ColorProfile::Mono, Self::Mono(_)) => self,
+            (ColorProfile::Mono, _) => {
+                let rgb = palette.resolve(self);
+                Self::Mono(rgb_to_mono(rgb.r, rgb.g, rgb.b))
+            }
+        }
+    }
+}
+
+// =============================================================================
+// Text notation
+// =============================================================================
+
+/// Parse `#rrggbb`, `0xrrggbb`, bare `rrggbb` or `#rgb`.
+pub(crate) fn parse_hex(value: &str) -> Option<Rgb> {
+    let hex = value
+        .trim()
+        .strip_prefix('#')
+        .or_else(|| value.trim().strip_prefix("0x"))
+        .unwrap_or(value.trim());
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    match hex.len() {
+        6 => {
+            let v = u32::from_str_radix(hex, 16).ok()?;
+            Some(Rgb::new((v >> 16) as u8, (v >> 8) as u8, v as u8))
+        }
+        3 => {
+            let v = u16::from_str_radix(hex, 16).ok()?;
+            let expand = |n: u16| (n as u8 & 0xF) * 17;
+            Some(Rgb::new(expand(v >> 8), expand(v >> 4), expand(v)))
+        }
+        _ => None,
+    }
+}
+
+/// Parse a color from text.
+///
+/// Accepts `#rgb`, `#rrggbb`, `rgb(r, g, b)`, ANSI names (`red`,
+/// `bright-blue`), `ansi256(n)` and `mono(black|white)`. Used by theme files
+/// and selector stylesheets.
+pub fn parse_color(value: &str) -> Result<Color, String> {
+    let text = value.trim();
+    let lower = text.to_ascii_lowercase();
+    if lower.starts_with('#') {
+        return parse_hex(&lower)
+            .map(Color::Rgb)
+            .ok_or_else(|| format!("invalid hex color `{text}`"));
+    }
+    if let Some(args) = call_args(&lower, "rgb") {
+        let parts: Vec<_> = args.split(',').map(str::trim).collect();
+        if let [r, g, b] = parts.as_slice()
+            && let (Ok(r), Ok(g), Ok(b)) = (r.parse(), g.parse(), b.parse())
+        {
+            return Ok(Color::rgb(r, g, b));
+        }
+        return Err(format!("invalid rgb color `{text}`"));
+    }
+    if let Some(args) = call_args(&lower, "ansi256") {
+        return args
+            .trim()
+            .parse::<u8>()
+            .map(Color::Ansi256)
+            .map_err(|_| format!("invalid ansi256 index `{text}`"));
+    }
+    if let Some(args) = call_args(&lower, "mono") {
+        return match args.trim() {
+            "black" => Ok(Color::Mono(MonoColor::Black)),
+            "white" => Ok(Color::Mono(MonoColor::White)),
+            _ => Err(format!("invalid mono color `{text}`")),
+        };
+    }
+    let name = lower.replace('_', "-");
+    (0..16u8)
+        .filter_map(Ansi16::from_u8)
+        .find(|ansi| ansi16_name(*ansi) == name)
+        .map(Color::Ansi16)
+        .ok_or_else(|| format!("unrecognized color `{text}`"))
+}
+
+/// Format a color as text (inverse of [`parse_color`]).
+#[must_use]
+pub fn format_color(color: Color) -> String {
+    match color {
+        Color::Rgb(Rgb { r, g, b }) => format!("#{r:02x}{g:02x}{b:02x}"),
+        Color::Ansi256(index) => format!("ansi256({index})"),
+        Color::Ansi16(ansi) => ansi16_name(ansi).to_string(),
+        Color::Mono(MonoColor::Black) => "mono(black)".to_string(),
+        Color::Mono(MonoColor::White) => "mono(white)".to_string(),
+    }
+}
+
+fn call_args<'a>(text: &'a str, func: &str) -> Option<&'a str> {
+    text.strip_prefix(func)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')
+}
+
+const fn ansi16_name(ansi: Ansi16) -> &'static str {
+    match ansi {
+        Ansi16::Black => "black",
+        Ansi16::Red => "red",
+        Ansi16::Green => "green",
+        Ansi16::Yellow => "yellow",
+        Ansi16::Blue => "blue",
+        Ansi16::Magenta => "magenta",
+        Ansi16::Cyan => "cyan",
+        Ansi16::White => "white",
+        Ansi16::BrightBlack => "bright-black",
+        Ansi16::BrightRed => "bright-red",
+        Ansi16::BrightGreen => "bright-green",
+        Ansi16::BrightYellow => "bright-yellow",
+        Ansi16::BrightBlue => "bright-blue",
+        Ansi16::BrightMagenta => "bright-magenta",
+        Ansi16::BrightCyan => "bright-cyan",
+        Ansi16::BrightWhite => "bright-white",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1156,6 +1537,132 @@ mod tests {
         let cache = ColorCache::with_capacity(ColorProfile::Ansi16, 0);
         assert_eq!(cache.stats().capacity, 1);
     }
+
+    // --- Perceptual matching ---
+
+    #[test]
+    fn oklab_reference_points() {
+        let black = Oklab::from_rgb(Rgb::new(0, 0, 0));
+        let white = Oklab::from_rgb(Rgb::new(255, 255, 255));
+        assert!(black.l.abs() < 1e-4);
+        assert!((white.l - 1.0).abs() < 1e-3);
+        let gray = Oklab::from(Rgb::new(128, 128, 128));
+        assert!(gray.a.abs() < 1e-4 && gray.b.abs() < 1e-4);
+    }
+
+    #[test]
+    fn oklab_round_trips_rgb() {
+        for rgb in [
+            Rgb::new(0, 0, 0),
+            Rgb::new(255, 255, 255),
+            Rgb::new(12, 200, 99),
+            Rgb::new(250, 30, 160),
+        ] {
+            assert_eq!(Oklab::from_rgb(rgb).to_rgb(), rgb);
+        }
+    }
+
+    #[test]
+    fn palette_round_trips_fixed_indices() {
+        let palette = Palette::xterm();
+        for index in 16..=255u8 {
+            assert_eq!(palette.nearest_256(palette.get(index)), index);
+        }
+        for index in 0..16u8 {
+            let color = Ansi16::from_u8(index).unwrap();
+            assert_eq!(palette.nearest_ansi16(palette.get(index)), color);
+        }
+    }
+
+    #[test]
+    fn perceptual_grays_stay_neutral() {
+        let palette = Palette::default();
+        let mut last_l = -1.0;
+        for v in 0..=255u8 {
+            let idx = palette.nearest_256(Rgb::new(v, v, v));
+            let rgb = palette.get(idx);
+            assert!(rgb.r == rgb.g && rgb.g == rgb.b, "gray {v} -> {rgb:?}");
+            let l = Oklab::from_rgb(rgb).l;
+            assert!(l >= last_l, "gray ramp not monotonic at {v}");
+            last_l = l;
+
+            let ansi = palette.get(palette.nearest_ansi16(Rgb::new(v, v, v)).as_u8());
+            assert!(ansi.r == ansi.g && ansi.g == ansi.b, "gray {v} -> {ansi:?}");
+        }
+    }
+
+    #[test]
+    fn probed_palette_drives_ansi16_choice() {
+        let mut ansi = [Rgb::new(0, 0, 0); 16];
+        for (index, slot) in ansi.iter_mut().enumerate() {
+            *slot = ansi16_to_rgb(Ansi16::from_u8(index as u8).unwrap());
+        }
+        // Solarized-style blue is far from xterm's (0, 0, 238).
+        ansi[4] = Rgb::new(38, 139, 210);
+        let palette = Palette::from_ansi16(ansi);
+        assert_eq!(palette.get(4), Rgb::new(38, 139, 210));
+        assert_eq!(palette.nearest_ansi16(Rgb::new(40, 140, 205)), Ansi16::Blue);
+
+        let downgraded = Color::rgb(40, 140, 205).downgrade_with(ColorProfile::Ansi16, &palette);
+        assert_eq!(downgraded, Color::Ansi16(Ansi16::Blue));
+        assert_eq!(palette.resolve(downgraded), Rgb::new(38, 139, 210));
+    }
+
+    #[test]
+    fn default_downgrade_is_perceptual() {
+        let palette = Palette::xterm();
+        for v in (0..=255u8).step_by(5) {
+            let gray = Color::rgb(v, v, v.saturating_add(6));
+            for profile in [ColorProfile::Ansi256, ColorProfile::Ansi16] {
+                let downgraded = gray.downgrade(profile);
+                assert_eq!(downgraded, gray.downgrade_with(profile, &palette));
+                let shown = palette.resolve(downgraded);
+                assert!(
+                    shown.r == shown.g && shown.g == shown.b,
+                    "{gray:?} -> {shown:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn downgrade_with_respects_profile() {
+        let palette = Palette::xterm();
+        let color = Color::rgb(200, 30, 60);
+        assert_eq!(
+            color.downgrade_with(ColorProfile::TrueColor, &palette),
+            color
+        );
+        assert!(matches!(
+            color.downgrade_with(ColorProfile::Ansi256, &palette),
+            Color::Ansi256(idx) if idx >= 16
+        ));
+        assert!(matches!(
+            color.downgrade_with(ColorProfile::Ansi16, &palette),
+            Color::Ansi16(_)
+        ));
+        assert_eq!(
+            Color::Ansi256(231).downgrade_with(ColorProfile::Mono, &palette),
+            Color::Mono(MonoColor::White)
+        );
+    }
+
+    #[test]
+    fn cache_with_palette_uses_perceptual_match() {
+        let palette = Palette::xterm();
+        let mut cache = ColorCache::with_palette(ColorProfile::Ansi256, palette.clone());
+        assert!(cache.palette().is_some());
+        for rgb in [
+            Rgb::new(10, 200, 90),
+            Rgb::new(90, 90, 110),
+            Rgb::new(250, 128, 0),
+        ] {
+            let expected = Color::Rgb(rgb).downgrade_with(ColorProfile::Ansi256, &palette);
+            assert_eq!(cache.downgrade_rgb(rgb), expected);
+            assert_eq!(cache.downgrade_rgb(rgb), expected);
+        }
+        assert_eq!(cache.stats().hits, 3);
+    }
 }
 
 #[cfg(test)]
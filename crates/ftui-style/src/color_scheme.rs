@@ -0,0 +1,693 @@
+#![forbid(unsafe_code)]
+
+//! Terminal color scheme importers.
+//!
+//! A [`ColorScheme`] is the 16 ANSI colors plus foreground, background,
+//! cursor and selection colors, as shipped by terminal emulators and scheme
+//! collections. Supported sources:
+//!
+//! - base16 / base24 YAML (`base00`..`base0F`)
+//! - iTerm2 `.itermcolors` property lists
+//! - kitty `.conf` color settings
+//! - Alacritty TOML `[colors.*]` tables (requires the `serde` feature)
+//!
+//! [`ColorScheme::to_theme`] maps a scheme onto the semantic [`Theme`] slots.
+//!
+//! # Example
+//! ```
+//! use ftui_style::color_scheme::ColorScheme;
+//!
+//! let kitty = "foreground #c0c5ce\nbackground #2b303b\ncolor1 #bf616a\ncolor4 #8fa1b3\n";
+//! let scheme = ColorScheme::from_kitty_conf(kitty).unwrap();
+//! let theme = scheme.to_theme();
+//! assert!(!theme.primary.is_adaptive());
+//! ```
+
+use std::fmt;
+use std::path::Path;
+
+use crate::color::{Ansi16, Color, Rgb, ansi16_to_rgb, parse_hex};
+use crate::theme::{AdaptiveColor, Theme};
+
+/// Source format of a color scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorSchemeFormat {
+    /// base16 / base24 YAML.
+    Base16,
+    /// iTerm2 `.itermcolors` property list.
+    Iterm2,
+    /// kitty `.conf` color settings.
+    Kitty,
+    /// Alacritty TOML configuration.
+    Alacritty,
+}
+
+impl ColorSchemeFormat {
+    /// Guess the format from a file extension.
+    #[must_use]
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "yaml" | "yml" => Some(Self::Base16),
+            "itermcolors" => Some(Self::Iterm2),
+            "conf" => Some(Self::Kitty),
+            "toml" => Some(Self::Alacritty),
+            _ => None,
+        }
+    }
+}
+
+/// Error raised while importing a color scheme.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorSchemeError {
+    /// Key in the source file that failed (e.g. `base0A`, `color3`).
+    pub key: String,
+    pub message: String,
+}
+
+impl ColorSchemeError {
+    fn new(key: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ColorSchemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.key.is_empty() {
+            f.write_str(&self.message)
+        } else {
+            write!(f, "{}: {}", self.key, self.message)
+        }
+    }
+}
+
+impl std::error::Error for ColorSchemeError {}
+
+/// A terminal color scheme.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorScheme {
+    /// Scheme name, if the source declares one.
+    pub name: Option<String>,
+    pub foreground: Rgb,
+    pub background: Rgb,
+    pub cursor: Option<Rgb>,
+    pub selection_background: Option<Rgb>,
+    pub selection_foreground: Option<Rgb>,
+    /// ANSI colors 0-15.
+    pub ansi: [Rgb; 16],
+}
+
+impl Default for ColorScheme {
+    fn default() -> Self {
+        let ansi = std::array::from_fn(|i| {
+            ansi16_to_rgb(Ansi16::from_u8(i as u8).unwrap_or(Ansi16::White))
+        });
+        Self {
+            name: None,
+            foreground: ansi[7],
+            background: ansi[0],
+            cursor: None,
+            selection_background: None,
+            selection_foreground: None,
+            ansi,
+        }
+    }
+}
+
+/// base16 slot feeding each ANSI color, following base16-shell.
+const BASE16_ANSI: [usize; 16] = [
+    0x00, 0x08, 0x0B, 0x0A, 0x0D, 0x0E, 0x0C, 0x05, 0x03, 0x08, 0x0B, 0x0A, 0x0D, 0x0E, 0x0C, 0x07,
+];
+
+/// base24 slots for the bright ANSI colors (8-15), when present.
+const BASE24_BRIGHT: [usize; 8] = [0x03, 0x12, 0x14, 0x13, 0x16, 0x17, 0x15, 0x07];
+
+impl ColorScheme {
+    /// Parse a scheme in the given format.
+    pub fn parse(source: &str, format: ColorSchemeFormat) -> Result<Self, ColorSchemeError> {
+        match format {
+            ColorSchemeFormat::Base16 => Self::from_base16_yaml(source),
+            ColorSchemeFormat::Iterm2 => Self::from_iterm2_plist(source),
+            ColorSchemeFormat::Kitty => Self::from_kitty_conf(source),
+            #[cfg(feature = "serde")]
+            ColorSchemeFormat::Alacritty => Self::from_alacritty_toml(source),
+            #[cfg(not(feature = "serde"))]
+            ColorSchemeFormat::Alacritty => Err(ColorSchemeError::new(
+                "",
+                "Alacritty schemes require the `serde` feature",
+            )),
+        }
+    }
+
+    /// Parse a base16 (or base24) scheme.
+    ///
+    /// Accepts both the classic flat layout and the newer `palette:` section;
+    /// indentation is ignored. All of `base00`..`base0F` are required.
+    pub fn from_base16_yaml(source: &str) -> Result<Self, ColorSchemeError> {
+        let mut slots: [Option<Rgb>; 0x18] = [None; 0x18];
+        let mut name = None;
+        for line in source.lines() {
+            let line = strip_comment(line, '#').trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let key = key.trim().trim_matches(['"', '\'']);
+            let value = value.trim().trim_matches(['"', '\'']);
+            if value.is_empty() {
+                continue;
+            }
+            if key == "scheme" || key == "name" {
+                name.get_or_insert_with(|| value.to_string());
+                continue;
+            }
+            let Some(hex) = key.strip_prefix("base") else {
+                continue;
+            };
+            let Ok(index) = usize::from_str_radix(hex, 16) else {
+                continue;
+            };
+            if hex.len() != 2 || index >= slots.len() {
+                continue;
+            }
+            slots[index] =
+                Some(parse_hex(value).ok_or_else(|| {
+                    ColorSchemeError::new(key, format!("invalid color `{value}`"))
+                })?);
+        }
+        for (index, slot) in slots.iter().enumerate().take(16) {
+            if slot.is_none() {
+                return Err(ColorSchemeError::new(
+                    format!("base{index:02X}"),
+                    "missing color",
+                ));
+            }
+        }
+        let slot = |i: usize| slots[i].unwrap_or(Rgb::new(0, 0, 0));
+        let mut ansi: [Rgb; 16] = std::array::from_fn(|i| slot(BASE16_ANSI[i]));
+        if slots[0x10..].iter().all(Option::is_some) {
+            for (i, &base) in BASE24_BRIGHT.iter().enumerate() {
+                ansi[8 + i] = slot(base);
+            }
+        }
+        Ok(Self {
+            name,
+            foreground: slot(0x05),
+            background: slot(0x00),
+            cursor: Some(slot(0x05)),
+            selection_background: Some(slot(0x02)),
+            selection_foreground: None,
+            ansi,
+        })
+    }
+
+    /// Parse an iTerm2 `.itermcolors` property list.
+    ///
+    /// Missing ANSI entries fall back to the xterm defaults.
+    pub fn from_iterm2_plist(source: &str) -> Result<Self, ColorSchemeError> {
+        let entries = parse_plist_colors(source)?;
+        if entries.is_empty() {
+            return Err(ColorSchemeError::new("", "no color entries found"));
+        }
+        let mut scheme = Self::default();
+        let mut foreground = None;
+        let mut background = None;
+        for (key, rgb) in entries {
+            match key.as_str() {
+                "Foreground Color" => foreground = Some(rgb),
+                "Background Color" => background = Some(rgb),
+                "Cursor Color" => scheme.cursor = Some(rgb),
+                "Selection Color" => scheme.selection_background = Some(rgb),
+                "Selected Text Color" => scheme.selection_foreground = Some(rgb),
+                other => {
+                    if let Some(index) = other
+                        .strip_prefix("Ansi ")
+                        .and_then(|rest| rest.strip_suffix(" Color"))
+                        .and_then(|n| n.parse::<usize>().ok())
+                        .filter(|&n| n < 16)
+                    {
+                        scheme.ansi[index] = rgb;
+                    }
+                }
+            }
+        }
+        scheme.foreground = foreground.unwrap_or(scheme.ansi[7]);
+        scheme.background = background.unwrap_or(scheme.ansi[0]);
+        Ok(scheme)
+    }
+
+    /// Parse kitty color settings (`foreground`, `background`, `color0`..`color15`, ...).
+    ///
+    /// Other settings are ignored; missing ANSI entries fall back to the xterm
+    /// defaults.
+    pub fn from_kitty_conf(source: &str) -> Result<Self, ColorSchemeError> {
+        let mut scheme = Self::default();
+        let mut foreground = None;
+        let mut background = None;
+        for line in source.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut parts = line.split_whitespace();
+            let (Some(key), Some(value)) = (parts.next(), parts.next()) else {
+                continue;
+            };
+            let target = match key {
+                "foreground" => &mut foreground,
+                "background" => &mut background,
+                "cursor" => &mut scheme.cursor,
+                "selection_background" => &mut scheme.selection_background,
+                "selection_foreground" => &mut scheme.selection_foreground,
+                _ => match key
+                    .strip_prefix("color")
+                    .and_then(|n| n.parse::<usize>().ok())
+                {
+                    Some(index) if index < 16 => {
+                        scheme.ansi[index] = parse_hex(value).ok_or_else(|| {
+                            ColorSchemeError::new(key, format!("invalid color `{value}`"))
+                        })?;
+                        continue;
+                    }
+                    _ => continue,
+                },
+            };
+            // kitty uses `none` to mean "use the cell colors".
+            if value == "none" {
+                continue;
+            }
+            *target =
+                Some(parse_hex(value).ok_or_else(|| {
+                    ColorSchemeError::new(key, format!("invalid color `{value}`"))
+                })?);
+        }
+        scheme.foreground = foreground.unwrap_or(scheme.ansi[7]);
+        scheme.background = background.unwrap_or(scheme.ansi[0]);
+        Ok(scheme)
+    }
+
+    /// Parse the `[colors]` section of an Alacritty TOML configuration.
+    #[cfg(feature = "serde")]
+    pub fn from_alacritty_toml(source: &str) -> Result<Self, ColorSchemeError> {
+        const NAMES: [&str; 8] = [
+            "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+        ];
+        let table: toml::Table =
+            toml::from_str(source).map_err(|err| ColorSchemeError::new("", err.to_string()))?;
+        let colors = table
+            .get("colors")
+            .and_then(toml::Value::as_table)
+            .ok_or_else(|| ColorSchemeError::new("colors", "missing table"))?;
+        let color = |section: &str, key: &str| -> Result<Option<Rgb>, ColorSchemeError> {
+            let Some(value) = colors
+                .get(section)
+                .and_then(toml::Value::as_table)
+                .and_then(|t| t.get(key))
+            else {
+                return Ok(None);
+            };
+            let path = format!("colors.{section}.{key}");
+            let text = value
+                .as_str()
+                .ok_or_else(|| ColorSchemeError::new(&path, "expected a color string"))?;
+            // `CellForeground` / `CellBackground` defer to the cell colors.
+            if text.starts_with("Cell") {
+                return Ok(None);
+            }
+            parse_hex(text)
+                .map(Some)
+                .ok_or_else(|| ColorSchemeError::new(&path, format!("invalid color `{text}`")))
+        };
+
+        let mut scheme = Self::default();
+        for (i, name) in NAMES.iter().enumerate() {
+            if let Some(rgb) = color("normal", name)? {
+                scheme.ansi[i] = rgb;
+            }
+            if let Some(rgb) = color("bright", name)? {
+                scheme.ansi[8 + i] = rgb;
+            }
+        }
+        scheme.foreground = color("primary", "foreground")?.unwrap_or(scheme.ansi[7]);
+        scheme.background = color("primary", "background")?.unwrap_or(scheme.ansi[0]);
+        scheme.cursor = color("cursor", "cursor")?;
+        scheme.selection_background = color("selection", "background")?;
+        scheme.selection_foreground = color("selection", "text")?;
+        Ok(scheme)
+    }
+
+    /// Read and parse a scheme file, guessing the format from its extension.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ColorSchemeError> {
+        let path = path.as_ref();
+        let format = ColorSchemeFormat::from_path(path).ok_or_else(|| {
+            ColorSchemeError::new("", format!("unknown scheme format: {}", path.display()))
+        })?;
+        let source = std::fs::read_to_string(path)
+            .map_err(|err| ColorSchemeError::new("", format!("{}: {err}", path.display())))?;
+        let mut scheme = Self::parse(&source, format)?;
+        if scheme.name.is_none() {
+            scheme.name = path.file_stem().map(|s| s.to_string_lossy().into_owned());
+        }
+        Ok(scheme)
+    }
+
+    /// Whether the background is dark.
+    #[must_use]
+    pub fn is_dark(&self) -> bool {
+        crate::color::relative_luminance(self.background) < 0.5
+    }
+
+    /// Map the scheme onto semantic theme slots.
+    ///
+    /// Accents come from the ANSI colors; surfaces and muted text are blends
+    /// of the background and foreground.
+    #[must_use]
+    pub fn to_theme(&self) -> Theme {
+        let fg = self.foreground;
+        let bg = self.background;
+        let blue = self.ansi[4];
+        let fixed = |rgb: Rgb| AdaptiveColor::fixed(Color::Rgb(rgb));
+        Theme {
+            primary: fixed(blue),
+            secondary: fixed(self.ansi[5]),
+            accent: fixed(self.ansi[6]),
+            background: fixed(bg),
+            surface: fixed(mix(bg, fg, 0.06)),
+            overlay: fixed(mix(bg, fg, 0.12)),
+            text: fixed(fg),
+            text_muted: fixed(mix(fg, bg, 0.3)),
+            text_subtle: fixed(mix(fg, bg, 0.5)),
+            success: fixed(self.ansi[2]),
+            warning: fixed(self.ansi[3]),
+            error: fixed(self.ansi[1]),
+            info: fixed(self.ansi[12]),
+            border: fixed(mix(bg, fg, 0.2)),
+            border_focused: fixed(blue),
+            selection_bg: fixed(self.selection_background.unwrap_or(mix(bg, blue, 0.35))),
+            selection_fg: fixed(self.selection_foreground.unwrap_or(fg)),
+            scrollbar_track: fixed(mix(bg, fg, 0.06)),
+            scrollbar_thumb: fixed(mix(bg, fg, 0.3)),
+        }
+    }
+}
+
+/// Linear blend from `a` toward `b`.
+fn mix(a: Rgb, b: Rgb, t: f64) -> Rgb {
+    let lerp = |x: u8, y: u8| (f64::from(x) + (f64::from(y) - f64::from(x)) * t).round() as u8;
+    Rgb::new(lerp(a.r, b.r), lerp(a.g, b.g), lerp(a.b, b.b))
+}
+
+fn strip_comment(line: &str, marker: char) -> &str {
+    // A marker inside quotes (e.g. "#ffffff") is part of the value.
+    let mut quote = None;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            (None, c) if c == marker && (i == 0 || line[..i].ends_with(char::is_whitespace)) => {
+                return &line[..i];
+            }
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Extract `key -> color` pairs from the top-level dict of an iTerm2 plist.
+fn parse_plist_colors(source: &str) -> Result<Vec<(String, Rgb)>, ColorSchemeError> {
+    let tokens = plist_tokens(source);
+    let mut out = Vec::new();
+    let mut depth = 0usize;
+    let mut pending_key: Option<String> = None;
+    let mut current: Option<(String, [Option<f64>; 3])> = None;
+    let mut component: Option<usize> = None;
+    for token in tokens {
+        match token {
+            PlistToken::Open("dict") => {
+                depth += 1;
+                if depth == 2 {
+                    current = pending_key.take().map(|key| (key, [None; 3]));
+                }
+            }
+            PlistToken::Close("dict") => {
+                if depth == 2
+                    && let Some((key, [r, g, b])) = current.take()
+                {
+                    match (r, g, b) {
+                        (Some(r), Some(g), Some(b)) => {
+                            let to_u8 = |v: f64| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+                            out.push((key, Rgb::new(to_u8(r), to_u8(g), to_u8(b))));
+                        }
+                        _ => {
+                            return Err(ColorSchemeError::new(
+                                key,
+                                "missing red, green or blue component",
+                            ));
+                        }
+                    }
+                }
+                depth = depth.saturating_sub(1);
+            }
+            PlistToken::Element("key", text) => {
+                if depth == 1 {
+                    pending_key = Some(text.to_string());
+                } else if depth == 2 {
+                    component = match text {
+                        "Red Component" => Some(0),
+                        "Green Component" => Some(1),
+                        "Blue Component" => Some(2),
+                        _ => None,
+                    };
+                }
+            }
+            PlistToken::Element("real" | "integer", text) => {
+                if let (Some(index), Some((key, values))) = (component.take(), current.as_mut()) {
+                    let value = text.trim().parse::<f64>().map_err(|_| {
+                        ColorSchemeError::new(key.as_str(), format!("invalid component `{text}`"))
+                    })?;
+                    values[index] = Some(value);
+                }
+            }
+            _ => component = None,
+        }
+    }
+    Ok(out)
+}
+
+enum PlistToken<'a> {
+    Open(&'a str),
+    Close(&'a str),
+    /// `<name>text</name>`
+    Element(&'a str, &'a str),
+    Other,
+}
+
+/// Minimal XML tokenizer covering the plist subset used by `.itermcolors`.
+fn plist_tokens(source: &str) -> Vec<PlistToken<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = source;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start..];
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+        if tag.starts_with('?') || tag.starts_with('!') {
+            tokens.push(PlistToken::Other);
+        } else if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(PlistToken::Close(name.trim()));
+        } else if tag.ends_with('/') {
+            tokens.push(PlistToken::Other);
+        } else {
+            let name = tag.split_whitespace().next().unwrap_or("");
+            if matches!(name, "key" | "real" | "integer" | "string") {
+                let close = format!("</{name}>");
+                let text_end = rest.find(&close).unwrap_or(rest.len());
+                tokens.push(PlistToken::Element(name, &rest[..text_end]));
+                rest = &rest[(text_end + close.len()).min(rest.len())..];
+            } else {
+                tokens.push(PlistToken::Open(name));
+            }
+        }
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE16: &str = r#"
+scheme: "Ocean"
+author: "Chris Kempson (http://chriskempson.com)"
+base00: "2b303b" # background
+base01: "343d46"
+base02: "4f5b66"
+base03: "65737e"
+base04: "a7adba"
+base05: "c0c5ce"
+base06: "dfe1e8"
+base07: "eff1f5"
+base08: "bf616a"
+base09: "d08770"
+base0A: "ebcb8b"
+base0B: "a3be8c"
+base0C: "96b5b4"
+base0D: "8fa1b3"
+base0E: "b48ead"
+base0F: "ab7967"
+"#;
+
+    #[test]
+    fn base16_maps_slots_to_ansi() {
+        let scheme = ColorScheme::from_base16_yaml(BASE16).unwrap();
+        assert_eq!(scheme.name.as_deref(), Some("Ocean"));
+        assert_eq!(scheme.background, Rgb::new(0x2b, 0x30, 0x3b));
+        assert_eq!(scheme.foreground, Rgb::new(0xc0, 0xc5, 0xce));
+        assert_eq!(scheme.ansi[1], Rgb::new(0xbf, 0x61, 0x6a));
+        assert_eq!(scheme.ansi[4], Rgb::new(0x8f, 0xa1, 0xb3));
+        assert_eq!(scheme.ansi[8], Rgb::new(0x65, 0x73, 0x7e));
+        assert_eq!(
+            scheme.selection_background,
+            Some(Rgb::new(0x4f, 0x5b, 0x66))
+        );
+        assert!(scheme.is_dark());
+    }
+
+    #[test]
+    fn base16_reports_missing_and_invalid_keys() {
+        let missing = BASE16.replace("base0A: \"ebcb8b\"\n", "");
+        let err = ColorScheme::from_base16_yaml(&missing).unwrap_err();
+        assert_eq!(err.key, "base0A");
+
+        let invalid = BASE16.replace("ebcb8b", "ebcb8");
+        let err = ColorScheme::from_base16_yaml(&invalid).unwrap_err();
+        assert_eq!(err.to_string(), "base0A: invalid color `ebcb8`");
+    }
+
+    #[test]
+    fn base16_palette_section_is_accepted() {
+        let nested = BASE16.replace("base", "  base").replace("scheme:", "name:");
+        let nested = format!("system: \"base16\"\npalette:\n{nested}");
+        let scheme = ColorScheme::from_base16_yaml(&nested).unwrap();
+        assert_eq!(scheme.name.as_deref(), Some("Ocean"));
+        assert_eq!(scheme.ansi[2], Rgb::new(0xa3, 0xbe, 0x8c));
+    }
+
+    fn iterm_entry(key: &str, r: f64, g: f64, b: f64) -> String {
+        format!(
+            "<key>{key}</key>\n<dict>\n<key>Alpha Component</key><real>1</real>\n\
+             <key>Blue Component</key><real>{b}</real>\n<key>Color Space</key><string>sRGB</string>\n\
+             <key>Green Component</key><real>{g}</real>\n<key>Red Component</key><real>{r}</real>\n</dict>\n"
+        )
+    }
+
+    #[test]
+    fn iterm2_plist_reads_components() {
+        let body = [
+            iterm_entry("Ansi 1 Color", 1.0, 0.0, 0.0),
+            iterm_entry("Background Color", 0.0, 0.0, 0.2),
+            iterm_entry("Foreground Color", 0.9, 0.9, 0.9),
+            iterm_entry("Selection Color", 0.2, 0.4, 0.6),
+        ]
+        .concat();
+        let plist = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<!DOCTYPE plist>\n<plist version=\"1.0\">\n<dict>\n{body}</dict>\n</plist>\n"
+        );
+        let scheme = ColorScheme::from_iterm2_plist(&plist).unwrap();
+        assert_eq!(scheme.ansi[1], Rgb::new(255, 0, 0));
+        assert_eq!(scheme.background, Rgb::new(0, 0, 51));
+        assert_eq!(scheme.foreground, Rgb::new(230, 230, 230));
+        assert_eq!(scheme.selection_background, Some(Rgb::new(51, 102, 153)));
+        // Unspecified ANSI colors keep the xterm defaults.
+        assert_eq!(scheme.ansi[2], ColorScheme::default().ansi[2]);
+    }
+
+    #[test]
+    fn iterm2_plist_reports_incomplete_entry() {
+        let plist = "<plist><dict><key>Ansi 3 Color</key><dict>\
+                     <key>Red Component</key><real>1</real></dict></dict></plist>";
+        let err = ColorScheme::from_iterm2_plist(plist).unwrap_err();
+        assert_eq!(err.key, "Ansi 3 Color");
+    }
+
+    #[test]
+    fn kitty_conf_parses_colors_and_ignores_other_settings() {
+        let conf = "# Theme\nfont_size 12\nforeground #dddddd\nbackground #111111\n\
+                    cursor none\nselection_background #334455\ncolor9 #ff5555\n";
+        let scheme = ColorScheme::from_kitty_conf(conf).unwrap();
+        assert_eq!(scheme.foreground, Rgb::new(0xdd, 0xdd, 0xdd));
+        assert_eq!(scheme.background, Rgb::new(0x11, 0x11, 0x11));
+        assert_eq!(scheme.cursor, None);
+        assert_eq!(scheme.ansi[9], Rgb::new(0xff, 0x55, 0x55));
+
+        let err = ColorScheme::from_kitty_conf("color4 blue\n").unwrap_err();
+        assert_eq!(err.key, "color4");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn alacritty_toml_reads_color_tables() {
+        let toml = r##"
+[colors.primary]
+background = "#1d1f21"
+foreground = "0xc5c8c6"
+
+[colors.cursor]
+text = "CellBackground"
+cursor = "CellForeground"
+
+[colors.normal]
+red = "#cc6666"
+blue = "#81a2be"
+
+[colors.bright]
+red = "#d54e53"
+"##;
+        let scheme = ColorScheme::from_alacritty_toml(toml).unwrap();
+        assert_eq!(scheme.background, Rgb::new(0x1d, 0x1f, 0x21));
+        assert_eq!(scheme.foreground, Rgb::new(0xc5, 0xc8, 0xc6));
+        assert_eq!(scheme.ansi[1], Rgb::new(0xcc, 0x66, 0x66));
+        assert_eq!(scheme.ansi[9], Rgb::new(0xd5, 0x4e, 0x53));
+        assert_eq!(scheme.cursor, None);
+
+        let err =
+            ColorScheme::from_alacritty_toml("[colors.normal]\ngreen = \"#12\"\n").unwrap_err();
+        assert_eq!(err.key, "colors.normal.green");
+    }
+
+    #[test]
+    fn to_theme_uses_ansi_accents() {
+        let scheme = ColorScheme::from_base16_yaml(BASE16).unwrap();
+        let theme = scheme.to_theme().resolve(true);
+        assert_eq!(theme.error, Color::Rgb(scheme.ansi[1]));
+        assert_eq!(theme.success, Color::Rgb(scheme.ansi[2]));
+        assert_eq!(theme.background, Color::Rgb(scheme.background));
+        assert_ne!(theme.surface, theme.background);
+    }
+
+    #[test]
+    fn hex_parsing_variants() {
+        assert_eq!(parse_hex("#abc"), Some(Rgb::new(0xaa, 0xbb, 0xcc)));
+        assert_eq!(parse_hex("0x102030"), Some(Rgb::new(0x10, 0x20, 0x30)));
+        assert_eq!(parse_hex("102030"), Some(Rgb::new(0x10, 0x20, 0x30)));
+        assert_eq!(parse_hex("#12345"), None);
+        assert_eq!(parse_hex("#gggggg"), None);
+    }
+
+    #[test]
+    fn format_from_extension() {
+        assert_eq!(
+            ColorSchemeFormat::from_path(Path::new("x/Dracula.itermcolors")),
+            Some(ColorSchemeFormat::Iterm2)
+        );
+        assert_eq!(
+            ColorSchemeFormat::from_path(Path::new("ocean.YAML")),
+            Some(ColorSchemeFormat::Base16)
+        );
+        assert_eq!(ColorSchemeFormat::from_path(Path::new("theme.txt")), None);
+    }
+}
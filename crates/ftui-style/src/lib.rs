@@ -10,9 +10,12 @@
 //! # This crate provides
 //! - [`Style`] for unified text styling with CSS-like inheritance.
 //! - [`StyleSheet`] for named style registration (CSS-like classes).
+//! - [`SelectorSheet`] for cascading rules matched by widget type, id, class and state.
 //! - [`Theme`] for semantic color slots with light/dark mode support.
-//! - Color types and downgrade utilities.
+//! - Color types and downgrade utilities, including perceptual palette matching.
+//! - [`ThemeAudit`] for WCAG and color-vision contrast checks with automatic repair.
 //! - Table themes and effects used by widgets and markdown rendering.
+//! - Theme files and terminal color scheme importers.
 //!
 //! # How it fits in the system
 //! `ftui-render` stores style values in cells, `ftui-widgets` computes styles
@@ -22,6 +25,10 @@
 
 /// Color types, profiles, and downgrade utilities.
 pub mod color;
+/// Terminal color scheme importers (base16, iTerm2, kitty, Alacritty).
+pub mod color_scheme;
+/// Cascading selector stylesheets (type, id, class and state selectors).
+pub mod selector;
 /// Style types with CSS-like cascading semantics.
 pub mod style;
 /// StyleSheet registry for named styles.
@@ -30,6 +37,11 @@ pub mod stylesheet;
 pub mod table_theme;
 /// Theme system with semantic color slots.
 pub mod theme;
+/// Theme accessibility auditing (WCAG contrast, color-vision simulation).
+pub mod theme_audit;
+/// TOML/JSON theme files.
+#[cfg(feature = "serde")]
+pub mod theme_file;
 
 pub use color::{
     // Color types
@@ -38,6 +50,8 @@ pub use color::{
     ColorCache,
     ColorProfile,
     MonoColor,
+    Oklab,
+    Palette,
     Rgb,
     // WCAG constants
     WCAG_AA_LARGE_TEXT,
@@ -55,6 +69,14 @@ pub use color::{
     meets_wcag_aaa,
     relative_luminance,
     relative_luminance_packed,
+    // Probed terminal palette
+    set_terminal_palette,
+    terminal_palette,
+};
+pub use color_scheme::{ColorScheme, ColorSchemeError, ColorSchemeFormat};
+pub use selector::{
+    Rule, Selector, SelectorSheet, SelectorSheetError, Specificity, StyleNode, StylePath,
+    WidgetState,
 };
 pub use style::{Style, StyleFlags};
 pub use stylesheet::{StyleId, StyleSheet};
@@ -63,7 +85,12 @@ pub use table_theme::{
     TableEffectScope, TableEffectTarget, TablePresetId, TableSection, TableTheme,
     TableThemeDiagnostics, TableThemeSpec,
 };
-pub use theme::{AdaptiveColor, ResolvedTheme, Theme, ThemeBuilder};
+pub use theme::{AdaptiveColor, ResolvedTheme, Theme, ThemeBuilder, bump_theme_epoch, theme_epoch};
+pub use theme_audit::{
+    AuditFailure, AuditReport, ColorVision, ContrastRequirement, ThemeAudit, WcagLevel,
+};
+#[cfg(feature = "serde")]
+pub use theme_file::{LoadedTheme, ThemeFileError, ThemeSpec, ThemeSpecError, load_theme_file};
 
 #[cfg(test)]
 mod tests {
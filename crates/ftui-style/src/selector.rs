@@ -0,0 +1,956 @@
+#![forbid(unsafe_code)]
+
+//! Cascading selector stylesheets.
+//!
+//! A [`SelectorSheet`] holds CSS-like rules that match widgets by type, id,
+//! class and state (`:focus`, `:hover`, `:disabled`, `:selected`), optionally
+//! constrained by their ancestors in the render tree. Widgets describe
+//! themselves with a [`StyleNode`] and ask the sheet for their style instead
+//! of exposing a builder method per styled part. The core widgets do this
+//! through `ftui_widgets::sheet::SheetStyled`.
+//!
+//! Matching rules are applied in order of [`Specificity`], then source order;
+//! later rules override earlier ones (attributes accumulate).
+//!
+//! # Text format
+//!
+//! ```text
+//! /* comments */
+//! Button { fg: #e0e0e0; bg: #30343c; }
+//! Button:focus, Button:hover { bg: #4c6ef5; attrs: bold; }
+//! #sidebar List > ListItem:selected { fg: black; bg: bright-yellow; }
+//! * :disabled { attrs: dim; }
+//! ```
+//!
+//! Properties: `fg` (`color`), `bg` (`background`), `underline-color`, and
+//! `attrs` (space separated: `bold dim italic underline blink reverse hidden
+//! strikethrough double-underline curly-underline`). Colors use the notation
+//! of [`parse_color`](crate::color::parse_color).
+//!
+//! # Example
+//! ```
+//! use ftui_style::selector::{SelectorSheet, StyleNode, WidgetState};
+//! use ftui_render::cell::PackedRgba;
+//!
+//! let sheet = SelectorSheet::parse(
+//!     "Button { fg: #ffffff; } Dialog Button:focus { bg: #0000ff; }",
+//! )
+//! .unwrap();
+//!
+//! let path = [
+//!     StyleNode::new("Dialog"),
+//!     StyleNode::new("Button").state(WidgetState::FOCUS),
+//! ];
+//! let style = sheet.resolve(&path);
+//! assert_eq!(style.fg, Some(PackedRgba::rgb(255, 255, 255)));
+//! assert_eq!(style.bg, Some(PackedRgba::rgb(0, 0, 255)));
+//! ```
+
+use std::fmt;
+use std::path::Path;
+
+use crate::color::parse_color;
+use crate::style::{Style, StyleFlags};
+use crate::stylesheet::StyleSheet;
+use ftui_render::cell::PackedRgba;
+
+/// Interaction state flags matched by `:focus`, `:hover`, `:disabled` and `:selected`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WidgetState(pub u8);
+
+impl WidgetState {
+    /// No state flags.
+    pub const NONE: Self = Self(0);
+    /// Widget has keyboard focus.
+    pub const FOCUS: Self = Self(1 << 0);
+    /// Pointer is over the widget.
+    pub const HOVER: Self = Self(1 << 1);
+    /// Widget is disabled.
+    pub const DISABLED: Self = Self(1 << 2);
+    /// Widget (or item) is selected.
+    pub const SELECTED: Self = Self(1 << 3);
+
+    /// Check if all flags in `other` are set.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        (self.0 & other.0) == other.0
+    }
+
+    /// Set the flags in `other`.
+    #[inline]
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    /// Clear the flags in `other`.
+    #[inline]
+    pub fn remove(&mut self, other: Self) {
+        self.0 &= !other.0;
+    }
+
+    /// Set or clear the flags in `other`.
+    #[inline]
+    pub fn set(&mut self, other: Self, on: bool) {
+        if on {
+            self.insert(other);
+        } else {
+            self.remove(other);
+        }
+    }
+
+    /// Check if no flags are set.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Combine two flag sets.
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Number of flags set (used for specificity).
+    #[inline]
+    const fn count(self) -> u16 {
+        self.0.count_ones() as u16
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "focus" | "focused" => Some(Self::FOCUS),
+            "hover" => Some(Self::HOVER),
+            "disabled" => Some(Self::DISABLED),
+            "selected" => Some(Self::SELECTED),
+            _ => None,
+        }
+    }
+}
+
+impl core::ops::BitOr for WidgetState {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+/// A widget as seen by selector matching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StyleNode<'a> {
+    /// Widget type name (e.g. `"Button"`).
+    pub type_name: &'a str,
+    /// Widget id matched by `#id` (e.g. `Some("sidebar")`).
+    pub id: Option<&'a str>,
+    /// Class names matched by `.class` (e.g. `&["item"]`).
+    pub classes: &'a [&'a str],
+    /// Interaction state matched by pseudo-classes (e.g. `:focus`).
+    pub state: WidgetState,
+}
+
+impl<'a> StyleNode<'a> {
+    /// Create a node for a widget type.
+    #[must_use]
+    pub const fn new(type_name: &'a str) -> Self {
+        Self {
+            type_name,
+            id: None,
+            classes: &[],
+            state: WidgetState::NONE,
+        }
+    }
+
+    /// Set the node id.
+    #[must_use]
+    pub const fn id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Set the node classes.
+    #[must_use]
+    pub const fn classes(mut self, classes: &'a [&'a str]) -> Self {
+        self.classes = classes;
+        self
+    }
+
+    /// Set the node state.
+    #[must_use]
+    pub const fn state(mut self, state: WidgetState) -> Self {
+        self.state = state;
+        self
+    }
+
+    fn has_class(&self, class: &str) -> bool {
+        self.classes.contains(&class)
+    }
+}
+
+/// Render-tree path from the root to the widget being styled.
+///
+/// Containers push themselves before rendering children and pop afterwards,
+/// so descendants can be styled by context.
+#[derive(Debug, Clone, Default)]
+pub struct StylePath<'a> {
+    nodes: Vec<StyleNode<'a>>,
+}
+
+impl<'a> StylePath<'a> {
+    /// Create an empty path.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enter a node.
+    pub fn push(&mut self, node: StyleNode<'a>) {
+        self.nodes.push(node);
+    }
+
+    /// Leave the innermost node.
+    pub fn pop(&mut self) -> Option<StyleNode<'a>> {
+        self.nodes.pop()
+    }
+
+    /// Nodes from root to innermost.
+    #[must_use]
+    pub fn nodes(&self) -> &[StyleNode<'a>] {
+        &self.nodes
+    }
+
+    /// Resolve the style for `node` as a child of the current path.
+    #[must_use]
+    pub fn resolve(&mut self, sheet: &SelectorSheet, node: StyleNode<'a>) -> Style {
+        self.nodes.push(node);
+        let style = sheet.resolve(&self.nodes);
+        self.nodes.pop();
+        style
+    }
+}
+
+/// Selector specificity: (ids, classes + states, types). Higher wins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Specificity(pub u16, pub u16, pub u16);
+
+/// One compound selector: `Type#id.class:state`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct Compound {
+    type_name: Option<String>,
+    id: Option<String>,
+    classes: Vec<String>,
+    state: WidgetState,
+}
+
+impl Compound {
+    fn matches(&self, node: &StyleNode<'_>) -> bool {
+        self.type_name
+            .as_deref()
+            .is_none_or(|name| name == node.type_name)
+            && self.id.as_deref().is_none_or(|id| node.id == Some(id))
+            && self.classes.iter().all(|class| node.has_class(class))
+            && node.state.contains(self.state)
+    }
+
+    fn is_universal(&self) -> bool {
+        self.type_name.is_none()
+            && self.id.is_none()
+            && self.classes.is_empty()
+            && self.state.is_empty()
+    }
+}
+
+impl fmt::Display for Compound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_universal() {
+            return f.write_str("*");
+        }
+        if let Some(name) = &self.type_name {
+            f.write_str(name)?;
+        }
+        if let Some(id) = &self.id {
+            write!(f, "#{id}")?;
+        }
+        for class in &self.classes {
+            write!(f, ".{class}")?;
+        }
+        for (flag, name) in [
+            (WidgetState::FOCUS, "focus"),
+            (WidgetState::HOVER, "hover"),
+            (WidgetState::DISABLED, "disabled"),
+            (WidgetState::SELECTED, "selected"),
+        ] {
+            if self.state.contains(flag) {
+                write!(f, ":{name}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Relationship between a compound and the one to its left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    /// `A B`: B anywhere below A.
+    Descendant,
+    /// `A > B`: B directly below A.
+    Child,
+}
+
+/// A complex selector such as `#sidebar List > .item:selected`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector {
+    /// Compounds left to right; the combinator links each to its predecessor.
+    parts: Vec<(Combinator, Compound)>,
+}
+
+impl Selector {
+    /// Parse a single selector.
+    pub fn parse(text: &str) -> Result<Self, SelectorSheetError> {
+        let mut parser = Parser::new(text);
+        let selector = parser.selector()?;
+        parser.skip_trivia();
+        if !parser.at_end() {
+            return Err(parser.error("unexpected input after selector"));
+        }
+        Ok(selector)
+    }
+
+    /// Selector specificity.
+    #[must_use]
+    pub fn specificity(&self) -> Specificity {
+        self.parts
+            .iter()
+            .fold(Specificity::default(), |Specificity(a, b, c), (_, part)| {
+                Specificity(
+                    a + u16::from(part.id.is_some()),
+                    b + part.classes.len() as u16 + part.state.count(),
+                    c + u16::from(part.type_name.is_some()),
+                )
+            })
+    }
+
+    /// Whether the selector matches the last node of `path` (root first).
+    #[must_use]
+    pub fn matches(&self, path: &[StyleNode<'_>]) -> bool {
+        let Some(last) = path.len().checked_sub(1) else {
+            return false;
+        };
+        if let [(_, compound)] = self.parts.as_slice() {
+            return compound.matches(&path[last]);
+        }
+        // Results per (part, node): descendant combinators retry the same
+        // pairs from many starting points, which is exponential uncached.
+        let mut memo = vec![None; self.parts.len() * path.len()];
+        self.matches_at(self.parts.len() - 1, path, last, &mut memo)
+    }
+
+    fn matches_at(
+        &self,
+        part: usize,
+        path: &[StyleNode<'_>],
+        node: usize,
+        memo: &mut [Option<bool>],
+    ) -> bool {
+        let slot = part * path.len() + node;
+        if let Some(matched) = memo[slot] {
+            return matched;
+        }
+        let (combinator, compound) = &self.parts[part];
+        let matched = if !compound.matches(&path[node]) {
+            false
+        } else if part == 0 {
+            true
+        } else {
+            match combinator {
+                Combinator::Child => node > 0 && self.matches_at(part - 1, path, node - 1, memo),
+                Combinator::Descendant => (0..node)
+                    .rev()
+                    .any(|ancestor| self.matches_at(part - 1, path, ancestor, memo)),
+            }
+        };
+        memo[slot] = Some(matched);
+        matched
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (combinator, compound)) in self.parts.iter().enumerate() {
+            if i > 0 {
+                f.write_str(match combinator {
+                    Combinator::Descendant => " ",
+                    Combinator::Child => " > ",
+                })?;
+            }
+            write!(f, "{compound}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A selector with the style it applies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    /// Nodes the rule applies to.
+    pub selector: Selector,
+    /// Style for matching nodes; more specific, then later, rules win.
+    pub style: Style,
+}
+
+/// Error parsing selector stylesheet text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorSheetError {
+    /// 1-based line.
+    pub line: usize,
+    /// 1-based column.
+    pub column: usize,
+    /// What went wrong, e.g. ``unknown property `padding` ``.
+    pub message: String,
+}
+
+impl fmt::Display for SelectorSheetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for SelectorSheetError {}
+
+/// Cascading stylesheet of selector rules.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SelectorSheet {
+    rules: Vec<Rule>,
+}
+
+impl SelectorSheet {
+    /// Create an empty sheet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse stylesheet text.
+    pub fn parse(text: &str) -> Result<Self, SelectorSheetError> {
+        let mut sheet = Self::new();
+        sheet.extend_from_str(text)?;
+        Ok(sheet)
+    }
+
+    /// Read and parse a stylesheet file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SelectorSheetError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|err| SelectorSheetError {
+            line: 0,
+            column: 0,
+            message: format!("{}: {err}", path.display()),
+        })?;
+        Self::parse(&text)
+    }
+
+    /// Build a sheet with one `.name` class rule per named style.
+    #[must_use]
+    pub fn from_stylesheet(sheet: &StyleSheet) -> Self {
+        let mut names = sheet.names();
+        names.sort();
+        let mut out = Self::new();
+        for name in names {
+            if let Some(style) = sheet.get(&name) {
+                out.push(Rule {
+                    selector: Selector {
+                        parts: vec![(
+                            Combinator::Descendant,
+                            Compound {
+                                classes: vec![name],
+                                ..Compound::default()
+                            },
+                        )],
+                    },
+                    style,
+                });
+            }
+        }
+        out
+    }
+
+    /// Append rules parsed from text (they follow existing rules in source order).
+    pub fn extend_from_str(&mut self, text: &str) -> Result<(), SelectorSheetError> {
+        let mut parser = Parser::new(text);
+        loop {
+            parser.skip_trivia();
+            if parser.at_end() {
+                return Ok(());
+            }
+            let mut selectors = vec![parser.selector()?];
+            parser.skip_trivia();
+            while parser.eat(',') {
+                parser.skip_trivia();
+                selectors.push(parser.selector()?);
+                parser.skip_trivia();
+            }
+            if !parser.eat('{') {
+                return Err(parser.error("expected `{`"));
+            }
+            let style = parser.declarations()?;
+            self.rules.extend(
+                selectors
+                    .into_iter()
+                    .map(|selector| Rule { selector, style }),
+            );
+        }
+    }
+
+    /// Add a rule after all existing rules.
+    pub fn push(&mut self, rule: Rule) {
+        self.rules.push(rule);
+    }
+
+    /// Add a rule from selector text.
+    pub fn add(&mut self, selector: &str, style: Style) -> Result<(), SelectorSheetError> {
+        self.push(Rule {
+            selector: Selector::parse(selector)?,
+            style,
+        });
+        Ok(())
+    }
+
+    /// Rules in source order.
+    #[must_use]
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Number of rules.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Whether the sheet has no rules.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Compute the style for the last node of `path` (root first).
+    #[must_use]
+    pub fn resolve(&self, path: &[StyleNode<'_>]) -> Style {
+        let mut matched: Vec<(Specificity, usize)> = self
+            .rules
+            .iter()
+            .enumerate()
+            .filter(|(_, rule)| rule.selector.matches(path))
+            .map(|(order, rule)| (rule.selector.specificity(), order))
+            .collect();
+        matched.sort_unstable();
+        matched.into_iter().fold(Style::new(), |acc, (_, order)| {
+            self.rules[order].style.merge(&acc)
+        })
+    }
+
+    /// Compute the style for a node with no ancestors.
+    #[must_use]
+    pub fn query(&self, node: StyleNode<'_>) -> Style {
+        self.resolve(&[node])
+    }
+}
+
+impl std::str::FromStr for SelectorSheet {
+    type Err = SelectorSheetError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos >= self.text.len()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn error(&self, message: impl Into<String>) -> SelectorSheetError {
+        let before = &self.text[..self.pos];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rfind('\n').map_or(before.chars().count(), |nl| {
+            before[nl + 1..].chars().count()
+        }) + 1;
+        SelectorSheetError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Skip whitespace and `/* */` comments. Returns whether anything was skipped.
+    fn skip_trivia(&mut self) -> bool {
+        let start = self.pos;
+        loop {
+            let trimmed = self.rest().trim_start();
+            self.pos = self.text.len() - trimmed.len();
+            if let Some(body) = trimmed.strip_prefix("/*") {
+                let end = body.find("*/").map_or(body.len(), |i| i + 2);
+                self.pos += 2 + end;
+            } else {
+                return self.pos != start;
+            }
+        }
+    }
+
+    fn ident(&mut self) -> Option<&'a str> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_'))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return None;
+        }
+        self.pos += len;
+        Some(&rest[..len])
+    }
+
+    fn selector(&mut self) -> Result<Selector, SelectorSheetError> {
+        let mut parts = vec![(Combinator::Descendant, self.compound()?)];
+        loop {
+            let checkpoint = self.pos;
+            let spaced = self.skip_trivia();
+            let combinator = if self.eat('>') {
+                self.skip_trivia();
+                Combinator::Child
+            } else if spaced
+                && matches!(self.peek(), Some(c) if c == '*' || c == '#' || c == '.' || c == ':' || c.is_alphanumeric() || c == '_')
+            {
+                Combinator::Descendant
+            } else {
+                self.pos = checkpoint;
+                return Ok(Selector { parts });
+            };
+            parts.push((combinator, self.compound()?));
+        }
+    }
+
+    fn compound(&mut self) -> Result<Compound, SelectorSheetError> {
+        let mut compound = Compound::default();
+        let mut any = self.eat('*');
+        if !any && let Some(name) = self.ident() {
+            compound.type_name = Some(name.to_string());
+            any = true;
+        }
+        loop {
+            let sigil_pos = self.pos;
+            if self.eat('#') {
+                let id = self
+                    .ident()
+                    .ok_or_else(|| self.error("expected id after `#`"))?;
+                compound.id = Some(id.to_string());
+            } else if self.eat('.') {
+                let class = self
+                    .ident()
+                    .ok_or_else(|| self.error("expected class after `.`"))?;
+                compound.classes.push(class.to_string());
+            } else if self.eat(':') {
+                let name = self
+                    .ident()
+                    .ok_or_else(|| self.error("expected state after `:`"))?;
+                let state = WidgetState::from_name(name).ok_or_else(|| {
+                    self.pos = sigil_pos;
+                    self.error(format!(
+                        "unknown state `:{name}` (expected focus, hover, disabled or selected)"
+                    ))
+                })?;
+                compound.state.insert(state);
+            } else {
+                break;
+            }
+            any = true;
+        }
+        if any {
+            Ok(compound)
+        } else {
+            Err(self.error("expected selector"))
+        }
+    }
+
+    fn declarations(&mut self) -> Result<Style, SelectorSheetError> {
+        let mut style = Style::new();
+        loop {
+            self.skip_trivia();
+            if self.eat('}') {
+                return Ok(style);
+            }
+            if self.at_end() {
+                return Err(self.error("expected `}`"));
+            }
+            let name_pos = self.pos;
+            let name = self
+                .ident()
+                .ok_or_else(|| self.error("expected property name"))?;
+            self.skip_trivia();
+            if !self.eat(':') {
+                return Err(self.error(format!("expected `:` after `{name}`")));
+            }
+            self.skip_trivia();
+            let value_pos = self.pos;
+            let rest = self.rest();
+            let len = rest.find([';', '}']).unwrap_or(rest.len());
+            let value = rest[..len].trim();
+            self.pos += len;
+            self.eat(';');
+
+            let value_error = |parser: &mut Self, message: String| {
+                parser.pos = value_pos;
+                parser.error(message)
+            };
+            match name {
+                "fg" | "color" => {
+                    style.fg = Some(parse_packed(value).map_err(|m| value_error(self, m))?);
+                }
+                "bg" | "background" => {
+                    style.bg = Some(parse_packed(value).map_err(|m| value_error(self, m))?);
+                }
+                "underline-color" => {
+                    style.underline_color =
+                        Some(parse_packed(value).map_err(|m| value_error(self, m))?);
+                }
+                "attrs" => {
+                    style.attrs = Some(parse_attrs(value).map_err(|m| value_error(self, m))?);
+                }
+                _ => {
+                    self.pos = name_pos;
+                    return Err(self.error(format!("unknown property `{name}`")));
+                }
+            }
+        }
+    }
+}
+
+fn parse_packed(value: &str) -> Result<PackedRgba, String> {
+    let rgb = parse_color(value)?.to_rgb();
+    Ok(PackedRgba::rgb(rgb.r, rgb.g, rgb.b))
+}
+
+fn parse_attrs(value: &str) -> Result<StyleFlags, String> {
+    let mut flags = StyleFlags::NONE;
+    for word in value.split_whitespace() {
+        flags.insert(match word {
+            "none" => StyleFlags::NONE,
+            "bold" => StyleFlags::BOLD,
+            "dim" => StyleFlags::DIM,
+            "italic" => StyleFlags::ITALIC,
+            "underline" => StyleFlags::UNDERLINE,
+            "blink" => StyleFlags::BLINK,
+            "reverse" => StyleFlags::REVERSE,
+            "hidden" => StyleFlags::HIDDEN,
+            "strikethrough" => StyleFlags::STRIKETHROUGH,
+            "double-underline" => StyleFlags::DOUBLE_UNDERLINE,
+            "curly-underline" => StyleFlags::CURLY_UNDERLINE,
+            other => return Err(format!("unknown attribute `{other}`")),
+        });
+    }
+    Ok(flags)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: PackedRgba = PackedRgba::rgb(255, 0, 0);
+    const GREEN: PackedRgba = PackedRgba::rgb(0, 255, 0);
+    const BLUE: PackedRgba = PackedRgba::rgb(0, 0, 255);
+
+    #[test]
+    fn specificity_orders_ids_classes_types() {
+        let spec = |s: &str| Selector::parse(s).unwrap().specificity();
+        assert_eq!(spec("*"), Specificity(0, 0, 0));
+        assert_eq!(spec("Button"), Specificity(0, 0, 1));
+        assert_eq!(spec("Button.primary:focus"), Specificity(0, 2, 1));
+        assert_eq!(spec("#ok"), Specificity(1, 0, 0));
+        assert_eq!(spec("Dialog > Button#ok"), Specificity(1, 0, 2));
+        assert!(spec("#ok") > spec("Button.a.b.c:hover"));
+    }
+
+    #[test]
+    fn more_specific_rule_wins_regardless_of_order() {
+        let sheet = SelectorSheet::parse(
+            "Button.primary { fg: #ff0000; }
+             Button { fg: #00ff00; bg: #0000ff; }",
+        )
+        .unwrap();
+        let style = sheet.query(StyleNode::new("Button").classes(&["primary"]));
+        assert_eq!(style.fg, Some(RED));
+        assert_eq!(style.bg, Some(BLUE));
+    }
+
+    #[test]
+    fn later_rule_wins_on_equal_specificity() {
+        let sheet =
+            SelectorSheet::parse("Button { fg: #ff0000; } Button { fg: #00ff00; }").unwrap();
+        assert_eq!(sheet.query(StyleNode::new("Button")).fg, Some(GREEN));
+    }
+
+    #[test]
+    fn state_pseudo_classes_require_state() {
+        let sheet = SelectorSheet::parse(
+            "Input { fg: white; }
+             Input:focus { bg: blue; attrs: bold; }
+             Input:disabled { attrs: dim; }
+             Item:hover:selected { bg: #ff0000; }",
+        )
+        .unwrap();
+        let idle = sheet.query(StyleNode::new("Input"));
+        assert_eq!(idle.bg, None);
+        let focused = sheet.query(StyleNode::new("Input").state(WidgetState::FOCUS));
+        assert!(focused.bg.is_some());
+        assert!(focused.attrs.unwrap().contains(StyleFlags::BOLD));
+        let both =
+            sheet.query(StyleNode::new("Input").state(WidgetState::FOCUS | WidgetState::DISABLED));
+        let attrs = both.attrs.unwrap();
+        assert!(attrs.contains(StyleFlags::BOLD) && attrs.contains(StyleFlags::DIM));
+
+        let hover = StyleNode::new("Item").state(WidgetState::HOVER);
+        assert_eq!(sheet.query(hover).bg, None);
+        let hover_selected = hover.state(WidgetState::HOVER | WidgetState::SELECTED);
+        assert_eq!(sheet.query(hover_selected).bg, Some(RED));
+    }
+
+    #[test]
+    fn descendant_and_child_combinators() {
+        let sheet = SelectorSheet::parse(
+            "#sidebar .item { fg: #ff0000; }
+             List > .item { bg: #00ff00; }",
+        )
+        .unwrap();
+        let sidebar = StyleNode::new("Panel").id("sidebar");
+        let list = StyleNode::new("List");
+        let item = StyleNode::new("Text").classes(&["item"]);
+
+        let nested = sheet.resolve(&[sidebar, StyleNode::new("Block"), list, item]);
+        assert_eq!(nested.fg, Some(RED));
+        assert_eq!(nested.bg, Some(GREEN));
+
+        let not_direct = sheet.resolve(&[list, StyleNode::new("Block"), item]);
+        assert_eq!(not_direct.bg, None);
+        assert_eq!(not_direct.fg, None);
+    }
+
+    #[test]
+    fn descendant_matching_backtracks() {
+        let sheet = SelectorSheet::parse("A B C { fg: red; }").unwrap();
+        let path = [
+            StyleNode::new("A"),
+            StyleNode::new("B"),
+            StyleNode::new("X"),
+            StyleNode::new("B"),
+            StyleNode::new("C"),
+        ];
+        assert!(sheet.resolve(&path).fg.is_some());
+        let path = [
+            StyleNode::new("B"),
+            StyleNode::new("A"),
+            StyleNode::new("C"),
+        ];
+        assert!(sheet.resolve(&path).fg.is_none());
+    }
+
+    #[test]
+    fn deep_descendant_chains_stay_linear() {
+        // Uncached, the failing `Z` forces every way of placing twelve `A`s
+        // among sixty ancestors to be tried.
+        let selector = Selector::parse(&format!("Z {}B", "A ".repeat(12))).unwrap();
+        let mut path = vec![StyleNode::new("A"); 60];
+        path.push(StyleNode::new("B"));
+        assert!(!selector.matches(&path));
+        path[0] = StyleNode::new("Z");
+        assert!(selector.matches(&path));
+    }
+
+    #[test]
+    fn style_path_scopes_children() {
+        let sheet = SelectorSheet::parse("Dialog Button { fg: #ff0000; }").unwrap();
+        let mut path = StylePath::new();
+        assert_eq!(path.resolve(&sheet, StyleNode::new("Button")).fg, None);
+        path.push(StyleNode::new("Dialog"));
+        assert_eq!(path.resolve(&sheet, StyleNode::new("Button")).fg, Some(RED));
+        assert_eq!(path.nodes().len(), 1);
+        path.pop();
+        assert!(path.nodes().is_empty());
+    }
+
+    #[test]
+    fn selector_lists_comments_and_display() {
+        let sheet = SelectorSheet::parse(
+            "/* header */ Button:focus, #ok.primary > *:hover /* x */ { fg: red }",
+        )
+        .unwrap();
+        assert_eq!(sheet.len(), 2);
+        assert_eq!(sheet.rules()[0].selector.to_string(), "Button:focus");
+        assert_eq!(
+            sheet.rules()[1].selector.to_string(),
+            "#ok.primary > :hover"
+        );
+    }
+
+    #[test]
+    fn errors_report_position() {
+        let err = SelectorSheet::parse("Button {\n  fg: #ff0000;\n  padding: 1;\n}").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.to_string(), "3:3: unknown property `padding`");
+
+        let err = SelectorSheet::parse("Button:pressed { }").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 7));
+
+        let err = SelectorSheet::parse("Button { fg: #12345; }").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 14));
+
+        let err = SelectorSheet::parse("Button { attrs: bold sparkly }").unwrap_err();
+        assert!(err.message.contains("sparkly"));
+
+        assert!(SelectorSheet::parse("Button { fg: red").is_err());
+        assert!(SelectorSheet::parse("{ fg: red }").is_err());
+    }
+
+    #[test]
+    fn from_stylesheet_maps_names_to_classes() {
+        let named = StyleSheet::new();
+        named.define("error", Style::new().fg(RED));
+        let sheet = SelectorSheet::from_stylesheet(&named);
+        let style = sheet.query(StyleNode::new("Paragraph").classes(&["error"]));
+        assert_eq!(style.fg, Some(RED));
+        assert_eq!(sheet.rules()[0].selector.to_string(), ".error");
+    }
+
+    #[test]
+    fn load_reads_file() {
+        let path = std::env::temp_dir().join(format!("ftui-selector-{}.tss", std::process::id()));
+        std::fs::write(&path, "Button { bg: blue; }").unwrap();
+        let sheet = SelectorSheet::load(&path).unwrap();
+        assert!(sheet.query(StyleNode::new("Button")).bg.is_some());
+        std::fs::remove_file(&path).ok();
+        assert!(SelectorSheet::load(&path).is_err());
+    }
+}
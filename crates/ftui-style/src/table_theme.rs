@@ -49,6 +49,21 @@ pub enum TablePresetId {
     TerminalClassic,
 }
 
+impl TablePresetId {
+    /// Every preset, in declaration order.
+    pub const ALL: [TablePresetId; 9] = [
+        TablePresetId::Aurora,
+        TablePresetId::Graphite,
+        TablePresetId::Neon,
+        TablePresetId::Slate,
+        TablePresetId::Solar,
+        TablePresetId::Orchard,
+        TablePresetId::Paper,
+        TablePresetId::Midnight,
+        TablePresetId::TerminalClassic,
+    ];
+}
+
 /// Semantic table sections.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
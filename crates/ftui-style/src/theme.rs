@@ -23,6 +23,26 @@
 
 use crate::color::Color;
 use std::env;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static THEME_EPOCH: AtomicU64 = AtomicU64::new(0);
+
+/// Current theme epoch.
+///
+/// The epoch changes whenever the active theme's colors change at runtime
+/// (theme swaps, light/dark switches, cross-fade frames). Caches that bake
+/// colors into their output compare it against the epoch they were filled at.
+#[must_use]
+pub fn theme_epoch() -> u64 {
+    THEME_EPOCH.load(Ordering::Relaxed)
+}
+
+/// Advance the theme epoch, invalidating color-dependent caches.
+///
+/// Returns the new epoch.
+pub fn bump_theme_epoch() -> u64 {
+    THEME_EPOCH.fetch_add(1, Ordering::Relaxed).wrapping_add(1)
+}
 
 /// An adaptive color that can change based on light/dark mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -411,6 +431,19 @@ impl Default for ThemeBuilder {
 pub mod themes {
     use super::*;
 
+    type Constructor = fn() -> Theme;
+
+    /// Every built-in theme, keyed by the name theme files use for it.
+    pub const ALL: [(&str, Constructor); 7] = [
+        ("dark", dark),
+        ("light", light),
+        ("nord", nord),
+        ("dracula", dracula),
+        ("solarized_dark", solarized_dark),
+        ("solarized_light", solarized_light),
+        ("monokai", monokai),
+    ];
+
     /// Default sensible theme (dark mode).
     #[must_use]
     pub fn default() -> Theme {
@@ -589,8 +622,9 @@ pub mod themes {
             surface: AdaptiveColor::fixed(Color::rgb(238, 232, 213)),    // Base2
             overlay: AdaptiveColor::fixed(Color::rgb(253, 246, 227)),    // Base3
 
-            text: AdaptiveColor::fixed(Color::rgb(101, 123, 131)), // Base00
-            text_muted: AdaptiveColor::fixed(Color::rgb(88, 110, 117)), // Base01
+            // Base00 body text is only 4.1:1 on Base3; use Base01 to meet WCAG AA.
+            text: AdaptiveColor::fixed(Color::rgb(88, 110, 117)), // Base01
+            text_muted: AdaptiveColor::fixed(Color::rgb(101, 123, 131)), // Base00
             text_subtle: AdaptiveColor::fixed(Color::rgb(147, 161, 161)), // Base1
 
             success: AdaptiveColor::fixed(Color::rgb(133, 153, 0)), // Green
@@ -0,0 +1,724 @@
+#![forbid(unsafe_code)]
+
+//! Theme accessibility auditing and contrast repair.
+//!
+//! [`ThemeAudit`] checks every foreground/background pairing a [`Theme`] or
+//! [`TableTheme`] implies against WCAG 2.x contrast thresholds, optionally
+//! under simulated color-vision deficiencies, and can repair failures by
+//! shifting foreground lightness in OKLCH (hue and chroma are preserved).
+//!
+//! # Example
+//! ```
+//! use ftui_style::theme::themes;
+//! use ftui_style::theme_audit::{ThemeAudit, WcagLevel};
+//!
+//! let audit = ThemeAudit::new(WcagLevel::Aa);
+//! let report = audit.audit_theme("dark", &themes::dark(), true);
+//! for failure in &report.failures {
+//!     eprintln!("{failure}");
+//! }
+//!
+//! let repaired = audit.repair_theme(&themes::dark(), true);
+//! assert!(audit.audit_theme("dark", &repaired, true).is_passing());
+//! ```
+
+use std::fmt;
+
+use crate::color::{Color, Oklab, Rgb, contrast_ratio, linear_to_srgb, srgb_to_linear};
+use crate::style::Style;
+use crate::table_theme::TableTheme;
+use crate::theme::{AdaptiveColor, Theme};
+use ftui_render::cell::PackedRgba;
+
+/// WCAG conformance level to audit against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WcagLevel {
+    /// Level AA (4.5:1 text, 3:1 large text and UI components).
+    #[default]
+    Aa,
+    /// Level AAA (7:1 text, 4.5:1 large text; UI components stay at 3:1).
+    Aaa,
+}
+
+/// What a foreground is used for, which determines its required contrast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContrastRequirement {
+    /// Body text and status messages.
+    Text,
+    /// Large or de-emphasized text (hints, placeholders).
+    LargeText,
+    /// Non-text UI components such as focus rings and scrollbar thumbs.
+    NonText,
+}
+
+impl ContrastRequirement {
+    /// Minimum contrast ratio at the given level.
+    #[must_use]
+    pub const fn min_ratio(self, level: WcagLevel) -> f64 {
+        match (self, level) {
+            (Self::Text, WcagLevel::Aa) => 4.5,
+            (Self::Text, WcagLevel::Aaa) => 7.0,
+            (Self::LargeText, WcagLevel::Aa) => 3.0,
+            (Self::LargeText, WcagLevel::Aaa) => 4.5,
+            (Self::NonText, _) => 3.0,
+        }
+    }
+}
+
+/// Color vision simulated during an audit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorVision {
+    /// Typical trichromatic vision.
+    Normal,
+    /// No functioning L (red) cones.
+    Protanopia,
+    /// No functioning M (green) cones.
+    Deuteranopia,
+    /// No functioning S (blue) cones.
+    Tritanopia,
+}
+
+impl ColorVision {
+    /// All simulated visions, normal first.
+    pub const ALL: [Self; 4] = [
+        Self::Normal,
+        Self::Protanopia,
+        Self::Deuteranopia,
+        Self::Tritanopia,
+    ];
+
+    /// Short lowercase name.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Normal => "normal",
+            Self::Protanopia => "protanopia",
+            Self::Deuteranopia => "deuteranopia",
+            Self::Tritanopia => "tritanopia",
+        }
+    }
+
+    /// Simulate how `rgb` appears with this color vision.
+    ///
+    /// Uses the Machado, Oliveira & Fernandes (2009) full-severity matrices
+    /// in linear RGB.
+    #[must_use]
+    pub fn simulate(self, rgb: Rgb) -> Rgb {
+        let matrix: [[f64; 3]; 3] = match self {
+            Self::Normal => return rgb,
+            Self::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            Self::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            Self::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+        };
+        let lin = [rgb.r, rgb.g, rgb.b].map(|c| srgb_to_linear(f64::from(c) / 255.0));
+        let [r, g, b] = matrix.map(|row| {
+            let c = row[0] * lin[0] + row[1] * lin[1] + row[2] * lin[2];
+            (linear_to_srgb(c) * 255.0).round() as u8
+        });
+        Rgb::new(r, g, b)
+    }
+}
+
+impl fmt::Display for ColorVision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A pairing that fell short of its required contrast.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditFailure {
+    /// Human-readable pairing, e.g. `"text_muted on surface"`.
+    pub pair: String,
+    /// Color vision the pairing was checked under.
+    pub vision: ColorVision,
+    /// Foreground color as seen under `vision`.
+    pub fg: Rgb,
+    /// Background color as seen under `vision`.
+    pub bg: Rgb,
+    /// Measured contrast ratio (under `vision`).
+    pub ratio: f64,
+    /// Minimum contrast ratio the pairing must reach.
+    pub required: f64,
+}
+
+impl fmt::Display for AuditFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {:.2}:1 < {:.1}:1",
+            self.pair, self.ratio, self.required
+        )?;
+        if self.vision != ColorVision::Normal {
+            write!(f, " ({})", self.vision)?;
+        }
+        Ok(())
+    }
+}
+
+/// Result of auditing one theme.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AuditReport {
+    /// Name of the audited theme.
+    pub subject: String,
+    /// Number of pairings checked (per vision).
+    pub checked: usize,
+    /// Pairings that fell short, across all audited visions.
+    pub failures: Vec<AuditFailure>,
+}
+
+impl AuditReport {
+    /// Whether every pairing met its requirement.
+    #[must_use]
+    pub fn is_passing(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Failures under a specific color vision.
+    pub fn failures_for(&self, vision: ColorVision) -> impl Iterator<Item = &AuditFailure> {
+        self.failures.iter().filter(move |f| f.vision == vision)
+    }
+}
+
+impl fmt::Display for AuditReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} pairings, {} failures",
+            self.subject,
+            self.checked,
+            self.failures.len()
+        )?;
+        for failure in &self.failures {
+            write!(f, "\n  {failure}")?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! theme_slots {
+    ($($slot:ident => $field:ident),* $(,)?) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Slot {
+            $($slot,)*
+        }
+
+        impl Slot {
+            const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$slot => stringify!($field),)*
+                }
+            }
+
+            fn get(self, theme: &Theme) -> AdaptiveColor {
+                match self {
+                    $(Self::$slot => theme.$field,)*
+                }
+            }
+
+            fn get_mut(self, theme: &mut Theme) -> &mut AdaptiveColor {
+                match self {
+                    $(Self::$slot => &mut theme.$field,)*
+                }
+            }
+        }
+    };
+}
+
+theme_slots! {
+    Primary => primary,
+    Secondary => secondary,
+    Accent => accent,
+    Background => background,
+    Surface => surface,
+    Overlay => overlay,
+    Text => text,
+    TextMuted => text_muted,
+    TextSubtle => text_subtle,
+    Success => success,
+    Warning => warning,
+    Error => error,
+    Info => info,
+    BorderFocused => border_focused,
+    SelectionBg => selection_bg,
+    SelectionFg => selection_fg,
+    ScrollbarTrack => scrollbar_track,
+    ScrollbarThumb => scrollbar_thumb,
+}
+
+/// Foreground/background pairings implied by the semantic slots.
+const THEME_PAIRS: &[(Slot, Slot, ContrastRequirement)] = {
+    use ContrastRequirement as Req;
+    use Slot::*;
+    &[
+        (Text, Background, Req::Text),
+        (Text, Surface, Req::Text),
+        (Text, Overlay, Req::Text),
+        (TextMuted, Background, Req::Text),
+        (TextMuted, Surface, Req::Text),
+        (TextSubtle, Background, Req::LargeText),
+        (TextSubtle, Surface, Req::LargeText),
+        (Primary, Background, Req::Text),
+        (Secondary, Background, Req::Text),
+        (Accent, Background, Req::Text),
+        (Success, Background, Req::Text),
+        (Warning, Background, Req::Text),
+        (Error, Background, Req::Text),
+        (Info, Background, Req::Text),
+        (SelectionFg, SelectionBg, Req::Text),
+        (BorderFocused, Background, Req::NonText),
+        (ScrollbarThumb, ScrollbarTrack, Req::NonText),
+    ]
+};
+
+/// Accessibility auditor for themes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThemeAudit {
+    level: WcagLevel,
+    visions: Vec<ColorVision>,
+}
+
+impl Default for ThemeAudit {
+    fn default() -> Self {
+        Self::new(WcagLevel::Aa)
+    }
+}
+
+impl ThemeAudit {
+    /// Audit at `level` under every [`ColorVision`].
+    #[must_use]
+    pub fn new(level: WcagLevel) -> Self {
+        Self {
+            level,
+            visions: ColorVision::ALL.to_vec(),
+        }
+    }
+
+    /// Restrict the simulated color visions.
+    #[must_use]
+    pub fn with_visions(mut self, visions: impl IntoIterator<Item = ColorVision>) -> Self {
+        self.visions = visions.into_iter().collect();
+        self
+    }
+
+    /// Conformance level.
+    #[must_use]
+    pub const fn level(&self) -> WcagLevel {
+        self.level
+    }
+
+    /// Simulated color visions.
+    #[must_use]
+    pub fn visions(&self) -> &[ColorVision] {
+        &self.visions
+    }
+
+    /// Audit the semantic slots of `theme` resolved for `is_dark`.
+    #[must_use]
+    pub fn audit_theme(&self, name: &str, theme: &Theme, is_dark: bool) -> AuditReport {
+        let mut report = self.report(name);
+        for &(fg, bg, requirement) in THEME_PAIRS {
+            let pair = format!("{} on {}", fg.name(), bg.name());
+            self.check(
+                &mut report,
+                pair,
+                resolve_rgb(fg.get(theme), is_dark),
+                resolve_rgb(bg.get(theme), is_dark),
+                requirement,
+            );
+        }
+        report
+    }
+
+    /// Audit a table theme drawn over `base_bg`.
+    ///
+    /// Styles without a background are checked against `base_bg`; the
+    /// border is treated as a UI component, dividers as decoration.
+    #[must_use]
+    pub fn audit_table(&self, name: &str, table: &TableTheme, base_bg: Rgb) -> AuditReport {
+        let mut report = self.report(name);
+        for (section, style, requirement) in table_pairs(table) {
+            if let Some(fg) = style.fg {
+                let bg = style.bg.map_or(base_bg, Rgb::from);
+                let pair = format!("{section} fg on bg");
+                self.check(&mut report, pair, Rgb::from(fg), bg, requirement);
+            }
+        }
+        report
+    }
+
+    /// Return a copy of `theme` with failing foregrounds adjusted for `is_dark`.
+    ///
+    /// Only the variant used in that mode changes; a fixed color that needs
+    /// repair becomes adaptive with the other mode untouched. Foregrounds
+    /// that cannot be fixed by lightness alone are left as they are.
+    #[must_use]
+    pub fn repair_theme(&self, theme: &Theme, is_dark: bool) -> Theme {
+        let mut repaired = theme.clone();
+        let mut done: Vec<Slot> = Vec::new();
+        for &(fg_slot, _, _) in THEME_PAIRS {
+            if done.contains(&fg_slot) {
+                continue;
+            }
+            done.push(fg_slot);
+            let constraints: Vec<(Rgb, f64)> = THEME_PAIRS
+                .iter()
+                .filter(|(fg, _, _)| *fg == fg_slot)
+                .map(|&(_, bg, requirement)| {
+                    (
+                        resolve_rgb(bg.get(theme), is_dark),
+                        requirement.min_ratio(self.level),
+                    )
+                })
+                .collect();
+            let fg = resolve_rgb(fg_slot.get(theme), is_dark);
+            if let Some(fixed) = self.repair_color(fg, &constraints)
+                && fixed != fg
+            {
+                let slot = fg_slot.get_mut(&mut repaired);
+                *slot = with_mode(*slot, is_dark, Color::Rgb(fixed));
+            }
+        }
+        repaired
+    }
+
+    /// Return a copy of `table` with failing foregrounds adjusted.
+    ///
+    /// When a foreground cannot be fixed and the style has its own
+    /// background, that background is adjusted instead.
+    #[must_use]
+    pub fn repair_table(&self, table: &TableTheme, base_bg: Rgb) -> TableTheme {
+        let mut repaired = table.clone();
+        let level = self.level;
+        let fix = |style: &mut Style, requirement: ContrastRequirement| {
+            let Some(fg) = style.fg else {
+                return;
+            };
+            let required = requirement.min_ratio(level);
+            let bg = style.bg.map_or(base_bg, Rgb::from);
+            if let Some(fixed) = self.repair_color(Rgb::from(fg), &[(bg, required)]) {
+                style.fg = Some(with_rgb(fg, fixed));
+            } else if let Some(own_bg) = style.bg
+                && let Some(fixed) = self.repair_color(bg, &[(Rgb::from(fg), required)])
+            {
+                // Foreground is already at an extreme; move the row's own background.
+                style.bg = Some(with_rgb(own_bg, fixed));
+            }
+        };
+        fix(&mut repaired.border, ContrastRequirement::NonText);
+        fix(&mut repaired.header, ContrastRequirement::Text);
+        fix(&mut repaired.row, ContrastRequirement::Text);
+        fix(&mut repaired.row_alt, ContrastRequirement::Text);
+        fix(&mut repaired.row_selected, ContrastRequirement::Text);
+        fix(&mut repaired.row_hover, ContrastRequirement::Text);
+        repaired
+    }
+
+    /// Find the foreground closest in OKLCH lightness to `fg` that meets
+    /// every `(background, min_ratio)` constraint under all audited visions.
+    ///
+    /// Returns `fg` unchanged if it already passes, and `None` if no
+    /// lightness (including pure black or white) satisfies all constraints.
+    #[must_use]
+    pub fn repair_color(&self, fg: Rgb, constraints: &[(Rgb, f64)]) -> Option<Rgb> {
+        if self.passes(fg, constraints) {
+            return Some(fg);
+        }
+        let lab = Oklab::from_rgb(fg);
+        for step in 1..=100 {
+            let delta = step as f32 * 0.01;
+            for l in [lab.l + delta, lab.l - delta] {
+                if !(0.0..=1.0).contains(&l) {
+                    continue;
+                }
+                let candidate = Oklab { l, ..lab }.to_rgb();
+                if self.passes(candidate, constraints) {
+                    return Some(candidate);
+                }
+            }
+        }
+        [Rgb::new(255, 255, 255), Rgb::new(0, 0, 0)]
+            .into_iter()
+            .find(|&extreme| self.passes(extreme, constraints))
+    }
+
+    fn passes(&self, fg: Rgb, constraints: &[(Rgb, f64)]) -> bool {
+        constraints.iter().all(|&(bg, required)| {
+            self.visions
+                .iter()
+                .all(|vision| contrast_ratio(vision.simulate(fg), vision.simulate(bg)) >= required)
+        })
+    }
+
+    fn report(&self, name: &str) -> AuditReport {
+        AuditReport {
+            subject: name.to_string(),
+            ..AuditReport::default()
+        }
+    }
+
+    fn check(
+        &self,
+        report: &mut AuditReport,
+        pair: String,
+        fg: Rgb,
+        bg: Rgb,
+        requirement: ContrastRequirement,
+    ) {
+        report.checked += 1;
+        let required = requirement.min_ratio(self.level);
+        for &vision in &self.visions {
+            let (sim_fg, sim_bg) = (vision.simulate(fg), vision.simulate(bg));
+            let ratio = contrast_ratio(sim_fg, sim_bg);
+            if ratio < required {
+                report.failures.push(AuditFailure {
+                    pair: pair.clone(),
+                    vision,
+                    fg: sim_fg,
+                    bg: sim_bg,
+                    ratio,
+                    required,
+                });
+            }
+        }
+    }
+}
+
+fn table_pairs(table: &TableTheme) -> [(&'static str, &Style, ContrastRequirement); 6] {
+    use ContrastRequirement::{NonText, Text};
+    [
+        ("border", &table.border, NonText),
+        ("header", &table.header, Text),
+        ("row", &table.row, Text),
+        ("row_alt", &table.row_alt, Text),
+        ("row_selected", &table.row_selected, Text),
+        ("row_hover", &table.row_hover, Text),
+    ]
+}
+
+fn with_rgb(color: PackedRgba, rgb: Rgb) -> PackedRgba {
+    PackedRgba::rgba(rgb.r, rgb.g, rgb.b, color.a())
+}
+
+fn resolve_rgb(color: AdaptiveColor, is_dark: bool) -> Rgb {
+    color.resolve(is_dark).to_rgb()
+}
+
+fn with_mode(color: AdaptiveColor, is_dark: bool, replacement: Color) -> AdaptiveColor {
+    let (light, dark) = match color {
+        AdaptiveColor::Fixed(c) => (c, c),
+        AdaptiveColor::Adaptive { light, dark } => (light, dark),
+    };
+    if is_dark {
+        AdaptiveColor::adaptive(light, replacement)
+    } else {
+        AdaptiveColor::adaptive(replacement, dark)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::{ColorProfile, relative_luminance};
+    use crate::table_theme::TablePresetId;
+    use crate::theme::themes;
+
+    /// Built-in themes with whether they are meant for a dark terminal,
+    /// judged by their background.
+    fn builtin_themes() -> Vec<(&'static str, Theme, bool)> {
+        themes::ALL
+            .iter()
+            .map(|&(name, theme)| {
+                let theme = theme();
+                let is_dark = relative_luminance(theme.background.resolve(true).to_rgb()) < 0.5;
+                (name, theme, is_dark)
+            })
+            .collect()
+    }
+
+    fn table_presets() -> Vec<(TablePresetId, TableTheme)> {
+        TablePresetId::ALL
+            .into_iter()
+            .map(|id| {
+                let table = match id {
+                    // Pin the profile so the audit doesn't depend on the host terminal.
+                    TablePresetId::TerminalClassic => {
+                        TableTheme::terminal_classic_for(ColorProfile::TrueColor)
+                    }
+                    _ => TableTheme::preset(id),
+                };
+                (id, table)
+            })
+            .collect()
+    }
+
+    fn table_base(id: TablePresetId) -> Rgb {
+        let theme = if id == TablePresetId::Paper {
+            themes::light()
+        } else {
+            themes::dark()
+        };
+        theme
+            .background
+            .resolve(id != TablePresetId::Paper)
+            .to_rgb()
+    }
+
+    #[test]
+    fn requirement_thresholds() {
+        use ContrastRequirement::*;
+        assert_eq!(Text.min_ratio(WcagLevel::Aa), 4.5);
+        assert_eq!(Text.min_ratio(WcagLevel::Aaa), 7.0);
+        assert_eq!(LargeText.min_ratio(WcagLevel::Aa), 3.0);
+        assert_eq!(LargeText.min_ratio(WcagLevel::Aaa), 4.5);
+        assert_eq!(NonText.min_ratio(WcagLevel::Aaa), 3.0);
+    }
+
+    #[test]
+    fn simulation_preserves_grays_and_dims_red_for_protanopes() {
+        let red = Rgb::new(255, 0, 0);
+        assert_eq!(ColorVision::Normal.simulate(red), red);
+        for vision in ColorVision::ALL {
+            let gray = vision.simulate(Rgb::new(128, 128, 128));
+            for c in [gray.r, gray.g, gray.b] {
+                assert!(c.abs_diff(128) <= 2, "{vision}: {gray:?}");
+            }
+        }
+        let seen = ColorVision::Protanopia.simulate(red);
+        assert!(relative_luminance(seen) < relative_luminance(red));
+    }
+
+    #[test]
+    fn audit_reports_failing_pairs() {
+        let theme = Theme::builder()
+            .background(Color::rgb(20, 20, 20))
+            .text(Color::rgb(70, 70, 70))
+            .build();
+        let report = ThemeAudit::new(WcagLevel::Aa)
+            .with_visions([ColorVision::Normal])
+            .audit_theme("murky", &theme, true);
+        assert_eq!(report.checked, THEME_PAIRS.len());
+        let failure = report
+            .failures
+            .iter()
+            .find(|f| f.pair == "text on background")
+            .expect("text on background should fail");
+        assert!(failure.ratio < 4.5);
+        assert!(report.to_string().starts_with("murky: 17 pairings"));
+        assert!(failure.to_string().starts_with("text on background: "));
+    }
+
+    #[test]
+    fn cvd_audit_catches_red_on_dark() {
+        // Passes for typical vision but collapses for protanopes.
+        let fg = Rgb::new(255, 60, 60);
+        let bg = Rgb::new(30, 30, 30);
+        let normal = ThemeAudit::new(WcagLevel::Aa).with_visions([ColorVision::Normal]);
+        assert_eq!(normal.repair_color(fg, &[(bg, 4.5)]), Some(fg));
+        let protan = ThemeAudit::new(WcagLevel::Aa).with_visions([ColorVision::Protanopia]);
+        assert_ne!(protan.repair_color(fg, &[(bg, 4.5)]), Some(fg));
+    }
+
+    #[test]
+    fn repair_color_shifts_lightness_and_keeps_hue() {
+        let audit = ThemeAudit::new(WcagLevel::Aa);
+        let bg = Rgb::new(30, 30, 40);
+        let fg = Rgb::new(60, 90, 170);
+        let fixed = audit.repair_color(fg, &[(bg, 4.5)]).unwrap();
+        assert!(audit.passes(fixed, &[(bg, 4.5)]));
+
+        let (before, after) = (Oklab::from_rgb(fg), Oklab::from_rgb(fixed));
+        assert!(after.l > before.l);
+        let hue = |lab: Oklab| lab.b.atan2(lab.a);
+        assert!((hue(before) - hue(after)).abs() < 0.15);
+    }
+
+    #[test]
+    fn repair_color_reports_impossible_constraints() {
+        // Mid gray cannot reach 7:1 against anything.
+        let audit = ThemeAudit::new(WcagLevel::Aaa).with_visions([ColorVision::Normal]);
+        let bg = Rgb::new(118, 118, 118);
+        assert_eq!(audit.repair_color(Rgb::new(0, 0, 0), &[(bg, 7.0)]), None);
+    }
+
+    #[test]
+    fn repair_theme_only_touches_active_mode() {
+        let original = Color::rgb(70, 70, 70);
+        let theme = Theme::builder()
+            .background(Color::rgb(20, 20, 20))
+            .text(original)
+            .build();
+        let audit = ThemeAudit::default();
+        let repaired = audit.repair_theme(&theme, true);
+        match repaired.text {
+            AdaptiveColor::Adaptive { light, dark } => {
+                assert_eq!(light, original);
+                assert_ne!(dark, original);
+            }
+            AdaptiveColor::Fixed(_) => panic!("repaired slot should become adaptive"),
+        }
+        assert_eq!(repaired.background, theme.background);
+        assert!(
+            audit
+                .audit_theme("repaired", &repaired, true)
+                .failures
+                .iter()
+                .all(|f| !f.pair.starts_with("text on"))
+        );
+    }
+
+    #[test]
+    fn builtin_themes_audit_and_repair() {
+        let audit = ThemeAudit::new(WcagLevel::Aa);
+        for (name, theme, is_dark) in builtin_themes() {
+            // Body text meets AA (4.5:1) as shipped, before any repair.
+            let report = audit.audit_theme(name, &theme, is_dark);
+            assert!(
+                report
+                    .failures_for(ColorVision::Normal)
+                    .all(|f| f.pair != "text on background"),
+                "{report}"
+            );
+
+            let repaired = audit.repair_theme(&theme, is_dark);
+            let after = audit.audit_theme(name, &repaired, is_dark);
+            assert!(after.is_passing(), "{after}");
+            // The other mode is never altered.
+            assert_eq!(repaired.resolve(!is_dark), theme.resolve(!is_dark));
+        }
+    }
+
+    #[test]
+    fn table_presets_audit_and_repair() {
+        let audit = ThemeAudit::new(WcagLevel::Aa);
+        for (id, table) in table_presets() {
+            let base = table_base(id);
+            let name = format!("{id:?}");
+            let report = audit.audit_table(&name, &table, base);
+            assert_eq!(report.checked, 6);
+            assert!(
+                report
+                    .failures_for(ColorVision::Normal)
+                    .all(|f| f.pair != "row fg on bg"),
+                "{report}"
+            );
+
+            let repaired = audit.repair_table(&table, base);
+            let after = audit.audit_table(&name, &repaired, base);
+            assert!(after.is_passing(), "{after}");
+            assert_eq!(repaired.divider, table.divider);
+        }
+    }
+}
@@ -0,0 +1,582 @@
+#![forbid(unsafe_code)]
+
+//! Theme files: load and save [`Theme`]s as TOML or JSON.
+//!
+//! A theme file names an optional built-in `base` and overrides any of the
+//! semantic slots. Each slot is either a single color or a `{ light, dark }`
+//! pair that maps to [`AdaptiveColor::Adaptive`].
+//!
+//! ```toml
+//! version = 1
+//! name = "Harbor"
+//! base = "nord"
+//!
+//! [colors]
+//! primary = "#5e81ac"
+//! error = "bright-red"
+//! background = { light = "#eceff4", dark = "rgb(46, 52, 64)" }
+//! ```
+//!
+//! Accepted color notations: `#rgb`, `#rrggbb`, `rgb(r, g, b)`, ANSI names
+//! (`red`, `bright-blue`), `ansi256(n)` and `mono(black|white)`.
+//!
+//! [`load_theme_file`] also accepts terminal color schemes (see
+//! [`crate::color_scheme`]) and converts them with [`ColorScheme::to_theme`].
+
+use std::fmt;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+pub use crate::color::{format_color, parse_color};
+use crate::color_scheme::{ColorScheme, ColorSchemeError, ColorSchemeFormat};
+use crate::theme::{AdaptiveColor, Theme, themes};
+
+/// Current theme file format version.
+pub const THEME_FILE_VERSION: u8 = 1;
+
+/// Maximum length of a theme name.
+pub const THEME_FILE_MAX_NAME_LEN: usize = 64;
+
+/// Names accepted by [`ThemeSpec::base`].
+pub const BUILTIN_THEME_NAMES: [&str; themes::ALL.len()] = {
+    let mut names = [""; themes::ALL.len()];
+    let mut i = 0;
+    while i < names.len() {
+        names[i] = themes::ALL[i].0;
+        i += 1;
+    }
+    names
+};
+
+/// Look up a built-in theme by name (`-` and `_` are interchangeable).
+#[must_use]
+pub fn builtin_theme(name: &str) -> Option<Theme> {
+    let name = name.trim().to_ascii_lowercase().replace('-', "_");
+    if name == "default" {
+        return Some(themes::default());
+    }
+    themes::ALL
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+        .map(|(_, theme)| theme())
+}
+
+/// Serializable theme file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThemeSpec {
+    #[serde(default = "default_version")]
+    pub version: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Built-in theme providing slots not listed in `colors`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+    #[serde(default)]
+    pub colors: ThemeColorsSpec,
+}
+
+fn default_version() -> u8 {
+    THEME_FILE_VERSION
+}
+
+/// Per-slot overrides; unset slots come from the base theme.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThemeColorsSpec {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub primary: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secondary: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accent: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub surface: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overlay: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_muted: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_subtle: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub success: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub info: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border_focused: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selection_bg: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selection_fg: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scrollbar_track: Option<ColorSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scrollbar_thumb: Option<ColorSpec>,
+}
+
+/// A slot value: one color, or a light/dark pair.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ColorSpec {
+    Fixed(String),
+    Adaptive(AdaptiveColorSpec),
+}
+
+/// Light/dark color pair.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AdaptiveColorSpec {
+    pub light: String,
+    pub dark: String,
+}
+
+/// Validation error naming the offending key (e.g. `colors.border.dark`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThemeSpecError {
+    pub field: String,
+    pub message: String,
+}
+
+impl ThemeSpecError {
+    fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ThemeSpecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+impl std::error::Error for ThemeSpecError {}
+
+impl ColorSpec {
+    fn from_adaptive(color: AdaptiveColor) -> Self {
+        match color {
+            AdaptiveColor::Fixed(color) => Self::Fixed(format_color(color)),
+            AdaptiveColor::Adaptive { light, dark } => Self::Adaptive(AdaptiveColorSpec {
+                light: format_color(light),
+                dark: format_color(dark),
+            }),
+        }
+    }
+
+    fn to_adaptive(&self, field: &str) -> Result<AdaptiveColor, ThemeSpecError> {
+        let parse = |value: &str, field: String| {
+            parse_color(value).map_err(|message| ThemeSpecError::new(field, message))
+        };
+        match self {
+            Self::Fixed(value) => Ok(AdaptiveColor::Fixed(parse(value, field.to_string())?)),
+            Self::Adaptive(pair) => Ok(AdaptiveColor::Adaptive {
+                light: parse(&pair.light, format!("{field}.light"))?,
+                dark: parse(&pair.dark, format!("{field}.dark"))?,
+            }),
+        }
+    }
+}
+
+/// Applies `$body!(slot)` to every semantic slot.
+macro_rules! for_each_slot {
+    ($body:ident) => {
+        $body!(
+            primary,
+            secondary,
+            accent,
+            background,
+            surface,
+            overlay,
+            text,
+            text_muted,
+            text_subtle,
+            success,
+            warning,
+            error,
+            info,
+            border,
+            border_focused,
+            selection_bg,
+            selection_fg,
+            scrollbar_track,
+            scrollbar_thumb
+        )
+    };
+}
+
+impl ThemeSpec {
+    /// Create a spec listing every slot of `theme`.
+    #[must_use]
+    pub fn from_theme(theme: &Theme) -> Self {
+        macro_rules! colors {
+            ($($slot:ident),*) => {
+                ThemeColorsSpec {
+                    $($slot: Some(ColorSpec::from_adaptive(theme.$slot)),)*
+                }
+            };
+        }
+        Self {
+            version: THEME_FILE_VERSION,
+            name: None,
+            base: None,
+            colors: for_each_slot!(colors),
+        }
+    }
+
+    /// Validate the spec and build the theme.
+    pub fn to_theme(&self) -> Result<Theme, ThemeSpecError> {
+        if self.version != THEME_FILE_VERSION {
+            return Err(ThemeSpecError::new(
+                "version",
+                format!("unsupported version {}", self.version),
+            ));
+        }
+        if let Some(name) = &self.name {
+            if name.trim().is_empty() {
+                return Err(ThemeSpecError::new("name", "must not be empty"));
+            }
+            if name.len() > THEME_FILE_MAX_NAME_LEN {
+                return Err(ThemeSpecError::new(
+                    "name",
+                    format!("exceeds {THEME_FILE_MAX_NAME_LEN} bytes"),
+                ));
+            }
+        }
+        let mut theme = match &self.base {
+            Some(base) => builtin_theme(base).ok_or_else(|| {
+                ThemeSpecError::new(
+                    "base",
+                    format!(
+                        "unknown theme `{base}` (expected one of {})",
+                        BUILTIN_THEME_NAMES.join(", ")
+                    ),
+                )
+            })?,
+            None => themes::default(),
+        };
+        macro_rules! apply {
+            ($($slot:ident),*) => {
+                $(
+                    if let Some(spec) = &self.colors.$slot {
+                        theme.$slot = spec.to_adaptive(concat!("colors.", stringify!($slot)))?;
+                    }
+                )*
+            };
+        }
+        for_each_slot!(apply);
+        Ok(theme)
+    }
+
+    /// Parse a TOML theme file.
+    pub fn from_toml(source: &str) -> Result<Self, ThemeFileError> {
+        toml::from_str(source).map_err(|err| ThemeFileError::Parse(err.to_string()))
+    }
+
+    /// Parse a JSON theme file.
+    pub fn from_json(source: &str) -> Result<Self, ThemeFileError> {
+        serde_json::from_str(source).map_err(|err| ThemeFileError::Parse(err.to_string()))
+    }
+
+    /// Serialize as TOML.
+    pub fn to_toml(&self) -> Result<String, ThemeFileError> {
+        toml::to_string(self).map_err(|err| ThemeFileError::Parse(err.to_string()))
+    }
+
+    /// Serialize as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, ThemeFileError> {
+        serde_json::to_string_pretty(self).map_err(|err| ThemeFileError::Parse(err.to_string()))
+    }
+}
+
+/// Error loading a theme file.
+#[derive(Debug)]
+pub enum ThemeFileError {
+    Io(std::io::Error),
+    /// Syntax or structure error (unknown keys are reported by name).
+    Parse(String),
+    /// The file parsed but a value is invalid.
+    Invalid(ThemeSpecError),
+    /// A terminal color scheme failed to import.
+    Scheme(ColorSchemeError),
+    /// The file extension is not a recognized theme or scheme format.
+    UnknownFormat(String),
+}
+
+impl fmt::Display for ThemeFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "i/o error: {err}"),
+            Self::Parse(message) => write!(f, "parse error: {message}"),
+            Self::Invalid(err) => write!(f, "invalid theme: {err}"),
+            Self::Scheme(err) => write!(f, "invalid color scheme: {err}"),
+            Self::UnknownFormat(path) => write!(f, "unknown theme format: {path}"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Invalid(err) => Some(err),
+            Self::Scheme(err) => Some(err),
+            Self::Parse(_) | Self::UnknownFormat(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ThemeFileError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ThemeSpecError> for ThemeFileError {
+    fn from(err: ThemeSpecError) -> Self {
+        Self::Invalid(err)
+    }
+}
+
+impl From<ColorSchemeError> for ThemeFileError {
+    fn from(err: ColorSchemeError) -> Self {
+        Self::Scheme(err)
+    }
+}
+
+/// A theme loaded from disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadedTheme {
+    /// Name from the file, falling back to the file stem.
+    pub name: String,
+    pub theme: Theme,
+}
+
+/// Load a theme file or terminal color scheme, dispatching on the extension.
+///
+/// - `.json`: [`ThemeSpec`] JSON.
+/// - `.toml`: [`ThemeSpec`] TOML, or an Alacritty config when it has
+///   `[colors.primary]`, `[colors.normal]` or `[colors.bright]` tables.
+/// - `.yaml`/`.yml`, `.itermcolors`, `.conf`: base16, iTerm2 and kitty schemes.
+pub fn load_theme_file(path: impl AsRef<Path>) -> Result<LoadedTheme, ThemeFileError> {
+    let path = path.as_ref();
+    let ext = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let source = std::fs::read_to_string(path)?;
+
+    let spec = match ext.as_str() {
+        "json" => ThemeSpec::from_json(&source)?,
+        "toml" if is_alacritty(&source) => {
+            return Ok(scheme_theme(
+                ColorScheme::from_alacritty_toml(&source)?,
+                stem,
+            ));
+        }
+        "toml" => ThemeSpec::from_toml(&source)?,
+        _ => {
+            let format = ColorSchemeFormat::from_path(path)
+                .ok_or_else(|| ThemeFileError::UnknownFormat(path.display().to_string()))?;
+            return Ok(scheme_theme(ColorScheme::parse(&source, format)?, stem));
+        }
+    };
+    let theme = spec.to_theme()?;
+    Ok(LoadedTheme {
+        name: spec.name.unwrap_or(stem),
+        theme,
+    })
+}
+
+fn is_alacritty(source: &str) -> bool {
+    let Ok(table) = toml::from_str::<toml::Table>(source) else {
+        return false;
+    };
+    table
+        .get("colors")
+        .and_then(toml::Value::as_table)
+        .is_some_and(|colors| {
+            ["primary", "normal", "bright"]
+                .iter()
+                .any(|key| colors.get(*key).is_some_and(toml::Value::is_table))
+        })
+}
+
+fn scheme_theme(scheme: ColorScheme, stem: String) -> LoadedTheme {
+    LoadedTheme {
+        theme: scheme.to_theme(),
+        name: scheme.name.unwrap_or(stem),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::{Ansi16, Color, MonoColor};
+
+    const SAMPLE: &str = r##"
+version = 1
+name = "Harbor"
+base = "nord"
+
+[colors]
+primary = "#5e81ac"
+error = "bright-red"
+info = "ansi256(75)"
+background = { light = "#eceff4", dark = "rgb(46, 52, 64)" }
+"##;
+
+    #[test]
+    fn toml_overrides_base_slots() {
+        let spec = ThemeSpec::from_toml(SAMPLE).unwrap();
+        let theme = spec.to_theme().unwrap();
+        let nord = themes::nord();
+        assert_eq!(
+            theme.primary,
+            AdaptiveColor::fixed(Color::rgb(0x5e, 0x81, 0xac))
+        );
+        assert_eq!(
+            theme.error,
+            AdaptiveColor::fixed(Color::Ansi16(Ansi16::BrightRed))
+        );
+        assert_eq!(theme.info, AdaptiveColor::fixed(Color::Ansi256(75)));
+        assert_eq!(
+            theme.background,
+            AdaptiveColor::adaptive(Color::rgb(0xec, 0xef, 0xf4), Color::rgb(46, 52, 64))
+        );
+        assert_eq!(theme.text, nord.text);
+    }
+
+    #[test]
+    fn invalid_color_names_the_key() {
+        let source = SAMPLE.replace("rgb(46, 52, 64)", "rgb(46, 52)");
+        let err = ThemeSpec::from_toml(&source)
+            .unwrap()
+            .to_theme()
+            .unwrap_err();
+        assert_eq!(err.field, "colors.background.dark");
+
+        let source = SAMPLE.replace("bright-red", "crimson");
+        let err = ThemeSpec::from_toml(&source)
+            .unwrap()
+            .to_theme()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "colors.error: unrecognized color `crimson`"
+        );
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected_by_name() {
+        let source = SAMPLE.replace("primary =", "primry =");
+        let err = ThemeSpec::from_toml(&source).unwrap_err();
+        assert!(err.to_string().contains("primry"), "{err}");
+
+        let err =
+            ThemeSpec::from_json(r##"{"colors": {"text": "#fff"}, "extra": 1}"##).unwrap_err();
+        assert!(err.to_string().contains("extra"), "{err}");
+    }
+
+    #[test]
+    fn base_and_version_are_validated() {
+        let spec = ThemeSpec::from_toml("base = \"zenburn\"").unwrap();
+        assert_eq!(spec.to_theme().unwrap_err().field, "base");
+
+        let spec = ThemeSpec::from_toml("version = 9").unwrap();
+        assert_eq!(spec.to_theme().unwrap_err().field, "version");
+    }
+
+    #[test]
+    fn round_trips_every_builtin_through_toml_and_json() {
+        for name in BUILTIN_THEME_NAMES {
+            let theme = builtin_theme(name).unwrap();
+            let spec = ThemeSpec::from_theme(&theme);
+            let toml = spec.to_toml().unwrap();
+            assert_eq!(
+                ThemeSpec::from_toml(&toml).unwrap().to_theme().unwrap(),
+                theme
+            );
+            let json = spec.to_json().unwrap();
+            assert_eq!(
+                ThemeSpec::from_json(&json).unwrap().to_theme().unwrap(),
+                theme
+            );
+        }
+    }
+
+    #[test]
+    fn color_notation_round_trips() {
+        for color in [
+            Color::rgb(1, 2, 3),
+            Color::Ansi256(200),
+            Color::Ansi16(Ansi16::BrightCyan),
+            Color::Mono(MonoColor::White),
+        ] {
+            assert_eq!(parse_color(&format_color(color)), Ok(color));
+        }
+        assert_eq!(parse_color("#FFF"), Ok(Color::rgb(255, 255, 255)));
+        assert_eq!(
+            parse_color("bright_blue"),
+            Ok(Color::Ansi16(Ansi16::BrightBlue))
+        );
+        assert!(parse_color("rgb(1, 2, 300)").is_err());
+    }
+
+    #[test]
+    fn load_theme_file_dispatches_on_extension() {
+        let dir = std::env::temp_dir().join(format!("ftui-theme-file-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let toml_path = dir.join("harbor.toml");
+        std::fs::write(&toml_path, SAMPLE).unwrap();
+        let loaded = load_theme_file(&toml_path).unwrap();
+        assert_eq!(loaded.name, "Harbor");
+
+        let alacritty_path = dir.join("tomorrow.toml");
+        std::fs::write(
+            &alacritty_path,
+            "[colors.primary]\nbackground = \"#1d1f21\"\nforeground = \"#c5c8c6\"\n",
+        )
+        .unwrap();
+        let loaded = load_theme_file(&alacritty_path).unwrap();
+        assert_eq!(loaded.name, "tomorrow");
+        assert_eq!(
+            loaded.theme.background,
+            AdaptiveColor::fixed(Color::rgb(0x1d, 0x1f, 0x21))
+        );
+
+        let kitty_path = dir.join("plain.conf");
+        std::fs::write(&kitty_path, "color1 #zzzzzz\n").unwrap();
+        assert!(matches!(
+            load_theme_file(&kitty_path),
+            Err(ThemeFileError::Scheme(ref err)) if err.key == "color1"
+        ));
+
+        let unknown = dir.join("theme.ini");
+        std::fs::write(&unknown, "").unwrap();
+        assert!(matches!(
+            load_theme_file(&unknown),
+            Err(ThemeFileError::UnknownFormat(_))
+        ));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,50 @@
+//! The probed terminal palette is process-wide, so these checks live in their
+//! own test binary and run as a single test to avoid racing other caches.
+
+use ftui_style::{
+    Ansi16, Color, ColorCache, ColorProfile, Palette, Rgb, set_terminal_palette, terminal_palette,
+};
+
+#[test]
+fn probed_palette_drives_new_caches() {
+    // A "gruvbox-like" terminal where ANSI blue is a muted teal.
+    let mut probed = [[0u8; 3]; 16];
+    for (index, slot) in probed.iter_mut().enumerate() {
+        let rgb = Palette::xterm().get(index as u8);
+        *slot = [rgb.r, rgb.g, rgb.b];
+    }
+    probed[4] = [69, 133, 136];
+    let palette = Palette::from_probe(probed);
+    assert_eq!(palette.get(4), Rgb::new(69, 133, 136));
+    assert_eq!(palette.get(200), Palette::xterm().get(200));
+
+    let teal = Rgb::new(70, 130, 135);
+    let xterm_result = Color::Rgb(teal).downgrade(ColorProfile::Ansi16);
+    assert!(terminal_palette().is_none());
+    let mut before = ColorCache::new(ColorProfile::Ansi16);
+    assert!(before.palette().is_none());
+
+    set_terminal_palette(Some(palette.clone()));
+    assert_eq!(terminal_palette().as_ref(), Some(&palette));
+
+    // Caches created from now on match against the installed palette.
+    let mut cache = ColorCache::new(ColorProfile::Ansi16);
+    assert_eq!(cache.palette(), Some(&palette));
+    let probed_result = cache.downgrade_rgb(teal);
+    assert_eq!(probed_result, Color::Ansi16(Ansi16::Blue));
+    assert_eq!(
+        probed_result,
+        Color::Rgb(teal).downgrade_with(ColorProfile::Ansi16, &palette)
+    );
+    assert_ne!(probed_result, xterm_result);
+
+    // Existing caches and the free function keep the xterm defaults.
+    assert_eq!(before.downgrade_rgb(teal), xterm_result);
+    assert_eq!(
+        Color::Rgb(teal).downgrade(ColorProfile::Ansi16),
+        xterm_result
+    );
+
+    set_terminal_palette(None);
+    assert!(ColorCache::new(ColorProfile::Ansi16).palette().is_none());
+}
@@ -10,6 +10,8 @@
 //! - [`BidiSegment`] — precomputed BiDi analysis for a text string with O(1)
 //!   visual↔logical index mapping and cursor movement.
 //! - [`BidiRun`] — a contiguous run of characters sharing the same direction.
+//! - [`BidiLine`] — grapheme-level visual layout of one display line, with
+//!   cell positions and visual caret movement for widgets and editors.
 //! - [`Direction`] — LTR or RTL text flow direction.
 //! - [`ParagraphDirection`] — paragraph-level base direction (Auto/Ltr/Rtl).
 //!
@@ -32,7 +34,14 @@
 //!
 //! This module is only available when the `bidi` feature is enabled.
 
+use std::borrow::Cow;
+use std::ops::Range;
+
 use unicode_bidi::{BidiInfo, Level};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::text::{Line, Span};
+use crate::wrap::grapheme_width;
 
 // ---------------------------------------------------------------------------
 // Direction / ParagraphDirection
@@ -364,6 +373,333 @@ impl BidiSegment {
     }
 }
 
+// ---------------------------------------------------------------------------
+// BidiLine
+// ---------------------------------------------------------------------------
+
+/// Grapheme-level visual layout of a single display line.
+///
+/// Unlike [`BidiSegment`], which permutes individual characters, `BidiLine`
+/// moves whole grapheme clusters (a base letter keeps its combining marks),
+/// applies the line-level whitespace rule (UAX#9 L1) and tracks cell widths.
+/// Widgets draw [`visual_order`](Self::visual_order) left to right and use
+/// [`caret_x`](Self::caret_x) / [`caret_right`](Self::caret_right) for the
+/// cursor.
+///
+/// Grapheme indices are logical. Caret positions are logical grapheme
+/// boundaries `0..=len()`; position `p` sits just before grapheme `p`.
+#[derive(Debug, Clone)]
+pub struct BidiLine {
+    text: String,
+    /// Byte range of each grapheme, in logical order.
+    graphemes: Vec<Range<usize>>,
+    /// Resolved level of each grapheme (level of its first character).
+    levels: Vec<Level>,
+    /// Cell width of each grapheme.
+    widths: Vec<usize>,
+    /// Logical grapheme indices in left-to-right display order.
+    visual_to_logical: Vec<usize>,
+    /// Left cell edge of each logical grapheme.
+    cell_x: Vec<usize>,
+    base: Direction,
+}
+
+impl BidiLine {
+    /// Lay out `text` (a single line) with the given paragraph direction.
+    pub fn new(text: &str, direction: ParagraphDirection) -> Self {
+        let graphemes: Vec<Range<usize>> = text
+            .grapheme_indices(true)
+            .map(|(start, g)| start..start + g.len())
+            .collect();
+        let widths = graphemes
+            .iter()
+            .map(|range| grapheme_width(&text[range.clone()]))
+            .collect();
+
+        let (levels, base) = if text.is_empty() {
+            let base = match direction {
+                ParagraphDirection::Rtl => Direction::Rtl,
+                _ => Direction::Ltr,
+            };
+            (Vec::new(), base)
+        } else {
+            let bidi_info = BidiInfo::new(text, para_direction_to_level(direction));
+            let mut byte_levels = bidi_info.levels.clone();
+            for para in &bidi_info.paragraphs {
+                let line = para.range.clone();
+                let reordered = bidi_info.reordered_levels(para, line.clone());
+                byte_levels[line.clone()].copy_from_slice(&reordered[line]);
+            }
+            let base = match bidi_info.paragraphs.first() {
+                Some(para) if para.level.is_rtl() => Direction::Rtl,
+                _ => Direction::Ltr,
+            };
+            let levels = graphemes
+                .iter()
+                .map(|range| byte_levels[range.start])
+                .collect();
+            (levels, base)
+        };
+
+        let visual_to_logical = BidiSegment::compute_visual_order(&levels);
+        let mut line = Self {
+            text: text.to_string(),
+            graphemes,
+            levels,
+            widths,
+            visual_to_logical,
+            cell_x: Vec::new(),
+            base,
+        };
+        line.layout_cells();
+        line
+    }
+
+    /// Override per-grapheme cell widths (e.g. expanded tabs).
+    ///
+    /// `widths` is indexed by logical grapheme; missing entries keep their
+    /// measured width.
+    #[must_use]
+    pub fn with_cell_widths(mut self, widths: impl IntoIterator<Item = usize>) -> Self {
+        for (slot, width) in self.widths.iter_mut().zip(widths) {
+            *slot = width;
+        }
+        self.layout_cells();
+        self
+    }
+
+    /// Number of graphemes on the line.
+    pub fn len(&self) -> usize {
+        self.graphemes.len()
+    }
+
+    /// Whether the line is empty.
+    pub fn is_empty(&self) -> bool {
+        self.graphemes.is_empty()
+    }
+
+    /// The resolved paragraph direction.
+    pub fn base_direction(&self) -> Direction {
+        self.base
+    }
+
+    /// Whether display order differs from logical order anywhere.
+    pub fn is_reordered(&self) -> bool {
+        self.visual_to_logical
+            .iter()
+            .enumerate()
+            .any(|(visual, &logical)| visual != logical)
+    }
+
+    /// Total width in cells.
+    pub fn width(&self) -> usize {
+        self.widths.iter().sum()
+    }
+
+    /// Logical grapheme indices in left-to-right display order.
+    pub fn visual_order(&self) -> &[usize] {
+        &self.visual_to_logical
+    }
+
+    /// The grapheme at a logical index, as stored.
+    pub fn grapheme(&self, logical: usize) -> &str {
+        self.graphemes
+            .get(logical)
+            .map_or("", |range| &self.text[range.clone()])
+    }
+
+    /// The grapheme at a logical index as it should be drawn.
+    ///
+    /// Mirrorable punctuation inside right-to-left runs is swapped for its
+    /// mirror image (UAX#9 L4), so `(` reads as an opening parenthesis.
+    pub fn display_grapheme(&self, logical: usize) -> Cow<'_, str> {
+        let grapheme = self.grapheme(logical);
+        if !self.is_rtl(logical) {
+            return Cow::Borrowed(grapheme);
+        }
+        let mut chars = grapheme.chars();
+        match (chars.next().and_then(mirror_char), chars.next()) {
+            (Some(mirrored), None) => Cow::Owned(mirrored.to_string()),
+            _ => Cow::Borrowed(grapheme),
+        }
+    }
+
+    /// Byte range of a logical grapheme within the line text.
+    pub fn byte_range(&self, logical: usize) -> Range<usize> {
+        self.graphemes
+            .get(logical)
+            .cloned()
+            .unwrap_or(self.text.len()..self.text.len())
+    }
+
+    /// Cell width of a logical grapheme.
+    pub fn cell_width(&self, logical: usize) -> usize {
+        self.widths.get(logical).copied().unwrap_or(0)
+    }
+
+    /// Left cell edge of a logical grapheme.
+    pub fn cell_x(&self, logical: usize) -> usize {
+        self.cell_x
+            .get(logical)
+            .copied()
+            .unwrap_or_else(|| self.width())
+    }
+
+    /// Whether the grapheme at `logical` resolved to a right-to-left level.
+    pub fn is_rtl(&self, logical: usize) -> bool {
+        self.levels.get(logical).is_some_and(|level| level.is_rtl())
+    }
+
+    /// Cell column of the caret at logical position `pos`.
+    ///
+    /// The caret hugs the grapheme it precedes: the left edge of a
+    /// left-to-right grapheme, the right edge of a right-to-left one. At the
+    /// end of the line it trails the last grapheme in that grapheme's
+    /// direction.
+    pub fn caret_x(&self, pos: usize) -> usize {
+        let n = self.len();
+        if n == 0 {
+            return 0;
+        }
+        if pos < n {
+            let x = self.cell_x[pos];
+            if self.is_rtl(pos) {
+                x + self.widths[pos]
+            } else {
+                x
+            }
+        } else {
+            let last = n - 1;
+            let x = self.cell_x[last];
+            if self.is_rtl(last) {
+                x
+            } else {
+                x + self.widths[last]
+            }
+        }
+    }
+
+    /// Caret position one visual step to the left, or `None` at the edge.
+    pub fn caret_left(&self, pos: usize) -> Option<usize> {
+        let stops = self.caret_stops();
+        let idx = stops.iter().position(|&(_, p)| p == pos.min(self.len()))?;
+        idx.checked_sub(1).map(|prev| stops[prev].1)
+    }
+
+    /// Caret position one visual step to the right, or `None` at the edge.
+    pub fn caret_right(&self, pos: usize) -> Option<usize> {
+        let stops = self.caret_stops();
+        let idx = stops.iter().position(|&(_, p)| p == pos.min(self.len()))?;
+        stops.get(idx + 1).map(|&(_, p)| p)
+    }
+
+    /// Caret position whose column is closest to cell `x`.
+    pub fn caret_at_x(&self, x: usize) -> usize {
+        self.caret_stops()
+            .into_iter()
+            .min_by_key(|&(cx, p)| (cx.abs_diff(x), p))
+            .map_or(0, |(_, p)| p)
+    }
+
+    /// Every caret position ordered by column, ties by logical position.
+    ///
+    /// Stepping through this order visits each position exactly once, so
+    /// visual movement is reversible and never gets stuck at run edges.
+    fn caret_stops(&self) -> Vec<(usize, usize)> {
+        let mut stops: Vec<(usize, usize)> =
+            (0..=self.len()).map(|p| (self.caret_x(p), p)).collect();
+        stops.sort_unstable();
+        stops
+    }
+
+    /// Recompute cell edges from widths and visual order.
+    fn layout_cells(&mut self) {
+        self.cell_x = vec![0; self.len()];
+        let mut x = 0;
+        for &logical in &self.visual_to_logical {
+            self.cell_x[logical] = x;
+            x += self.widths[logical];
+        }
+    }
+}
+
+impl Line {
+    /// Reorder this line's spans into display order.
+    ///
+    /// Graphemes keep the style and link of the span they came from;
+    /// neighbouring graphemes from the same span are merged back into one
+    /// span. Wrap first, then reorder each wrapped line with the direction
+    /// of the whole paragraph (see [`paragraph_level`]).
+    #[must_use]
+    pub fn to_visual(&self, direction: ParagraphDirection) -> Line {
+        let mut text = String::new();
+        let mut span_starts = Vec::with_capacity(self.spans().len());
+        for span in self.spans() {
+            span_starts.push(text.len());
+            text.push_str(span.as_str());
+        }
+
+        let layout = BidiLine::new(&text, direction);
+        if !(0..layout.len()).any(|i| layout.is_rtl(i)) {
+            return self.clone();
+        }
+
+        let span_of = |byte: usize| span_starts.partition_point(|&start| start <= byte) - 1;
+        let mut out: Vec<Span<'static>> = Vec::new();
+        let mut current: Option<usize> = None;
+        for &logical in layout.visual_order() {
+            let span_idx = span_of(layout.byte_range(logical).start);
+            let grapheme = layout.display_grapheme(logical);
+            match out.last_mut() {
+                Some(last) if current == Some(span_idx) => {
+                    last.content.to_mut().push_str(&grapheme)
+                }
+                _ => {
+                    let source = &self.spans()[span_idx];
+                    out.push(Span {
+                        content: Cow::Owned(grapheme.into_owned()),
+                        style: source.style,
+                        link: source.link.clone(),
+                    });
+                    current = Some(span_idx);
+                }
+            }
+        }
+        Line::from_spans(out)
+    }
+}
+
+/// Mirror image of a paired punctuation character (Bidi_Mirrored subset).
+fn mirror_char(c: char) -> Option<char> {
+    Some(match c {
+        '(' => ')',
+        ')' => '(',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '<' => '>',
+        '>' => '<',
+        '«' => '»',
+        '»' => '«',
+        '‹' => '›',
+        '›' => '‹',
+        '\u{2045}' => '\u{2046}',
+        '\u{2046}' => '\u{2045}',
+        '\u{2264}' => '\u{2265}',
+        '\u{2265}' => '\u{2264}',
+        '\u{3008}' => '\u{3009}',
+        '\u{3009}' => '\u{3008}',
+        '\u{300A}' => '\u{300B}',
+        '\u{300B}' => '\u{300A}',
+        '\u{300C}' => '\u{300D}',
+        '\u{300D}' => '\u{300C}',
+        '\u{3010}' => '\u{3011}',
+        '\u{3011}' => '\u{3010}',
+        _ => return None,
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Standalone utility functions (pre-existing API preserved)
 // ---------------------------------------------------------------------------
@@ -1012,4 +1348,136 @@ mod tests {
         assert_eq!(seg.visual_pos(99), 99);
         assert_eq!(seg.logical_pos(99), 99);
     }
+
+    // --- BidiLine tests ---
+
+    const ALEF: &str = "\u{05D0}";
+    const BET: &str = "\u{05D1}";
+    const GIMEL: &str = "\u{05D2}";
+
+    fn visual(layout: &BidiLine) -> String {
+        layout
+            .visual_order()
+            .iter()
+            .map(|&i| layout.display_grapheme(i))
+            .collect()
+    }
+
+    #[test]
+    fn line_ltr_is_identity() {
+        let layout = BidiLine::new("abc", ParagraphDirection::Auto);
+        assert!(!layout.is_reordered());
+        assert_eq!(layout.visual_order(), &[0, 1, 2]);
+        for p in 0..=3 {
+            assert_eq!(layout.caret_x(p), p);
+        }
+    }
+
+    #[test]
+    fn line_mixed_order_and_carets() {
+        let text = format!("ab {ALEF}{BET}{GIMEL}");
+        let layout = BidiLine::new(&text, ParagraphDirection::Auto);
+        assert_eq!(layout.base_direction(), Direction::Ltr);
+        assert_eq!(layout.visual_order(), &[0, 1, 2, 5, 4, 3]);
+        let carets: Vec<usize> = (0..=6).map(|p| layout.caret_x(p)).collect();
+        assert_eq!(carets, vec![0, 1, 2, 6, 5, 4, 3]);
+    }
+
+    #[test]
+    fn line_caret_walks_visually() {
+        let text = format!("ab {ALEF}{BET}{GIMEL}");
+        let layout = BidiLine::new(&text, ParagraphDirection::Auto);
+        let mut pos = 2;
+        let mut path = vec![pos];
+        while let Some(next) = layout.caret_right(pos) {
+            pos = next;
+            path.push(pos);
+        }
+        // Past the space the caret jumps to the visual left of the RTL run
+        // (logical end) and walks back to its start.
+        assert_eq!(path, vec![2, 6, 5, 4, 3]);
+        assert_eq!(layout.caret_left(0), None);
+    }
+
+    #[test]
+    fn line_caret_steps_are_reversible() {
+        let texts = [
+            format!("{ALEF}{BET} abc {GIMEL}"),
+            format!("abc {ALEF}{BET} 123 def"),
+            format!("{ALEF}(x){BET}"),
+            String::new(),
+        ];
+        for text in &texts {
+            let layout = BidiLine::new(text, ParagraphDirection::Auto);
+            for p in 0..=layout.len() {
+                if let Some(next) = layout.caret_right(p) {
+                    assert_eq!(layout.caret_left(next), Some(p), "text={text:?} p={p}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn line_keeps_graphemes_intact() {
+        // Alef with qamats (combining) stays one cluster when reversed.
+        let text = format!("{ALEF}\u{05B8}{BET}");
+        let layout = BidiLine::new(&text, ParagraphDirection::Auto);
+        assert_eq!(layout.len(), 2);
+        assert_eq!(visual(&layout), format!("{BET}{ALEF}\u{05B8}"));
+        assert_eq!(layout.width(), 2);
+    }
+
+    #[test]
+    fn line_mirrors_brackets_in_rtl_runs() {
+        let text = format!("{ALEF}({BET})");
+        let layout = BidiLine::new(&text, ParagraphDirection::Auto);
+        assert_eq!(visual(&layout), format!("({BET}){ALEF}"));
+    }
+
+    #[test]
+    fn line_wide_graphemes_and_custom_widths() {
+        let layout = BidiLine::new("a\tb", ParagraphDirection::Ltr).with_cell_widths([1, 4, 1]);
+        assert_eq!(layout.width(), 6);
+        assert_eq!(layout.cell_x(2), 5);
+        assert_eq!(layout.caret_at_x(5), 2);
+    }
+
+    #[test]
+    fn line_empty() {
+        let layout = BidiLine::new("", ParagraphDirection::Rtl);
+        assert!(layout.is_empty());
+        assert_eq!(layout.base_direction(), Direction::Rtl);
+        assert_eq!(layout.caret_x(0), 0);
+        assert_eq!(layout.caret_left(0), None);
+        assert_eq!(layout.caret_right(0), None);
+    }
+
+    #[test]
+    fn styled_line_to_visual_keeps_span_styles() {
+        use ftui_style::Style;
+
+        let bold = Style::new().bold();
+        let line = Line::from_spans([
+            Span::styled("ab ", bold),
+            Span::raw(format!("{ALEF}{BET}")),
+            Span::styled(GIMEL, bold),
+        ]);
+        let out = line.to_visual(ParagraphDirection::Ltr);
+        let parts: Vec<(&str, Option<Style>)> =
+            out.spans().iter().map(|s| (s.as_str(), s.style)).collect();
+        assert_eq!(
+            parts,
+            vec![
+                ("ab ", Some(bold)),
+                (GIMEL, Some(bold)),
+                (&*format!("{BET}{ALEF}"), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn styled_line_to_visual_ltr_is_unchanged() {
+        let line = Line::raw("plain text");
+        assert_eq!(line.to_visual(ParagraphDirection::Auto), line);
+    }
 }
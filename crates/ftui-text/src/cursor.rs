@@ -4,6 +4,10 @@
 //!
 //! Provides grapheme-aware cursor movement and mapping between logical
 //! positions (line + grapheme) and visual columns (cell width).
+//!
+//! For multi-cursor editing, [`Caret`] holds a cursor as byte offsets,
+//! [`merge_carets`] folds overlapping carets together, and
+//! [`CursorNavigator::column_carets`] lays out a rectangular selection.
 
 use crate::rope::Rope;
 use crate::wrap::{display_width, graphemes};
@@ -33,6 +37,76 @@ impl CursorPosition {
     }
 }
 
+/// A cursor (with optional selection) as document byte offsets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Caret {
+    /// Byte offset where the selection started (equal to `head` when bare).
+    pub anchor: usize,
+    /// Byte offset of the cursor itself.
+    pub head: usize,
+    /// Whether this is the primary cursor.
+    pub primary: bool,
+}
+
+impl Caret {
+    /// Create a caret from explicit offsets.
+    #[must_use]
+    pub const fn new(anchor: usize, head: usize, primary: bool) -> Self {
+        Self {
+            anchor,
+            head,
+            primary,
+        }
+    }
+
+    /// Start of the selected range.
+    #[must_use]
+    pub fn start(&self) -> usize {
+        self.anchor.min(self.head)
+    }
+
+    /// End of the selected range.
+    #[must_use]
+    pub fn end(&self) -> usize {
+        self.anchor.max(self.head)
+    }
+}
+
+/// Sort carets into document order and merge overlapping ones.
+///
+/// Overlapping selections, and bare carets touching a selection edge or
+/// each other, become one caret that keeps the first one's direction. A
+/// merged caret is primary if any part was; if none was, the last caret is
+/// made primary.
+#[must_use]
+pub fn merge_carets(mut carets: Vec<Caret>) -> Vec<Caret> {
+    carets.sort_by_key(|c| (c.start(), c.end()));
+    let mut merged: Vec<Caret> = Vec::with_capacity(carets.len());
+    for caret in carets {
+        if let Some(last) = merged.last_mut() {
+            let (start, end) = (last.start(), last.end());
+            let touching = caret.start() == end && (caret.start() == caret.end() || start == end);
+            if caret.start() < end || caret.start() == start || touching {
+                let end = end.max(caret.end());
+                let primary = last.primary || caret.primary;
+                *last = if last.anchor <= last.head {
+                    Caret::new(start, end, primary)
+                } else {
+                    Caret::new(end, start, primary)
+                };
+                continue;
+            }
+        }
+        merged.push(caret);
+    }
+    if !merged.iter().any(|c| c.primary)
+        && let Some(last) = merged.last_mut()
+    {
+        last.primary = true;
+    }
+    merged
+}
+
 /// Cursor navigation helper for rope-backed text.
 #[derive(Debug, Clone, Copy)]
 pub struct CursorNavigator<'a> {
@@ -136,6 +210,61 @@ impl<'a> CursorNavigator<'a> {
         self.from_line_grapheme(pos.line + 1, 0)
     }
 
+    /// Move cursor one step to the left in display order.
+    ///
+    /// Within a line this follows the bidi visual order, so the cursor
+    /// walks through right-to-left runs the way they appear on screen. At
+    /// the left edge it leaves the line like a logical move in the
+    /// paragraph's reading direction. Pure left-to-right lines behave
+    /// exactly like [`move_left`](Self::move_left).
+    #[cfg(feature = "bidi")]
+    #[must_use]
+    pub fn move_visual_left(&self, pos: CursorPosition) -> CursorPosition {
+        self.move_visual(pos, false)
+    }
+
+    /// Move cursor one step to the right in display order.
+    ///
+    /// Mirror of [`move_visual_left`](Self::move_visual_left).
+    #[cfg(feature = "bidi")]
+    #[must_use]
+    pub fn move_visual_right(&self, pos: CursorPosition) -> CursorPosition {
+        self.move_visual(pos, true)
+    }
+
+    #[cfg(feature = "bidi")]
+    fn move_visual(&self, pos: CursorPosition, rightward: bool) -> CursorPosition {
+        use crate::bidi::{BidiLine, Direction, ParagraphDirection, has_rtl};
+
+        let pos = self.clamp(pos);
+        let text = line_text(self.rope, pos.line);
+        let text = strip_trailing_newline(&text);
+        if !has_rtl(text) {
+            return if rightward {
+                self.move_right(pos)
+            } else {
+                self.move_left(pos)
+            };
+        }
+
+        let layout = BidiLine::new(text, ParagraphDirection::Auto);
+        let step = if rightward {
+            layout.caret_right(pos.grapheme)
+        } else {
+            layout.caret_left(pos.grapheme)
+        };
+        if let Some(grapheme) = step {
+            return self.from_line_grapheme(pos.line, grapheme);
+        }
+        let next = if rightward == (layout.base_direction() == Direction::Ltr) {
+            self.move_right(self.line_end(pos))
+        } else {
+            self.move_left(self.line_start(pos))
+        };
+        // First or last line: stay put rather than jump within the line.
+        if next.line == pos.line { pos } else { next }
+    }
+
     /// Move cursor up one line, preserving visual column.
     #[must_use]
     pub fn move_up(&self, pos: CursorPosition) -> CursorPosition {
@@ -228,6 +357,32 @@ impl<'a> CursorNavigator<'a> {
         let next = move_word_right_in_line(line_text, pos.grapheme);
         self.from_line_grapheme(pos.line, next)
     }
+
+    /// Carets of a rectangular (column) selection between two corners.
+    ///
+    /// One caret per line from `anchor` to `head`, spanning their visual
+    /// columns; only `line` and `visual_col` of the corners are used, so the
+    /// columns may lie past the end of short lines. Lines ending before the
+    /// left column get no caret, except `head`'s line, whose caret is primary.
+    #[must_use]
+    pub fn column_carets(&self, anchor: CursorPosition, head: CursorPosition) -> Vec<Caret> {
+        let top = anchor.line.min(head.line);
+        let bottom = anchor.line.max(head.line).min(last_line_index(self.rope));
+        let left = anchor.visual_col.min(head.visual_col);
+        (top..=bottom)
+            .filter(|&line| {
+                line == head.line
+                    || self.line_end(self.from_line_grapheme(line, 0)).visual_col >= left
+            })
+            .map(|line| {
+                Caret::new(
+                    self.to_byte_index(self.from_visual_col(line, anchor.visual_col)),
+                    self.to_byte_index(self.from_visual_col(line, head.visual_col)),
+                    line == head.line,
+                )
+            })
+            .collect()
+    }
 }
 
 fn clamp_line_index(rope: &Rope, line: usize) -> usize {
@@ -394,6 +549,86 @@ mod tests {
         assert_eq!(pos.grapheme, 1);
     }
 
+    #[test]
+    fn merge_carets_folds_overlaps_in_order() {
+        let merged = merge_carets(vec![
+            Caret::new(8, 8, false),
+            Caret::new(5, 2, true),
+            Caret::new(4, 6, false),
+            Caret::new(0, 0, false),
+        ]);
+        // The reversed 2..5 selection swallows 4..6 and keeps its direction.
+        assert_eq!(
+            merged,
+            vec![
+                Caret::new(0, 0, false),
+                Caret::new(6, 2, true),
+                Caret::new(8, 8, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_carets_always_has_a_primary() {
+        let merged = merge_carets(vec![Caret::new(3, 3, false), Caret::new(1, 1, false)]);
+        assert_eq!(merged.iter().filter(|c| c.primary).count(), 1);
+        assert!(merged[1].primary);
+        assert!(merge_carets(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn column_carets_span_lines_and_skip_short_ones() {
+        let r = rope("abcd\nx\nabcd");
+        let nav = CursorNavigator::new(&r);
+        let carets = nav.column_carets(CursorPosition::new(0, 0, 2), CursorPosition::new(2, 0, 3));
+        // Line 1 ends before column 2, so only lines 0 and 2 get carets.
+        assert_eq!(
+            carets,
+            vec![Caret::new(2, 3, false), Caret::new(9, 10, true)]
+        );
+    }
+
+    #[cfg(feature = "bidi")]
+    #[test]
+    fn visual_moves_follow_display_order() {
+        // "ab " then Hebrew alef-bet-gimel: right from the space enters the
+        // RTL run at its visual left edge (logical end).
+        let r = rope("ab \u{05D0}\u{05D1}\u{05D2}");
+        let nav = CursorNavigator::new(&r);
+        let mut pos = nav.from_line_grapheme(0, 2);
+        let mut path = Vec::new();
+        for _ in 0..5 {
+            pos = nav.move_visual_right(pos);
+            path.push(pos.grapheme);
+        }
+        assert_eq!(path, vec![6, 5, 4, 3, 3]);
+        assert_eq!(nav.move_visual_left(pos).grapheme, 4);
+    }
+
+    #[cfg(feature = "bidi")]
+    #[test]
+    fn visual_moves_match_logical_for_ltr() {
+        let r = rope("abc\ndef");
+        let nav = CursorNavigator::new(&r);
+        let end = nav.from_line_grapheme(0, 3);
+        assert_eq!(nav.move_visual_right(end), nav.move_right(end));
+        let start = nav.from_line_grapheme(1, 0);
+        assert_eq!(nav.move_visual_left(start), nav.move_left(start));
+    }
+
+    #[cfg(feature = "bidi")]
+    #[test]
+    fn visual_left_edge_of_rtl_line_reads_forward() {
+        // In an RTL paragraph the left edge is the logical end, so moving
+        // further left continues to the next line.
+        let r = rope("\u{05D0}\u{05D1}\nx");
+        let nav = CursorNavigator::new(&r);
+        let end = nav.from_line_grapheme(0, 2);
+        assert_eq!(nav.move_visual_left(end), nav.from_line_grapheme(1, 0));
+        let start = nav.from_line_grapheme(0, 0);
+        assert_eq!(nav.move_visual_right(start), start);
+    }
+
     #[test]
     fn combining_mark_is_single_grapheme() {
         let r = rope("e\u{0301}x");
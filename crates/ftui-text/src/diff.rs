@@ -0,0 +1,610 @@
+#![forbid(unsafe_code)]
+
+//! Sequence and text diffing.
+//!
+//! Computes edit scripts between two sequences with either Myers' O(ND)
+//! algorithm or patience diff, plus word-level diffs for highlighting the
+//! changed parts of a modified line.
+//!
+//! # Example
+//! ```
+//! use ftui_text::diff::{DiffAlgorithm, DiffOp, diff_lines};
+//!
+//! let ops = diff_lines("a\nb\nc\n", "a\nB\nc\n", DiffAlgorithm::Myers);
+//! assert_eq!(
+//!     ops,
+//!     vec![
+//!         DiffOp::Equal { old: 0, new: 0 },
+//!         DiffOp::Delete { old: 1 },
+//!         DiffOp::Insert { new: 1 },
+//!         DiffOp::Equal { old: 2, new: 2 },
+//!     ]
+//! );
+//! ```
+//!
+//! # Invariants
+//!
+//! 1. Ops appear in sequence order: `old` and `new` indices are each
+//!    strictly increasing across the ops that carry them.
+//! 2. Every old index appears exactly once (as `Equal` or `Delete`) and every
+//!    new index exactly once (as `Equal` or `Insert`).
+//! 3. Within a run of changes, all deletions precede all insertions.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Range;
+
+/// Line diff strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiffAlgorithm {
+    /// Myers' shortest edit script.
+    #[default]
+    Myers,
+    /// Patience diff: anchors on lines unique to both sides, then falls back
+    /// to Myers between anchors. Tends to produce more readable diffs for
+    /// code with moved blocks.
+    Patience,
+}
+
+/// One step of an edit script, indexing into the old and new sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiffOp {
+    /// Element present in both sequences.
+    Equal { old: usize, new: usize },
+    /// Element only in the old sequence.
+    Delete { old: usize },
+    /// Element only in the new sequence.
+    Insert { new: usize },
+}
+
+/// Diff two slices.
+#[must_use]
+pub fn diff_slices<T: Eq + Hash>(old: &[T], new: &[T], algorithm: DiffAlgorithm) -> Vec<DiffOp> {
+    let mut ops = Vec::with_capacity(old.len().max(new.len()));
+    match algorithm {
+        DiffAlgorithm::Myers => diff_trimmed(old, new, 0, 0, &mut ops, myers),
+        DiffAlgorithm::Patience => patience(old, new, 0, 0, &mut ops),
+    }
+    normalize(ops)
+}
+
+/// Diff two texts line by line (see [`split_lines`]).
+#[must_use]
+pub fn diff_lines(old: &str, new: &str, algorithm: DiffAlgorithm) -> Vec<DiffOp> {
+    diff_slices(&split_lines(old), &split_lines(new), algorithm)
+}
+
+/// Split text into lines without terminators.
+///
+/// `\n` and `\r\n` both end a line; a trailing terminator does not produce an
+/// extra empty line.
+#[must_use]
+pub fn split_lines(text: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = text
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .collect();
+    if text.is_empty() || text.ends_with('\n') {
+        lines.pop();
+    }
+    lines
+}
+
+/// Changed byte ranges on each side of a word-level diff.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WordDiff {
+    /// Ranges in the old string that were removed or replaced.
+    pub old: Vec<Range<usize>>,
+    /// Ranges in the new string that were inserted or replaced.
+    pub new: Vec<Range<usize>>,
+    /// Fraction of non-whitespace word bytes shared by both sides (`0.0..=1.0`).
+    pub similarity: f32,
+}
+
+/// Word-level diff of two lines.
+///
+/// Words are runs of alphanumerics/underscores, runs of whitespace, or single
+/// punctuation characters. Adjacent changed words are merged into one range.
+#[must_use]
+pub fn diff_words(old: &str, new: &str) -> WordDiff {
+    let old_words = split_words(old);
+    let new_words = split_words(new);
+    let old_tokens: Vec<&str> = old_words.iter().map(|r| &old[r.clone()]).collect();
+    let new_tokens: Vec<&str> = new_words.iter().map(|r| &new[r.clone()]).collect();
+    let ops = diff_slices(&old_tokens, &new_tokens, DiffAlgorithm::Myers);
+
+    let mut result = WordDiff::default();
+    let mut shared = 0usize;
+    for op in ops {
+        match op {
+            DiffOp::Equal { old: i, .. } => {
+                if !old_tokens[i].trim().is_empty() {
+                    shared += old_tokens[i].len();
+                }
+            }
+            DiffOp::Delete { old: i } => push_merged(&mut result.old, old_words[i].clone()),
+            DiffOp::Insert { new: i } => push_merged(&mut result.new, new_words[i].clone()),
+        }
+    }
+    let total = |s: &str| s.len() - s.chars().filter(|c| c.is_whitespace()).count();
+    let denominator = total(old).max(total(new));
+    result.similarity = if denominator == 0 {
+        1.0
+    } else {
+        shared as f32 / denominator as f32
+    };
+    result
+}
+
+/// Byte ranges of the words in `line`.
+fn split_words(line: &str) -> Vec<Range<usize>> {
+    #[derive(PartialEq)]
+    enum Class {
+        Word,
+        Space,
+        Other,
+    }
+    let class = |c: char| {
+        if c.is_alphanumeric() || c == '_' {
+            Class::Word
+        } else if c.is_whitespace() {
+            Class::Space
+        } else {
+            Class::Other
+        }
+    };
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut current: Option<Class> = None;
+    for (i, c) in line.char_indices() {
+        let next = class(c);
+        let joins = match &current {
+            Some(prev) => *prev == next && next != Class::Other,
+            None => true,
+        };
+        if !joins {
+            words.push(start..i);
+            start = i;
+        }
+        current = Some(next);
+    }
+    if start < line.len() {
+        words.push(start..line.len());
+    }
+    words
+}
+
+fn push_merged(ranges: &mut Vec<Range<usize>>, range: Range<usize>) {
+    match ranges.last_mut() {
+        Some(last) if last.end == range.start => last.end = range.end,
+        _ => ranges.push(range),
+    }
+}
+
+/// Strip the common prefix and suffix, then run `inner` on the middle.
+fn diff_trimmed<T: Eq>(
+    old: &[T],
+    new: &[T],
+    old_base: usize,
+    new_base: usize,
+    ops: &mut Vec<DiffOp>,
+    inner: fn(&[T], &[T], usize, usize, &mut Vec<DiffOp>),
+) {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    for i in 0..prefix {
+        ops.push(DiffOp::Equal {
+            old: old_base + i,
+            new: new_base + i,
+        });
+    }
+    inner(
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+        old_base + prefix,
+        new_base + prefix,
+        ops,
+    );
+    for i in 0..suffix {
+        ops.push(DiffOp::Equal {
+            old: old_base + old.len() - suffix + i,
+            new: new_base + new.len() - suffix + i,
+        });
+    }
+}
+
+/// Myers' shortest edit script in linear space.
+///
+/// Searches forward and backward at once for the middle snake of the edit
+/// graph, then recurses on the halves on either side of it, so memory stays
+/// O(N + M) however far apart the inputs are.
+fn myers<T: Eq>(old: &[T], new: &[T], old_base: usize, new_base: usize, ops: &mut Vec<DiffOp>) {
+    let mut frontiers = Frontiers::new(old.len() + new.len());
+    conquer(old, new, old_base, new_base, &mut frontiers, ops);
+}
+
+/// Forward and backward furthest-reaching x per diagonal, reused across
+/// recursion levels.
+struct Frontiers {
+    forward: Vec<usize>,
+    backward: Vec<usize>,
+    offset: isize,
+}
+
+impl Frontiers {
+    fn new(total: usize) -> Self {
+        let max_d = total.div_ceil(2) + 1;
+        Self {
+            forward: vec![0; 2 * max_d + 2],
+            backward: vec![0; 2 * max_d + 2],
+            offset: max_d as isize + 1,
+        }
+    }
+
+    fn slot(&self, k: isize) -> usize {
+        (self.offset + k) as usize
+    }
+}
+
+/// Diff `old` against `new` by splitting at middle snakes.
+fn conquer<T: Eq>(
+    old: &[T],
+    new: &[T],
+    old_base: usize,
+    new_base: usize,
+    frontiers: &mut Frontiers,
+    ops: &mut Vec<DiffOp>,
+) {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    ops.extend((0..prefix).map(|i| DiffOp::Equal {
+        old: old_base + i,
+        new: new_base + i,
+    }));
+
+    let (old_mid, new_mid) = (
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+    );
+    let (ob, nb) = (old_base + prefix, new_base + prefix);
+    if old_mid.is_empty() || new_mid.is_empty() {
+        ops.extend((0..old_mid.len()).map(|i| DiffOp::Delete { old: ob + i }));
+        ops.extend((0..new_mid.len()).map(|j| DiffOp::Insert { new: nb + j }));
+    } else {
+        let (x, y) = middle_snake(old_mid, new_mid, frontiers);
+        conquer(&old_mid[..x], &new_mid[..y], ob, nb, frontiers, ops);
+        conquer(&old_mid[x..], &new_mid[y..], ob + x, nb + y, frontiers, ops);
+    }
+
+    ops.extend((0..suffix).map(|i| DiffOp::Equal {
+        old: old_base + old.len() - suffix + i,
+        new: new_base + new.len() - suffix + i,
+    }));
+}
+
+/// A point `(x, y)` where an optimal edit path of two non-empty sequences
+/// with no common prefix or suffix crosses its middle diagonal band.
+fn middle_snake<T: Eq>(old: &[T], new: &[T], f: &mut Frontiers) -> (usize, usize) {
+    let n = old.len() as isize;
+    let m = new.len() as isize;
+    let delta = n - m;
+    let odd = delta & 1 == 1;
+    let at = |v: &[usize], f: &Frontiers, k: isize| v[f.slot(k)] as isize;
+    let slot1 = f.slot(1);
+    f.forward[slot1] = 0;
+    f.backward[slot1] = 0;
+
+    for d in 0..=(n + m + 1) / 2 {
+        for k in (-d..=d).rev().step_by(2) {
+            let mut x =
+                if k == -d || (k != d && at(&f.forward, f, k - 1) < at(&f.forward, f, k + 1)) {
+                    at(&f.forward, f, k + 1)
+                } else {
+                    at(&f.forward, f, k - 1) + 1
+                };
+            let mut y = x - k;
+            let (x0, y0) = (x, y);
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            let slot = f.slot(k);
+            f.forward[slot] = x as usize;
+            if odd && (k - delta).abs() < d && x + at(&f.backward, f, delta - k) >= n {
+                return (x0 as usize, y0 as usize);
+            }
+        }
+        for k in (-d..=d).rev().step_by(2) {
+            let mut x =
+                if k == -d || (k != d && at(&f.backward, f, k - 1) < at(&f.backward, f, k + 1)) {
+                    at(&f.backward, f, k + 1)
+                } else {
+                    at(&f.backward, f, k - 1) + 1
+                };
+            let mut y = x - k;
+            while x < n && y < m && old[(n - x - 1) as usize] == new[(m - y - 1) as usize] {
+                x += 1;
+                y += 1;
+            }
+            let slot = f.slot(k);
+            f.backward[slot] = x as usize;
+            if !odd && (k - delta).abs() <= d && x + at(&f.forward, f, delta - k) >= n {
+                return ((n - x) as usize, (m - y) as usize);
+            }
+        }
+    }
+    // The searches always meet by d = ceil((n + m) / 2); splitting into a
+    // pure delete and a pure insert is still a valid script.
+    (old.len(), 0)
+}
+
+/// Patience diff: match unique common lines by longest increasing
+/// subsequence and recurse between them.
+fn patience<T: Eq + Hash>(
+    old: &[T],
+    new: &[T],
+    old_base: usize,
+    new_base: usize,
+    ops: &mut Vec<DiffOp>,
+) {
+    diff_trimmed(
+        old,
+        new,
+        old_base,
+        new_base,
+        ops,
+        |old, new, ob, nb, ops| {
+            // The middle has no common prefix/suffix; find unique anchors.
+            let mut counts: HashMap<&T, (usize, usize, usize)> = HashMap::new();
+            for (i, item) in old.iter().enumerate() {
+                let entry = counts.entry(item).or_insert((0, 0, 0));
+                entry.0 += 1;
+                entry.2 = i;
+            }
+            for item in new {
+                if let Some(entry) = counts.get_mut(item) {
+                    entry.1 += 1;
+                }
+            }
+            let mut pairs = Vec::new();
+            for (j, item) in new.iter().enumerate() {
+                if let Some(&(1, 1, i)) = counts.get(item) {
+                    pairs.push((i, j));
+                }
+            }
+            pairs.sort_unstable();
+            let anchors = longest_increasing(&pairs);
+            if anchors.is_empty() {
+                myers(old, new, ob, nb, ops);
+                return;
+            }
+
+            let (mut i0, mut j0) = (0, 0);
+            for (i, j) in anchors {
+                patience(&old[i0..i], &new[j0..j], ob + i0, nb + j0, ops);
+                ops.push(DiffOp::Equal {
+                    old: ob + i,
+                    new: nb + j,
+                });
+                i0 = i + 1;
+                j0 = j + 1;
+            }
+            patience(&old[i0..], &new[j0..], ob + i0, nb + j0, ops);
+        },
+    );
+}
+
+/// Longest chain of pairs increasing in both coordinates (pairs are sorted by
+/// the first coordinate).
+fn longest_increasing(pairs: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    // Patience sorting: tails[k] = index of smallest tail of a chain of length k+1.
+    let mut tails: Vec<usize> = Vec::new();
+    let mut prev: Vec<Option<usize>> = vec![None; pairs.len()];
+    for (idx, &(_, j)) in pairs.iter().enumerate() {
+        let pos = tails.partition_point(|&t| pairs[t].1 < j);
+        if pos > 0 {
+            prev[idx] = Some(tails[pos - 1]);
+        }
+        if pos == tails.len() {
+            tails.push(idx);
+        } else {
+            tails[pos] = idx;
+        }
+    }
+    let mut chain = Vec::with_capacity(tails.len());
+    let mut cursor = tails.last().copied();
+    while let Some(idx) = cursor {
+        chain.push(pairs[idx]);
+        cursor = prev[idx];
+    }
+    chain.reverse();
+    chain
+}
+
+/// Reorder each run of changes so deletions precede insertions.
+fn normalize(ops: Vec<DiffOp>) -> Vec<DiffOp> {
+    let mut out = Vec::with_capacity(ops.len());
+    let mut inserts = Vec::new();
+    for op in ops {
+        match op {
+            DiffOp::Insert { .. } => inserts.push(op),
+            DiffOp::Delete { .. } => out.push(op),
+            DiffOp::Equal { .. } => {
+                out.append(&mut inserts);
+                out.push(op);
+            }
+        }
+    }
+    out.append(&mut inserts);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(old: &[&str], new: &[&str], ops: &[DiffOp]) -> (Vec<String>, Vec<String>) {
+        let mut from_old = Vec::new();
+        let mut from_new = Vec::new();
+        for op in ops {
+            match *op {
+                DiffOp::Equal { old: i, new: j } => {
+                    assert_eq!(old[i], new[j]);
+                    from_old.push(old[i].to_string());
+                    from_new.push(new[j].to_string());
+                }
+                DiffOp::Delete { old: i } => from_old.push(old[i].to_string()),
+                DiffOp::Insert { new: j } => from_new.push(new[j].to_string()),
+            }
+        }
+        (from_old, from_new)
+    }
+
+    fn edit_count(ops: &[DiffOp]) -> usize {
+        ops.iter()
+            .filter(|op| !matches!(op, DiffOp::Equal { .. }))
+            .count()
+    }
+
+    #[test]
+    fn split_lines_handles_terminators() {
+        assert_eq!(split_lines(""), Vec::<&str>::new());
+        assert_eq!(split_lines("a"), vec!["a"]);
+        assert_eq!(split_lines("a\r\nb\n"), vec!["a", "b"]);
+        assert_eq!(split_lines("a\n\n"), vec!["a", ""]);
+    }
+
+    #[test]
+    fn myers_finds_shortest_script() {
+        let old: Vec<&str> = "ABCABBA".split("").filter(|s| !s.is_empty()).collect();
+        let new: Vec<&str> = "CBABAC".split("").filter(|s| !s.is_empty()).collect();
+        let ops = diff_slices(&old, &new, DiffAlgorithm::Myers);
+        assert_eq!(edit_count(&ops), 5);
+        let (o, n) = apply(&old, &new, &ops);
+        assert_eq!(o, old);
+        assert_eq!(n, new);
+    }
+
+    #[test]
+    fn myers_matches_lcs_edit_distance() {
+        // Small alphabet so the inputs share many interleaved symbols.
+        let mut seed = 0x2545_f491_u32;
+        let mut next = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            seed
+        };
+        for _ in 0..200 {
+            let old: Vec<u32> = (0..next() % 24).map(|_| next() % 4).collect();
+            let new: Vec<u32> = (0..next() % 24).map(|_| next() % 4).collect();
+            let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+            for i in (0..old.len()).rev() {
+                for j in (0..new.len()).rev() {
+                    lcs[i][j] = if old[i] == new[j] {
+                        lcs[i + 1][j + 1] + 1
+                    } else {
+                        lcs[i + 1][j].max(lcs[i][j + 1])
+                    };
+                }
+            }
+            let ops = diff_slices(&old, &new, DiffAlgorithm::Myers);
+            assert_eq!(
+                edit_count(&ops),
+                old.len() + new.len() - 2 * lcs[0][0],
+                "{old:?} -> {new:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn myers_handles_large_divergent_input() {
+        let old: Vec<String> = (0..4_000).map(|i| format!("old {i}")).collect();
+        let new: Vec<String> = (0..4_000).map(|i| format!("new {i}")).collect();
+        let ops = diff_slices(&old, &new, DiffAlgorithm::Myers);
+        assert_eq!(ops.len(), 8_000);
+        assert!(
+            ops[..4_000]
+                .iter()
+                .enumerate()
+                .all(|(i, op)| *op == DiffOp::Delete { old: i })
+        );
+        assert!(
+            ops[4_000..]
+                .iter()
+                .enumerate()
+                .all(|(j, op)| *op == DiffOp::Insert { new: j })
+        );
+    }
+
+    #[test]
+    fn both_algorithms_reconstruct_inputs() {
+        let cases = [
+            ("", "x\ny\n"),
+            ("x\ny\n", ""),
+            ("a\nb\nc\nd\n", "a\nc\nd\ne\n"),
+            ("fn a() {}\n\nfn b() {}\n", "fn b() {}\n\nfn a() {}\n"),
+            ("1\n2\n3\n4\n5\n", "1\n2\n3\n4\n5\n"),
+        ];
+        for (old, new) in cases {
+            let (old_lines, new_lines) = (split_lines(old), split_lines(new));
+            for algorithm in [DiffAlgorithm::Myers, DiffAlgorithm::Patience] {
+                let ops = diff_lines(old, new, algorithm);
+                let (o, n) = apply(&old_lines, &new_lines, &ops);
+                assert_eq!(o, old_lines, "{algorithm:?} {old:?} -> {new:?}");
+                assert_eq!(n, new_lines, "{algorithm:?} {old:?} -> {new:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn deletions_precede_insertions() {
+        let ops = diff_lines("a\nx\ny\nb\n", "a\np\nq\nb\n", DiffAlgorithm::Myers);
+        assert_eq!(
+            ops,
+            vec![
+                DiffOp::Equal { old: 0, new: 0 },
+                DiffOp::Delete { old: 1 },
+                DiffOp::Delete { old: 2 },
+                DiffOp::Insert { new: 1 },
+                DiffOp::Insert { new: 2 },
+                DiffOp::Equal { old: 3, new: 3 },
+            ]
+        );
+    }
+
+    #[test]
+    fn patience_anchors_on_unique_lines() {
+        let old = "}\nfn one() {\n}\nfn two() {\n}\n";
+        let new = "}\nfn two() {\n}\nfn one() {\n}\n";
+        let ops = diff_lines(old, new, DiffAlgorithm::Patience);
+        let (o, n) = apply(&split_lines(old), &split_lines(new), &ops);
+        assert_eq!(o, split_lines(old));
+        assert_eq!(n, split_lines(new));
+        assert!(ops.contains(&DiffOp::Equal { old: 3, new: 1 }));
+    }
+
+    #[test]
+    fn word_diff_marks_changed_words() {
+        let diff = diff_words("let x = foo(1);", "let y = foo(2);");
+        assert_eq!(diff.old, vec![4..5, 12..13]);
+        assert_eq!(diff.new, vec![4..5, 12..13]);
+        assert!(diff.similarity > 0.5);
+
+        let diff = diff_words("alpha beta", "alpha gamma delta");
+        assert_eq!(diff.old, vec![6..10]);
+        assert_eq!(diff.new, vec![6..17]);
+
+        assert!(diff_words("abc", "xyz").similarity < 0.01);
+    }
+}
@@ -25,15 +25,36 @@
 //! ed.delete_backward(); // deletes the space
 //! assert_eq!(ed.text(), "helloworld");
 //! ```
+//!
+//! # Multiple cursors
+//!
+//! Secondary cursors are added with [`Editor::add_cursor_above`],
+//! [`Editor::add_cursor_below`], [`Editor::add_next_occurrence`] or
+//! [`Editor::select_rectangle`]. While more than one cursor exists, edits
+//! and movements apply to every cursor, overlapping cursors are merged, and
+//! each edit is a single undo step. [`Editor::cursor`] and
+//! [`Editor::selection`] always describe the primary cursor.
 
-use crate::cursor::{CursorNavigator, CursorPosition};
+use std::collections::VecDeque;
+use std::sync::OnceLock;
+
+use crate::cursor::{Caret, CursorNavigator, CursorPosition, merge_carets};
 use crate::rope::Rope;
+use crate::search::{SearchOptions, SearchPattern, SearchResult, is_word_char, search_exact};
 
 /// A single edit operation for undo/redo.
 #[derive(Debug, Clone)]
 enum EditOp {
-    Insert { byte_offset: usize, text: String },
-    Delete { byte_offset: usize, text: String },
+    Insert {
+        byte_offset: usize,
+        text: String,
+    },
+    Delete {
+        byte_offset: usize,
+        text: String,
+    },
+    /// Operations applied in order and undone as a single step.
+    Group(Vec<EditOp>),
 }
 
 impl EditOp {
@@ -47,10 +68,23 @@ impl EditOp {
                 byte_offset: *byte_offset,
                 text: text.clone(),
             },
+            Self::Group(ops) => Self::Group(ops.iter().rev().map(Self::inverse).collect()),
+        }
+    }
+
+    /// Lowest byte offset the operation touches. Text before it is left
+    /// unchanged, whichever order grouped operations apply in.
+    fn first_byte(&self) -> usize {
+        match self {
+            Self::Insert { byte_offset, .. } | Self::Delete { byte_offset, .. } => *byte_offset,
+            Self::Group(ops) => ops.iter().map(Self::first_byte).min().unwrap_or(0),
         }
     }
 }
 
+/// Number of recent changes remembered for [`Editor::first_changed_line_since`].
+const CHANGE_LOG_LEN: usize = 64;
+
 /// Selection defined by anchor (fixed) and head (moving with cursor).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Selection {
@@ -99,6 +133,22 @@ pub struct Editor {
     current_undo_size: usize,
     /// Maximum size of undo history in bytes (default 10MB).
     max_undo_size: usize,
+    /// Incremented on every content change.
+    revision: u64,
+    /// `(revision, first line touched)` for the most recent changes.
+    changes: VecDeque<(u64, usize)>,
+    /// Contiguous copy of the text, built on demand for this revision.
+    text_cache: OnceLock<String>,
+    /// Secondary cursors in document order (`anchor == head` when bare).
+    extra_cursors: Vec<Selection>,
+    /// Ops collected for an undo group or a multi-cursor edit.
+    recording: Option<Vec<EditOp>>,
+    /// Nesting depth of [`Editor::begin_undo_group`] calls.
+    undo_group_depth: usize,
+    /// Cursor when the outermost undo group began.
+    undo_group_cursor: CursorPosition,
+    /// Set while a multi-cursor edit visits each cursor.
+    fanning_out: bool,
 }
 
 impl Default for Editor {
@@ -120,6 +170,14 @@ impl Editor {
             max_history: 1000,
             current_undo_size: 0,
             max_undo_size: 10 * 1024 * 1024, // 10MB default
+            revision: 0,
+            changes: VecDeque::new(),
+            text_cache: OnceLock::new(),
+            extra_cursors: Vec::new(),
+            recording: None,
+            undo_group_depth: 0,
+            undo_group_cursor: CursorPosition::default(),
+            fanning_out: false,
         }
     }
 
@@ -138,6 +196,14 @@ impl Editor {
             max_history: 1000,
             current_undo_size: 0,
             max_undo_size: 10 * 1024 * 1024,
+            revision: 0,
+            changes: VecDeque::new(),
+            text_cache: OnceLock::new(),
+            extra_cursors: Vec::new(),
+            recording: None,
+            undo_group_depth: 0,
+            undo_group_cursor: CursorPosition::default(),
+            fanning_out: false,
         }
     }
 
@@ -162,12 +228,68 @@ impl Editor {
         self.rope.to_string()
     }
 
+    /// Borrow the full text as one string.
+    ///
+    /// The string is built on first use after each change and reused until
+    /// the next one, so repeated reads between edits are free.
+    #[must_use]
+    pub fn text_str(&self) -> &str {
+        self.text_cache.get_or_init(|| self.rope.to_string())
+    }
+
     /// Get a reference to the underlying rope.
     #[must_use]
     pub fn rope(&self) -> &Rope {
         &self.rope
     }
 
+    /// Content revision, incremented on every change to the text.
+    ///
+    /// Useful for invalidating caches derived from the buffer.
+    #[must_use]
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Lowest line whose text may differ from what it was at `revision`,
+    /// or `None` if nothing changed since.
+    ///
+    /// Lines above the returned one are unchanged, so caches keyed by line
+    /// only need revalidating from there down. Returns `Some(0)` once
+    /// `revision` is older than the short change log.
+    #[must_use]
+    pub fn first_changed_line_since(&self, revision: u64) -> Option<usize> {
+        if revision == self.revision {
+            return None;
+        }
+        let covered = self
+            .changes
+            .front()
+            .is_some_and(|&(oldest, _)| oldest <= revision.wrapping_add(1));
+        if !covered {
+            return Some(0);
+        }
+        self.changes
+            .iter()
+            .filter(|&&(rev, _)| rev > revision)
+            .map(|&(_, line)| line)
+            .min()
+    }
+
+    /// Bump the revision and log the first line about to change at `byte`.
+    fn mark_changed(&mut self, byte: usize) {
+        self.revision = self.revision.wrapping_add(1);
+        self.text_cache.take();
+        let line = self
+            .rope
+            .byte_to_line_col(byte.min(self.rope.len_bytes()))
+            .0;
+        if self.changes.len() == CHANGE_LOG_LEN {
+            self.changes.pop_front();
+        }
+        self.changes.push_back((self.revision, line));
+    }
+
     /// Get the current cursor position.
     #[must_use]
     pub fn cursor(&self) -> CursorPosition {
@@ -179,6 +301,7 @@ impl Editor {
         let nav = CursorNavigator::new(&self.rope);
         self.cursor = nav.clamp(pos);
         self.selection = None;
+        self.extra_cursors.clear();
     }
 
     /// Current selection, if any.
@@ -249,6 +372,13 @@ impl Editor {
         if sanitized.is_empty() {
             return;
         }
+        if self.is_multi_edit() {
+            self.edit_each_cursor(|ed| {
+                ed.insert_text(&sanitized);
+                true
+            });
+            return;
+        }
 
         self.delete_selection_inner();
         let nav = CursorNavigator::new(&self.rope);
@@ -281,6 +411,9 @@ impl Editor {
     ///
     /// Returns `true` if a character was deleted.
     pub fn delete_backward(&mut self) -> bool {
+        if self.is_multi_edit() {
+            return self.edit_each_cursor(Self::delete_backward);
+        }
         if self.delete_selection_inner() {
             return true;
         }
@@ -314,6 +447,9 @@ impl Editor {
     ///
     /// Returns `true` if a character was deleted.
     pub fn delete_forward(&mut self) -> bool {
+        if self.is_multi_edit() {
+            return self.edit_each_cursor(Self::delete_forward);
+        }
         if self.delete_selection_inner() {
             return true;
         }
@@ -348,6 +484,9 @@ impl Editor {
     ///
     /// Returns `true` if any text was deleted.
     pub fn delete_word_backward(&mut self) -> bool {
+        if self.is_multi_edit() {
+            return self.edit_each_cursor(Self::delete_word_backward);
+        }
         if self.delete_selection_inner() {
             return true;
         }
@@ -381,6 +520,9 @@ impl Editor {
     ///
     /// Returns `true` if any text was deleted.
     pub fn delete_to_end_of_line(&mut self) -> bool {
+        if self.is_multi_edit() {
+            return self.edit_each_cursor(Self::delete_to_end_of_line);
+        }
         if self.delete_selection_inner() {
             return true;
         }
@@ -415,8 +557,25 @@ impl Editor {
     // Undo / redo
     // ====================================================================
 
-    /// Push an edit operation onto the undo stack.
+    /// Push an edit operation that is about to be applied onto the undo stack.
+    ///
+    /// Bumps the revision, except while fanning out to several cursors,
+    /// where [`edit_each_cursor`](Self::edit_each_cursor) bumps it once.
     fn push_undo(&mut self, op: EditOp) {
+        if self.fanning_out {
+            self.text_cache.take();
+        } else {
+            self.mark_changed(op.first_byte());
+        }
+        self.record_undo(op);
+    }
+
+    /// Push an already counted edit operation onto the undo stack.
+    fn record_undo(&mut self, op: EditOp) {
+        if let Some(recording) = &mut self.recording {
+            recording.push(op);
+            return;
+        }
         let op_len = op.byte_len();
         self.undo_stack.push((op, self.cursor));
         self.current_undo_size += op_len;
@@ -439,8 +598,50 @@ impl Editor {
         self.redo_stack.clear();
     }
 
+    /// Start grouping edits into a single undo step.
+    ///
+    /// Every edit until the matching [`end_undo_group`](Self::end_undo_group)
+    /// is undone and redone together. Groups may nest; only the outermost
+    /// one creates an undo step. Undo and redo close any open group first.
+    pub fn begin_undo_group(&mut self) {
+        if self.undo_group_depth == 0 {
+            self.undo_group_cursor = self.cursor;
+            self.recording = Some(Vec::new());
+        }
+        self.undo_group_depth += 1;
+    }
+
+    /// Finish an undo group started with [`begin_undo_group`](Self::begin_undo_group).
+    pub fn end_undo_group(&mut self) {
+        if self.undo_group_depth == 0 {
+            return;
+        }
+        self.undo_group_depth -= 1;
+        if self.undo_group_depth > 0 {
+            return;
+        }
+        let ops = self.recording.take().unwrap_or_default();
+        if !ops.is_empty() {
+            let cursor = self.cursor;
+            self.cursor = self.undo_group_cursor;
+            self.record_undo(EditOp::Group(ops));
+            self.cursor = cursor;
+        }
+    }
+
+    /// Close any open undo group so history stays consistent.
+    fn close_undo_group(&mut self) {
+        if self.undo_group_depth > 0 {
+            self.undo_group_depth = 1;
+            self.end_undo_group();
+        }
+    }
+
     /// Undo the last edit operation.
+    ///
+    /// Collapses to the primary cursor.
     pub fn undo(&mut self) -> bool {
+        self.close_undo_group();
         let Some((op, cursor_before)) = self.undo_stack.pop() else {
             return false;
         };
@@ -450,11 +651,13 @@ impl Editor {
         self.redo_stack.push((inverse, self.cursor));
         self.cursor = cursor_before;
         self.selection = None;
+        self.extra_cursors.clear();
         true
     }
 
     /// Redo the last undone operation.
     pub fn redo(&mut self) -> bool {
+        self.close_undo_group();
         let Some((op, cursor_before)) = self.redo_stack.pop() else {
             return false;
         };
@@ -473,6 +676,7 @@ impl Editor {
 
         self.cursor = cursor_before;
         self.selection = None;
+        self.extra_cursors.clear();
         // Move cursor to the correct position after redo
         let nav = CursorNavigator::new(&self.rope);
         self.cursor = nav.clamp(self.cursor);
@@ -481,6 +685,12 @@ impl Editor {
 
     /// Apply an edit operation directly to the rope.
     fn apply_op(&mut self, op: &EditOp) {
+        self.mark_changed(op.first_byte());
+        self.apply_to_rope(op);
+    }
+
+    /// Apply an edit operation to the rope without bumping the revision.
+    fn apply_to_rope(&mut self, op: &EditOp) {
         match op {
             EditOp::Insert { byte_offset, text } => {
                 let char_idx = self.rope.byte_to_char(*byte_offset);
@@ -491,6 +701,11 @@ impl Editor {
                 let end_char = self.rope.byte_to_char(*byte_offset + text.len());
                 self.rope.remove(start_char..end_char);
             }
+            EditOp::Group(ops) => {
+                for op in ops {
+                    self.apply_to_rope(op);
+                }
+            }
         }
     }
 
@@ -529,72 +744,64 @@ impl Editor {
 
     /// Move cursor left by one grapheme.
     pub fn move_left(&mut self) {
-        self.selection = None;
-        let nav = CursorNavigator::new(&self.rope);
-        self.cursor = nav.move_left(self.cursor);
+        self.move_cursors(|nav, pos| nav.move_left(pos));
     }
 
     /// Move cursor right by one grapheme.
     pub fn move_right(&mut self) {
-        self.selection = None;
-        let nav = CursorNavigator::new(&self.rope);
-        self.cursor = nav.move_right(self.cursor);
+        self.move_cursors(|nav, pos| nav.move_right(pos));
+    }
+
+    /// Move cursor left by one grapheme in display (bidi visual) order.
+    #[cfg(feature = "bidi")]
+    pub fn move_visual_left(&mut self) {
+        self.move_cursors(|nav, pos| nav.move_visual_left(pos));
+    }
+
+    /// Move cursor right by one grapheme in display (bidi visual) order.
+    #[cfg(feature = "bidi")]
+    pub fn move_visual_right(&mut self) {
+        self.move_cursors(|nav, pos| nav.move_visual_right(pos));
     }
 
     /// Move cursor up one line.
     pub fn move_up(&mut self) {
-        self.selection = None;
-        let nav = CursorNavigator::new(&self.rope);
-        self.cursor = nav.move_up(self.cursor);
+        self.move_cursors(|nav, pos| nav.move_up(pos));
     }
 
     /// Move cursor down one line.
     pub fn move_down(&mut self) {
-        self.selection = None;
-        let nav = CursorNavigator::new(&self.rope);
-        self.cursor = nav.move_down(self.cursor);
+        self.move_cursors(|nav, pos| nav.move_down(pos));
     }
 
     /// Move cursor left by one word.
     pub fn move_word_left(&mut self) {
-        self.selection = None;
-        let nav = CursorNavigator::new(&self.rope);
-        self.cursor = nav.move_word_left(self.cursor);
+        self.move_cursors(|nav, pos| nav.move_word_left(pos));
     }
 
     /// Move cursor right by one word.
     pub fn move_word_right(&mut self) {
-        self.selection = None;
-        let nav = CursorNavigator::new(&self.rope);
-        self.cursor = nav.move_word_right(self.cursor);
+        self.move_cursors(|nav, pos| nav.move_word_right(pos));
     }
 
     /// Move cursor to start of line.
     pub fn move_to_line_start(&mut self) {
-        self.selection = None;
-        let nav = CursorNavigator::new(&self.rope);
-        self.cursor = nav.line_start(self.cursor);
+        self.move_cursors(|nav, pos| nav.line_start(pos));
     }
 
     /// Move cursor to end of line.
     pub fn move_to_line_end(&mut self) {
-        self.selection = None;
-        let nav = CursorNavigator::new(&self.rope);
-        self.cursor = nav.line_end(self.cursor);
+        self.move_cursors(|nav, pos| nav.line_end(pos));
     }
 
     /// Move cursor to start of document.
     pub fn move_to_document_start(&mut self) {
-        self.selection = None;
-        let nav = CursorNavigator::new(&self.rope);
-        self.cursor = nav.document_start();
+        self.move_cursors(|nav, _| nav.document_start());
     }
 
     /// Move cursor to end of document.
     pub fn move_to_document_end(&mut self) {
-        self.selection = None;
-        let nav = CursorNavigator::new(&self.rope);
-        self.cursor = nav.document_end();
+        self.move_cursors(|nav, _| nav.document_end());
     }
 
     // ====================================================================
@@ -611,6 +818,21 @@ impl Editor {
         self.extend_selection(|nav, pos| nav.move_right(pos));
     }
 
+    /// Extend selection left by one grapheme in display order.
+    ///
+    /// The selection stays a logical range, so across direction runs it
+    /// may highlight non-adjacent cells.
+    #[cfg(feature = "bidi")]
+    pub fn select_visual_left(&mut self) {
+        self.extend_selection(|nav, pos| nav.move_visual_left(pos));
+    }
+
+    /// Extend selection right by one grapheme in display order.
+    #[cfg(feature = "bidi")]
+    pub fn select_visual_right(&mut self) {
+        self.extend_selection(|nav, pos| nav.move_visual_right(pos));
+    }
+
     /// Extend selection up one line.
     pub fn select_up(&mut self) {
         self.extend_selection(|nav, pos| nav.move_up(pos));
@@ -641,6 +863,7 @@ impl Editor {
             head: end,
         });
         self.cursor = end;
+        self.extra_cursors.clear();
     }
 
     /// Clear current selection without moving cursor.
@@ -648,7 +871,7 @@ impl Editor {
         self.selection = None;
     }
 
-    /// Get selected text, if any non-empty selection exists.
+    /// Get selected text of the primary cursor, if any non-empty selection exists.
     #[must_use]
     pub fn selected_text(&self) -> Option<String> {
         let sel = self.selection?;
@@ -664,7 +887,7 @@ impl Editor {
 
     fn extend_selection(
         &mut self,
-        f: impl FnOnce(&CursorNavigator<'_>, CursorPosition) -> CursorPosition,
+        f: impl Fn(&CursorNavigator<'_>, CursorPosition) -> CursorPosition,
     ) {
         let anchor = match self.selection {
             Some(sel) => sel.anchor,
@@ -677,6 +900,532 @@ impl Editor {
             anchor,
             head: new_head,
         });
+        if self.extra_cursors.is_empty() {
+            return;
+        }
+        for sel in &mut self.extra_cursors {
+            sel.head = f(&nav, sel.head);
+        }
+        self.merge_cursors();
+    }
+
+    /// Move every cursor with `f`, clearing selections.
+    fn move_cursors(&mut self, f: impl Fn(&CursorNavigator<'_>, CursorPosition) -> CursorPosition) {
+        let nav = CursorNavigator::new(&self.rope);
+        self.selection = None;
+        self.cursor = f(&nav, self.cursor);
+        if self.extra_cursors.is_empty() {
+            return;
+        }
+        for sel in &mut self.extra_cursors {
+            let head = f(&nav, sel.head);
+            *sel = Selection { anchor: head, head };
+        }
+        self.merge_cursors();
+    }
+
+    /// Select a byte range, placing the cursor at `end`.
+    ///
+    /// Offsets are snapped to grapheme boundaries.
+    pub fn select_byte_range(&mut self, start: usize, end: usize) {
+        let nav = CursorNavigator::new(&self.rope);
+        let anchor = nav.from_byte_index(start);
+        let head = nav.from_byte_index(end);
+        self.cursor = head;
+        self.selection = Some(Selection { anchor, head });
+        self.extra_cursors.clear();
+    }
+
+    // ====================================================================
+    // Multiple cursors
+    // ====================================================================
+
+    /// Number of cursors, including the primary one.
+    #[must_use]
+    pub fn cursor_count(&self) -> usize {
+        1 + self.extra_cursors.len()
+    }
+
+    /// Every cursor as a selection, in document order.
+    ///
+    /// A bare cursor has `anchor == head`. The primary cursor is included.
+    #[must_use]
+    pub fn selections(&self) -> Vec<Selection> {
+        let nav = CursorNavigator::new(&self.rope);
+        let mut all: Vec<Selection> = self
+            .cursor_selections()
+            .into_iter()
+            .map(|(sel, _)| sel)
+            .collect();
+        all.sort_by_key(|sel| sel.byte_range(&nav));
+        all
+    }
+
+    /// Add a cursor at `pos` and make it the primary cursor.
+    pub fn add_cursor(&mut self, pos: CursorPosition) {
+        let nav = CursorNavigator::new(&self.rope);
+        let pos = nav.clamp(pos);
+        self.extra_cursors.push(self.primary_selection());
+        self.cursor = pos;
+        self.selection = None;
+        self.merge_cursors();
+    }
+
+    /// Add a cursor one line above the topmost cursor.
+    ///
+    /// Returns `false` if the topmost cursor is already on the first line.
+    pub fn add_cursor_above(&mut self) -> bool {
+        let top = self
+            .cursor_selections()
+            .into_iter()
+            .map(|(sel, _)| sel.head.line)
+            .min()
+            .unwrap_or(self.cursor.line);
+        if top == 0 {
+            return false;
+        }
+        let nav = CursorNavigator::new(&self.rope);
+        let pos = nav.from_visual_col(top - 1, self.cursor.visual_col);
+        self.add_cursor(pos);
+        true
+    }
+
+    /// Add a cursor one line below the bottommost cursor.
+    ///
+    /// Returns `false` if the bottommost cursor is already on the last line.
+    pub fn add_cursor_below(&mut self) -> bool {
+        let bottom = self
+            .cursor_selections()
+            .into_iter()
+            .map(|(sel, _)| sel.head.line)
+            .max()
+            .unwrap_or(self.cursor.line);
+        if bottom + 1 >= self.line_count() {
+            return false;
+        }
+        let nav = CursorNavigator::new(&self.rope);
+        let pos = nav.from_visual_col(bottom + 1, self.cursor.visual_col);
+        self.add_cursor(pos);
+        true
+    }
+
+    /// Select the next occurrence of the primary selection with a new cursor.
+    ///
+    /// With no selection, the word under the cursor is selected first.
+    /// The search wraps around the end of the buffer and skips occurrences
+    /// that are already selected. Returns `false` if nothing was selected.
+    pub fn add_next_occurrence(&mut self) -> bool {
+        let text = self.text();
+        let nav = CursorNavigator::new(&self.rope);
+        let Some(sel) = self.selection.filter(|sel| !sel.is_empty()) else {
+            let at = nav.to_byte_index(self.cursor);
+            let start = text[..at]
+                .char_indices()
+                .rev()
+                .take_while(|&(_, c)| is_word_char(c))
+                .last()
+                .map_or(at, |(i, _)| i);
+            let end = at
+                + text[at..]
+                    .chars()
+                    .take_while(|&c| is_word_char(c))
+                    .map(char::len_utf8)
+                    .sum::<usize>();
+            if start == end {
+                return false;
+            }
+            let anchor = nav.from_byte_index(start);
+            self.cursor = nav.from_byte_index(end);
+            self.selection = Some(Selection {
+                anchor,
+                head: self.cursor,
+            });
+            self.merge_cursors();
+            return true;
+        };
+        let (start, end) = sel.byte_range(&nav);
+        let selected: Vec<(usize, usize)> = self
+            .cursor_selections()
+            .iter()
+            .map(|(sel, _)| sel.byte_range(&nav))
+            .collect();
+        let matches = search_exact(&text, &text[start..end]);
+        let Some(next) = matches
+            .iter()
+            .filter(|m| m.range.start >= end)
+            .chain(&matches)
+            .find(|m| !selected.contains(&(m.range.start, m.range.end)))
+        else {
+            return false;
+        };
+        self.extra_cursors.push(self.primary_selection());
+        let anchor = nav.from_byte_index(next.range.start);
+        self.cursor = nav.from_byte_index(next.range.end);
+        self.selection = Some(Selection {
+            anchor,
+            head: self.cursor,
+        });
+        self.merge_cursors();
+        true
+    }
+
+    /// Replace all cursors with a rectangular (column) selection.
+    ///
+    /// Creates one selection per line between `anchor` and `head`, spanning
+    /// their visual columns; only `line` and `visual_col` are used, so the
+    /// columns may lie past the end of short lines. Lines ending before the
+    /// left column get no cursor, except `head`'s line, whose cursor becomes
+    /// the primary cursor.
+    pub fn select_rectangle(&mut self, anchor: CursorPosition, head: CursorPosition) {
+        let carets = CursorNavigator::new(&self.rope).column_carets(anchor, head);
+        self.set_carets(carets);
+    }
+
+    /// Remove all secondary cursors, keeping the primary one.
+    pub fn clear_secondary_cursors(&mut self) {
+        self.extra_cursors.clear();
+    }
+
+    /// The primary cursor as a selection (`anchor == head` when bare).
+    fn primary_selection(&self) -> Selection {
+        Selection {
+            anchor: self.selection.map_or(self.cursor, |sel| sel.anchor),
+            head: self.cursor,
+        }
+    }
+
+    /// All cursors as selections, flagged `true` for the primary one.
+    fn cursor_selections(&self) -> Vec<(Selection, bool)> {
+        std::iter::once((self.primary_selection(), true))
+            .chain(self.extra_cursors.iter().map(|&sel| (sel, false)))
+            .collect()
+    }
+
+    /// Merge overlapping cursors, keeping them in document order.
+    fn merge_cursors(&mut self) {
+        let nav = CursorNavigator::new(&self.rope);
+        let carets = self
+            .cursor_selections()
+            .into_iter()
+            .map(|(sel, primary)| {
+                Caret::new(
+                    nav.to_byte_index(sel.anchor),
+                    nav.to_byte_index(sel.head),
+                    primary,
+                )
+            })
+            .collect();
+        self.set_carets(carets);
+    }
+
+    /// Set all cursors from byte-offset carets, merging overlaps.
+    fn set_carets(&mut self, carets: Vec<Caret>) {
+        let merged = merge_carets(carets);
+        let nav = CursorNavigator::new(&self.rope);
+        self.extra_cursors.clear();
+        let mut has_primary = false;
+        for caret in merged {
+            let sel = Selection {
+                anchor: nav.from_byte_index(caret.anchor),
+                head: nav.from_byte_index(caret.head),
+            };
+            if caret.primary && !has_primary {
+                has_primary = true;
+                self.cursor = sel.head;
+                self.selection = (!sel.is_empty()).then_some(sel);
+            } else {
+                self.extra_cursors.push(sel);
+            }
+        }
+    }
+
+    /// Whether an edit should be applied at every cursor.
+    fn is_multi_edit(&self) -> bool {
+        !self.extra_cursors.is_empty() && !self.fanning_out
+    }
+
+    /// Apply a single-cursor edit at every cursor as one undo step.
+    ///
+    /// Cursors are visited last to first; the offsets of the others are
+    /// shifted by the ops each edit records.
+    fn edit_each_cursor(&mut self, mut edit: impl FnMut(&mut Self) -> bool) -> bool {
+        let nav = CursorNavigator::new(&self.rope);
+        let mut carets: Vec<Caret> = self
+            .cursor_selections()
+            .into_iter()
+            .map(|(sel, primary)| {
+                Caret::new(
+                    nav.to_byte_index(sel.anchor),
+                    nav.to_byte_index(sel.head),
+                    primary,
+                )
+            })
+            .collect();
+        carets.sort_by_key(|c| std::cmp::Reverse(c.start()));
+
+        let cursor_before = self.cursor;
+        let outer = self.recording.replace(Vec::new());
+        self.fanning_out = true;
+        let mut changed = false;
+        for i in 0..carets.len() {
+            let Caret { anchor, head, .. } = carets[i];
+            let nav = CursorNavigator::new(&self.rope);
+            self.cursor = nav.from_byte_index(head);
+            self.selection = (anchor != head).then(|| Selection {
+                anchor: nav.from_byte_index(anchor),
+                head: self.cursor,
+            });
+            let recorded = self.recording.as_ref().map_or(0, Vec::len);
+            changed |= edit(self);
+
+            let nav = CursorNavigator::new(&self.rope);
+            let head = nav.to_byte_index(self.cursor);
+            let anchor = self
+                .selection
+                .map_or(head, |sel| nav.to_byte_index(sel.anchor));
+            let ops = self.recording.as_deref().unwrap_or_default();
+            for (j, caret) in carets.iter_mut().enumerate() {
+                if j == i {
+                    (caret.anchor, caret.head) = (anchor, head);
+                    continue;
+                }
+                for op in &ops[recorded..] {
+                    caret.anchor = op.map_offset(caret.anchor);
+                    caret.head = op.map_offset(caret.head);
+                }
+            }
+        }
+
+        self.fanning_out = false;
+        let ops = self.recording.take().unwrap_or_default();
+        if !ops.is_empty() {
+            // The lowest cursor is edited last, so its line is still current.
+            let first = ops.iter().map(EditOp::first_byte).min().unwrap_or(0);
+            self.mark_changed(first);
+        }
+        if let Some(mut outer) = outer {
+            if !ops.is_empty() {
+                outer.push(EditOp::Group(ops));
+            }
+            self.recording = Some(outer);
+        } else if !ops.is_empty() {
+            self.cursor = cursor_before;
+            self.record_undo(EditOp::Group(ops));
+        }
+        self.set_carets(carets);
+        changed
+    }
+
+    // ====================================================================
+    // Find / replace
+    // ====================================================================
+
+    /// All matches of `needle` in the buffer (byte ranges, ascending).
+    #[must_use]
+    pub fn find_all(&self, needle: &str, options: &SearchOptions) -> Vec<SearchResult> {
+        SearchPattern::new(needle, options).find_all(self.text_str())
+    }
+
+    /// Select the next match after the cursor, wrapping around the end.
+    ///
+    /// Returns the selected match, or `None` if there are no matches.
+    pub fn find_next(&mut self, needle: &str, options: &SearchOptions) -> Option<SearchResult> {
+        let nav = CursorNavigator::new(&self.rope);
+        let from = match self.selection {
+            Some(sel) => sel.byte_range(&nav).1,
+            None => nav.to_byte_index(self.cursor),
+        };
+        let found = self.match_after(&SearchPattern::new(needle, options), from)?;
+        self.select_byte_range(found.range.start, found.range.end);
+        Some(found)
+    }
+
+    /// Select the previous match before the cursor, wrapping around the start.
+    ///
+    /// Returns the selected match, or `None` if there are no matches.
+    pub fn find_prev(&mut self, needle: &str, options: &SearchOptions) -> Option<SearchResult> {
+        let nav = CursorNavigator::new(&self.rope);
+        let before = match self.selection {
+            Some(sel) => sel.byte_range(&nav).0,
+            None => nav.to_byte_index(self.cursor),
+        };
+        let found = self.match_before(&SearchPattern::new(needle, options), before)?;
+        self.select_byte_range(found.range.start, found.range.end);
+        Some(found)
+    }
+
+    /// The first match starting at or after byte `from`, wrapping around.
+    ///
+    /// Patterns that stay within a line are searched line by line outwards
+    /// from `from`, so nearby matches are found without reading the rest
+    /// of the buffer.
+    fn match_after(&self, pattern: &SearchPattern, from: usize) -> Option<SearchResult> {
+        if pattern.spans_lines() {
+            let text = self.text_str();
+            return pattern
+                .find_at(text, from)
+                .or_else(|| pattern.find_at(text, 0));
+        }
+        let first = self.rope.byte_to_line_col(from).0;
+        let count = self.rope.len_lines();
+        // The starting line comes round again last, for matches before `from`.
+        (0..=count).find_map(|i| {
+            let mut matches = self.line_matches(pattern, (first + i) % count).into_iter();
+            if i == 0 {
+                matches.find(|m| m.range.start >= from)
+            } else {
+                matches.next()
+            }
+        })
+    }
+
+    /// The last match starting before byte `before`, wrapping around.
+    fn match_before(&self, pattern: &SearchPattern, before: usize) -> Option<SearchResult> {
+        if pattern.spans_lines() {
+            let mut matches = pattern.find_all(self.text_str());
+            let index = matches.iter().rposition(|m| m.range.start < before);
+            return match index {
+                Some(index) => Some(matches.swap_remove(index)),
+                None => matches.pop(),
+            };
+        }
+        let first = self.rope.byte_to_line_col(before).0;
+        let count = self.rope.len_lines();
+        (0..=count).find_map(|i| {
+            let mut matches = self
+                .line_matches(pattern, (first + count - i % count) % count)
+                .into_iter();
+            if i == 0 {
+                matches.rfind(|m| m.range.start < before)
+            } else {
+                matches.next_back()
+            }
+        })
+    }
+
+    /// Matches of a single-line pattern in line `line`, as buffer offsets.
+    fn line_matches(&self, pattern: &SearchPattern, line: usize) -> Vec<SearchResult> {
+        let Some(text) = self.rope.line(line) else {
+            return Vec::new();
+        };
+        let start = self.rope.char_to_byte(self.rope.line_to_char(line));
+        pattern
+            .find_all(&text)
+            .into_iter()
+            .map(|m| SearchResult::new(m.range.start + start, m.range.end + start))
+            .collect()
+    }
+
+    /// Replace the selected match (if the selection is exactly a match) and
+    /// select the next one.
+    ///
+    /// If the selection is not a match, this only moves to the next match.
+    /// Returns `true` if a replacement was made.
+    pub fn replace_next(
+        &mut self,
+        needle: &str,
+        replacement: &str,
+        options: &SearchOptions,
+    ) -> bool {
+        let pattern = SearchPattern::new(needle, options);
+        let selected = self.selection.filter(|sel| !sel.is_empty()).map(|sel| {
+            let nav = CursorNavigator::new(&self.rope);
+            sel.byte_range(&nav)
+        });
+        let current = selected.and_then(|(start, end)| {
+            self.match_after(&pattern, start)
+                .filter(|m| m.range.start == start && m.range.end == end)
+        });
+        let Some(current) = current else {
+            self.find_next(needle, options);
+            return false;
+        };
+        // Only regex replacements read the buffer, to expand captures.
+        let with = if options.regex {
+            pattern.expand(self.text_str(), &current, replacement)
+        } else {
+            replacement.to_string()
+        };
+        self.replace_ranges(&[(current, with)]);
+        self.find_next(needle, options);
+        true
+    }
+
+    /// Replace every match as a single undoable step.
+    ///
+    /// Returns the number of replacements made.
+    pub fn replace_all(
+        &mut self,
+        needle: &str,
+        replacement: &str,
+        options: &SearchOptions,
+    ) -> usize {
+        let pattern = SearchPattern::new(needle, options);
+        let text = self.text_str();
+        let edits: Vec<(SearchResult, String)> = pattern
+            .find_all(text)
+            .into_iter()
+            .map(|m| {
+                let with = pattern.expand(text, &m, replacement);
+                (m, with)
+            })
+            .collect();
+        self.replace_ranges(&edits);
+        edits.len()
+    }
+
+    /// Replace a byte range with `text` as a single undo step.
+    ///
+    /// Offsets must lie on grapheme boundaries. The cursor ends after the
+    /// inserted text.
+    pub fn replace_byte_range(&mut self, start: usize, end: usize, text: &str) {
+        let len = self.rope.len_bytes();
+        let end = end.min(len);
+        let start = start.min(end);
+        if start == end && text.is_empty() {
+            return;
+        }
+        self.replace_ranges(&[(SearchResult::new(start, end), text.to_string())]);
+    }
+
+    /// Replace non-overlapping, ascending byte ranges in one undo step.
+    ///
+    /// The cursor ends after the last replacement.
+    fn replace_ranges(&mut self, edits: &[(SearchResult, String)]) {
+        if edits.is_empty() {
+            return;
+        }
+        self.selection = None;
+        self.extra_cursors.clear();
+        let mut ops = Vec::with_capacity(edits.len() * 2);
+        // Apply back to front so earlier offsets stay valid.
+        for (m, with) in edits.iter().rev() {
+            let start_char = self.rope.byte_to_char(m.range.start);
+            let end_char = self.rope.byte_to_char(m.range.end);
+            let deleted = self.rope.slice(start_char..end_char).into_owned();
+            ops.push(EditOp::Delete {
+                byte_offset: m.range.start,
+                text: deleted,
+            });
+            if !with.is_empty() {
+                ops.push(EditOp::Insert {
+                    byte_offset: m.range.start,
+                    text: with.clone(),
+                });
+            }
+        }
+        let group = EditOp::Group(ops);
+        self.apply_op(&group);
+        self.record_undo(group);
+
+        let (last, rest) = edits.split_last().expect("non-empty edits");
+        let shift: isize = rest
+            .iter()
+            .map(|(m, with)| with.len() as isize - m.range.len() as isize)
+            .sum();
+        let end = last.0.range.start as isize + shift + last.1.len() as isize;
+        let nav = CursorNavigator::new(&self.rope);
+        self.cursor = nav.from_byte_index(end.max(0) as usize);
     }
 
     // ====================================================================
@@ -685,10 +1434,14 @@ impl Editor {
 
     /// Replace all content and reset cursor to end. Clears undo history.
     pub fn set_text(&mut self, text: &str) {
+        self.mark_changed(0);
         self.rope.replace(text);
         let nav = CursorNavigator::new(&self.rope);
         self.cursor = nav.document_end();
         self.selection = None;
+        self.extra_cursors.clear();
+        self.recording = None;
+        self.undo_group_depth = 0;
         self.undo_stack.clear();
         self.redo_stack.clear();
         self.current_undo_size = 0;
@@ -696,9 +1449,13 @@ impl Editor {
 
     /// Clear all content and reset cursor. Clears undo history.
     pub fn clear(&mut self) {
+        self.mark_changed(0);
         self.rope.clear();
         self.cursor = CursorPosition::default();
         self.selection = None;
+        self.extra_cursors.clear();
+        self.recording = None;
+        self.undo_group_depth = 0;
         self.undo_stack.clear();
         self.redo_stack.clear();
         self.current_undo_size = 0;
@@ -710,6 +1467,22 @@ impl EditOp {
         match self {
             Self::Insert { text, .. } => text.len(),
             Self::Delete { text, .. } => text.len(),
+            Self::Group(ops) => ops.iter().map(Self::byte_len).sum(),
+        }
+    }
+
+    /// Map a byte offset from before this op to after it.
+    ///
+    /// Offsets inside a deleted range collapse to its start; an insertion
+    /// at exactly `offset` leaves it in place.
+    fn map_offset(&self, offset: usize) -> usize {
+        match self {
+            Self::Insert { byte_offset, text } if offset > *byte_offset => offset + text.len(),
+            Self::Delete { byte_offset, text } if offset > *byte_offset => {
+                offset.saturating_sub(text.len()).max(*byte_offset)
+            }
+            Self::Group(ops) => ops.iter().fold(offset, |offset, op| op.map_offset(offset)),
+            _ => offset,
         }
     }
 }
@@ -1527,6 +2300,402 @@ mod tests {
         assert!(start <= end);
         assert_eq!(end - start, 3);
     }
+
+    // ================================================================
+    // Find / replace
+    // ================================================================
+
+    #[test]
+    fn revision_tracks_content_changes() {
+        let mut ed = Editor::new();
+        let r0 = ed.revision();
+        ed.insert_text("abc");
+        let r1 = ed.revision();
+        assert_ne!(r0, r1);
+        ed.move_left();
+        assert_eq!(ed.revision(), r1);
+        ed.undo();
+        assert_ne!(ed.revision(), r1);
+    }
+
+    #[test]
+    fn revision_bumps_once_per_edit() {
+        let mut ed = Editor::with_text("a\nb\nc");
+        let r0 = ed.revision();
+        ed.insert_char('x');
+        assert_eq!(ed.revision(), r0 + 1);
+
+        ed.begin_undo_group();
+        ed.insert_char('y');
+        ed.insert_char('z');
+        ed.end_undo_group();
+        assert_eq!(ed.revision(), r0 + 3);
+
+        ed.undo();
+        assert_eq!(ed.revision(), r0 + 4);
+
+        ed.move_to_document_start();
+        ed.add_cursor_below();
+        ed.add_cursor_below();
+        let r1 = ed.revision();
+        ed.insert_char('!');
+        assert_eq!(ed.text(), "!a\n!b\n!cx");
+        assert_eq!(ed.revision(), r1 + 1);
+        assert_eq!(ed.first_changed_line_since(r1), Some(0));
+
+        assert_eq!(ed.replace_all("!", "", &SearchOptions::default()), 3);
+        assert_eq!(ed.revision(), r1 + 2);
+    }
+
+    #[test]
+    fn first_changed_line_tracks_edits() {
+        let mut ed = Editor::with_text("a\nb\nc\nd");
+        let r0 = ed.revision();
+        assert_eq!(ed.first_changed_line_since(r0), None);
+
+        ed.set_cursor(CursorPosition::new(2, 1, 1));
+        ed.insert_char('!');
+        assert_eq!(ed.first_changed_line_since(r0), Some(2));
+        let r1 = ed.revision();
+
+        ed.set_cursor(CursorPosition::new(1, 0, 0));
+        ed.delete_backward();
+        assert_eq!(ed.first_changed_line_since(r1), Some(0));
+        let r2 = ed.revision();
+        ed.undo();
+        assert_eq!(ed.first_changed_line_since(r2), Some(0));
+        assert_eq!(ed.first_changed_line_since(r0), Some(0));
+
+        // History older than the log is reported as a full change.
+        let r3 = ed.revision();
+        for _ in 0..=CHANGE_LOG_LEN {
+            ed.set_cursor(CursorPosition::new(2, 0, 0));
+            ed.insert_char('x');
+        }
+        assert_eq!(ed.first_changed_line_since(r3), Some(0));
+    }
+
+    #[test]
+    fn find_next_wraps_around() {
+        let mut ed = Editor::with_text("foo bar foo");
+        let opts = SearchOptions::default();
+        ed.move_to_document_start();
+        assert_eq!(ed.find_next("foo", &opts).unwrap().range, 0..3);
+        assert_eq!(ed.selected_text().as_deref(), Some("foo"));
+        assert_eq!(ed.find_next("foo", &opts).unwrap().range, 8..11);
+        assert_eq!(ed.find_next("foo", &opts).unwrap().range, 0..3);
+        assert!(ed.find_next("zzz", &opts).is_none());
+    }
+
+    #[test]
+    fn find_prev_wraps_around() {
+        let mut ed = Editor::with_text("foo bar foo");
+        let opts = SearchOptions::default();
+        ed.move_to_document_start();
+        assert_eq!(ed.find_prev("foo", &opts).unwrap().range, 8..11);
+        assert_eq!(ed.find_prev("foo", &opts).unwrap().range, 0..3);
+    }
+
+    #[test]
+    fn find_across_lines() {
+        let mut ed = Editor::with_text("one\ntwo\nthree two");
+        ed.move_to_document_start();
+        ed.find_next("two", &SearchOptions::default());
+        assert_eq!(ed.cursor().line, 1);
+        ed.find_next("two", &SearchOptions::default());
+        assert_eq!(ed.cursor().line, 2);
+        assert_eq!(ed.cursor().grapheme, 9);
+    }
+
+    #[test]
+    fn find_searches_outward_from_the_cursor() {
+        let mut ed = Editor::with_text("id\nx id\nidx id\nid");
+        let opts = SearchOptions::default().whole_word(true);
+        ed.set_cursor(CursorPosition::new(1, 1, 1));
+        assert_eq!(ed.find_next("id", &opts).unwrap().range, 5..7);
+        assert_eq!(ed.find_next("id", &opts).unwrap().range, 12..14);
+        assert_eq!(ed.find_next("id", &opts).unwrap().range, 15..17);
+        assert_eq!(ed.find_next("id", &opts).unwrap().range, 0..2);
+        assert_eq!(ed.find_prev("id", &opts).unwrap().range, 15..17);
+        assert_eq!(ed.find_prev("id", &opts).unwrap().range, 12..14);
+        // A needle with a line break is searched across lines.
+        assert_eq!(ed.find_next("id\nid", &opts).unwrap().range, 12..17);
+    }
+
+    #[cfg(feature = "regex-search")]
+    #[test]
+    fn find_regex_from_the_cursor() {
+        let mut ed = Editor::with_text("a1\nb22\nc333");
+        let opts = SearchOptions::default().regex(true);
+        ed.set_cursor(CursorPosition::new(1, 2, 2));
+        // Anchors see the text before the cursor: `^` does not match mid-line.
+        assert_eq!(ed.find_next(r"^\w", &opts).unwrap().range, 7..8);
+        assert_eq!(ed.find_next(r"\d+$", &opts).unwrap().range, 8..11);
+        assert_eq!(ed.find_next(r"\d+$", &opts).unwrap().range, 1..2);
+        assert_eq!(ed.find_prev(r"\d+$", &opts).unwrap().range, 8..11);
+        assert!(ed.replace_next(r"\d+$", "[$0]", &opts));
+        assert_eq!(ed.text(), "a1\nb22\nc[333]");
+    }
+
+    #[test]
+    fn replace_next_replaces_selected_match_only() {
+        let mut ed = Editor::with_text("a-a-a");
+        let opts = SearchOptions::default();
+        ed.move_to_document_start();
+        // First call only selects.
+        assert!(!ed.replace_next("a", "b", &opts));
+        assert!(ed.replace_next("a", "b", &opts));
+        assert_eq!(ed.text(), "b-a-a");
+        assert_eq!(ed.selected_text().as_deref(), Some("a"));
+        assert!(ed.replace_next("a", "b", &opts));
+        assert_eq!(ed.text(), "b-b-a");
+    }
+
+    #[test]
+    fn replace_all_is_single_undo_step() {
+        let mut ed = Editor::with_text("cat hat cat\ncat");
+        let n = ed.replace_all("cat", "dog", &SearchOptions::default());
+        assert_eq!(n, 3);
+        assert_eq!(ed.text(), "dog hat dog\ndog");
+        assert!(ed.undo());
+        assert_eq!(ed.text(), "cat hat cat\ncat");
+        assert!(!ed.can_undo());
+        assert!(ed.redo());
+        assert_eq!(ed.text(), "dog hat dog\ndog");
+    }
+
+    #[test]
+    fn replace_all_with_different_lengths() {
+        let mut ed = Editor::with_text("x.x.x");
+        ed.replace_all("x", "long", &SearchOptions::default());
+        assert_eq!(ed.text(), "long.long.long");
+        assert_eq!(ed.cursor().grapheme, 14);
+        ed.undo();
+        ed.replace_all("x", "", &SearchOptions::default());
+        assert_eq!(ed.text(), "..");
+    }
+
+    #[test]
+    fn replace_all_whole_word_case_insensitive() {
+        let mut ed = Editor::with_text("Id id idx ID");
+        let opts = SearchOptions::default()
+            .case_sensitive(false)
+            .whole_word(true);
+        assert_eq!(ed.replace_all("id", "key", &opts), 3);
+        assert_eq!(ed.text(), "key key idx key");
+    }
+
+    #[test]
+    fn replace_all_no_matches_keeps_history() {
+        let mut ed = Editor::with_text("abc");
+        assert_eq!(ed.replace_all("z", "y", &SearchOptions::default()), 0);
+        assert!(!ed.can_undo());
+    }
+
+    #[cfg(feature = "regex-search")]
+    #[test]
+    fn replace_all_regex_with_captures() {
+        let mut ed = Editor::with_text("a=1\nb=2");
+        let opts = SearchOptions::default().regex(true);
+        assert_eq!(ed.replace_all(r"^(\w)=(\d)$", "$2:$1", &opts), 2);
+        assert_eq!(ed.text(), "1:a\n2:b");
+    }
+
+    #[test]
+    fn add_cursor_below_and_type() {
+        let mut ed = Editor::with_text("abc\nabc\nabc");
+        ed.move_to_document_start();
+        ed.move_right();
+        assert!(ed.add_cursor_below());
+        assert!(ed.add_cursor_below());
+        assert!(!ed.add_cursor_below());
+        assert_eq!(ed.cursor_count(), 3);
+        assert_eq!(ed.cursor().line, 2);
+        ed.insert_char('X');
+        assert_eq!(ed.text(), "aXbc\naXbc\naXbc");
+        assert!(ed.selections().iter().all(|s| s.head.grapheme == 2));
+    }
+
+    #[test]
+    fn multi_cursor_edit_is_single_undo_step() {
+        let mut ed = Editor::with_text("ab\nab");
+        ed.add_cursor_above();
+        ed.insert_text("!!");
+        ed.delete_backward();
+        assert_eq!(ed.text(), "ab!\nab!");
+        assert!(ed.undo());
+        assert_eq!(ed.text(), "ab!!\nab!!");
+        assert!(ed.undo());
+        assert_eq!(ed.text(), "ab\nab");
+        assert_eq!(ed.cursor_count(), 1);
+        assert!(ed.redo());
+        assert_eq!(ed.text(), "ab!!\nab!!");
+    }
+
+    #[test]
+    fn multi_cursor_same_line_offsets_shift() {
+        let mut ed = Editor::with_text("a b c");
+        ed.move_to_document_start();
+        ed.add_cursor(CursorPosition::new(0, 2, 2));
+        ed.add_cursor(CursorPosition::new(0, 4, 4));
+        ed.insert_text("--");
+        assert_eq!(ed.text(), "--a --b --c");
+        let heads: Vec<usize> = ed.selections().iter().map(|s| s.head.grapheme).collect();
+        assert_eq!(heads, vec![2, 6, 10]);
+    }
+
+    #[test]
+    fn overlapping_cursors_merge() {
+        let mut ed = Editor::with_text("abc");
+        ed.move_to_document_start();
+        ed.add_cursor(CursorPosition::new(0, 1, 1));
+        assert_eq!(ed.cursor_count(), 2);
+        ed.move_left();
+        assert_eq!(ed.cursor_count(), 1);
+        ed.add_cursor(CursorPosition::new(0, 0, 0));
+        assert_eq!(ed.cursor_count(), 1);
+    }
+
+    #[test]
+    fn deletes_that_swallow_cursors_merge() {
+        let mut ed = Editor::with_text("aaaa");
+        ed.move_to_document_start();
+        ed.add_cursor(CursorPosition::new(0, 1, 1));
+        ed.delete_forward();
+        assert_eq!(ed.text(), "aa");
+        assert_eq!(ed.cursor_count(), 1);
+        assert_eq!(ed.cursor().grapheme, 0);
+    }
+
+    #[test]
+    fn add_next_occurrence_selects_word_then_matches() {
+        let mut ed = Editor::with_text("foo bar foo foo");
+        ed.move_to_document_start();
+        assert!(ed.add_next_occurrence());
+        assert_eq!(ed.selected_text().as_deref(), Some("foo"));
+        assert_eq!(ed.cursor_count(), 1);
+        assert!(ed.add_next_occurrence());
+        assert!(ed.add_next_occurrence());
+        assert_eq!(ed.cursor_count(), 3);
+        assert!(!ed.add_next_occurrence());
+        ed.insert_text("qux");
+        assert_eq!(ed.text(), "qux bar qux qux");
+    }
+
+    #[test]
+    fn add_next_occurrence_wraps() {
+        let mut ed = Editor::with_text("x x x");
+        ed.select_byte_range(2, 3);
+        assert!(ed.add_next_occurrence());
+        assert!(ed.add_next_occurrence());
+        let ranges: Vec<usize> = ed.selections().iter().map(|s| s.head.grapheme).collect();
+        assert_eq!(ranges, vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn add_next_occurrence_without_word_is_noop() {
+        let mut ed = Editor::with_text("  ");
+        assert!(!ed.add_next_occurrence());
+        assert!(ed.selection().is_none());
+    }
+
+    #[test]
+    fn rectangle_selection_spans_columns() {
+        let mut ed = Editor::with_text("abcd\nab\nabcd");
+        ed.select_rectangle(CursorPosition::new(0, 0, 1), CursorPosition::new(2, 0, 3));
+        assert_eq!(ed.cursor_count(), 3);
+        assert_eq!(ed.cursor(), CursorPosition::new(2, 3, 3));
+        ed.delete_backward();
+        assert_eq!(ed.text(), "ad\na\nad");
+        assert_eq!(ed.cursor_count(), 3);
+    }
+
+    #[test]
+    fn rectangle_selection_skips_short_lines() {
+        let mut ed = Editor::with_text("abcd\na\nabcd");
+        ed.select_rectangle(CursorPosition::new(0, 0, 2), CursorPosition::new(2, 0, 3));
+        assert_eq!(ed.cursor_count(), 2);
+        ed.insert_char('_');
+        assert_eq!(ed.text(), "ab_d\na\nab_d");
+    }
+
+    #[test]
+    fn movement_applies_to_all_cursors() {
+        let mut ed = Editor::with_text("ab\ncd");
+        ed.move_to_document_start();
+        ed.add_cursor_below();
+        ed.move_to_line_end();
+        ed.select_left();
+        let texts: Vec<(usize, usize)> = ed
+            .selections()
+            .iter()
+            .map(|s| (s.anchor.grapheme, s.head.grapheme))
+            .collect();
+        assert_eq!(texts, vec![(2, 1), (2, 1)]);
+        ed.move_to_document_end();
+        assert_eq!(ed.cursor_count(), 1);
+    }
+
+    #[test]
+    fn undo_group_is_single_step() {
+        let mut ed = Editor::with_text("abc");
+        ed.begin_undo_group();
+        ed.delete_backward();
+        ed.begin_undo_group();
+        ed.insert_text("XY");
+        ed.end_undo_group();
+        ed.insert_char('Z');
+        ed.end_undo_group();
+        assert_eq!(ed.text(), "abXYZ");
+        assert!(ed.undo());
+        assert_eq!(ed.text(), "abc");
+        assert!(!ed.can_undo());
+        assert!(ed.redo());
+        assert_eq!(ed.text(), "abXYZ");
+    }
+
+    #[test]
+    fn empty_undo_group_adds_no_step() {
+        let mut ed = Editor::with_text("abc");
+        ed.begin_undo_group();
+        ed.move_left();
+        ed.end_undo_group();
+        assert!(!ed.can_undo());
+    }
+
+    #[test]
+    fn replace_byte_range_moves_cursor() {
+        let mut ed = Editor::with_text("hello world");
+        ed.replace_byte_range(0, 5, "bye");
+        assert_eq!(ed.text(), "bye world");
+        assert_eq!(ed.cursor().grapheme, 3);
+        ed.undo();
+        assert_eq!(ed.text(), "hello world");
+    }
+
+    #[test]
+    fn set_cursor_clears_secondary_cursors() {
+        let mut ed = Editor::with_text("a\nb");
+        ed.add_cursor_above();
+        assert_eq!(ed.cursor_count(), 2);
+        ed.set_cursor(CursorPosition::default());
+        assert_eq!(ed.cursor_count(), 1);
+    }
+
+    #[cfg(feature = "bidi")]
+    #[test]
+    fn visual_selection_is_a_logical_range() {
+        let mut ed = Editor::with_text("ab \u{05D0}\u{05D1}\u{05D2}");
+        ed.set_cursor(CursorPosition::new(0, 2, 2));
+        ed.select_visual_right();
+        ed.select_visual_right();
+        // The first step jumps to the logical end of the RTL run and the
+        // second walks back one letter; the selection stays logical.
+        assert_eq!(ed.cursor().grapheme, 5);
+        assert_eq!(ed.selected_text().as_deref(), Some(" \u{05D0}\u{05D1}"));
+        ed.move_visual_left();
+        assert!(ed.selection().is_none());
+    }
 }
 
 // ================================================================
@@ -0,0 +1,313 @@
+#![forbid(unsafe_code)]
+
+//! Liang-pattern hyphenation.
+//!
+//! A [`Hyphenator`] finds the positions inside a word where a line may be
+//! broken with a hyphen, using the pattern algorithm from Frank Liang's
+//! thesis (the one TeX uses). Dictionaries are plain TeX pattern text, so any
+//! language from the hyph-utf8 collection can be loaded with
+//! [`Hyphenator::from_patterns`]. US English ships built in with the
+//! `hyphenation` feature.
+//!
+//! Break positions are byte offsets that always fall on grapheme cluster
+//! boundaries, so splitting a word at one never tears an emoji or a
+//! combining sequence.
+//!
+//! # Example
+//! ```
+//! # #[cfg(feature = "hyphenation")]
+//! # {
+//! use ftui_text::hyphenate::Hyphenator;
+//!
+//! let en = Hyphenator::english();
+//! assert_eq!(en.syllables("extensive"), vec!["ex", "ten", "sive"]);
+//! # }
+//! ```
+
+#[cfg(feature = "hyphenation")]
+use std::sync::{Arc, OnceLock};
+
+use rustc_hash::FxHashMap;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// U+00AD SOFT HYPHEN: an invisible, author-supplied break opportunity that
+/// renders as `-` only when a line is broken there.
+pub const SOFT_HYPHEN: char = '\u{00AD}';
+
+/// US English patterns (hyph-en-us).
+#[cfg(feature = "hyphenation")]
+const EN_US_PATTERNS: &str = include_str!("../data/hyph-en-us.pat");
+
+/// US English exception words from hyph-en-us.
+#[cfg(feature = "hyphenation")]
+const EN_US_EXCEPTIONS: &str = "as-so-ciate as-so-ciates dec-li-na-tion oblig-a-tory \
+     phil-an-thropic present presents project projects reci-procity \
+     re-cog-ni-zance ref-or-ma-tion ret-ri-bu-tion ta-ble";
+
+/// Pattern-based hyphenation dictionary for one language.
+#[derive(Debug, Clone)]
+pub struct Hyphenator {
+    /// Pattern letters (with `.` word edges) to inter-letter levels.
+    /// Each level vector has one more entry than the key.
+    patterns: FxHashMap<Vec<char>, Vec<u8>>,
+    /// Whole-word overrides: lowercase word to break indices (in letters).
+    exceptions: FxHashMap<String, Vec<usize>>,
+    /// Longest pattern key, bounds the matching window.
+    max_pattern_len: usize,
+    /// Minimum letters before the first break.
+    left_min: usize,
+    /// Minimum letters after the last break.
+    right_min: usize,
+}
+
+impl Hyphenator {
+    /// Build a hyphenator from TeX-style patterns and an exception list.
+    ///
+    /// `patterns` is whitespace separated (`%` starts a comment), e.g.
+    /// `".ach4 4b1l a1ba"`. `exceptions` lists whole words with explicit
+    /// hyphens, e.g. `"ta-ble pro-ject"`. Malformed entries are skipped.
+    #[must_use]
+    pub fn from_patterns(patterns: &str, exceptions: &str) -> Self {
+        let mut table = FxHashMap::default();
+        let mut max_pattern_len = 0;
+        for pattern in tokens(patterns) {
+            let mut letters = Vec::new();
+            let mut levels = vec![0u8];
+            for c in pattern.chars() {
+                if let Some(level) = c.to_digit(10) {
+                    *levels.last_mut().expect("levels is never empty") = level as u8;
+                } else {
+                    letters.push(c);
+                    levels.push(0);
+                }
+            }
+            if letters.is_empty() {
+                continue;
+            }
+            max_pattern_len = max_pattern_len.max(letters.len());
+            table.insert(letters, levels);
+        }
+
+        let mut exception_table = FxHashMap::default();
+        for entry in tokens(exceptions) {
+            let mut word = String::new();
+            let mut breaks = Vec::new();
+            let mut letters = 0;
+            for c in entry.chars() {
+                if c == '-' {
+                    breaks.push(letters);
+                } else {
+                    word.extend(c.to_lowercase());
+                    letters += 1;
+                }
+            }
+            if !word.is_empty() {
+                exception_table.insert(word, breaks);
+            }
+        }
+
+        Self {
+            patterns: table,
+            exceptions: exception_table,
+            max_pattern_len,
+            left_min: 2,
+            right_min: 3,
+        }
+    }
+
+    /// The built-in US English dictionary.
+    ///
+    /// Parsed once and shared; cloning the `Arc` is cheap.
+    #[cfg(feature = "hyphenation")]
+    #[must_use]
+    pub fn english() -> Arc<Self> {
+        static ENGLISH: OnceLock<Arc<Hyphenator>> = OnceLock::new();
+        ENGLISH
+            .get_or_init(|| Arc::new(Self::from_patterns(EN_US_PATTERNS, EN_US_EXCEPTIONS)))
+            .clone()
+    }
+
+    /// Set the minimum number of letters kept before the first and after
+    /// the last hyphen (TeX's `\lefthyphenmin` / `\righthyphenmin`).
+    ///
+    /// Defaults to 2 and 3, the English convention.
+    #[must_use]
+    pub fn with_min(mut self, left: usize, right: usize) -> Self {
+        self.left_min = left.max(1);
+        self.right_min = right.max(1);
+        self
+    }
+
+    /// Number of patterns in the dictionary.
+    #[must_use]
+    pub fn pattern_count(&self) -> usize {
+        self.patterns.len()
+    }
+
+    /// Byte offsets in `word` where a hyphenated break is allowed.
+    ///
+    /// Each maximal run of alphabetic graphemes is hyphenated on its own, so
+    /// punctuation and explicit hyphens (`"well-known,"`) are left alone.
+    /// Offsets are ascending and grapheme aligned.
+    #[must_use]
+    pub fn hyphenate(&self, word: &str) -> Vec<usize> {
+        let mut breaks = Vec::new();
+        // (byte offset, lowercase key letter) for the current alphabetic run.
+        let mut run: Vec<(usize, char)> = Vec::new();
+        for (offset, grapheme) in word.grapheme_indices(true) {
+            let first = grapheme.chars().next().unwrap_or(' ');
+            if first.is_alphabetic() {
+                let key = first.to_lowercase().next().unwrap_or(first);
+                run.push((offset, key));
+            } else {
+                self.hyphenate_run(&run, &mut breaks);
+                run.clear();
+            }
+        }
+        self.hyphenate_run(&run, &mut breaks);
+        breaks
+    }
+
+    /// Split `word` at every hyphenation point.
+    #[must_use]
+    pub fn syllables<'a>(&self, word: &'a str) -> Vec<&'a str> {
+        let mut parts = Vec::new();
+        let mut start = 0;
+        for at in self.hyphenate(word) {
+            parts.push(&word[start..at]);
+            start = at;
+        }
+        parts.push(&word[start..]);
+        parts
+    }
+
+    /// Hyphenate one alphabetic run, appending byte offsets to `breaks`.
+    fn hyphenate_run(&self, run: &[(usize, char)], breaks: &mut Vec<usize>) {
+        let n = run.len();
+        if n < self.left_min + self.right_min {
+            return;
+        }
+
+        let word: String = run.iter().map(|&(_, c)| c).collect();
+        if let Some(points) = self.exceptions.get(&word) {
+            breaks.extend(
+                points
+                    .iter()
+                    .filter(|&&i| i > 0 && i < n)
+                    .map(|&i| run[i].0),
+            );
+            return;
+        }
+
+        // levels[k] is the level of the gap before dotted[k].
+        let mut dotted = Vec::with_capacity(n + 2);
+        dotted.push('.');
+        dotted.extend(run.iter().map(|&(_, c)| c));
+        dotted.push('.');
+        let mut levels = vec![0u8; dotted.len() + 1];
+        for start in 0..dotted.len() {
+            let limit = dotted.len().min(start + self.max_pattern_len);
+            for end in start + 1..=limit {
+                if let Some(pattern) = self.patterns.get(&dotted[start..end]) {
+                    for (k, &level) in pattern.iter().enumerate() {
+                        let slot = &mut levels[start + k];
+                        *slot = (*slot).max(level);
+                    }
+                }
+            }
+        }
+
+        // Gap before letter i sits before dotted[i + 1].
+        for i in self.left_min..=n - self.right_min {
+            if levels[i + 1] % 2 == 1 {
+                breaks.push(run[i].0);
+            }
+        }
+    }
+}
+
+/// Whitespace-separated tokens with `%` comments stripped.
+fn tokens(source: &str) -> impl Iterator<Item = &str> {
+    source
+        .lines()
+        .map(|line| line.split('%').next().unwrap_or(""))
+        .flat_map(str::split_whitespace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "hyphenation")]
+    fn hyphenated(word: &str) -> String {
+        Hyphenator::english().syllables(word).join("-")
+    }
+
+    #[test]
+    #[cfg(feature = "hyphenation")]
+    fn english_dictionary_loads() {
+        assert_eq!(Hyphenator::english().pattern_count(), 4938);
+    }
+
+    #[test]
+    #[cfg(feature = "hyphenation")]
+    fn english_words() {
+        assert_eq!(hyphenated("hi"), "hi");
+        assert_eq!(hyphenated("welcome"), "wel-come");
+        assert_eq!(hyphenated("walking"), "walk-ing");
+        assert_eq!(hyphenated("broken"), "bro-ken");
+        assert_eq!(hyphenated("extensive"), "ex-ten-sive");
+        assert_eq!(hyphenated("recognize"), "rec-og-nize");
+        assert_eq!(hyphenated("hyphenation"), "hy-phen-ation");
+    }
+
+    #[test]
+    #[cfg(feature = "hyphenation")]
+    fn case_is_preserved_and_ignored() {
+        assert_eq!(hyphenated("Probability"), "Prob-a-bil-ity");
+        assert_eq!(hyphenated("WHATEVER"), "WHAT-EVER");
+    }
+
+    #[test]
+    #[cfg(feature = "hyphenation")]
+    fn exceptions_override_patterns() {
+        assert_eq!(hyphenated("table"), "ta-ble");
+        assert_eq!(hyphenated("project"), "project");
+    }
+
+    #[test]
+    #[cfg(feature = "hyphenation")]
+    fn punctuation_splits_runs() {
+        assert_eq!(hyphenated("(extensive)"), "(ex-ten-sive)");
+        assert_eq!(hyphenated("well-known"), "well-known");
+    }
+
+    #[test]
+    #[cfg(feature = "hyphenation")]
+    fn offsets_are_grapheme_aligned() {
+        // Combining acute on the first letter shifts later offsets by 2 bytes.
+        let word = "e\u{0301}xtensive";
+        let breaks = Hyphenator::english().hyphenate(word);
+        assert_eq!(breaks, vec![4, 7]);
+        for at in breaks {
+            assert!(word.is_char_boundary(at));
+        }
+    }
+
+    #[test]
+    fn custom_patterns_and_bounds() {
+        let h = Hyphenator::from_patterns("% comment\na1b b1a", "").with_min(1, 1);
+        assert_eq!(h.syllables("abab"), vec!["a", "b", "a", "b"]);
+        let strict = h.clone().with_min(2, 2);
+        assert_eq!(strict.syllables("abab"), vec!["ab", "ab"]);
+    }
+
+    #[test]
+    #[cfg(feature = "hyphenation")]
+    fn empty_and_short_words() {
+        let en = Hyphenator::english();
+        assert!(en.hyphenate("").is_empty());
+        assert!(en.hyphenate("cat").is_empty());
+        assert!(en.hyphenate("1234567").is_empty());
+    }
+}
@@ -13,6 +13,7 @@
 //! - [`CursorPosition`] / [`CursorNavigator`] - text cursor utilities
 //! - [`Editor`] - core text editing operations (insert, delete, cursor movement)
 //! - [`WidthCache`] - LRU cache for text width measurements
+//! - [`Hyphenator`] - Liang-pattern hyphenation (English with `hyphenation`)
 //!
 //! # Role in FrankenTUI
 //! `ftui-text` owns the text model used by widgets and renderers: spans, lines,
@@ -55,7 +56,9 @@
 //! ```
 
 pub mod cursor;
+pub mod diff;
 pub mod editor;
+pub mod hyphenate;
 pub mod rope;
 pub mod segment;
 pub mod text;
@@ -121,8 +124,9 @@ impl TextMeasurement {
     }
 }
 
-pub use cursor::{CursorNavigator, CursorPosition};
+pub use cursor::{Caret, CursorNavigator, CursorPosition, merge_carets};
 pub use editor::{Editor, Selection};
+pub use hyphenate::{Hyphenator, SOFT_HYPHEN};
 pub use rope::Rope;
 pub use segment::{ControlCode, Segment, SegmentLine, SegmentLines, join_lines, split_into_lines};
 pub use text::{Line, Span, Text};
@@ -132,9 +136,10 @@ pub use width_cache::{
 };
 pub use wrap::{
     KpBreakResult, WrapMode, WrapOptions, ascii_width, display_width, grapheme_count,
-    grapheme_width, graphemes, has_wide_chars, is_ascii_only, truncate_to_width,
+    grapheme_width, graphemes, has_wide_chars, is_ascii_only, justify_line, truncate_to_width,
     truncate_to_width_with_info, truncate_with_ellipsis, word_boundaries, word_segments,
-    wrap_optimal, wrap_text, wrap_text_optimal, wrap_with_options,
+    wrap_optimal, wrap_optimal_with, wrap_text, wrap_text_optimal, wrap_text_optimal_with,
+    wrap_with_options,
 };
 
 #[cfg(feature = "markup")]
@@ -144,7 +149,8 @@ pub use markup::{MarkupError, MarkupParser, parse_markup};
 pub use normalization::{NormForm, eq_normalized, is_normalized, normalize, normalize_for_search};
 
 pub use search::{
-    SearchResult, search_ascii_case_insensitive, search_exact, search_exact_overlapping,
+    SearchOptions, SearchPattern, SearchResult, is_word_char, search_ascii_case_insensitive,
+    search_exact, search_exact_overlapping, search_with_options,
 };
 #[cfg(feature = "normalization")]
 pub use search::{search_case_insensitive, search_normalized};
@@ -252,6 +252,238 @@ pub fn search_normalized(
     results
 }
 
+/// Options for [`search_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchOptions {
+    /// Match case exactly.
+    pub case_sensitive: bool,
+    /// Only accept matches bounded by non-word characters.
+    pub whole_word: bool,
+    /// Treat the needle as a regular expression (requires `regex-search`).
+    pub regex: bool,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            case_sensitive: true,
+            whole_word: false,
+            regex: false,
+        }
+    }
+}
+
+impl SearchOptions {
+    /// Set case sensitivity (builder).
+    #[must_use]
+    pub const fn case_sensitive(mut self, yes: bool) -> Self {
+        self.case_sensitive = yes;
+        self
+    }
+
+    /// Set whole-word matching (builder).
+    #[must_use]
+    pub const fn whole_word(mut self, yes: bool) -> Self {
+        self.whole_word = yes;
+        self
+    }
+
+    /// Set regex mode (builder).
+    #[must_use]
+    pub const fn regex(mut self, yes: bool) -> Self {
+        self.regex = yes;
+        self
+    }
+}
+
+/// Find all non-overlapping matches using the given options.
+///
+/// Invalid regular expressions (or regex mode without the `regex-search`
+/// feature) produce no matches. Empty regex matches are skipped.
+#[must_use]
+pub fn search_with_options(
+    haystack: &str,
+    needle: &str,
+    options: &SearchOptions,
+) -> Vec<SearchResult> {
+    SearchPattern::new(needle, options).find_all(haystack)
+}
+
+/// A needle and [`SearchOptions`] prepared for repeated searches.
+///
+/// In regex mode the expression is compiled once, here, and reused by every
+/// search and replacement expansion.
+#[derive(Debug, Clone)]
+pub struct SearchPattern {
+    needle: String,
+    options: SearchOptions,
+    #[cfg(feature = "regex-search")]
+    regex: Option<regex::Regex>,
+}
+
+impl SearchPattern {
+    /// Prepare `needle` for searching with `options`.
+    #[must_use]
+    pub fn new(needle: &str, options: &SearchOptions) -> Self {
+        Self {
+            needle: needle.to_string(),
+            options: *options,
+            #[cfg(feature = "regex-search")]
+            regex: if options.regex && !needle.is_empty() {
+                compile_regex(needle, options)
+            } else {
+                None
+            },
+        }
+    }
+
+    /// The needle as given.
+    #[must_use]
+    pub fn needle(&self) -> &str {
+        &self.needle
+    }
+
+    /// The search options.
+    #[must_use]
+    pub fn options(&self) -> &SearchOptions {
+        &self.options
+    }
+
+    /// Whether a match can cross a line break: regex patterns and needles
+    /// containing `\n`. Other patterns can be searched one line at a time.
+    #[must_use]
+    pub fn spans_lines(&self) -> bool {
+        self.options.regex || self.needle.contains('\n')
+    }
+
+    /// All non-overlapping matches in `haystack`.
+    ///
+    /// See [`search_with_options`] for how invalid patterns behave.
+    #[must_use]
+    pub fn find_all(&self, haystack: &str) -> Vec<SearchResult> {
+        if self.needle.is_empty() {
+            return Vec::new();
+        }
+        if !self.options.regex {
+            return self.literal_matches(haystack, 0);
+        }
+        #[cfg(feature = "regex-search")]
+        if let Some(re) = &self.regex {
+            return re
+                .find_iter(haystack)
+                .filter(|m| !m.is_empty() && self.word_ok(haystack, m.start(), m.end()))
+                .map(|m| SearchResult::new(m.start(), m.end()))
+                .collect();
+        }
+        Vec::new()
+    }
+
+    /// The first match starting at or after byte `start`, which must lie on
+    /// a char boundary.
+    ///
+    /// Matching begins at `start` instead of the beginning of `haystack`;
+    /// word boundaries and regex anchors still see the text before it.
+    #[must_use]
+    pub fn find_at(&self, haystack: &str, start: usize) -> Option<SearchResult> {
+        if self.needle.is_empty() || start > haystack.len() {
+            return None;
+        }
+        if !self.options.regex {
+            return self.literal_matches(haystack, start).into_iter().next();
+        }
+        #[cfg(feature = "regex-search")]
+        if let Some(re) = &self.regex {
+            let mut at = start;
+            while at <= haystack.len() {
+                let m = re.find_at(haystack, at)?;
+                if !m.is_empty() && self.word_ok(haystack, m.start(), m.end()) {
+                    return Some(SearchResult::new(m.start(), m.end()));
+                }
+                at = if m.is_empty() {
+                    // Step one char past an empty match.
+                    m.end() + haystack[m.end()..].chars().next().map_or(1, char::len_utf8)
+                } else {
+                    m.end()
+                };
+            }
+        }
+        None
+    }
+
+    /// Non-regex matches in `haystack[start..]`, as offsets into `haystack`.
+    fn literal_matches(&self, haystack: &str, start: usize) -> Vec<SearchResult> {
+        let tail = &haystack[start..];
+        let results = if self.options.case_sensitive {
+            search_exact(tail, &self.needle)
+        } else {
+            #[cfg(feature = "normalization")]
+            {
+                search_case_insensitive(tail, &self.needle)
+            }
+            #[cfg(not(feature = "normalization"))]
+            {
+                search_ascii_case_insensitive(tail, &self.needle)
+            }
+        };
+        results
+            .into_iter()
+            .map(|r| SearchResult::new(r.range.start + start, r.range.end + start))
+            .filter(|r| self.word_ok(haystack, r.range.start, r.range.end))
+            .collect()
+    }
+
+    /// Whether `start..end` satisfies the whole-word option.
+    fn word_ok(&self, haystack: &str, start: usize, end: usize) -> bool {
+        !self.options.whole_word || is_whole_word(haystack, start, end)
+    }
+
+    /// Replacement text for `result`, a match of this pattern in `haystack`.
+    ///
+    /// In regex mode `$1`, `${name}` and `$$` in `replacement` are expanded
+    /// from the match's capture groups; otherwise `replacement` is returned
+    /// verbatim.
+    #[must_use]
+    pub fn expand(&self, haystack: &str, result: &SearchResult, replacement: &str) -> String {
+        #[cfg(feature = "regex-search")]
+        if let Some(re) = &self.regex
+            && let Some(caps) = re.captures_at(haystack, result.range.start)
+            && caps.get(0).is_some_and(|m| m.start() == result.range.start)
+        {
+            let mut out = String::new();
+            caps.expand(replacement, &mut out);
+            return out;
+        }
+        #[cfg(not(feature = "regex-search"))]
+        let _ = (haystack, result);
+        replacement.to_string()
+    }
+}
+
+/// Compile a regex from the needle, respecting case sensitivity.
+#[cfg(feature = "regex-search")]
+fn compile_regex(needle: &str, options: &SearchOptions) -> Option<regex::Regex> {
+    regex::RegexBuilder::new(needle)
+        .case_insensitive(!options.case_sensitive)
+        .multi_line(true)
+        .build()
+        .ok()
+}
+
+/// Whether `c` is part of a word (letters, digits, underscore).
+///
+/// Shared by whole-word search, editor word selection and keymap motions.
+#[must_use]
+pub fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Whether `start..end` is not adjacent to word characters on either side.
+fn is_whole_word(haystack: &str, start: usize, end: usize) -> bool {
+    let before = haystack[..start].chars().next_back();
+    let after = haystack[end..].chars().next();
+    !before.is_some_and(is_word_char) && !after.is_some_and(is_word_char)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -415,6 +647,82 @@ mod tests {
             assert_eq!(&"hello 🌍 world 🌍 end"[r.range.clone()], "🌍");
         }
     }
+
+    // ==========================================================
+    // Option-driven search
+    // ==========================================================
+
+    #[test]
+    fn options_default_is_case_sensitive_literal() {
+        let opts = SearchOptions::default();
+        let r = search_with_options("Foo foo FOO", "foo", &opts);
+        assert_eq!(r, vec![SearchResult::new(4, 7)]);
+    }
+
+    #[test]
+    fn options_case_insensitive() {
+        let opts = SearchOptions::default().case_sensitive(false);
+        assert_eq!(search_with_options("Foo foo FOO", "foo", &opts).len(), 3);
+    }
+
+    #[test]
+    fn options_whole_word() {
+        let opts = SearchOptions::default().whole_word(true);
+        let r = search_with_options("cat concat cat_x cat.", "cat", &opts);
+        assert_eq!(r, vec![SearchResult::new(0, 3), SearchResult::new(17, 20)]);
+    }
+
+    #[test]
+    fn options_empty_needle() {
+        assert!(search_with_options("abc", "", &SearchOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn literal_replacement_is_verbatim() {
+        let pattern = SearchPattern::new("abc", &SearchOptions::default());
+        let m = SearchResult::new(0, 3);
+        assert_eq!(pattern.expand("abc", &m, "$1x"), "$1x");
+    }
+
+    #[cfg(feature = "regex-search")]
+    #[test]
+    fn options_regex_matches() {
+        let opts = SearchOptions::default().regex(true);
+        let r = search_with_options("a1 b22 c333", r"\d+", &opts);
+        assert_eq!(r.len(), 3);
+        assert_eq!(r[2].text("a1 b22 c333"), "333");
+    }
+
+    #[cfg(feature = "regex-search")]
+    #[test]
+    fn options_regex_invalid_yields_nothing() {
+        let opts = SearchOptions::default().regex(true);
+        assert!(search_with_options("abc", "(", &opts).is_empty());
+    }
+
+    #[cfg(feature = "regex-search")]
+    #[test]
+    fn options_regex_case_insensitive_whole_word() {
+        let opts = SearchOptions::default()
+            .regex(true)
+            .case_sensitive(false)
+            .whole_word(true);
+        let r = search_with_options("Let letter LET", "let", &opts);
+        assert_eq!(r.len(), 2);
+    }
+
+    #[cfg(feature = "regex-search")]
+    #[test]
+    fn regex_replacement_expands_groups() {
+        let hay = "key=value a=b";
+        let pattern = SearchPattern::new(r"(\w+)=(\w+)", &SearchOptions::default().regex(true));
+        let expanded: Vec<String> = pattern
+            .find_all(hay)
+            .iter()
+            .map(|m| pattern.expand(hay, m, "$2=$1"))
+            .collect();
+        assert_eq!(expanded, ["value=key", "b=a"]);
+    }
 }
 
 #[cfg(all(test, feature = "normalization"))]
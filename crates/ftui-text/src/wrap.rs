@@ -19,9 +19,32 @@
 //! let lines = wrap_text("Supercalifragilistic", 10, WrapMode::Char);
 //! assert_eq!(lines.len(), 2);
 //! ```
+//!
+//! # Hyphenation and justification
+//! Word modes and the optimal breaker honor soft hyphens (U+00AD) and, when
+//! given a [`Hyphenator`], dictionary hyphenation points. `justify` pads
+//! every line but the last of each paragraph to the full width.
+//! ```
+//! # #[cfg(feature = "hyphenation")]
+//! # {
+//! use ftui_text::hyphenate::Hyphenator;
+//! use ftui_text::wrap::{wrap_with_options, WrapOptions};
+//!
+//! let options = WrapOptions::new(12)
+//!     .hyphenator(Hyphenator::english())
+//!     .justify(true);
+//! let lines = wrap_with_options("the extensive hyphenation", &options);
+//! assert_eq!(lines, vec!["the   exten-", "sive hyphen-", "ation"]);
+//! # }
+//! ```
+
+use std::borrow::Cow;
+use std::sync::Arc;
 
 use unicode_segmentation::UnicodeSegmentation;
 
+use crate::hyphenate::{Hyphenator, SOFT_HYPHEN};
+
 /// Text wrapping mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum WrapMode {
@@ -37,7 +60,13 @@ pub enum WrapMode {
 }
 
 /// Options for text wrapping.
+///
+/// Non-exhaustive, which breaks struct literals written before the
+/// hyphenation options existed; start from [`WrapOptions::new`] and use the
+/// builder methods. Hyphenation and justification are set only through
+/// [`hyphenator`](Self::hyphenator) and [`justify`](Self::justify).
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct WrapOptions {
     /// Maximum width in cells.
     pub width: usize,
@@ -47,6 +76,10 @@ pub struct WrapOptions {
     pub preserve_indent: bool,
     /// Trim trailing whitespace from wrapped lines.
     pub trim_trailing: bool,
+    /// Dictionary used to hyphenate words that do not fit.
+    hyphenator: Option<Arc<Hyphenator>>,
+    /// Pad all but the last line of each paragraph to the full width.
+    justify: bool,
 }
 
 impl WrapOptions {
@@ -58,6 +91,8 @@ impl WrapOptions {
             mode: WrapMode::Word,
             preserve_indent: false,
             trim_trailing: true,
+            hyphenator: None,
+            justify: false,
         }
     }
 
@@ -81,6 +116,20 @@ impl WrapOptions {
         self.trim_trailing = trim;
         self
     }
+
+    /// Enable dictionary hyphenation with the given hyphenator.
+    #[must_use]
+    pub fn hyphenator(mut self, hyphenator: Arc<Hyphenator>) -> Self {
+        self.hyphenator = Some(hyphenator);
+        self
+    }
+
+    /// Set whether to justify wrapped lines.
+    #[must_use]
+    pub fn justify(mut self, justify: bool) -> Self {
+        self.justify = justify;
+        self
+    }
 }
 
 impl Default for WrapOptions {
@@ -179,6 +228,13 @@ fn wrap_words(text: &str, options: &WrapOptions, char_fallback: bool) -> Vec<Str
         if !current_line.is_empty() || lines.len() == len_before {
             lines.push(finalize_line(&current_line, options));
         }
+
+        if options.justify {
+            let last = lines.len() - 1;
+            for line in &mut lines[len_before..last] {
+                *line = justify_line(line, options.width);
+            }
+        }
     }
 
     lines
@@ -193,8 +249,35 @@ fn wrap_paragraph(
     current_line: &mut String,
     current_width: &mut usize,
 ) {
-    for word in split_words(text) {
-        let word_width = display_width(&word);
+    for raw_word in split_words(text) {
+        let (word, mut points) = discretionary_breaks(&raw_word, options.hyphenator.as_deref());
+        let mut word = word.into_owned();
+        let mut word_width = display_width(&word);
+
+        // Hyphenate into the remaining room while the word doesn't fit.
+        while *current_width + word_width > options.width {
+            let room = options.width.saturating_sub(*current_width);
+            let Some(at) = points
+                .iter()
+                .rev()
+                .copied()
+                .find(|&at| display_width(&word[..at]) < room)
+            else {
+                break;
+            };
+            current_line.push_str(&word[..at]);
+            current_line.push('-');
+            lines.push(finalize_line(current_line, options));
+            current_line.clear();
+            *current_width = 0;
+
+            word.drain(..at);
+            points.retain(|&p| p > at);
+            for p in &mut points {
+                *p -= at;
+            }
+            word_width = display_width(&word);
+        }
 
         // If word fits on current line
         if *current_width + word_width <= options.width {
@@ -294,6 +377,92 @@ fn split_words(text: &str) -> Vec<String> {
     words
 }
 
+/// Strip soft hyphens from `word` and collect its hyphenation points.
+///
+/// Soft hyphens are author-chosen and take precedence; the dictionary is only
+/// consulted for words without them. Returned offsets index the cleaned word
+/// and exclude its edges.
+fn discretionary_breaks<'a>(
+    word: &'a str,
+    hyphenator: Option<&Hyphenator>,
+) -> (Cow<'a, str>, Vec<usize>) {
+    if word.contains(SOFT_HYPHEN) {
+        let mut clean = String::with_capacity(word.len());
+        let mut points = Vec::new();
+        for part in word.split(SOFT_HYPHEN) {
+            if !clean.is_empty() && !part.is_empty() && points.last() != Some(&clean.len()) {
+                points.push(clean.len());
+            }
+            clean.push_str(part);
+        }
+        return (Cow::Owned(clean), points);
+    }
+    let points = match hyphenator {
+        Some(h) if !word.trim().is_empty() => h.hyphenate(word),
+        _ => Vec::new(),
+    };
+    (Cow::Borrowed(word), points)
+}
+
+/// Justify a line to `width` cells by widening its inter-word gaps.
+///
+/// Extra cells are handed out one at a time, left to right, so gap widths
+/// differ by at most one. Leading indentation is kept as-is and trailing
+/// whitespace is dropped. Lines without interior gaps, or already at least
+/// `width` wide, are returned unchanged apart from the trim.
+///
+/// # Example
+/// ```
+/// use ftui_text::wrap::justify_line;
+///
+/// assert_eq!(justify_line("a bb c", 10), "a   bb   c");
+/// assert_eq!(justify_line("a b c d", 12), "a   b   c  d");
+/// ```
+#[must_use]
+pub fn justify_line(line: &str, width: usize) -> String {
+    let line = line.trim_end();
+    let body = line.trim_start();
+    let indent = &line[..line.len() - body.len()];
+
+    // Alternate word / gap runs over graphemes so wide glyphs stay intact.
+    let mut words: Vec<&str> = Vec::new();
+    let mut gaps: Vec<usize> = Vec::new();
+    let mut start = 0;
+    let mut in_gap = false;
+    for (offset, grapheme) in body.grapheme_indices(true) {
+        let is_ws = grapheme.chars().all(char::is_whitespace);
+        if is_ws != in_gap {
+            if in_gap {
+                gaps.push(display_width(&body[start..offset]));
+            } else {
+                words.push(&body[start..offset]);
+            }
+            start = offset;
+            in_gap = is_ws;
+        }
+    }
+    words.push(&body[start..]);
+
+    let current = display_width(line);
+    if gaps.is_empty() || current >= width {
+        return line.to_string();
+    }
+
+    let extra = width - current;
+    let per_gap = extra / gaps.len();
+    let remainder = extra % gaps.len();
+    let mut out = String::with_capacity(line.len() + extra);
+    out.push_str(indent);
+    for (idx, word) in words.iter().enumerate() {
+        out.push_str(word);
+        if let Some(&gap) = gaps.get(idx) {
+            let cells = gap + per_gap + usize::from(idx < remainder);
+            out.extend(std::iter::repeat_n(' ', cells));
+        }
+    }
+    out
+}
+
 /// Finalize a line (apply trimming, etc.).
 fn finalize_line(line: &str, options: &WrapOptions) -> String {
     let mut result = if options.trim_trailing {
@@ -587,8 +756,9 @@ pub fn word_segments(text: &str) -> impl Iterator<Item = &str> {
 //
 // ## Penalties
 //
-// - PENALTY_HYPHEN: cost for breaking at a hyphen (not yet used, reserved)
-// - PENALTY_FLAGGED: cost for consecutive flagged breaks
+// - PENALTY_HYPHEN: cost for breaking at a soft hyphen or dictionary
+//   hyphenation point (the inserted `-` also counts toward line width)
+// - PENALTY_FLAGGED: cost for consecutive flagged (hyphenated) breaks
 // - PENALTY_FORCE_BREAK: large penalty for forcing a break mid-word
 //
 // ## DP Recurrence
@@ -610,6 +780,12 @@ const BADNESS_SCALE: u64 = 10_000;
 /// Badness value for infeasible lines (overflow).
 const BADNESS_INF: u64 = u64::MAX / 2;
 
+/// Penalty for breaking at a hyphenation point.
+const PENALTY_HYPHEN: u64 = 300;
+
+/// Extra penalty when two consecutive lines both end in a hyphen.
+const PENALTY_FLAGGED: u64 = 3000;
+
 /// Penalty for forcing a mid-word character break.
 const PENALTY_FORCE_BREAK: u64 = 5000;
 
@@ -660,10 +836,15 @@ struct KpWord {
     content_width: usize,
     /// Cell width of the trailing space (0 if none).
     space_width: usize,
+    /// Breaking after this fragment is a hyphenation (renders a `-`).
+    hyphen: bool,
 }
 
 /// Split text into KpWord tokens for Knuth-Plass processing.
-fn kp_tokenize(text: &str) -> Vec<KpWord> {
+///
+/// Words with soft hyphens or dictionary hyphenation points are split into
+/// fragments; every fragment but the last is flagged as a hyphen break.
+fn kp_tokenize(text: &str, hyphenator: Option<&Hyphenator>) -> Vec<KpWord> {
     let mut words = Vec::new();
     let raw_segments: Vec<&str> = text.split_word_bounds().collect();
 
@@ -682,16 +863,23 @@ fn kp_tokenize(text: &str) -> Vec<KpWord> {
                     text: seg.to_string(),
                     content_width: 0,
                     space_width: display_width(seg),
+                    hyphen: false,
                 });
             }
             i += 1;
         } else {
-            let content_width = display_width(seg);
-            words.push(KpWord {
-                text: seg.to_string(),
-                content_width,
-                space_width: 0,
-            });
+            let (clean, points) = discretionary_breaks(seg, hyphenator);
+            let mut start = 0;
+            for end in points.into_iter().chain(std::iter::once(clean.len())) {
+                let fragment = &clean[start..end];
+                words.push(KpWord {
+                    text: fragment.to_string(),
+                    content_width: display_width(fragment),
+                    space_width: 0,
+                    hyphen: end < clean.len(),
+                });
+                start = end;
+            }
             i += 1;
         }
     }
@@ -725,6 +913,18 @@ pub struct KpBreakResult {
 /// # Returns
 /// `KpBreakResult` with optimal lines, total cost, and per-line badness.
 pub fn wrap_optimal(text: &str, width: usize) -> KpBreakResult {
+    wrap_optimal_with(text, &WrapOptions::new(width))
+}
+
+/// Compute optimal line breaks honoring hyphenation and justification.
+///
+/// Uses `options.width`, `options.hyphenator` and `options.justify`; the
+/// other fields only apply to greedy wrapping. Soft hyphens are always
+/// break candidates. Hyphenated breaks carry a fixed penalty plus the
+/// cell taken by the inserted `-`, so they are chosen only when they
+/// noticeably tighten the paragraph.
+pub fn wrap_optimal_with(text: &str, options: &WrapOptions) -> KpBreakResult {
+    let width = options.width;
     if width == 0 || text.is_empty() {
         return KpBreakResult {
             lines: vec![text.to_string()],
@@ -733,7 +933,7 @@ pub fn wrap_optimal(text: &str, width: usize) -> KpBreakResult {
         };
     }
 
-    let words = kp_tokenize(text);
+    let words = kp_tokenize(text, options.hyphenator.as_deref());
     if words.is_empty() {
         return KpBreakResult {
             lines: vec![text.to_string()],
@@ -751,7 +951,13 @@ pub fn wrap_optimal(text: &str, width: usize) -> KpBreakResult {
     cost[0] = 0;
 
     for j in 1..=n {
-        let mut line_width: usize = 0;
+        // A hyphenated break adds the rendered `-` to the line.
+        let mut line_width = usize::from(words[j - 1].hyphen);
+        let break_penalty = if words[j - 1].hyphen {
+            PENALTY_HYPHEN
+        } else {
+            0
+        };
         // Try all possible line starts i (going backwards from j).
         // Bounded by KP_MAX_LOOKAHEAD to keep runtime O(n × lookahead).
         let earliest = j.saturating_sub(KP_MAX_LOOKAHEAD);
@@ -778,7 +984,15 @@ pub fn wrap_optimal(text: &str, width: usize) -> KpBreakResult {
                 knuth_plass_badness(slack, width, is_last)
             };
 
-            let candidate = cost[i].saturating_add(badness);
+            let flagged = if break_penalty > 0 && i > 0 && words[i - 1].hyphen {
+                PENALTY_FLAGGED
+            } else {
+                0
+            };
+            let candidate = cost[i]
+                .saturating_add(badness)
+                .saturating_add(break_penalty)
+                .saturating_add(flagged);
             // Tie-breaking: prefer later break (fewer lines)
             if candidate < cost[j] || (candidate == cost[j] && i > from[j]) {
                 cost[j] = candidate;
@@ -813,6 +1027,9 @@ pub fn wrap_optimal(text: &str, width: usize) -> KpBreakResult {
         for word in words.iter().take(end).skip(start) {
             line.push_str(&word.text);
         }
+        if words[end - 1].hyphen {
+            line.push('-');
+        }
 
         // Trim trailing whitespace from each line
         let trimmed = line.trim_end().to_string();
@@ -827,7 +1044,11 @@ pub fn wrap_optimal(text: &str, width: usize) -> KpBreakResult {
             knuth_plass_badness(slack, width, is_last)
         };
 
-        lines.push(trimmed);
+        if options.justify && !is_last {
+            lines.push(justify_line(&trimmed, width));
+        } else {
+            lines.push(trimmed);
+        }
         line_badness.push(bad);
     }
 
@@ -843,6 +1064,14 @@ pub fn wrap_optimal(text: &str, width: usize) -> KpBreakResult {
 /// Handles multiple paragraphs separated by `\n`.
 #[must_use]
 pub fn wrap_text_optimal(text: &str, width: usize) -> Vec<String> {
+    wrap_text_optimal_with(text, &WrapOptions::new(width))
+}
+
+/// Wrap multi-paragraph text optimally with hyphenation and justification.
+///
+/// See [`wrap_optimal_with`] for which options apply.
+#[must_use]
+pub fn wrap_text_optimal_with(text: &str, options: &WrapOptions) -> Vec<String> {
     let mut result = Vec::new();
     for raw_paragraph in text.split('\n') {
         let paragraph = raw_paragraph.strip_suffix('\r').unwrap_or(raw_paragraph);
@@ -850,7 +1079,7 @@ pub fn wrap_text_optimal(text: &str, width: usize) -> Vec<String> {
             result.push(String::new());
             continue;
         }
-        let kp = wrap_optimal(paragraph, width);
+        let kp = wrap_optimal_with(paragraph, options);
         result.extend(kp.lines);
     }
     result
@@ -1729,7 +1958,7 @@ mod tests {
 
     #[test]
     fn kp_tokenize_basic() {
-        let words = kp_tokenize("hello world foo");
+        let words = kp_tokenize("hello world foo", None);
         assert_eq!(words.len(), 3);
         assert_eq!(words[0].content_width, 5);
         assert_eq!(words[0].space_width, 1);
@@ -2085,6 +2314,123 @@ mod tests {
             }
         }
     }
+
+    // =========================================================================
+    // Hyphenation + Justification
+    // =========================================================================
+
+    #[cfg(feature = "hyphenation")]
+    fn hyphenated(width: usize) -> WrapOptions {
+        WrapOptions::new(width).hyphenator(Hyphenator::english())
+    }
+
+    #[test]
+    fn justify_distributes_left_to_right() {
+        assert_eq!(justify_line("a b c d", 10), "a  b  c  d");
+        assert_eq!(justify_line("a b c d", 11), "a   b  c  d");
+        assert_eq!(justify_line("one two", 7), "one two");
+    }
+
+    #[test]
+    fn justify_keeps_indent_and_drops_trailing_space() {
+        assert_eq!(justify_line("  ab cd  ", 10), "  ab    cd");
+    }
+
+    #[test]
+    fn justify_leaves_single_words_and_overfull_lines() {
+        assert_eq!(justify_line("word", 10), "word");
+        assert_eq!(justify_line("too wide here", 5), "too wide here");
+    }
+
+    #[test]
+    fn justify_is_width_correct_for_wide_glyphs() {
+        let line = justify_line("\u{4F60}\u{597D} \u{1F600} ok", 12);
+        assert_eq!(line.width(), 12);
+        assert!(line.starts_with("\u{4F60}\u{597D}"));
+        assert!(line.ends_with(" ok"));
+    }
+
+    #[test]
+    fn soft_hyphens_are_invisible_unless_broken() {
+        let text = "con\u{AD}cat\u{AD}enate";
+        assert_eq!(wrap_text(text, 20, WrapMode::Word), vec!["concatenate"]);
+        let lines = wrap_text(&format!("to {text}"), 8, WrapMode::Word);
+        assert_eq!(lines, vec!["to con-", "catenate"]);
+        assert!(lines.iter().all(|l| !l.contains(SOFT_HYPHEN)));
+    }
+
+    #[test]
+    #[cfg(feature = "hyphenation")]
+    fn soft_hyphens_override_dictionary() {
+        // The dictionary would split "ex-ten-sive"; the author's choice wins.
+        let lines = wrap_with_options("a exten\u{AD}sive", &hyphenated(8));
+        assert_eq!(lines, vec!["a exten-", "sive"]);
+    }
+
+    #[test]
+    #[cfg(feature = "hyphenation")]
+    fn greedy_hyphenates_into_remaining_room() {
+        let lines = wrap_with_options("an extensive test", &hyphenated(10));
+        assert_eq!(lines, vec!["an exten-", "sive test"]);
+        for line in &lines {
+            assert!(line.width() <= 10);
+        }
+    }
+
+    #[test]
+    fn greedy_without_hyphenator_is_unchanged() {
+        let plain = wrap_text("an extensive test", 10, WrapMode::Word);
+        assert_eq!(plain, vec!["an", "extensive", "test"]);
+    }
+
+    #[test]
+    fn greedy_justify_skips_paragraph_last_lines() {
+        let options = WrapOptions::new(10).justify(true);
+        let lines = wrap_with_options("aa bb cc dd\nee ff gg hh", &options);
+        assert_eq!(lines, vec!["aa  bb  cc", "dd", "ee  ff  gg", "hh"]);
+    }
+
+    #[test]
+    fn kp_tokenize_splits_hyphenation_fragments() {
+        let words = kp_tokenize("un\u{AD}do it", None);
+        let texts: Vec<&str> = words.iter().map(|w| w.text.as_str()).collect();
+        assert_eq!(texts, vec!["un", "do ", "it"]);
+        assert!(words[0].hyphen);
+        assert!(!words[1].hyphen);
+        assert_eq!(words[0].content_width, 2);
+    }
+
+    #[test]
+    #[cfg(feature = "hyphenation")]
+    fn kp_uses_hyphen_when_it_tightens_lines() {
+        let text = "the documentation explains";
+        let plain = wrap_optimal(text, 12);
+        let hyph = wrap_optimal_with(text, &hyphenated(12));
+        assert!(hyph.total_cost < plain.total_cost);
+        assert!(hyph.lines.iter().any(|l| l.ends_with('-')));
+        for line in &hyph.lines {
+            assert!(line.width() <= 12, "line '{line}' exceeds width");
+        }
+        let rejoined: String = hyph
+            .lines
+            .iter()
+            .map(|l| l.strip_suffix('-').unwrap_or(l).to_string() + " ")
+            .collect();
+        assert_eq!(rejoined.replace(' ', ""), text.replace(' ', ""));
+    }
+
+    #[test]
+    fn kp_justify_fills_all_but_last_line() {
+        let options = WrapOptions::new(16).justify(true);
+        let lines = wrap_text_optimal_with("one two three four five six seven", &options);
+        let (last, body) = lines.split_last().unwrap();
+        assert!(!body.is_empty());
+        for line in body {
+            assert_eq!(line.width(), 16, "line '{line}' not justified");
+        }
+        assert!(last.width() <= 16);
+        assert!(!last.contains("  "));
+    }
 }
 
 #[cfg(test)]
@@ -2186,6 +2532,34 @@ mod proptests {
             }
         }
 
+        /// Property: justified lines with interior gaps hit the target width.
+        #[test]
+        fn property_justify_fills_width(
+            text in "[a-z]{1,4}( [a-z]{1,4}){1,5}",
+            extra in 0usize..10,
+        ) {
+            let width = text.width() + extra;
+            let line = justify_line(&text, width);
+            prop_assert_eq!(line.width(), width);
+            prop_assert_eq!(line.split_whitespace().collect::<Vec<_>>(), text.split_whitespace().collect::<Vec<_>>());
+        }
+
+        /// Property: hyphenated DP output never exceeds width when words fit.
+        #[test]
+        #[cfg(feature = "hyphenation")]
+        fn property_hyphenated_dp_respects_width(
+            text in "[a-z]{1,8}( [a-z]{1,8}){1,12}",
+            width in 10usize..30,
+        ) {
+            let options = WrapOptions::new(width)
+                .hyphenator(Hyphenator::english())
+                .justify(true);
+            let result = wrap_optimal_with(&text, &options);
+            for line in &result.lines {
+                prop_assert!(line.width() <= width, "line '{}' exceeds {}", line, width);
+            }
+        }
+
         /// Property: DP preserves all non-whitespace content.
         #[test]
         fn property_dp_preserves_content(
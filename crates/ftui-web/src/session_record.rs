@@ -47,8 +47,8 @@
 use core::time::Duration;
 
 use ftui_core::event::{
-    ClipboardEvent, ClipboardSource, Event, KeyCode, KeyEvent, KeyEventKind, Modifiers,
-    MouseButton, MouseEvent, MouseEventKind, PasteEvent,
+    BackgroundColor, ClipboardEvent, ClipboardSource, Event, KeyCode, KeyEvent, KeyEventKind,
+    Modifiers, MouseButton, MouseEvent, MouseEventKind, PasteEvent,
 };
 use ftui_runtime::render_trace::checksum_buffer;
 
@@ -601,6 +601,12 @@ fn event_to_json(event: &Event) -> String {
                 source
             )
         }
+        Event::BackgroundColor(c) => {
+            format!(
+                r#"{{"kind":"background_color","r":{},"g":{},"b":{}}}"#,
+                c.r, c.g, c.b
+            )
+        }
         Event::Tick => r#"{"kind":"tick"}"#.to_string(),
     }
 }
@@ -1189,6 +1195,18 @@ fn parse_event_json(data: &str) -> Result<Event, String> {
             };
             Ok(Event::Clipboard(ClipboardEvent::new(content, source)))
         }
+        "background_color" => {
+            let component = |key| {
+                extract_u64(data, key)
+                    .and_then(|v| u8::try_from(v).ok())
+                    .ok_or(format!("missing {key}"))
+            };
+            Ok(Event::BackgroundColor(BackgroundColor::new(
+                component("r")?,
+                component("g")?,
+                component("b")?,
+            )))
+        }
         "tick" => Ok(Event::Tick),
         other => Err(format!("unknown event kind: {other}")),
     }
@@ -2216,6 +2234,7 @@ mod tests {
             Event::Paste(PasteEvent::bracketed("hello world")),
             Event::Focus(true),
             Event::Focus(false),
+            Event::BackgroundColor(BackgroundColor::new(30, 30, 46)),
             Event::Tick,
         ];
 
@@ -413,6 +413,9 @@ impl<M: Model> StepProgram<M> {
                 features.mouse_capture = enabled;
                 let _ = self.backend.events_mut().set_features(features);
             }
+            Cmd::QueryBackground => {
+                // The browser host knows its own background; nothing to ask.
+            }
             Cmd::SaveState | Cmd::RestoreState => {
                 // No persistence in WASM (yet).
             }
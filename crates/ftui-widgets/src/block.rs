@@ -3,12 +3,13 @@
 use crate::Widget;
 use crate::borders::{BorderSet, BorderType, Borders};
 use crate::measurable::{MeasurableWidget, SizeConstraints};
+use crate::sheet::{self, SheetStyled};
 use crate::{apply_style, draw_text_span, set_style_area};
 use ftui_core::geometry::{Rect, Size};
 use ftui_render::buffer::Buffer;
 use ftui_render::cell::Cell;
 use ftui_render::frame::Frame;
-use ftui_style::Style;
+use ftui_style::{SelectorSheet, Style, StyleNode};
 use ftui_text::{grapheme_width, graphemes};
 
 /// A widget that draws a block with optional borders, title, and padding.
@@ -346,6 +347,18 @@ impl Widget for Block<'_> {
     }
 }
 
+impl SheetStyled for Block<'_> {
+    const TYPE_NAME: &'static str = "Block";
+
+    fn styled_by(mut self, sheet: &SelectorSheet, path: &[StyleNode<'_>]) -> Self {
+        self.style = self.style.merge(&sheet.resolve(path));
+        self.border_style =
+            self.border_style
+                .merge(&sheet::part_style(sheet, path, StyleNode::new("Border")));
+        self
+    }
+}
+
 impl MeasurableWidget for Block<'_> {
     fn measure(&self, _available: Size) -> SizeConstraints {
         let (chrome_width, chrome_height) = self.chrome_size();
@@ -960,4 +973,22 @@ mod tests {
         let b = block.measure(Size::new(100, 50));
         assert_eq!(a, b);
     }
+
+    #[test]
+    fn styled_by_resolves_block_and_border() {
+        let sheet =
+            SelectorSheet::parse("Panel Block { bg: #101010; } Block > Border { fg: #ff0000; }")
+                .unwrap();
+        let path = [StyleNode::new("Panel"), StyleNode::new(Block::TYPE_NAME)];
+        let block = Block::new()
+            .border_style(Style::new().fg(PackedRgba::rgb(0, 255, 0)))
+            .styled_by(&sheet, &path);
+        assert_eq!(block.style.bg, Some(PackedRgba::rgb(16, 16, 16)));
+        // Builder-set styles win over the sheet.
+        assert_eq!(block.border_style.fg, Some(PackedRgba::rgb(0, 255, 0)));
+
+        let block = Block::new().styled_by(&sheet, &[StyleNode::new(Block::TYPE_NAME)]);
+        assert_eq!(block.style.bg, None);
+        assert_eq!(block.border_style.fg, Some(PackedRgba::rgb(255, 0, 0)));
+    }
 }
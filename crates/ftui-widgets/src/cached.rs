@@ -1,6 +1,9 @@
 #![forbid(unsafe_code)]
 
 //! Cached widget wrapper with manual invalidation and optional cache keys.
+//!
+//! Cached buffers bake in resolved colors, so they are also re-rendered
+//! whenever the theme epoch ([`ftui_style::theme_epoch`]) advances.
 
 use crate::{StatefulWidget, Widget};
 use ftui_core::geometry::Rect;
@@ -71,6 +74,7 @@ pub struct CachedWidgetState {
     last_area: Option<Rect>,
     dirty: bool,
     last_key: Option<u64>,
+    last_theme_epoch: u64,
 }
 
 #[cfg(feature = "tracing")]
@@ -80,6 +84,7 @@ enum CacheMissReason {
     Dirty,
     AreaChanged,
     KeyChanged,
+    ThemeChanged,
 }
 
 impl<W> CachedWidget<W, NoCacheKey> {
@@ -188,8 +193,11 @@ impl<W: Widget, K: CacheKey<W>> StatefulWidget for CachedWidget<W, K> {
         let key = self.key.cache_key(&self.inner);
         let area_changed = state.last_area != Some(area);
         let key_changed = key != state.last_key;
+        let theme_epoch = ftui_style::theme_epoch();
+        let theme_changed = theme_epoch != state.last_theme_epoch;
 
-        let needs_render = state.cache.is_none() || state.dirty || area_changed || key_changed;
+        let needs_render =
+            state.cache.is_none() || state.dirty || area_changed || key_changed || theme_changed;
 
         #[cfg(feature = "tracing")]
         let reason = if state.cache.is_none() {
@@ -198,8 +206,10 @@ impl<W: Widget, K: CacheKey<W>> StatefulWidget for CachedWidget<W, K> {
             CacheMissReason::Dirty
         } else if area_changed {
             CacheMissReason::AreaChanged
-        } else {
+        } else if key_changed {
             CacheMissReason::KeyChanged
+        } else {
+            CacheMissReason::ThemeChanged
         };
 
         if needs_render {
@@ -214,6 +224,7 @@ impl<W: Widget, K: CacheKey<W>> StatefulWidget for CachedWidget<W, K> {
             state.last_area = Some(area);
             state.dirty = false;
             state.last_key = key;
+            state.last_theme_epoch = theme_epoch;
 
             #[cfg(feature = "tracing")]
             debug!(
@@ -439,6 +450,29 @@ mod tests {
         assert!(!state.dirty);
     }
 
+    #[test]
+    fn theme_epoch_change_rerenders() {
+        let count = Rc::new(CounterCell::new(0));
+        let widget = CountWidget {
+            count: count.clone(),
+        };
+        let cached = CachedWidget::new(widget);
+        let mut state = CachedWidgetState::new();
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(5, 5, &mut pool);
+        let area = Rect::new(0, 0, 3, 3);
+
+        cached.render(area, &mut frame, &mut state);
+        assert_eq!(state.last_theme_epoch, ftui_style::theme_epoch());
+
+        // Simulate a theme swap since the buffer was cached.
+        state.last_theme_epoch = state.last_theme_epoch.wrapping_sub(1);
+        cached.render(area, &mut frame, &mut state);
+        assert_eq!(count.get(), 2);
+        cached.render(area, &mut frame, &mut state);
+        assert_eq!(count.get(), 2);
+    }
+
     #[test]
     fn no_cache_key_returns_none() {
         let key = NoCacheKey;
@@ -0,0 +1,835 @@
+#![forbid(unsafe_code)]
+
+//! Month-grid calendar widget and a dependency-free civil [`Date`] type.
+//!
+//! The grid starts on a configurable [`Weekday`] (see
+//! [`Weekday::first_for_locale`] or `LocaleContext::first_weekday` in the
+//! runtime), and supports highlighted date ranges, event markers, min/max
+//! bounds, and keyboard/mouse navigation through [`CalendarState`].
+//!
+//! Dates are proleptic Gregorian with no time zone attached; callers decide
+//! what "today" means. [`Date::today_utc`] is provided for the common case.
+//!
+//! ```text
+//!     October 2026
+//! Mo Tu We Th Fr Sa Su
+//!           1  2  3  4
+//!  5  6  7  8  9 10 11
+//! 12 13 14 15 16 17 18•
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::{MeasurableWidget, SizeConstraints, StatefulWidget, apply_style, draw_text_span};
+use ftui_core::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ftui_core::geometry::{Rect, Size};
+use ftui_render::cell::Cell;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+use ftui_text::display_width;
+
+pub use ftui_i18n::Weekday;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Columns per day cell: two digits plus a marker/gap column.
+const DAY_CELL_WIDTH: u16 = 3;
+
+/// A calendar date (proleptic Gregorian, no time zone).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// Unix epoch, 1970-01-01.
+    pub const EPOCH: Self = Self {
+        year: 1970,
+        month: 1,
+        day: 1,
+    };
+
+    /// Create a date, returning `None` if the month or day is out of range.
+    #[must_use]
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    /// Year.
+    #[must_use]
+    pub const fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Month (`1..=12`).
+    #[must_use]
+    pub const fn month(self) -> u8 {
+        self.month
+    }
+
+    /// Day of month (`1..=31`).
+    #[must_use]
+    pub const fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Days since 1970-01-01 (negative before the epoch).
+    #[must_use]
+    pub fn to_days(self) -> i64 {
+        // Howard Hinnant's days_from_civil.
+        let y = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let m = i64::from(self.month);
+        let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    /// Date from days since 1970-01-01.
+    #[must_use]
+    pub fn from_days(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+
+    /// Current UTC date from the system clock.
+    #[must_use]
+    pub fn today_utc() -> Self {
+        let secs = web_time::SystemTime::now()
+            .duration_since(web_time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        Self::from_days((secs / 86_400) as i64)
+    }
+
+    /// Day of the week.
+    #[must_use]
+    pub fn weekday(self) -> Weekday {
+        // 1970-01-01 was a Thursday (index 3 from Monday).
+        Weekday::from_index((self.to_days() + 3).rem_euclid(7) as u8)
+    }
+
+    /// Shift by a number of days.
+    #[must_use]
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days(self.to_days() + days)
+    }
+
+    /// Shift by a number of months, clamping the day to the target month.
+    ///
+    /// Saturates at the first and last months representable by an `i32` year.
+    #[must_use]
+    pub fn add_months(self, months: i32) -> Self {
+        let index = (i64::from(self.year) * 12 + i64::from(self.month) - 1 + i64::from(months))
+            .clamp(i64::from(i32::MIN) * 12, i64::from(i32::MAX) * 12 + 11);
+        let year = index.div_euclid(12) as i32;
+        let month = (index.rem_euclid(12) + 1) as u8;
+        let day = self.day.min(days_in_month(year, month));
+        Self { year, month, day }
+    }
+
+    /// First day of this date's month.
+    #[must_use]
+    pub const fn first_of_month(self) -> Self {
+        Self {
+            year: self.year,
+            month: self.month,
+            day: 1,
+        }
+    }
+
+    /// Clamp into optional inclusive bounds.
+    #[must_use]
+    pub fn clamp_to(self, min: Option<Date>, max: Option<Date>) -> Self {
+        let mut date = self;
+        if let Some(max) = max {
+            date = date.min(max);
+        }
+        if let Some(min) = min {
+            date = date.max(min);
+        }
+        date
+    }
+
+    /// Parse an ISO 8601 calendar date (`YYYY-MM-DD`).
+    #[must_use]
+    pub fn parse_iso(s: &str) -> Option<Self> {
+        let mut parts = s.trim().splitn(3, '-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let day = parts.next()?.parse().ok()?;
+        Self::new(year, month, day)
+    }
+}
+
+impl Default for Date {
+    fn default() -> Self {
+        Self::EPOCH
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Whether `year` is a Gregorian leap year.
+#[must_use]
+pub const fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Number of days in a month (`0` for an invalid month).
+#[must_use]
+pub const fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// English month name for `1..=12`.
+#[must_use]
+pub fn month_name(month: u8) -> &'static str {
+    MONTH_NAMES
+        .get(usize::from(month.wrapping_sub(1)))
+        .copied()
+        .unwrap_or("")
+}
+
+/// Inclusive range of dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    /// First date in the range.
+    pub start: Date,
+    /// Last date in the range.
+    pub end: Date,
+}
+
+impl DateRange {
+    /// Create a range; the endpoints are reordered if needed.
+    #[must_use]
+    pub fn new(a: Date, b: Date) -> Self {
+        Self {
+            start: a.min(b),
+            end: a.max(b),
+        }
+    }
+
+    /// Whether `date` lies within the range.
+    #[must_use]
+    pub fn contains(&self, date: Date) -> bool {
+        self.start <= date && date <= self.end
+    }
+}
+
+// ---------------------------------------------------------------------------
+// CalendarState
+// ---------------------------------------------------------------------------
+
+/// Navigation state for a [`Calendar`].
+///
+/// The cursor determines the displayed month. Navigation is clamped to the
+/// optional `min`/`max` bounds.
+#[derive(Debug, Clone, Default)]
+pub struct CalendarState {
+    /// Date under the keyboard cursor.
+    pub cursor: Date,
+    /// Confirmed selection.
+    pub selected: Option<Date>,
+    min: Option<Date>,
+    max: Option<Date>,
+    last_area: Rect,
+    last_first_weekday: Option<Weekday>,
+}
+
+impl CalendarState {
+    /// Create state with the cursor on `cursor`.
+    #[must_use]
+    pub fn new(cursor: Date) -> Self {
+        Self {
+            cursor,
+            ..Self::default()
+        }
+    }
+
+    /// Restrict navigation and selection to inclusive bounds.
+    #[must_use]
+    pub fn with_bounds(mut self, min: Option<Date>, max: Option<Date>) -> Self {
+        self.set_bounds(min, max);
+        self
+    }
+
+    /// Update bounds in place, clamping the cursor.
+    pub fn set_bounds(&mut self, min: Option<Date>, max: Option<Date>) {
+        self.min = min;
+        self.max = max;
+        self.cursor = self.cursor.clamp_to(min, max);
+    }
+
+    /// Lower bound, if any.
+    #[must_use]
+    pub fn min(&self) -> Option<Date> {
+        self.min
+    }
+
+    /// Upper bound, if any.
+    #[must_use]
+    pub fn max(&self) -> Option<Date> {
+        self.max
+    }
+
+    /// Whether `date` lies within the bounds.
+    #[must_use]
+    pub fn in_bounds(&self, date: Date) -> bool {
+        self.min.is_none_or(|min| date >= min) && self.max.is_none_or(|max| date <= max)
+    }
+
+    /// Move the cursor, clamped to bounds. Returns `true` if it moved.
+    pub fn move_to(&mut self, date: Date) -> bool {
+        let date = date.clamp_to(self.min, self.max);
+        let changed = date != self.cursor;
+        self.cursor = date;
+        changed
+    }
+
+    /// Select the cursor date.
+    pub fn select_cursor(&mut self) -> bool {
+        if !self.in_bounds(self.cursor) || self.selected == Some(self.cursor) {
+            return false;
+        }
+        self.selected = Some(self.cursor);
+        true
+    }
+
+    /// Handle a key press. Returns `true` if the state changed.
+    ///
+    /// Arrows move by day/week, PageUp/PageDown by month, Home/End jump to
+    /// the start/end of the week (using the week start from the last render),
+    /// and Enter/Space select the cursor date.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> bool {
+        let week_start = self.last_first_weekday.unwrap_or(Weekday::Monday);
+        let offset = i64::from(self.cursor.weekday().days_since(week_start));
+        match key.code {
+            KeyCode::Left => self.move_to(self.cursor.add_days(-1)),
+            KeyCode::Right => self.move_to(self.cursor.add_days(1)),
+            KeyCode::Up => self.move_to(self.cursor.add_days(-7)),
+            KeyCode::Down => self.move_to(self.cursor.add_days(7)),
+            KeyCode::PageUp => self.move_to(self.cursor.add_months(-1)),
+            KeyCode::PageDown => self.move_to(self.cursor.add_months(1)),
+            KeyCode::Home => self.move_to(self.cursor.add_days(-offset)),
+            KeyCode::End => self.move_to(self.cursor.add_days(6 - offset)),
+            KeyCode::Enter | KeyCode::Char(' ') => self.select_cursor(),
+            _ => false,
+        }
+    }
+
+    /// Handle a mouse event against the last rendered grid. A left click on
+    /// an in-bounds day moves the cursor there and selects it.
+    pub fn handle_mouse(&mut self, event: &MouseEvent) -> bool {
+        if event.kind != MouseEventKind::Down(MouseButton::Left) {
+            return false;
+        }
+        let Some(date) = self.date_at(event.x, event.y) else {
+            return false;
+        };
+        if !self.in_bounds(date) {
+            return false;
+        }
+        let moved = self.move_to(date);
+        self.select_cursor() || moved
+    }
+
+    /// Date shown at a screen position in the last rendered grid.
+    #[must_use]
+    pub fn date_at(&self, x: u16, y: u16) -> Option<Date> {
+        let area = self.last_area;
+        let week_start = self.last_first_weekday?;
+        if !area.contains(x, y) || y < area.y + 2 {
+            return None;
+        }
+        let col = (x - area.x) / DAY_CELL_WIDTH;
+        if col >= 7 {
+            return None;
+        }
+        let row = y - area.y - 2;
+        let first = self.cursor.first_of_month();
+        let lead = i64::from(first.weekday().days_since(week_start));
+        let index = i64::from(row) * 7 + i64::from(col) - lead;
+        let days = i64::from(days_in_month(first.year, first.month));
+        (0..days).contains(&index).then(|| first.add_days(index))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Calendar
+// ---------------------------------------------------------------------------
+
+/// Month-grid calendar widget.
+///
+/// Renders a title row, a weekday header row, and up to six week rows for the
+/// month containing [`CalendarState::cursor`]. Each day occupies three
+/// columns: two for the day number and one for an event marker.
+#[derive(Debug, Clone)]
+pub struct Calendar {
+    first_weekday: Weekday,
+    style: Style,
+    title_style: Style,
+    header_style: Style,
+    day_style: Style,
+    disabled_style: Style,
+    selected_style: Style,
+    cursor_style: Style,
+    today_style: Style,
+    marker_style: Style,
+    marker_symbol: char,
+    show_cursor: bool,
+    today: Option<Date>,
+    ranges: Vec<(DateRange, Style)>,
+    markers: BTreeMap<Date, Option<char>>,
+}
+
+impl Default for Calendar {
+    fn default() -> Self {
+        Self {
+            first_weekday: Weekday::Monday,
+            style: Style::default(),
+            title_style: Style::default(),
+            header_style: Style::default(),
+            day_style: Style::default(),
+            disabled_style: Style::default(),
+            selected_style: Style::new().reverse(),
+            cursor_style: Style::new().reverse(),
+            today_style: Style::default(),
+            marker_style: Style::default(),
+            marker_symbol: '•',
+            show_cursor: true,
+            today: None,
+            ranges: Vec::new(),
+            markers: BTreeMap::new(),
+        }
+    }
+}
+
+impl Calendar {
+    /// Width of the grid in cells.
+    pub const WIDTH: u16 = 7 * DAY_CELL_WIDTH;
+
+    /// Height of the grid in cells (title + header + six weeks).
+    pub const HEIGHT: u16 = 8;
+
+    /// Create a calendar starting weeks on Monday.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the first day of the week.
+    #[must_use]
+    pub fn first_weekday(mut self, weekday: Weekday) -> Self {
+        self.first_weekday = weekday;
+        self
+    }
+
+    /// Set the first day of the week from a locale tag (e.g. `"en-US"`).
+    #[must_use]
+    pub fn locale(self, locale: &str) -> Self {
+        self.first_weekday(Weekday::first_for_locale(locale))
+    }
+
+    /// Set the base style.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the month/year title style.
+    #[must_use]
+    pub fn title_style(mut self, style: Style) -> Self {
+        self.title_style = style;
+        self
+    }
+
+    /// Set the weekday header style.
+    #[must_use]
+    pub fn header_style(mut self, style: Style) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    /// Set the style for ordinary days.
+    #[must_use]
+    pub fn day_style(mut self, style: Style) -> Self {
+        self.day_style = style;
+        self
+    }
+
+    /// Set the style for days outside the state's bounds.
+    #[must_use]
+    pub fn disabled_style(mut self, style: Style) -> Self {
+        self.disabled_style = style;
+        self
+    }
+
+    /// Set the style for the selected date.
+    #[must_use]
+    pub fn selected_style(mut self, style: Style) -> Self {
+        self.selected_style = style;
+        self
+    }
+
+    /// Set the style for the cursor date.
+    #[must_use]
+    pub fn cursor_style(mut self, style: Style) -> Self {
+        self.cursor_style = style;
+        self
+    }
+
+    /// Show or hide the cursor highlight (e.g. when unfocused).
+    #[must_use]
+    pub fn show_cursor(mut self, show: bool) -> Self {
+        self.show_cursor = show;
+        self
+    }
+
+    /// Mark a date as today.
+    #[must_use]
+    pub fn today(mut self, today: Date, style: Style) -> Self {
+        self.today = Some(today);
+        self.today_style = style;
+        self
+    }
+
+    /// Highlight an inclusive date range. Later ranges take precedence.
+    #[must_use]
+    pub fn highlight(mut self, range: DateRange, style: Style) -> Self {
+        self.ranges.push((range, style));
+        self
+    }
+
+    /// Add an event marker using the default symbol.
+    #[must_use]
+    pub fn marker(mut self, date: Date) -> Self {
+        self.markers.insert(date, None);
+        self
+    }
+
+    /// Add an event marker with a custom symbol.
+    #[must_use]
+    pub fn marker_with(mut self, date: Date, symbol: char) -> Self {
+        self.markers.insert(date, Some(symbol));
+        self
+    }
+
+    /// Set the default marker symbol and style.
+    #[must_use]
+    pub fn marker_style(mut self, symbol: char, style: Style) -> Self {
+        self.marker_symbol = symbol;
+        self.marker_style = style;
+        self
+    }
+
+    fn day_cell_style(&self, date: Date, state: &CalendarState) -> Style {
+        let mut style = if state.in_bounds(date) {
+            self.day_style
+        } else {
+            self.disabled_style.merge(&self.day_style)
+        };
+        for (range, range_style) in &self.ranges {
+            if range.contains(date) {
+                style = range_style.merge(&style);
+            }
+        }
+        if self.today == Some(date) {
+            style = self.today_style.merge(&style);
+        }
+        if state.selected == Some(date) {
+            style = self.selected_style.merge(&style);
+        }
+        if self.show_cursor && state.cursor == date {
+            style = self.cursor_style.merge(&style);
+        }
+        style
+    }
+}
+
+impl StatefulWidget for Calendar {
+    type State = CalendarState;
+
+    fn render(&self, area: Rect, frame: &mut Frame, state: &mut Self::State) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "widget_render",
+            widget = "Calendar",
+            x = area.x,
+            y = area.y,
+            w = area.width,
+            h = area.height
+        )
+        .entered();
+
+        state.last_area = area;
+        state.last_first_weekday = Some(self.first_weekday);
+        if area.is_empty() {
+            return;
+        }
+        let styled = frame.buffer.degradation.apply_styling();
+        let style_for = |style: Style| if styled { style } else { Style::default() };
+        crate::set_style_area(&mut frame.buffer, area, style_for(self.style));
+
+        let max_x = area.right();
+        let first = state.cursor.first_of_month();
+        let title = format!("{} {}", month_name(first.month), first.year);
+        let grid_width = Self::WIDTH.min(area.width);
+        let title_x = area.x + grid_width.saturating_sub(display_width(&title) as u16) / 2;
+        draw_text_span(
+            frame,
+            title_x,
+            area.y,
+            &title,
+            style_for(self.title_style),
+            max_x,
+        );
+
+        if area.height < 2 {
+            return;
+        }
+        let mut weekday = self.first_weekday;
+        for col in 0..7u16 {
+            let x = area.x + col * DAY_CELL_WIDTH;
+            if x >= max_x {
+                break;
+            }
+            draw_text_span(
+                frame,
+                x,
+                area.y + 1,
+                weekday.short_name(),
+                style_for(self.header_style),
+                max_x,
+            );
+            weekday = weekday.succ();
+        }
+
+        let lead = u16::from(first.weekday().days_since(self.first_weekday));
+        for index in 0..u16::from(days_in_month(first.year, first.month)) {
+            let slot = lead + index;
+            let y = area.y + 2 + slot / 7;
+            if y >= area.bottom() {
+                break;
+            }
+            let x = area.x + (slot % 7) * DAY_CELL_WIDTH;
+            if x >= max_x {
+                continue;
+            }
+            let date = first.add_days(i64::from(index));
+            let label = format!("{:>2}", date.day);
+            draw_text_span(
+                frame,
+                x,
+                y,
+                &label,
+                style_for(self.day_cell_style(date, state)),
+                max_x,
+            );
+            if let Some(symbol) = self.markers.get(&date)
+                && x + 2 < max_x
+            {
+                let mut cell = Cell::from_char(symbol.unwrap_or(self.marker_symbol));
+                apply_style(&mut cell, style_for(self.marker_style));
+                frame.buffer.set_fast(x + 2, y, cell);
+            }
+        }
+    }
+}
+
+impl MeasurableWidget for Calendar {
+    fn measure(&self, _available: Size) -> SizeConstraints {
+        let size = Size::new(Self::WIDTH, Self::HEIGHT);
+        SizeConstraints {
+            min: Size::new(Self::WIDTH - 1, 3),
+            preferred: size,
+            max: Some(size),
+        }
+    }
+
+    fn has_intrinsic_size(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_core::event::Modifiers;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn date(y: i32, m: u8, d: u8) -> Date {
+        Date::new(y, m, d).expect("valid date")
+    }
+
+    fn row(frame: &Frame, y: u16) -> String {
+        (0..Calendar::WIDTH)
+            .map(|x| {
+                frame
+                    .buffer
+                    .get(x, y)
+                    .and_then(|c| c.content.as_char())
+                    .unwrap_or(' ')
+            })
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    #[test]
+    fn date_day_conversion_round_trips() {
+        assert_eq!(Date::EPOCH.to_days(), 0);
+        assert_eq!(date(2000, 3, 1).to_days(), 11_017);
+        assert_eq!(Date::from_days(-1), date(1969, 12, 31));
+        for days in [-800_000, -1, 0, 59, 10_957, 20_744, 1_000_000] {
+            assert_eq!(Date::from_days(days).to_days(), days);
+        }
+    }
+
+    #[test]
+    fn date_validation_and_arithmetic() {
+        assert!(Date::new(2023, 2, 29).is_none());
+        assert!(Date::new(2024, 2, 29).is_some());
+        assert!(Date::new(2024, 13, 1).is_none());
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 1, 15).add_months(-13), date(2022, 12, 15));
+        assert_eq!(
+            date(i32::MAX, 11, 30).add_months(i32::MAX),
+            date(i32::MAX, 12, 30)
+        );
+        assert_eq!(
+            date(i32::MIN, 3, 31).add_months(i32::MIN),
+            date(i32::MIN, 1, 31)
+        );
+        assert_eq!(date(2026, 10, 18).weekday(), Weekday::Sunday);
+        assert_eq!(Date::parse_iso("2026-10-18"), Some(date(2026, 10, 18)));
+        assert_eq!(date(7, 3, 9).to_string(), "0007-03-09");
+    }
+
+    #[test]
+    fn renders_month_grid_with_week_start() {
+        let mut state = CalendarState::new(date(2026, 10, 18));
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(Calendar::WIDTH, Calendar::HEIGHT, &mut pool);
+        let calendar = Calendar::new().marker(date(2026, 10, 18));
+        calendar.render(
+            Rect::new(0, 0, Calendar::WIDTH, Calendar::HEIGHT),
+            &mut frame,
+            &mut state,
+        );
+        assert_eq!(row(&frame, 0), "    October 2026");
+        assert_eq!(row(&frame, 1), "Mo Tu We Th Fr Sa Su");
+        assert_eq!(row(&frame, 2), "          1  2  3  4");
+        assert_eq!(row(&frame, 4), "12 13 14 15 16 17 18•");
+
+        let mut frame = Frame::new(Calendar::WIDTH, Calendar::HEIGHT, &mut pool);
+        Calendar::new().locale("en-US").render(
+            Rect::new(0, 0, Calendar::WIDTH, Calendar::HEIGHT),
+            &mut frame,
+            &mut state,
+        );
+        assert_eq!(row(&frame, 1), "Su Mo Tu We Th Fr Sa");
+        assert_eq!(row(&frame, 2), "             1  2  3");
+    }
+
+    #[test]
+    fn keyboard_navigation_respects_bounds() {
+        let mut state = CalendarState::new(date(2026, 10, 18))
+            .with_bounds(Some(date(2026, 10, 1)), Some(date(2026, 11, 5)));
+        assert!(state.handle_key(&KeyEvent::new(KeyCode::Down)));
+        assert_eq!(state.cursor, date(2026, 10, 25));
+        assert!(state.handle_key(&KeyEvent::new(KeyCode::PageDown)));
+        assert_eq!(state.cursor, date(2026, 11, 5));
+        assert!(state.handle_key(&KeyEvent::new(KeyCode::PageUp)));
+        assert_eq!(state.cursor, date(2026, 10, 5));
+        assert!(!state.handle_key(&KeyEvent::new(KeyCode::Home)));
+        assert!(state.handle_key(&KeyEvent::new(KeyCode::End)));
+        assert_eq!(state.cursor, date(2026, 10, 11));
+        state.move_to(date(2026, 10, 2));
+        assert!(state.handle_key(&KeyEvent::new(KeyCode::Up)));
+        assert_eq!(state.cursor, date(2026, 10, 1));
+        assert!(state.handle_key(&KeyEvent::new(KeyCode::Enter)));
+        assert_eq!(state.selected, Some(date(2026, 10, 1)));
+        assert!(
+            !state.handle_key(&KeyEvent::new(KeyCode::Char('q')).with_modifiers(Modifiers::NONE))
+        );
+    }
+
+    #[test]
+    fn click_selects_day() {
+        let mut state = CalendarState::new(date(2026, 10, 18));
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(30, 10, &mut pool);
+        Calendar::new().render(Rect::new(2, 1, 21, 8), &mut frame, &mut state);
+
+        assert_eq!(state.date_at(2 + 9, 1 + 2), Some(date(2026, 10, 1)));
+        assert_eq!(state.date_at(2, 1 + 2), None);
+        assert!(state.handle_mouse(&MouseEvent::new(
+            MouseEventKind::Down(MouseButton::Left),
+            2 + 6,
+            1 + 4,
+        )));
+        assert_eq!(state.selected, Some(date(2026, 10, 14)));
+    }
+
+    #[test]
+    fn highlight_and_selection_styles_layer() {
+        let range_style = Style::new().bold();
+        let calendar = Calendar::new().show_cursor(false).highlight(
+            DateRange::new(date(2026, 10, 12), date(2026, 10, 10)),
+            range_style,
+        );
+        let mut state = CalendarState::new(date(2026, 10, 1));
+        state.selected = Some(date(2026, 10, 11));
+        assert_eq!(
+            calendar.day_cell_style(date(2026, 10, 10), &state),
+            range_style
+        );
+        assert_eq!(
+            calendar.day_cell_style(date(2026, 10, 13), &state),
+            Style::default()
+        );
+        assert_eq!(
+            calendar.day_cell_style(date(2026, 10, 11), &state),
+            Style::new().reverse().merge(&range_style)
+        );
+    }
+}
@@ -42,7 +42,8 @@ pub use scorer::{
 };
 
 use ftui_core::event::{Event, KeyCode, KeyEvent, KeyEventKind, Modifiers};
-use ftui_core::geometry::Rect;
+use ftui_core::geometry::{Rect, Size};
+use ftui_layout::{AnchorAlign, place_within};
 use ftui_render::cell::{Cell, CellAttrs, CellContent, PackedRgba, StyleFlags as CellStyleFlags};
 use ftui_render::frame::Frame;
 use ftui_style::Style;
@@ -1140,10 +1141,14 @@ impl Widget for CommandPalette {
         let palette_height = (result_rows as u16 + 4)
             .max(6)
             .min(area.height.saturating_sub(2));
-        let palette_x = area.x + (area.width.saturating_sub(palette_width)) / 2;
-        let palette_y = area.y + area.height / 6; // ~1/6 from top
-
-        let palette_area = Rect::new(palette_x, palette_y, palette_width, palette_height);
+        // Centered horizontally, ~1/6 from the top.
+        let palette_area = place_within(
+            area,
+            Size::new(palette_width, palette_height),
+            AnchorAlign::Center,
+            AnchorAlign::Start,
+            area.height / 6,
+        );
 
         // Clear the palette area.
         self.clear_area(palette_area, frame);
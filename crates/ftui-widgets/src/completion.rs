@@ -0,0 +1,1070 @@
+#![forbid(unsafe_code)]
+
+//! Autocomplete for [`TextInput`] and [`TextArea`].
+//!
+//! A [`CompletionProvider`] supplies candidates for the word before the
+//! cursor. A [`Completer`] attached with `with_completion` ranks them with
+//! the command palette's [`BayesianScorer`], shows the top candidate's
+//! remainder as ghost text after the cursor, and exposes the ranked list as a
+//! [`CompletionPopup`] anchored at the cursor.
+//!
+//! # Keys
+//!
+//! While candidates are shown:
+//!
+//! | Key | Action |
+//! |-----|--------|
+//! | Down / Ctrl+N / Up / Ctrl+P / Shift+Tab | Move the selection |
+//! | PageDown / PageUp | Move the selection by a page |
+//! | Tab / Enter | Accept the selected candidate |
+//! | Right / End at the end of a line | Accept the ghost text |
+//! | Esc | Dismiss until the next edit |
+//!
+//! Ctrl+Space asks for candidates even when the word is shorter than
+//! [`Completer::with_min_prefix`].
+//!
+//! # Async providers
+//!
+//! A provider that returns `true` from [`CompletionProvider::is_async`] is
+//! never called by the widget. Instead each change of the word queues a
+//! [`CompletionRequest`]; the application takes it, runs the provider off
+//! the UI thread (e.g. in `Cmd::task`), and hands the result back with
+//! [`Completer::deliver`]. Results for an outdated request are dropped.
+//!
+//! ```ignore
+//! if let Some(request) = input.completion_mut().and_then(Completer::take_request) {
+//!     let provider = input.completion().unwrap().provider();
+//!     return Cmd::task(move || Msg::Completions(request.generation, provider.complete(&request)));
+//! }
+//! // later, in update():
+//! Msg::Completions(generation, items) => {
+//!     input.completion_mut().unwrap().deliver(generation, items);
+//! }
+//! ```
+//!
+//! # Popup layering
+//!
+//! The popup is drawn in screen coordinates so a parent panel never clips
+//! it. Either render it directly with [`Completer::render_popup`] after the
+//! rest of the frame, or push the [`CompletionPopup`] snapshot onto a
+//! [`ModalStack`](crate::modal::ModalStack): it positions itself at the
+//! anchor, draws no backdrop and does not trap focus. Clicking a row closes
+//! it with [`ModalResultData::Custom`] holding the candidate value.
+//!
+//! # Example
+//! ```
+//! use ftui_core::event::{Event, KeyCode, KeyEvent};
+//! use ftui_widgets::completion::{Completer, StaticCompletions};
+//! use ftui_widgets::input::TextInput;
+//!
+//! let words = StaticCompletions::new(["checkout", "cherry-pick", "commit"]);
+//! let mut input = TextInput::new().with_completion(Completer::new(words));
+//! for c in "che".chars() {
+//!     input.handle_event(&Event::Key(KeyEvent::new(KeyCode::Char(c))));
+//! }
+//! assert_eq!(input.completion().unwrap().ghost_text(), Some("ckout"));
+//!
+//! input.handle_event(&Event::Key(KeyEvent::new(KeyCode::Tab)));
+//! assert_eq!(input.value(), "checkout");
+//! ```
+//!
+//! [`TextInput`]: crate::input::TextInput
+//! [`TextArea`]: crate::textarea::TextArea
+
+use std::cell::Cell;
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+
+use ftui_core::event::{Event, KeyCode, KeyEvent, Modifiers, MouseButton, MouseEventKind};
+use ftui_core::geometry::{Rect, Size};
+use ftui_render::frame::{Frame, HitId};
+use ftui_style::Style;
+use ftui_text::display_width;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::command_palette::BayesianScorer;
+pub use crate::command_palette::CompletionItem;
+use crate::modal::{BackdropConfig, ModalResultData, ModalSizeConstraints, StackModal};
+use crate::{Widget, draw_text_span, set_style_area};
+
+/// Widest popup, in cells.
+const MAX_POPUP_WIDTH: u16 = 60;
+
+/// Columns between a label and its description.
+const DESCRIPTION_GAP: usize = 2;
+
+/// The word being completed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionRequest {
+    /// Full text of the widget.
+    pub text: String,
+    /// Cursor byte offset.
+    pub cursor: usize,
+    /// Byte offset where the word being completed starts.
+    pub start: usize,
+    /// Identifies the request for [`Completer::deliver`].
+    pub generation: u64,
+}
+
+impl CompletionRequest {
+    /// The partial word before the cursor.
+    #[must_use]
+    pub fn prefix(&self) -> &str {
+        &self.text[self.start..self.cursor]
+    }
+}
+
+/// Source of completion candidates.
+///
+/// Providers return unranked candidates; the [`Completer`] filters and ranks
+/// them against the prefix. Closures of type
+/// `Fn(&CompletionRequest) -> Vec<CompletionItem>` are providers too.
+pub trait CompletionProvider: Send + Sync {
+    /// Candidates for `request`.
+    fn complete(&self, request: &CompletionRequest) -> Vec<CompletionItem>;
+
+    /// Byte offset where the word ending at `cursor` starts.
+    ///
+    /// Defaults to the last whitespace-separated word.
+    fn word_start(&self, text: &str, cursor: usize) -> usize {
+        text[..cursor]
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(0, |(i, c)| i + c.len_utf8())
+    }
+
+    /// Whether [`complete`](Self::complete) is too slow for the UI thread.
+    ///
+    /// Async providers are driven by the application through
+    /// [`Completer::take_request`] and [`Completer::deliver`].
+    fn is_async(&self) -> bool {
+        false
+    }
+}
+
+impl<F> CompletionProvider for F
+where
+    F: Fn(&CompletionRequest) -> Vec<CompletionItem> + Send + Sync,
+{
+    fn complete(&self, request: &CompletionRequest) -> Vec<CompletionItem> {
+        self(request)
+    }
+}
+
+/// Fixed candidate list.
+#[derive(Debug, Clone, Default)]
+pub struct StaticCompletions {
+    items: Vec<CompletionItem>,
+}
+
+impl StaticCompletions {
+    /// Candidates whose value and label are the given words.
+    #[must_use]
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let items = words
+            .into_iter()
+            .map(|word| {
+                let value = word.into();
+                CompletionItem {
+                    label: value.clone(),
+                    value,
+                    description: String::new(),
+                }
+            })
+            .collect();
+        Self { items }
+    }
+
+    /// Candidates with their own labels and descriptions.
+    #[must_use]
+    pub fn from_items(items: Vec<CompletionItem>) -> Self {
+        Self { items }
+    }
+}
+
+impl CompletionProvider for StaticCompletions {
+    fn complete(&self, _request: &CompletionRequest) -> Vec<CompletionItem> {
+        self.items.clone()
+    }
+}
+
+/// Outcome of [`Completer::handle_key`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompletionKeyResult {
+    /// Not a completion key; the widget handles it.
+    Ignored,
+    /// The completer consumed the key.
+    Handled,
+    /// Replace the byte `range` of the text with `value`.
+    Accept {
+        /// Byte range of the word being completed.
+        range: Range<usize>,
+        /// Text to insert.
+        value: String,
+    },
+}
+
+/// A ranked candidate.
+#[derive(Debug, Clone)]
+struct Ranked {
+    item: CompletionItem,
+    /// Matched byte offsets in the label.
+    positions: Vec<usize>,
+}
+
+/// Completion state attached to an editing widget.
+#[derive(Clone)]
+pub struct Completer {
+    provider: Arc<dyn CompletionProvider>,
+    scorer: BayesianScorer,
+    /// The word candidates were computed for.
+    request: Option<CompletionRequest>,
+    /// Request waiting for an async provider.
+    pending: Option<CompletionRequest>,
+    generation: u64,
+    ranked: Vec<Ranked>,
+    selected: usize,
+    dismissed: bool,
+    min_prefix: usize,
+    max_visible: usize,
+    ghost_text: bool,
+    style: Style,
+    selected_style: Style,
+    match_style: Style,
+    description_style: Style,
+    ghost_style: Style,
+}
+
+impl fmt::Debug for Completer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Completer")
+            .field("request", &self.request)
+            .field("candidates", &self.ranked.len())
+            .field("selected", &self.selected)
+            .field("dismissed", &self.dismissed)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Completer {
+    /// Create a completer for `provider`.
+    #[must_use]
+    pub fn new(provider: impl CompletionProvider + 'static) -> Self {
+        Self::from_arc(Arc::new(provider))
+    }
+
+    /// Create a completer sharing `provider`.
+    #[must_use]
+    pub fn from_arc(provider: Arc<dyn CompletionProvider>) -> Self {
+        Self {
+            provider,
+            scorer: BayesianScorer::fast(),
+            request: None,
+            pending: None,
+            generation: 0,
+            ranked: Vec::new(),
+            selected: 0,
+            dismissed: false,
+            min_prefix: 1,
+            max_visible: 8,
+            ghost_text: true,
+            style: Style::new().reverse(),
+            selected_style: Style::new().bold(),
+            match_style: Style::new().underline(),
+            description_style: Style::new().dim(),
+            ghost_style: Style::new().dim(),
+        }
+    }
+
+    /// Minimum prefix length (in graphemes) before candidates appear (builder).
+    #[must_use]
+    pub fn with_min_prefix(mut self, graphemes: usize) -> Self {
+        self.min_prefix = graphemes;
+        self
+    }
+
+    /// Maximum popup rows (builder).
+    #[must_use]
+    pub fn with_max_visible(mut self, rows: usize) -> Self {
+        self.max_visible = rows.max(1);
+        self
+    }
+
+    /// Show the top candidate's remainder after the cursor (builder).
+    #[must_use]
+    pub fn with_ghost_text(mut self, enabled: bool) -> Self {
+        self.ghost_text = enabled;
+        self
+    }
+
+    /// Set popup base style (builder).
+    #[must_use]
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set selected row style (builder).
+    #[must_use]
+    pub fn with_selected_style(mut self, style: Style) -> Self {
+        self.selected_style = style;
+        self
+    }
+
+    /// Set style of matched label characters (builder).
+    #[must_use]
+    pub fn with_match_style(mut self, style: Style) -> Self {
+        self.match_style = style;
+        self
+    }
+
+    /// Set candidate description style (builder).
+    #[must_use]
+    pub fn with_description_style(mut self, style: Style) -> Self {
+        self.description_style = style;
+        self
+    }
+
+    /// Set ghost text style (builder).
+    #[must_use]
+    pub fn with_ghost_style(mut self, style: Style) -> Self {
+        self.ghost_style = style;
+        self
+    }
+
+    /// The candidate provider, e.g. to run it in a background task.
+    #[must_use]
+    pub fn provider(&self) -> Arc<dyn CompletionProvider> {
+        Arc::clone(&self.provider)
+    }
+
+    /// Ghost text style.
+    #[must_use]
+    pub fn ghost_style(&self) -> Style {
+        self.ghost_style
+    }
+
+    // --- Updates ---
+
+    /// Recompute candidates after the text or cursor changed.
+    ///
+    /// Does nothing if the word before the cursor is unchanged.
+    pub fn update(&mut self, text: &str, cursor: usize) {
+        self.refresh(text, cursor, false);
+    }
+
+    /// Ask for candidates regardless of the prefix length (Ctrl+Space).
+    pub fn trigger(&mut self, text: &str, cursor: usize) {
+        self.dismissed = false;
+        self.refresh(text, cursor, true);
+    }
+
+    fn refresh(&mut self, text: &str, cursor: usize, force: bool) {
+        let cursor = cursor.min(text.len());
+        let start = self.provider.word_start(text, cursor).min(cursor);
+        let prefix = &text[start..cursor];
+        let unchanged = self
+            .request
+            .as_ref()
+            .is_some_and(|r| r.start == start && r.prefix() == prefix);
+        if unchanged && !force {
+            // Same word: only the surrounding text or cursor moved.
+            if let Some(request) = &mut self.request {
+                request.text.replace_range(.., text);
+                request.cursor = cursor;
+            }
+            return;
+        }
+        self.dismissed = false;
+        if !force && prefix.graphemes(true).count() < self.min_prefix {
+            self.close();
+            return;
+        }
+        self.generation += 1;
+        let request = CompletionRequest {
+            text: text.to_string(),
+            cursor,
+            start,
+            generation: self.generation,
+        };
+        if self.provider.is_async() {
+            // Keep showing the previous candidates, re-filtered, meanwhile.
+            self.pending = Some(request.clone());
+            let items = self.ranked.drain(..).map(|r| r.item).collect();
+            self.request = Some(request);
+            self.rank(items);
+        } else {
+            let items = self.provider.complete(&request);
+            self.request = Some(request);
+            self.rank(items);
+        }
+    }
+
+    /// Take the request an async provider should answer.
+    pub fn take_request(&mut self) -> Option<CompletionRequest> {
+        self.pending.take()
+    }
+
+    /// Supply candidates for the request with `generation`.
+    ///
+    /// Returns `false` (and ignores `items`) if the request is outdated.
+    pub fn deliver(&mut self, generation: u64, items: Vec<CompletionItem>) -> bool {
+        if self.request.as_ref().map(|r| r.generation) != Some(generation) {
+            return false;
+        }
+        self.rank(items);
+        true
+    }
+
+    fn rank(&mut self, items: Vec<CompletionItem>) {
+        self.selected = 0;
+        let prefix = self.request.as_ref().map_or("", CompletionRequest::prefix);
+        if prefix.is_empty() {
+            // Nothing to match: keep the provider's order.
+            self.ranked = items
+                .into_iter()
+                .map(|item| Ranked {
+                    item,
+                    positions: Vec::new(),
+                })
+                .collect();
+            return;
+        }
+        let mut scored: Vec<(f64, Ranked)> = items
+            .into_iter()
+            .filter_map(|item| {
+                let result = self.scorer.score(prefix, &item.label);
+                (result.score > 0.0).then(|| {
+                    let ranked = Ranked {
+                        positions: result.match_positions,
+                        item,
+                    };
+                    (result.score, ranked)
+                })
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+        self.ranked = scored.into_iter().map(|(_, ranked)| ranked).collect();
+    }
+
+    /// Hide candidates and forget the current word.
+    pub fn close(&mut self) {
+        self.request = None;
+        self.pending = None;
+        self.ranked.clear();
+        self.selected = 0;
+    }
+
+    /// Hide candidates until the word changes.
+    pub fn dismiss(&mut self) {
+        self.dismissed = true;
+    }
+
+    // --- Queries ---
+
+    /// Whether candidates are shown.
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        !self.dismissed && !self.ranked.is_empty()
+    }
+
+    /// Ranked candidates, best first.
+    pub fn candidates(&self) -> impl Iterator<Item = &CompletionItem> {
+        self.ranked.iter().map(|r| &r.item)
+    }
+
+    /// Index of the selected candidate.
+    #[must_use]
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    /// The selected candidate, if open.
+    #[must_use]
+    pub fn selected(&self) -> Option<&CompletionItem> {
+        if !self.is_open() {
+            return None;
+        }
+        self.ranked.get(self.selected).map(|r| &r.item)
+    }
+
+    /// Byte range of the word being completed.
+    #[must_use]
+    pub fn word_range(&self) -> Option<Range<usize>> {
+        self.request.as_ref().map(|r| r.start..r.cursor)
+    }
+
+    /// Remainder of the selected candidate after the typed prefix.
+    ///
+    /// Only shown when the candidate extends the prefix exactly and the
+    /// cursor is at the end of a line.
+    #[must_use]
+    pub fn ghost_text(&self) -> Option<&str> {
+        if !self.ghost_text {
+            return None;
+        }
+        let request = self.request.as_ref()?;
+        let after = &request.text[request.cursor..];
+        if !(after.is_empty() || after.starts_with('\n')) {
+            return None;
+        }
+        let rest = self.selected()?.value.strip_prefix(request.prefix())?;
+        (!rest.is_empty()).then_some(rest)
+    }
+
+    // --- Navigation ---
+
+    /// Select the next candidate, wrapping around.
+    pub fn select_next(&mut self) {
+        if !self.ranked.is_empty() {
+            self.selected = (self.selected + 1) % self.ranked.len();
+        }
+    }
+
+    /// Select the previous candidate, wrapping around.
+    pub fn select_prev(&mut self) {
+        if !self.ranked.is_empty() {
+            self.selected = self
+                .selected
+                .checked_sub(1)
+                .unwrap_or(self.ranked.len() - 1);
+        }
+    }
+
+    fn select_page(&mut self, forward: bool) {
+        let last = self.ranked.len().saturating_sub(1);
+        self.selected = if forward {
+            (self.selected + self.max_visible).min(last)
+        } else {
+            self.selected.saturating_sub(self.max_visible)
+        };
+    }
+
+    /// Accept the selected candidate, returning the edit to apply.
+    pub fn accept(&mut self) -> Option<(Range<usize>, String)> {
+        let value = self.selected()?.value.clone();
+        let range = self.word_range()?;
+        self.accepted(&range, &value);
+        Some((range, value))
+    }
+
+    /// Accept a candidate value chosen elsewhere (e.g. a popup click).
+    pub fn accept_value(&mut self, value: &str) -> Option<(Range<usize>, String)> {
+        let range = self.word_range()?;
+        self.accepted(&range, value);
+        Some((range, value.to_string()))
+    }
+
+    /// Remember the completed word so the resulting update keeps it closed.
+    fn accepted(&mut self, range: &Range<usize>, value: &str) {
+        let mut text = self.request.take().map(|r| r.text).unwrap_or_default();
+        text.replace_range(range.clone(), value);
+        self.ranked.clear();
+        self.pending = None;
+        self.selected = 0;
+        self.request = Some(CompletionRequest {
+            text,
+            cursor: range.start + value.len(),
+            start: range.start,
+            generation: self.generation,
+        });
+    }
+
+    /// Handle a key press against the widget's `text` and `cursor`.
+    pub fn handle_key(&mut self, key: &KeyEvent, text: &str, cursor: usize) -> CompletionKeyResult {
+        let ctrl = key.modifiers.contains(Modifiers::CTRL);
+        if ctrl && key.code == KeyCode::Char(' ') {
+            self.trigger(text, cursor);
+            return CompletionKeyResult::Handled;
+        }
+        if !self.is_open() {
+            return CompletionKeyResult::Ignored;
+        }
+        let plain = !ctrl && !key.modifiers.contains(Modifiers::ALT);
+        let shift = key.modifiers.contains(Modifiers::SHIFT);
+        match key.code {
+            KeyCode::Down if plain => self.select_next(),
+            KeyCode::Char('n') if ctrl => self.select_next(),
+            KeyCode::Up if plain => self.select_prev(),
+            KeyCode::Char('p') if ctrl => self.select_prev(),
+            KeyCode::BackTab => self.select_prev(),
+            KeyCode::Tab if shift => self.select_prev(),
+            KeyCode::PageDown if plain => self.select_page(true),
+            KeyCode::PageUp if plain => self.select_page(false),
+            KeyCode::Escape => self.dismiss(),
+            KeyCode::Tab | KeyCode::Enter if plain => return self.accept_result(),
+            KeyCode::Right | KeyCode::End if plain && !shift && self.ghost_text().is_some() => {
+                return self.accept_result();
+            }
+            _ => return CompletionKeyResult::Ignored,
+        }
+        CompletionKeyResult::Handled
+    }
+
+    fn accept_result(&mut self) -> CompletionKeyResult {
+        match self.accept() {
+            Some((range, value)) => CompletionKeyResult::Accept { range, value },
+            None => CompletionKeyResult::Ignored,
+        }
+    }
+
+    // --- Popup ---
+
+    /// Snapshot of the candidate list anchored below the cell `anchor`.
+    ///
+    /// `anchor` is the screen position of the start of the word being
+    /// completed. Returns `None` when no candidates are shown.
+    #[must_use]
+    pub fn popup(&self, anchor: (u16, u16)) -> Option<CompletionPopup> {
+        if !self.is_open() {
+            return None;
+        }
+        let top = self
+            .selected
+            .saturating_sub(self.max_visible.saturating_sub(1));
+        let rows = self
+            .ranked
+            .iter()
+            .skip(top)
+            .take(self.max_visible)
+            .cloned()
+            .collect();
+        Some(CompletionPopup {
+            rows,
+            selected: self.selected - top,
+            anchor,
+            style: self.style,
+            selected_style: self.selected_style,
+            match_style: self.match_style,
+            description_style: self.description_style,
+            rendered: Cell::new(None),
+        })
+    }
+
+    /// Draw the popup for `anchor` within `screen`, above everything drawn
+    /// so far.
+    pub fn render_popup(&self, anchor: (u16, u16), screen: Rect, frame: &mut Frame) {
+        if let Some(popup) = self.popup(anchor) {
+            let area = popup.area(screen);
+            popup.render(area, frame);
+        }
+    }
+}
+
+/// Candidate list snapshot, rendered as an overlay anchored at the cursor.
+///
+/// Implements [`Widget`] for direct rendering and [`StackModal`] for the
+/// modal stack.
+#[derive(Debug, Clone)]
+pub struct CompletionPopup {
+    rows: Vec<Ranked>,
+    /// Selected row within `rows`.
+    selected: usize,
+    anchor: (u16, u16),
+    style: Style,
+    selected_style: Style,
+    match_style: Style,
+    description_style: Style,
+    /// Area of the last render, for mouse hits.
+    rendered: Cell<Option<Rect>>,
+}
+
+impl CompletionPopup {
+    /// Preferred size: one row per candidate, wide enough for the longest.
+    #[must_use]
+    pub fn size(&self) -> Size {
+        let width = self
+            .rows
+            .iter()
+            .map(|r| {
+                let label = display_width(&r.item.label);
+                let description = display_width(&r.item.description);
+                if description == 0 {
+                    label
+                } else {
+                    label + DESCRIPTION_GAP + description
+                }
+            })
+            .max()
+            .unwrap_or(0)
+            + 2;
+        Size::new((width as u16).min(MAX_POPUP_WIDTH), self.rows.len() as u16)
+    }
+
+    /// Where the popup goes on `screen`: below the anchor, or above it when
+    /// there is more room there, shifted left to stay on screen.
+    #[must_use]
+    pub fn area(&self, screen: Rect) -> Rect {
+        self.place(screen, self.size())
+    }
+
+    fn place(&self, screen: Rect, size: Size) -> Rect {
+        let width = size.width.min(screen.width);
+        let (ax, ay) = self.anchor;
+        let below = screen.bottom().saturating_sub(ay.saturating_add(1));
+        let above = ay.saturating_sub(screen.y);
+        let (y, height) = if below >= size.height || below >= above {
+            (ay.saturating_add(1), size.height.min(below))
+        } else {
+            let height = size.height.min(above);
+            (ay - height, height)
+        };
+        let x = ax
+            .saturating_sub(1)
+            .min(screen.right().saturating_sub(width))
+            .max(screen.x);
+        Rect::new(x, y, width, height)
+    }
+
+    /// Value of the candidate on screen row `row` of the popup.
+    fn value_at_row(&self, row: usize) -> Option<&str> {
+        self.rows.get(row).map(|r| r.item.value.as_str())
+    }
+}
+
+impl Widget for CompletionPopup {
+    fn render(&self, area: Rect, frame: &mut Frame) {
+        self.rendered.set(Some(area));
+        if area.is_empty() {
+            return;
+        }
+        let deg = frame.buffer.degradation;
+        if deg.apply_styling() {
+            set_style_area(&mut frame.buffer, area, self.style);
+        }
+        let max_x = area.right().saturating_sub(1);
+        for (i, row) in self.rows.iter().take(area.height as usize).enumerate() {
+            let y = area.y + i as u16;
+            let base = if i == self.selected {
+                let style = self.selected_style.merge(&self.style);
+                if deg.apply_styling() {
+                    set_style_area(
+                        &mut frame.buffer,
+                        Rect::new(area.x, y, area.width, 1),
+                        style,
+                    );
+                }
+                style
+            } else {
+                self.style
+            };
+            let styled = |style: Style| {
+                if deg.apply_styling() {
+                    style.merge(&base)
+                } else {
+                    Style::default()
+                }
+            };
+
+            let mut x = area.x.saturating_add(1);
+            let mut matches = row.positions.iter().peekable();
+            for (byte, g) in row.item.label.grapheme_indices(true) {
+                while matches.next_if(|&&p| p < byte).is_some() {}
+                let end = byte + g.len();
+                let is_match = matches.peek().is_some_and(|&&p| p < end);
+                let style = if is_match {
+                    styled(self.match_style)
+                } else {
+                    styled(Style::default())
+                };
+                x = draw_text_span(frame, x, y, g, style, max_x);
+            }
+
+            if !row.item.description.is_empty() {
+                let label_end = x.saturating_add(DESCRIPTION_GAP as u16);
+                let width = display_width(&row.item.description) as u16;
+                let desc_x = max_x.saturating_sub(width).max(label_end);
+                draw_text_span(
+                    frame,
+                    desc_x,
+                    y,
+                    &row.item.description,
+                    styled(self.description_style),
+                    max_x,
+                );
+            }
+        }
+    }
+}
+
+impl StackModal for CompletionPopup {
+    fn render_content(&self, area: Rect, frame: &mut Frame) {
+        self.render(area, frame);
+    }
+
+    fn handle_event(&mut self, event: &Event, _hit_id: HitId) -> Option<ModalResultData> {
+        // Keys stay with the text widget; only clicks are handled here.
+        let Event::Mouse(mouse) = event else {
+            return None;
+        };
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return None;
+        }
+        let area = self.rendered.get()?;
+        if !area.contains(mouse.x, mouse.y) {
+            return Some(ModalResultData::Dismissed);
+        }
+        let row = usize::from(mouse.y - area.y);
+        self.value_at_row(row)
+            .map(|value| ModalResultData::Custom(value.to_string()))
+    }
+
+    fn size_constraints(&self) -> ModalSizeConstraints {
+        let size = self.size();
+        ModalSizeConstraints::new()
+            .min_width(size.width)
+            .max_width(size.width)
+            .min_height(size.height)
+            .max_height(size.height)
+    }
+
+    fn backdrop_config(&self) -> BackdropConfig {
+        BackdropConfig::default().opacity(0.0)
+    }
+
+    fn close_on_escape(&self) -> bool {
+        false
+    }
+
+    fn aria_modal(&self) -> bool {
+        false
+    }
+
+    fn content_area(&self, screen: Rect, size: Size) -> Rect {
+        self.place(screen, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn words() -> Completer {
+        Completer::new(StaticCompletions::new([
+            "checkout",
+            "cherry-pick",
+            "commit",
+            "clone",
+        ]))
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code)
+    }
+
+    fn labels(c: &Completer) -> Vec<&str> {
+        c.candidates().map(|i| i.label.as_str()).collect()
+    }
+
+    #[test]
+    fn ranks_with_palette_scorer() {
+        let mut c = words();
+        c.update("git che", 7);
+        assert_eq!(c.word_range(), Some(4..7));
+        assert!(c.is_open());
+        assert_eq!(labels(&c), ["checkout", "cherry-pick"]);
+        c.update("git co", 6);
+        assert_eq!(labels(&c)[0], "commit");
+    }
+
+    #[test]
+    fn min_prefix_and_trigger() {
+        let mut c = words().with_min_prefix(2);
+        c.update("c", 1);
+        assert!(!c.is_open());
+        c.trigger("c", 1);
+        assert!(c.is_open());
+        c.update("", 0);
+        assert!(!c.is_open());
+        c.trigger("", 0);
+        // Empty prefix keeps the provider's order.
+        assert_eq!(labels(&c), ["checkout", "cherry-pick", "commit", "clone"]);
+    }
+
+    #[test]
+    fn ghost_text_needs_exact_prefix_and_end_of_text() {
+        let mut c = words();
+        c.update("chec", 4);
+        assert_eq!(c.ghost_text(), Some("kout"));
+        c.update("chec x", 4);
+        assert_eq!(c.ghost_text(), None);
+        c.update("ckt", 3);
+        assert_eq!(c.selected().map(|i| i.value.as_str()), Some("checkout"));
+        assert_eq!(c.ghost_text(), None);
+        let mut c = words().with_ghost_text(false);
+        c.update("chec", 4);
+        assert_eq!(c.ghost_text(), None);
+    }
+
+    #[test]
+    fn navigation_and_accept() {
+        let mut c = words();
+        c.update("ch", 2);
+        assert_eq!(
+            c.handle_key(&key(KeyCode::Down), "ch", 2),
+            CompletionKeyResult::Handled
+        );
+        assert_eq!(c.selected_index(), 1);
+        c.handle_key(&key(KeyCode::Down), "ch", 2);
+        assert_eq!(c.selected_index(), 0);
+        c.handle_key(&key(KeyCode::BackTab), "ch", 2);
+        assert_eq!(c.selected_index(), 1);
+        let result = c.handle_key(&key(KeyCode::Tab), "ch", 2);
+        assert_eq!(
+            result,
+            CompletionKeyResult::Accept {
+                range: 0..2,
+                value: "cherry-pick".into()
+            }
+        );
+        assert!(!c.is_open());
+        // The widget's follow-up update for the accepted word stays closed.
+        c.update("cherry-pick", 11);
+        assert!(!c.is_open());
+        c.update("cherry-pic", 10);
+        assert!(c.is_open());
+    }
+
+    #[test]
+    fn right_accepts_ghost_text_only_at_end() {
+        let mut c = words();
+        c.update("chec", 4);
+        assert!(matches!(
+            c.handle_key(&key(KeyCode::Right), "chec", 4),
+            CompletionKeyResult::Accept { .. }
+        ));
+        let mut c = words();
+        c.update("xyz cl", 6);
+        c.handle_key(&key(KeyCode::Down), "xyz cl", 6);
+        assert_eq!(c.ghost_text(), Some("one"));
+        c.update("xyz cl ", 6);
+        assert_eq!(
+            c.handle_key(&key(KeyCode::Right), "xyz cl ", 6),
+            CompletionKeyResult::Ignored
+        );
+    }
+
+    #[test]
+    fn escape_dismisses_until_edit() {
+        let mut c = words();
+        c.update("c", 1);
+        c.handle_key(&key(KeyCode::Escape), "c", 1);
+        assert!(!c.is_open());
+        assert_eq!(
+            c.handle_key(&key(KeyCode::Tab), "c", 1),
+            CompletionKeyResult::Ignored
+        );
+        c.update("co", 2);
+        assert!(c.is_open());
+    }
+
+    #[test]
+    fn async_provider_round_trip() {
+        struct Slow;
+        impl CompletionProvider for Slow {
+            fn complete(&self, request: &CompletionRequest) -> Vec<CompletionItem> {
+                StaticCompletions::new([format!("{}-done", request.prefix())]).complete(request)
+            }
+            fn is_async(&self) -> bool {
+                true
+            }
+        }
+        let mut c = Completer::new(Slow);
+        c.update("a", 1);
+        assert!(!c.is_open());
+        let first = c.take_request().unwrap();
+        assert!(c.take_request().is_none());
+        c.update("ab", 2);
+        let second = c.take_request().unwrap();
+        let provider = c.provider();
+        assert!(!c.deliver(first.generation, provider.complete(&first)));
+        assert!(c.deliver(second.generation, provider.complete(&second)));
+        assert_eq!(labels(&c), ["ab-done"]);
+    }
+
+    #[test]
+    fn closure_provider() {
+        let mut c = Completer::new(|r: &CompletionRequest| {
+            StaticCompletions::new([r.prefix().to_uppercase()]).complete(r)
+        });
+        c.update("abc", 3);
+        assert_eq!(labels(&c), ["ABC"]);
+    }
+
+    #[test]
+    fn popup_flips_above_near_bottom() {
+        let mut c = words();
+        c.update("c", 1);
+        let popup = c.popup((5, 2)).unwrap();
+        let screen = Rect::new(0, 0, 40, 10);
+        assert_eq!(popup.area(screen), Rect::new(4, 3, 13, 4));
+        let popup = c.popup((5, 8)).unwrap();
+        assert_eq!(popup.area(screen), Rect::new(4, 4, 13, 4));
+        let popup = c.popup((38, 2)).unwrap();
+        assert_eq!(popup.area(screen).x, 27);
+    }
+
+    #[test]
+    fn popup_scrolls_to_selection() {
+        let mut c = words().with_max_visible(2);
+        c.trigger("", 0);
+        c.select_prev();
+        let popup = c.popup((0, 0)).unwrap();
+        assert_eq!(popup.rows.len(), 2);
+        assert_eq!(popup.rows[popup.selected].item.label, "clone");
+    }
+
+    #[test]
+    fn popup_renders_rows() {
+        let mut c = Completer::new(StaticCompletions::from_items(vec![CompletionItem {
+            value: "main".into(),
+            label: "main".into(),
+            description: "branch".into(),
+        }]));
+        c.update("ma", 2);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(20, 4, &mut pool);
+        c.render_popup((0, 0), Rect::new(0, 0, 20, 4), &mut frame);
+        let row: String = (0..14)
+            .map(|x| {
+                frame
+                    .buffer
+                    .get(x, 1)
+                    .and_then(|cell| cell.content.as_char())
+                    .unwrap_or(' ')
+            })
+            .collect();
+        assert_eq!(row, " main  branch ");
+    }
+
+    #[test]
+    fn popup_is_a_stack_modal() {
+        use crate::modal::ModalStack;
+        use ftui_core::event::MouseEvent;
+        let mut c = words();
+        c.update("c", 1);
+        let mut stack = ModalStack::new();
+        stack.push(Box::new(c.popup((3, 1)).unwrap()));
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(30, 10, &mut pool);
+        stack.render(&mut frame, Rect::new(0, 0, 30, 10));
+        assert_eq!(frame.buffer.get(3, 2).unwrap().content.as_char(), Some('c'));
+        let click = Event::Mouse(MouseEvent::new(
+            MouseEventKind::Down(MouseButton::Left),
+            4,
+            3,
+        ));
+        let result = stack.handle_event(&click).unwrap();
+        let Some(ModalResultData::Custom(value)) = result.data else {
+            panic!("expected a chosen value");
+        };
+        assert_eq!(c.accept_value(&value), Some((0..1, value)));
+    }
+}
@@ -0,0 +1,1102 @@
+#![forbid(unsafe_code)]
+
+//! Text diff viewer with unified and side-by-side layouts.
+//!
+//! [`TextDiff`] computes a line diff between two texts (Myers or patience, see
+//! [`ftui_text::diff`]), pairs modified lines for word-level emphasis, and
+//! groups changes into hunks with surrounding context. [`DiffView`] renders it
+//! either unified (one column, `+`/`-` markers) or side-by-side (old on the
+//! left, new on the right, scrolled together), folding unchanged runs between
+//! hunks into a single expandable row.
+//!
+//! Syntax highlighting uses the same [`LineHighlighter`] extension point as
+//! [`TextArea`](crate::textarea::TextArea); call [`TextDiff::highlight`] once
+//! after building the diff.
+//!
+//! # Example
+//! ```
+//! use ftui_widgets::diff_view::{DiffMode, DiffView, DiffViewState, TextDiff};
+//!
+//! let diff = TextDiff::new("let x = 1;\n", "let x = 2;\n");
+//! assert_eq!(diff.hunks().len(), 1);
+//! let view = DiffView::new(&diff).mode(DiffMode::SideBySide);
+//! let mut state = DiffViewState::default();
+//! state.next_hunk(&diff);
+//! # let _ = (view, state);
+//! ```
+
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+use ftui_core::event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ftui_core::geometry::Rect;
+use ftui_render::cell::PackedRgba;
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+use ftui_text::diff::{DiffAlgorithm, DiffOp, diff_lines, diff_words, split_lines};
+use ftui_text::wrap::display_width;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::textarea::{HighlightSpan, HighlightState, LineHighlighter};
+use crate::{StatefulWidget, draw_text_span};
+
+/// Default number of unchanged lines shown around each change.
+pub const DEFAULT_CONTEXT: usize = 3;
+
+/// Word emphasis is skipped for line pairs sharing less than this fraction of
+/// their words; highlighting nearly everything is just noise.
+const MIN_EMPHASIS_SIMILARITY: f32 = 0.3;
+
+const TAB_WIDTH: usize = 4;
+
+// ---------------------------------------------------------------------------
+// TextDiff
+// ---------------------------------------------------------------------------
+
+/// Kind of a diff line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiffLineKind {
+    /// Unchanged line present on both sides.
+    Context,
+    /// Line only in the new text.
+    Added,
+    /// Line only in the old text.
+    Removed,
+}
+
+/// One line of a computed diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    /// Whether the line is unchanged, added, or removed.
+    pub kind: DiffLineKind,
+    /// 1-based line number in the old text.
+    pub old_number: Option<usize>,
+    /// 1-based line number in the new text.
+    pub new_number: Option<usize>,
+    /// Line content without terminator.
+    pub text: String,
+    /// Changed byte ranges within `text` (word-level diff against the paired line).
+    pub emphasis: Vec<Range<usize>>,
+}
+
+/// A group of changes with surrounding context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffHunk {
+    /// Indices into [`TextDiff::lines`] covered by the hunk.
+    pub lines: Range<usize>,
+    /// 1-based first old line (0 when the hunk has no old lines).
+    pub old_start: usize,
+    /// Number of old lines in the hunk.
+    pub old_len: usize,
+    /// 1-based first new line (0 when the hunk has no new lines).
+    pub new_start: usize,
+    /// Number of new lines in the hunk.
+    pub new_len: usize,
+}
+
+impl DiffHunk {
+    /// Unified-diff style header, e.g. `@@ -3,7 +3,8 @@`.
+    #[must_use]
+    pub fn header(&self) -> String {
+        format!(
+            "@@ -{},{} +{},{} @@",
+            self.old_start, self.old_len, self.new_start, self.new_len
+        )
+    }
+}
+
+/// A computed line diff between two texts.
+#[derive(Debug, Clone, Default)]
+pub struct TextDiff {
+    lines: Vec<DiffLine>,
+    hunks: Vec<DiffHunk>,
+    old_highlights: Vec<Vec<HighlightSpan>>,
+    new_highlights: Vec<Vec<HighlightSpan>>,
+}
+
+impl TextDiff {
+    /// Diff with Myers' algorithm and [`DEFAULT_CONTEXT`] context lines.
+    #[must_use]
+    pub fn new(old: &str, new: &str) -> Self {
+        Self::with_options(old, new, DiffAlgorithm::Myers, DEFAULT_CONTEXT)
+    }
+
+    /// Diff with an explicit algorithm and context size.
+    #[must_use]
+    pub fn with_options(old: &str, new: &str, algorithm: DiffAlgorithm, context: usize) -> Self {
+        let old_lines = split_lines(old);
+        let new_lines = split_lines(new);
+        let mut lines: Vec<DiffLine> = diff_lines(old, new, algorithm)
+            .into_iter()
+            .map(|op| {
+                let (kind, old_index, new_index) = match op {
+                    DiffOp::Equal { old, new } => (DiffLineKind::Context, Some(old), Some(new)),
+                    DiffOp::Delete { old } => (DiffLineKind::Removed, Some(old), None),
+                    DiffOp::Insert { new } => (DiffLineKind::Added, None, Some(new)),
+                };
+                let text = match (old_index, new_index) {
+                    (Some(i), _) => old_lines[i],
+                    (None, Some(j)) => new_lines[j],
+                    (None, None) => "",
+                };
+                DiffLine {
+                    kind,
+                    old_number: old_index.map(|i| i + 1),
+                    new_number: new_index.map(|j| j + 1),
+                    text: text.to_string(),
+                    emphasis: Vec::new(),
+                }
+            })
+            .collect();
+        add_word_emphasis(&mut lines);
+        let hunks = build_hunks(&lines, context);
+        Self {
+            lines,
+            hunks,
+            old_highlights: Vec::new(),
+            new_highlights: Vec::new(),
+        }
+    }
+
+    /// All diff lines in order.
+    #[must_use]
+    pub fn lines(&self) -> &[DiffLine] {
+        &self.lines
+    }
+
+    /// Hunks in order.
+    #[must_use]
+    pub fn hunks(&self) -> &[DiffHunk] {
+        &self.hunks
+    }
+
+    /// Whether the texts are identical.
+    #[must_use]
+    pub fn is_unchanged(&self) -> bool {
+        self.hunks.is_empty()
+    }
+
+    /// Number of (added, removed) lines.
+    #[must_use]
+    pub fn stats(&self) -> (usize, usize) {
+        self.lines
+            .iter()
+            .fold((0, 0), |(add, del), line| match line.kind {
+                DiffLineKind::Added => (add + 1, del),
+                DiffLineKind::Removed => (add, del + 1),
+                DiffLineKind::Context => (add, del),
+            })
+    }
+
+    /// Syntax-highlight both sides, carrying lexer state through each text
+    /// independently. Replaces any previous highlighting.
+    pub fn highlight(&mut self, highlighter: &dyn LineHighlighter) {
+        let mut old_state: HighlightState = 0;
+        let mut new_state: HighlightState = 0;
+        self.old_highlights = Vec::with_capacity(self.lines.len());
+        self.new_highlights = Vec::with_capacity(self.lines.len());
+        for line in &self.lines {
+            let (old_spans, new_spans) = match line.kind {
+                DiffLineKind::Context => {
+                    let (spans, state) = highlighter.highlight_line(&line.text, old_state);
+                    old_state = state;
+                    let (new_spans, state) = highlighter.highlight_line(&line.text, new_state);
+                    new_state = state;
+                    (spans, new_spans)
+                }
+                DiffLineKind::Removed => {
+                    let (spans, state) = highlighter.highlight_line(&line.text, old_state);
+                    old_state = state;
+                    (spans, Vec::new())
+                }
+                DiffLineKind::Added => {
+                    let (spans, state) = highlighter.highlight_line(&line.text, new_state);
+                    new_state = state;
+                    (Vec::new(), spans)
+                }
+            };
+            self.old_highlights.push(old_spans);
+            self.new_highlights.push(new_spans);
+        }
+    }
+
+    /// Syntax spans for a line (old-side lexing for removed/context lines).
+    fn highlights(&self, index: usize) -> &[HighlightSpan] {
+        let side = match self.lines[index].kind {
+            DiffLineKind::Added => &self.new_highlights,
+            _ => &self.old_highlights,
+        };
+        side.get(index).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Pair removed and added lines within each change block and attach
+/// word-level emphasis ranges.
+fn add_word_emphasis(lines: &mut [DiffLine]) {
+    let mut i = 0;
+    while i < lines.len() {
+        if lines[i].kind == DiffLineKind::Context {
+            i += 1;
+            continue;
+        }
+        let removed_start = i;
+        while i < lines.len() && lines[i].kind == DiffLineKind::Removed {
+            i += 1;
+        }
+        let added_start = i;
+        while i < lines.len() && lines[i].kind == DiffLineKind::Added {
+            i += 1;
+        }
+        let pairs = (added_start - removed_start).min(i - added_start);
+        for k in 0..pairs {
+            let (old, new) = (removed_start + k, added_start + k);
+            let words = diff_words(&lines[old].text, &lines[new].text);
+            if words.similarity >= MIN_EMPHASIS_SIMILARITY {
+                lines[old].emphasis = words.old;
+                lines[new].emphasis = words.new;
+            }
+        }
+    }
+}
+
+fn build_hunks(lines: &[DiffLine], context: usize) -> Vec<DiffHunk> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        if line.kind == DiffLineKind::Context {
+            continue;
+        }
+        let start = i.saturating_sub(context);
+        let end = (i + 1 + context).min(lines.len());
+        match ranges.last_mut() {
+            Some(last) if start <= last.end => last.end = end,
+            _ => ranges.push(start..end),
+        }
+    }
+    ranges
+        .into_iter()
+        .map(|range| {
+            let slice = &lines[range.clone()];
+            let old_len = slice.iter().filter(|l| l.old_number.is_some()).count();
+            let new_len = slice.iter().filter(|l| l.new_number.is_some()).count();
+            let old_start = slice.iter().find_map(|l| l.old_number).unwrap_or(0);
+            let new_start = slice.iter().find_map(|l| l.new_number).unwrap_or(0);
+            DiffHunk {
+                lines: range,
+                old_start,
+                old_len,
+                new_start,
+                new_len,
+            }
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Rows
+// ---------------------------------------------------------------------------
+
+/// Layout of a [`DiffView`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiffMode {
+    /// Single column with `+`/`-` markers.
+    #[default]
+    Unified,
+    /// Old text on the left, new text on the right.
+    SideBySide,
+}
+
+/// A visual row of the diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Row {
+    /// Hunk header.
+    Header(usize),
+    /// Folded run of unchanged lines starting at a line index.
+    Fold { start: usize, len: usize },
+    /// Unified line.
+    Line(usize),
+    /// Side-by-side pair (old line, new line).
+    Pair(Option<usize>, Option<usize>),
+}
+
+fn build_rows(diff: &TextDiff, mode: DiffMode, fold: bool, expanded: &BTreeSet<usize>) -> Vec<Row> {
+    let mut rows = Vec::with_capacity(diff.lines.len() + diff.hunks.len());
+    let push_lines = |rows: &mut Vec<Row>, range: Range<usize>| match mode {
+        DiffMode::Unified => rows.extend(range.map(Row::Line)),
+        DiffMode::SideBySide => {
+            let mut i = range.start;
+            while i < range.end {
+                if diff.lines[i].kind == DiffLineKind::Context {
+                    rows.push(Row::Pair(Some(i), Some(i)));
+                    i += 1;
+                    continue;
+                }
+                let removed_start = i;
+                while i < range.end && diff.lines[i].kind == DiffLineKind::Removed {
+                    i += 1;
+                }
+                let added_start = i;
+                while i < range.end && diff.lines[i].kind == DiffLineKind::Added {
+                    i += 1;
+                }
+                let removed = added_start - removed_start;
+                let added = i - added_start;
+                for k in 0..removed.max(added) {
+                    rows.push(Row::Pair(
+                        (k < removed).then_some(removed_start + k),
+                        (k < added).then_some(added_start + k),
+                    ));
+                }
+            }
+        }
+    };
+    let push_gap = |rows: &mut Vec<Row>, range: Range<usize>| {
+        if range.is_empty() {
+            return;
+        }
+        if fold && !expanded.contains(&range.start) {
+            rows.push(Row::Fold {
+                start: range.start,
+                len: range.len(),
+            });
+        } else {
+            push_lines(rows, range);
+        }
+    };
+
+    if !fold {
+        push_lines(&mut rows, 0..diff.lines.len());
+        return rows;
+    }
+    let mut cursor = 0;
+    for (h, hunk) in diff.hunks.iter().enumerate() {
+        push_gap(&mut rows, cursor..hunk.lines.start);
+        rows.push(Row::Header(h));
+        push_lines(&mut rows, hunk.lines.clone());
+        cursor = hunk.lines.end;
+    }
+    push_gap(&mut rows, cursor..diff.lines.len());
+    rows
+}
+
+// ---------------------------------------------------------------------------
+// DiffViewState
+// ---------------------------------------------------------------------------
+
+/// Scroll, fold, and hunk-navigation state for a [`DiffView`].
+///
+/// Both panes of a side-by-side view share one vertical and one horizontal
+/// offset, so they always scroll together.
+#[derive(Debug, Clone, Default)]
+pub struct DiffViewState {
+    scroll: usize,
+    h_scroll: usize,
+    current_hunk: Option<usize>,
+    jump_pending: bool,
+    resync_hunk: bool,
+    expanded: BTreeSet<usize>,
+    last_area: Rect,
+    last_fold_rows: Vec<(u16, usize)>,
+}
+
+impl DiffViewState {
+    /// First visible row.
+    #[must_use]
+    pub fn scroll(&self) -> usize {
+        self.scroll
+    }
+
+    /// Horizontal scroll in cells.
+    #[must_use]
+    pub fn h_scroll(&self) -> usize {
+        self.h_scroll
+    }
+
+    /// Hunk most recently navigated to.
+    #[must_use]
+    pub fn current_hunk(&self) -> Option<usize> {
+        self.current_hunk
+    }
+
+    /// Scroll up by `rows`.
+    pub fn scroll_up(&mut self, rows: usize) {
+        self.scroll = self.scroll.saturating_sub(rows);
+        self.jump_pending = false;
+        self.resync_hunk = true;
+    }
+
+    /// Scroll down by `rows` (clamped at render time).
+    pub fn scroll_down(&mut self, rows: usize) {
+        self.scroll = self.scroll.saturating_add(rows);
+        self.jump_pending = false;
+        self.resync_hunk = true;
+    }
+
+    /// Scroll left by `cells`.
+    pub fn scroll_left(&mut self, cells: usize) {
+        self.h_scroll = self.h_scroll.saturating_sub(cells);
+    }
+
+    /// Scroll right by `cells`.
+    pub fn scroll_right(&mut self, cells: usize) {
+        self.h_scroll = self.h_scroll.saturating_add(cells);
+    }
+
+    /// Scroll to the first row.
+    pub fn scroll_to_top(&mut self) {
+        self.scroll = 0;
+        self.jump_pending = false;
+        self.resync_hunk = true;
+    }
+
+    /// Scroll to the last page.
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll = usize::MAX;
+        self.jump_pending = false;
+        self.resync_hunk = true;
+    }
+
+    /// Jump to the next hunk. Returns `false` at the last hunk.
+    pub fn next_hunk(&mut self, diff: &TextDiff) -> bool {
+        let next = match self.current_hunk {
+            Some(h) => h + 1,
+            None => 0,
+        };
+        self.jump_to_hunk(diff, next)
+    }
+
+    /// Jump to the previous hunk. Returns `false` at the first hunk.
+    pub fn prev_hunk(&mut self, diff: &TextDiff) -> bool {
+        match self.current_hunk {
+            Some(h) if h > 0 => self.jump_to_hunk(diff, h - 1),
+            None if !diff.hunks.is_empty() => self.jump_to_hunk(diff, diff.hunks.len() - 1),
+            _ => false,
+        }
+    }
+
+    /// Jump to hunk `index`; the view scrolls to it on the next render.
+    pub fn jump_to_hunk(&mut self, diff: &TextDiff, index: usize) -> bool {
+        if index >= diff.hunks.len() {
+            return false;
+        }
+        self.current_hunk = Some(index);
+        self.jump_pending = true;
+        self.resync_hunk = false;
+        true
+    }
+
+    /// Expand or collapse the folded run starting at line `start`.
+    pub fn toggle_fold(&mut self, start: usize) {
+        if !self.expanded.remove(&start) {
+            self.expanded.insert(start);
+        }
+    }
+
+    /// Whether the unchanged run starting at line `start` is expanded.
+    #[must_use]
+    pub fn is_expanded(&self, start: usize) -> bool {
+        self.expanded.contains(&start)
+    }
+
+    /// Expand every folded run.
+    pub fn expand_all(&mut self, diff: &TextDiff) {
+        let mut cursor = 0;
+        for hunk in &diff.hunks {
+            if hunk.lines.start > cursor {
+                self.expanded.insert(cursor);
+            }
+            cursor = hunk.lines.end;
+        }
+        if cursor < diff.lines.len() {
+            self.expanded.insert(cursor);
+        }
+    }
+
+    /// Collapse every unchanged run.
+    pub fn collapse_all(&mut self) {
+        self.expanded.clear();
+    }
+
+    /// Handle a key press. Returns `true` if the state changed.
+    ///
+    /// | Key | Action |
+    /// |-----|--------|
+    /// | `Up`/`k`, `Down`/`j` | Scroll one row |
+    /// | `PageUp`, `PageDown` | Scroll one page |
+    /// | `Home`/`g`, `End`/`G` | Top / bottom |
+    /// | `Left`/`h`, `Right`/`l` | Scroll horizontally |
+    /// | `n`/`]`, `p`/`N`/`[` | Next / previous hunk |
+    /// | `z` | Expand or collapse all unchanged runs |
+    pub fn handle_key(&mut self, diff: &TextDiff, key: &KeyEvent) -> bool {
+        let page = usize::from(self.last_area.height.max(1));
+        let before = (self.scroll, self.h_scroll);
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.scroll_up(1),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll_down(1),
+            KeyCode::PageUp => self.scroll_up(page),
+            KeyCode::PageDown => self.scroll_down(page),
+            KeyCode::Home | KeyCode::Char('g') => self.scroll_to_top(),
+            KeyCode::End | KeyCode::Char('G') => self.scroll_to_bottom(),
+            KeyCode::Left | KeyCode::Char('h') => self.scroll_left(TAB_WIDTH),
+            KeyCode::Right | KeyCode::Char('l') => self.scroll_right(TAB_WIDTH),
+            KeyCode::Char('n' | ']') => return self.next_hunk(diff),
+            KeyCode::Char('p' | 'N' | '[') => return self.prev_hunk(diff),
+            KeyCode::Char('z') => {
+                if self.expanded.is_empty() {
+                    self.expand_all(diff);
+                } else {
+                    self.collapse_all();
+                }
+                return true;
+            }
+            _ => return false,
+        }
+        before != (self.scroll, self.h_scroll)
+    }
+
+    /// Handle a mouse event against the last rendered area: the wheel
+    /// scrolls, and clicking a folded row expands it.
+    pub fn handle_mouse(&mut self, event: &MouseEvent) -> bool {
+        if !self.last_area.contains(event.x, event.y) {
+            return false;
+        }
+        match event.kind {
+            MouseEventKind::ScrollUp => {
+                let before = self.scroll;
+                self.scroll_up(3);
+                before != self.scroll
+            }
+            MouseEventKind::ScrollDown => {
+                self.scroll_down(3);
+                true
+            }
+            MouseEventKind::ScrollLeft => {
+                self.scroll_left(TAB_WIDTH);
+                true
+            }
+            MouseEventKind::ScrollRight => {
+                self.scroll_right(TAB_WIDTH);
+                true
+            }
+            MouseEventKind::Down(MouseButton::Left) => {
+                let Some(&(_, start)) = self.last_fold_rows.iter().find(|(y, _)| *y == event.y)
+                else {
+                    return false;
+                };
+                self.toggle_fold(start);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// DiffView
+// ---------------------------------------------------------------------------
+
+/// Diff viewer widget over a [`TextDiff`].
+#[derive(Debug, Clone)]
+pub struct DiffView<'a> {
+    diff: &'a TextDiff,
+    mode: DiffMode,
+    fold_unchanged: bool,
+    line_numbers: bool,
+    style: Style,
+    added_style: Style,
+    removed_style: Style,
+    added_emphasis_style: Style,
+    removed_emphasis_style: Style,
+    gutter_style: Style,
+    header_style: Style,
+    current_header_style: Style,
+    fold_style: Style,
+    separator_style: Style,
+}
+
+impl<'a> DiffView<'a> {
+    /// Create a unified view with folding and line numbers.
+    #[must_use]
+    pub fn new(diff: &'a TextDiff) -> Self {
+        Self {
+            diff,
+            mode: DiffMode::Unified,
+            fold_unchanged: true,
+            line_numbers: true,
+            style: Style::default(),
+            added_style: Style::new()
+                .fg(PackedRgba::rgb(120, 220, 140))
+                .bg(PackedRgba::rgb(18, 48, 28)),
+            removed_style: Style::new()
+                .fg(PackedRgba::rgb(240, 130, 130))
+                .bg(PackedRgba::rgb(56, 20, 22)),
+            added_emphasis_style: Style::new().bg(PackedRgba::rgb(36, 104, 56)),
+            removed_emphasis_style: Style::new().bg(PackedRgba::rgb(120, 36, 40)),
+            gutter_style: Style::new().fg(PackedRgba::rgb(110, 110, 120)),
+            header_style: Style::new().fg(PackedRgba::rgb(100, 170, 230)),
+            current_header_style: Style::new().fg(PackedRgba::rgb(100, 170, 230)).bold(),
+            fold_style: Style::new().fg(PackedRgba::rgb(110, 110, 120)).italic(),
+            separator_style: Style::new().fg(PackedRgba::rgb(80, 80, 90)),
+        }
+    }
+
+    /// Set the layout.
+    #[must_use]
+    pub fn mode(mut self, mode: DiffMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Fold unchanged runs between hunks (default `true`).
+    #[must_use]
+    pub fn fold_unchanged(mut self, fold: bool) -> Self {
+        self.fold_unchanged = fold;
+        self
+    }
+
+    /// Show line numbers (default `true`).
+    #[must_use]
+    pub fn line_numbers(mut self, show: bool) -> Self {
+        self.line_numbers = show;
+        self
+    }
+
+    /// Set the base style (context lines).
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style for added lines.
+    #[must_use]
+    pub fn added_style(mut self, style: Style) -> Self {
+        self.added_style = style;
+        self
+    }
+
+    /// Set the style for removed lines.
+    #[must_use]
+    pub fn removed_style(mut self, style: Style) -> Self {
+        self.removed_style = style;
+        self
+    }
+
+    /// Set the style for changed words within added lines.
+    #[must_use]
+    pub fn added_emphasis_style(mut self, style: Style) -> Self {
+        self.added_emphasis_style = style;
+        self
+    }
+
+    /// Set the style for changed words within removed lines.
+    #[must_use]
+    pub fn removed_emphasis_style(mut self, style: Style) -> Self {
+        self.removed_emphasis_style = style;
+        self
+    }
+
+    /// Set the line number gutter style.
+    #[must_use]
+    pub fn gutter_style(mut self, style: Style) -> Self {
+        self.gutter_style = style;
+        self
+    }
+
+    /// Set the hunk header style, and the style for the current hunk's header.
+    #[must_use]
+    pub fn header_style(mut self, style: Style, current: Style) -> Self {
+        self.header_style = style;
+        self.current_header_style = current;
+        self
+    }
+
+    /// Set the folded-run row style.
+    #[must_use]
+    pub fn fold_style(mut self, style: Style) -> Self {
+        self.fold_style = style;
+        self
+    }
+
+    /// Set the side-by-side separator style.
+    #[must_use]
+    pub fn separator_style(mut self, style: Style) -> Self {
+        self.separator_style = style;
+        self
+    }
+
+    fn number_width(&self) -> usize {
+        let max = self
+            .diff
+            .lines
+            .iter()
+            .flat_map(|l| [l.old_number, l.new_number])
+            .flatten()
+            .max()
+            .unwrap_or(0);
+        max.max(1).to_string().len()
+    }
+
+    fn line_style(&self, kind: DiffLineKind) -> (Style, Style) {
+        match kind {
+            DiffLineKind::Context => (self.style, self.style),
+            DiffLineKind::Added => (
+                self.added_style.merge(&self.style),
+                self.added_emphasis_style.merge(&self.added_style),
+            ),
+            DiffLineKind::Removed => (
+                self.removed_style.merge(&self.style),
+                self.removed_emphasis_style.merge(&self.removed_style),
+            ),
+        }
+    }
+
+    /// Draw one side of a line: gutter numbers, marker, and content.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_line(
+        &self,
+        frame: &mut Frame,
+        x: u16,
+        y: u16,
+        width: u16,
+        index: usize,
+        numbers: &[Option<usize>],
+        h_scroll: usize,
+        styled: bool,
+    ) {
+        let line = &self.diff.lines[index];
+        let pick = |style: Style| if styled { style } else { Style::default() };
+        let (base, emphasis) = self.line_style(line.kind);
+        let max_x = x.saturating_add(width);
+        crate::set_style_area(&mut frame.buffer, Rect::new(x, y, width, 1), pick(base));
+
+        let mut cx = x;
+        if self.line_numbers {
+            let digits = self.number_width();
+            for number in numbers {
+                let label = number.map_or_else(|| " ".repeat(digits), |n| format!("{n:>digits$}"));
+                cx = draw_text_span(frame, cx, y, &label, pick(self.gutter_style), max_x);
+                cx = draw_text_span(frame, cx, y, " ", pick(base), max_x);
+            }
+        }
+        let marker = match line.kind {
+            DiffLineKind::Context => "  ",
+            DiffLineKind::Added => "+ ",
+            DiffLineKind::Removed => "- ",
+        };
+        cx = draw_text_span(frame, cx, y, marker, pick(base), max_x);
+
+        let spans = if styled {
+            self.diff.highlights(index)
+        } else {
+            &[]
+        };
+        let mut next_span = 0;
+        let mut col = 0usize;
+        let mut offset = 0usize;
+        for grapheme in line.text.graphemes(true) {
+            let cells = if grapheme == "\t" {
+                TAB_WIDTH - col % TAB_WIDTH
+            } else {
+                display_width(grapheme)
+            };
+            let byte = offset;
+            offset += grapheme.len();
+            let start = col;
+            col += cells;
+            if col <= h_scroll || cells == 0 {
+                continue;
+            }
+            let screen = cx as usize + start.saturating_sub(h_scroll);
+            if screen >= max_x as usize {
+                break;
+            }
+            while next_span < spans.len() && spans[next_span].end <= byte {
+                next_span += 1;
+            }
+            let mut style = base;
+            if let Some(span) = spans.get(next_span).filter(|s| s.start <= byte) {
+                style = span.style.merge(&style);
+            }
+            if line.emphasis.iter().any(|r| r.contains(&byte)) {
+                style = emphasis.merge(&style);
+            }
+            // Tabs, and graphemes cut by the horizontal scroll edge, render as blanks.
+            if grapheme == "\t" || start < h_scroll {
+                for i in 0..col - start.max(h_scroll) {
+                    draw_text_span(frame, (screen + i) as u16, y, " ", pick(style), max_x);
+                }
+            } else {
+                draw_text_span(frame, screen as u16, y, grapheme, pick(style), max_x);
+            }
+        }
+    }
+}
+
+impl StatefulWidget for DiffView<'_> {
+    type State = DiffViewState;
+
+    fn render(&self, area: Rect, frame: &mut Frame, state: &mut Self::State) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "widget_render",
+            widget = "DiffView",
+            x = area.x,
+            y = area.y,
+            w = area.width,
+            h = area.height
+        )
+        .entered();
+
+        state.last_area = area;
+        state.last_fold_rows.clear();
+        if area.is_empty() {
+            return;
+        }
+        let styled = frame.buffer.degradation.apply_styling();
+        let pick = |style: Style| if styled { style } else { Style::default() };
+        crate::set_style_area(&mut frame.buffer, area, pick(self.style));
+
+        let rows = build_rows(self.diff, self.mode, self.fold_unchanged, &state.expanded);
+        let height = usize::from(area.height);
+        if state.jump_pending {
+            state.jump_pending = false;
+            if let Some(hunk) = state.current_hunk.and_then(|h| self.diff.hunks.get(h)) {
+                let target = rows.iter().position(|row| match *row {
+                    Row::Header(h) => Some(h) == state.current_hunk,
+                    Row::Line(i) | Row::Pair(Some(i), _) | Row::Pair(None, Some(i)) => {
+                        hunk.lines.contains(&i)
+                    }
+                    _ => false,
+                });
+                if let Some(target) = target {
+                    state.scroll = target;
+                }
+            }
+        }
+        state.scroll = state.scroll.min(rows.len().saturating_sub(height));
+        if state.resync_hunk {
+            // After manual scrolling, n/p continue from the hunk at the top row.
+            state.resync_hunk = false;
+            let line = rows.get(state.scroll).and_then(|row| match *row {
+                Row::Header(h) => Some(self.diff.hunks[h].lines.start),
+                Row::Fold { start, .. } => Some(start),
+                Row::Line(i) | Row::Pair(Some(i), _) | Row::Pair(None, Some(i)) => Some(i),
+                Row::Pair(None, None) => None,
+            });
+            if let Some(line) = line {
+                state.current_hunk = self
+                    .diff
+                    .hunks
+                    .iter()
+                    .rposition(|hunk| hunk.lines.start <= line);
+            }
+        }
+
+        let max_x = area.right();
+        for (offset, row) in rows.iter().skip(state.scroll).take(height).enumerate() {
+            let y = area.y + offset as u16;
+            match *row {
+                Row::Header(h) => {
+                    let style = if state.current_hunk == Some(h) {
+                        self.current_header_style
+                    } else {
+                        self.header_style
+                    };
+                    let header = self.diff.hunks[h].header();
+                    draw_text_span(frame, area.x, y, &header, pick(style), max_x);
+                }
+                Row::Fold { start, len } => {
+                    state.last_fold_rows.push((y, start));
+                    let noun = if len == 1 { "line" } else { "lines" };
+                    let label = format!("  ⋯ {len} unchanged {noun}");
+                    draw_text_span(frame, area.x, y, &label, pick(self.fold_style), max_x);
+                }
+                Row::Line(i) => {
+                    let line = &self.diff.lines[i];
+                    self.draw_line(
+                        frame,
+                        area.x,
+                        y,
+                        area.width,
+                        i,
+                        &[line.old_number, line.new_number],
+                        state.h_scroll,
+                        styled,
+                    );
+                }
+                Row::Pair(left, right) => {
+                    let left_width = area.width.saturating_sub(1) / 2;
+                    let right_x = area.x + left_width + 1;
+                    let right_width = area.right().saturating_sub(right_x);
+                    if let Some(i) = left {
+                        let number = self.diff.lines[i].old_number;
+                        self.draw_line(
+                            frame,
+                            area.x,
+                            y,
+                            left_width,
+                            i,
+                            &[number],
+                            state.h_scroll,
+                            styled,
+                        );
+                    }
+                    let separator = if frame.buffer.degradation.use_unicode_borders() {
+                        "│"
+                    } else {
+                        "|"
+                    };
+                    draw_text_span(
+                        frame,
+                        area.x + left_width,
+                        y,
+                        separator,
+                        pick(self.separator_style),
+                        max_x,
+                    );
+                    if let Some(j) = right {
+                        let number = self.diff.lines[j].new_number;
+                        self.draw_line(
+                            frame,
+                            right_x,
+                            y,
+                            right_width,
+                            j,
+                            &[number],
+                            state.h_scroll,
+                            styled,
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    const OLD: &str = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\n";
+    const NEW: &str = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\nm\n";
+
+    fn row(frame: &Frame, y: u16, width: u16) -> String {
+        (0..width)
+            .map(|x| {
+                frame
+                    .buffer
+                    .get(x, y)
+                    .and_then(|c| c.content.as_char())
+                    .unwrap_or(' ')
+            })
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    fn render(view: &DiffView<'_>, state: &mut DiffViewState, w: u16, h: u16) -> Vec<String> {
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(w, h, &mut pool);
+        view.render(Rect::new(0, 0, w, h), &mut frame, state);
+        (0..h).map(|y| row(&frame, y, w)).collect()
+    }
+
+    #[test]
+    fn hunks_group_changes_with_context() {
+        let diff = TextDiff::with_options(OLD, NEW, DiffAlgorithm::Myers, 1);
+        assert_eq!(diff.stats(), (2, 1));
+        let hunks = diff.hunks();
+        assert_eq!(hunks.len(), 2);
+        assert_eq!(hunks[0].header(), "@@ -1,3 +1,3 @@");
+        assert_eq!(hunks[1].header(), "@@ -12,1 +12,2 @@");
+        assert!(TextDiff::new("x\n", "x\n").is_unchanged());
+    }
+
+    #[test]
+    fn modified_lines_get_word_emphasis() {
+        let diff = TextDiff::new("let total = a + b;\n", "let total = a - b;\n");
+        let lines = diff.lines();
+        assert_eq!(lines[0].kind, DiffLineKind::Removed);
+        assert_eq!(lines[0].emphasis, vec![14..15]);
+        assert_eq!(lines[1].emphasis, vec![14..15]);
+
+        let diff = TextDiff::new("alpha\n", "zzz\n");
+        assert!(diff.lines().iter().all(|l| l.emphasis.is_empty()));
+    }
+
+    #[test]
+    fn unified_render_folds_unchanged_runs() {
+        let diff = TextDiff::with_options(OLD, NEW, DiffAlgorithm::Myers, 1);
+        let mut state = DiffViewState::default();
+        let lines = render(&DiffView::new(&diff), &mut state, 30, 10);
+        assert_eq!(lines[0], "@@ -1,3 +1,3 @@");
+        assert_eq!(lines[1], " 1  1   a");
+        assert_eq!(lines[2], " 2    - b");
+        assert_eq!(lines[3], "    2 + B");
+        assert_eq!(lines[5], "  ⋯ 8 unchanged lines");
+        assert_eq!(lines[6], "@@ -12,1 +12,2 @@");
+        assert_eq!(lines[8], "   13 + m");
+
+        assert!(state.handle_mouse(&MouseEvent::new(
+            MouseEventKind::Down(MouseButton::Left),
+            3,
+            5
+        )));
+        let lines = render(&DiffView::new(&diff), &mut state, 30, 20);
+        assert_eq!(lines[5], " 4  4   d");
+    }
+
+    #[test]
+    fn side_by_side_pairs_changes() {
+        let diff = TextDiff::new("x\nold\ny\n", "x\nnew\nextra\ny\n");
+        let mut state = DiffViewState::default();
+        let view = DiffView::new(&diff).mode(DiffMode::SideBySide);
+        let lines = render(&view, &mut state, 25, 6);
+        assert_eq!(lines[1], "1   x       │1   x");
+        assert_eq!(lines[2], "2 - old     │2 + new");
+        assert_eq!(lines[3], "            │3 + extra");
+    }
+
+    #[test]
+    fn hunk_navigation_scrolls_to_header() {
+        let diff = TextDiff::with_options(OLD, NEW, DiffAlgorithm::Myers, 1);
+        let mut state = DiffViewState::default();
+        let view = DiffView::new(&diff).fold_unchanged(false);
+        render(&view, &mut state, 20, 4);
+        assert_eq!(state.scroll(), 0);
+
+        assert!(state.handle_key(&diff, &KeyEvent::new(KeyCode::Char('n'))));
+        assert!(state.handle_key(&diff, &KeyEvent::new(KeyCode::Char('n'))));
+        assert!(!state.handle_key(&diff, &KeyEvent::new(KeyCode::Char('n'))));
+        let lines = render(&view, &mut state, 20, 4);
+        assert_eq!(state.current_hunk(), Some(1));
+        assert_eq!(lines[0], "10 10   j");
+        assert!(state.handle_key(&diff, &KeyEvent::new(KeyCode::Char('p'))));
+        render(&view, &mut state, 20, 4);
+        assert_eq!(state.scroll(), 0);
+    }
+
+    #[test]
+    fn highlighter_spans_apply_per_side() {
+        struct Upper;
+        impl LineHighlighter for Upper {
+            fn highlight_line(
+                &self,
+                line: &str,
+                state: HighlightState,
+            ) -> (Vec<HighlightSpan>, HighlightState) {
+                let spans = line
+                    .char_indices()
+                    .filter(|(_, c)| c.is_uppercase())
+                    .map(|(i, _)| HighlightSpan::new(i, i + 1, Style::new().bold()))
+                    .collect();
+                (spans, state + 1)
+            }
+        }
+        let mut diff = TextDiff::new("a\nb\n", "a\nB\n");
+        diff.highlight(&Upper);
+        assert!(diff.highlights(1).is_empty());
+        assert_eq!(diff.highlights(2).len(), 1);
+    }
+}
@@ -0,0 +1,909 @@
+#![forbid(unsafe_code)]
+
+//! Virtualized hex viewer over random-access byte sources.
+//!
+//! [`HexView`] renders offset, hex, and ASCII columns for any [`ByteSource`]:
+//! in-memory buffers and memory-mapped files (as `&mmap[..]`), or a
+//! [`ChunkedReader`] that pages a `Read + Seek` stream through a small chunk
+//! cache. Only the visible rows are read on each render, so multi-gigabyte
+//! sources are fine.
+//!
+//! [`HexViewState`] tracks the cursor, range selection, and scroll position,
+//! and provides byte-pattern search ([`HexViewState::find_next`]) and go-to
+//! offset ([`HexViewState::goto`]).
+//!
+//! ```text
+//! 00000000  50 57 41 44 02 00 00 00  0c 00 00 00 de ad be ef  PWAD............
+//! ```
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::ops::Range;
+
+use ftui_core::event::{KeyCode, KeyEvent, Modifiers, MouseButton, MouseEvent, MouseEventKind};
+use ftui_core::geometry::Rect;
+use ftui_render::cell::{Cell, PackedRgba};
+use ftui_render::frame::Frame;
+use ftui_style::Style;
+
+use crate::{StatefulWidget, apply_style, draw_text_span};
+
+/// Window size used when scanning for patterns.
+const SEARCH_WINDOW: usize = 64 * 1024;
+
+/// Bytes-per-row candidates tried, widest first, when auto-fitting.
+const AUTO_ROW_WIDTHS: [usize; 6] = [32, 24, 16, 8, 4, 1];
+
+// ---------------------------------------------------------------------------
+// ByteSource
+// ---------------------------------------------------------------------------
+
+/// Random-access, read-only byte storage.
+pub trait ByteSource {
+    /// Total length in bytes.
+    fn len(&self) -> u64;
+
+    /// Copy bytes starting at `offset` into `buf`, returning how many were
+    /// read. Short reads happen only at the end of the source or on I/O
+    /// failure.
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> usize;
+
+    /// Whether the source is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl ByteSource for [u8] {
+    fn len(&self) -> u64 {
+        <[u8]>::len(self) as u64
+    }
+
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> usize {
+        let Some(available) = usize::try_from(offset)
+            .ok()
+            .and_then(|start| self.get(start..))
+        else {
+            return 0;
+        };
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        n
+    }
+}
+
+impl ByteSource for Vec<u8> {
+    fn len(&self) -> u64 {
+        ByteSource::len(self.as_slice())
+    }
+
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> usize {
+        self.as_slice().read_at(offset, buf)
+    }
+}
+
+impl<const N: usize> ByteSource for [u8; N] {
+    fn len(&self) -> u64 {
+        N as u64
+    }
+
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> usize {
+        self.as_slice().read_at(offset, buf)
+    }
+}
+
+/// A [`ByteSource`] over a seekable reader, cached in fixed-size chunks.
+///
+/// Keeps the most recently used chunks in memory (8 × 64 KiB by default), so
+/// scrolling through a large file only touches the reader for new regions.
+#[derive(Debug)]
+pub struct ChunkedReader<R> {
+    inner: RefCell<R>,
+    len: u64,
+    chunk_size: usize,
+    capacity: usize,
+    cache: RefCell<VecDeque<(u64, Vec<u8>)>>,
+}
+
+impl<R: Read + Seek> ChunkedReader<R> {
+    /// Wrap a reader, measuring its length by seeking to the end.
+    pub fn new(reader: R) -> io::Result<Self> {
+        Self::with_chunks(reader, 64 * 1024, 8)
+    }
+
+    /// Wrap a reader with a custom chunk size and number of cached chunks.
+    pub fn with_chunks(mut reader: R, chunk_size: usize, capacity: usize) -> io::Result<Self> {
+        let len = reader.seek(SeekFrom::End(0))?;
+        Ok(Self {
+            inner: RefCell::new(reader),
+            len,
+            chunk_size: chunk_size.max(1),
+            capacity: capacity.max(1),
+            cache: RefCell::new(VecDeque::new()),
+        })
+    }
+
+    /// Consume the wrapper and return the reader.
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+
+    /// Copy up to `buf.len()` bytes of chunk `index` from `start` within it.
+    fn read_chunk(&self, index: u64, start: usize, buf: &mut [u8]) -> usize {
+        let mut cache = self.cache.borrow_mut();
+        let position = cache.iter().position(|(i, _)| *i == index);
+        let chunk = match position {
+            Some(pos) => {
+                let entry = cache.remove(pos).expect("cached chunk");
+                cache.push_front(entry);
+                &cache[0].1
+            }
+            None => {
+                let offset = index * self.chunk_size as u64;
+                let size = (self.len - offset).min(self.chunk_size as u64) as usize;
+                let mut data = vec![0u8; size];
+                let mut reader = self.inner.borrow_mut();
+                if reader.seek(SeekFrom::Start(offset)).is_err() {
+                    return 0;
+                }
+                let mut filled = 0;
+                while filled < size {
+                    match reader.read(&mut data[filled..]) {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => filled += n,
+                    }
+                }
+                data.truncate(filled);
+                if cache.len() >= self.capacity {
+                    cache.pop_back();
+                }
+                cache.push_front((index, data));
+                &cache[0].1
+            }
+        };
+        let available = chunk.get(start..).unwrap_or(&[]);
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        n
+    }
+}
+
+impl<R: Read + Seek> ByteSource for ChunkedReader<R> {
+    fn len(&self) -> u64 {
+        self.len
+    }
+
+    fn read_at(&self, offset: u64, buf: &mut [u8]) -> usize {
+        let chunk_size = self.chunk_size as u64;
+        let mut done = 0usize;
+        while done < buf.len() {
+            let position = offset + done as u64;
+            if position >= self.len {
+                break;
+            }
+            let n = self.read_chunk(
+                position / chunk_size,
+                (position % chunk_size) as usize,
+                &mut buf[done..],
+            );
+            if n == 0 {
+                break;
+            }
+            done += n;
+        }
+        done
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Parsing and search helpers
+// ---------------------------------------------------------------------------
+
+/// Parse a hex byte pattern such as `"de ad be ef"`, `"DEADBEEF"`, or
+/// `"0xde,0xad"`. Returns `None` for odd digit counts or invalid characters.
+#[must_use]
+pub fn parse_hex_pattern(input: &str) -> Option<Vec<u8>> {
+    let digits: String = input
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .map(|token| {
+            token
+                .strip_prefix("0x")
+                .or_else(|| token.strip_prefix("0X"))
+                .unwrap_or(token)
+        })
+        .collect();
+    if digits.is_empty() || !digits.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(digits.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Parse an offset in decimal or `0x`-prefixed hexadecimal.
+#[must_use]
+pub fn parse_offset(input: &str) -> Option<u64> {
+    let input = input.trim();
+    match input
+        .strip_prefix("0x")
+        .or_else(|| input.strip_prefix("0X"))
+    {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => input.parse().ok(),
+    }
+}
+
+/// Find the first occurrence of `pattern` starting at or after `from`.
+#[must_use]
+pub fn find_forward(source: &dyn ByteSource, pattern: &[u8], from: u64) -> Option<u64> {
+    let len = source.len();
+    if pattern.is_empty() || pattern.len() as u64 > len {
+        return None;
+    }
+    let overlap = pattern.len() - 1;
+    let mut buf = vec![0u8; SEARCH_WINDOW + overlap];
+    let mut start = from;
+    while start + pattern.len() as u64 <= len {
+        let n = source.read_at(start, &mut buf);
+        if n < pattern.len() {
+            return None;
+        }
+        if let Some(pos) = buf[..n].windows(pattern.len()).position(|w| w == pattern) {
+            return Some(start + pos as u64);
+        }
+        start += (n - overlap) as u64;
+    }
+    None
+}
+
+/// Find the last occurrence of `pattern` starting strictly before `before`.
+#[must_use]
+pub fn find_backward(source: &dyn ByteSource, pattern: &[u8], before: u64) -> Option<u64> {
+    if pattern.is_empty() {
+        return None;
+    }
+    let overlap = pattern.len() as u64 - 1;
+    // Exclusive end of the bytes a match starting before `before` may touch.
+    let mut end = before.saturating_add(overlap).min(source.len());
+    let mut buf = vec![0u8; SEARCH_WINDOW + overlap as usize];
+    while end >= pattern.len() as u64 {
+        let start = end.saturating_sub(buf.len() as u64);
+        let n = source.read_at(start, &mut buf[..(end - start) as usize]);
+        if let Some(pos) = buf[..n].windows(pattern.len()).rposition(|w| w == pattern) {
+            return Some(start + pos as u64);
+        }
+        if start == 0 {
+            break;
+        }
+        end = start + overlap;
+    }
+    None
+}
+
+// ---------------------------------------------------------------------------
+// HexViewState
+// ---------------------------------------------------------------------------
+
+/// Cursor, selection, and scroll state for a [`HexView`].
+#[derive(Debug, Clone, Default)]
+pub struct HexViewState {
+    cursor: u64,
+    anchor: Option<u64>,
+    scroll_row: u64,
+    follow_cursor: bool,
+    last_area: Rect,
+    last_layout: Option<RowLayout>,
+}
+
+impl HexViewState {
+    /// Byte offset under the cursor.
+    #[must_use]
+    pub fn cursor(&self) -> u64 {
+        self.cursor
+    }
+
+    /// First visible row.
+    #[must_use]
+    pub fn scroll_row(&self) -> u64 {
+        self.scroll_row
+    }
+
+    /// Selected byte range (inclusive of the cursor), if a selection is active.
+    #[must_use]
+    pub fn selection(&self) -> Option<Range<u64>> {
+        let anchor = self.anchor?;
+        Some(anchor.min(self.cursor)..anchor.max(self.cursor) + 1)
+    }
+
+    /// Select `range`, placing the cursor on its last byte.
+    pub fn select(&mut self, range: Range<u64>) {
+        if range.is_empty() {
+            self.anchor = None;
+            return;
+        }
+        self.anchor = Some(range.start);
+        self.cursor = range.end - 1;
+        self.follow_cursor = true;
+    }
+
+    /// Clear the selection. Returns `true` if one was active.
+    pub fn clear_selection(&mut self) -> bool {
+        self.anchor.take().is_some()
+    }
+
+    /// Move the cursor to `offset` (clamped to the source), clearing the
+    /// selection and scrolling it into view.
+    pub fn goto(&mut self, source: &dyn ByteSource, offset: u64) {
+        self.anchor = None;
+        self.cursor = offset.min(source.len().saturating_sub(1));
+        self.follow_cursor = true;
+    }
+
+    /// Search forward from just after the cursor, wrapping to the start.
+    /// On a match the cursor moves to it and the match is selected.
+    pub fn find_next(&mut self, source: &dyn ByteSource, pattern: &[u8]) -> Option<u64> {
+        let from = self.selection().map_or(self.cursor, |r| r.start) + 1;
+        let found =
+            find_forward(source, pattern, from).or_else(|| find_forward(source, pattern, 0));
+        self.select_match(found, pattern.len())
+    }
+
+    /// Search backward from the cursor, wrapping to the end.
+    pub fn find_prev(&mut self, source: &dyn ByteSource, pattern: &[u8]) -> Option<u64> {
+        let before = self.selection().map_or(self.cursor, |r| r.start);
+        let found = find_backward(source, pattern, before)
+            .or_else(|| find_backward(source, pattern, source.len()));
+        self.select_match(found, pattern.len())
+    }
+
+    fn select_match(&mut self, found: Option<u64>, len: usize) -> Option<u64> {
+        let start = found?;
+        self.anchor = Some(start);
+        self.cursor = start + len as u64 - 1;
+        self.follow_cursor = true;
+        Some(start)
+    }
+
+    /// Scroll up by `rows` without moving the cursor.
+    pub fn scroll_up(&mut self, rows: u64) {
+        self.scroll_row = self.scroll_row.saturating_sub(rows);
+        self.follow_cursor = false;
+    }
+
+    /// Scroll down by `rows` without moving the cursor (clamped at render).
+    pub fn scroll_down(&mut self, rows: u64) {
+        self.scroll_row = self.scroll_row.saturating_add(rows);
+        self.follow_cursor = false;
+    }
+
+    fn bytes_per_row(&self) -> u64 {
+        self.last_layout
+            .map_or(16, |layout| layout.bytes_per_row as u64)
+    }
+
+    fn move_cursor(&mut self, source: &dyn ByteSource, target: u64, extend: bool) -> bool {
+        let before = (self.cursor, self.anchor);
+        if extend {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = target.min(source.len().saturating_sub(1));
+        self.follow_cursor = true;
+        before != (self.cursor, self.anchor)
+    }
+
+    /// Handle a key press. Returns `true` if the state changed.
+    ///
+    /// Arrows move by byte/row, PageUp/PageDown by page, Home/End to the row
+    /// start/end (with Ctrl: the source start/end). Shift extends the
+    /// selection; Escape clears it.
+    pub fn handle_key(&mut self, source: &dyn ByteSource, key: &KeyEvent) -> bool {
+        let bpr = self.bytes_per_row();
+        let page = u64::from(self.last_area.height.max(1)) * bpr;
+        let extend = key.shift();
+        let cursor = self.cursor;
+        let target = match key.code {
+            KeyCode::Left => cursor.saturating_sub(1),
+            KeyCode::Right => cursor.saturating_add(1),
+            KeyCode::Up if cursor >= bpr => cursor - bpr,
+            KeyCode::Up => cursor,
+            KeyCode::Down if cursor + bpr < source.len() => cursor + bpr,
+            KeyCode::Down => cursor,
+            KeyCode::PageUp => cursor.saturating_sub(page),
+            KeyCode::PageDown => cursor.saturating_add(page),
+            KeyCode::Home if key.modifiers.contains(Modifiers::CTRL) => 0,
+            KeyCode::End if key.modifiers.contains(Modifiers::CTRL) => u64::MAX,
+            KeyCode::Home => cursor - cursor % bpr,
+            KeyCode::End => cursor - cursor % bpr + bpr - 1,
+            KeyCode::Escape => return self.clear_selection(),
+            _ => return false,
+        };
+        self.move_cursor(source, target, extend)
+    }
+
+    /// Handle a mouse event against the last render: click places the
+    /// cursor (Shift+click extends), drag selects, and the wheel scrolls.
+    pub fn handle_mouse(&mut self, source: &dyn ByteSource, event: &MouseEvent) -> bool {
+        if !self.last_area.contains(event.x, event.y) {
+            return false;
+        }
+        match event.kind {
+            MouseEventKind::ScrollUp => {
+                let before = self.scroll_row;
+                self.scroll_up(3);
+                before != self.scroll_row
+            }
+            MouseEventKind::ScrollDown => {
+                self.scroll_down(3);
+                true
+            }
+            MouseEventKind::Down(MouseButton::Left) | MouseEventKind::Drag(MouseButton::Left) => {
+                let Some(offset) = self.offset_at(event.x, event.y) else {
+                    return false;
+                };
+                if offset >= source.len() {
+                    return false;
+                }
+                let extend = matches!(event.kind, MouseEventKind::Drag(_))
+                    || event.modifiers.contains(Modifiers::SHIFT);
+                self.move_cursor(source, offset, extend)
+            }
+            _ => false,
+        }
+    }
+
+    /// Byte offset displayed at a screen position in the last render.
+    #[must_use]
+    pub fn offset_at(&self, x: u16, y: u16) -> Option<u64> {
+        let layout = self.last_layout?;
+        let area = self.last_area;
+        if !area.contains(x, y) {
+            return None;
+        }
+        let column = layout.byte_at(x - area.x)?;
+        let row = self.scroll_row + u64::from(y - area.y);
+        Some(row * layout.bytes_per_row as u64 + column as u64)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Layout
+// ---------------------------------------------------------------------------
+
+/// Column positions for one row, relative to the widget's left edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct RowLayout {
+    bytes_per_row: usize,
+    offset_digits: usize,
+}
+
+impl RowLayout {
+    fn hex_start(self) -> usize {
+        self.offset_digits + 2
+    }
+
+    /// Column of byte `i`'s first hex digit (an extra gap every 8 bytes).
+    fn hex_col(self, i: usize) -> usize {
+        self.hex_start() + 3 * i + i / 8
+    }
+
+    fn ascii_start(self) -> usize {
+        self.hex_col(self.bytes_per_row.saturating_sub(1)) + 4
+    }
+
+    fn width(self) -> usize {
+        self.ascii_start() + self.bytes_per_row
+    }
+
+    fn byte_at(self, x: u16) -> Option<usize> {
+        let x = usize::from(x);
+        if x >= self.ascii_start() {
+            let i = x - self.ascii_start();
+            return (i < self.bytes_per_row).then_some(i);
+        }
+        (0..self.bytes_per_row).find(|&i| (self.hex_col(i)..self.hex_col(i) + 2).contains(&x))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// HexView
+// ---------------------------------------------------------------------------
+
+/// An annotated byte range drawn with its own style.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HexRegion {
+    /// Byte range covered.
+    pub range: Range<u64>,
+    /// Style merged over the bytes in the range.
+    pub style: Style,
+    /// Optional description (see [`HexView::region_at`]).
+    pub label: Option<String>,
+}
+
+/// Hex viewer widget.
+pub struct HexView<'a> {
+    source: &'a dyn ByteSource,
+    bytes_per_row: usize,
+    regions: Vec<HexRegion>,
+    style: Style,
+    offset_style: Style,
+    zero_style: Style,
+    control_style: Style,
+    selection_style: Style,
+    cursor_style: Style,
+}
+
+impl std::fmt::Debug for HexView<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HexView")
+            .field("len", &self.source.len())
+            .field("bytes_per_row", &self.bytes_per_row)
+            .field("regions", &self.regions)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> HexView<'a> {
+    /// Create a viewer showing 16 bytes per row.
+    #[must_use]
+    pub fn new(source: &'a dyn ByteSource) -> Self {
+        Self {
+            source,
+            bytes_per_row: 16,
+            regions: Vec::new(),
+            style: Style::default(),
+            offset_style: Style::new().fg(PackedRgba::rgb(110, 110, 120)),
+            zero_style: Style::new().fg(PackedRgba::rgb(90, 90, 100)),
+            control_style: Style::new().fg(PackedRgba::rgb(90, 90, 100)),
+            selection_style: Style::new().bg(PackedRgba::rgb(50, 70, 110)),
+            cursor_style: Style::new().reverse(),
+        }
+    }
+
+    /// Set bytes per row; `0` picks the widest of 32/24/16/8/4/1 that fits.
+    #[must_use]
+    pub fn bytes_per_row(mut self, bytes: usize) -> Self {
+        self.bytes_per_row = bytes;
+        self
+    }
+
+    /// Highlight a byte range. Later regions take precedence.
+    #[must_use]
+    pub fn region(mut self, range: Range<u64>, style: Style, label: Option<String>) -> Self {
+        self.regions.push(HexRegion {
+            range,
+            style,
+            label,
+        });
+        self
+    }
+
+    /// Set the base style.
+    #[must_use]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the offset column style.
+    #[must_use]
+    pub fn offset_style(mut self, style: Style) -> Self {
+        self.offset_style = style;
+        self
+    }
+
+    /// Set the style for `00` bytes.
+    #[must_use]
+    pub fn zero_style(mut self, style: Style) -> Self {
+        self.zero_style = style;
+        self
+    }
+
+    /// Set the style for non-printable bytes in the ASCII column.
+    #[must_use]
+    pub fn control_style(mut self, style: Style) -> Self {
+        self.control_style = style;
+        self
+    }
+
+    /// Set the selection style.
+    #[must_use]
+    pub fn selection_style(mut self, style: Style) -> Self {
+        self.selection_style = style;
+        self
+    }
+
+    /// Set the cursor style.
+    #[must_use]
+    pub fn cursor_style(mut self, style: Style) -> Self {
+        self.cursor_style = style;
+        self
+    }
+
+    /// The innermost (last added) region containing `offset`.
+    #[must_use]
+    pub fn region_at(&self, offset: u64) -> Option<&HexRegion> {
+        self.regions
+            .iter()
+            .rev()
+            .find(|r| r.range.contains(&offset))
+    }
+
+    fn layout(&self, width: u16) -> RowLayout {
+        let hex_digits = 64 - self.source.len().saturating_sub(1).leading_zeros() as usize;
+        let offset_digits = hex_digits.div_ceil(4).max(8);
+        let fixed = |bytes_per_row| RowLayout {
+            bytes_per_row,
+            offset_digits,
+        };
+        if self.bytes_per_row > 0 {
+            return fixed(self.bytes_per_row);
+        }
+        AUTO_ROW_WIDTHS
+            .into_iter()
+            .map(fixed)
+            .find(|layout| layout.width() <= usize::from(width))
+            .unwrap_or(fixed(1))
+    }
+
+    fn byte_style(&self, offset: u64, state: &HexViewState, base: Style) -> Style {
+        let mut style = base;
+        for region in &self.regions {
+            if region.range.contains(&offset) {
+                style = region.style.merge(&style);
+            }
+        }
+        if state.selection().is_some_and(|r| r.contains(&offset)) {
+            style = self.selection_style.merge(&style);
+        }
+        if offset == state.cursor {
+            style = self.cursor_style.merge(&style);
+        }
+        style
+    }
+}
+
+impl StatefulWidget for HexView<'_> {
+    type State = HexViewState;
+
+    fn render(&self, area: Rect, frame: &mut Frame, state: &mut Self::State) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "widget_render",
+            widget = "HexView",
+            x = area.x,
+            y = area.y,
+            w = area.width,
+            h = area.height
+        )
+        .entered();
+
+        let layout = self.layout(area.width);
+        state.last_area = area;
+        state.last_layout = Some(layout);
+        if area.is_empty() {
+            return;
+        }
+        let styled = frame.buffer.degradation.apply_styling();
+        let pick = |style: Style| if styled { style } else { Style::default() };
+        crate::set_style_area(&mut frame.buffer, area, pick(self.style));
+
+        let len = self.source.len();
+        let bpr = layout.bytes_per_row as u64;
+        let height = u64::from(area.height);
+        let total_rows = len.div_ceil(bpr);
+        state.cursor = state.cursor.min(len.saturating_sub(1));
+        if state.follow_cursor {
+            let cursor_row = state.cursor / bpr;
+            if cursor_row < state.scroll_row {
+                state.scroll_row = cursor_row;
+            } else if cursor_row >= state.scroll_row + height {
+                state.scroll_row = cursor_row + 1 - height;
+            }
+        }
+        state.scroll_row = state.scroll_row.min(total_rows.saturating_sub(height));
+
+        let first = state.scroll_row * bpr;
+        let visible = (height * bpr).min(len.saturating_sub(first)) as usize;
+        let mut bytes = vec![0u8; visible];
+        let read = self.source.read_at(first, &mut bytes);
+        bytes.truncate(read);
+
+        let max_x = area.right();
+        let hex = b"0123456789abcdef";
+        for (row, chunk) in bytes.chunks(layout.bytes_per_row).enumerate() {
+            let y = area.y + row as u16;
+            let row_offset = first + row as u64 * bpr;
+            let label = format!("{row_offset:0width$x}", width = layout.offset_digits);
+            draw_text_span(frame, area.x, y, &label, pick(self.offset_style), max_x);
+
+            for (i, &byte) in chunk.iter().enumerate() {
+                let offset = row_offset + i as u64;
+                let base = if byte == 0 {
+                    self.zero_style.merge(&self.style)
+                } else {
+                    self.style
+                };
+                let style = pick(self.byte_style(offset, state, base));
+                let x = area.x as usize + layout.hex_col(i);
+                for (d, digit) in [hex[usize::from(byte >> 4)], hex[usize::from(byte & 0xf)]]
+                    .into_iter()
+                    .enumerate()
+                {
+                    if x + d < max_x as usize {
+                        let mut cell = Cell::from_char(char::from(digit));
+                        apply_style(&mut cell, style);
+                        frame.buffer.set_fast((x + d) as u16, y, cell);
+                    }
+                }
+
+                let x = area.x as usize + layout.ascii_start() + i;
+                if x < max_x as usize {
+                    let printable = (0x20..0x7f).contains(&byte);
+                    let (ch, base) = if printable {
+                        (char::from(byte), self.style)
+                    } else {
+                        ('.', self.control_style.merge(&self.style))
+                    };
+                    let mut cell = Cell::from_char(ch);
+                    apply_style(&mut cell, pick(self.byte_style(offset, state, base)));
+                    frame.buffer.set_fast(x as u16, y, cell);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_render::grapheme_pool::GraphemePool;
+    use std::io::Cursor;
+
+    fn row(frame: &Frame, y: u16, width: u16) -> String {
+        (0..width)
+            .map(|x| {
+                frame
+                    .buffer
+                    .get(x, y)
+                    .and_then(|c| c.content.as_char())
+                    .unwrap_or(' ')
+            })
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    fn render(view: &HexView<'_>, state: &mut HexViewState, w: u16, h: u16) -> Vec<String> {
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(w, h, &mut pool);
+        view.render(Rect::new(0, 0, w, h), &mut frame, state);
+        (0..h).map(|y| row(&frame, y, w)).collect()
+    }
+
+    #[test]
+    fn renders_offset_hex_and_ascii_columns() {
+        let data: Vec<u8> = b"PWAD\x02\x00\x00\x00Hello, hex view!".to_vec();
+        let view = HexView::new(&data);
+        let mut state = HexViewState::default();
+        let lines = render(&view, &mut state, 80, 3);
+        assert_eq!(
+            lines[0],
+            "00000000  50 57 41 44 02 00 00 00  48 65 6c 6c 6f 2c 20 68  PWAD....Hello, h"
+        );
+        assert_eq!(
+            lines[1],
+            "00000010  65 78 20 76 69 65 77 21                           ex view!"
+        );
+        assert_eq!(lines[2], "");
+    }
+
+    #[test]
+    fn auto_width_fits_area() {
+        let data = vec![0u8; 64];
+        let view = HexView::new(&data).bytes_per_row(0);
+        assert_eq!(view.layout(80).bytes_per_row, 16);
+        assert_eq!(view.layout(200).bytes_per_row, 32);
+        assert_eq!(view.layout(20).bytes_per_row, 1);
+    }
+
+    #[test]
+    fn keyboard_moves_and_extends_selection() {
+        let data = vec![0u8; 100];
+        let view = HexView::new(&data);
+        let mut state = HexViewState::default();
+        render(&view, &mut state, 80, 4);
+
+        assert!(state.handle_key(&data, &KeyEvent::new(KeyCode::Down)));
+        assert_eq!(state.cursor(), 16);
+        let shift = |code| KeyEvent::new(code).with_modifiers(Modifiers::SHIFT);
+        assert!(state.handle_key(&data, &shift(KeyCode::Right)));
+        assert!(state.handle_key(&data, &shift(KeyCode::End)));
+        assert_eq!(state.selection(), Some(16..32));
+        assert!(state.handle_key(&data, &KeyEvent::new(KeyCode::Escape)));
+        assert_eq!(state.selection(), None);
+
+        let ctrl_end = KeyEvent::new(KeyCode::End).with_modifiers(Modifiers::CTRL);
+        assert!(state.handle_key(&data, &ctrl_end));
+        assert_eq!(state.cursor(), 99);
+        render(&view, &mut state, 80, 4);
+        assert_eq!(state.scroll_row(), 3);
+    }
+
+    #[test]
+    fn mouse_click_and_drag_select() {
+        let data = vec![0xAAu8; 64];
+        let view = HexView::new(&data);
+        let mut state = HexViewState::default();
+        render(&view, &mut state, 80, 4);
+
+        // Byte 9 sits after the mid-row gap: 10 + 3*9 + 1 = 38.
+        assert_eq!(state.offset_at(38, 0), Some(9));
+        assert_eq!(state.offset_at(60, 1), Some(16));
+        assert_eq!(state.offset_at(61, 1), Some(17));
+        assert_eq!(state.offset_at(34, 0), None);
+
+        let click = MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 13, 0);
+        assert!(state.handle_mouse(&data, &click));
+        let drag = MouseEvent::new(MouseEventKind::Drag(MouseButton::Left), 61, 1);
+        assert!(state.handle_mouse(&data, &drag));
+        assert_eq!(state.selection(), Some(1..18));
+    }
+
+    #[test]
+    fn search_and_goto() {
+        let mut data = vec![0u8; 200_000];
+        data[10..14].copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+        data[150_000..150_004].copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+        let pattern = parse_hex_pattern("0xDE ad,BEef").expect("pattern");
+        assert_eq!(pattern, vec![0xde, 0xad, 0xbe, 0xef]);
+        assert!(parse_hex_pattern("abc").is_none());
+        assert_eq!(parse_offset("0x1f"), Some(31));
+        assert_eq!(parse_offset("42"), Some(42));
+
+        let mut state = HexViewState::default();
+        assert_eq!(state.find_next(&data, &pattern), Some(10));
+        assert_eq!(state.selection(), Some(10..14));
+        assert_eq!(state.find_next(&data, &pattern), Some(150_000));
+        assert_eq!(state.find_next(&data, &pattern), Some(10));
+        assert_eq!(state.find_prev(&data, &pattern), Some(150_000));
+
+        state.goto(&data, 1_000_000);
+        assert_eq!(state.cursor(), 199_999);
+        assert_eq!(state.selection(), None);
+    }
+
+    #[test]
+    fn chunked_reader_reads_across_chunks() {
+        let data: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+        let reader = ChunkedReader::with_chunks(Cursor::new(data.clone()), 64, 2).expect("reader");
+        assert_eq!(ByteSource::len(&reader), 1000);
+        let mut buf = [0u8; 150];
+        assert_eq!(reader.read_at(100, &mut buf), 150);
+        assert_eq!(&buf[..], &data[100..250]);
+        assert_eq!(reader.read_at(990, &mut buf), 10);
+        assert_eq!(find_forward(&reader, &[250, 251, 252], 300), Some(506));
+        assert_eq!(find_backward(&reader, &[250, 251, 252], 506), Some(250));
+    }
+
+    #[test]
+    fn regions_style_bytes() {
+        let data = vec![1u8; 32];
+        let header = Style::new().bold();
+        let view = HexView::new(&data).region(0..4, header, Some("header".into()));
+        assert_eq!(
+            view.region_at(2).and_then(|r| r.label.as_deref()),
+            Some("header")
+        );
+        let mut state = HexViewState::default();
+        state.goto(&data, 20);
+        assert_eq!(view.byte_style(1, &state, Style::default()), header);
+        assert_eq!(
+            view.byte_style(5, &state, Style::default()),
+            Style::default()
+        );
+    }
+}
@@ -4,16 +4,29 @@
 //!
 //! A single-line text input field with cursor management, scrolling, selection,
 //! word-level operations, and styling. Grapheme-cluster aware for correct Unicode handling.
+//!
+//! With the `bidi` feature, values containing right-to-left text are displayed
+//! in bidi visual order, and Left/Right move the cursor visually; the value,
+//! cursor and selection stay in logical order.
+//!
+//! A vim or emacs [`Keymap`] can be installed with [`TextInput::with_keymap`],
+//! and autocomplete attached with [`TextInput::with_completion`].
 
 use ftui_core::event::{Event, KeyCode, KeyEvent, KeyEventKind, Modifiers};
 use ftui_core::geometry::Rect;
 use ftui_render::cell::{Cell, CellContent};
 use ftui_render::frame::Frame;
-use ftui_style::Style;
+use ftui_style::{SelectorSheet, Style, StyleNode, WidgetState};
+#[cfg(feature = "bidi")]
+use ftui_text::bidi::{self, BidiLine, ParagraphDirection};
 use ftui_text::grapheme_width;
+use std::borrow::Cow;
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::Widget;
+use crate::completion::{Completer, CompletionKeyResult, CompletionPopup};
+use crate::keymap::{Keymap, KeymapMode, KeymapResult, KeymapSlot, KeymapTarget};
+use crate::sheet::{self, SheetStyled};
 use crate::undo_support::{TextEditOperation, TextInputUndoExt, UndoSupport, UndoWidgetId};
 
 /// A single-line text input widget.
@@ -45,6 +58,12 @@ pub struct TextInput {
     selection_style: Style,
     /// Whether the input is focused (controls cursor output).
     focused: bool,
+    /// Editing keymap consulted before the default bindings.
+    keymap: Option<KeymapSlot>,
+    /// Edits made while a keymap is installed, for its undo and redo.
+    keymap_edits: KeymapEdits,
+    /// Autocomplete state.
+    completion: Option<Completer>,
 }
 
 impl TextInput {
@@ -61,6 +80,7 @@ impl TextInput {
         self.value = value.into();
         self.cursor = self.value.graphemes(true).count();
         self.selection_anchor = None;
+        self.keymap_edits = KeymapEdits::default();
         self
     }
 
@@ -120,6 +140,66 @@ impl TextInput {
         self
     }
 
+    /// Install an editing keymap (builder).
+    #[must_use]
+    pub fn with_keymap(mut self, keymap: impl Keymap + 'static) -> Self {
+        self.keymap = Some(KeymapSlot(Box::new(keymap)));
+        self
+    }
+
+    /// Install or remove the editing keymap.
+    pub fn set_keymap(&mut self, keymap: Option<Box<dyn Keymap>>) {
+        self.keymap = keymap.map(KeymapSlot);
+    }
+
+    /// The installed editing keymap, if any.
+    #[must_use]
+    pub fn keymap(&self) -> Option<&dyn Keymap> {
+        self.keymap.as_ref().map(|slot| &*slot.0)
+    }
+
+    /// Mode of the installed keymap, for status-line display.
+    #[must_use]
+    pub fn keymap_mode(&self) -> Option<KeymapMode> {
+        self.keymap.as_ref().map(|slot| slot.0.mode())
+    }
+
+    /// Attach autocomplete (builder).
+    #[must_use]
+    pub fn with_completion(mut self, completer: Completer) -> Self {
+        self.completion = Some(completer);
+        self
+    }
+
+    /// Attach or remove autocomplete.
+    pub fn set_completion(&mut self, completer: Option<Completer>) {
+        self.completion = completer;
+    }
+
+    /// The attached completer, if any.
+    #[must_use]
+    pub fn completion(&self) -> Option<&Completer> {
+        self.completion.as_ref()
+    }
+
+    /// Mutable access to the attached completer, e.g. to deliver async results.
+    pub fn completion_mut(&mut self) -> Option<&mut Completer> {
+        self.completion.as_mut()
+    }
+
+    /// Candidate popup anchored under the word being completed, for an input
+    /// rendered at `area`.
+    #[must_use]
+    pub fn completion_popup(&self, area: Rect) -> Option<CompletionPopup> {
+        let completion = self.completion.as_ref()?;
+        let range = completion.word_range()?;
+        let start = self.value.get(..range.start)?;
+        let start_visual: usize = start.graphemes(true).map(|g| self.grapheme_width(g)).sum();
+        let scroll = self.effective_scroll(area.width as usize);
+        let rel_x = start_visual.saturating_sub(scroll).min(u16::MAX as usize) as u16;
+        completion.popup((area.x.saturating_add(rel_x), area.y))
+    }
+
     // --- Value access ---
 
     /// Get the current value.
@@ -134,6 +214,7 @@ impl TextInput {
         self.cursor = self.cursor.min(max);
         self.scroll_cells.set(0);
         self.selection_anchor = None;
+        self.keymap_edits = KeymapEdits::default();
     }
 
     /// Clear all text.
@@ -142,6 +223,7 @@ impl TextInput {
         self.cursor = 0;
         self.scroll_cells.set(0);
         self.selection_anchor = None;
+        self.keymap_edits = KeymapEdits::default();
     }
 
     /// Get the cursor position (grapheme index).
@@ -196,17 +278,56 @@ impl TextInput {
             Event::Key(key)
                 if key.kind == KeyEventKind::Press || key.kind == KeyEventKind::Repeat =>
             {
-                self.handle_key(key)
+                if self.completion_key(key) {
+                    return true;
+                }
+                let changed = if let Some(mut slot) = self.keymap.take() {
+                    let result = slot.0.handle_key(key, self);
+                    self.keymap = Some(slot);
+                    result == KeymapResult::Handled
+                        || self.record_keymap_edit(|input| input.handle_key(key))
+                } else {
+                    self.handle_key(key)
+                };
+                self.update_completion();
+                changed
             }
             Event::Paste(paste) => {
-                self.delete_selection();
-                self.insert_text(&paste.text);
+                self.record_keymap_edit(|input| {
+                    input.delete_selection();
+                    input.insert_text(&paste.text);
+                    true
+                });
+                self.update_completion();
                 true
             }
             _ => false,
         }
     }
 
+    /// Let the completer handle `key`. Returns `true` if it consumed it.
+    fn completion_key(&mut self, key: &KeyEvent) -> bool {
+        let cursor = self.grapheme_byte_offset(self.cursor);
+        let Some(completion) = &mut self.completion else {
+            return false;
+        };
+        match completion.handle_key(key, &self.value, cursor) {
+            CompletionKeyResult::Ignored => false,
+            CompletionKeyResult::Handled => true,
+            CompletionKeyResult::Accept { range, value } => {
+                self.replace_offsets(range.start, range.end, &value);
+                true
+            }
+        }
+    }
+
+    fn update_completion(&mut self) {
+        let cursor = self.grapheme_byte_offset(self.cursor);
+        if let Some(completion) = &mut self.completion {
+            completion.update(&self.value, cursor);
+        }
+    }
+
     fn handle_key(&mut self, key: &KeyEvent) -> bool {
         let ctrl = key.modifiers.contains(Modifiers::CTRL);
         let shift = key.modifiers.contains(Modifiers::SHIFT);
@@ -486,34 +607,66 @@ impl TextInput {
 
     fn move_cursor_left(&mut self) {
         if let Some(anchor) = self.selection_anchor.take() {
-            self.cursor = self.cursor.min(anchor);
-        } else if self.cursor > 0 {
-            self.cursor -= 1;
+            self.cursor = self.selection_edge(anchor, false);
+        } else {
+            self.cursor = self.visual_step(false);
         }
     }
 
     fn move_cursor_right(&mut self) {
         if let Some(anchor) = self.selection_anchor.take() {
-            self.cursor = self.cursor.max(anchor);
-        } else if self.cursor < self.grapheme_count() {
-            self.cursor += 1;
+            self.cursor = self.selection_edge(anchor, true);
+        } else {
+            self.cursor = self.visual_step(true);
         }
     }
 
     fn move_cursor_left_select(&mut self) {
         self.ensure_selection_anchor();
-        if self.cursor > 0 {
-            self.cursor -= 1;
-        }
+        self.cursor = self.visual_step(false);
     }
 
     fn move_cursor_right_select(&mut self) {
         self.ensure_selection_anchor();
-        if self.cursor < self.grapheme_count() {
-            self.cursor += 1;
+        self.cursor = self.visual_step(true);
+    }
+
+    /// Cursor position one step left or right on screen.
+    ///
+    /// Logical ±1 for left-to-right text; follows the bidi display order
+    /// when the value contains right-to-left text.
+    fn visual_step(&self, rightward: bool) -> usize {
+        #[cfg(feature = "bidi")]
+        if let Some(layout) = self.bidi_layout() {
+            let next = if rightward {
+                layout.caret_right(self.cursor)
+            } else {
+                layout.caret_left(self.cursor)
+            };
+            return next.unwrap_or(self.cursor);
+        }
+        if rightward {
+            (self.cursor + 1).min(self.grapheme_count())
+        } else {
+            self.cursor.saturating_sub(1)
         }
     }
 
+    /// The end of a selection that is further left (or right) on screen.
+    fn selection_edge(&self, anchor: usize, rightward: bool) -> usize {
+        let (start, end) = self.selection_range(anchor);
+        #[cfg(feature = "bidi")]
+        if let Some(layout) = self.bidi_layout() {
+            let (left, right) = if layout.caret_x(start) <= layout.caret_x(end) {
+                (start, end)
+            } else {
+                (end, start)
+            };
+            return if rightward { right } else { left };
+        }
+        if rightward { end } else { start }
+    }
+
     fn get_grapheme_class(g: &str) -> u8 {
         if g.chars().all(char::is_whitespace) {
             0
@@ -599,6 +752,13 @@ impl TextInput {
             .unwrap_or(self.value.len())
     }
 
+    /// Grapheme index of a byte offset.
+    fn grapheme_index_at_byte(&self, offset: usize) -> usize {
+        self.value[..offset.min(self.value.len())]
+            .graphemes(true)
+            .count()
+    }
+
     fn grapheme_width(&self, g: &str) -> usize {
         if let Some(mask) = self.mask_char {
             let mut buf = [0u8; 4];
@@ -620,10 +780,22 @@ impl TextInput {
             .unwrap_or(0)
     }
 
+    /// Bidi layout of the value, or `None` when it displays in logical order
+    /// (no right-to-left text, or masked).
+    #[cfg(feature = "bidi")]
+    fn bidi_layout(&self) -> Option<BidiLine> {
+        (self.mask_char.is_none() && bidi::has_rtl(&self.value))
+            .then(|| BidiLine::new(&self.value, ParagraphDirection::Auto))
+    }
+
     fn cursor_visual_pos(&self) -> usize {
         if self.value.is_empty() {
             return 0;
         }
+        #[cfg(feature = "bidi")]
+        if let Some(layout) = self.bidi_layout() {
+            return layout.caret_x(self.cursor);
+        }
         self.value
             .graphemes(true)
             .take(self.cursor)
@@ -678,6 +850,8 @@ impl Widget for TextInput {
         }
 
         let graphemes: Vec<&str> = self.value.graphemes(true).collect();
+        #[cfg(feature = "bidi")]
+        let layout = self.bidi_layout();
         let show_placeholder = self.value.is_empty() && !self.placeholder.is_empty();
 
         let viewport_width = area.width as usize;
@@ -741,8 +915,22 @@ impl Widget for TextInput {
                 visual_x += w;
             }
         } else {
-            for (gi, g) in graphemes.iter().enumerate() {
-                let w = self.grapheme_width(g);
+            #[cfg(feature = "bidi")]
+            let visual_order: Vec<usize> = match &layout {
+                Some(layout) => layout.visual_order().to_vec(),
+                None => (0..graphemes.len()).collect(),
+            };
+            #[cfg(not(feature = "bidi"))]
+            let visual_order = 0..graphemes.len();
+            for gi in visual_order {
+                #[cfg(feature = "bidi")]
+                let g = match &layout {
+                    Some(layout) => layout.display_grapheme(gi),
+                    None => Cow::Borrowed(graphemes[gi]),
+                };
+                #[cfg(not(feature = "bidi"))]
+                let g = Cow::Borrowed(graphemes[gi]);
+                let w = self.grapheme_width(&g);
                 if w == 0 {
                     continue;
                 }
@@ -782,7 +970,7 @@ impl Widget for TextInput {
                 let mut cell = if let Some(mask) = self.mask_char {
                     Cell::from_char(mask)
                 } else if g.chars().count() > 1 || w > 1 {
-                    let id = frame.intern_with_width(g, w as u8);
+                    let id = frame.intern_with_width(&g, w as u8);
                     Cell::new(CellContent::from_grapheme(id))
                 } else {
                     Cell::from_char(g.chars().next().unwrap_or(' '))
@@ -794,12 +982,47 @@ impl Widget for TextInput {
                     .set(area.x.saturating_add(rel_x as u16), y, cell);
                 visual_x += w;
             }
+
+            // Completion ghost text after a cursor at the end.
+            if self.focused
+                && self.mask_char.is_none()
+                && self.cursor == graphemes.len()
+                && let Some(completion) = &self.completion
+                && let Some(ghost) = completion.ghost_text()
+            {
+                let rel_x = cursor_visual_pos.saturating_sub(effective_scroll);
+                if rel_x < viewport_width {
+                    let style = if deg.apply_styling() {
+                        completion.ghost_style().merge(&self.style)
+                    } else {
+                        Style::default()
+                    };
+                    crate::draw_text_span(
+                        frame,
+                        area.x.saturating_add(rel_x as u16),
+                        y,
+                        ghost,
+                        style,
+                        area.right(),
+                    );
+                }
+            }
         }
 
         if self.focused {
-            // Set cursor style at cursor position
-            let cursor_rel_x = cursor_visual_pos.saturating_sub(effective_scroll);
-            if cursor_rel_x < viewport_width {
+            // Set cursor style at cursor position. Inside a right-to-left run
+            // the caret sits on the grapheme's right edge, but the block
+            // cursor still covers the grapheme itself.
+            #[cfg(feature = "bidi")]
+            let cursor_cell_x = match &layout {
+                Some(layout) if self.cursor < layout.len() => layout.cell_x(self.cursor),
+                _ => cursor_visual_pos,
+            };
+            #[cfg(not(feature = "bidi"))]
+            let cursor_cell_x = cursor_visual_pos;
+            if let Some(cursor_rel_x) = cursor_cell_x.checked_sub(effective_scroll)
+                && cursor_rel_x < viewport_width
+            {
                 let cursor_screen_x = area.x.saturating_add(cursor_rel_x as u16);
                 if let Some(cell) = frame.buffer.get_mut(cursor_screen_x, y) {
                     if !deg.apply_styling() {
@@ -830,6 +1053,37 @@ impl Widget for TextInput {
     }
 }
 
+impl SheetStyled for TextInput {
+    const TYPE_NAME: &'static str = "TextInput";
+
+    fn styled_by(mut self, sheet: &SelectorSheet, path: &[StyleNode<'_>]) -> Self {
+        let state = if self.focused {
+            WidgetState::FOCUS
+        } else {
+            WidgetState::NONE
+        };
+        let mut path = path.to_vec();
+        if let Some(node) = path.last_mut() {
+            node.state = node.state.union(state);
+        }
+        self.style = self.style.merge(&sheet.resolve(&path));
+        self.cursor_style =
+            self.cursor_style
+                .merge(&sheet::part_style(sheet, &path, StyleNode::new("Cursor")));
+        self.placeholder_style = self.placeholder_style.merge(&sheet::part_style(
+            sheet,
+            &path,
+            StyleNode::new("Placeholder"),
+        ));
+        self.selection_style = self.selection_style.merge(&sheet::part_style(
+            sheet,
+            &path,
+            StyleNode::new("Selection"),
+        ));
+        self
+    }
+}
+
 // ============================================================================
 // Undo Support Implementation
 // ============================================================================
@@ -959,6 +1213,234 @@ impl TextInput {
     }
 }
 
+/// Edits made while a keymap is installed, as [`TextEditOperation`]s, so the
+/// keymap's undo and redo commands work on a [`TextInput`].
+#[derive(Debug, Clone, Default)]
+struct KeymapEdits {
+    undo: Vec<Vec<TextEditOperation>>,
+    redo: Vec<Vec<TextEditOperation>>,
+    /// Operations of the open edit group.
+    group: Vec<TextEditOperation>,
+    /// Nesting depth of open edit groups.
+    depth: usize,
+}
+
+impl KeymapEdits {
+    fn record(&mut self, op: TextEditOperation) {
+        self.redo.clear();
+        if self.depth > 0 {
+            self.group.push(op);
+        } else {
+            self.undo.push(vec![op]);
+        }
+    }
+}
+
+impl TextInput {
+    /// Run a default binding while a keymap is installed, recording any
+    /// change it makes for the keymap's undo.
+    fn record_keymap_edit(&mut self, edit: impl FnOnce(&mut Self) -> bool) -> bool {
+        if self.keymap.is_none() {
+            return edit(self);
+        }
+        let before = self.value.clone();
+        let changed = edit(self);
+        if let Some(op) = text_edit_between(&before, &self.value) {
+            self.keymap_edits.record(op);
+        }
+        changed
+    }
+
+    /// Apply `op`, or revert it when `reverse` is set. Returns the byte
+    /// offset after the text it put in place, or `None` if the value no
+    /// longer matches the operation.
+    fn apply_text_edit(&mut self, op: &TextEditOperation, reverse: bool) -> Option<usize> {
+        let (position, old, new) = match op {
+            TextEditOperation::Insert { position, text } => (*position, "", text.as_str()),
+            TextEditOperation::Delete {
+                position,
+                deleted_text,
+            } => (*position, deleted_text.as_str(), ""),
+            TextEditOperation::Replace {
+                position,
+                old_text,
+                new_text,
+            } => (*position, old_text.as_str(), new_text.as_str()),
+            TextEditOperation::SetValue {
+                old_value,
+                new_value,
+            } => (0, old_value.as_str(), new_value.as_str()),
+        };
+        let (remove, insert) = if reverse { (new, old) } else { (old, new) };
+        let at = self.grapheme_byte_offset(position);
+        if !self.value[at..].starts_with(remove) {
+            return None;
+        }
+        self.value.replace_range(at..at + remove.len(), insert);
+        self.selection_anchor = None;
+        Some(at + insert.len())
+    }
+}
+
+/// The edit turning `before` into `after`, positioned by grapheme index.
+fn text_edit_between(before: &str, after: &str) -> Option<TextEditOperation> {
+    if before == after {
+        return None;
+    }
+    let (mut position, mut prefix) = (0, 0);
+    for (a, b) in before.graphemes(true).zip(after.graphemes(true)) {
+        if a != b {
+            break;
+        }
+        position += 1;
+        prefix += a.len();
+    }
+    let (old_rest, new_rest) = (&before[prefix..], &after[prefix..]);
+    let suffix: usize = old_rest
+        .graphemes(true)
+        .rev()
+        .zip(new_rest.graphemes(true).rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(a, _)| a.len())
+        .sum();
+    let old_text = old_rest[..old_rest.len() - suffix].to_string();
+    let new_text = new_rest[..new_rest.len() - suffix].to_string();
+    Some(text_edit(position, old_text, new_text))
+}
+
+/// The operation replacing `old_text` with `new_text` at grapheme `position`.
+fn text_edit(position: usize, old_text: String, new_text: String) -> TextEditOperation {
+    if old_text.is_empty() {
+        TextEditOperation::Insert {
+            position,
+            text: new_text,
+        }
+    } else if new_text.is_empty() {
+        TextEditOperation::Delete {
+            position,
+            deleted_text: old_text,
+        }
+    } else {
+        TextEditOperation::Replace {
+            position,
+            old_text,
+            new_text,
+        }
+    }
+}
+
+/// Single-line target: line breaks are dropped and edits that would exceed
+/// `max_length` are ignored. Keymap edits, and default bindings used while a
+/// keymap is installed, are recorded as [`TextEditOperation`]s for the
+/// keymap's undo and redo; the external [`UndoSupport`] history is separate.
+impl KeymapTarget for TextInput {
+    fn text(&self) -> Cow<'_, str> {
+        Cow::Borrowed(&self.value)
+    }
+
+    fn cursor_offset(&self) -> usize {
+        self.grapheme_byte_offset(self.cursor)
+    }
+
+    fn selection_offsets(&self) -> Option<(usize, usize)> {
+        let anchor = self.selection_anchor.filter(|&a| a != self.cursor)?;
+        Some((
+            self.grapheme_byte_offset(anchor),
+            self.grapheme_byte_offset(self.cursor),
+        ))
+    }
+
+    fn set_cursor_offset(&mut self, offset: usize) {
+        self.cursor = self.grapheme_index_at_byte(offset);
+        self.selection_anchor = None;
+    }
+
+    fn select_offsets(&mut self, anchor: usize, head: usize) {
+        self.selection_anchor = Some(self.grapheme_index_at_byte(anchor));
+        self.cursor = self.grapheme_index_at_byte(head);
+    }
+
+    fn replace_offsets(&mut self, start: usize, end: usize, text: &str) {
+        let end = end.min(self.value.len());
+        let start = start.min(end);
+        let clean: String = text.chars().filter(|c| !c.is_control()).collect();
+        if let Some(max) = self.max_length
+            && !clean.is_empty()
+            && self.value[..start].graphemes(true).count()
+                + clean.graphemes(true).count()
+                + self.value[end..].graphemes(true).count()
+                > max
+        {
+            return;
+        }
+        self.selection_anchor = None;
+        if start == end && clean.is_empty() {
+            self.cursor = self.grapheme_index_at_byte(start);
+            return;
+        }
+        let op = text_edit(
+            self.grapheme_index_at_byte(start),
+            self.value[start..end].to_string(),
+            clean,
+        );
+        if let Some(after) = self.apply_text_edit(&op, false) {
+            self.cursor = self.grapheme_index_at_byte(after);
+            self.keymap_edits.record(op);
+        }
+    }
+
+    fn insert_newline(&mut self) {}
+
+    fn undo(&mut self) -> bool {
+        let Some(ops) = self.keymap_edits.undo.pop() else {
+            return false;
+        };
+        for op in ops.iter().rev() {
+            self.apply_text_edit(op, true);
+        }
+        if let Some(first) = ops.first() {
+            let position = match first {
+                TextEditOperation::Insert { position, .. }
+                | TextEditOperation::Delete { position, .. }
+                | TextEditOperation::Replace { position, .. } => *position,
+                TextEditOperation::SetValue { .. } => 0,
+            };
+            self.cursor = position.min(self.grapheme_count());
+        }
+        self.keymap_edits.redo.push(ops);
+        true
+    }
+
+    fn redo(&mut self) -> bool {
+        let Some(ops) = self.keymap_edits.redo.pop() else {
+            return false;
+        };
+        for op in &ops {
+            if let Some(after) = self.apply_text_edit(op, false) {
+                self.cursor = self.grapheme_index_at_byte(after);
+            }
+        }
+        self.keymap_edits.undo.push(ops);
+        true
+    }
+
+    fn begin_edit_group(&mut self) {
+        self.keymap_edits.depth += 1;
+    }
+
+    fn end_edit_group(&mut self) {
+        let edits = &mut self.keymap_edits;
+        if edits.depth == 0 {
+            return;
+        }
+        edits.depth -= 1;
+        if edits.depth == 0 && !edits.group.is_empty() {
+            let group = std::mem::take(&mut edits.group);
+            edits.undo.push(group);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1423,6 +1905,67 @@ mod tests {
         assert_eq!(frame.cursor_position, Some((2, 0)));
     }
 
+    #[test]
+    #[cfg(feature = "bidi")]
+    fn test_render_rtl_in_visual_order() {
+        use ftui_render::frame::Frame;
+        use ftui_render::grapheme_pool::GraphemePool;
+
+        // "ab " followed by Hebrew alef-bet-gimel, cursor at the logical end.
+        let input = TextInput::new()
+            .with_value("ab \u{05D0}\u{05D1}\u{05D2}")
+            .with_focused(true);
+        let area = Rect::new(0, 0, 10, 1);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(10, 1, &mut pool);
+        input.render(area, &mut frame);
+
+        let row: String = (0..6)
+            .filter_map(|x| cell_at(&frame, x, 0).content.as_char())
+            .collect();
+        assert_eq!(row, "ab \u{05D2}\u{05D1}\u{05D0}");
+        // The logical end of an RTL run is its visual left edge.
+        assert_eq!(frame.cursor_position, Some((3, 0)));
+    }
+
+    #[test]
+    #[cfg(feature = "bidi")]
+    fn test_arrows_move_visually_through_rtl() {
+        let mut input = TextInput::new().with_value("ab \u{05D0}\u{05D1}\u{05D2}");
+        input.cursor = 2;
+        let mut path = Vec::new();
+        for _ in 0..5 {
+            input.move_cursor_right();
+            path.push(input.cursor());
+        }
+        assert_eq!(path, vec![6, 5, 4, 3, 3]);
+        input.move_cursor_left();
+        assert_eq!(input.cursor(), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "bidi")]
+    fn test_rtl_selection_stays_logical() {
+        let mut input = TextInput::new().with_value("\u{05D0}\u{05D1}\u{05D2}");
+        input.cursor = 0;
+        // In an RTL value the start is on the right, so Left advances.
+        input.move_cursor_left_select();
+        input.move_cursor_left_select();
+        assert_eq!(input.selected_text(), Some("\u{05D0}\u{05D1}"));
+        // Collapsing left lands on the visually leftmost end.
+        input.move_cursor_left();
+        assert_eq!(input.cursor(), 2);
+    }
+
+    #[test]
+    fn test_masked_rtl_stays_logical() {
+        let mut input = TextInput::new()
+            .with_value("\u{05D0}\u{05D1}")
+            .with_mask('*');
+        input.move_cursor_left();
+        assert_eq!(input.cursor(), 1);
+    }
+
     // ========================================================================
     // Undo Support Tests
     // ========================================================================
@@ -1577,4 +2120,146 @@ mod tests {
         // If bug exists, this assertion will fail because cell is empty/default
         assert!(!cell.is_empty(), "Wide char should be visible");
     }
+
+    #[test]
+    fn test_vim_keymap() {
+        use crate::keymap::VimKeymap;
+        let mut input = TextInput::new()
+            .with_value("héllo world")
+            .with_keymap(VimKeymap::new());
+        let press = |input: &mut TextInput, c: char| {
+            input.handle_event(&Event::Key(KeyEvent::new(KeyCode::Char(c))))
+        };
+        for c in "0wdw".chars() {
+            press(&mut input, c);
+        }
+        assert_eq!(input.value(), "héllo ");
+        for c in "bcwhi".chars() {
+            press(&mut input, c);
+        }
+        assert_eq!(input.keymap_mode(), Some(KeymapMode::Insert));
+        assert_eq!(input.value(), "hi ");
+        assert_eq!(input.cursor(), 2);
+    }
+
+    #[test]
+    fn test_keymap_respects_max_length() {
+        use crate::keymap::VimKeymap;
+        let mut input = TextInput::new()
+            .with_value("abc")
+            .with_max_length(3)
+            .with_keymap(VimKeymap::new());
+        for c in "0ylp".chars() {
+            input.handle_event(&Event::Key(KeyEvent::new(KeyCode::Char(c))));
+        }
+        assert_eq!(input.value(), "abc");
+    }
+
+    #[test]
+    fn test_vim_undo_and_redo() {
+        use crate::keymap::VimKeymap;
+        let mut input = TextInput::new()
+            .with_value("one two three")
+            .with_keymap(VimKeymap::new());
+        let press = |input: &mut TextInput, code: KeyCode, mods: Modifiers| {
+            input.handle_event(&Event::Key(KeyEvent::new(code).with_modifiers(mods)));
+        };
+        for c in "0wcwfour".chars() {
+            press(&mut input, KeyCode::Char(c), Modifiers::NONE);
+        }
+        press(&mut input, KeyCode::Escape, Modifiers::NONE);
+        for c in "wdw".chars() {
+            press(&mut input, KeyCode::Char(c), Modifiers::NONE);
+        }
+        assert_eq!(input.value(), "one four ");
+
+        press(&mut input, KeyCode::Char('u'), Modifiers::NONE);
+        assert_eq!(input.value(), "one four three");
+        // The change and the text typed after it are one step.
+        press(&mut input, KeyCode::Char('u'), Modifiers::NONE);
+        assert_eq!(input.value(), "one two three");
+        assert_eq!(input.cursor(), 4);
+        press(&mut input, KeyCode::Char('r'), Modifiers::CTRL);
+        assert_eq!(input.value(), "one four three");
+    }
+
+    #[test]
+    fn test_emacs_undo_covers_default_bindings() {
+        use crate::keymap::EmacsKeymap;
+        let mut input = TextInput::new()
+            .with_value("abc")
+            .with_keymap(EmacsKeymap::new());
+        // Plain characters fall through to the widget's own insertion.
+        input.handle_event(&Event::Key(KeyEvent::new(KeyCode::Char('d'))));
+        input.handle_event(&Event::Key(
+            KeyEvent::new(KeyCode::Char('a')).with_modifiers(Modifiers::CTRL),
+        ));
+        input.handle_event(&Event::Key(
+            KeyEvent::new(KeyCode::Char('k')).with_modifiers(Modifiers::CTRL),
+        ));
+        assert_eq!(input.value(), "");
+        let undo = Event::Key(KeyEvent::new(KeyCode::Char('/')).with_modifiers(Modifiers::CTRL));
+        input.handle_event(&undo);
+        assert_eq!(input.value(), "abcd");
+        input.handle_event(&undo);
+        assert_eq!(input.value(), "abc");
+    }
+
+    #[test]
+    fn test_completion_ghost_and_accept() {
+        use crate::completion::{Completer, StaticCompletions};
+        use ftui_render::grapheme_pool::GraphemePool;
+        let words = StaticCompletions::new(["status", "stash"]);
+        let mut input = TextInput::new()
+            .with_completion(Completer::new(words))
+            .with_focused(true);
+        for c in "git sta".chars() {
+            input.handle_event(&Event::Key(KeyEvent::new(KeyCode::Char(c))));
+        }
+        assert!(input.completion().unwrap().is_open());
+
+        let area = Rect::new(2, 1, 20, 1);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(24, 4, &mut pool);
+        input.render(area, &mut frame);
+        // "stash" ranks first; its remainder is drawn after the cursor.
+        assert_eq!(cell_at(&frame, 9, 1).content.as_char(), Some('s'));
+        assert_eq!(
+            input
+                .completion_popup(area)
+                .unwrap()
+                .area(Rect::new(0, 0, 24, 4)),
+            Rect::new(5, 2, 8, 2)
+        );
+
+        input.handle_event(&Event::Key(KeyEvent::new(KeyCode::Down)));
+        input.handle_event(&Event::Key(KeyEvent::new(KeyCode::Tab)));
+        assert_eq!(input.value(), "git status");
+        assert_eq!(input.cursor(), 10);
+        assert!(!input.completion().unwrap().is_open());
+    }
+
+    #[test]
+    fn styled_by_adds_focus_state() {
+        use ftui_render::cell::PackedRgba;
+
+        let sheet = SelectorSheet::parse(
+            "TextInput { fg: #808080; } TextInput:focus { fg: #ffffff; } \
+             TextInput:focus > Cursor { bg: #ff00ff; } Placeholder { attrs: dim; }",
+        )
+        .unwrap();
+        let path = [StyleNode::new(TextInput::TYPE_NAME)];
+
+        let idle = TextInput::new().styled_by(&sheet, &path);
+        assert_eq!(idle.style.fg, Some(PackedRgba::rgb(128, 128, 128)));
+        assert_eq!(idle.cursor_style.bg, None);
+        assert!(idle.placeholder_style.has_attr(ftui_style::StyleFlags::DIM));
+
+        let focused = TextInput::new()
+            .with_focused(true)
+            .with_cursor_style(Style::new().bg(PackedRgba::rgb(1, 2, 3)))
+            .styled_by(&sheet, &path);
+        assert_eq!(focused.style.fg, Some(PackedRgba::rgb(255, 255, 255)));
+        assert_eq!(focused.cursor_style.bg, Some(PackedRgba::rgb(1, 2, 3)));
+    }
 }
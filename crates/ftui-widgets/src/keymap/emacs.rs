@@ -0,0 +1,573 @@
+#![forbid(unsafe_code)]
+
+//! Emacs keymap.
+//!
+//! Supported commands:
+//!
+//! - Motion: `C-f C-b C-n C-p C-a C-e M-f M-b M-< M->`.
+//! - Deletion: `C-d M-d M-Backspace`; kills `C-k C-w` and copy `M-w` go to
+//!   the kill ring, yanked back with `C-y` and cycled with `M-y`.
+//! - Mark: `C-Space`/`C-@` sets it, motions then extend the region, `C-g`
+//!   deactivates it, `C-x C-x` exchanges point and mark, `C-x h` marks the
+//!   whole buffer.
+//! - Undo: `C-/`, `C-_` and `C-x u`.
+//!
+//! Consecutive kills append to the same kill-ring entry.
+
+use ftui_core::event::{KeyCode, KeyEvent, Modifiers};
+use ftui_core::key_sequence::{
+    KeySequenceAction, KeySequenceConfig, KeySequenceInterpreter, KeySequenceKind,
+};
+use web_time::Instant;
+
+use super::{
+    Keymap, KeymapMode, KeymapResult, KeymapTarget, is_word_char, line_column, next_boundary,
+    next_grapheme, offset_at_column, prev_boundary, prev_grapheme,
+};
+
+/// `C-x C-x`: exchange point and mark.
+const SEQ_EXCHANGE: u16 = 1;
+/// `C-x u`: undo.
+const SEQ_UNDO: u16 = 2;
+/// `C-x h`: mark the whole buffer.
+const SEQ_MARK_ALL: u16 = 3;
+
+/// Largest number of kill-ring entries kept.
+const KILL_RING_MAX: usize = 60;
+
+/// Previous command, for kill appending and `M-y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LastCommand {
+    Other,
+    Kill,
+    /// Yanked `start..end` from kill-ring entry `index` (from the newest).
+    Yank {
+        start: usize,
+        end: usize,
+        index: usize,
+    },
+}
+
+/// Emacs-style keymap with a mark and kill ring.
+///
+/// Reports [`KeymapMode::Mark`] while the region is active and
+/// [`KeymapMode::Insert`] otherwise. Plain text keys fall through to the
+/// widget.
+#[derive(Debug, Clone)]
+pub struct EmacsKeymap {
+    mark: Option<usize>,
+    mark_active: bool,
+    kill_ring: Vec<String>,
+    last: LastCommand,
+    /// `C-x`-prefixed commands.
+    sequences: KeySequenceInterpreter,
+    /// Column kept across `C-n`/`C-p`.
+    desired_col: Option<usize>,
+}
+
+impl Default for EmacsKeymap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EmacsKeymap {
+    /// Create an emacs keymap with an empty kill ring.
+    #[must_use]
+    pub fn new() -> Self {
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c)).with_modifiers(Modifiers::CTRL);
+        let plain = |c| KeyEvent::new(KeyCode::Char(c));
+        let config = KeySequenceConfig {
+            detect_double_escape: false,
+            ..Default::default()
+        }
+        .with_sequence(SEQ_EXCHANGE, vec![ctrl('x'), ctrl('x')])
+        .with_sequence(SEQ_UNDO, vec![ctrl('x'), plain('u')])
+        .with_sequence(SEQ_MARK_ALL, vec![ctrl('x'), plain('h')]);
+        Self {
+            mark: None,
+            mark_active: false,
+            kill_ring: Vec::new(),
+            last: LastCommand::Other,
+            sequences: KeySequenceInterpreter::new(config),
+            desired_col: None,
+        }
+    }
+
+    /// Kill-ring entries, newest last.
+    #[must_use]
+    pub fn kill_ring(&self) -> &[String] {
+        &self.kill_ring
+    }
+
+    /// Mark byte offset, if set.
+    #[must_use]
+    pub fn mark(&self) -> Option<usize> {
+        self.mark
+    }
+
+    fn set_mark(&mut self, at: usize, t: &mut dyn KeymapTarget) {
+        self.mark = Some(at);
+        self.mark_active = true;
+        t.set_cursor_offset(at);
+    }
+
+    fn deactivate_mark(&mut self, t: &mut dyn KeymapTarget) {
+        if self.mark_active {
+            self.mark_active = false;
+            let at = t.cursor_offset();
+            t.set_cursor_offset(at);
+        }
+    }
+
+    /// Move point, extending the region while the mark is active.
+    fn move_to(&mut self, at: usize, t: &mut dyn KeymapTarget) {
+        match self.mark {
+            Some(mark) if self.mark_active => t.select_offsets(mark, at),
+            _ => t.set_cursor_offset(at),
+        }
+    }
+
+    /// Active region as `(start, end)`.
+    fn region(&self, t: &dyn KeymapTarget) -> Option<(usize, usize)> {
+        let mark = self.mark.filter(|_| self.mark_active)?;
+        let point = t.cursor_offset();
+        Some((mark.min(point), mark.max(point)))
+    }
+
+    /// Add killed text to the ring, appending or prepending to the newest
+    /// entry after another kill.
+    fn push_kill(&mut self, text: &str, prepend: bool) {
+        if text.is_empty() {
+            return;
+        }
+        match self.kill_ring.last_mut() {
+            Some(last) if self.last == LastCommand::Kill => {
+                if prepend {
+                    last.insert_str(0, text);
+                } else {
+                    last.push_str(text);
+                }
+            }
+            _ => {
+                self.kill_ring.push(text.to_string());
+                if self.kill_ring.len() > KILL_RING_MAX {
+                    self.kill_ring.remove(0);
+                }
+            }
+        }
+    }
+
+    /// Kill `start..end`; `prepend` for backward kills.
+    fn kill(&mut self, start: usize, end: usize, prepend: bool, t: &mut dyn KeymapTarget) {
+        let killed = t.slice(start..end).into_owned();
+        self.push_kill(&killed, prepend);
+        self.mark_active = false;
+        t.replace_offsets(start, end, "");
+        self.last = LastCommand::Kill;
+    }
+
+    fn yank(&mut self, t: &mut dyn KeymapTarget) {
+        let Some(entry) = self.kill_ring.last().cloned() else {
+            return;
+        };
+        let start = t.cursor_offset();
+        self.mark = Some(start);
+        self.mark_active = false;
+        t.replace_offsets(start, start, &entry);
+        self.last = LastCommand::Yank {
+            start,
+            end: start + entry.len(),
+            index: 0,
+        };
+    }
+
+    fn yank_pop(&mut self, t: &mut dyn KeymapTarget) {
+        let LastCommand::Yank { start, end, index } = self.last else {
+            return;
+        };
+        let len = self.kill_ring.len();
+        let index = (index + 1) % len;
+        let entry = self.kill_ring[len - 1 - index].clone();
+        t.replace_offsets(start, end, &entry);
+        self.last = LastCommand::Yank {
+            start,
+            end: start + entry.len(),
+            index,
+        };
+    }
+
+    fn sequence(&mut self, id: u16, t: &mut dyn KeymapTarget) {
+        match id {
+            SEQ_EXCHANGE => {
+                if let Some(mark) = self.mark {
+                    let point = t.cursor_offset();
+                    self.mark = Some(point);
+                    self.mark_active = true;
+                    t.select_offsets(point, mark);
+                }
+            }
+            SEQ_UNDO => {
+                self.mark_active = false;
+                t.undo();
+            }
+            _ => {
+                let len = t.len();
+                self.mark = Some(0);
+                self.mark_active = true;
+                t.select_offsets(0, len);
+            }
+        }
+    }
+
+    fn ctrl_key(&mut self, c: char, at: usize, t: &mut dyn KeymapTarget) -> bool {
+        if !matches!(c, 'n' | 'p') {
+            self.desired_col = None;
+        }
+        match c {
+            'f' => self.move_to(next_grapheme(t, at), t),
+            'b' => self.move_to(prev_grapheme(t, at), t),
+            'a' => self.move_to(t.line_bounds(at).start, t),
+            'e' => self.move_to(t.line_bounds(at).end, t),
+            'n' | 'p' => {
+                let line = t.line_bounds(at);
+                let target = if c == 'n' {
+                    if line.end == t.len() {
+                        self.move_to(line.end, t);
+                        return true;
+                    }
+                    t.line_bounds(line.end + 1)
+                } else if line.start == 0 {
+                    self.move_to(0, t);
+                    return true;
+                } else {
+                    t.line_bounds(line.start - 1)
+                };
+                let col = *self.desired_col.get_or_insert_with(|| line_column(t, at));
+                let offset = offset_at_column(&t.slice(target.clone()), 0, col);
+                self.move_to(target.start + offset, t);
+            }
+            'd' => {
+                self.mark_active = false;
+                if at < t.len() {
+                    let end = next_grapheme(t, at);
+                    t.replace_offsets(at, end, "");
+                }
+            }
+            'k' => {
+                let end = t.line_bounds(at).end;
+                let end = if end == at && at < t.len() {
+                    at + 1
+                } else {
+                    end
+                };
+                self.kill(at, end, false, t);
+                return true;
+            }
+            'w' => {
+                if let Some((start, end)) = self.region(t) {
+                    self.kill(start, end, false, t);
+                    return true;
+                }
+            }
+            'y' => {
+                self.yank(t);
+                return true;
+            }
+            ' ' | '@' => self.set_mark(at, t),
+            'g' => {
+                self.mark_active = false;
+                t.set_cursor_offset(at);
+            }
+            '/' | '_' => {
+                self.mark_active = false;
+                t.undo();
+            }
+            _ => return false,
+        }
+        self.last = LastCommand::Other;
+        true
+    }
+
+    fn alt_key(&mut self, code: KeyCode, at: usize, t: &mut dyn KeymapTarget) -> bool {
+        self.desired_col = None;
+        match code {
+            KeyCode::Char('f') => self.move_to(word_forward(&t.text(), at), t),
+            KeyCode::Char('b') => self.move_to(word_backward(&t.text(), at), t),
+            KeyCode::Char('<') => self.move_to(0, t),
+            KeyCode::Char('>') => self.move_to(t.len(), t),
+            KeyCode::Char('d') => {
+                let end = word_forward(&t.text(), at);
+                self.kill(at, end, false, t);
+                return true;
+            }
+            KeyCode::Backspace => {
+                let start = word_backward(&t.text(), at);
+                self.kill(start, at, true, t);
+                return true;
+            }
+            KeyCode::Char('w') => {
+                if let Some((start, end)) = self.region(t) {
+                    let copied = t.slice(start..end).into_owned();
+                    self.last = LastCommand::Other;
+                    self.push_kill(&copied, false);
+                    self.deactivate_mark(t);
+                }
+            }
+            KeyCode::Char('y') => {
+                self.yank_pop(t);
+                return true;
+            }
+            _ => return false,
+        }
+        self.last = LastCommand::Other;
+        true
+    }
+}
+
+impl Keymap for EmacsKeymap {
+    fn handle_key(&mut self, key: &KeyEvent, target: &mut dyn KeymapTarget) -> KeymapResult {
+        let mut key = *key;
+        if matches!(key.code, KeyCode::Char(_)) {
+            key.modifiers.remove(Modifiers::SHIFT);
+        }
+        let was_pending = self.sequences.has_pending();
+        match self.sequences.feed(&key, Instant::now()) {
+            KeySequenceAction::Pending => return KeymapResult::Handled,
+            KeySequenceAction::EmitSequence {
+                kind: KeySequenceKind::Custom(id),
+                ..
+            } => {
+                self.sequence(id, target);
+                self.last = LastCommand::Other;
+                return KeymapResult::Handled;
+            }
+            // An unbound key after `C-x` is swallowed.
+            _ if was_pending => return KeymapResult::Handled,
+            _ => {}
+        }
+
+        let at = target.cursor_offset();
+        let ctrl = key.modifiers.contains(Modifiers::CTRL);
+        let alt = key.modifiers.contains(Modifiers::ALT);
+        let handled = match key.code {
+            KeyCode::Char(c) if ctrl && !alt => self.ctrl_key(c, at, target),
+            code if alt && !ctrl => self.alt_key(code, at, target),
+            _ => false,
+        };
+        if handled {
+            return KeymapResult::Handled;
+        }
+        // Any other key ends the region and breaks kill/yank chains.
+        self.last = LastCommand::Other;
+        self.desired_col = None;
+        self.deactivate_mark(target);
+        KeymapResult::Unhandled
+    }
+
+    fn mode(&self) -> KeymapMode {
+        if self.mark_active {
+            KeymapMode::Mark
+        } else {
+            KeymapMode::Insert
+        }
+    }
+
+    fn pending_keys(&self) -> String {
+        if self.sequences.has_pending() {
+            "C-x".to_string()
+        } else {
+            String::new()
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Keymap> {
+        Box::new(self.clone())
+    }
+}
+
+/// `M-f`: end of the next word.
+fn word_forward(text: &str, at: usize) -> usize {
+    let is_word = |p: usize| text[p..].chars().next().is_some_and(is_word_char);
+    let mut p = at;
+    while p < text.len() && !is_word(p) {
+        p = next_boundary(text, p);
+    }
+    while p < text.len() && is_word(p) {
+        p = next_boundary(text, p);
+    }
+    p
+}
+
+/// `M-b`: start of the previous word.
+fn word_backward(text: &str, at: usize) -> usize {
+    let is_word = |p: usize| text[..p].chars().next_back().is_some_and(is_word_char);
+    let mut p = at;
+    while p > 0 && !is_word(p) {
+        p = prev_boundary(text, p);
+    }
+    while p > 0 && is_word(p) {
+        p = prev_boundary(text, p);
+    }
+    p
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_text::editor::Editor;
+
+    fn ctrl(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c)).with_modifiers(Modifiers::CTRL)
+    }
+
+    fn alt(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code).with_modifiers(Modifiers::ALT)
+    }
+
+    fn setup(text: &str) -> (EmacsKeymap, Editor) {
+        let mut ed = Editor::with_text(text);
+        ed.move_to_document_start();
+        (EmacsKeymap::new(), ed)
+    }
+
+    fn press(emacs: &mut EmacsKeymap, ed: &mut Editor, keys: &[KeyEvent]) {
+        for key in keys {
+            emacs.handle_key(key, ed);
+        }
+    }
+
+    fn offset(ed: &Editor) -> usize {
+        KeymapTarget::cursor_offset(ed)
+    }
+
+    #[test]
+    fn motions() {
+        let (mut emacs, mut ed) = setup("foo bar\nbaz");
+        press(&mut emacs, &mut ed, &[ctrl('e')]);
+        assert_eq!(offset(&ed), 7);
+        press(&mut emacs, &mut ed, &[ctrl('a'), alt(KeyCode::Char('f'))]);
+        assert_eq!(offset(&ed), 3);
+        press(&mut emacs, &mut ed, &[alt(KeyCode::Char('f'))]);
+        assert_eq!(offset(&ed), 7);
+        press(&mut emacs, &mut ed, &[alt(KeyCode::Char('b'))]);
+        assert_eq!(offset(&ed), 4);
+        press(&mut emacs, &mut ed, &[ctrl('n')]);
+        assert_eq!(offset(&ed), 11);
+        press(
+            &mut emacs,
+            &mut ed,
+            &[ctrl('p'), ctrl('b'), ctrl('f'), ctrl('f')],
+        );
+        assert_eq!(offset(&ed), 5);
+        press(&mut emacs, &mut ed, &[alt(KeyCode::Char('>'))]);
+        assert_eq!(offset(&ed), 11);
+        press(&mut emacs, &mut ed, &[alt(KeyCode::Char('<'))]);
+        assert_eq!(offset(&ed), 0);
+    }
+
+    #[test]
+    fn consecutive_kills_append() {
+        let (mut emacs, mut ed) = setup("one\ntwo\nthree");
+        press(&mut emacs, &mut ed, &[ctrl('k'), ctrl('k'), ctrl('k')]);
+        assert_eq!(Editor::text(&ed), "\nthree");
+        assert_eq!(emacs.kill_ring(), ["one\ntwo"]);
+        press(&mut emacs, &mut ed, &[ctrl('e'), ctrl('y')]);
+        assert_eq!(Editor::text(&ed), "one\ntwo\nthree");
+    }
+
+    #[test]
+    fn backward_kill_prepends() {
+        let (mut emacs, mut ed) = setup("foo bar baz");
+        press(&mut emacs, &mut ed, &[ctrl('e')]);
+        press(
+            &mut emacs,
+            &mut ed,
+            &[alt(KeyCode::Backspace), alt(KeyCode::Backspace)],
+        );
+        assert_eq!(Editor::text(&ed), "foo ");
+        assert_eq!(emacs.kill_ring(), ["bar baz"]);
+    }
+
+    #[test]
+    fn yank_pop_cycles_ring() {
+        let (mut emacs, mut ed) = setup("a b");
+        press(&mut emacs, &mut ed, &[alt(KeyCode::Char('d')), ctrl('f')]);
+        press(&mut emacs, &mut ed, &[alt(KeyCode::Char('d'))]);
+        assert_eq!(Editor::text(&ed), " ");
+        assert_eq!(emacs.kill_ring(), ["a", "b"]);
+        press(&mut emacs, &mut ed, &[ctrl('y')]);
+        assert_eq!(Editor::text(&ed), " b");
+        press(&mut emacs, &mut ed, &[alt(KeyCode::Char('y'))]);
+        assert_eq!(Editor::text(&ed), " a");
+        press(&mut emacs, &mut ed, &[alt(KeyCode::Char('y'))]);
+        assert_eq!(Editor::text(&ed), " b");
+    }
+
+    #[test]
+    fn mark_region_kill_and_copy() {
+        let (mut emacs, mut ed) = setup("hello world");
+        press(&mut emacs, &mut ed, &[ctrl(' ')]);
+        assert_eq!(emacs.mode(), KeymapMode::Mark);
+        press(&mut emacs, &mut ed, &[alt(KeyCode::Char('f'))]);
+        assert_eq!(ed.selected_text().as_deref(), Some("hello"));
+        press(&mut emacs, &mut ed, &[alt(KeyCode::Char('w'))]);
+        assert_eq!(emacs.mode(), KeymapMode::Insert);
+        assert!(ed.selection().is_none());
+        assert_eq!(emacs.kill_ring(), ["hello"]);
+        press(&mut emacs, &mut ed, &[ctrl('@'), ctrl('e'), ctrl('w')]);
+        assert_eq!(Editor::text(&ed), "hello");
+        assert_eq!(emacs.kill_ring(), ["hello", " world"]);
+    }
+
+    #[test]
+    fn exchange_point_and_mark() {
+        let (mut emacs, mut ed) = setup("abcdef");
+        press(&mut emacs, &mut ed, &[ctrl(' '), ctrl('f'), ctrl('f')]);
+        press(&mut emacs, &mut ed, &[ctrl('x'), ctrl('x')]);
+        assert_eq!(offset(&ed), 0);
+        assert_eq!(emacs.mark(), Some(2));
+        assert_eq!(ed.selected_text().as_deref(), Some("ab"));
+    }
+
+    #[test]
+    fn prefix_commands() {
+        let (mut emacs, mut ed) = setup("abc");
+        press(&mut emacs, &mut ed, &[ctrl('x')]);
+        assert_eq!(emacs.pending_keys(), "C-x");
+        press(&mut emacs, &mut ed, &[KeyEvent::new(KeyCode::Char('h'))]);
+        assert_eq!(ed.selected_text().as_deref(), Some("abc"));
+        press(&mut emacs, &mut ed, &[ctrl('w')]);
+        assert_eq!(Editor::text(&ed), "");
+        press(
+            &mut emacs,
+            &mut ed,
+            &[ctrl('x'), KeyEvent::new(KeyCode::Char('u'))],
+        );
+        assert_eq!(Editor::text(&ed), "abc");
+        let result = emacs.handle_key(&ctrl('x'), &mut ed);
+        assert_eq!(result, KeymapResult::Handled);
+        let result = emacs.handle_key(&KeyEvent::new(KeyCode::Char('q')), &mut ed);
+        assert_eq!(result, KeymapResult::Handled);
+        assert_eq!(Editor::text(&ed), "abc");
+    }
+
+    #[test]
+    fn unbound_keys_fall_through_and_clear_mark() {
+        let (mut emacs, mut ed) = setup("abc");
+        press(&mut emacs, &mut ed, &[ctrl(' '), ctrl('f')]);
+        let result = emacs.handle_key(&KeyEvent::new(KeyCode::Char('x')), &mut ed);
+        assert_eq!(result, KeymapResult::Unhandled);
+        assert_eq!(emacs.mode(), KeymapMode::Insert);
+        assert!(ed.selection().is_none());
+    }
+
+    #[test]
+    fn delete_and_undo() {
+        let (mut emacs, mut ed) = setup("abc");
+        press(&mut emacs, &mut ed, &[ctrl('d')]);
+        assert_eq!(Editor::text(&ed), "bc");
+        press(&mut emacs, &mut ed, &[ctrl('/')]);
+        assert_eq!(Editor::text(&ed), "abc");
+    }
+}
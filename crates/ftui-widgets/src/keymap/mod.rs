@@ -0,0 +1,437 @@
+#![forbid(unsafe_code)]
+
+//! Pluggable editing keymaps for [`TextArea`] and [`TextInput`].
+//!
+//! A [`Keymap`] sees key presses before the widget's built-in bindings and
+//! drives the widget through [`KeymapTarget`], a byte-offset view of the
+//! buffer backed by [`Editor`] operations. Keys a keymap leaves
+//! [`Unhandled`](KeymapResult::Unhandled) fall through to the widget's
+//! default bindings.
+//!
+//! Two keymaps are provided:
+//!
+//! - [`VimKeymap`]: modal editing with normal, insert, visual and visual-line
+//!   modes, motions, operators, counts, registers, text objects and `.` repeat.
+//! - [`EmacsKeymap`]: point/mark editing with a kill ring.
+//!
+//! Prefix keys (vim's `g`, emacs' `C-x`) go through
+//! [`KeySequenceInterpreter`](ftui_core::key_sequence::KeySequenceInterpreter).
+//! The active [`KeymapMode`] is exposed for status-line display.
+//!
+//! # Example
+//! ```
+//! use ftui_core::event::{Event, KeyCode, KeyEvent};
+//! use ftui_widgets::keymap::{KeymapMode, VimKeymap};
+//! use ftui_widgets::textarea::TextArea;
+//!
+//! let mut ta = TextArea::new()
+//!     .with_text("hello world")
+//!     .with_keymap(VimKeymap::new());
+//! assert_eq!(ta.keymap_mode(), Some(KeymapMode::Normal));
+//!
+//! for c in "dw".chars() {
+//!     ta.handle_event(&Event::Key(KeyEvent::new(KeyCode::Char(c))));
+//! }
+//! assert_eq!(ta.text(), "world");
+//! ```
+//!
+//! [`TextArea`]: crate::textarea::TextArea
+//! [`TextInput`]: crate::input::TextInput
+
+mod emacs;
+mod vim;
+
+pub use emacs::EmacsKeymap;
+pub use vim::VimKeymap;
+
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Range;
+
+use ftui_core::event::KeyEvent;
+use ftui_text::cursor::CursorNavigator;
+use ftui_text::editor::Editor;
+use ftui_text::search::is_word_char;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Editing mode reported by a keymap, for status-line display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeymapMode {
+    /// Vim normal mode.
+    Normal,
+    /// Text entry: vim insert mode, or emacs without an active mark.
+    Insert,
+    /// Vim characterwise visual mode.
+    Visual,
+    /// Vim linewise visual mode.
+    VisualLine,
+    /// Emacs with an active mark.
+    Mark,
+}
+
+impl KeymapMode {
+    /// Short upper-case label, e.g. `"NORMAL"`.
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Normal => "NORMAL",
+            Self::Insert => "INSERT",
+            Self::Visual => "VISUAL",
+            Self::VisualLine => "V-LINE",
+            Self::Mark => "MARK",
+        }
+    }
+}
+
+impl fmt::Display for KeymapMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Outcome of [`Keymap::handle_key`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeymapResult {
+    /// The keymap consumed the key.
+    Handled,
+    /// The widget should apply its default binding.
+    Unhandled,
+}
+
+/// Buffer operations a keymap drives.
+///
+/// Offsets are byte offsets into [`text`](Self::text) and lie on grapheme
+/// boundaries.
+///
+/// Per-key work should go through the narrow queries ([`len`](Self::len),
+/// [`char_at`](Self::char_at), [`slice`](Self::slice),
+/// [`line_bounds`](Self::line_bounds)); [`text`](Self::text) is for motions
+/// that may span many lines.
+pub trait KeymapTarget {
+    /// Full buffer contents, borrowed where the target can.
+    fn text(&self) -> Cow<'_, str>;
+
+    /// Buffer length in bytes.
+    fn len(&self) -> usize {
+        self.text().len()
+    }
+
+    /// Whether the buffer is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Text of the byte range `range`.
+    fn slice(&self, range: Range<usize>) -> Cow<'_, str> {
+        match self.text() {
+            Cow::Borrowed(text) => Cow::Borrowed(&text[range]),
+            Cow::Owned(text) => Cow::Owned(text[range].to_string()),
+        }
+    }
+
+    /// Character starting at byte `offset`, or `None` at the end.
+    fn char_at(&self, offset: usize) -> Option<char> {
+        self.text()[offset..].chars().next()
+    }
+
+    /// Byte range of the line containing `offset`, without its line break.
+    fn line_bounds(&self, offset: usize) -> Range<usize> {
+        let text = self.text();
+        line_start(&text, offset)..line_end(&text, offset)
+    }
+
+    /// Cursor byte offset.
+    fn cursor_offset(&self) -> usize;
+
+    /// Non-empty selection as `(anchor, head)` byte offsets.
+    fn selection_offsets(&self) -> Option<(usize, usize)>;
+
+    /// Move the cursor, clearing any selection.
+    fn set_cursor_offset(&mut self, offset: usize);
+
+    /// Select from `anchor` to `head`, placing the cursor at `head`.
+    fn select_offsets(&mut self, anchor: usize, head: usize);
+
+    /// Replace `start..end` with `text`, leaving the cursor after it.
+    fn replace_offsets(&mut self, start: usize, end: usize, text: &str);
+
+    /// Insert a line break at the cursor.
+    fn insert_newline(&mut self) {
+        let at = self.cursor_offset();
+        self.replace_offsets(at, at, "\n");
+    }
+
+    /// Undo the last edit. Returns `false` if unsupported or nothing to undo.
+    fn undo(&mut self) -> bool {
+        false
+    }
+
+    /// Redo the last undone edit. Returns `false` if unsupported.
+    fn redo(&mut self) -> bool {
+        false
+    }
+
+    /// Start grouping edits into one undo step.
+    fn begin_edit_group(&mut self) {}
+
+    /// Finish an edit group started with [`begin_edit_group`](Self::begin_edit_group).
+    fn end_edit_group(&mut self) {}
+}
+
+/// A pluggable set of editing key bindings.
+pub trait Keymap: Send + Sync {
+    /// Handle a key press.
+    fn handle_key(&mut self, key: &KeyEvent, target: &mut dyn KeymapTarget) -> KeymapResult;
+
+    /// Current mode, for status-line display.
+    fn mode(&self) -> KeymapMode;
+
+    /// Keys of an incomplete command typed so far (e.g. `"2d"`).
+    fn pending_keys(&self) -> String {
+        String::new()
+    }
+
+    /// Clone into a box, so widgets holding a keymap stay `Clone`.
+    fn clone_box(&self) -> Box<dyn Keymap>;
+}
+
+/// Boxed keymap stored by editing widgets.
+pub(crate) struct KeymapSlot(pub(crate) Box<dyn Keymap>);
+
+impl Clone for KeymapSlot {
+    fn clone(&self) -> Self {
+        Self(self.0.clone_box())
+    }
+}
+
+impl fmt::Debug for KeymapSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Keymap").field(&self.0.mode()).finish()
+    }
+}
+
+impl KeymapTarget for Editor {
+    fn text(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.text_str())
+    }
+
+    fn len(&self) -> usize {
+        self.rope().len_bytes()
+    }
+
+    fn slice(&self, range: Range<usize>) -> Cow<'_, str> {
+        let rope = self.rope();
+        rope.slice(rope.byte_to_char(range.start)..rope.byte_to_char(range.end))
+    }
+
+    fn char_at(&self, offset: usize) -> Option<char> {
+        let rope = self.rope();
+        let at = rope.byte_to_char(offset);
+        rope.slice(at..at + 1).chars().next()
+    }
+
+    fn line_bounds(&self, offset: usize) -> Range<usize> {
+        let rope = self.rope();
+        let line = rope.byte_to_line_col(offset).0;
+        let start = rope.line_col_to_byte(line, 0);
+        let text = rope.line(line).unwrap_or_default();
+        start..start + text.strip_suffix('\n').unwrap_or(&text).len()
+    }
+
+    fn cursor_offset(&self) -> usize {
+        CursorNavigator::new(self.rope()).to_byte_index(self.cursor())
+    }
+
+    fn selection_offsets(&self) -> Option<(usize, usize)> {
+        let sel = self.selection().filter(|sel| !sel.is_empty())?;
+        let nav = CursorNavigator::new(self.rope());
+        Some((nav.to_byte_index(sel.anchor), nav.to_byte_index(sel.head)))
+    }
+
+    fn set_cursor_offset(&mut self, offset: usize) {
+        let pos = CursorNavigator::new(self.rope()).from_byte_index(offset);
+        self.set_cursor(pos);
+    }
+
+    fn select_offsets(&mut self, anchor: usize, head: usize) {
+        self.select_byte_range(anchor, head);
+    }
+
+    fn replace_offsets(&mut self, start: usize, end: usize, text: &str) {
+        self.replace_byte_range(start, end, text);
+    }
+
+    fn undo(&mut self) -> bool {
+        Editor::undo(self)
+    }
+
+    fn redo(&mut self) -> bool {
+        Editor::redo(self)
+    }
+
+    fn begin_edit_group(&mut self) {
+        self.begin_undo_group();
+    }
+
+    fn end_edit_group(&mut self) {
+        self.end_undo_group();
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Text helpers shared by the keymaps (byte offsets into the buffer)
+// ---------------------------------------------------------------------------
+
+/// Start of the line containing `at`.
+fn line_start(text: &str, at: usize) -> usize {
+    text[..at].rfind('\n').map_or(0, |i| i + 1)
+}
+
+/// End of the line containing `at` (before its newline).
+fn line_end(text: &str, at: usize) -> usize {
+    text[at..].find('\n').map_or(text.len(), |i| at + i)
+}
+
+/// Offset of the grapheme after the one at `at`.
+fn next_boundary(text: &str, at: usize) -> usize {
+    text[at..]
+        .graphemes(true)
+        .next()
+        .map_or(text.len(), |g| at + g.len())
+}
+
+/// Offset of the grapheme before `at`.
+fn prev_boundary(text: &str, at: usize) -> usize {
+    text[..at]
+        .graphemes(true)
+        .next_back()
+        .map_or(0, |g| at - g.len())
+}
+
+/// [`next_boundary`] reading only the line around `at` (and its break).
+fn next_grapheme(t: &dyn KeymapTarget, at: usize) -> usize {
+    let end = (t.line_bounds(at).end + 1).min(t.len());
+    at + next_boundary(&t.slice(at..end), 0)
+}
+
+/// [`prev_boundary`] reading only the line around `at` (and the previous
+/// line when `at` starts one).
+fn prev_grapheme(t: &dyn KeymapTarget, at: usize) -> usize {
+    let line = t.line_bounds(at);
+    let start = if at > line.start {
+        line.start
+    } else {
+        t.line_bounds(at.saturating_sub(1)).start
+    };
+    start + prev_boundary(&t.slice(start..at), at - start)
+}
+
+/// [`first_non_blank`] reading only the line around `at`.
+fn first_non_blank_on(t: &dyn KeymapTarget, at: usize) -> usize {
+    let line = t.line_bounds(at);
+    line.start + first_non_blank(&t.slice(line.clone()), 0)
+}
+
+/// Grapheme column of `at` within its line, reading only that line.
+fn line_column(t: &dyn KeymapTarget, at: usize) -> usize {
+    t.slice(t.line_bounds(at).start..at).graphemes(true).count()
+}
+
+/// Zero-based line index of `at`.
+fn line_index(text: &str, at: usize) -> usize {
+    text[..at].matches('\n').count()
+}
+
+/// Start offset of line `line`, clamped to the last line.
+fn line_offset(text: &str, line: usize) -> usize {
+    if line == 0 {
+        return 0;
+    }
+    text.match_indices('\n')
+        .nth(line - 1)
+        .map_or_else(|| line_start(text, text.len()), |(i, _)| i + 1)
+}
+
+/// Grapheme column of `at` within its line.
+fn column(text: &str, at: usize) -> usize {
+    text[line_start(text, at)..at].graphemes(true).count()
+}
+
+/// Offset of grapheme column `col` on the line starting at `start`, clamped
+/// to the line end.
+fn offset_at_column(text: &str, start: usize, col: usize) -> usize {
+    let end = line_end(text, start);
+    text[start..end]
+        .grapheme_indices(true)
+        .nth(col)
+        .map_or(end, |(i, _)| start + i)
+}
+
+/// Offset of the first non-blank character on the line containing `at`.
+fn first_non_blank(text: &str, at: usize) -> usize {
+    let start = line_start(text, at);
+    let end = line_end(text, at);
+    text[start..end]
+        .find(|c: char| c != ' ' && c != '\t')
+        .map_or(end, |i| start + i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_helpers() {
+        let text = "ab\ncde\n\nf";
+        assert_eq!(line_start(text, 4), 3);
+        assert_eq!(line_end(text, 4), 6);
+        assert_eq!(line_index(text, 7), 2);
+        assert_eq!(line_offset(text, 2), 7);
+        assert_eq!(line_offset(text, 9), 8);
+        assert_eq!(column(text, 5), 2);
+        assert_eq!(offset_at_column(text, 3, 9), 6);
+        assert_eq!(first_non_blank("  x", 0), 2);
+    }
+
+    #[test]
+    fn boundaries_respect_graphemes() {
+        let text = "e\u{301}x";
+        assert_eq!(next_boundary(text, 0), 3);
+        assert_eq!(prev_boundary(text, 3), 0);
+        assert_eq!(next_boundary(text, text.len()), text.len());
+        assert_eq!(prev_boundary(text, 0), 0);
+    }
+
+    #[test]
+    fn editor_target_offsets() {
+        let mut ed = Editor::with_text("hello\nworld");
+        KeymapTarget::set_cursor_offset(&mut ed, 7);
+        assert_eq!(ed.cursor_offset(), 7);
+        ed.select_offsets(6, 9);
+        assert_eq!(ed.selection_offsets(), Some((6, 9)));
+        ed.replace_offsets(0, 5, "bye");
+        assert_eq!(Editor::text(&ed), "bye\nworld");
+        assert_eq!(ed.cursor_offset(), 3);
+    }
+
+    #[test]
+    fn editor_target_queries_match_text() {
+        let ed = Editor::with_text("héllo\nworld\n");
+        let text = Editor::text(&ed);
+        assert_eq!(KeymapTarget::len(&ed), text.len());
+        assert_eq!(KeymapTarget::slice(&ed, 1..6), &text[1..6]);
+        assert_eq!(KeymapTarget::char_at(&ed, 1), Some('é'));
+        assert_eq!(KeymapTarget::char_at(&ed, text.len()), None);
+        assert_eq!(ed.line_bounds(3), 0..6);
+        assert_eq!(ed.line_bounds(6), 0..6);
+        assert_eq!(ed.line_bounds(7), 7..12);
+        assert_eq!(ed.line_bounds(text.len()), text.len()..text.len());
+        assert_eq!(ed.text_str(), text);
+    }
+
+    #[test]
+    fn mode_labels() {
+        assert_eq!(KeymapMode::Normal.to_string(), "NORMAL");
+        assert_eq!(KeymapMode::VisualLine.label(), "V-LINE");
+    }
+}
@@ -0,0 +1,1661 @@
+#![forbid(unsafe_code)]
+
+//! Modal vim keymap.
+//!
+//! Supported commands:
+//!
+//! - Motions: `h j k l w W b B e E ge gE 0 ^ $ gg G f F t T ; ,` and Enter,
+//!   plus the arrow, Home and End keys.
+//! - Operators `d c y` with a motion, a text object (`iw aw iW aW`, quotes,
+//!   `i( a( ib i[ i{ iB i<` ...), or doubled for whole lines (`dd cc yy`).
+//! - `x X s S D C Y p P J ~ r u` Ctrl-R, `.` repeat, and counts everywhere.
+//! - Registers: `"a`–`"z` (upper case appends), `"0` last yank, `"_` black
+//!   hole, and the unnamed register.
+//! - Insert entries `i a I A o O`; visual modes `v V` with `o` to swap ends.
+//!
+//! A change followed by its insert session is a single undo step.
+
+use std::collections::HashMap;
+
+use ftui_core::event::{KeyCode, KeyEvent, Modifiers};
+use ftui_core::key_sequence::{
+    KeySequenceAction, KeySequenceConfig, KeySequenceInterpreter, KeySequenceKind,
+};
+use web_time::Instant;
+
+use super::{
+    Keymap, KeymapMode, KeymapResult, KeymapTarget, column, first_non_blank, first_non_blank_on,
+    is_word_char, line_end, line_index, line_offset, line_start, next_boundary, next_grapheme,
+    offset_at_column, prev_boundary, prev_grapheme,
+};
+
+/// `gg`: first line (or line `count`).
+const SEQ_TOP: u16 = 1;
+/// `ge`: back to the end of the previous word.
+const SEQ_WORD_END_BACK: u16 = 2;
+/// `gE`: back to the end of the previous WORD.
+const SEQ_BIG_WORD_END_BACK: u16 = 3;
+
+/// Largest accepted count.
+const MAX_COUNT: usize = 99_999;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Delete,
+    Change,
+    Yank,
+}
+
+/// `f F t T` character searches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Find {
+    Forward,
+    Backward,
+    TillForward,
+    TillBackward,
+}
+
+impl Find {
+    fn reversed(self) -> Self {
+        match self {
+            Self::Forward => Self::Backward,
+            Self::Backward => Self::Forward,
+            Self::TillForward => Self::TillBackward,
+            Self::TillBackward => Self::TillForward,
+        }
+    }
+}
+
+/// What the next key completes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pending {
+    None,
+    Register,
+    Find(Find),
+    Replace,
+    Object { inner: bool },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Motion {
+    Left,
+    Right,
+    Up,
+    Down,
+    WordStart { big: bool },
+    WordEnd { big: bool },
+    WordBack { big: bool },
+    WordEndBack { big: bool },
+    LineStart,
+    FirstNonBlank,
+    LineEnd,
+    NextLine,
+    Top,
+    Bottom,
+    Find(Find, char),
+}
+
+/// How an operator treats the range a motion covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Span {
+    Exclusive,
+    Inclusive,
+    Linewise,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Register {
+    text: String,
+    linewise: bool,
+}
+
+/// Vim-style modal keymap.
+///
+/// Starts in [`KeymapMode::Normal`]. In insert mode, keys other than text,
+/// Enter, Backspace, Delete and Esc fall through to the widget.
+#[derive(Debug, Clone)]
+pub struct VimKeymap {
+    mode: KeymapMode,
+    count: Option<usize>,
+    operator: Option<(Operator, usize)>,
+    pending: Pending,
+    register: Option<char>,
+    registers: HashMap<char, Register>,
+    last_find: Option<(Find, char)>,
+    visual_anchor: usize,
+    visual_head: usize,
+    /// Column kept across `j`/`k` (`usize::MAX` after `$`).
+    desired_col: Option<usize>,
+    /// `g`-prefixed commands.
+    sequences: KeySequenceInterpreter,
+    /// Keys of an incomplete command, for [`Keymap::pending_keys`].
+    typed: String,
+    /// Keys of the command being recorded for `.`.
+    keys: Vec<KeyEvent>,
+    /// Keys of the last change, replayed by `.`.
+    last_change: Vec<KeyEvent>,
+    /// The current command modified the buffer.
+    changed: bool,
+    /// Recording continues through the insert session of a change.
+    recording_insert: bool,
+    replaying: bool,
+    /// An undo group is open on the target.
+    group_open: bool,
+}
+
+impl Default for VimKeymap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VimKeymap {
+    /// Create a vim keymap in normal mode.
+    #[must_use]
+    pub fn new() -> Self {
+        let g = KeyEvent::new(KeyCode::Char('g'));
+        let config = KeySequenceConfig {
+            detect_double_escape: false,
+            ..Default::default()
+        }
+        .with_sequence(SEQ_TOP, vec![g, g])
+        .with_sequence(
+            SEQ_WORD_END_BACK,
+            vec![g, KeyEvent::new(KeyCode::Char('e'))],
+        )
+        .with_sequence(
+            SEQ_BIG_WORD_END_BACK,
+            vec![g, KeyEvent::new(KeyCode::Char('E'))],
+        );
+        Self {
+            mode: KeymapMode::Normal,
+            count: None,
+            operator: None,
+            pending: Pending::None,
+            register: None,
+            registers: HashMap::new(),
+            last_find: None,
+            visual_anchor: 0,
+            visual_head: 0,
+            desired_col: None,
+            sequences: KeySequenceInterpreter::new(config),
+            typed: String::new(),
+            keys: Vec::new(),
+            last_change: Vec::new(),
+            changed: false,
+            recording_insert: false,
+            replaying: false,
+            group_open: false,
+        }
+    }
+
+    /// Contents of register `name`, if set.
+    ///
+    /// `'"'` is the unnamed register; `'0'` holds the last yank.
+    #[must_use]
+    pub fn register(&self, name: char) -> Option<&str> {
+        self.registers.get(&name).map(|r| r.text.as_str())
+    }
+
+    fn is_idle(&self) -> bool {
+        self.count.is_none()
+            && self.operator.is_none()
+            && self.pending == Pending::None
+            && self.register.is_none()
+            && !self.sequences.has_pending()
+    }
+
+    /// Drop any partially typed command.
+    fn reset(&mut self) {
+        self.count = None;
+        self.operator = None;
+        self.pending = Pending::None;
+        self.register = None;
+        self.sequences.reset();
+        self.typed.clear();
+    }
+
+    fn take_count(&mut self) -> Option<usize> {
+        self.count.take()
+    }
+
+    fn is_visual(&self) -> bool {
+        matches!(self.mode, KeymapMode::Visual | KeymapMode::VisualLine)
+    }
+
+    /// Position commands act from: the visual head or the cursor.
+    fn head(&self, t: &dyn KeymapTarget) -> usize {
+        if self.is_visual() {
+            self.visual_head
+        } else {
+            t.cursor_offset()
+        }
+    }
+
+    fn begin_change(&mut self, t: &mut dyn KeymapTarget) {
+        self.changed = true;
+        if !self.group_open {
+            t.begin_edit_group();
+            self.group_open = true;
+        }
+    }
+
+    fn end_change(&mut self, t: &mut dyn KeymapTarget) {
+        if self.group_open {
+            t.end_edit_group();
+            self.group_open = false;
+        }
+    }
+
+    // -- Insert mode --------------------------------------------------------
+
+    fn enter_insert(&mut self, t: &mut dyn KeymapTarget) {
+        self.begin_change(t);
+        self.mode = KeymapMode::Insert;
+    }
+
+    fn leave_insert(&mut self, t: &mut dyn KeymapTarget) {
+        self.mode = KeymapMode::Normal;
+        self.end_change(t);
+        if self.recording_insert {
+            self.recording_insert = false;
+            self.last_change = std::mem::take(&mut self.keys);
+        }
+        let at = t.cursor_offset();
+        if at > t.line_bounds(at).start {
+            t.set_cursor_offset(prev_grapheme(t, at));
+        }
+    }
+
+    fn insert_key(&mut self, key: &KeyEvent, t: &mut dyn KeymapTarget) -> KeymapResult {
+        let ctrl = key.modifiers.contains(Modifiers::CTRL);
+        let alt = key.modifiers.contains(Modifiers::ALT);
+        let handled = match key.code {
+            KeyCode::Escape | KeyCode::Enter | KeyCode::Backspace | KeyCode::Delete => !ctrl,
+            KeyCode::Char('[' | 'c') if ctrl => true,
+            KeyCode::Char(_) => !ctrl && !alt,
+            _ => false,
+        };
+        if !handled {
+            return KeymapResult::Unhandled;
+        }
+        if self.recording_insert && !self.replaying {
+            self.keys.push(*key);
+        }
+        let at = t.cursor_offset();
+        match key.code {
+            KeyCode::Escape | KeyCode::Char(_) if ctrl || key.code == KeyCode::Escape => {
+                self.leave_insert(t);
+            }
+            KeyCode::Char(c) => {
+                let mut buf = [0u8; 4];
+                t.replace_offsets(at, at, c.encode_utf8(&mut buf));
+            }
+            KeyCode::Enter => t.insert_newline(),
+            KeyCode::Backspace => {
+                if at > 0 {
+                    t.replace_offsets(prev_grapheme(t, at), at, "");
+                }
+            }
+            _ => {
+                if at < t.len() {
+                    t.replace_offsets(at, next_grapheme(t, at), "");
+                }
+            }
+        }
+        KeymapResult::Handled
+    }
+
+    // -- Normal / visual mode -------------------------------------------------
+
+    fn command_key(
+        &mut self,
+        key: &KeyEvent,
+        t: &mut dyn KeymapTarget,
+        now: Instant,
+    ) -> KeymapResult {
+        let ctrl = key.modifiers.contains(Modifiers::CTRL);
+        if key.modifiers.contains(Modifiers::ALT) {
+            return KeymapResult::Unhandled;
+        }
+        let was_visual = self.is_visual();
+        if !self.replaying {
+            if self.is_idle() {
+                self.keys.clear();
+            }
+            self.keys.push(*key);
+        }
+
+        let result = self.dispatch(key, ctrl, t, now);
+
+        if self.changed {
+            self.changed = false;
+            if self.mode == KeymapMode::Insert {
+                self.recording_insert = !self.replaying && !was_visual;
+            } else if !self.replaying && !was_visual {
+                self.last_change = self.keys.clone();
+            }
+        }
+        if self.mode == KeymapMode::Normal {
+            clamp_cursor(t);
+        }
+        result
+    }
+
+    fn dispatch(
+        &mut self,
+        key: &KeyEvent,
+        ctrl: bool,
+        t: &mut dyn KeymapTarget,
+        now: Instant,
+    ) -> KeymapResult {
+        use KeymapResult::Handled;
+
+        // Completions of a pending key.
+        let pending = std::mem::replace(&mut self.pending, Pending::None);
+        if pending != Pending::None {
+            let KeyCode::Char(c) = key.code else {
+                self.reset();
+                return Handled;
+            };
+            match pending {
+                Pending::Register => {
+                    self.register = Some(c);
+                    self.typed.push(c);
+                }
+                Pending::Find(kind) => {
+                    self.last_find = Some((kind, c));
+                    self.motion(Motion::Find(kind, c), t);
+                }
+                Pending::Replace => self.replace_chars(c, t),
+                Pending::Object { inner } => self.text_object(inner, c, t),
+                Pending::None => {}
+            }
+            return Handled;
+        }
+
+        // `g` prefix.
+        let was_pending = self.sequences.has_pending();
+        match self.sequences.feed(key, now) {
+            KeySequenceAction::Pending => {
+                self.typed.push('g');
+                return Handled;
+            }
+            KeySequenceAction::EmitSequence {
+                kind: KeySequenceKind::Custom(id),
+                ..
+            } => {
+                self.typed.pop();
+                let motion = match id {
+                    SEQ_TOP => Motion::Top,
+                    SEQ_WORD_END_BACK => Motion::WordEndBack { big: false },
+                    _ => Motion::WordEndBack { big: true },
+                };
+                self.motion(motion, t);
+                return Handled;
+            }
+            _ if was_pending => {
+                self.reset();
+                return Handled;
+            }
+            _ => {}
+        }
+
+        if ctrl {
+            return match key.code {
+                KeyCode::Char('r') => {
+                    let n = self.take_count().unwrap_or(1);
+                    for _ in 0..n {
+                        t.redo();
+                    }
+                    self.reset();
+                    Handled
+                }
+                KeyCode::Char('c' | '[') => self.escape(t),
+                _ => KeymapResult::Unhandled,
+            };
+        }
+
+        let c = match key.code {
+            KeyCode::Char(c) => c,
+            KeyCode::Escape => return self.escape(t),
+            KeyCode::Left | KeyCode::Backspace => 'h',
+            KeyCode::Right => 'l',
+            KeyCode::Up => 'k',
+            KeyCode::Down => 'j',
+            KeyCode::Home => '0',
+            KeyCode::End => '$',
+            KeyCode::Enter => '+',
+            KeyCode::Delete => 'x',
+            _ => {
+                self.reset();
+                return KeymapResult::Unhandled;
+            }
+        };
+
+        // Counts (a leading `0` is a motion).
+        if let Some(d) = c.to_digit(10)
+            && (d != 0 || self.count.is_some())
+        {
+            let n = self.count.unwrap_or(0).saturating_mul(10) + d as usize;
+            self.count = Some(n.min(MAX_COUNT));
+            self.typed.push(c);
+            return Handled;
+        }
+
+        let motion = match c {
+            'h' => Some(Motion::Left),
+            'l' | ' ' => Some(Motion::Right),
+            'j' => Some(Motion::Down),
+            'k' => Some(Motion::Up),
+            'w' | 'W' => Some(Motion::WordStart { big: c == 'W' }),
+            'e' | 'E' => Some(Motion::WordEnd { big: c == 'E' }),
+            'b' | 'B' => Some(Motion::WordBack { big: c == 'B' }),
+            '0' => Some(Motion::LineStart),
+            '^' => Some(Motion::FirstNonBlank),
+            '$' => Some(Motion::LineEnd),
+            '+' => Some(Motion::NextLine),
+            'G' => Some(Motion::Bottom),
+            ';' => self.last_find.map(|(kind, ch)| Motion::Find(kind, ch)),
+            ',' => self
+                .last_find
+                .map(|(kind, ch)| Motion::Find(kind.reversed(), ch)),
+            _ => None,
+        };
+        if let Some(motion) = motion {
+            self.motion(motion, t);
+            return Handled;
+        }
+
+        match c {
+            '"' => self.set_pending(Pending::Register, c),
+            'f' => self.set_pending(Pending::Find(Find::Forward), c),
+            'F' => self.set_pending(Pending::Find(Find::Backward), c),
+            't' => self.set_pending(Pending::Find(Find::TillForward), c),
+            'T' => self.set_pending(Pending::Find(Find::TillBackward), c),
+            'd' => self.operator(Operator::Delete, c, t),
+            'c' => self.operator(Operator::Change, c, t),
+            'y' => self.operator(Operator::Yank, c, t),
+            'i' | 'a' if self.operator.is_some() || self.is_visual() => {
+                self.set_pending(Pending::Object { inner: c == 'i' }, c);
+            }
+            _ if self.operator.is_some() => self.reset(),
+            _ if self.is_visual() => self.visual_command(c, t),
+            _ => self.normal_command(c, t),
+        }
+        Handled
+    }
+
+    fn set_pending(&mut self, pending: Pending, c: char) {
+        self.pending = pending;
+        self.typed.push(c);
+    }
+
+    fn escape(&mut self, t: &mut dyn KeymapTarget) -> KeymapResult {
+        let idle = self.is_idle();
+        self.reset();
+        if self.is_visual() {
+            self.exit_visual(t);
+            KeymapResult::Handled
+        } else if idle {
+            KeymapResult::Unhandled
+        } else {
+            KeymapResult::Handled
+        }
+    }
+
+    fn normal_command(&mut self, c: char, t: &mut dyn KeymapTarget) {
+        let count = self.count.unwrap_or(1);
+        match c {
+            'x' => self.operate_with(Operator::Delete, Motion::Right, t),
+            'X' => self.operate_with(Operator::Delete, Motion::Left, t),
+            's' => self.operate_with(Operator::Change, Motion::Right, t),
+            'D' => self.operate_with(Operator::Delete, Motion::LineEnd, t),
+            'C' => self.operate_with(Operator::Change, Motion::LineEnd, t),
+            'S' => self.operate_lines(Operator::Change, t),
+            'Y' => self.operate_lines(Operator::Yank, t),
+            'p' | 'P' => self.paste(c == 'P', count, t),
+            'J' => self.join_lines(count.max(2) - 1, t),
+            '~' => {
+                let at = t.cursor_offset();
+                let line_end = t.line_bounds(at).end;
+                let mut end = at;
+                for _ in 0..count {
+                    end = next_grapheme(t, end).min(line_end);
+                }
+                if end > at {
+                    let toggled = toggle_case(&t.slice(at..end));
+                    self.begin_change(t);
+                    t.replace_offsets(at, end, &toggled);
+                    self.end_change(t);
+                }
+            }
+            'r' => {
+                self.pending = Pending::Replace;
+                self.typed.push(c);
+                return;
+            }
+            'u' => {
+                for _ in 0..count {
+                    t.undo();
+                }
+            }
+            '.' => {
+                self.reset();
+                self.repeat(count, t);
+                return;
+            }
+            'i' | 'a' | 'I' | 'A' => {
+                let at = t.cursor_offset();
+                let pos = match c {
+                    'i' => at,
+                    'a' => next_grapheme(t, at).min(t.line_bounds(at).end),
+                    'I' => first_non_blank_on(t, at),
+                    _ => t.line_bounds(at).end,
+                };
+                t.set_cursor_offset(pos);
+                self.enter_insert(t);
+            }
+            'o' => {
+                let end = t.line_bounds(t.cursor_offset()).end;
+                self.enter_insert(t);
+                t.set_cursor_offset(end);
+                t.insert_newline();
+            }
+            'O' => {
+                let start = t.line_bounds(t.cursor_offset()).start;
+                self.enter_insert(t);
+                t.replace_offsets(start, start, "\n");
+                t.set_cursor_offset(start);
+            }
+            'v' | 'V' => {
+                let at = t.cursor_offset();
+                self.visual_anchor = at;
+                self.visual_head = at;
+                self.mode = if c == 'v' {
+                    KeymapMode::Visual
+                } else {
+                    KeymapMode::VisualLine
+                };
+                self.update_visual(t);
+            }
+            _ => {}
+        }
+        self.reset();
+    }
+
+    fn visual_command(&mut self, c: char, t: &mut dyn KeymapTarget) {
+        match c {
+            'x' => self.visual_operator(Operator::Delete, false, t),
+            's' => self.visual_operator(Operator::Change, false, t),
+            'D' | 'X' => self.visual_operator(Operator::Delete, true, t),
+            'C' | 'S' | 'R' => self.visual_operator(Operator::Change, true, t),
+            'Y' => self.visual_operator(Operator::Yank, true, t),
+            'p' | 'P' => self.visual_paste(t),
+            '~' | 'u' | 'U' => {
+                let (start, end, _) = self.visual_range(t);
+                let selected = t.slice(start..end);
+                let replaced = match c {
+                    '~' => toggle_case(&selected),
+                    'u' => selected.to_lowercase(),
+                    _ => selected.to_uppercase(),
+                };
+                self.exit_visual(t);
+                self.begin_change(t);
+                t.replace_offsets(start, end, &replaced);
+                self.end_change(t);
+                t.set_cursor_offset(start);
+            }
+            'J' => {
+                let start = self.visual_anchor.min(self.visual_head);
+                let lines = {
+                    let text = t.text();
+                    line_index(&text, self.visual_anchor.max(self.visual_head))
+                        - line_index(&text, start)
+                };
+                self.exit_visual(t);
+                t.set_cursor_offset(start);
+                self.join_lines(lines.max(1), t);
+            }
+            'o' => {
+                std::mem::swap(&mut self.visual_anchor, &mut self.visual_head);
+                self.update_visual(t);
+            }
+            'v' | 'V' => {
+                let mode = if c == 'v' {
+                    KeymapMode::Visual
+                } else {
+                    KeymapMode::VisualLine
+                };
+                if self.mode == mode {
+                    self.exit_visual(t);
+                } else {
+                    self.mode = mode;
+                    self.update_visual(t);
+                }
+            }
+            _ => {}
+        }
+        self.reset();
+    }
+
+    // -- Motions and operators ------------------------------------------------
+
+    /// Apply a motion: move, extend the visual selection, or complete an operator.
+    fn motion(&mut self, motion: Motion, t: &mut dyn KeymapTarget) {
+        let at = self.head(t);
+        let count = self.take_count();
+        let operator = self.operator.take();
+        let count = match (count, operator) {
+            (None, Some((_, 1)) | None) => None,
+            (count, op) => Some(count.unwrap_or(1) * op.map_or(1, |(_, n)| n)),
+        };
+        let op = operator.map(|(op, _)| op);
+        let target = self.motion_target(motion, count, op, &t.text(), at);
+        self.typed.clear();
+        let Some((target, span)) = target else {
+            return;
+        };
+        if let Some(op) = op {
+            let (start, end) = (at.min(target), at.max(target));
+            match span {
+                Span::Linewise => self.apply_linewise(op, start, end, t),
+                Span::Inclusive => {
+                    let end = next_grapheme(t, end);
+                    self.apply_charwise(op, start, end, t);
+                }
+                Span::Exclusive => self.apply_charwise(op, start, end, t),
+            }
+        } else if self.is_visual() {
+            self.visual_head = target;
+            self.update_visual(t);
+        } else {
+            t.set_cursor_offset(clamp_normal(t, target));
+        }
+    }
+
+    /// Target offset and span of `motion` from `at`, or `None` if it fails.
+    fn motion_target(
+        &mut self,
+        motion: Motion,
+        explicit: Option<usize>,
+        op: Option<Operator>,
+        text: &str,
+        at: usize,
+    ) -> Option<(usize, Span)> {
+        let count = explicit.unwrap_or(1).max(1);
+        if !matches!(motion, Motion::Up | Motion::Down) {
+            self.desired_col = None;
+        }
+        let last_line = line_index(text, text.len());
+        let result = match motion {
+            Motion::Left => {
+                let start = line_start(text, at);
+                let mut p = at;
+                for _ in 0..count {
+                    if p > start {
+                        p = prev_boundary(text, p);
+                    }
+                }
+                (p, Span::Exclusive)
+            }
+            Motion::Right => {
+                let end = line_end(text, at);
+                let mut p = at;
+                for _ in 0..count {
+                    if p < end {
+                        p = next_boundary(text, p);
+                    }
+                }
+                (p, Span::Exclusive)
+            }
+            Motion::Up | Motion::Down => {
+                let line = line_index(text, at);
+                let target = if motion == Motion::Up {
+                    line.checked_sub(count)?
+                } else if line + count <= last_line {
+                    line + count
+                } else {
+                    return None;
+                };
+                let col = *self.desired_col.get_or_insert_with(|| column(text, at));
+                let p = offset_at_column(text, line_offset(text, target), col);
+                (p, Span::Linewise)
+            }
+            Motion::WordStart { big } => {
+                // `cw` on a word changes to its end, like `ce`.
+                if op == Some(Operator::Change) && class_at(text, at, big) != 0 {
+                    let mut p = run_end(text, at, big);
+                    for _ in 1..count {
+                        p = word_end(text, p, big);
+                    }
+                    return Some((p, Span::Inclusive));
+                }
+                let mut p = at;
+                let mut prev = at;
+                for _ in 0..count {
+                    prev = p;
+                    p = word_forward(text, p, big);
+                }
+                // An operator stops at the end of the line of the last word.
+                if op.is_some() && line_index(text, p) > line_index(text, prev) {
+                    p = line_end(text, prev).max(at);
+                }
+                (p, Span::Exclusive)
+            }
+            Motion::WordEnd { big } => {
+                let mut p = at;
+                for _ in 0..count {
+                    p = word_end(text, p, big);
+                }
+                (p, Span::Inclusive)
+            }
+            Motion::WordBack { big } => {
+                let mut p = at;
+                for _ in 0..count {
+                    p = word_back(text, p, big);
+                }
+                (p, Span::Exclusive)
+            }
+            Motion::WordEndBack { big } => {
+                let mut p = at;
+                for _ in 0..count {
+                    p = word_end_back(text, p, big);
+                }
+                (p, Span::Inclusive)
+            }
+            Motion::LineStart => (line_start(text, at), Span::Exclusive),
+            Motion::FirstNonBlank => (first_non_blank(text, at), Span::Exclusive),
+            Motion::LineEnd => {
+                let line = (line_index(text, at) + count - 1).min(last_line);
+                self.desired_col = Some(usize::MAX);
+                (line_end(text, line_offset(text, line)), Span::Exclusive)
+            }
+            Motion::NextLine => {
+                let line = line_index(text, at) + count;
+                if line > last_line {
+                    return None;
+                }
+                (
+                    first_non_blank(text, line_offset(text, line)),
+                    Span::Linewise,
+                )
+            }
+            Motion::Top | Motion::Bottom => {
+                let line = match explicit {
+                    Some(n) => n.saturating_sub(1).min(last_line),
+                    None if motion == Motion::Top => 0,
+                    None => last_line,
+                };
+                (
+                    first_non_blank(text, line_offset(text, line)),
+                    Span::Linewise,
+                )
+            }
+            Motion::Find(kind, c) => {
+                let p = find_in_line(text, at, kind, c, count)?;
+                let span = match kind {
+                    Find::Forward | Find::TillForward => Span::Inclusive,
+                    _ => Span::Exclusive,
+                };
+                (p, span)
+            }
+        };
+        Some(result)
+    }
+
+    /// Start an operator, or run it linewise if doubled (`dd`).
+    fn operator(&mut self, op: Operator, c: char, t: &mut dyn KeymapTarget) {
+        if self.is_visual() {
+            self.visual_operator(op, false, t);
+            self.reset();
+            return;
+        }
+        match self.operator {
+            Some((pending, _)) if pending == op => self.operate_lines(op, t),
+            Some(_) => self.reset(),
+            None => {
+                let count = self.take_count().unwrap_or(1);
+                self.operator = Some((op, count));
+                self.typed.push(c);
+            }
+        }
+    }
+
+    /// Run `op` with `motion` as if typed (e.g. `x` is `dl`).
+    fn operate_with(&mut self, op: Operator, motion: Motion, t: &mut dyn KeymapTarget) {
+        self.operator = Some((op, 1));
+        self.motion(motion, t);
+    }
+
+    /// Run `op` over `count` whole lines from the cursor (`dd`, `cc`, `yy`).
+    fn operate_lines(&mut self, op: Operator, t: &mut dyn KeymapTarget) {
+        let at = t.cursor_offset();
+        let count = self.take_count().unwrap_or(1) * self.operator.take().map_or(1, |(_, n)| n);
+        let mut end = at;
+        for _ in 1..count {
+            let line_end = t.line_bounds(end).end;
+            if line_end == t.len() {
+                break;
+            }
+            end = line_end + 1;
+        }
+        self.apply_linewise(op, at, end, t);
+        self.reset();
+    }
+
+    fn apply_charwise(&mut self, op: Operator, start: usize, end: usize, t: &mut dyn KeymapTarget) {
+        let yanked = t.slice(start..end).into_owned();
+        self.store_register(op, yanked, false);
+        match op {
+            Operator::Yank => t.set_cursor_offset(start),
+            Operator::Delete => {
+                if start < end {
+                    self.begin_change(t);
+                    t.replace_offsets(start, end, "");
+                    self.end_change(t);
+                }
+                t.set_cursor_offset(start);
+            }
+            Operator::Change => {
+                self.enter_insert(t);
+                t.replace_offsets(start, end, "");
+            }
+        }
+    }
+
+    fn apply_linewise(&mut self, op: Operator, start: usize, end: usize, t: &mut dyn KeymapTarget) {
+        let first = t.line_bounds(start).start;
+        let last = t.line_bounds(end).end;
+        let mut content = t.slice(first..last).into_owned();
+        content.push('\n');
+        self.store_register(op, content, true);
+        match op {
+            Operator::Yank => t.set_cursor_offset(start),
+            Operator::Delete => {
+                let (from, to) = if last < t.len() {
+                    (first, last + 1)
+                } else {
+                    (first.saturating_sub(1), last)
+                };
+                self.begin_change(t);
+                t.replace_offsets(from, to, "");
+                self.end_change(t);
+                t.set_cursor_offset(first_non_blank_on(t, from.min(t.len())));
+            }
+            Operator::Change => {
+                self.enter_insert(t);
+                t.replace_offsets(first, last, "");
+            }
+        }
+    }
+
+    fn store_register(&mut self, op: Operator, text: String, linewise: bool) {
+        let name = self.register.take();
+        if name == Some('_') {
+            return;
+        }
+        let entry = Register { text, linewise };
+        match name {
+            Some(c) if c.is_ascii_lowercase() => {
+                self.registers.insert(c, entry.clone());
+            }
+            Some(c) if c.is_ascii_uppercase() => {
+                let reg = self.registers.entry(c.to_ascii_lowercase()).or_default();
+                reg.text.push_str(&entry.text);
+                reg.linewise |= entry.linewise;
+            }
+            _ => {}
+        }
+        if op == Operator::Yank {
+            self.registers.insert('0', entry.clone());
+        }
+        self.registers.insert('"', entry);
+    }
+
+    fn take_register(&mut self) -> Option<Register> {
+        let name = self.register.take().unwrap_or('"').to_ascii_lowercase();
+        self.registers.get(&name).cloned()
+    }
+
+    fn paste(&mut self, before: bool, count: usize, t: &mut dyn KeymapTarget) {
+        let Some(reg) = self.take_register() else {
+            return;
+        };
+        let at = t.cursor_offset();
+        let line = t.line_bounds(at);
+        let body = reg.text.repeat(count);
+        self.begin_change(t);
+        if reg.linewise {
+            if before {
+                let pos = line.start;
+                t.replace_offsets(pos, pos, &body);
+                t.set_cursor_offset(first_non_blank_on(t, pos));
+            } else {
+                let pos = line.end;
+                let body = format!("\n{}", body.strip_suffix('\n').unwrap_or(&body));
+                t.replace_offsets(pos, pos, &body);
+                t.set_cursor_offset(first_non_blank_on(t, pos + 1));
+            }
+        } else {
+            let pos = if before || at == line.end {
+                at
+            } else {
+                next_grapheme(t, at)
+            };
+            t.replace_offsets(pos, pos, &body);
+            t.set_cursor_offset(prev_grapheme(t, pos + body.len()).max(pos));
+        }
+        self.end_change(t);
+    }
+
+    fn join_lines(&mut self, joins: usize, t: &mut dyn KeymapTarget) {
+        let at = t.cursor_offset();
+        self.begin_change(t);
+        for _ in 0..joins {
+            let line = t.line_bounds(at);
+            if line.end >= t.len() {
+                break;
+            }
+            let end = line.end;
+            let next = first_non_blank_on(t, end + 1);
+            let blank = next == t.line_bounds(end + 1).end || end == line.start;
+            t.replace_offsets(end, next, if blank { "" } else { " " });
+            t.set_cursor_offset(end);
+        }
+        self.end_change(t);
+    }
+
+    fn replace_chars(&mut self, c: char, t: &mut dyn KeymapTarget) {
+        let at = t.cursor_offset();
+        let line_end = t.line_bounds(at).end;
+        let count = self.take_count().unwrap_or(1);
+        let mut end = at;
+        for _ in 0..count {
+            if end >= line_end {
+                self.reset();
+                return;
+            }
+            end = next_grapheme(t, end);
+        }
+        self.begin_change(t);
+        t.replace_offsets(at, end, &c.to_string().repeat(count));
+        self.end_change(t);
+        t.set_cursor_offset(prev_grapheme(t, at + c.len_utf8() * count));
+        self.reset();
+    }
+
+    fn text_object(&mut self, inner: bool, c: char, t: &mut dyn KeymapTarget) {
+        let at = self.head(t);
+        let text = t.text();
+        let range = match c {
+            'w' | 'W' => word_object(&text, at, inner, c == 'W'),
+            '"' | '\'' | '`' => quote_object(&text, at, c, inner),
+            '(' | ')' | 'b' => bracket_object(&text, at, '(', ')', inner),
+            '[' | ']' => bracket_object(&text, at, '[', ']', inner),
+            '{' | '}' | 'B' => bracket_object(&text, at, '{', '}', inner),
+            '<' | '>' => bracket_object(&text, at, '<', '>', inner),
+            _ => None,
+        };
+        drop(text);
+        let operator = self.operator.take();
+        self.reset();
+        let Some((start, end)) = range else {
+            return;
+        };
+        if let Some((op, _)) = operator {
+            self.apply_charwise(op, start, end, t);
+        } else if self.is_visual() && end > start {
+            self.mode = KeymapMode::Visual;
+            self.visual_anchor = start;
+            self.visual_head = prev_grapheme(t, end);
+            self.update_visual(t);
+        }
+    }
+
+    fn repeat(&mut self, count: usize, t: &mut dyn KeymapTarget) {
+        let keys = self.last_change.clone();
+        self.replaying = true;
+        for _ in 0..count {
+            for key in &keys {
+                self.handle_key(key, t);
+            }
+        }
+        self.replaying = false;
+        self.changed = false;
+    }
+
+    // -- Visual mode ----------------------------------------------------------
+
+    /// Visual selection as `(start, end, linewise)`, `end` exclusive.
+    fn visual_range(&self, t: &dyn KeymapTarget) -> (usize, usize, bool) {
+        let start = self.visual_anchor.min(self.visual_head);
+        let end = self.visual_anchor.max(self.visual_head);
+        if self.mode == KeymapMode::VisualLine {
+            (t.line_bounds(start).start, t.line_bounds(end).end, true)
+        } else {
+            (start, next_grapheme(t, end), false)
+        }
+    }
+
+    fn update_visual(&mut self, t: &mut dyn KeymapTarget) {
+        let (anchor, head) = (self.visual_anchor, self.visual_head);
+        let (from, to) = if self.mode == KeymapMode::VisualLine {
+            if head >= anchor {
+                (t.line_bounds(anchor).start, t.line_bounds(head).end)
+            } else {
+                (t.line_bounds(anchor).end, t.line_bounds(head).start)
+            }
+        } else if head >= anchor {
+            (anchor, next_grapheme(t, head))
+        } else {
+            (next_grapheme(t, anchor), head)
+        };
+        t.select_offsets(from, to);
+    }
+
+    fn exit_visual(&mut self, t: &mut dyn KeymapTarget) {
+        self.mode = KeymapMode::Normal;
+        let at = clamp_normal(t, self.visual_head.min(t.len()));
+        t.set_cursor_offset(at);
+    }
+
+    fn visual_operator(&mut self, op: Operator, linewise: bool, t: &mut dyn KeymapTarget) {
+        let (start, end, line_mode) = self.visual_range(t);
+        let last = self.visual_anchor.max(self.visual_head);
+        self.exit_visual(t);
+        if line_mode || linewise {
+            self.apply_linewise(op, start, last, t);
+        } else {
+            self.apply_charwise(op, start, end, t);
+        }
+    }
+
+    fn visual_paste(&mut self, t: &mut dyn KeymapTarget) {
+        let Some(reg) = self.take_register() else {
+            return;
+        };
+        let (start, end, linewise) = self.visual_range(t);
+        let replaced = t.slice(start..end).into_owned();
+        self.exit_visual(t);
+        let body = if linewise {
+            reg.text.strip_suffix('\n').unwrap_or(&reg.text)
+        } else {
+            &reg.text
+        };
+        self.begin_change(t);
+        t.replace_offsets(start, end, body);
+        self.end_change(t);
+        self.store_register(Operator::Delete, replaced, linewise);
+    }
+}
+
+impl VimKeymap {
+    fn handle_key_at(
+        &mut self,
+        key: &KeyEvent,
+        target: &mut dyn KeymapTarget,
+        now: Instant,
+    ) -> KeymapResult {
+        // A `g` left waiting past the sequence timeout is dropped along with
+        // the rest of the partial command, so this key starts afresh.
+        if self.sequences.check_timeout(now).is_some() {
+            self.reset();
+        }
+        // Shift is already reflected in the character.
+        let mut key = *key;
+        if matches!(key.code, KeyCode::Char(_)) {
+            key.modifiers.remove(Modifiers::SHIFT);
+        }
+        if self.mode == KeymapMode::Insert {
+            self.insert_key(&key, target)
+        } else {
+            self.command_key(&key, target, now)
+        }
+    }
+}
+
+impl Keymap for VimKeymap {
+    fn handle_key(&mut self, key: &KeyEvent, target: &mut dyn KeymapTarget) -> KeymapResult {
+        self.handle_key_at(key, target, Instant::now())
+    }
+
+    fn mode(&self) -> KeymapMode {
+        self.mode
+    }
+
+    fn pending_keys(&self) -> String {
+        self.typed.clone()
+    }
+
+    fn clone_box(&self) -> Box<dyn Keymap> {
+        Box::new(self.clone())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Text helpers
+// ---------------------------------------------------------------------------
+
+/// In normal mode the cursor sits on a character, not past the line end.
+fn clamp_normal(t: &dyn KeymapTarget, at: usize) -> usize {
+    let line = t.line_bounds(at);
+    if at == line.end && at > line.start {
+        prev_grapheme(t, at)
+    } else {
+        at
+    }
+}
+
+fn clamp_cursor(t: &mut dyn KeymapTarget) {
+    if t.selection_offsets().is_some() {
+        return;
+    }
+    let at = t.cursor_offset();
+    let clamped = clamp_normal(t, at);
+    if clamped != at {
+        t.set_cursor_offset(clamped);
+    }
+}
+
+/// Character class at `at`: 0 whitespace/end, 1 word, 2 punctuation.
+///
+/// With `big`, every non-blank is a word character.
+fn class_at(text: &str, at: usize, big: bool) -> u8 {
+    match text[at..].chars().next() {
+        None => 0,
+        Some(c) if c.is_whitespace() => 0,
+        Some(c) if big || is_word_char(c) => 1,
+        Some(_) => 2,
+    }
+}
+
+/// Whether `at` starts an empty line.
+fn is_empty_line(text: &str, at: usize) -> bool {
+    text[at..].starts_with('\n') && (at == 0 || text[..at].ends_with('\n'))
+}
+
+/// `w`: start of the next word (empty lines count as words).
+fn word_forward(text: &str, at: usize, big: bool) -> usize {
+    let class = class_at(text, at, big);
+    let mut p = at;
+    if class != 0 {
+        while p < text.len() && class_at(text, p, big) == class {
+            p = next_boundary(text, p);
+        }
+    }
+    while p < text.len() && class_at(text, p, big) == 0 {
+        if p != at && is_empty_line(text, p) {
+            break;
+        }
+        p = next_boundary(text, p);
+    }
+    p
+}
+
+/// Last character of the run containing `at`.
+fn run_end(text: &str, at: usize, big: bool) -> usize {
+    let class = class_at(text, at, big);
+    let mut p = at;
+    loop {
+        let next = next_boundary(text, p);
+        if next >= text.len() || class_at(text, next, big) != class {
+            return p;
+        }
+        p = next;
+    }
+}
+
+/// `e`: end of the current or next word.
+fn word_end(text: &str, at: usize, big: bool) -> usize {
+    let mut p = next_boundary(text, at);
+    while p < text.len() && class_at(text, p, big) == 0 {
+        p = next_boundary(text, p);
+    }
+    if p >= text.len() {
+        return prev_boundary(text, text.len()).max(at);
+    }
+    run_end(text, p, big)
+}
+
+/// `b`: start of the current or previous word.
+fn word_back(text: &str, at: usize, big: bool) -> usize {
+    if at == 0 {
+        return 0;
+    }
+    let mut p = prev_boundary(text, at);
+    while p > 0 && class_at(text, p, big) == 0 {
+        if is_empty_line(text, p) {
+            return p;
+        }
+        p = prev_boundary(text, p);
+    }
+    let class = class_at(text, p, big);
+    while p > 0 {
+        let prev = prev_boundary(text, p);
+        if class_at(text, prev, big) != class {
+            break;
+        }
+        p = prev;
+    }
+    p
+}
+
+/// `ge`: end of the previous word.
+fn word_end_back(text: &str, at: usize, big: bool) -> usize {
+    let class = class_at(text, at, big);
+    let mut p = at;
+    if class != 0 {
+        while p > 0 && class_at(text, p, big) == class {
+            p = prev_boundary(text, p);
+        }
+        if class_at(text, p, big) == class {
+            return p;
+        }
+    }
+    while p > 0 && class_at(text, p, big) == 0 {
+        p = prev_boundary(text, p);
+    }
+    p
+}
+
+/// `f F t T`: the `count`-th `c` on the current line.
+fn find_in_line(text: &str, at: usize, kind: Find, c: char, count: usize) -> Option<usize> {
+    let n = count.max(1) - 1;
+    match kind {
+        Find::Forward | Find::TillForward => {
+            let from = next_boundary(text, at);
+            let end = line_end(text, at).max(from);
+            let (i, _) = text[from..end].match_indices(c).nth(n)?;
+            let p = from + i;
+            Some(if kind == Find::Forward {
+                p
+            } else {
+                prev_boundary(text, p)
+            })
+        }
+        Find::Backward | Find::TillBackward => {
+            let start = line_start(text, at);
+            let (i, _) = text[start..at].rmatch_indices(c).nth(n)?;
+            let p = start + i;
+            Some(if kind == Find::Backward {
+                p
+            } else {
+                next_boundary(text, p)
+            })
+        }
+    }
+}
+
+fn toggle_case(s: &str) -> String {
+    s.chars()
+        .flat_map(|c| {
+            if c.is_uppercase() {
+                c.to_lowercase().collect::<Vec<_>>()
+            } else {
+                c.to_uppercase().collect()
+            }
+        })
+        .collect()
+}
+
+/// `iw`/`aw` (and `iW`/`aW`) on the current line.
+fn word_object(text: &str, at: usize, inner: bool, big: bool) -> Option<(usize, usize)> {
+    let (first, last) = (line_start(text, at), line_end(text, at));
+    if at >= last {
+        return None;
+    }
+    let class = class_at(text, at, big);
+    let extend_back = |mut p: usize, class: u8| {
+        while p > first && class_at(text, prev_boundary(text, p), big) == class {
+            p = prev_boundary(text, p);
+        }
+        p
+    };
+    let extend_forward = |mut p: usize, class: u8| {
+        while p < last && class_at(text, p, big) == class {
+            p = next_boundary(text, p);
+        }
+        p
+    };
+    let start = extend_back(at, class);
+    let end = extend_forward(at, class);
+    if inner {
+        return Some((start, end));
+    }
+    if class == 0 {
+        let next = class_at(text, end, big);
+        return Some((start, extend_forward(end, next)));
+    }
+    let trailing = extend_forward(end, 0);
+    if trailing > end {
+        Some((start, trailing))
+    } else {
+        Some((extend_back(start, 0), end))
+    }
+}
+
+/// `i"`/`a"` on the current line: the quoted string around or after `at`.
+fn quote_object(text: &str, at: usize, quote: char, inner: bool) -> Option<(usize, usize)> {
+    let (first, last) = (line_start(text, at), line_end(text, at));
+    let quotes: Vec<usize> = text[first..last]
+        .match_indices(quote)
+        .map(|(i, _)| first + i)
+        .collect();
+    let (open, close) = quotes
+        .chunks_exact(2)
+        .map(|pair| (pair[0], pair[1]))
+        .find(|&(open, close)| at <= close || open > at)?;
+    let width = quote.len_utf8();
+    Some(if inner {
+        (open + width, close)
+    } else {
+        (open, close + width)
+    })
+}
+
+/// `i(`/`a(` and friends: the innermost bracket pair enclosing `at`.
+fn bracket_object(
+    text: &str,
+    at: usize,
+    open: char,
+    close: char,
+    inner: bool,
+) -> Option<(usize, usize)> {
+    let mut depth = 0usize;
+    let mut start = None;
+    let upto = next_boundary(text, at);
+    for (i, c) in text[..upto].char_indices().rev() {
+        if c == close && i != at {
+            depth += 1;
+        } else if c == open {
+            if depth == 0 {
+                start = Some(i);
+                break;
+            }
+            depth -= 1;
+        }
+    }
+    let start = start?;
+    let mut depth = 0usize;
+    let mut end = None;
+    for (i, c) in text[start + open.len_utf8()..].char_indices() {
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            if depth == 0 {
+                end = Some(start + open.len_utf8() + i);
+                break;
+            }
+            depth -= 1;
+        }
+    }
+    let end = end?;
+    Some(if inner {
+        (start + open.len_utf8(), end)
+    } else {
+        (start, end + close.len_utf8())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ftui_text::editor::Editor;
+
+    /// Feed keys written vim-style: `<Esc>`, `<CR>`, `<C-r>`, else literal chars.
+    fn feed(vim: &mut VimKeymap, ed: &mut Editor, keys: &str) {
+        let mut rest = keys;
+        while !rest.is_empty() {
+            let (key, len) = if let Some(tail) = rest.strip_prefix("<Esc>") {
+                (KeyEvent::new(KeyCode::Escape), rest.len() - tail.len())
+            } else if let Some(tail) = rest.strip_prefix("<CR>") {
+                (KeyEvent::new(KeyCode::Enter), rest.len() - tail.len())
+            } else if let Some(tail) = rest.strip_prefix("<C-r>") {
+                (
+                    KeyEvent::new(KeyCode::Char('r')).with_modifiers(Modifiers::CTRL),
+                    rest.len() - tail.len(),
+                )
+            } else {
+                let c = rest.chars().next().unwrap();
+                (KeyEvent::new(KeyCode::Char(c)), c.len_utf8())
+            };
+            vim.handle_key(&key, ed);
+            rest = &rest[len..];
+        }
+    }
+
+    fn run(text: &str, keys: &str) -> (String, usize) {
+        let mut ed = Editor::with_text(text);
+        ed.move_to_document_start();
+        let mut vim = VimKeymap::new();
+        feed(&mut vim, &mut ed, keys);
+        let at = KeymapTarget::cursor_offset(&ed);
+        (Editor::text(&ed), at)
+    }
+
+    #[test]
+    fn motions_move_cursor() {
+        assert_eq!(run("foo bar baz", "w").1, 4);
+        assert_eq!(run("foo bar baz", "2w").1, 8);
+        assert_eq!(run("foo bar baz", "e").1, 2);
+        assert_eq!(run("foo bar baz", "$").1, 10);
+        assert_eq!(run("foo bar baz", "$b").1, 8);
+        assert_eq!(run("foo bar baz", "$ge").1, 6);
+        assert_eq!(run("foo.bar", "w").1, 3);
+        assert_eq!(run("foo.bar x", "W").1, 8);
+        assert_eq!(run("  foo", "$^").1, 2);
+        assert_eq!(run("abcabc", "fc").1, 2);
+        assert_eq!(run("abcabc", "fc;").1, 5);
+        assert_eq!(run("abcabc", "tc").1, 1);
+        assert_eq!(run("abcabc", "$Fa").1, 3);
+    }
+
+    #[test]
+    fn line_motions_keep_column() {
+        assert_eq!(run("abcd\nx\nabcd", "3ljj").1, 10);
+        assert_eq!(run("a\nb\nc", "G").1, 4);
+        assert_eq!(run("a\nb\nc", "Ggg").1, 0);
+        assert_eq!(run("a\nb\nc", "2G").1, 2);
+        assert_eq!(run("a\n  b", "<CR>").1, 4);
+    }
+
+    #[test]
+    fn g_prefix_expires_after_timeout() {
+        let mut ed = Editor::with_text("a\nb\nc");
+        let mut vim = VimKeymap::new();
+        let g = KeyEvent::new(KeyCode::Char('g'));
+        let start = Instant::now();
+        feed(&mut vim, &mut ed, "G");
+        vim.handle_key_at(&g, &mut ed, start);
+        assert_eq!(vim.pending_keys(), "g");
+        // The second `g` arrives too late, so it starts a new prefix.
+        let late = start + KeySequenceConfig::default().sequence_timeout * 2;
+        vim.handle_key_at(&g, &mut ed, late);
+        assert_eq!(vim.pending_keys(), "g");
+        assert_eq!(KeymapTarget::cursor_offset(&ed), 4);
+        vim.handle_key_at(&g, &mut ed, late);
+        assert_eq!(KeymapTarget::cursor_offset(&ed), 0);
+    }
+
+    #[test]
+    fn l_stops_on_last_character() {
+        assert_eq!(run("ab", "lll").1, 1);
+    }
+
+    #[test]
+    fn delete_operators() {
+        assert_eq!(run("foo bar baz", "dw").0, "bar baz");
+        assert_eq!(run("foo bar baz", "d2w").0, "baz");
+        assert_eq!(run("foo bar baz", "2dw").0, "baz");
+        assert_eq!(run("foo bar baz", "de").0, " bar baz");
+        assert_eq!(run("foo bar baz", "wd$").0, "foo ");
+        assert_eq!(run("foo bar baz", "wD").0, "foo ");
+        assert_eq!(run("abc", "x").0, "bc");
+        assert_eq!(run("abc", "3x").0, "");
+        assert_eq!(run("abcabc", "dtc").0, "cabc");
+        assert_eq!(run("abcabc", "dfc").0, "abc");
+    }
+
+    #[test]
+    fn dw_stops_at_line_end() {
+        assert_eq!(run("foo\nbar", "dw").0, "\nbar");
+    }
+
+    #[test]
+    fn linewise_delete_and_paste() {
+        assert_eq!(run("a\nb\nc", "dd").0, "b\nc");
+        assert_eq!(run("a\nb\nc", "2dd").0, "c");
+        assert_eq!(run("a\nb\nc", "Gdd").0, "a\nb");
+        assert_eq!(run("a\nb\nc", "djp").0, "c\na\nb");
+        assert_eq!(run("a\nb\nc", "yyjp").0, "a\nb\na\nc");
+        assert_eq!(run("a\nb\nc", "yyjP").0, "a\na\nb\nc");
+    }
+
+    #[test]
+    fn charwise_paste() {
+        assert_eq!(run("ab", "xp").0, "ba");
+        assert_eq!(run("ab", "x2p").0, "baa");
+        assert_eq!(run("abc", "ywP").0, "abcabc");
+    }
+
+    #[test]
+    fn change_is_one_undo_step_and_repeats() {
+        let mut ed = Editor::with_text("foo foo foo");
+        ed.move_to_document_start();
+        let mut vim = VimKeymap::new();
+        feed(&mut vim, &mut ed, "cwbar<Esc>");
+        assert_eq!(vim.mode(), KeymapMode::Normal);
+        assert_eq!(Editor::text(&ed), "bar foo foo");
+        feed(&mut vim, &mut ed, "w.w.");
+        assert_eq!(Editor::text(&ed), "bar bar bar");
+        feed(&mut vim, &mut ed, "u");
+        assert_eq!(Editor::text(&ed), "bar bar foo");
+        feed(&mut vim, &mut ed, "uu");
+        assert_eq!(Editor::text(&ed), "foo foo foo");
+        feed(&mut vim, &mut ed, "<C-r>");
+        assert_eq!(Editor::text(&ed), "bar foo foo");
+    }
+
+    #[test]
+    fn dot_repeats_delete_with_count() {
+        assert_eq!(run("abcdef", "x.").0, "cdef");
+        assert_eq!(run("abcdef", "x3.").0, "ef");
+        assert_eq!(run("a b c d", "dw.").0, "c d");
+    }
+
+    #[test]
+    fn insert_entries() {
+        assert_eq!(run("abc", "iX<Esc>").0, "Xabc");
+        assert_eq!(run("abc", "aX<Esc>").0, "aXbc");
+        assert_eq!(run("  abc", "IX<Esc>").0, "  Xabc");
+        assert_eq!(run("abc", "AX<Esc>").0, "abcX");
+        assert_eq!(run("a\nb", "oX<Esc>").0, "a\nX\nb");
+        assert_eq!(run("a\nb", "jOX<Esc>").0, "a\nX\nb");
+        assert_eq!(run("abc", "ccX<Esc>").0, "X");
+        assert_eq!(run("abc", "sX<Esc>").0, "Xbc");
+        assert_eq!(run("ab cd", "wCX<Esc>").0, "ab X");
+    }
+
+    #[test]
+    fn esc_leaves_cursor_on_last_inserted_char() {
+        assert_eq!(run("", "iab<Esc>").1, 1);
+    }
+
+    #[test]
+    fn text_objects() {
+        assert_eq!(run("foo bar baz", "wdiw").0, "foo  baz");
+        assert_eq!(run("foo bar baz", "wdaw").0, "foo baz");
+        assert_eq!(run("say \"hi there\" ok", "fhdi\"").0, "say \"\" ok");
+        assert_eq!(run("say \"hi\" ok", "da\"").0, "say  ok");
+        assert_eq!(run("f(a, (b), c)", "fadi(").0, "f()");
+        assert_eq!(run("f(a, (b), c)", "fbda(").0, "f(a, , c)");
+        assert_eq!(run("x { y }", "fyciBz<Esc>").0, "x {z}");
+    }
+
+    #[test]
+    fn registers() {
+        let mut ed = Editor::with_text("one two");
+        ed.move_to_document_start();
+        let mut vim = VimKeymap::new();
+        feed(&mut vim, &mut ed, "\"ayw");
+        assert_eq!(vim.register('a'), Some("one "));
+        assert_eq!(vim.register('0'), Some("one "));
+        feed(&mut vim, &mut ed, "w\"Ayw");
+        assert_eq!(vim.register('a'), Some("one two"));
+        feed(&mut vim, &mut ed, "\"_dw");
+        assert_eq!(vim.register('"'), Some("two"));
+        feed(&mut vim, &mut ed, "\"ap");
+        assert_eq!(Editor::text(&ed), "one one two");
+    }
+
+    #[test]
+    fn visual_mode() {
+        let mut ed = Editor::with_text("hello world");
+        ed.move_to_document_start();
+        let mut vim = VimKeymap::new();
+        feed(&mut vim, &mut ed, "vl");
+        assert_eq!(vim.mode(), KeymapMode::Visual);
+        assert_eq!(ed.selected_text().as_deref(), Some("he"));
+        feed(&mut vim, &mut ed, "e");
+        assert_eq!(ed.selected_text().as_deref(), Some("hello"));
+        feed(&mut vim, &mut ed, "d");
+        assert_eq!(vim.mode(), KeymapMode::Normal);
+        assert_eq!(Editor::text(&ed), " world");
+        assert_eq!(run("ab\ncd\nef", "Vjd").0, "ef");
+        assert_eq!(run("abc", "vly$p").0, "abcab");
+        assert_eq!(run("abc def", "wviwU").0, "abc DEF");
+        assert_eq!(run("abc def", "viwyw").1, 4);
+    }
+
+    #[test]
+    fn visual_esc_clears_selection() {
+        let mut ed = Editor::with_text("abc");
+        ed.move_to_document_start();
+        let mut vim = VimKeymap::new();
+        feed(&mut vim, &mut ed, "vl<Esc>");
+        assert_eq!(vim.mode(), KeymapMode::Normal);
+        assert!(ed.selection().is_none());
+        assert_eq!(KeymapTarget::cursor_offset(&ed), 1);
+    }
+
+    #[test]
+    fn misc_commands() {
+        assert_eq!(run("a\n  b\nc", "J").0, "a b\nc");
+        assert_eq!(run("a\nb\nc", "3J").0, "a b c");
+        assert_eq!(run("abc", "~~").0, "ABc");
+        assert_eq!(run("abc", "2rx").0, "xxc");
+        assert_eq!(run("abc", "5rx").0, "abc");
+        assert_eq!(run("x", "dd").0, "");
+    }
+
+    #[test]
+    fn pending_keys_and_mode() {
+        let mut ed = Editor::with_text("abc");
+        let mut vim = VimKeymap::new();
+        feed(&mut vim, &mut ed, "\"a2d");
+        assert_eq!(vim.pending_keys(), "\"a2d");
+        feed(&mut vim, &mut ed, "<Esc>");
+        assert_eq!(vim.pending_keys(), "");
+        feed(&mut vim, &mut ed, "g");
+        assert_eq!(vim.pending_keys(), "g");
+        feed(&mut vim, &mut ed, "q");
+        assert_eq!(vim.pending_keys(), "");
+        feed(&mut vim, &mut ed, "i");
+        assert_eq!(vim.mode(), KeymapMode::Insert);
+        assert_eq!(vim.mode().label(), "INSERT");
+    }
+
+    #[test]
+    fn idle_escape_and_unknown_keys_fall_through() {
+        let mut ed = Editor::with_text("abc");
+        let mut vim = VimKeymap::new();
+        let esc = KeyEvent::new(KeyCode::Escape);
+        assert_eq!(vim.handle_key(&esc, &mut ed), KeymapResult::Unhandled);
+        let page = KeyEvent::new(KeyCode::PageDown);
+        assert_eq!(vim.handle_key(&page, &mut ed), KeymapResult::Unhandled);
+        let q = KeyEvent::new(KeyCode::Char('Q'));
+        assert_eq!(vim.handle_key(&q, &mut ed), KeymapResult::Handled);
+        assert_eq!(Editor::text(&ed), "abc");
+    }
+}
@@ -132,11 +132,16 @@ pub mod badge;
 pub mod block;
 pub mod borders;
 pub mod cached;
+/// Month-grid calendar widget and civil date type.
+pub mod calendar;
 pub mod columns;
 pub mod command_palette;
+pub mod completion;
 pub mod constraint_overlay;
 #[cfg(feature = "debug-overlay")]
 pub mod debug_overlay;
+/// Unified and side-by-side text diff viewer.
+pub mod diff_view;
 /// Drag-and-drop protocol: [`Draggable`](drag::Draggable) sources, [`DropTarget`](drag::DropTarget) targets, and [`DragPayload`](drag::DragPayload).
 pub mod drag;
 pub mod emoji;
@@ -151,6 +156,8 @@ pub mod group;
 pub mod height_predictor;
 pub mod help;
 pub mod help_registry;
+/// Virtualized hex/binary viewer.
+pub mod hex_view;
 /// Utility-based keybinding hint ranking with Bayesian posteriors.
 pub mod hint_ranker;
 /// Undo/redo history panel widget for displaying command history.
@@ -160,6 +167,8 @@ pub mod input;
 pub mod inspector;
 pub mod json_view;
 pub mod keyboard_drag;
+/// Pluggable vim and emacs editing keymaps for text widgets.
+pub mod keymap;
 pub mod layout;
 pub mod layout_debugger;
 pub mod list;
@@ -176,6 +185,8 @@ pub mod mouse;
 pub mod notification_queue;
 pub mod padding;
 pub mod paginator;
+/// Split-pane container driven by a pane split tree.
+pub mod pane_container;
 pub mod panel;
 /// Multi-line styled text paragraph widget.
 pub mod paragraph;
@@ -183,6 +194,7 @@ pub mod pretty;
 pub mod progress;
 pub mod rule;
 pub mod scrollbar;
+pub mod sheet;
 pub mod sparkline;
 pub mod spinner;
 /// Opt-in persistable state trait for widgets.
@@ -221,6 +233,7 @@ pub use log_ring::LogRing;
 pub use log_viewer::{LogViewer, LogViewerState, LogWrapMode, SearchConfig, SearchMode};
 pub use paginator::{Paginator, PaginatorMode};
 pub use panel::Panel;
+pub use sheet::SheetStyled;
 pub use sparkline::Sparkline;
 pub use status_line::{StatusItem, StatusLine};
 pub use virtualized::{
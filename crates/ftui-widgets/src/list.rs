@@ -7,13 +7,14 @@
 use crate::block::Block;
 use crate::measurable::{MeasurableWidget, SizeConstraints};
 use crate::mouse::MouseResult;
+use crate::sheet::{self, SheetStyled};
 use crate::stateful::{StateKey, Stateful};
 use crate::undo_support::{ListUndoExt, UndoSupport, UndoWidgetId};
 use crate::{StatefulWidget, Widget, draw_text_span, draw_text_span_with_link, set_style_area};
 use ftui_core::event::{MouseButton, MouseEvent, MouseEventKind};
 use ftui_core::geometry::{Rect, Size};
 use ftui_render::frame::{Frame, HitId, HitRegion};
-use ftui_style::Style;
+use ftui_style::{SelectorSheet, Style, StyleNode, WidgetState};
 use ftui_text::{Text, display_width};
 
 /// A single item in a list.
@@ -483,6 +484,36 @@ impl<'a> Widget for List<'a> {
     }
 }
 
+impl<'a> SheetStyled for List<'a> {
+    const TYPE_NAME: &'static str = "List";
+
+    fn styled_by(mut self, sheet: &SelectorSheet, path: &[StyleNode<'_>]) -> Self {
+        let item = StyleNode::new("ListItem");
+        self.style = self.style.merge(&sheet.resolve(path));
+        self.highlight_style = self.highlight_style.merge(&sheet::part_style(
+            sheet,
+            path,
+            item.state(WidgetState::SELECTED),
+        ));
+        self.hover_style = self.hover_style.merge(&sheet::part_style(
+            sheet,
+            path,
+            item.state(WidgetState::HOVER),
+        ));
+        let item_style = sheet::part_style(sheet, path, item);
+        for list_item in &mut self.items {
+            list_item.style = list_item.style.merge(&item_style);
+        }
+        self.block = self.block.map(|block| {
+            block.styled_by(
+                sheet,
+                &sheet::child_path(path, StyleNode::new(Block::TYPE_NAME)),
+            )
+        });
+        self
+    }
+}
+
 impl MeasurableWidget for ListItem<'_> {
     fn measure(&self, _available: Size) -> SizeConstraints {
         // ListItem is a single line of text with optional marker
@@ -1374,4 +1405,29 @@ mod tests {
         let result = state.handle_mouse(&event, None, HitId::new(1), 10);
         assert_eq!(result, MouseResult::Ignored);
     }
+
+    #[test]
+    fn styled_by_resolves_item_parts() {
+        use ftui_render::cell::PackedRgba;
+
+        let sheet = SelectorSheet::parse(
+            "#files ListItem { fg: #c0c0c0; } \
+             #files ListItem:selected { bg: #ffff00; attrs: bold; } \
+             ListItem:hover { bg: #333333; }",
+        )
+        .unwrap();
+        let path = [StyleNode::new(List::TYPE_NAME).id("files")];
+        let list = List::new(["a", "b"])
+            .highlight_style(Style::new().bg(PackedRgba::rgb(0, 0, 255)))
+            .styled_by(&sheet, &path);
+        assert!(
+            list.items
+                .iter()
+                .all(|item| item.style.fg == Some(PackedRgba::rgb(192, 192, 192)))
+        );
+        // The builder's highlight background wins; the sheet adds bold.
+        assert_eq!(list.highlight_style.bg, Some(PackedRgba::rgb(0, 0, 255)));
+        assert!(list.highlight_style.has_attr(ftui_style::StyleFlags::BOLD));
+        assert_eq!(list.hover_style.bg, Some(PackedRgba::rgb(51, 51, 51)));
+    }
 }
@@ -230,6 +230,30 @@ impl Default for SizeConstraints {
     }
 }
 
+/// Bridge to [`ftui_layout::FlexBox`] measurers.
+///
+/// ```ignore
+/// let rects = flexbox.split_with_measurer(area, |i, available| {
+///     tags[i].measure(available).into()
+/// });
+/// ```
+impl From<SizeConstraints> for ftui_layout::IntrinsicSize {
+    fn from(c: SizeConstraints) -> Self {
+        ftui_layout::IntrinsicSize {
+            width: ftui_layout::LayoutSizeHint {
+                min: c.min.width,
+                preferred: c.preferred.width,
+                max: c.max.map(|m| m.width),
+            },
+            height: ftui_layout::LayoutSizeHint {
+                min: c.min.height,
+                preferred: c.preferred.height,
+                max: c.max.map(|m| m.height),
+            },
+        }
+    }
+}
+
 /// A widget that can report its intrinsic dimensions.
 ///
 /// Implement this trait for widgets whose size depends on their content.
@@ -463,6 +487,25 @@ mod tests {
         assert_eq!(c.preferred, Size::new(12, 6));
     }
 
+    #[test]
+    fn size_constraints_into_intrinsic_size() {
+        let c = SizeConstraints {
+            min: Size::new(5, 2),
+            preferred: Size::new(10, 5),
+            max: Some(Size::new(20, 10)),
+        };
+        let hint: ftui_layout::IntrinsicSize = c.into();
+        assert_eq!(hint.width.min, 5);
+        assert_eq!(hint.width.preferred, 10);
+        assert_eq!(hint.width.max, Some(20));
+        assert_eq!(hint.height.min, 2);
+        assert_eq!(hint.height.preferred, 5);
+        assert_eq!(hint.height.max, Some(10));
+
+        let unbounded: ftui_layout::IntrinsicSize = SizeConstraints::ZERO.into();
+        assert_eq!(unbounded, ftui_layout::IntrinsicSize::ZERO);
+    }
+
     // --- MeasurableWidget default implementation tests ---
 
     struct PlainWidget;
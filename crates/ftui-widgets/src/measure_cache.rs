@@ -63,6 +63,12 @@
 //! }
 //! ```
 //!
+//! ## Theme Changes (Automatic)
+//!
+//! Theme swaps advance [`ftui_style::theme_epoch()`]; the cache notices on the
+//! next lookup and invalidates itself, as widgets may size themselves from
+//! theme-dependent content.
+//!
 //! ## Content-Addressed (Automatic)
 //!
 //! Use content hash as widget ID for automatic invalidation:
@@ -189,6 +195,7 @@ pub struct CacheStats {
 pub struct MeasureCache {
     entries: HashMap<CacheKey, CacheEntry>,
     generation: u64,
+    theme_epoch: u64,
     max_entries: usize,
     hits: u64,
     misses: u64,
@@ -213,6 +220,7 @@ impl MeasureCache {
         Self {
             entries: HashMap::with_capacity(max_entries),
             generation: 0,
+            theme_epoch: ftui_style::theme_epoch(),
             max_entries,
             hits: 0,
             misses: 0,
@@ -254,6 +262,12 @@ impl MeasureCache {
             available,
         };
 
+        let theme_epoch = ftui_style::theme_epoch();
+        if theme_epoch != self.theme_epoch {
+            self.theme_epoch = theme_epoch;
+            self.invalidate_all();
+        }
+
         // Check for existing valid entry
         if let Some(entry) = self.entries.get_mut(&key)
             && entry.generation == self.generation
@@ -515,6 +529,27 @@ mod tests {
         assert_eq!(call_count, 2); // Re-computed after invalidation
     }
 
+    #[test]
+    fn theme_epoch_change_invalidates() {
+        let mut cache = MeasureCache::new(100);
+        let widget_id = WidgetId(42);
+        let available = Size::new(80, 24);
+
+        let mut call_count = 0;
+        let mut compute = || {
+            call_count += 1;
+            SizeConstraints::ZERO
+        };
+
+        cache.get_or_compute(widget_id, available, &mut compute);
+        // Simulate a theme swap since the entry was cached.
+        cache.theme_epoch = cache.theme_epoch.wrapping_sub(1);
+        cache.get_or_compute(widget_id, available, &mut compute);
+        cache.get_or_compute(widget_id, available, &mut compute);
+
+        assert_eq!(call_count, 2);
+    }
+
     #[test]
     fn widget_specific_invalidation() {
         let mut cache = MeasureCache::new(100);
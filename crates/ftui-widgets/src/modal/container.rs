@@ -15,6 +15,7 @@ use ftui_core::event::{
     Event, KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind,
 };
 use ftui_core::geometry::{Rect, Size};
+use ftui_layout::{AnchorAlign, AnchorPlacement, place_within};
 use ftui_render::cell::PackedRgba;
 use ftui_render::frame::{Frame, HitData, HitId, HitRegion};
 use ftui_style::Style;
@@ -160,6 +161,12 @@ pub enum ModalPosition {
         x: u16,
         y: u16,
     },
+    /// Popover-style placement next to an anchor rect, flipping and
+    /// shifting to stay within the area.
+    Anchored {
+        anchor: Rect,
+        placement: AnchorPlacement,
+    },
 }
 
 impl ModalPosition {
@@ -170,18 +177,19 @@ impl ModalPosition {
         let max_y = base_y + (area.height as i32 - size.height as i32);
 
         let (mut x, mut y) = match self {
-            Self::Center => (
-                base_x + (area.width as i32 - size.width as i32) / 2,
-                base_y + (area.height as i32 - size.height as i32) / 2,
-            ),
+            Self::Center => {
+                return place_within(area, size, AnchorAlign::Center, AnchorAlign::Center, 0);
+            }
+            Self::TopCenter { margin } => {
+                return place_within(area, size, AnchorAlign::Center, AnchorAlign::Start, margin);
+            }
+            Self::Anchored { anchor, placement } => {
+                return placement.solve(anchor, size, area).rect;
+            }
             Self::CenterOffset { x, y } => (
                 base_x + (area.width as i32 - size.width as i32) / 2 + x as i32,
                 base_y + (area.height as i32 - size.height as i32) / 2 + y as i32,
             ),
-            Self::TopCenter { margin } => (
-                base_x + (area.width as i32 - size.width as i32) / 2,
-                base_y + margin as i32,
-            ),
             Self::Custom { x, y } => (x as i32, y as i32),
         };
 
@@ -440,6 +448,7 @@ impl<C: Widget> Widget for Modal<C> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ftui_layout::AnchorSide;
     use ftui_render::frame::Frame;
     use ftui_render::grapheme_pool::GraphemePool;
 
@@ -724,6 +733,19 @@ mod tests {
         assert_eq!(rect, Rect::new(11, 4, 8, 4));
     }
 
+    #[test]
+    fn position_anchored_flips_within_area() {
+        let pos = ModalPosition::Anchored {
+            anchor: Rect::new(30, 17, 6, 1),
+            placement: AnchorPlacement::new(AnchorSide::Bottom),
+        };
+        let area = Rect::new(0, 0, 40, 20);
+        let size = Size::new(12, 5);
+        let rect = pos.resolve(area, size);
+        // No room below (20 - 18 = 2 rows), so it flips above the anchor.
+        assert_eq!(rect, Rect::new(27, 12, 12, 5));
+    }
+
     // --- ModalConfig tests ---
 
     #[test]
@@ -39,7 +39,7 @@
 //! ```
 
 use ftui_core::event::Event;
-use ftui_core::geometry::Rect;
+use ftui_core::geometry::{Rect, Size};
 use ftui_render::frame::{Frame, HitId};
 use ftui_style::Style;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -176,6 +176,16 @@ pub trait StackModal: Send {
     fn focusable_ids(&self) -> Option<Vec<ModalFocusId>> {
         None
     }
+
+    /// Place content of the clamped `size` within `screen`.
+    ///
+    /// Defaults to centering. Anchored overlays (e.g. a completion popup at
+    /// the text cursor) override this to position themselves.
+    fn content_area(&self, screen: Rect, size: Size) -> Rect {
+        let x = screen.x + (screen.width.saturating_sub(size.width)) / 2;
+        let y = screen.y + (screen.height.saturating_sub(size.height)) / 2;
+        Rect::new(x, y, size.width, size.height)
+    }
 }
 
 /// An active modal in the stack.
@@ -416,17 +426,14 @@ impl ModalStack {
 
             // Calculate modal content area
             let constraints = modal.modal.size_constraints();
-            let available = ftui_core::geometry::Size::new(screen.width, screen.height);
+            let available = Size::new(screen.width, screen.height);
             let size = constraints.clamp(available);
 
             if size.width == 0 || size.height == 0 {
                 continue;
             }
 
-            // Center the modal
-            let x = screen.x + (screen.width.saturating_sub(size.width)) / 2;
-            let y = screen.y + (screen.height.saturating_sub(size.height)) / 2;
-            let content_area = Rect::new(x, y, size.width, size.height);
+            let content_area = modal.modal.content_area(screen, size);
 
             // Render modal content
             modal.modal.render_content(content_area, frame);
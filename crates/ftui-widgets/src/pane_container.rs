@@ -0,0 +1,1418 @@
+#![forbid(unsafe_code)]
+
+//! Split-pane container driven by [`ftui_layout::PaneTree`].
+//!
+//! [`PaneContainer`] renders the leaves of a pane split tree side by side,
+//! draws a one-cell splitter between the two children of every split, and
+//! routes interaction through the canonical [`PaneSemanticInputEvent`] schema
+//! so the same resize logic can be replayed or driven by other adapters.
+//!
+//! # Interaction
+//!
+//! - Dragging a splitter with the left mouse button emits `PointerDown`,
+//!   `PointerMove` and `PointerUp` events; `Esc` during a drag emits `Cancel`
+//!   and restores the original ratio.
+//! - Scrolling over a splitter emits `WheelNudge`.
+//! - Keyboard chords use a tmux-style prefix (default `Ctrl+B`):
+//!
+//! | Chord | Action |
+//! |-------|--------|
+//! | `%` / `"` | Split focused pane side by side / stacked |
+//! | `x` | Close focused pane |
+//! | `z` | Toggle zoom of the focused pane |
+//! | `o` | Focus next pane |
+//! | Arrows | Focus neighbouring pane |
+//! | `Ctrl`/`Alt` + Arrows | Move the nearest splitter by 1 / 5 cells (`KeyboardResize`) |
+//!
+//! # Persistence
+//!
+//! [`PaneContainerState`] implements [`Stateful`](crate::stateful::Stateful),
+//! saving the [`PaneTreeSnapshot`] together with focus and zoom so layouts
+//! survive restarts through the runtime's state registry.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut state = PaneContainerState::new(PaneTree::singleton("editor"));
+//! state.split_focused(SplitAxis::Horizontal)?;
+//!
+//! PaneContainer::new()
+//!     .pane("editor", Paragraph::new("main.rs"))
+//!     .render(area, frame, &mut state);
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::focus::NavDirection;
+use crate::{StatefulWidget, Widget, apply_style};
+use ftui_core::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, Modifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use ftui_core::geometry::Rect;
+use ftui_layout::{
+    PaneCancelReason, PaneId, PaneLayout, PaneLeaf, PaneModifierSnapshot, PaneNodeKind,
+    PaneOperation, PaneOperationError, PaneOperationOutcome, PanePlacement, PanePointerButton,
+    PanePointerPosition, PaneResizeDirection, PaneResizeTarget, PaneSemanticInputEvent,
+    PaneSemanticInputEventKind, PaneSplitRatio, PaneTree, PaneTreeSnapshot, SplitAxis,
+};
+use ftui_render::cell::Cell;
+use ftui_render::frame::{Frame, HitId, HitRegion};
+use ftui_style::Style;
+
+/// Pointer id used for the terminal mouse (terminals expose a single pointer).
+const MOUSE_POINTER_ID: u32 = 1;
+
+/// Splitter movement for `Alt` + arrow chords.
+const COARSE_RESIZE_UNITS: u16 = 5;
+
+/// Geometry of one rendered splitter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaneSplitter {
+    /// Split node this splitter resizes.
+    pub split: PaneId,
+    /// Axis of the split (`Horizontal` draws a vertical line).
+    pub axis: SplitAxis,
+    /// Cells occupied by the splitter line.
+    pub line: Rect,
+    /// Full area of the split node.
+    pub span: Rect,
+}
+
+impl PaneSplitter {
+    /// Resize target addressing this splitter.
+    #[must_use]
+    pub const fn target(&self) -> PaneResizeTarget {
+        PaneResizeTarget {
+            split_id: self.split,
+            axis: self.axis,
+        }
+    }
+}
+
+/// A leaf pane as laid out by the last render.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaneView {
+    /// Leaf node id.
+    pub id: PaneId,
+    /// Content area, excluding splitter cells.
+    pub area: Rect,
+    /// Whether this pane has focus.
+    pub focused: bool,
+}
+
+/// Result of routing input through a [`PaneContainerState`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaneContainerAction {
+    /// Input not relevant to the container.
+    Ignored,
+    /// Input consumed without a visible change (prefix armed, drag started).
+    Handled,
+    /// Focus moved to the given leaf.
+    FocusChanged(PaneId),
+    /// The ratio of the given split changed.
+    Resized(PaneId),
+    /// A new leaf was created by splitting.
+    Split(PaneId),
+    /// The given node was closed.
+    Closed(PaneId),
+    /// Zoom was toggled; `Some` holds the maximized leaf.
+    ZoomChanged(Option<PaneId>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PaneDrag {
+    target: PaneResizeTarget,
+    pointer_id: u32,
+    original: PaneSplitRatio,
+    last: PanePointerPosition,
+}
+
+/// Interactive state for a [`PaneContainer`]: the pane tree plus focus,
+/// zoom, drag tracking, and the last solved layout.
+#[derive(Debug, Clone)]
+pub struct PaneContainerState {
+    tree: PaneTree,
+    focused: PaneId,
+    zoomed: Option<PaneId>,
+    prefix: KeyEvent,
+    prefix_armed: bool,
+    drag: Option<PaneDrag>,
+    sequence: u64,
+    operation_id: u64,
+    events: Vec<PaneSemanticInputEvent>,
+    area: Rect,
+    layout: Option<PaneLayout>,
+    splitters: Vec<PaneSplitter>,
+    views: Vec<PaneView>,
+    persistence_id: Option<String>,
+}
+
+impl Default for PaneContainerState {
+    fn default() -> Self {
+        Self::new(PaneTree::singleton("main"))
+    }
+}
+
+impl PaneContainerState {
+    /// Create state for an existing tree, focusing its first leaf.
+    #[must_use]
+    pub fn new(tree: PaneTree) -> Self {
+        let focused = first_leaf(&tree, tree.root());
+        Self {
+            tree,
+            focused,
+            zoomed: None,
+            prefix: KeyEvent::new(KeyCode::Char('b')).with_modifiers(Modifiers::CTRL),
+            prefix_armed: false,
+            drag: None,
+            sequence: 0,
+            operation_id: 0,
+            events: Vec::new(),
+            area: Rect::default(),
+            layout: None,
+            splitters: Vec::new(),
+            views: Vec::new(),
+            persistence_id: None,
+        }
+    }
+
+    /// Set the chord prefix key (default `Ctrl+B`).
+    #[must_use]
+    pub fn with_prefix(mut self, code: KeyCode, modifiers: Modifiers) -> Self {
+        self.prefix = KeyEvent::new(code).with_modifiers(modifiers);
+        self
+    }
+
+    /// Set a persistence ID for state saving.
+    #[must_use]
+    pub fn with_persistence_id(mut self, id: impl Into<String>) -> Self {
+        self.persistence_id = Some(id.into());
+        self
+    }
+
+    /// Get the persistence ID, if set.
+    #[must_use]
+    pub fn persistence_id(&self) -> Option<&str> {
+        self.persistence_id.as_deref()
+    }
+
+    /// The underlying pane tree.
+    #[must_use]
+    pub fn tree(&self) -> &PaneTree {
+        &self.tree
+    }
+
+    /// Currently focused leaf.
+    #[must_use]
+    pub fn focused(&self) -> PaneId {
+        self.focused
+    }
+
+    /// Currently maximized leaf, if any.
+    #[must_use]
+    pub fn zoomed(&self) -> Option<PaneId> {
+        self.zoomed
+    }
+
+    /// Whether the chord prefix has been pressed and awaits a command key.
+    #[must_use]
+    pub fn is_prefix_armed(&self) -> bool {
+        self.prefix_armed
+    }
+
+    /// Whether a splitter drag is in progress.
+    #[must_use]
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Surface key of a leaf.
+    #[must_use]
+    pub fn surface_key(&self, id: PaneId) -> Option<&str> {
+        match &self.tree.node(id)?.kind {
+            PaneNodeKind::Leaf(leaf) => Some(leaf.surface_key.as_str()),
+            PaneNodeKind::Split(_) => None,
+        }
+    }
+
+    /// Leaf ids in visual (depth-first, first-child-first) order.
+    #[must_use]
+    pub fn leaves(&self) -> Vec<PaneId> {
+        let mut out = Vec::new();
+        collect_leaves(&self.tree, self.tree.root(), &mut out);
+        out
+    }
+
+    /// Visible panes from the last layout pass.
+    #[must_use]
+    pub fn views(&self) -> &[PaneView] {
+        &self.views
+    }
+
+    /// Splitters from the last layout pass (empty while zoomed).
+    #[must_use]
+    pub fn splitters(&self) -> &[PaneSplitter] {
+        &self.splitters
+    }
+
+    /// Take the semantic events emitted from terminal input since the last call.
+    ///
+    /// Hosts can record these for replay or forward them to other adapters.
+    pub fn drain_semantic_events(&mut self) -> Vec<PaneSemanticInputEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Solve the layout for `area` and cache splitter and pane geometry.
+    ///
+    /// Called automatically on render. If the tree cannot fit in `area`, the
+    /// focused pane is shown alone.
+    pub fn layout(&mut self, area: Rect) {
+        self.area = area;
+        self.splitters.clear();
+        self.views.clear();
+        self.layout = self.tree.solve_layout(area).ok();
+
+        let solo = match (self.zoomed, &self.layout) {
+            (Some(zoomed), _) => Some(zoomed),
+            (None, None) => Some(self.focused),
+            (None, Some(_)) => None,
+        };
+        if let Some(id) = solo {
+            self.views.push(PaneView {
+                id,
+                area,
+                focused: true,
+            });
+            return;
+        }
+        let Some(layout) = &self.layout else {
+            return;
+        };
+
+        collect_splitters(&self.tree, layout, self.tree.root(), &mut self.splitters);
+        for id in self.leaves() {
+            let Some(rect) = layout.rect(id) else {
+                continue;
+            };
+            self.views.push(PaneView {
+                id,
+                area: content_rect(rect, &self.splitters),
+                focused: id == self.focused,
+            });
+        }
+    }
+
+    /// Apply a structural operation, keeping focus and zoom valid.
+    pub fn apply_operation(
+        &mut self,
+        operation: PaneOperation,
+    ) -> Result<PaneOperationOutcome, PaneOperationError> {
+        let anchor = self.focus_anchor();
+        self.operation_id += 1;
+        let outcome = self.tree.apply_operation(self.operation_id, operation)?;
+        self.layout(self.area);
+        self.repair_focus(anchor);
+        Ok(outcome)
+    }
+
+    /// Split `target` along `axis`, inserting `leaf` after it, and focus the
+    /// new leaf.
+    pub fn split(
+        &mut self,
+        target: PaneId,
+        axis: SplitAxis,
+        leaf: PaneLeaf,
+    ) -> Result<PaneId, PaneOperationError> {
+        self.apply_operation(PaneOperation::SplitLeaf {
+            target,
+            axis,
+            ratio: PaneSplitRatio::default(),
+            placement: PanePlacement::ExistingFirst,
+            new_leaf: leaf,
+        })?;
+        let new_leaf = self
+            .tree
+            .node(target)
+            .and_then(|node| node.parent)
+            .and_then(|parent| match &self.tree.node(parent)?.kind {
+                PaneNodeKind::Split(split) => Some(split.second),
+                PaneNodeKind::Leaf(_) => None,
+            })
+            .unwrap_or(self.focused);
+        self.zoomed = None;
+        self.focused = new_leaf;
+        self.layout(self.area);
+        Ok(new_leaf)
+    }
+
+    /// Split the focused leaf, naming the new surface `pane-<n>`.
+    pub fn split_focused(&mut self, axis: SplitAxis) -> Result<PaneId, PaneOperationError> {
+        let key = format!("pane-{}", self.tree.next_id().get());
+        self.split(self.focused, axis, PaneLeaf::new(key))
+    }
+
+    /// Close a node and promote its sibling.
+    pub fn close(&mut self, target: PaneId) -> Result<PaneOperationOutcome, PaneOperationError> {
+        self.apply_operation(PaneOperation::CloseNode { target })
+    }
+
+    /// Close the focused leaf.
+    pub fn close_focused(&mut self) -> Result<PaneOperationOutcome, PaneOperationError> {
+        self.close(self.focused)
+    }
+
+    /// Focus a leaf. Returns `false` if `id` is not a leaf.
+    pub fn focus(&mut self, id: PaneId) -> bool {
+        if self.surface_key(id).is_none() {
+            return false;
+        }
+        if self.zoomed.is_some_and(|zoomed| zoomed != id) {
+            self.zoomed = None;
+        }
+        self.focused = id;
+        self.layout(self.area);
+        true
+    }
+
+    /// Move focus in a direction. `Next`/`Prev` cycle in visual order;
+    /// arrows pick the nearest neighbour sharing an edge.
+    pub fn focus_direction(&mut self, direction: NavDirection) -> bool {
+        let target = match direction {
+            NavDirection::Next | NavDirection::Prev => {
+                let leaves = self.leaves();
+                let Some(pos) = leaves.iter().position(|&id| id == self.focused) else {
+                    return false;
+                };
+                let len = leaves.len();
+                let next = if direction == NavDirection::Next {
+                    (pos + 1) % len
+                } else {
+                    (pos + len - 1) % len
+                };
+                Some(leaves[next])
+            }
+            _ => self.neighbour(direction),
+        };
+        match target {
+            Some(id) if id != self.focused => self.focus(id),
+            _ => false,
+        }
+    }
+
+    /// Toggle zoom of the focused leaf.
+    pub fn toggle_zoom(&mut self) {
+        let zoomed = if self.zoomed.is_some() {
+            None
+        } else {
+            Some(self.focused)
+        };
+        self.set_zoom(zoomed);
+    }
+
+    /// Maximize a leaf, or restore the split layout with `None`.
+    pub fn set_zoom(&mut self, zoomed: Option<PaneId>) {
+        if let Some(id) = zoomed {
+            if self.surface_key(id).is_none() {
+                return;
+            }
+            self.focused = id;
+        }
+        self.zoomed = zoomed;
+        self.drag = None;
+        self.layout(self.area);
+    }
+
+    /// Route a terminal event. Mouse coordinates are matched against the
+    /// geometry from the last render.
+    pub fn handle_event(&mut self, event: &Event) -> PaneContainerAction {
+        match event {
+            Event::Key(key) if key.kind != KeyEventKind::Release => self.handle_key(key),
+            Event::Mouse(mouse) => self.handle_mouse(mouse),
+            Event::Focus(false) if self.drag.is_some() => {
+                let target = self.drag.map(|drag| drag.target);
+                self.emit(
+                    PaneSemanticInputEventKind::Blur { target },
+                    PaneModifierSnapshot::none(),
+                )
+            }
+            _ => PaneContainerAction::Ignored,
+        }
+    }
+
+    /// Route a key press through the chord prefix.
+    pub fn handle_key(&mut self, key: &KeyEvent) -> PaneContainerAction {
+        if key.code == KeyCode::Escape && self.drag.is_some() {
+            self.prefix_armed = false;
+            let target = self.drag.map(|drag| drag.target);
+            return self.emit(
+                PaneSemanticInputEventKind::Cancel {
+                    target,
+                    reason: PaneCancelReason::EscapeKey,
+                },
+                modifier_snapshot(key.modifiers),
+            );
+        }
+        if !self.prefix_armed {
+            if key.code == self.prefix.code && key.modifiers == self.prefix.modifiers {
+                self.prefix_armed = true;
+                return PaneContainerAction::Handled;
+            }
+            return PaneContainerAction::Ignored;
+        }
+        self.prefix_armed = false;
+
+        let arrow = match key.code {
+            KeyCode::Up => Some(NavDirection::Up),
+            KeyCode::Down => Some(NavDirection::Down),
+            KeyCode::Left => Some(NavDirection::Left),
+            KeyCode::Right => Some(NavDirection::Right),
+            _ => None,
+        };
+        if let Some(direction) = arrow {
+            if key.ctrl() || key.alt() {
+                let units = if key.alt() { COARSE_RESIZE_UNITS } else { 1 };
+                return self.keyboard_resize(direction, units, modifier_snapshot(key.modifiers));
+            }
+            return if self.focus_direction(direction) {
+                PaneContainerAction::FocusChanged(self.focused)
+            } else {
+                PaneContainerAction::Handled
+            };
+        }
+
+        match key.code {
+            KeyCode::Char('%') => self.chord_split(SplitAxis::Horizontal),
+            KeyCode::Char('"') => self.chord_split(SplitAxis::Vertical),
+            KeyCode::Char('x') => {
+                let closed = self.focused;
+                match self.close_focused() {
+                    Ok(_) => PaneContainerAction::Closed(closed),
+                    Err(_) => PaneContainerAction::Handled,
+                }
+            }
+            KeyCode::Char('z') => {
+                self.toggle_zoom();
+                PaneContainerAction::ZoomChanged(self.zoomed)
+            }
+            KeyCode::Char('o') => {
+                if self.focus_direction(NavDirection::Next) {
+                    PaneContainerAction::FocusChanged(self.focused)
+                } else {
+                    PaneContainerAction::Handled
+                }
+            }
+            _ => PaneContainerAction::Handled,
+        }
+    }
+
+    /// Route a mouse event: splitter drags and wheel nudges become semantic
+    /// events, clicks inside a pane focus it.
+    pub fn handle_mouse(&mut self, mouse: &MouseEvent) -> PaneContainerAction {
+        let position = PanePointerPosition::new(
+            i32::from(mouse.x) - i32::from(self.area.x),
+            i32::from(mouse.y) - i32::from(self.area.y),
+        );
+        let modifiers = modifier_snapshot(mouse.modifiers);
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                if let Some(splitter) = self.splitter_at(mouse.x, mouse.y) {
+                    return self.emit(
+                        PaneSemanticInputEventKind::PointerDown {
+                            target: splitter.target(),
+                            pointer_id: MOUSE_POINTER_ID,
+                            button: PanePointerButton::Primary,
+                            position,
+                        },
+                        modifiers,
+                    );
+                }
+                let hit = self
+                    .views
+                    .iter()
+                    .find(|view| view.area.contains(mouse.x, mouse.y))
+                    .map(|view| view.id);
+                match hit {
+                    Some(id) if id != self.focused => {
+                        self.focus(id);
+                        PaneContainerAction::FocusChanged(id)
+                    }
+                    _ => PaneContainerAction::Ignored,
+                }
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let Some(drag) = self.drag else {
+                    return PaneContainerAction::Ignored;
+                };
+                self.emit(
+                    PaneSemanticInputEventKind::PointerMove {
+                        target: drag.target,
+                        pointer_id: drag.pointer_id,
+                        position,
+                        delta_x: position.x - drag.last.x,
+                        delta_y: position.y - drag.last.y,
+                    },
+                    modifiers,
+                )
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                let Some(drag) = self.drag else {
+                    return PaneContainerAction::Ignored;
+                };
+                self.emit(
+                    PaneSemanticInputEventKind::PointerUp {
+                        target: drag.target,
+                        pointer_id: drag.pointer_id,
+                        button: PanePointerButton::Primary,
+                        position,
+                    },
+                    modifiers,
+                )
+            }
+            MouseEventKind::ScrollUp | MouseEventKind::ScrollDown => {
+                let Some(splitter) = self.splitter_at(mouse.x, mouse.y) else {
+                    return PaneContainerAction::Ignored;
+                };
+                let lines = if mouse.kind == MouseEventKind::ScrollUp {
+                    -1
+                } else {
+                    1
+                };
+                self.emit(
+                    PaneSemanticInputEventKind::WheelNudge {
+                        target: splitter.target(),
+                        lines,
+                    },
+                    modifiers,
+                )
+            }
+            _ => PaneContainerAction::Ignored,
+        }
+    }
+
+    /// Apply one semantic input event.
+    ///
+    /// Pointer positions are relative to the container area. Resizes are
+    /// expressed in cells of the split's first child and stored back into the
+    /// tree as a reduced ratio. Invalid events are ignored.
+    pub fn apply_semantic_event(&mut self, event: &PaneSemanticInputEvent) -> PaneContainerAction {
+        if event.validate().is_err() {
+            return PaneContainerAction::Ignored;
+        }
+        match event.kind {
+            PaneSemanticInputEventKind::PointerDown {
+                target,
+                pointer_id,
+                button: PanePointerButton::Primary,
+                position,
+            } => {
+                if self.drag.is_some() || self.zoomed.is_some() {
+                    return PaneContainerAction::Ignored;
+                }
+                let Some(original) = self.split_ratio(target.split_id) else {
+                    return PaneContainerAction::Ignored;
+                };
+                self.drag = Some(PaneDrag {
+                    target,
+                    pointer_id,
+                    original,
+                    last: position,
+                });
+                PaneContainerAction::Handled
+            }
+            PaneSemanticInputEventKind::PointerDown { .. } => PaneContainerAction::Ignored,
+            PaneSemanticInputEventKind::PointerMove {
+                target,
+                pointer_id,
+                position,
+                ..
+            } => {
+                let Some(drag) = self.drag.as_mut() else {
+                    return PaneContainerAction::Ignored;
+                };
+                if drag.target != target || drag.pointer_id != pointer_id {
+                    return PaneContainerAction::Ignored;
+                }
+                drag.last = position;
+                let Some(span) = self.split_span(target.split_id) else {
+                    return PaneContainerAction::Ignored;
+                };
+                let first = match target.axis {
+                    SplitAxis::Horizontal => {
+                        i32::from(self.area.x) + position.x - i32::from(span.x) + 1
+                    }
+                    SplitAxis::Vertical => {
+                        i32::from(self.area.y) + position.y - i32::from(span.y) + 1
+                    }
+                };
+                self.resize_to(target.split_id, first)
+            }
+            PaneSemanticInputEventKind::PointerUp {
+                target, pointer_id, ..
+            } => match self.drag {
+                Some(drag) if drag.target == target && drag.pointer_id == pointer_id => {
+                    self.drag = None;
+                    PaneContainerAction::Handled
+                }
+                _ => PaneContainerAction::Ignored,
+            },
+            PaneSemanticInputEventKind::WheelNudge { target, lines } => {
+                self.resize_by(target.split_id, i32::from(lines))
+            }
+            PaneSemanticInputEventKind::KeyboardResize {
+                target,
+                direction,
+                units,
+            } => {
+                let delta = match direction {
+                    PaneResizeDirection::Increase => i32::from(units),
+                    PaneResizeDirection::Decrease => -i32::from(units),
+                };
+                self.resize_by(target.split_id, delta)
+            }
+            PaneSemanticInputEventKind::Cancel { target, .. } => {
+                let Some(drag) = self.drag else {
+                    return PaneContainerAction::Ignored;
+                };
+                if target.is_some_and(|target| target != drag.target) {
+                    return PaneContainerAction::Ignored;
+                }
+                self.drag = None;
+                let split = drag.target.split_id;
+                if self
+                    .apply_operation(PaneOperation::SetSplitRatio {
+                        split,
+                        ratio: drag.original,
+                    })
+                    .is_ok()
+                {
+                    PaneContainerAction::Resized(split)
+                } else {
+                    PaneContainerAction::Handled
+                }
+            }
+            PaneSemanticInputEventKind::Blur { .. } => {
+                if self.drag.take().is_some() {
+                    PaneContainerAction::Handled
+                } else {
+                    PaneContainerAction::Ignored
+                }
+            }
+        }
+    }
+
+    fn emit(
+        &mut self,
+        kind: PaneSemanticInputEventKind,
+        modifiers: PaneModifierSnapshot,
+    ) -> PaneContainerAction {
+        self.sequence += 1;
+        let mut event = PaneSemanticInputEvent::new(self.sequence, kind);
+        event.modifiers = modifiers;
+        let action = self.apply_semantic_event(&event);
+        self.events.push(event);
+        action
+    }
+
+    fn chord_split(&mut self, axis: SplitAxis) -> PaneContainerAction {
+        match self.split_focused(axis) {
+            Ok(id) => PaneContainerAction::Split(id),
+            Err(_) => PaneContainerAction::Handled,
+        }
+    }
+
+    fn keyboard_resize(
+        &mut self,
+        direction: NavDirection,
+        units: u16,
+        modifiers: PaneModifierSnapshot,
+    ) -> PaneContainerAction {
+        if self.zoomed.is_some() {
+            return PaneContainerAction::Handled;
+        }
+        let (axis, direction) = match direction {
+            NavDirection::Left => (SplitAxis::Horizontal, PaneResizeDirection::Decrease),
+            NavDirection::Right => (SplitAxis::Horizontal, PaneResizeDirection::Increase),
+            NavDirection::Up => (SplitAxis::Vertical, PaneResizeDirection::Decrease),
+            _ => (SplitAxis::Vertical, PaneResizeDirection::Increase),
+        };
+        let Some(split_id) = self.enclosing_split(self.focused, axis) else {
+            return PaneContainerAction::Handled;
+        };
+        self.emit(
+            PaneSemanticInputEventKind::KeyboardResize {
+                target: PaneResizeTarget { split_id, axis },
+                direction,
+                units,
+            },
+            modifiers,
+        )
+    }
+
+    fn resize_by(&mut self, split: PaneId, delta: i32) -> PaneContainerAction {
+        if self.zoomed.is_some() {
+            return PaneContainerAction::Ignored;
+        }
+        let Some(first) = self.first_size(split) else {
+            return PaneContainerAction::Ignored;
+        };
+        self.resize_to(split, i32::from(first) + delta)
+    }
+
+    fn resize_to(&mut self, split: PaneId, first: i32) -> PaneContainerAction {
+        let Some(span) = self.split_span(split) else {
+            return PaneContainerAction::Ignored;
+        };
+        let Some(PaneNodeKind::Split(node)) = self.tree.node(split).map(|node| &node.kind) else {
+            return PaneContainerAction::Ignored;
+        };
+        let available = match node.axis {
+            SplitAxis::Horizontal => span.width,
+            SplitAxis::Vertical => span.height,
+        };
+        if available < 2 {
+            return PaneContainerAction::Ignored;
+        }
+        let first = first.clamp(1, i32::from(available) - 1) as u32;
+        let Ok(ratio) = PaneSplitRatio::new(first, u32::from(available) - first) else {
+            return PaneContainerAction::Ignored;
+        };
+        if ratio == node.ratio {
+            return PaneContainerAction::Handled;
+        }
+        match self.apply_operation(PaneOperation::SetSplitRatio { split, ratio }) {
+            Ok(_) => PaneContainerAction::Resized(split),
+            Err(_) => PaneContainerAction::Ignored,
+        }
+    }
+
+    fn split_ratio(&self, split: PaneId) -> Option<PaneSplitRatio> {
+        match &self.tree.node(split)?.kind {
+            PaneNodeKind::Split(node) => Some(node.ratio),
+            PaneNodeKind::Leaf(_) => None,
+        }
+    }
+
+    fn split_span(&self, split: PaneId) -> Option<Rect> {
+        self.layout.as_ref()?.rect(split)
+    }
+
+    fn first_size(&self, split: PaneId) -> Option<u16> {
+        let PaneNodeKind::Split(node) = &self.tree.node(split)?.kind else {
+            return None;
+        };
+        let first = self.layout.as_ref()?.rect(node.first)?;
+        Some(match node.axis {
+            SplitAxis::Horizontal => first.width,
+            SplitAxis::Vertical => first.height,
+        })
+    }
+
+    fn enclosing_split(&self, mut id: PaneId, axis: SplitAxis) -> Option<PaneId> {
+        while let Some(parent) = self.tree.node(id)?.parent {
+            if let PaneNodeKind::Split(split) = &self.tree.node(parent)?.kind
+                && split.axis == axis
+            {
+                return Some(parent);
+            }
+            id = parent;
+        }
+        None
+    }
+
+    fn splitter_at(&self, x: u16, y: u16) -> Option<PaneSplitter> {
+        self.splitters
+            .iter()
+            .rev()
+            .find(|splitter| splitter.line.contains(x, y))
+            .copied()
+    }
+
+    fn neighbour(&self, direction: NavDirection) -> Option<PaneId> {
+        let layout = self.layout.as_ref()?;
+        let current = layout.rect(self.focused)?;
+        self.leaves()
+            .into_iter()
+            .filter(|&id| id != self.focused)
+            .filter_map(|id| {
+                let rect = layout.rect(id)?;
+                let (gap, overlap) = match direction {
+                    NavDirection::Right if rect.x >= current.right() => (
+                        rect.x - current.right(),
+                        span_overlap(rect.y, rect.bottom(), current.y, current.bottom()),
+                    ),
+                    NavDirection::Left if rect.right() <= current.x => (
+                        current.x - rect.right(),
+                        span_overlap(rect.y, rect.bottom(), current.y, current.bottom()),
+                    ),
+                    NavDirection::Down if rect.y >= current.bottom() => (
+                        rect.y - current.bottom(),
+                        span_overlap(rect.x, rect.right(), current.x, current.right()),
+                    ),
+                    NavDirection::Up if rect.bottom() <= current.y => (
+                        current.y - rect.bottom(),
+                        span_overlap(rect.x, rect.right(), current.x, current.right()),
+                    ),
+                    _ => return None,
+                };
+                (overlap > 0).then_some((gap, u16::MAX - overlap, id))
+            })
+            .min()
+            .map(|(_, _, id)| id)
+    }
+
+    fn focus_anchor(&self) -> Option<(u16, u16)> {
+        let rect = self.layout.as_ref()?.rect(self.focused)?;
+        Some((rect.x + rect.width / 2, rect.y + rect.height / 2))
+    }
+
+    fn repair_focus(&mut self, anchor: Option<(u16, u16)>) {
+        if self
+            .zoomed
+            .is_some_and(|zoomed| self.surface_key(zoomed).is_none())
+        {
+            self.zoomed = None;
+        }
+        if self
+            .drag
+            .is_some_and(|drag| self.split_ratio(drag.target.split_id).is_none())
+        {
+            self.drag = None;
+        }
+        if self.surface_key(self.focused).is_some() {
+            return;
+        }
+        let by_anchor = anchor.and_then(|(x, y)| {
+            let layout = self.layout.as_ref()?;
+            self.leaves()
+                .into_iter()
+                .find(|&id| layout.rect(id).is_some_and(|rect| rect.contains(x, y)))
+        });
+        self.focused = by_anchor.unwrap_or_else(|| first_leaf(&self.tree, self.tree.root()));
+        self.layout(self.area);
+    }
+}
+
+fn modifier_snapshot(modifiers: Modifiers) -> PaneModifierSnapshot {
+    PaneModifierSnapshot {
+        shift: modifiers.contains(Modifiers::SHIFT),
+        alt: modifiers.contains(Modifiers::ALT),
+        ctrl: modifiers.contains(Modifiers::CTRL),
+        meta: modifiers.contains(Modifiers::SUPER),
+    }
+}
+
+fn span_overlap(a_start: u16, a_end: u16, b_start: u16, b_end: u16) -> u16 {
+    a_end.min(b_end).saturating_sub(a_start.max(b_start))
+}
+
+fn first_leaf(tree: &PaneTree, mut id: PaneId) -> PaneId {
+    while let Some(PaneNodeKind::Split(split)) = tree.node(id).map(|node| &node.kind) {
+        id = split.first;
+    }
+    id
+}
+
+fn collect_leaves(tree: &PaneTree, id: PaneId, out: &mut Vec<PaneId>) {
+    match tree.node(id).map(|node| &node.kind) {
+        Some(PaneNodeKind::Leaf(_)) => out.push(id),
+        Some(PaneNodeKind::Split(split)) => {
+            collect_leaves(tree, split.first, out);
+            collect_leaves(tree, split.second, out);
+        }
+        None => {}
+    }
+}
+
+/// Post-order so outer splitters are drawn over inner ones and stay continuous.
+fn collect_splitters(
+    tree: &PaneTree,
+    layout: &PaneLayout,
+    id: PaneId,
+    out: &mut Vec<PaneSplitter>,
+) {
+    let Some(PaneNodeKind::Split(split)) = tree.node(id).map(|node| &node.kind) else {
+        return;
+    };
+    collect_splitters(tree, layout, split.first, out);
+    collect_splitters(tree, layout, split.second, out);
+    let (Some(span), Some(first)) = (layout.rect(id), layout.rect(split.first)) else {
+        return;
+    };
+    if first.is_empty() {
+        return;
+    }
+    let line = match split.axis {
+        SplitAxis::Horizontal => Rect::new(first.right() - 1, span.y, 1, span.height),
+        SplitAxis::Vertical => Rect::new(span.x, first.bottom() - 1, span.width, 1),
+    };
+    out.push(PaneSplitter {
+        split: id,
+        axis: split.axis,
+        line,
+        span,
+    });
+}
+
+/// Splitters occupy the last column/row of the first child, so shrink leaves
+/// that touch one.
+fn content_rect(rect: Rect, splitters: &[PaneSplitter]) -> Rect {
+    let mut content = rect;
+    for splitter in splitters {
+        if rect.intersection_opt(&splitter.line).is_none() {
+            continue;
+        }
+        match splitter.axis {
+            SplitAxis::Horizontal if splitter.line.x + 1 == rect.right() => {
+                content.width = rect.width - 1;
+            }
+            SplitAxis::Vertical if splitter.line.y + 1 == rect.bottom() => {
+                content.height = rect.height - 1;
+            }
+            _ => {}
+        }
+    }
+    content
+}
+
+/// Split-pane container rendering the leaves of a [`PaneContainerState`].
+///
+/// Pane content is supplied per surface key; leaves without a registered
+/// widget are left blank so hosts can also draw them from
+/// [`PaneContainerState::views`] after rendering.
+#[derive(Default)]
+pub struct PaneContainer<'a> {
+    panes: BTreeMap<String, Box<dyn Widget + 'a>>,
+    splitter_style: Style,
+    active_splitter_style: Style,
+    hit_id: Option<HitId>,
+}
+
+impl std::fmt::Debug for PaneContainer<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PaneContainer")
+            .field("panes", &self.panes.keys().collect::<Vec<_>>())
+            .field("splitter_style", &self.splitter_style)
+            .field("active_splitter_style", &self.active_splitter_style)
+            .field("hit_id", &self.hit_id)
+            .finish()
+    }
+}
+
+impl<'a> PaneContainer<'a> {
+    /// Create an empty container.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the widget rendered in the leaf with `surface_key`.
+    #[must_use]
+    pub fn pane(mut self, surface_key: impl Into<String>, widget: impl Widget + 'a) -> Self {
+        self.panes.insert(surface_key.into(), Box::new(widget));
+        self
+    }
+
+    /// Style for splitter lines.
+    #[must_use]
+    pub fn splitter_style(mut self, style: Style) -> Self {
+        self.splitter_style = style;
+        self
+    }
+
+    /// Style for splitter cells bordering the focused pane or being dragged.
+    #[must_use]
+    pub fn active_splitter_style(mut self, style: Style) -> Self {
+        self.active_splitter_style = style;
+        self
+    }
+
+    /// Register hit regions: splitters as `Handle` (data = split id) and
+    /// panes as `Content` (data = leaf id).
+    #[must_use]
+    pub fn hit_id(mut self, id: HitId) -> Self {
+        self.hit_id = Some(id);
+        self
+    }
+}
+
+impl StatefulWidget for PaneContainer<'_> {
+    type State = PaneContainerState;
+
+    fn render(&self, area: Rect, frame: &mut Frame, state: &mut Self::State) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "widget_render",
+            widget = "PaneContainer",
+            x = area.x,
+            y = area.y,
+            w = area.width,
+            h = area.height
+        )
+        .entered();
+
+        state.layout(area);
+        if area.is_empty() {
+            return;
+        }
+
+        for view in &state.views {
+            if view.area.is_empty() {
+                continue;
+            }
+            if let Some(widget) = state
+                .surface_key(view.id)
+                .and_then(|key| self.panes.get(key))
+            {
+                frame.buffer.push_scissor(view.area);
+                widget.render(view.area, frame);
+                frame.buffer.pop_scissor();
+            }
+            if let Some(id) = self.hit_id {
+                frame.register_hit(view.area, id, HitRegion::Content, view.id.get());
+            }
+        }
+
+        let unicode = frame.buffer.degradation.use_unicode_borders();
+        let styled = frame.buffer.degradation.apply_styling();
+        let focused = state
+            .layout
+            .as_ref()
+            .and_then(|layout| layout.rect(state.focused));
+        let dragging = state.drag.map(|drag| drag.target.split_id);
+        for splitter in &state.splitters {
+            let ch = match (splitter.axis, unicode) {
+                (SplitAxis::Horizontal, true) => '│',
+                (SplitAxis::Horizontal, false) => '|',
+                (SplitAxis::Vertical, true) => '─',
+                (SplitAxis::Vertical, false) => '-',
+            };
+            let line = splitter.line;
+            for y in line.y..line.bottom() {
+                for x in line.x..line.right() {
+                    let active = dragging == Some(splitter.split)
+                        || focused.is_some_and(|rect| borders(rect, splitter.axis, x, y));
+                    let mut cell = Cell::from_char(ch);
+                    if styled {
+                        let style = if active {
+                            self.active_splitter_style.merge(&self.splitter_style)
+                        } else {
+                            self.splitter_style
+                        };
+                        apply_style(&mut cell, style);
+                    }
+                    frame.buffer.set_fast(x, y, cell);
+                }
+            }
+            if let Some(id) = self.hit_id {
+                frame.register_hit(line, id, HitRegion::Handle, splitter.split.get());
+            }
+        }
+    }
+}
+
+/// Whether splitter cell `(x, y)` lies on the edge of `rect`.
+fn borders(rect: Rect, axis: SplitAxis, x: u16, y: u16) -> bool {
+    match axis {
+        SplitAxis::Horizontal => {
+            (rect.y..rect.bottom()).contains(&y) && (x + 1 == rect.right() || x + 1 == rect.x)
+        }
+        SplitAxis::Vertical => {
+            (rect.x..rect.right()).contains(&x) && (y + 1 == rect.bottom() || y + 1 == rect.y)
+        }
+    }
+}
+
+// ============================================================================
+// Stateful Persistence Implementation
+// ============================================================================
+
+/// Persistable state for a [`PaneContainerState`].
+///
+/// Stores the canonical tree snapshot along with focus and zoom.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "state-persistence",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct PaneContainerPersistState {
+    /// Canonical pane tree snapshot.
+    pub snapshot: Option<PaneTreeSnapshot>,
+    /// Focused leaf.
+    pub focused: Option<PaneId>,
+    /// Maximized leaf.
+    pub zoomed: Option<PaneId>,
+}
+
+impl crate::stateful::Stateful for PaneContainerState {
+    type State = PaneContainerPersistState;
+
+    fn state_key(&self) -> crate::stateful::StateKey {
+        crate::stateful::StateKey::new(
+            "PaneContainer",
+            self.persistence_id.as_deref().unwrap_or("default"),
+        )
+    }
+
+    fn save_state(&self) -> PaneContainerPersistState {
+        PaneContainerPersistState {
+            snapshot: Some(self.tree.to_snapshot()),
+            focused: Some(self.focused),
+            zoomed: self.zoomed,
+        }
+    }
+
+    fn restore_state(&mut self, state: PaneContainerPersistState) {
+        if let Some(snapshot) = state.snapshot {
+            let tree = PaneTree::from_snapshot(snapshot.clone())
+                .ok()
+                .or_else(|| snapshot.repair_safe().ok().map(|outcome| outcome.tree));
+            let Some(tree) = tree else {
+                return;
+            };
+            self.tree = tree;
+            self.drag = None;
+            self.focused = first_leaf(&self.tree, self.tree.root());
+            self.zoomed = None;
+        }
+        if let Some(focused) = state.focused
+            && self.surface_key(focused).is_some()
+        {
+            self.focused = focused;
+        }
+        self.zoomed = state.zoomed.filter(|&id| self.surface_key(id).is_some());
+        if let Some(zoomed) = self.zoomed {
+            self.focused = zoomed;
+        }
+        self.layout(self.area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stateful::Stateful;
+    use ftui_render::grapheme_pool::GraphemePool;
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code)
+    }
+
+    fn prefix() -> KeyEvent {
+        KeyEvent::new(KeyCode::Char('b')).with_modifiers(Modifiers::CTRL)
+    }
+
+    fn chord(state: &mut PaneContainerState, event: KeyEvent) -> PaneContainerAction {
+        assert_eq!(state.handle_key(&prefix()), PaneContainerAction::Handled);
+        state.handle_key(&event)
+    }
+
+    fn mouse(kind: MouseEventKind, x: u16, y: u16) -> Event {
+        Event::Mouse(MouseEvent::new(kind, x, y))
+    }
+
+    fn ratio_of(state: &PaneContainerState, split: PaneId) -> (u32, u32) {
+        let ratio = state.split_ratio(split).expect("split");
+        (ratio.numerator(), ratio.denominator())
+    }
+
+    fn row(frame: &Frame, y: u16, width: u16) -> String {
+        (0..width)
+            .map(|x| {
+                frame
+                    .buffer
+                    .get(x, y)
+                    .and_then(|c| c.content.as_char())
+                    .unwrap_or(' ')
+            })
+            .collect()
+    }
+
+    fn side_by_side() -> PaneContainerState {
+        let mut state = PaneContainerState::new(PaneTree::singleton("left"));
+        state.layout(Rect::new(0, 0, 20, 4));
+        state
+            .split(
+                state.focused(),
+                SplitAxis::Horizontal,
+                PaneLeaf::new("right"),
+            )
+            .expect("split");
+        state
+    }
+
+    #[test]
+    fn renders_leaves_and_splitter() {
+        let mut state = side_by_side();
+        let container = PaneContainer::new()
+            .pane("left", crate::paragraph::Paragraph::new("L"))
+            .pane("right", crate::paragraph::Paragraph::new("R"));
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(20, 4, &mut pool);
+        container.render(Rect::new(0, 0, 20, 4), &mut frame, &mut state);
+
+        assert_eq!(row(&frame, 0, 20), "L        │R         ");
+        assert_eq!(row(&frame, 3, 20), "         │          ");
+        let views = state.views();
+        assert_eq!(views.len(), 2);
+        assert_eq!(views[0].area, Rect::new(0, 0, 9, 4));
+        assert_eq!(views[1].area, Rect::new(10, 0, 10, 4));
+        assert!(views[1].focused);
+    }
+
+    #[test]
+    fn mouse_drag_emits_semantic_events_and_resizes() {
+        let mut state = side_by_side();
+        let split = state.splitters()[0].split;
+        assert_eq!(state.splitters()[0].line, Rect::new(9, 0, 1, 4));
+
+        assert_eq!(
+            state.handle_event(&mouse(MouseEventKind::Down(MouseButton::Left), 9, 1)),
+            PaneContainerAction::Handled
+        );
+        assert!(state.is_dragging());
+        assert_eq!(
+            state.handle_event(&mouse(MouseEventKind::Drag(MouseButton::Left), 14, 1)),
+            PaneContainerAction::Resized(split)
+        );
+        assert_eq!(ratio_of(&state, split), (3, 1));
+        assert_eq!(state.splitters()[0].line.x, 14);
+        state.handle_event(&mouse(MouseEventKind::Up(MouseButton::Left), 14, 1));
+        assert!(!state.is_dragging());
+
+        let events = state.drain_semantic_events();
+        assert_eq!(events.len(), 3);
+        assert_eq!(
+            events.iter().map(|e| e.sequence).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert!(matches!(
+            events[1].kind,
+            PaneSemanticInputEventKind::PointerMove { delta_x: 5, .. }
+        ));
+        assert!(events.iter().all(|e| e.validate().is_ok()));
+    }
+
+    #[test]
+    fn escape_cancels_drag_and_restores_ratio() {
+        let mut state = side_by_side();
+        let split = state.splitters()[0].split;
+        state.handle_event(&mouse(MouseEventKind::Down(MouseButton::Left), 9, 0));
+        state.handle_event(&mouse(MouseEventKind::Drag(MouseButton::Left), 3, 0));
+        assert_ne!(ratio_of(&state, split), (1, 1));
+
+        assert_eq!(
+            state.handle_key(&key(KeyCode::Escape)),
+            PaneContainerAction::Resized(split)
+        );
+        assert_eq!(ratio_of(&state, split), (1, 1));
+        assert!(!state.is_dragging());
+    }
+
+    #[test]
+    fn chords_split_focus_resize_and_close() {
+        let mut state = PaneContainerState::new(PaneTree::singleton("main"));
+        state.layout(Rect::new(0, 0, 40, 10));
+        let main = state.focused();
+
+        let PaneContainerAction::Split(right) = chord(&mut state, key(KeyCode::Char('%'))) else {
+            panic!("expected split");
+        };
+        assert_eq!(state.focused(), right);
+        assert_eq!(state.surface_key(right), Some("pane-2"));
+
+        assert_eq!(
+            chord(&mut state, key(KeyCode::Left)),
+            PaneContainerAction::FocusChanged(main)
+        );
+        let split = state.tree().root();
+        assert_eq!(
+            chord(
+                &mut state,
+                key(KeyCode::Right).with_modifiers(Modifiers::ALT)
+            ),
+            PaneContainerAction::Resized(split)
+        );
+        assert_eq!(ratio_of(&state, split), (5, 3));
+        assert!(matches!(
+            state.drain_semantic_events()[0].kind,
+            PaneSemanticInputEventKind::KeyboardResize {
+                direction: PaneResizeDirection::Increase,
+                units: 5,
+                ..
+            }
+        ));
+
+        assert_eq!(
+            chord(&mut state, key(KeyCode::Char('x'))),
+            PaneContainerAction::Closed(main)
+        );
+        assert_eq!(state.leaves(), vec![right]);
+        assert_eq!(state.focused(), right);
+        assert!(state.splitters().is_empty());
+    }
+
+    #[test]
+    fn unarmed_keys_are_ignored() {
+        let mut state = side_by_side();
+        assert_eq!(
+            state.handle_key(&key(KeyCode::Char('x'))),
+            PaneContainerAction::Ignored
+        );
+        assert_eq!(state.leaves().len(), 2);
+    }
+
+    #[test]
+    fn zoom_maximizes_focused_pane() {
+        let mut state = side_by_side();
+        let focused = state.focused();
+        assert_eq!(
+            chord(&mut state, key(KeyCode::Char('z'))),
+            PaneContainerAction::ZoomChanged(Some(focused))
+        );
+        assert_eq!(state.views().len(), 1);
+        assert_eq!(state.views()[0].area, Rect::new(0, 0, 20, 4));
+        assert!(state.splitters().is_empty());
+
+        chord(&mut state, key(KeyCode::Char('z')));
+        assert_eq!(state.zoomed(), None);
+        assert_eq!(state.views().len(), 2);
+    }
+
+    #[test]
+    fn click_focuses_pane() {
+        let mut state = side_by_side();
+        let left = state.leaves()[0];
+        assert_eq!(
+            state.handle_event(&mouse(MouseEventKind::Down(MouseButton::Left), 2, 2)),
+            PaneContainerAction::FocusChanged(left)
+        );
+        assert_eq!(state.focused(), left);
+    }
+
+    #[test]
+    fn persistence_round_trip() {
+        let mut state = side_by_side().with_persistence_id("workspace");
+        let split = state.tree().root();
+        state.apply_semantic_event(&PaneSemanticInputEvent::new(
+            1,
+            PaneSemanticInputEventKind::KeyboardResize {
+                target: PaneResizeTarget {
+                    split_id: split,
+                    axis: SplitAxis::Horizontal,
+                },
+                direction: PaneResizeDirection::Decrease,
+                units: 4,
+            },
+        ));
+        state.toggle_zoom();
+        let saved = state.save_state();
+        assert_eq!(state.state_key().to_string(), "PaneContainer::workspace");
+
+        let mut restored = PaneContainerState::default();
+        restored.restore_state(saved);
+        assert_eq!(restored.tree().to_snapshot(), state.tree().to_snapshot());
+        assert_eq!(restored.focused(), state.focused());
+        assert_eq!(restored.zoomed(), state.zoomed());
+        assert_eq!(ratio_of(&restored, split), (3, 7));
+    }
+}
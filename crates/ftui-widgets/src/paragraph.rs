@@ -2,11 +2,16 @@
 
 use crate::block::{Alignment, Block};
 use crate::measurable::{MeasurableWidget, SizeConstraints};
+use crate::sheet::{self, SheetStyled};
 use crate::{Widget, draw_text_span_scrolled, draw_text_span_with_link, set_style_area};
 use ftui_core::geometry::{Rect, Size};
 use ftui_render::frame::Frame;
-use ftui_style::Style;
-use ftui_text::{Text, WrapMode, display_width};
+use ftui_style::{SelectorSheet, Style, StyleNode};
+#[cfg(feature = "bidi")]
+use ftui_text::bidi::{self, ParagraphDirection};
+use ftui_text::{Line, Text, WrapMode, display_width};
+#[cfg(feature = "bidi")]
+use std::borrow::Cow;
 
 /// A widget that renders multi-line styled text.
 #[derive(Debug, Clone, Default)]
@@ -17,6 +22,8 @@ pub struct Paragraph<'a> {
     wrap: Option<WrapMode>,
     alignment: Alignment,
     scroll: (u16, u16),
+    #[cfg(feature = "bidi")]
+    direction: ParagraphDirection,
 }
 
 impl<'a> Paragraph<'a> {
@@ -30,6 +37,8 @@ impl<'a> Paragraph<'a> {
             wrap: None,
             alignment: Alignment::Left,
             scroll: (0, 0),
+            #[cfg(feature = "bidi")]
+            direction: ParagraphDirection::Auto,
         }
     }
 
@@ -67,6 +76,17 @@ impl<'a> Paragraph<'a> {
         self.scroll = offset;
         self
     }
+
+    /// Set the base direction used to reorder lines with right-to-left text.
+    ///
+    /// Defaults to [`ParagraphDirection::Auto`], which detects it per line
+    /// from the first strong character.
+    #[cfg(feature = "bidi")]
+    #[must_use]
+    pub fn direction(mut self, direction: ParagraphDirection) -> Self {
+        self.direction = direction;
+        self
+    }
 }
 
 impl Widget for Paragraph<'_> {
@@ -131,7 +151,7 @@ impl Widget for Paragraph<'_> {
         let mut current_visual_line = 0;
         let scroll_offset = self.scroll.0 as usize;
 
-        let mut render_line = |line: &ftui_text::Line, y: u16| {
+        let mut render_line = |line: &Line, y: u16| {
             // Render spans with proper Unicode widths
             let line_width: usize = line.width();
 
@@ -220,6 +240,11 @@ impl Widget for Paragraph<'_> {
                 break;
             }
 
+            // Mixed-direction lines are wrapped in logical order, then each
+            // visual line is reordered with the paragraph's base direction.
+            #[cfg(feature = "bidi")]
+            let direction = line_direction(line, self.direction);
+
             // If wrapping is enabled and line is wider than area, wrap it
             if let Some(wrap_mode) = self.wrap {
                 let line_width = line.width();
@@ -235,6 +260,9 @@ impl Widget for Paragraph<'_> {
                             break;
                         }
 
+                        #[cfg(feature = "bidi")]
+                        render_line(&to_visual(wrapped_line, direction), y);
+                        #[cfg(not(feature = "bidi"))]
                         render_line(wrapped_line, y);
                         y += 1;
                         current_visual_line += 1;
@@ -249,12 +277,31 @@ impl Widget for Paragraph<'_> {
                 continue;
             }
 
+            #[cfg(feature = "bidi")]
+            render_line(&to_visual(line, direction), y);
+            #[cfg(not(feature = "bidi"))]
             render_line(line, y);
             y = y.saturating_add(1);
             current_visual_line += 1;
         }
     }
 }
+
+impl<'a> SheetStyled for Paragraph<'a> {
+    const TYPE_NAME: &'static str = "Paragraph";
+
+    fn styled_by(mut self, sheet: &SelectorSheet, path: &[StyleNode<'_>]) -> Self {
+        self.style = self.style.merge(&sheet.resolve(path));
+        self.block = self.block.map(|block| {
+            block.styled_by(
+                sheet,
+                &sheet::child_path(path, StyleNode::new(Block::TYPE_NAME)),
+            )
+        });
+        self
+    }
+}
+
 impl MeasurableWidget for Paragraph<'_> {
     fn measure(&self, available: Size) -> SizeConstraints {
         // Calculate text measurements
@@ -364,6 +411,29 @@ impl Paragraph<'_> {
     }
 }
 
+/// Base direction of a line that needs bidi reordering, or `None` for
+/// lines without right-to-left text.
+#[cfg(feature = "bidi")]
+fn line_direction(line: &Line, direction: ParagraphDirection) -> Option<ParagraphDirection> {
+    let plain = line.to_plain_text();
+    if !bidi::has_rtl(&plain) {
+        return None;
+    }
+    Some(match direction {
+        ParagraphDirection::Auto => bidi::paragraph_level(&plain),
+        fixed => fixed,
+    })
+}
+
+/// Reorder a line for display when it carries right-to-left text.
+#[cfg(feature = "bidi")]
+fn to_visual(line: &Line, direction: Option<ParagraphDirection>) -> Cow<'_, Line> {
+    match direction {
+        Some(direction) => Cow::Owned(line.to_visual(direction)),
+        None => Cow::Borrowed(line),
+    }
+}
+
 /// Calculate the starting x position for a line given alignment.
 fn align_x(area: Rect, line_width: usize, alignment: Alignment) -> u16 {
     let line_width_u16 = u16::try_from(line_width).unwrap_or(u16::MAX);
@@ -503,6 +573,61 @@ mod tests {
         assert_eq!(frame.buffer.get(0, 1).unwrap().content.as_char(), Some('e'));
     }
 
+    #[cfg(feature = "bidi")]
+    fn row(frame: &Frame, y: u16) -> String {
+        (0..frame.buffer.width())
+            .filter_map(|x| frame.buffer.get(x, y).unwrap().content.as_char())
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    #[test]
+    #[cfg(feature = "bidi")]
+    fn render_mixed_direction_in_visual_order() {
+        let para = Paragraph::new(Text::raw("ab \u{05D0}\u{05D1}\u{05D2} cd"));
+        let area = Rect::new(0, 0, 12, 1);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(12, 1, &mut pool);
+        para.render(area, &mut frame);
+
+        assert_eq!(row(&frame, 0), "ab \u{05D2}\u{05D1}\u{05D0} cd");
+    }
+
+    #[test]
+    #[cfg(feature = "bidi")]
+    fn render_forced_ltr_direction() {
+        // Auto detects an RTL paragraph, which puts the leading number on
+        // the right.
+        let text = Text::raw("1 \u{05D0}\u{05D1}");
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(8, 1, &mut pool);
+        Paragraph::new(text.clone()).render(Rect::new(0, 0, 8, 1), &mut frame);
+        assert_eq!(row(&frame, 0), "\u{05D1}\u{05D0} 1");
+
+        let mut frame = Frame::new(8, 1, &mut pool);
+        Paragraph::new(text)
+            .direction(ParagraphDirection::Ltr)
+            .render(Rect::new(0, 0, 8, 1), &mut frame);
+        assert_eq!(row(&frame, 0), "1 \u{05D1}\u{05D0}");
+    }
+
+    #[test]
+    #[cfg(feature = "bidi")]
+    fn render_rtl_wraps_before_reordering() {
+        // Each wrapped line is reordered on its own, so the first logical
+        // word stays on the first row.
+        let para =
+            Paragraph::new(Text::raw("\u{05D0}\u{05D1} \u{05D2}\u{05D3}")).wrap(WrapMode::Word);
+        let area = Rect::new(0, 0, 3, 2);
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(3, 2, &mut pool);
+        para.render(area, &mut frame);
+
+        assert!(row(&frame, 0).contains("\u{05D1}\u{05D0}"));
+        assert_eq!(row(&frame, 1), "\u{05D3}\u{05D2}");
+    }
+
     #[test]
     fn scroll_past_all_lines() {
         let para = Paragraph::new(Text::raw("AB")).scroll((5, 0));
@@ -798,4 +923,23 @@ mod tests {
         let b = para.measure(Size::new(100, 50));
         assert_eq!(a, b);
     }
+
+    #[test]
+    fn styled_by_styles_paragraph_and_its_block() {
+        use ftui_render::cell::PackedRgba;
+
+        let sheet =
+            SelectorSheet::parse(".note { fg: #00ff00; } Paragraph > Block { bg: #202020; }")
+                .unwrap();
+        let path = [StyleNode::new(Paragraph::TYPE_NAME).classes(&["note"])];
+        let para = Paragraph::new(Text::raw("hi"))
+            .block(Block::new())
+            .styled_by(&sheet, &path);
+        assert_eq!(para.style.fg, Some(PackedRgba::rgb(0, 255, 0)));
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(4, 1, &mut pool);
+        para.render(Rect::new(0, 0, 4, 1), &mut frame);
+        let cell = frame.buffer.get(3, 0).unwrap();
+        assert_eq!(cell.bg, PackedRgba::rgb(32, 32, 32));
+    }
 }
@@ -0,0 +1,76 @@
+#![forbid(unsafe_code)]
+
+//! Styling widgets from a selector stylesheet.
+//!
+//! [`SheetStyled::styled_by`] resolves a widget's styles from a
+//! [`SelectorSheet`] for its place in the render tree, given as the
+//! [`StyleNode`] path from the root down to the widget itself. The widget
+//! adds its own interaction state (e.g. `:focus` on a focused [`TextInput`])
+//! and styles its parts as child nodes:
+//!
+//! | Widget        | Type name   | Parts                                                      |
+//! |---------------|-------------|------------------------------------------------------------|
+//! | [`Block`]     | `Block`     | `Border`                                                   |
+//! | [`Paragraph`] | `Paragraph` | `Block`                                                    |
+//! | [`List`]      | `List`      | `ListItem`, `ListItem:selected`, `ListItem:hover`, `Block` |
+//! | [`Table`]     | `Table`     | `Header`, `Row`, `Row:selected`, `Block`                   |
+//! | [`TextInput`] | `TextInput` | `Cursor`, `Placeholder`, `Selection`                       |
+//!
+//! Styles set with the `*_style` builders override sheet rules property by
+//! property; attributes from both accumulate.
+//!
+//! # Example
+//! ```
+//! use ftui_style::{SelectorSheet, StyleNode};
+//! use ftui_widgets::list::{List, ListItem};
+//! use ftui_widgets::sheet::SheetStyled;
+//!
+//! let sheet = SelectorSheet::parse(
+//!     "#sidebar List > ListItem:selected { fg: black; bg: bright-yellow; }",
+//! )
+//! .unwrap();
+//! let path = [
+//!     StyleNode::new("Panel").id("sidebar"),
+//!     StyleNode::new(List::TYPE_NAME),
+//! ];
+//! let list = List::new([ListItem::new("one")]).styled_by(&sheet, &path);
+//! # let _ = list;
+//! ```
+//!
+//! [`Block`]: crate::block::Block
+//! [`Paragraph`]: crate::paragraph::Paragraph
+//! [`List`]: crate::list::List
+//! [`Table`]: crate::table::Table
+//! [`TextInput`]: crate::input::TextInput
+
+use ftui_style::{SelectorSheet, Style, StyleNode};
+
+/// A widget whose styles can be resolved from a [`SelectorSheet`].
+pub trait SheetStyled: Sized {
+    /// Type name matched by type selectors, e.g. `"List"`.
+    const TYPE_NAME: &'static str;
+
+    /// Resolve this widget's styles from `sheet`.
+    ///
+    /// `path` runs from the render-tree root to this widget; its last node
+    /// describes the widget (id, classes, state). Styles already set with
+    /// the `*_style` builders take precedence.
+    #[must_use]
+    fn styled_by(self, sheet: &SelectorSheet, path: &[StyleNode<'_>]) -> Self;
+}
+
+/// Sheet style of a part of the widget at the end of `path`.
+pub(crate) fn part_style(
+    sheet: &SelectorSheet,
+    path: &[StyleNode<'_>],
+    part: StyleNode<'_>,
+) -> Style {
+    sheet.resolve(&child_path(path, part))
+}
+
+/// Extend `path` with a child node, for styling nested widgets.
+pub(crate) fn child_path<'a>(path: &[StyleNode<'a>], child: StyleNode<'a>) -> Vec<StyleNode<'a>> {
+    let mut nodes = path.to_vec();
+    nodes.push(child);
+    nodes
+}
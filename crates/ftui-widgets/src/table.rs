@@ -1,5 +1,6 @@
 use crate::block::Block;
 use crate::mouse::MouseResult;
+use crate::sheet::{self, SheetStyled};
 use crate::undo_support::{TableUndoExt, UndoSupport, UndoWidgetId};
 use crate::{
     MeasurableWidget, SizeConstraints, StatefulWidget, Widget, apply_style, set_style_area,
@@ -11,7 +12,8 @@ use ftui_render::buffer::Buffer;
 use ftui_render::cell::Cell;
 use ftui_render::frame::{Frame, HitId, HitRegion};
 use ftui_style::{
-    Style, TableEffectResolver, TableEffectScope, TableEffectTarget, TableSection, TableTheme,
+    SelectorSheet, Style, StyleNode, TableEffectResolver, TableEffectScope, TableEffectTarget,
+    TableSection, TableTheme, WidgetState,
 };
 use ftui_text::Text;
 use std::any::Any;
@@ -205,6 +207,37 @@ impl<'a> Table<'a> {
     }
 }
 
+impl<'a> SheetStyled for Table<'a> {
+    const TYPE_NAME: &'static str = "Table";
+
+    fn styled_by(mut self, sheet: &SelectorSheet, path: &[StyleNode<'_>]) -> Self {
+        let row = StyleNode::new("Row");
+        self.style = self.style.merge(&sheet.resolve(path));
+        self.highlight_style = self.highlight_style.merge(&sheet::part_style(
+            sheet,
+            path,
+            row.state(WidgetState::SELECTED),
+        ));
+        let row_style = sheet::part_style(sheet, path, row);
+        for row in &mut self.rows {
+            row.style = row.style.merge(&row_style);
+        }
+        if let Some(header) = &mut self.header {
+            header.style =
+                header
+                    .style
+                    .merge(&sheet::part_style(sheet, path, StyleNode::new("Header")));
+        }
+        self.block = self.block.map(|block| {
+            block.styled_by(
+                sheet,
+                &sheet::child_path(path, StyleNode::new(Block::TYPE_NAME)),
+            )
+        });
+        self
+    }
+}
+
 impl<'a> Widget for Table<'a> {
     fn render(&self, area: Rect, frame: &mut Frame) {
         let mut state = TableState::default();
@@ -2633,4 +2666,21 @@ mod tests {
         // Should not overflow — saturates at u16::MAX
         assert!(c.preferred.height > 0);
     }
+
+    #[test]
+    fn styled_by_resolves_header_and_rows() {
+        let sheet = SelectorSheet::parse(
+            "Table { fg: #ffffff; } Table > Header { attrs: bold; } \
+             Table > Row:selected { bg: #0000ff; } Row { bg: #111111; }",
+        )
+        .unwrap();
+        let table = Table::new([Row::new(["a"])], [Constraint::Fixed(3)])
+            .header(Row::new(["H"]))
+            .styled_by(&sheet, &[StyleNode::new(Table::TYPE_NAME)]);
+        assert_eq!(table.style.fg, Some(PackedRgba::rgb(255, 255, 255)));
+        let header = table.header.as_ref().unwrap();
+        assert!(header.style.has_attr(ftui_style::StyleFlags::BOLD));
+        assert_eq!(table.rows[0].style.bg, Some(PackedRgba::rgb(17, 17, 17)));
+        assert_eq!(table.highlight_style.bg, Some(PackedRgba::rgb(0, 0, 255)));
+    }
 }
@@ -11,16 +11,74 @@
 //! ta.insert_text("Hello\nWorld");
 //! assert_eq!(ta.line_count(), 2);
 //! ```
-
-use ftui_core::event::{Event, KeyCode, KeyEvent, KeyEventKind, Modifiers};
+//!
+//! # Code editing
+//!
+//! A [`LineHighlighter`] can be attached with [`TextArea::with_highlighter`].
+//! Lines are tokenized lazily during render and cached together with the
+//! lexer state at the end of each line, so an edit only re-lexes the edited
+//! lines plus any following lines whose incoming state changed.
+//!
+//! Bracket matching, auto-indent, tab/indent width and visible whitespace are
+//! opt-in builder settings.
+//!
+//! # Bidirectional text
+//!
+//! With the `bidi` feature, lines containing right-to-left text are drawn in
+//! bidi visual order (each
+//! soft-wrapped row reordered with its line's base direction), and Left/Right
+//! move the cursor visually. Selections remain logical byte ranges.
+//!
+//! # Find and replace
+//!
+//! [`TextArea::search`] runs an incremental find (call it as the query is
+//! typed), highlights every match, and selects the current one.
+//! [`TextArea::replace_all`] is a single undo step on the [`Editor`] history.
+//!
+//! # Multiple cursors
+//!
+//! Ctrl+Alt+Up/Down adds a cursor above/below, Ctrl+D selects the next
+//! occurrence of the selection, Alt+Shift+arrows or Alt+mouse drag extend a
+//! rectangular selection, and Esc returns to a single cursor. Secondary cursors are drawn
+//! with [`TextArea::with_secondary_cursor_style`]; the terminal cursor shows
+//! the primary one.
+//!
+//! # Keymaps
+//!
+//! [`TextArea::with_keymap`] installs a vim or emacs [`Keymap`] that sees key
+//! presses before the built-in bindings; [`TextArea::keymap_mode`] reports its
+//! mode for a status line.
+//!
+//! # Completion
+//!
+//! [`TextArea::with_completion`] attaches a [`Completer`] for the word before
+//! the cursor. The top candidate shows as ghost text at the end of the line;
+//! [`TextArea::completion_popup`] returns the candidate list anchored at the
+//! last rendered cursor position, to be drawn over the rest of the screen.
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::fmt;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::ops::Range;
+use std::sync::Arc;
+
+use ftui_core::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, Modifiers, MouseButton, MouseEvent, MouseEventKind,
+};
 use ftui_core::geometry::Rect;
 use ftui_render::frame::Frame;
 use ftui_style::Style;
+#[cfg(feature = "bidi")]
+use ftui_text::bidi::{self, BidiLine, ParagraphDirection};
 use ftui_text::editor::{Editor, Selection};
+use ftui_text::search::SearchOptions;
 use ftui_text::wrap::display_width;
 use ftui_text::{CursorNavigator, CursorPosition};
 use unicode_segmentation::UnicodeSegmentation;
 
+use crate::completion::{Completer, CompletionKeyResult, CompletionPopup};
+use crate::keymap::{Keymap, KeymapMode, KeymapResult, KeymapSlot, KeymapTarget};
 use crate::{StatefulWidget, Widget, apply_style, draw_text_span};
 
 /// Multi-line text editor widget.
@@ -59,6 +117,45 @@ pub struct TextArea {
     last_viewport_height: std::cell::Cell<usize>,
     /// Last viewport width for visibility checks.
     last_viewport_width: std::cell::Cell<usize>,
+    /// Optional incremental syntax highlighter.
+    highlighter: Option<HighlighterSlot>,
+    /// Per-line highlight cache, revalidated lazily on render.
+    highlight_cache: RefCell<HighlightCache>,
+    /// Highlight the bracket pair around the cursor.
+    bracket_matching: bool,
+    /// Matched bracket highlight style.
+    bracket_match_style: Style,
+    /// Copy indentation (and indent after an opening bracket) on Enter.
+    auto_indent: bool,
+    /// Tab / BackTab indent and dedent instead of being left unhandled.
+    tab_indents: bool,
+    /// Display width of a tab stop in cells.
+    tab_width: usize,
+    /// Width of one indentation level in columns.
+    indent_width: usize,
+    /// Indent with spaces instead of tab characters.
+    insert_spaces: bool,
+    /// Render spaces and tabs with visible markers.
+    show_whitespace: bool,
+    /// Style of visible whitespace markers.
+    whitespace_style: Style,
+    /// Active find query, if any.
+    search: Option<SearchState>,
+    /// Highlight style for search matches.
+    search_highlight_style: Style,
+    /// Style of secondary cursors.
+    secondary_cursor_style: Style,
+    /// Rectangular selection in progress: anchor and unclamped head
+    /// `(line, visual_col)`.
+    column_select: Option<(CursorPosition, usize, usize)>,
+    /// Editing keymap consulted before the default bindings.
+    keymap: Option<KeymapSlot>,
+    /// Autocomplete state.
+    completion: Option<Completer>,
+    /// Screen position of the cursor at the last render (focused only).
+    cursor_screen: std::cell::Cell<Option<(u16, u16)>>,
+    /// Text region (right of the gutter) at the last render, for mouse hits.
+    last_text_area: std::cell::Cell<Rect>,
 }
 
 impl Default for TextArea {
@@ -76,6 +173,94 @@ pub struct TextAreaState {
     pub last_viewport_width: u16,
 }
 
+/// Opaque lexer state carried from the end of one line to the start of the next.
+///
+/// `0` is the initial state used for the first line of the document.
+pub type HighlightState = u64;
+
+/// A styled byte range within a single line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HighlightSpan {
+    /// Start byte offset within the line.
+    pub start: usize,
+    /// End byte offset within the line (exclusive).
+    pub end: usize,
+    /// Style applied to the range.
+    pub style: Style,
+}
+
+impl HighlightSpan {
+    /// Create a styled span covering `start..end`.
+    #[must_use]
+    pub const fn new(start: usize, end: usize, style: Style) -> Self {
+        Self { start, end, style }
+    }
+}
+
+/// Incremental, line-oriented syntax highlighter for [`TextArea`].
+///
+/// Implementors style a single line given the state left by the previous
+/// line and return the state to carry into the next one. Spans must be
+/// sorted by `start` and must not overlap; unstyled gaps use the base style.
+pub trait LineHighlighter: Send + Sync {
+    /// Highlight one line (without its trailing newline).
+    fn highlight_line(
+        &self,
+        line: &str,
+        state: HighlightState,
+    ) -> (Vec<HighlightSpan>, HighlightState);
+}
+
+/// Shared highlighter handle (keeps `TextArea: Clone + Debug`).
+#[derive(Clone)]
+struct HighlighterSlot(Arc<dyn LineHighlighter>);
+
+impl fmt::Debug for HighlighterSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("LineHighlighter")
+    }
+}
+
+/// Cached highlight result for one line.
+#[derive(Debug, Clone)]
+struct HighlightedLine {
+    /// Hash of the line text the spans were computed for.
+    text_hash: u64,
+    state_in: HighlightState,
+    state_out: HighlightState,
+    spans: Vec<HighlightSpan>,
+}
+
+/// Highlight results indexed by line, kept aligned with the text across
+/// edits that add or remove lines.
+#[derive(Debug, Clone, Default)]
+struct HighlightCache {
+    /// Entries past `valid` may be stale and are only reused while their
+    /// text and incoming state still match.
+    lines: Vec<Option<HighlightedLine>>,
+    /// Leading lines known to be current at `revision`.
+    valid: usize,
+    /// Editor revision the cache was last aligned to.
+    revision: u64,
+    /// Line count at `revision`.
+    line_count: usize,
+}
+
+/// Active find state.
+#[derive(Debug, Clone)]
+struct SearchState {
+    query: String,
+    options: SearchOptions,
+    /// Match byte ranges and the editor revision they were computed at.
+    matches: RefCell<(u64, Vec<Range<usize>>)>,
+}
+
+/// Maximum number of lines scanned when looking for a matching bracket.
+const BRACKET_SCAN_LINES: usize = 1_000;
+
+/// Bracket pairs recognized by bracket matching and auto-indent.
+const BRACKET_PAIRS: [(char, char); 3] = [('(', ')'), ('[', ']'), ('{', '}')];
+
 #[derive(Debug, Clone)]
 struct WrappedSlice {
     text: String,
@@ -104,6 +289,25 @@ impl TextArea {
             scroll_left: std::cell::Cell::new(0),
             last_viewport_height: std::cell::Cell::new(0),
             last_viewport_width: std::cell::Cell::new(0),
+            highlighter: None,
+            highlight_cache: RefCell::new(HighlightCache::default()),
+            bracket_matching: false,
+            bracket_match_style: Style::new().bold().underline(),
+            auto_indent: false,
+            tab_indents: false,
+            tab_width: 4,
+            indent_width: 4,
+            insert_spaces: true,
+            show_whitespace: false,
+            whitespace_style: Style::new().dim(),
+            search: None,
+            search_highlight_style: Style::new().underline(),
+            secondary_cursor_style: Style::new().reverse(),
+            column_select: None,
+            keymap: None,
+            completion: None,
+            cursor_screen: std::cell::Cell::new(None),
+            last_text_area: std::cell::Cell::new(Rect::default()),
         }
     }
 
@@ -117,22 +321,112 @@ impl TextArea {
             Event::Key(key)
                 if key.kind == KeyEventKind::Press || key.kind == KeyEventKind::Repeat =>
             {
-                self.handle_key(key)
+                if self.completion_key(key) {
+                    return true;
+                }
+                let changed = if let Some(mut slot) = self.keymap.take() {
+                    let result = slot.0.handle_key(key, self);
+                    self.keymap = Some(slot);
+                    result == KeymapResult::Handled || self.handle_key(key)
+                } else {
+                    self.handle_key(key)
+                };
+                self.update_completion();
+                changed
             }
             Event::Paste(paste) => {
                 self.insert_text(&paste.text);
+                self.update_completion();
                 true
             }
+            Event::Mouse(mouse) => self.handle_mouse(mouse),
             _ => false,
         }
     }
 
+    /// Alt+click starts a rectangular selection and Alt+drag extends it.
+    fn handle_mouse(&mut self, mouse: &MouseEvent) -> bool {
+        if !mouse.modifiers.contains(Modifiers::ALT) {
+            return false;
+        }
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left)
+                if self.last_text_area.get().contains(mouse.x, mouse.y) =>
+            {
+                let (line, col) = self.cell_at_screen(mouse.x, mouse.y);
+                let anchor = CursorPosition::new(line, 0, col);
+                self.select_rectangle(anchor, anchor);
+                self.column_select = Some((anchor, line, col));
+                true
+            }
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let Some((anchor, _, _)) = self.column_select else {
+                    return false;
+                };
+                let (line, col) = self.cell_at_screen(mouse.x, mouse.y);
+                self.select_rectangle(anchor, CursorPosition::new(line, 0, col));
+                self.column_select = Some((anchor, line, col));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Let the completer handle `key`. Returns `true` if it consumed it.
+    fn completion_key(&mut self, key: &KeyEvent) -> bool {
+        let Some(completion) = &mut self.completion else {
+            return false;
+        };
+        let text = self.editor.text();
+        let cursor = self.editor.cursor_offset();
+        match completion.handle_key(key, &text, cursor) {
+            CompletionKeyResult::Ignored => false,
+            CompletionKeyResult::Handled => true,
+            CompletionKeyResult::Accept { range, value } => {
+                self.editor
+                    .replace_byte_range(range.start, range.end, &value);
+                self.ensure_cursor_visible();
+                true
+            }
+        }
+    }
+
+    fn update_completion(&mut self) {
+        if let Some(completion) = &mut self.completion {
+            completion.update(&self.editor.text(), self.editor.cursor_offset());
+        }
+    }
+
     fn handle_key(&mut self, key: &KeyEvent) -> bool {
         let ctrl = key.modifiers.contains(Modifiers::CTRL);
         let shift = key.modifiers.contains(Modifiers::SHIFT);
-        let _alt = key.modifiers.contains(Modifiers::ALT);
+        let alt = key.modifiers.contains(Modifiers::ALT);
+        let column_select = self.column_select.take();
 
         match key.code {
+            // Alt+Shift+arrows: rectangular selection
+            KeyCode::Left | KeyCode::Right | KeyCode::Up | KeyCode::Down if alt && shift => {
+                self.extend_column_selection(key.code, column_select);
+                true
+            }
+            // Ctrl+Alt+Up/Down: add a cursor above / below
+            KeyCode::Up if ctrl && alt => {
+                self.add_cursor_above();
+                true
+            }
+            KeyCode::Down if ctrl && alt => {
+                self.add_cursor_below();
+                true
+            }
+            // Ctrl+D: select next occurrence with a new cursor
+            KeyCode::Char('d') if ctrl => {
+                self.add_next_occurrence();
+                true
+            }
+            KeyCode::Escape if self.editor.cursor_count() > 1 => {
+                self.clear_secondary_cursors();
+                true
+            }
             KeyCode::Char(c) if !ctrl => {
                 self.insert_char(c);
                 true
@@ -141,6 +435,27 @@ impl TextArea {
                 self.insert_newline();
                 true
             }
+            KeyCode::Tab if self.tab_indents && !ctrl => {
+                if shift {
+                    self.dedent();
+                } else {
+                    self.indent();
+                }
+                true
+            }
+            KeyCode::BackTab if self.tab_indents => {
+                self.dedent();
+                true
+            }
+            // F3 / Shift+F3: next / previous search match
+            KeyCode::F(3) if self.search.is_some() => {
+                if shift {
+                    self.prev_match();
+                } else {
+                    self.next_match();
+                }
+                true
+            }
             KeyCode::Backspace => {
                 if ctrl {
                     self.delete_word_backward();
@@ -243,6 +558,7 @@ impl TextArea {
     pub fn with_text(mut self, text: &str) -> Self {
         self.editor = Editor::with_text(text);
         self.editor.move_to_document_start();
+        self.reset_highlights();
         self
     }
 
@@ -302,6 +618,154 @@ impl TextArea {
         self
     }
 
+    /// Attach an incremental syntax highlighter (builder).
+    #[must_use]
+    pub fn with_highlighter(mut self, highlighter: Arc<dyn LineHighlighter>) -> Self {
+        self.set_highlighter(Some(highlighter));
+        self
+    }
+
+    /// Enable bracket pair highlighting around the cursor (builder).
+    #[must_use]
+    pub fn with_bracket_matching(mut self, enabled: bool) -> Self {
+        self.bracket_matching = enabled;
+        self
+    }
+
+    /// Set matched bracket style (builder).
+    #[must_use]
+    pub fn with_bracket_match_style(mut self, style: Style) -> Self {
+        self.bracket_match_style = style;
+        self
+    }
+
+    /// Enable auto-indent on Enter (builder).
+    #[must_use]
+    pub fn with_auto_indent(mut self, enabled: bool) -> Self {
+        self.auto_indent = enabled;
+        self
+    }
+
+    /// Let Tab / Shift+Tab indent and dedent (builder).
+    ///
+    /// Off by default so Tab keeps moving focus between widgets.
+    #[must_use]
+    pub fn with_tab_indents(mut self, enabled: bool) -> Self {
+        self.tab_indents = enabled;
+        self
+    }
+
+    /// Set tab stop width in cells (builder). Clamped to at least 1.
+    #[must_use]
+    pub fn with_tab_width(mut self, width: usize) -> Self {
+        self.tab_width = width.max(1);
+        self
+    }
+
+    /// Set indentation width in columns (builder). Clamped to at least 1.
+    #[must_use]
+    pub fn with_indent_width(mut self, width: usize) -> Self {
+        self.indent_width = width.max(1);
+        self
+    }
+
+    /// Indent with spaces (`true`) or tab characters (`false`) (builder).
+    #[must_use]
+    pub fn with_insert_spaces(mut self, spaces: bool) -> Self {
+        self.insert_spaces = spaces;
+        self
+    }
+
+    /// Render spaces as `·` and tabs as `→` (builder).
+    #[must_use]
+    pub fn with_show_whitespace(mut self, show: bool) -> Self {
+        self.show_whitespace = show;
+        self
+    }
+
+    /// Set visible whitespace marker style (builder).
+    #[must_use]
+    pub fn with_whitespace_style(mut self, style: Style) -> Self {
+        self.whitespace_style = style;
+        self
+    }
+
+    /// Set secondary cursor style (builder).
+    #[must_use]
+    pub fn with_secondary_cursor_style(mut self, style: Style) -> Self {
+        self.secondary_cursor_style = style;
+        self
+    }
+
+    /// Set search match highlight style (builder).
+    #[must_use]
+    pub fn with_search_highlight_style(mut self, style: Style) -> Self {
+        self.search_highlight_style = style;
+        self
+    }
+
+    /// Install an editing keymap (builder).
+    #[must_use]
+    pub fn with_keymap(mut self, keymap: impl Keymap + 'static) -> Self {
+        self.keymap = Some(KeymapSlot(Box::new(keymap)));
+        self
+    }
+
+    /// Install or remove the editing keymap.
+    pub fn set_keymap(&mut self, keymap: Option<Box<dyn Keymap>>) {
+        self.keymap = keymap.map(KeymapSlot);
+    }
+
+    /// The installed editing keymap, if any.
+    #[must_use]
+    pub fn keymap(&self) -> Option<&dyn Keymap> {
+        self.keymap.as_ref().map(|slot| &*slot.0)
+    }
+
+    /// Mode of the installed keymap, for status-line display.
+    #[must_use]
+    pub fn keymap_mode(&self) -> Option<KeymapMode> {
+        self.keymap.as_ref().map(|slot| slot.0.mode())
+    }
+
+    /// Attach autocomplete (builder).
+    #[must_use]
+    pub fn with_completion(mut self, completer: Completer) -> Self {
+        self.completion = Some(completer);
+        self
+    }
+
+    /// Attach or remove autocomplete.
+    pub fn set_completion(&mut self, completer: Option<Completer>) {
+        self.completion = completer;
+    }
+
+    /// The attached completer, if any.
+    #[must_use]
+    pub fn completion(&self) -> Option<&Completer> {
+        self.completion.as_ref()
+    }
+
+    /// Mutable access to the attached completer, e.g. to deliver async results.
+    pub fn completion_mut(&mut self) -> Option<&mut Completer> {
+        self.completion.as_mut()
+    }
+
+    /// Candidate popup anchored under the word being completed.
+    ///
+    /// Uses the cursor position of the last render; `None` if the text area
+    /// was not focused or no candidates are shown.
+    #[must_use]
+    pub fn completion_popup(&self) -> Option<CompletionPopup> {
+        let completion = self.completion.as_ref()?;
+        let (x, y) = self.cursor_screen.get()?;
+        let range = completion.word_range()?;
+        let text = self.editor.text();
+        let prefix = text.get(range)?;
+        let width = display_width(prefix).min(u16::MAX as usize) as u16;
+        completion.popup((x.saturating_sub(width), y))
+    }
+
     // ── State access ───────────────────────────────────────────────
 
     /// Get the full text content.
@@ -377,6 +841,34 @@ impl TextArea {
         &mut self.editor
     }
 
+    /// Replace (or remove) the syntax highlighter. Drops cached highlights.
+    pub fn set_highlighter(&mut self, highlighter: Option<Arc<dyn LineHighlighter>>) {
+        self.highlighter = highlighter.map(HighlighterSlot);
+        self.reset_highlights();
+    }
+
+    /// Whether a syntax highlighter is attached.
+    #[must_use]
+    pub fn has_highlighter(&self) -> bool {
+        self.highlighter.is_some()
+    }
+
+    /// Highlight spans for a line, lexing it (and any stale lines above it)
+    /// if needed. Returns an empty list when no highlighter is attached.
+    #[must_use]
+    pub fn line_highlights(&self, line: usize) -> Vec<HighlightSpan> {
+        if line >= self.editor.line_count() {
+            return Vec::new();
+        }
+        self.refresh_highlights(line);
+        self.highlight_cache
+            .borrow()
+            .lines
+            .get(line)
+            .and_then(|h| h.as_ref().map(|h| h.spans.clone()))
+            .unwrap_or_default()
+    }
+
     // ── Editing operations (delegated to Editor) ───────────────────
 
     /// Insert text at cursor.
@@ -392,11 +884,140 @@ impl TextArea {
     }
 
     /// Insert a newline.
+    ///
+    /// With auto-indent enabled the new line inherits the current line's
+    /// leading whitespace, plus one indent level after an opening bracket.
     pub fn insert_newline(&mut self) {
-        self.editor.insert_newline();
+        if !self.auto_indent {
+            self.editor.insert_newline();
+            self.ensure_cursor_visible();
+            return;
+        }
+        let cursor = self.editor.cursor();
+        let line = self.editor.line_text(cursor.line).unwrap_or_default();
+        let before: String = line.graphemes(true).take(cursor.grapheme).collect();
+        let mut text = String::from("\n");
+        text.extend(before.chars().take_while(|c| *c == ' ' || *c == '\t'));
+        if before
+            .trim_end()
+            .chars()
+            .next_back()
+            .is_some_and(|c| BRACKET_PAIRS.iter().any(|&(open, _)| open == c))
+        {
+            text.push_str(&self.indent_unit());
+        }
+        self.editor.insert_text(&text);
+        self.ensure_cursor_visible();
+    }
+
+    /// Insert one indent level at the cursor, or indent every selected line.
+    ///
+    /// Indenting several lines is a single undo step and keeps the
+    /// selection on the same text.
+    pub fn indent(&mut self) {
+        if let Some(sel) = self.editor.selection().filter(|s| !s.is_empty())
+            && sel.anchor.line != sel.head.line
+        {
+            let first = sel.anchor.line.min(sel.head.line);
+            let last = sel.anchor.line.max(sel.head.line);
+            let unit = self.indent_unit();
+            let width = unit.graphemes(true).count();
+            self.editor.begin_undo_group();
+            for line in first..=last {
+                self.editor.set_cursor(CursorPosition::new(line, 0, 0));
+                self.editor.insert_text(&unit);
+            }
+            self.editor.end_undo_group();
+            let shift = |pos: CursorPosition| pos.grapheme + width;
+            self.reselect(sel, shift(sel.anchor), shift(sel.head));
+            return;
+        }
+        let text = if self.insert_spaces {
+            let col = self.cursor_display_col();
+            " ".repeat(self.indent_width - col % self.indent_width)
+        } else {
+            "\t".to_string()
+        };
+        self.editor.insert_text(&text);
+        self.ensure_cursor_visible();
+    }
+
+    /// Remove up to one indent level from the start of the cursor line, or
+    /// of every selected line.
+    ///
+    /// Dedenting several lines is a single undo step and keeps the
+    /// selection on the same text.
+    pub fn dedent(&mut self) {
+        let selection = self.editor.selection().filter(|s| !s.is_empty());
+        let cursor = self.editor.cursor();
+        let (first, last) = selection.map_or((cursor.line, cursor.line), |sel| {
+            (
+                sel.anchor.line.min(sel.head.line),
+                sel.anchor.line.max(sel.head.line),
+            )
+        });
+        let removed: Vec<usize> = (first..=last)
+            .map(|line| self.leading_indent_len(line))
+            .collect();
+        if removed.iter().all(|&n| n == 0) {
+            return;
+        }
+        self.editor.begin_undo_group();
+        for (line, &remove) in (first..=last).zip(&removed) {
+            if remove == 0 {
+                continue;
+            }
+            self.editor.set_cursor(CursorPosition::new(line, 0, 0));
+            for _ in 0..remove {
+                self.editor.select_right();
+            }
+            self.editor.delete_backward();
+        }
+        self.editor.end_undo_group();
+        let shift = |pos: CursorPosition| pos.grapheme.saturating_sub(removed[pos.line - first]);
+        match selection {
+            Some(sel) => self.reselect(sel, shift(sel.anchor), shift(sel.head)),
+            None => {
+                self.editor
+                    .set_cursor(CursorPosition::new(cursor.line, shift(cursor), 0));
+                self.ensure_cursor_visible();
+            }
+        }
+    }
+
+    /// Graphemes [`dedent`](Self::dedent) removes from the start of `line`:
+    /// one tab, or up to one indent width of spaces.
+    fn leading_indent_len(&self, line: usize) -> usize {
+        let text = self.editor.line_text(line).unwrap_or_default();
+        if text.starts_with('\t') {
+            1
+        } else {
+            text.chars()
+                .take(self.indent_width)
+                .take_while(|c| *c == ' ')
+                .count()
+        }
+    }
+
+    /// Select from `sel`'s anchor line to its head line again after a
+    /// reindent, at the given grapheme columns.
+    fn reselect(&mut self, sel: Selection, anchor_grapheme: usize, head_grapheme: usize) {
+        let nav = CursorNavigator::new(self.editor.rope());
+        let anchor = nav.to_byte_index(nav.from_line_grapheme(sel.anchor.line, anchor_grapheme));
+        let head = nav.to_byte_index(nav.from_line_grapheme(sel.head.line, head_grapheme));
+        self.editor.select_byte_range(anchor, head);
         self.ensure_cursor_visible();
     }
 
+    /// Text inserted for one indentation level.
+    fn indent_unit(&self) -> String {
+        if self.insert_spaces {
+            " ".repeat(self.indent_width)
+        } else {
+            "\t".to_string()
+        }
+    }
+
     /// Delete backward (backspace).
     pub fn delete_backward(&mut self) {
         self.editor.delete_backward();
@@ -433,16 +1054,151 @@ impl TextArea {
         self.ensure_cursor_visible();
     }
 
+    // ── Find / replace ─────────────────────────────────────────────
+
+    /// Start or update an incremental search and return the match count.
+    ///
+    /// Selects the first match at or after the start of the current
+    /// selection, so extending the query keeps the same match selected.
+    /// An empty query clears the search.
+    pub fn search(&mut self, query: &str, options: SearchOptions) -> usize {
+        if query.is_empty() {
+            self.clear_search();
+            return 0;
+        }
+        let start = self.editor.selection().map_or_else(
+            || self.editor.cursor(),
+            |sel| {
+                let nav = CursorNavigator::new(self.editor.rope());
+                nav.from_byte_index(sel.byte_range(&nav).0)
+            },
+        );
+        self.search = Some(SearchState {
+            query: query.to_string(),
+            options,
+            matches: RefCell::new((u64::MAX, Vec::new())),
+        });
+        let count = self.search_matches().len();
+        if count > 0 {
+            self.editor.set_cursor(start);
+            self.editor.find_next(query, &options);
+        }
+        self.ensure_cursor_visible();
+        count
+    }
+
+    /// Select the next search match (wrapping).
+    pub fn next_match(&mut self) {
+        if let Some(search) = &self.search {
+            let (query, options) = (search.query.clone(), search.options);
+            self.editor.find_next(&query, &options);
+            self.ensure_cursor_visible();
+        }
+    }
+
+    /// Select the previous search match (wrapping).
+    pub fn prev_match(&mut self) {
+        if let Some(search) = &self.search {
+            let (query, options) = (search.query.clone(), search.options);
+            self.editor.find_prev(&query, &options);
+            self.ensure_cursor_visible();
+        }
+    }
+
+    /// Clear the active search.
+    pub fn clear_search(&mut self) {
+        self.search = None;
+    }
+
+    /// Active search query, if any.
+    #[must_use]
+    pub fn search_query(&self) -> Option<&str> {
+        self.search.as_ref().map(|s| s.query.as_str())
+    }
+
+    /// Current search match info: `(current_match_1indexed, total_matches)`.
+    ///
+    /// `current` is 0 when the selection is not on a match.
+    #[must_use]
+    pub fn search_info(&self) -> Option<(usize, usize)> {
+        let matches = self.search_matches();
+        if matches.is_empty() {
+            return None;
+        }
+        let current = self
+            .editor
+            .selection()
+            .and_then(|sel| {
+                let nav = CursorNavigator::new(self.editor.rope());
+                let (a, b) = sel.byte_range(&nav);
+                matches.iter().position(|m| m.start == a && m.end == b)
+            })
+            .map_or(0, |i| i + 1);
+        Some((current, matches.len()))
+    }
+
+    /// Replace the selected match and select the next one.
+    ///
+    /// Returns `true` if a replacement was made.
+    pub fn replace_match(&mut self, replacement: &str) -> bool {
+        let Some(search) = &self.search else {
+            return false;
+        };
+        let (query, options) = (search.query.clone(), search.options);
+        let replaced = self.editor.replace_next(&query, replacement, &options);
+        self.ensure_cursor_visible();
+        replaced
+    }
+
+    /// Replace every search match as a single undo step.
+    ///
+    /// Returns the number of replacements.
+    pub fn replace_all(&mut self, replacement: &str) -> usize {
+        let Some(search) = &self.search else {
+            return 0;
+        };
+        let (query, options) = (search.query.clone(), search.options);
+        let count = self.editor.replace_all(&query, replacement, &options);
+        self.ensure_cursor_visible();
+        count
+    }
+
+    /// Byte ranges of all search matches, recomputed after edits.
+    fn search_matches(&self) -> Vec<Range<usize>> {
+        let Some(search) = &self.search else {
+            return Vec::new();
+        };
+        let revision = self.editor.revision();
+        let mut cached = search.matches.borrow_mut();
+        if cached.0 != revision {
+            *cached = (
+                revision,
+                self.editor
+                    .find_all(&search.query, &search.options)
+                    .into_iter()
+                    .map(|m| m.range)
+                    .collect(),
+            );
+        }
+        cached.1.clone()
+    }
+
     // ── Navigation ─────────────────────────────────────────────────
 
-    /// Move cursor left.
+    /// Move cursor left on screen (visual order in right-to-left text).
     pub fn move_left(&mut self) {
+        #[cfg(feature = "bidi")]
+        self.editor.move_visual_left();
+        #[cfg(not(feature = "bidi"))]
         self.editor.move_left();
         self.ensure_cursor_visible();
     }
 
-    /// Move cursor right.
+    /// Move cursor right on screen (visual order in right-to-left text).
     pub fn move_right(&mut self) {
+        #[cfg(feature = "bidi")]
+        self.editor.move_visual_right();
+        #[cfg(not(feature = "bidi"))]
         self.editor.move_right();
         self.ensure_cursor_visible();
     }
@@ -497,14 +1253,20 @@ impl TextArea {
 
     // ── Selection ──────────────────────────────────────────────────
 
-    /// Extend selection left.
+    /// Extend selection left on screen.
     pub fn select_left(&mut self) {
+        #[cfg(feature = "bidi")]
+        self.editor.select_visual_left();
+        #[cfg(not(feature = "bidi"))]
         self.editor.select_left();
         self.ensure_cursor_visible();
     }
 
-    /// Extend selection right.
+    /// Extend selection right on screen.
     pub fn select_right(&mut self) {
+        #[cfg(feature = "bidi")]
+        self.editor.select_visual_right();
+        #[cfg(not(feature = "bidi"))]
         self.editor.select_right();
         self.ensure_cursor_visible();
     }
@@ -531,6 +1293,104 @@ impl TextArea {
         self.editor.clear_selection();
     }
 
+    // ── Multiple cursors ───────────────────────────────────────────
+
+    /// Number of cursors, including the primary one.
+    #[must_use]
+    pub fn cursor_count(&self) -> usize {
+        self.editor.cursor_count()
+    }
+
+    /// Add a cursor one line above the topmost cursor.
+    pub fn add_cursor_above(&mut self) {
+        self.editor.add_cursor_above();
+        self.ensure_cursor_visible();
+    }
+
+    /// Add a cursor one line below the bottommost cursor.
+    pub fn add_cursor_below(&mut self) {
+        self.editor.add_cursor_below();
+        self.ensure_cursor_visible();
+    }
+
+    /// Select the next occurrence of the selection (or the word under the
+    /// cursor) with a new cursor.
+    pub fn add_next_occurrence(&mut self) {
+        self.editor.add_next_occurrence();
+        self.ensure_cursor_visible();
+    }
+
+    /// Replace all cursors with a rectangular selection between two corners.
+    ///
+    /// See [`Editor::select_rectangle`].
+    pub fn select_rectangle(&mut self, anchor: CursorPosition, head: CursorPosition) {
+        self.editor.select_rectangle(anchor, head);
+        self.ensure_cursor_visible();
+    }
+
+    /// Remove all secondary cursors.
+    pub fn clear_secondary_cursors(&mut self) {
+        self.editor.clear_secondary_cursors();
+    }
+
+    /// Move the head corner of the rectangular selection one step.
+    ///
+    /// The head column is tracked unclamped so it survives short lines.
+    fn extend_column_selection(
+        &mut self,
+        code: KeyCode,
+        current: Option<(CursorPosition, usize, usize)>,
+    ) {
+        let cursor = self.editor.cursor();
+        let (anchor, line, col) = current.unwrap_or((cursor, cursor.line, cursor.visual_col));
+        let last_line = self.editor.line_count().saturating_sub(1);
+        let (line, col) = match code {
+            KeyCode::Left => (line, col.saturating_sub(1)),
+            KeyCode::Right => (line, col + 1),
+            KeyCode::Up => (line.saturating_sub(1), col),
+            _ => ((line + 1).min(last_line), col),
+        };
+        self.select_rectangle(anchor, CursorPosition::new(line, 0, col));
+        self.column_select = Some((anchor, line, col));
+    }
+
+    /// Document `(line, visual column)` under a screen cell, clamped into
+    /// the text area of the last render.
+    fn cell_at_screen(&self, x: u16, y: u16) -> (usize, usize) {
+        let area = self.last_text_area.get();
+        let dx = x
+            .clamp(area.x, area.right().saturating_sub(1))
+            .saturating_sub(area.x) as usize;
+        let row = y
+            .clamp(area.y, area.bottom().saturating_sub(1))
+            .saturating_sub(area.y) as usize;
+        let scroll_top = match self.scroll_top.get() {
+            usize::MAX => 0,
+            top => top,
+        };
+        let last_line = self.editor.line_count().saturating_sub(1);
+        if !self.soft_wrap {
+            return (
+                (scroll_top + row).min(last_line),
+                self.scroll_left.get() + dx,
+            );
+        }
+
+        // Soft wrap: scroll_top counts wrapped rows.
+        let width = area.width as usize;
+        let mut target = scroll_top + row;
+        for line in 0..=last_line {
+            let text = self.editor.line_text(line).unwrap_or_default();
+            let slices = Self::wrap_line_slices(&text, width, self.tab_width);
+            if target < slices.len() || line == last_line {
+                let slice = &slices[target.min(slices.len() - 1)];
+                return (line, slice.start_col + dx.min(slice.width));
+            }
+            target -= slices.len();
+        }
+        (last_line, dx)
+    }
+
     // ── Viewport management ────────────────────────────────────────
 
     /// Page up (move viewport and cursor up by viewport height).
@@ -571,7 +1431,7 @@ impl TextArea {
     /// Count how many wrapped lines this text will occupy.
     ///
     /// This is a zero-allocation version of `wrap_line_slices` for layout calculations.
-    fn measure_wrap_count(line_text: &str, max_width: usize) -> usize {
+    fn measure_wrap_count(line_text: &str, max_width: usize, tab_width: usize) -> usize {
         if line_text.is_empty() {
             return 1;
         }
@@ -580,7 +1440,7 @@ impl TextArea {
         let mut current_width = 0;
         let mut has_content = false;
 
-        Self::run_wrapping_logic(line_text, max_width, |_, width, flush| {
+        Self::run_wrapping_logic(line_text, max_width, tab_width, |_, width, flush| {
             if flush {
                 count += 1;
                 current_width = 0;
@@ -607,16 +1467,17 @@ impl TextArea {
     /// The callback receives `(start_index, width, flush)`.
     /// - `flush == true`: The current line is full/done. `width` is the width of the flushed line.
     /// - `flush == false`: Update current line width.
-    fn run_wrapping_logic<F>(line_text: &str, max_width: usize, mut callback: F)
+    fn run_wrapping_logic<F>(line_text: &str, max_width: usize, tab_width: usize, mut callback: F)
     where
         F: FnMut(usize, usize, bool),
     {
         let mut current_width = 0;
         let mut byte_cursor = 0;
+        let mut col_cursor = 0;
 
         for segment in line_text.split_word_bounds() {
             let seg_len = segment.len();
-            let seg_width: usize = segment.graphemes(true).map(display_width).sum();
+            let seg_width = segment_cells(segment, col_cursor, tab_width);
 
             if max_width > 0 && current_width + seg_width > max_width {
                 // Flush current
@@ -626,7 +1487,7 @@ impl TextArea {
 
             if max_width > 0 && seg_width > max_width {
                 for grapheme in segment.graphemes(true) {
-                    let g_width = display_width(grapheme);
+                    let g_width = grapheme_cells(grapheme, col_cursor, tab_width);
                     let g_len = grapheme.len();
 
                     if max_width > 0 && current_width + g_width > max_width && current_width > 0 {
@@ -636,6 +1497,7 @@ impl TextArea {
 
                     current_width += g_width;
                     byte_cursor += g_len;
+                    col_cursor += g_width;
                     callback(byte_cursor, current_width, false);
                 }
                 continue;
@@ -643,11 +1505,12 @@ impl TextArea {
 
             current_width += seg_width;
             byte_cursor += seg_len;
+            col_cursor += seg_width;
             callback(byte_cursor, current_width, false);
         }
     }
 
-    fn wrap_line_slices(line_text: &str, max_width: usize) -> Vec<WrappedSlice> {
+    fn wrap_line_slices(line_text: &str, max_width: usize, tab_width: usize) -> Vec<WrappedSlice> {
         if line_text.is_empty() {
             return vec![WrappedSlice {
                 text: String::new(),
@@ -690,7 +1553,7 @@ impl TextArea {
 
         for segment in line_text.split_word_bounds() {
             let seg_len = segment.len();
-            let seg_width: usize = segment.graphemes(true).map(display_width).sum();
+            let seg_width = segment_cells(segment, col_cursor, tab_width);
 
             if max_width > 0 && current_width + seg_width > max_width {
                 push_current(
@@ -706,7 +1569,7 @@ impl TextArea {
 
             if max_width > 0 && seg_width > max_width {
                 for grapheme in segment.graphemes(true) {
-                    let g_width = display_width(grapheme);
+                    let g_width = grapheme_cells(grapheme, col_cursor, tab_width);
                     let g_len = grapheme.len();
 
                     if max_width > 0 && current_width + g_width > max_width && current_width > 0 {
@@ -750,9 +1613,10 @@ impl TextArea {
     fn cursor_wrap_position(
         line_text: &str,
         max_width: usize,
+        tab_width: usize,
         cursor_col: usize,
     ) -> (usize, usize) {
-        let slices = Self::wrap_line_slices(line_text, max_width);
+        let slices = Self::wrap_line_slices(line_text, max_width, tab_width);
         if slices.is_empty() {
             return (0, 0);
         }
@@ -768,20 +1632,57 @@ impl TextArea {
         (0, 0)
     }
 
+    /// Display column of the cursor, with tabs expanded to tab stops.
+    fn cursor_display_col(&self) -> usize {
+        let cursor = self.editor.cursor();
+        let line = self.editor.line_text(cursor.line).unwrap_or_default();
+        display_col(&line, cursor.grapheme, self.tab_width)
+    }
+
+    /// Screen column of the cursor on its unwrapped line: the display column,
+    /// or the bidi caret position when the line holds right-to-left text.
+    fn cursor_screen_col(&self) -> usize {
+        let cursor = self.editor.cursor();
+        let line = self.editor.line_text(cursor.line).unwrap_or_default();
+        #[cfg(feature = "bidi")]
+        if let Some(layout) = bidi_layout(&line, ParagraphDirection::Auto, 0, self.tab_width) {
+            return layout.caret_x(cursor.grapheme);
+        }
+        display_col(&line, cursor.grapheme, self.tab_width)
+    }
+
+    /// Caret column inside wrapped slice `slice_idx` of a line holding
+    /// right-to-left text, or `None` for left-to-right lines.
+    #[cfg(feature = "bidi")]
+    fn bidi_wrap_caret(
+        line_text: &str,
+        max_width: usize,
+        tab_width: usize,
+        slice_idx: usize,
+        grapheme: usize,
+    ) -> Option<usize> {
+        if !bidi::has_rtl(line_text) {
+            return None;
+        }
+        let direction = bidi::paragraph_level(line_text);
+        let slices = Self::wrap_line_slices(line_text, max_width, tab_width);
+        let slice = slices.get(slice_idx)?;
+        let first = line_text[..slice.start_byte].graphemes(true).count();
+        let layout = bidi_layout(&slice.text, direction, slice.start_col, tab_width)?;
+        Some(layout.caret_x(grapheme.saturating_sub(first).min(layout.len())))
+    }
+
     /// Get the visual width of the character immediately before the cursor.
     fn get_prev_char_width(&self) -> usize {
         let cursor = self.editor.cursor();
         if cursor.grapheme == 0 {
             return 0;
         }
-        let rope = self.editor.rope();
-        let line = rope
-            .line(cursor.line)
-            .unwrap_or(std::borrow::Cow::Borrowed(""));
-
+        let line = self.editor.line_text(cursor.line).unwrap_or_default();
+        let col = display_col(&line, cursor.grapheme - 1, self.tab_width);
         line.graphemes(true)
             .nth(cursor.grapheme - 1)
-            .map(display_width)
+            .map(|g| grapheme_cells(g, col, self.tab_width))
             .unwrap_or(0)
     }
 
@@ -831,7 +1732,7 @@ impl TextArea {
         if !self.soft_wrap {
             let current_left = self.scroll_left.get();
 
-            let visual_col = cursor.visual_col;
+            let visual_col = self.cursor_screen_col();
 
             // Scroll left if cursor is before viewport
 
@@ -853,6 +1754,361 @@ impl TextArea {
             }
         }
     }
+
+    // ── Code editing ───────────────────────────────────────────────
+
+    /// Drop all cached highlights.
+    fn reset_highlights(&mut self) {
+        *self.highlight_cache.get_mut() = HighlightCache {
+            revision: self.editor.revision(),
+            line_count: self.editor.line_count(),
+            ..HighlightCache::default()
+        };
+    }
+
+    /// Bring cached highlights up to date through line `upto`.
+    ///
+    /// Only lines from the editor's first changed line down are revisited;
+    /// entries below an edit are shifted with the lines they belong to, and
+    /// a cached line is reused while both its text and incoming state are
+    /// unchanged. A line already validated at this revision costs nothing,
+    /// so repeated lookups while rendering stay O(1).
+    fn refresh_highlights(&self, upto: usize) {
+        let Some(slot) = &self.highlighter else {
+            return;
+        };
+        let mut cache = self.highlight_cache.borrow_mut();
+        let line_count = self.editor.line_count();
+
+        if let Some(first) = self.editor.first_changed_line_since(cache.revision) {
+            cache.valid = cache.valid.min(first);
+            let at = (first + 1).min(cache.lines.len());
+            if line_count > cache.line_count {
+                let added = line_count - cache.line_count;
+                cache.lines.splice(at..at, std::iter::repeat_n(None, added));
+            } else {
+                let removed = (cache.line_count - line_count).min(cache.lines.len() - at);
+                cache.lines.drain(at..at + removed);
+            }
+            cache.lines.truncate(line_count);
+            cache.revision = self.editor.revision();
+            cache.line_count = line_count;
+        }
+
+        let upto = upto.min(line_count.saturating_sub(1));
+        if upto < cache.valid {
+            return;
+        }
+        let rope = self.editor.rope();
+        let mut state = match cache.valid {
+            0 => 0,
+            n => cache.lines[n - 1].as_ref().map_or(0, |h| h.state_out),
+        };
+        for idx in cache.valid..=upto {
+            let raw = rope.line(idx).unwrap_or(std::borrow::Cow::Borrowed(""));
+            let text = raw.trim_end_matches('\n').trim_end_matches('\r');
+            let mut hasher = DefaultHasher::new();
+            text.hash(&mut hasher);
+            let text_hash = hasher.finish();
+
+            if idx >= cache.lines.len() {
+                cache.lines.push(None);
+            }
+            if let Some(cached) = &cache.lines[idx]
+                && cached.text_hash == text_hash
+                && cached.state_in == state
+            {
+                state = cached.state_out;
+                continue;
+            }
+
+            let (spans, state_out) = slot.0.highlight_line(text, state);
+            cache.lines[idx] = Some(HighlightedLine {
+                text_hash,
+                state_in: state,
+                state_out,
+                spans,
+            });
+            state = state_out;
+        }
+        cache.valid = upto + 1;
+    }
+
+    /// Positions of the bracket adjacent to the cursor and its partner.
+    ///
+    /// The grapheme under the cursor is checked first, then the one before
+    /// it. Returns `None` if neither is a bracket or no partner is found
+    /// within the scan window.
+    #[must_use]
+    pub fn matching_bracket(&self) -> Option<(CursorPosition, CursorPosition)> {
+        let cursor = self.editor.cursor();
+        let line = self.editor.line_text(cursor.line)?;
+        let graphemes: Vec<&str> = line.graphemes(true).collect();
+        let nav = CursorNavigator::new(self.editor.rope());
+
+        for idx in [Some(cursor.grapheme), cursor.grapheme.checked_sub(1)]
+            .into_iter()
+            .flatten()
+        {
+            let Some(ch) = graphemes.get(idx).and_then(|g| single_char(g)) else {
+                continue;
+            };
+            if let Some((line, grapheme)) = self.find_bracket_partner(cursor.line, idx, ch) {
+                return Some((
+                    nav.from_line_grapheme(cursor.line, idx),
+                    nav.from_line_grapheme(line, grapheme),
+                ));
+            }
+        }
+        None
+    }
+
+    fn find_bracket_partner(
+        &self,
+        line: usize,
+        grapheme: usize,
+        ch: char,
+    ) -> Option<(usize, usize)> {
+        let (open, close, forward) = BRACKET_PAIRS.iter().find_map(|&(open, close)| {
+            if ch == open {
+                Some((open, close, true))
+            } else if ch == close {
+                Some((open, close, false))
+            } else {
+                None
+            }
+        })?;
+        let (same, partner) = if forward {
+            (open, close)
+        } else {
+            (close, open)
+        };
+        let mut depth = 0usize;
+        let mut visit = |l: usize, g_idx: usize, g: &str| -> Option<(usize, usize)> {
+            match single_char(g) {
+                Some(c) if c == same => depth += 1,
+                Some(c) if c == partner => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return Some((l, g_idx));
+                    }
+                }
+                _ => {}
+            }
+            None
+        };
+
+        if forward {
+            let end = self.editor.line_count().min(line + BRACKET_SCAN_LINES);
+            for l in line..end {
+                let text = self.editor.line_text(l)?;
+                let skip = if l == line { grapheme } else { 0 };
+                for (g_idx, g) in text.graphemes(true).enumerate().skip(skip) {
+                    if let Some(found) = visit(l, g_idx, g) {
+                        return Some(found);
+                    }
+                }
+            }
+        } else {
+            for l in (line.saturating_sub(BRACKET_SCAN_LINES)..=line).rev() {
+                let text = self.editor.line_text(l)?;
+                let graphemes: Vec<&str> = text.graphemes(true).collect();
+                let take = if l == line {
+                    (grapheme + 1).min(graphemes.len())
+                } else {
+                    graphemes.len()
+                };
+                for g_idx in (0..take).rev() {
+                    if let Some(found) = visit(l, g_idx, graphemes[g_idx]) {
+                        return Some(found);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Draw a single grapheme occupying `cells` columns, expanding tabs and
+    /// drawing whitespace markers when enabled.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_grapheme(
+        &self,
+        frame: &mut Frame,
+        x: u16,
+        y: u16,
+        grapheme: &str,
+        cells: usize,
+        style: Style,
+        max_x: u16,
+        styling: bool,
+    ) {
+        let ws_style = if styling {
+            self.whitespace_style.merge(&style)
+        } else {
+            style
+        };
+        match grapheme {
+            "\t" => {
+                for i in 0..cells {
+                    let px = x.saturating_add(i as u16);
+                    if self.show_whitespace && i == 0 {
+                        draw_text_span(frame, px, y, "→", ws_style, max_x);
+                    } else {
+                        draw_text_span(frame, px, y, " ", style, max_x);
+                    }
+                }
+            }
+            " " if self.show_whitespace => {
+                draw_text_span(frame, x, y, "·", ws_style, max_x);
+            }
+            _ if cells > 0 => {
+                draw_text_span(frame, x, y, grapheme, style, max_x);
+            }
+            _ => {}
+        }
+    }
+
+    /// Draw a line (or wrapped slice) holding right-to-left text in bidi
+    /// visual order, skipping the first `scroll_left` cells.
+    ///
+    /// `text_byte` is the document offset of the laid-out text and
+    /// `line_byte` that of its line, for highlight span lookups.
+    #[cfg(feature = "bidi")]
+    #[allow(clippy::too_many_arguments)]
+    fn draw_bidi_text(
+        &self,
+        frame: &mut Frame,
+        x: u16,
+        y: u16,
+        width: usize,
+        scroll_left: usize,
+        layout: &BidiLine,
+        text_byte: usize,
+        line_byte: usize,
+        spans: &[HighlightSpan],
+        style_at: &dyn Fn(usize, Option<Style>) -> Style,
+        max_x: u16,
+        styling: bool,
+    ) {
+        for &gi in layout.visual_order() {
+            let Some(screen_x) = layout.cell_x(gi).checked_sub(scroll_left) else {
+                continue;
+            };
+            if screen_x >= width {
+                break;
+            }
+            let byte = text_byte + layout.byte_range(gi).start;
+            // Visual order is not monotonic in bytes, so look spans up afresh.
+            let token_style = highlight_style_at(spans, &mut 0, byte - line_byte);
+            self.draw_grapheme(
+                frame,
+                x.saturating_add(screen_x as u16),
+                y,
+                &layout.display_grapheme(gi),
+                layout.cell_width(gi),
+                style_at(byte, token_style),
+                max_x,
+                styling,
+            );
+        }
+    }
+
+    /// Draw a secondary cursor sitting at the end of a line.
+    fn draw_eol_cursor(&self, frame: &mut Frame, x: u16, y: u16, byte: usize, cursors: &[usize]) {
+        if cursors.binary_search(&byte).is_ok()
+            && let Some(cell) = frame.buffer.get_mut(x, y)
+        {
+            apply_style(cell, self.secondary_cursor_style);
+        }
+    }
+
+    /// Show the terminal cursor at `(x, y)` with any completion ghost text
+    /// after it.
+    fn place_cursor(&self, frame: &mut Frame, x: u16, y: u16, max_x: u16) {
+        if let Some(ghost) = self.completion.as_ref().and_then(Completer::ghost_text) {
+            let style = if frame.buffer.degradation.apply_styling() {
+                self.completion
+                    .as_ref()
+                    .map_or_else(Style::default, Completer::ghost_style)
+                    .merge(&self.style)
+            } else {
+                Style::default()
+            };
+            draw_text_span(frame, x, y, ghost, style, max_x);
+        }
+        self.cursor_screen.set(Some((x, y)));
+        frame.set_cursor(Some((x, y)));
+    }
+}
+
+/// Cell width of a grapheme starting at display column `col`.
+///
+/// Tabs advance to the next multiple of `tab_width`.
+fn grapheme_cells(grapheme: &str, col: usize, tab_width: usize) -> usize {
+    if grapheme == "\t" {
+        tab_width - col % tab_width
+    } else {
+        display_width(grapheme)
+    }
+}
+
+/// Cell width of a word segment starting at display column `col`.
+fn segment_cells(segment: &str, col: usize, tab_width: usize) -> usize {
+    segment.graphemes(true).fold(0, |width, g| {
+        width + grapheme_cells(g, col + width, tab_width)
+    })
+}
+
+/// Bidi layout of `text` with tabs expanded from display column `start_col`,
+/// or `None` when it holds no right-to-left text.
+#[cfg(feature = "bidi")]
+fn bidi_layout(
+    text: &str,
+    direction: ParagraphDirection,
+    start_col: usize,
+    tab_width: usize,
+) -> Option<BidiLine> {
+    if !bidi::has_rtl(text) {
+        return None;
+    }
+    let mut col = start_col;
+    let widths: Vec<usize> = text
+        .graphemes(true)
+        .map(|g| {
+            let cells = grapheme_cells(g, col, tab_width);
+            col += cells;
+            cells
+        })
+        .collect();
+    Some(BidiLine::new(text, direction).with_cell_widths(widths))
+}
+
+/// Display column of the `grapheme`-th grapheme in `line`.
+fn display_col(line: &str, grapheme: usize, tab_width: usize) -> usize {
+    line.graphemes(true)
+        .take(grapheme)
+        .fold(0, |col, g| col + grapheme_cells(g, col, tab_width))
+}
+
+/// The grapheme as a `char` if it is a single scalar value.
+fn single_char(grapheme: &str) -> Option<char> {
+    let mut chars = grapheme.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+/// Style for the grapheme at `offset` (bytes within the line), advancing the
+/// span cursor `next` monotonically.
+fn highlight_style_at(spans: &[HighlightSpan], next: &mut usize, offset: usize) -> Option<Style> {
+    while *next < spans.len() && spans[*next].end <= offset {
+        *next += 1;
+    }
+    spans
+        .get(*next)
+        .filter(|span| span.start <= offset)
+        .map(|span| span.style)
 }
 
 impl Widget for TextArea {
@@ -862,6 +2118,7 @@ impl Widget for TextArea {
         }
 
         self.last_viewport_height.set(area.height as usize);
+        self.cursor_screen.set(None);
 
         let deg = frame.buffer.degradation;
         if deg.apply_styling() {
@@ -872,10 +2129,24 @@ impl Widget for TextArea {
         let text_area_x = area.x.saturating_add(gutter_w);
         let text_area_w = area.width.saturating_sub(gutter_w) as usize;
         let vp_height = area.height as usize;
+        let tab_width = self.tab_width;
 
         self.last_viewport_width.set(text_area_w);
+        self.last_text_area.set(Rect::new(
+            text_area_x,
+            area.y,
+            text_area_w as u16,
+            area.height,
+        ));
 
         let cursor = self.editor.cursor();
+        // Soft wrap picks the cursor's row by logical column; unwrapped lines
+        // scroll by the on-screen (bidi) column.
+        let cursor_col = if self.soft_wrap {
+            self.cursor_display_col()
+        } else {
+            self.cursor_screen_col()
+        };
         // Use a mutable copy for scroll adjustment
         let mut scroll_top = if self.scroll_top.get() == usize::MAX {
             0
@@ -893,7 +2164,7 @@ impl Widget for TextArea {
 
         let mut scroll_left = self.scroll_left.get();
         if !self.soft_wrap && text_area_w > 0 {
-            let visual_col = cursor.visual_col;
+            let visual_col = cursor_col;
             if visual_col < scroll_left {
                 scroll_left = visual_col;
             } else if visual_col >= scroll_left + text_area_w {
@@ -909,15 +2180,63 @@ impl Widget for TextArea {
         let rope = self.editor.rope();
         let nav = CursorNavigator::new(rope);
 
-        // Selection byte range for highlighting
-        let sel_range = self.editor.selection().and_then(|sel| {
-            if sel.is_empty() {
-                None
-            } else {
-                let (a, b) = sel.byte_range(&nav);
-                Some((a, b))
+        // Selection byte ranges for highlighting, in document order
+        let selections = self.editor.selections();
+        let sel_ranges: Vec<(usize, usize)> = selections
+            .iter()
+            .filter(|sel| !sel.is_empty())
+            .map(|sel| sel.byte_range(&nav))
+            .collect();
+        // Secondary cursor byte offsets, in document order
+        let primary = self.editor.cursor();
+        let secondary_cursors: Vec<usize> = if deg.apply_styling() {
+            selections
+                .iter()
+                .filter(|sel| sel.head != primary)
+                .map(|sel| nav.to_byte_index(sel.head))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        // Matched bracket byte offsets
+        let bracket_bytes = if self.bracket_matching && deg.apply_styling() {
+            self.matching_bracket()
+                .map(|(a, b)| [nav.to_byte_index(a), nav.to_byte_index(b)])
+        } else {
+            None
+        };
+        let use_highlights = self.highlighter.is_some() && deg.apply_styling();
+        let search_matches = if deg.apply_styling() {
+            self.search_matches()
+        } else {
+            Vec::new()
+        };
+
+        // Style for the grapheme at a document byte offset.
+        let style_at = |byte: usize, token_style: Option<Style>| -> Style {
+            let mut g_style = self.style;
+            if let Some(token_style) = token_style {
+                g_style = token_style.merge(&g_style);
             }
-        });
+            let m = search_matches.partition_point(|m| m.end <= byte);
+            if search_matches.get(m).is_some_and(|m| m.start <= byte) {
+                g_style = self.search_highlight_style.merge(&g_style);
+            }
+            let s = sel_ranges.partition_point(|&(_, end)| end <= byte);
+            if sel_ranges.get(s).is_some_and(|&(start, _)| start <= byte) && deg.apply_styling() {
+                g_style = g_style.merge(&self.selection_style);
+            }
+            if secondary_cursors.binary_search(&byte).is_ok() {
+                g_style = self.secondary_cursor_style.merge(&g_style);
+            }
+            if let Some(brackets) = bracket_bytes
+                && brackets.contains(&byte)
+            {
+                g_style = self.bracket_match_style.merge(&g_style);
+            }
+            g_style
+        };
 
         // Show placeholder if empty
         if self.editor.is_empty() && !self.placeholder.is_empty() {
@@ -950,7 +2269,7 @@ impl Widget for TextArea {
                     .line(line_idx)
                     .unwrap_or(std::borrow::Cow::Borrowed(""));
                 let line_text = line_text.strip_suffix('\n').unwrap_or(&line_text);
-                cursor_virtual += Self::measure_wrap_count(line_text, text_area_w);
+                cursor_virtual += Self::measure_wrap_count(line_text, text_area_w, tab_width);
             }
 
             let cursor_line_text = rope
@@ -960,7 +2279,16 @@ impl Widget for TextArea {
                 .strip_suffix('\n')
                 .unwrap_or(&cursor_line_text);
             let (cursor_wrap_idx, cursor_col_in_wrap) =
-                Self::cursor_wrap_position(cursor_line_text, text_area_w, cursor.visual_col);
+                Self::cursor_wrap_position(cursor_line_text, text_area_w, tab_width, cursor_col);
+            #[cfg(feature = "bidi")]
+            let cursor_col_in_wrap = Self::bidi_wrap_caret(
+                cursor_line_text,
+                text_area_w,
+                tab_width,
+                cursor_wrap_idx,
+                cursor.grapheme,
+            )
+            .unwrap_or(cursor_col_in_wrap);
             cursor_virtual = cursor_virtual.saturating_add(cursor_wrap_idx);
 
             // Adjust scroll to keep cursor visible
@@ -985,14 +2313,22 @@ impl Widget for TextArea {
                 let line_text = line_text.strip_suffix('\n').unwrap_or(&line_text);
 
                 // Fast path: check if this whole physical line is skipped
-                let wrap_count = Self::measure_wrap_count(line_text, text_area_w);
+                let wrap_count = Self::measure_wrap_count(line_text, text_area_w, tab_width);
                 if virtual_index + wrap_count <= scroll_virtual {
                     virtual_index += wrap_count;
                     continue;
                 }
 
                 let line_start_byte = nav.to_byte_index(nav.from_line_grapheme(line_idx, 0));
-                let slices = Self::wrap_line_slices(line_text, text_area_w);
+                let slices = Self::wrap_line_slices(line_text, text_area_w, tab_width);
+                #[cfg(feature = "bidi")]
+                let direction = bidi::has_rtl(line_text).then(|| bidi::paragraph_level(line_text));
+                let spans = if use_highlights {
+                    self.line_highlights(line_idx)
+                } else {
+                    Vec::new()
+                };
+                let mut next_span = 0usize;
 
                 for (slice_idx, slice) in slices.iter().enumerate() {
                     if virtual_index < scroll_virtual {
@@ -1032,12 +2368,44 @@ impl Widget for TextArea {
                         }
                     }
 
+                    #[cfg(feature = "bidi")]
+                    if let Some(layout) = direction
+                        .and_then(|dir| bidi_layout(&slice.text, dir, slice.start_col, tab_width))
+                    {
+                        self.draw_bidi_text(
+                            frame,
+                            text_area_x,
+                            y,
+                            text_area_w,
+                            0,
+                            &layout,
+                            line_start_byte + slice.start_byte,
+                            line_start_byte,
+                            &spans,
+                            &style_at,
+                            area.right(),
+                            deg.apply_styling(),
+                        );
+                        let eol_x = layout.caret_x(layout.len());
+                        if slice_idx + 1 == slices.len() && eol_x < text_area_w {
+                            self.draw_eol_cursor(
+                                frame,
+                                text_area_x + eol_x as u16,
+                                y,
+                                line_start_byte + line_text.len(),
+                                &secondary_cursors,
+                            );
+                        }
+                        virtual_index += 1;
+                        continue;
+                    }
+
                     // Render graphemes inside the wrapped slice
                     let mut visual_x: usize = 0;
                     let mut grapheme_byte_offset = line_start_byte + slice.start_byte;
 
                     for g in slice.text.graphemes(true) {
-                        let g_width = display_width(g);
+                        let g_width = grapheme_cells(g, slice.start_col + visual_x, tab_width);
                         let g_byte_len = g.len();
 
                         if visual_x >= text_area_w {
@@ -1046,24 +2414,37 @@ impl Widget for TextArea {
 
                         let px = text_area_x + visual_x as u16;
 
-                        // Determine style (selection highlight)
-                        let mut g_style = self.style;
-                        if let Some((sel_start, sel_end)) = sel_range
-                            && grapheme_byte_offset >= sel_start
-                            && grapheme_byte_offset < sel_end
-                            && deg.apply_styling()
-                        {
-                            g_style = g_style.merge(&self.selection_style);
-                        }
-
-                        if g_width > 0 {
-                            draw_text_span(frame, px, y, g, g_style, area.right());
-                        }
+                        let token_style = highlight_style_at(
+                            &spans,
+                            &mut next_span,
+                            grapheme_byte_offset - line_start_byte,
+                        );
+                        let g_style = style_at(grapheme_byte_offset, token_style);
+                        self.draw_grapheme(
+                            frame,
+                            px,
+                            y,
+                            g,
+                            g_width,
+                            g_style,
+                            area.right(),
+                            deg.apply_styling(),
+                        );
 
                         visual_x += g_width;
                         grapheme_byte_offset += g_byte_len;
                     }
 
+                    if slice_idx + 1 == slices.len() && visual_x < text_area_w {
+                        self.draw_eol_cursor(
+                            frame,
+                            text_area_x + visual_x as u16,
+                            y,
+                            grapheme_byte_offset,
+                            &secondary_cursors,
+                        );
+                    }
+
                     virtual_index += 1;
                 }
             }
@@ -1075,7 +2456,7 @@ impl Widget for TextArea {
                     let cursor_screen_x = text_area_x.saturating_add(cursor_col_in_wrap as u16);
                     let cursor_screen_y = area.y.saturating_add(row as u16);
                     if cursor_screen_x < area.right() && cursor_screen_y < area.bottom() {
-                        frame.set_cursor(Some((cursor_screen_x, cursor_screen_y)));
+                        self.place_cursor(frame, cursor_screen_x, cursor_screen_y, area.right());
                     }
                 }
             }
@@ -1084,6 +2465,9 @@ impl Widget for TextArea {
         }
 
         // Render visible lines (no soft wrap)
+        if use_highlights {
+            self.refresh_highlights(scroll_top + vp_height.saturating_sub(1));
+        }
         for row in 0..vp_height {
             let line_idx = scroll_top + row;
             let y = area.y.saturating_add(row as u16);
@@ -1123,6 +2507,43 @@ impl Widget for TextArea {
 
             // Calculate line byte offset for selection mapping
             let line_start_byte = nav.to_byte_index(nav.from_line_grapheme(line_idx, 0));
+            let spans = if use_highlights {
+                self.line_highlights(line_idx)
+            } else {
+                Vec::new()
+            };
+            let mut next_span = 0usize;
+
+            #[cfg(feature = "bidi")]
+            if let Some(layout) = bidi_layout(line_text, ParagraphDirection::Auto, 0, tab_width) {
+                self.draw_bidi_text(
+                    frame,
+                    text_area_x,
+                    y,
+                    text_area_w,
+                    scroll_left,
+                    &layout,
+                    line_start_byte,
+                    line_start_byte,
+                    &spans,
+                    &style_at,
+                    area.right(),
+                    deg.apply_styling(),
+                );
+                let eol_x = layout.caret_x(layout.len());
+                if let Some(screen_x) = eol_x.checked_sub(scroll_left)
+                    && screen_x < text_area_w
+                {
+                    self.draw_eol_cursor(
+                        frame,
+                        text_area_x + screen_x as u16,
+                        y,
+                        line_start_byte + line_text.len(),
+                        &secondary_cursors,
+                    );
+                }
+                continue;
+            }
 
             // Render each grapheme
             let mut visual_x: usize = 0;
@@ -1130,7 +2551,7 @@ impl Widget for TextArea {
             let mut grapheme_byte_offset = line_start_byte;
 
             for g in &graphemes {
-                let g_width = display_width(g);
+                let g_width = grapheme_cells(g, visual_x, tab_width);
                 let g_byte_len = g.len();
 
                 // Skip graphemes before horizontal scroll
@@ -1155,35 +2576,50 @@ impl Widget for TextArea {
 
                 let px = text_area_x + screen_x as u16;
 
-                // Determine style (selection highlight)
-                let mut g_style = self.style;
-                if let Some((sel_start, sel_end)) = sel_range
-                    && grapheme_byte_offset >= sel_start
-                    && grapheme_byte_offset < sel_end
-                    && deg.apply_styling()
-                {
-                    g_style = g_style.merge(&self.selection_style);
-                }
-
-                // Write grapheme to buffer
-                if g_width > 0 {
-                    draw_text_span(frame, px, y, g, g_style, area.right());
-                }
+                let token_style = highlight_style_at(
+                    &spans,
+                    &mut next_span,
+                    grapheme_byte_offset - line_start_byte,
+                );
+                let g_style = style_at(grapheme_byte_offset, token_style);
+                self.draw_grapheme(
+                    frame,
+                    px,
+                    y,
+                    g,
+                    g_width,
+                    g_style,
+                    area.right(),
+                    deg.apply_styling(),
+                );
 
                 visual_x += g_width;
                 grapheme_byte_offset += g_byte_len;
             }
+
+            if grapheme_byte_offset == line_start_byte + line_text.len()
+                && visual_x >= scroll_left
+                && visual_x - scroll_left < text_area_w
+            {
+                self.draw_eol_cursor(
+                    frame,
+                    text_area_x + (visual_x - scroll_left) as u16,
+                    y,
+                    grapheme_byte_offset,
+                    &secondary_cursors,
+                );
+            }
         }
 
         // Set cursor position if focused
         if self.focused {
             let cursor_row = cursor.line.saturating_sub(scroll_top);
             if cursor_row < vp_height {
-                let cursor_screen_x = (cursor.visual_col.saturating_sub(scroll_left) as u16)
-                    .saturating_add(text_area_x);
+                let cursor_screen_x =
+                    (cursor_col.saturating_sub(scroll_left) as u16).saturating_add(text_area_x);
                 let cursor_screen_y = area.y.saturating_add(cursor_row as u16);
                 if cursor_screen_x < area.right() && cursor_screen_y < area.bottom() {
-                    frame.set_cursor(Some((cursor_screen_x, cursor_screen_y)));
+                    self.place_cursor(frame, cursor_screen_x, cursor_screen_y, area.right());
                 }
             }
         }
@@ -1194,6 +2630,75 @@ impl Widget for TextArea {
     }
 }
 
+impl KeymapTarget for TextArea {
+    fn text(&self) -> Cow<'_, str> {
+        KeymapTarget::text(&self.editor)
+    }
+
+    fn len(&self) -> usize {
+        KeymapTarget::len(&self.editor)
+    }
+
+    fn slice(&self, range: Range<usize>) -> Cow<'_, str> {
+        self.editor.slice(range)
+    }
+
+    fn char_at(&self, offset: usize) -> Option<char> {
+        self.editor.char_at(offset)
+    }
+
+    fn line_bounds(&self, offset: usize) -> Range<usize> {
+        self.editor.line_bounds(offset)
+    }
+
+    fn cursor_offset(&self) -> usize {
+        self.editor.cursor_offset()
+    }
+
+    fn selection_offsets(&self) -> Option<(usize, usize)> {
+        self.editor.selection_offsets()
+    }
+
+    fn set_cursor_offset(&mut self, offset: usize) {
+        KeymapTarget::set_cursor_offset(&mut self.editor, offset);
+        self.ensure_cursor_visible();
+    }
+
+    fn select_offsets(&mut self, anchor: usize, head: usize) {
+        self.editor.select_byte_range(anchor, head);
+        self.ensure_cursor_visible();
+    }
+
+    fn replace_offsets(&mut self, start: usize, end: usize, text: &str) {
+        self.editor.replace_byte_range(start, end, text);
+        self.ensure_cursor_visible();
+    }
+
+    fn insert_newline(&mut self) {
+        TextArea::insert_newline(self);
+    }
+
+    fn undo(&mut self) -> bool {
+        let undone = self.editor.undo();
+        self.ensure_cursor_visible();
+        undone
+    }
+
+    fn redo(&mut self) -> bool {
+        let redone = self.editor.redo();
+        self.ensure_cursor_visible();
+        redone
+    }
+
+    fn begin_edit_group(&mut self) {
+        self.editor.begin_undo_group();
+    }
+
+    fn end_edit_group(&mut self) {
+        self.editor.end_undo_group();
+    }
+}
+
 impl StatefulWidget for TextArea {
     type State = TextAreaState;
 
@@ -1663,6 +3168,657 @@ mod tests {
         assert_eq!(ta.text(), "café");
     }
 
+    /// Highlights `#` to end of line; `/*` opens a block that carries state.
+    #[derive(Default)]
+    struct CountingHighlighter {
+        calls: std::sync::atomic::AtomicUsize,
+    }
+
+    impl LineHighlighter for CountingHighlighter {
+        fn highlight_line(
+            &self,
+            line: &str,
+            state: HighlightState,
+        ) -> (Vec<HighlightSpan>, HighlightState) {
+            self.calls
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            let style = Style::new().bold();
+            if state == 1 || line.starts_with("/*") {
+                let out = u64::from(!line.ends_with("*/"));
+                return (vec![HighlightSpan::new(0, line.len(), style)], out);
+            }
+            let spans = line
+                .find('#')
+                .map(|i| vec![HighlightSpan::new(i, line.len(), style)])
+                .unwrap_or_default();
+            (spans, 0)
+        }
+    }
+
+    fn render_frame(ta: &TextArea, w: u16, h: u16) -> Vec<String> {
+        use ftui_render::grapheme_pool::GraphemePool;
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(w, h, &mut pool);
+        Widget::render(ta, Rect::new(0, 0, w, h), &mut frame);
+        (0..h)
+            .map(|y| {
+                (0..w)
+                    .map(|x| {
+                        frame
+                            .buffer
+                            .get(x, y)
+                            .and_then(|c| c.content.as_char())
+                            .unwrap_or(' ')
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn highlighter_styles_spans() {
+        use ftui_render::cell::StyleFlags;
+        use ftui_render::grapheme_pool::GraphemePool;
+        let ta = TextArea::new()
+            .with_text("a # b")
+            .with_highlighter(Arc::new(CountingHighlighter::default()));
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(10, 1, &mut pool);
+        Widget::render(&ta, Rect::new(0, 0, 10, 1), &mut frame);
+        let plain = frame.buffer.get(0, 0).unwrap();
+        let marked = frame.buffer.get(2, 0).unwrap();
+        assert!(!plain.attrs.has_flag(StyleFlags::BOLD));
+        assert!(marked.attrs.has_flag(StyleFlags::BOLD));
+    }
+
+    #[test]
+    fn highlighter_carries_state_across_lines() {
+        let ta = TextArea::new()
+            .with_text("/* open\nstill\nclose */\nplain")
+            .with_highlighter(Arc::new(CountingHighlighter::default()));
+        assert_eq!(ta.line_highlights(1).len(), 1);
+        assert_eq!(ta.line_highlights(2).len(), 1);
+        assert!(ta.line_highlights(3).is_empty());
+    }
+
+    #[test]
+    fn highlighter_relexes_only_edited_lines() {
+        let hl = Arc::new(CountingHighlighter::default());
+        let mut ta = TextArea::new()
+            .with_text("one\ntwo\nthree\nfour")
+            .with_highlighter(hl.clone());
+        let _ = ta.line_highlights(3);
+        let calls = || hl.calls.load(std::sync::atomic::Ordering::Relaxed);
+        assert_eq!(calls(), 4);
+
+        // Cached: no re-lexing.
+        let _ = ta.line_highlights(3);
+        assert_eq!(calls(), 4);
+
+        // Editing line 1 without changing its end state re-lexes one line.
+        ta.set_cursor_position(CursorPosition::new(1, 3, 3));
+        ta.insert_char('!');
+        let _ = ta.line_highlights(3);
+        assert_eq!(calls(), 5);
+
+        // Opening a block comment cascades through the following lines.
+        ta.set_cursor_position(CursorPosition::new(1, 0, 0));
+        ta.insert_text("/*");
+        let spans = ta.line_highlights(3);
+        assert_eq!(calls(), 8);
+        assert_eq!(spans.len(), 1);
+    }
+
+    #[test]
+    fn highlighter_keeps_cache_across_inserted_lines() {
+        let hl = Arc::new(CountingHighlighter::default());
+        let text: Vec<String> = (0..50).map(|i| format!("line {i}")).collect();
+        let mut ta = TextArea::new()
+            .with_text(&text.join("\n"))
+            .with_highlighter(hl.clone());
+        let calls = || hl.calls.load(std::sync::atomic::Ordering::Relaxed);
+        let _ = render_frame(&ta, 20, 50);
+        assert_eq!(calls(), 50);
+
+        // Re-rendering unchanged text lexes nothing.
+        let _ = render_frame(&ta, 20, 50);
+        assert_eq!(calls(), 50);
+
+        // Splitting line 10 lexes the two halves; lines below shift intact.
+        ta.set_cursor_position(CursorPosition::new(10, 4, 4));
+        ta.insert_newline();
+        let _ = render_frame(&ta, 20, 51);
+        assert_eq!(calls(), 52);
+        assert_eq!(ta.line_highlights(50).len(), 0);
+
+        // Joining them back only re-lexes the joined line.
+        ta.delete_backward();
+        let _ = render_frame(&ta, 20, 50);
+        assert_eq!(calls(), 53);
+    }
+
+    #[test]
+    fn matching_bracket_forward_and_backward() {
+        let mut ta = TextArea::new().with_text("f(a, [b])\n{\n  x\n}");
+        ta.set_cursor_position(CursorPosition::new(0, 1, 1));
+        let (at, partner) = ta.matching_bracket().unwrap();
+        assert_eq!((at.line, at.grapheme), (0, 1));
+        assert_eq!((partner.line, partner.grapheme), (0, 8));
+
+        // Cursor just after a closing bracket.
+        ta.set_cursor_position(CursorPosition::new(3, 1, 1));
+        let (at, partner) = ta.matching_bracket().unwrap();
+        assert_eq!((at.line, at.grapheme), (3, 0));
+        assert_eq!((partner.line, partner.grapheme), (1, 0));
+
+        ta.set_cursor_position(CursorPosition::new(2, 2, 2));
+        assert!(ta.matching_bracket().is_none());
+    }
+
+    #[test]
+    fn unbalanced_bracket_has_no_match() {
+        let mut ta = TextArea::new().with_text("((a)");
+        ta.set_cursor_position(CursorPosition::new(0, 0, 0));
+        assert!(ta.matching_bracket().is_none());
+    }
+
+    #[test]
+    fn bracket_match_is_styled() {
+        use ftui_render::cell::StyleFlags;
+        use ftui_render::grapheme_pool::GraphemePool;
+        let mut ta = TextArea::new().with_text("(x)").with_bracket_matching(true);
+        ta.set_cursor_position(CursorPosition::new(0, 0, 0));
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(5, 1, &mut pool);
+        Widget::render(&ta, Rect::new(0, 0, 5, 1), &mut frame);
+        let flags = |x| frame.buffer.get(x, 0).unwrap().attrs;
+        assert!(flags(0).has_flag(StyleFlags::UNDERLINE));
+        assert!(!flags(1).has_flag(StyleFlags::UNDERLINE));
+        assert!(flags(2).has_flag(StyleFlags::UNDERLINE));
+    }
+
+    #[test]
+    fn auto_indent_copies_leading_whitespace() {
+        let mut ta = TextArea::new()
+            .with_text("    let x = 1;")
+            .with_auto_indent(true);
+        ta.move_to_document_end();
+        ta.insert_newline();
+        assert_eq!(ta.text(), "    let x = 1;\n    ");
+        assert_eq!(ta.cursor().grapheme, 4);
+    }
+
+    #[test]
+    fn auto_indent_after_open_bracket() {
+        let mut ta = TextArea::new()
+            .with_text("fn main() {")
+            .with_auto_indent(true)
+            .with_indent_width(2);
+        ta.move_to_document_end();
+        ta.insert_newline();
+        assert_eq!(ta.text(), "fn main() {\n  ");
+    }
+
+    #[test]
+    fn auto_indent_disabled_by_default() {
+        let mut ta = TextArea::new().with_text("  a");
+        ta.move_to_document_end();
+        ta.insert_newline();
+        assert_eq!(ta.text(), "  a\n");
+    }
+
+    #[test]
+    fn tab_key_indents_only_when_enabled() {
+        let mut ta = TextArea::new().with_text("ab");
+        ta.move_to_document_start();
+        assert!(!ta.handle_event(&Event::Key(KeyEvent::new(KeyCode::Tab))));
+
+        let mut ta = TextArea::new().with_text("ab").with_tab_indents(true);
+        ta.set_cursor_position(CursorPosition::new(0, 1, 1));
+        assert!(ta.handle_event(&Event::Key(KeyEvent::new(KeyCode::Tab))));
+        // Spaces to the next indent stop.
+        assert_eq!(ta.text(), "a   b");
+        assert!(ta.handle_event(&Event::Key(KeyEvent::new(KeyCode::BackTab))));
+        assert_eq!(ta.text(), "a   b");
+    }
+
+    #[test]
+    fn dedent_removes_one_level() {
+        let mut ta = TextArea::new().with_text("      x").with_indent_width(4);
+        ta.move_to_document_end();
+        ta.dedent();
+        assert_eq!(ta.text(), "  x");
+        assert_eq!(ta.cursor().grapheme, 3);
+        ta.dedent();
+        assert_eq!(ta.text(), "x");
+        ta.undo();
+        assert_eq!(ta.text(), "  x");
+    }
+
+    #[test]
+    fn dedent_selected_lines_as_one_undo_step() {
+        let mut ta = TextArea::new()
+            .with_text("    a\n  b\nc\n\td")
+            .with_indent_width(4);
+        ta.move_to_document_start();
+        ta.move_right();
+        ta.move_right();
+        for _ in 0..3 {
+            ta.select_down();
+        }
+        ta.dedent();
+        assert_eq!(ta.text(), "a\nb\nc\nd");
+        // The selection still spans all four lines.
+        let sel = ta.editor().selection().unwrap();
+        assert_eq!((sel.anchor.line, sel.anchor.grapheme), (0, 0));
+        assert_eq!(sel.head.line, 3);
+        ta.undo();
+        assert_eq!(ta.text(), "    a\n  b\nc\n\td");
+    }
+
+    #[test]
+    fn indent_selected_lines_as_one_undo_step() {
+        let mut ta = TextArea::new().with_text("a\nb\nc").with_indent_width(2);
+        ta.move_to_document_start();
+        ta.move_right();
+        ta.select_down();
+        ta.select_down();
+        ta.indent();
+        assert_eq!(ta.text(), "  a\n  b\n  c");
+        let sel = ta.editor().selection().unwrap();
+        assert_eq!((sel.anchor.line, sel.anchor.grapheme), (0, 3));
+        assert_eq!((sel.head.line, sel.head.grapheme), (2, 3));
+        ta.undo();
+        assert_eq!(ta.text(), "a\nb\nc");
+    }
+
+    #[test]
+    fn indent_with_hard_tabs() {
+        let mut ta = TextArea::new().with_insert_spaces(false);
+        ta.indent();
+        assert_eq!(ta.text(), "\t");
+    }
+
+    #[test]
+    fn indent_selected_lines() {
+        let mut ta = TextArea::new().with_text("a\nb\nc").with_indent_width(2);
+        ta.move_to_document_start();
+        ta.select_down();
+        ta.indent();
+        assert_eq!(ta.text(), "  a\n  b\nc");
+    }
+
+    #[test]
+    fn tabs_expand_to_tab_stops() {
+        let ta = TextArea::new()
+            .with_text("a\tb\n\tc")
+            .with_tab_width(4)
+            .with_focus(true);
+        let rows = render_frame(&ta, 10, 2);
+        assert_eq!(rows[0], "a   b     ");
+        assert_eq!(rows[1], "    c     ");
+    }
+
+    #[test]
+    fn cursor_after_tab_uses_expanded_column() {
+        use ftui_render::grapheme_pool::GraphemePool;
+        let mut ta = TextArea::new()
+            .with_text("\tx")
+            .with_tab_width(8)
+            .with_focus(true);
+        ta.move_to_document_end();
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(20, 1, &mut pool);
+        Widget::render(&ta, Rect::new(0, 0, 20, 1), &mut frame);
+        assert_eq!(frame.cursor_position, Some((9, 0)));
+    }
+
+    #[test]
+    #[cfg(feature = "bidi")]
+    fn rtl_line_renders_in_visual_order() {
+        let ta = TextArea::new().with_text("ab \u{05D0}\u{05D1}\u{05D2}\n\u{05D3}(x)");
+        let rows = render_frame(&ta, 8, 2);
+        assert_eq!(rows[0], "ab \u{05D2}\u{05D1}\u{05D0}  ");
+        // RTL base line: mirrored brackets keep reading correctly.
+        assert_eq!(rows[1], "(x)\u{05D3}    ");
+    }
+
+    #[test]
+    #[cfg(feature = "bidi")]
+    fn rtl_cursor_moves_visually() {
+        use ftui_render::grapheme_pool::GraphemePool;
+        let mut ta = TextArea::new()
+            .with_text("\u{05D0}\u{05D1}\u{05D2}")
+            .with_focus(true);
+        let render_cursor = |ta: &TextArea| {
+            let mut pool = GraphemePool::new();
+            let mut frame = Frame::new(10, 1, &mut pool);
+            Widget::render(ta, Rect::new(0, 0, 10, 1), &mut frame);
+            frame.cursor_position
+        };
+        // The logical end of an RTL line is its left edge.
+        ta.move_to_document_end();
+        assert_eq!(render_cursor(&ta), Some((0, 0)));
+        ta.move_right();
+        assert_eq!(ta.cursor().grapheme, 2);
+        assert_eq!(render_cursor(&ta), Some((1, 0)));
+        ta.select_right();
+        assert_eq!(ta.selected_text().as_deref(), Some("\u{05D1}"));
+    }
+
+    #[test]
+    #[cfg(feature = "bidi")]
+    fn rtl_soft_wrap_reorders_each_row() {
+        use ftui_render::grapheme_pool::GraphemePool;
+        let mut ta = TextArea::new()
+            .with_text("\u{05D0}\u{05D1} \u{05D2}\u{05D3}")
+            .with_soft_wrap(true)
+            .with_focus(true);
+        ta.move_to_document_end();
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(3, 2, &mut pool);
+        Widget::render(&ta, Rect::new(0, 0, 3, 2), &mut frame);
+        let row = |y: u16| -> String {
+            (0..3)
+                .filter_map(|x| frame.buffer.get(x, y).and_then(|c| c.content.as_char()))
+                .collect()
+        };
+        assert!(row(0).contains("\u{05D1}\u{05D0}"));
+        assert_eq!(row(1).trim_end(), "\u{05D3}\u{05D2}");
+        assert_eq!(frame.cursor_position, Some((0, 1)));
+    }
+
+    #[test]
+    fn visible_whitespace_markers() {
+        let ta = TextArea::new()
+            .with_text("a b\tc")
+            .with_tab_width(4)
+            .with_show_whitespace(true);
+        let rows = render_frame(&ta, 8, 1);
+        assert_eq!(rows[0], "a·b→c   ");
+    }
+
+    #[test]
+    fn soft_wrap_expands_tabs() {
+        let ta = TextArea::new()
+            .with_text("ab\tcd")
+            .with_tab_width(4)
+            .with_soft_wrap(true);
+        // "ab" + tab to column 4 fills the first row; "cd" wraps.
+        let rows = render_frame(&ta, 4, 2);
+        assert_eq!(rows[0], "ab  ");
+        assert_eq!(rows[1], "cd  ");
+    }
+
+    #[test]
+    fn search_selects_first_match_and_counts() {
+        let mut ta = TextArea::new().with_text("foo bar foo\nfoo");
+        assert_eq!(ta.search("foo", SearchOptions::default()), 3);
+        assert_eq!(ta.selected_text().as_deref(), Some("foo"));
+        assert_eq!(ta.search_info(), Some((1, 3)));
+        ta.next_match();
+        assert_eq!(ta.search_info(), Some((2, 3)));
+        ta.prev_match();
+        ta.prev_match();
+        assert_eq!(ta.search_info(), Some((3, 3)));
+    }
+
+    #[test]
+    fn incremental_search_keeps_anchor() {
+        let mut ta = TextArea::new().with_text("ab abc");
+        ta.search("ab", SearchOptions::default());
+        ta.next_match();
+        assert_eq!(ta.search_info(), Some((2, 2)));
+        assert_eq!(ta.search("abc", SearchOptions::default()), 1);
+        assert_eq!(ta.search_info(), Some((1, 1)));
+    }
+
+    #[test]
+    fn empty_search_clears() {
+        let mut ta = TextArea::new().with_text("abc");
+        ta.search("b", SearchOptions::default());
+        assert_eq!(ta.search("", SearchOptions::default()), 0);
+        assert!(ta.search_query().is_none());
+        assert!(ta.search_info().is_none());
+    }
+
+    #[test]
+    fn f3_cycles_matches() {
+        let mut ta = TextArea::new().with_text("x x x");
+        ta.search("x", SearchOptions::default());
+        ta.handle_event(&Event::Key(KeyEvent::new(KeyCode::F(3))));
+        assert_eq!(ta.search_info(), Some((2, 3)));
+        ta.handle_event(&Event::Key(
+            KeyEvent::new(KeyCode::F(3)).with_modifiers(Modifiers::SHIFT),
+        ));
+        assert_eq!(ta.search_info(), Some((1, 3)));
+    }
+
+    #[test]
+    fn replace_match_and_replace_all() {
+        let mut ta = TextArea::new().with_text("a-a-a");
+        ta.search("a", SearchOptions::default());
+        assert!(ta.replace_match("b"));
+        assert_eq!(ta.text(), "b-a-a");
+        assert_eq!(ta.search_info(), Some((1, 2)));
+        assert_eq!(ta.replace_all("c"), 2);
+        assert_eq!(ta.text(), "b-c-c");
+        assert!(ta.search_info().is_none());
+        ta.undo();
+        assert_eq!(ta.text(), "b-a-a");
+    }
+
+    #[test]
+    fn search_matches_are_highlighted() {
+        use ftui_render::cell::StyleFlags;
+        use ftui_render::grapheme_pool::GraphemePool;
+        let mut ta = TextArea::new().with_text("ab ab");
+        ta.search("ab", SearchOptions::default());
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(6, 1, &mut pool);
+        Widget::render(&ta, Rect::new(0, 0, 6, 1), &mut frame);
+        let attrs = |x| frame.buffer.get(x, 0).unwrap().attrs;
+        assert!(attrs(3).has_flag(StyleFlags::UNDERLINE));
+        assert!(!attrs(2).has_flag(StyleFlags::UNDERLINE));
+    }
+
+    fn key(code: KeyCode, modifiers: Modifiers) -> Event {
+        Event::Key(KeyEvent::new(code).with_modifiers(modifiers))
+    }
+
+    #[test]
+    fn ctrl_alt_down_adds_cursor_and_esc_clears() {
+        let mut ta = TextArea::new().with_text("ab\ncd\nef");
+        ta.editor_mut().move_to_document_start();
+        ta.handle_event(&key(KeyCode::Down, Modifiers::CTRL | Modifiers::ALT));
+        ta.handle_event(&key(KeyCode::Down, Modifiers::CTRL | Modifiers::ALT));
+        assert_eq!(ta.cursor_count(), 3);
+        ta.handle_event(&Event::Key(KeyEvent::new(KeyCode::Char('>'))));
+        assert_eq!(ta.text(), ">ab\n>cd\n>ef");
+        assert!(ta.handle_event(&Event::Key(KeyEvent::new(KeyCode::Escape))));
+        assert_eq!(ta.cursor_count(), 1);
+        assert!(!ta.handle_event(&Event::Key(KeyEvent::new(KeyCode::Escape))));
+    }
+
+    #[test]
+    fn ctrl_d_adds_next_occurrence() {
+        let mut ta = TextArea::new().with_text("let a = a + a;");
+        ta.editor_mut().move_to_document_start();
+        ta.editor_mut().move_word_right();
+        ta.editor_mut().move_right();
+        for _ in 0..3 {
+            ta.handle_event(&key(KeyCode::Char('d'), Modifiers::CTRL));
+        }
+        assert_eq!(ta.cursor_count(), 3);
+        ta.insert_text("b");
+        assert_eq!(ta.text(), "let b = b + b;");
+    }
+
+    #[test]
+    fn alt_shift_arrows_select_rectangle() {
+        let mut ta = TextArea::new().with_text("abcd\nx\nabcd");
+        ta.editor_mut().move_to_document_start();
+        ta.editor_mut().move_right();
+        ta.editor_mut().move_right();
+        let alt_shift = Modifiers::ALT | Modifiers::SHIFT;
+        ta.handle_event(&key(KeyCode::Down, alt_shift));
+        ta.handle_event(&key(KeyCode::Down, alt_shift));
+        ta.handle_event(&key(KeyCode::Right, alt_shift));
+        ta.handle_event(&key(KeyCode::Right, alt_shift));
+        // The short middle line gets no cursor; the column survives it.
+        assert_eq!(ta.cursor_count(), 2);
+        assert_eq!(ta.editor().cursor().grapheme, 4);
+        ta.delete_backward();
+        assert_eq!(ta.text(), "ab\nx\nab");
+    }
+
+    fn alt_mouse(kind: MouseEventKind, x: u16, y: u16) -> Event {
+        Event::Mouse(MouseEvent::new(kind, x, y).with_modifiers(Modifiers::ALT))
+    }
+
+    #[test]
+    fn alt_drag_selects_rectangle() {
+        let mut ta = TextArea::new().with_text("abcd\nx\nabcd");
+        render_frame(&ta, 10, 3);
+        assert!(ta.handle_event(&alt_mouse(MouseEventKind::Down(MouseButton::Left), 2, 0)));
+        assert!(ta.handle_event(&alt_mouse(MouseEventKind::Drag(MouseButton::Left), 4, 2)));
+        // The short middle line gets no cursor.
+        assert_eq!(ta.cursor_count(), 2);
+        assert_eq!(ta.selected_text().as_deref(), Some("cd"));
+        ta.delete_backward();
+        assert_eq!(ta.text(), "ab\nx\nab");
+    }
+
+    #[test]
+    fn alt_drag_respects_gutter_and_scroll() {
+        let text = (0..20)
+            .map(|i| format!("line{i:02}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut ta = TextArea::new().with_text(&text).with_line_numbers(true);
+        ta.editor_mut().move_to_document_end();
+        render_frame(&ta, 12, 4);
+        let origin = ta.last_text_area.get();
+        let top = ta.scroll_top.get();
+        ta.handle_event(&alt_mouse(
+            MouseEventKind::Down(MouseButton::Left),
+            origin.x + 4,
+            origin.y,
+        ));
+        // Dragging below the area clamps to its last row.
+        ta.handle_event(&alt_mouse(
+            MouseEventKind::Drag(MouseButton::Left),
+            origin.x + 6,
+            origin.bottom() + 5,
+        ));
+        assert_eq!(ta.cursor_count(), 4);
+        assert_eq!(ta.editor().cursor().line, top + 3);
+        assert_eq!(
+            ta.selected_text().as_deref(),
+            Some(&format!("{:02}", top + 3)[..])
+        );
+    }
+
+    #[test]
+    fn mouse_without_alt_is_ignored() {
+        let mut ta = TextArea::new().with_text("abcd");
+        render_frame(&ta, 10, 1);
+        let down = Event::Mouse(MouseEvent::new(
+            MouseEventKind::Down(MouseButton::Left),
+            1,
+            0,
+        ));
+        assert!(!ta.handle_event(&down));
+        let drag = alt_mouse(MouseEventKind::Drag(MouseButton::Left), 3, 0);
+        assert!(!ta.handle_event(&drag));
+        assert_eq!(ta.cursor_count(), 1);
+    }
+
+    #[test]
+    fn secondary_cursors_are_rendered() {
+        use ftui_render::cell::StyleFlags;
+        use ftui_render::grapheme_pool::GraphemePool;
+        let mut ta = TextArea::new().with_text("ab\nab");
+        ta.editor_mut().move_to_document_end();
+        ta.add_cursor_above();
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(4, 2, &mut pool);
+        Widget::render(&ta, Rect::new(0, 0, 4, 2), &mut frame);
+        // The added cursor on the first line is primary (terminal cursor);
+        // the original one at the end of the second line is drawn.
+        let attrs = |x, y| frame.buffer.get(x, y).unwrap().attrs;
+        assert!(attrs(2, 1).has_flag(StyleFlags::REVERSE));
+        assert!(!attrs(2, 0).has_flag(StyleFlags::REVERSE));
+        assert!(!attrs(1, 1).has_flag(StyleFlags::REVERSE));
+    }
+
+    #[test]
+    fn vim_keymap_drives_textarea() {
+        use crate::keymap::VimKeymap;
+        let mut ta = TextArea::new()
+            .with_text("one\ntwo")
+            .with_keymap(VimKeymap::new());
+        assert_eq!(ta.keymap_mode(), Some(KeymapMode::Normal));
+        for c in "jddu".chars() {
+            ta.handle_event(&key(KeyCode::Char(c), Modifiers::NONE));
+        }
+        assert_eq!(ta.text(), "one\ntwo");
+        for c in "Ax".chars() {
+            ta.handle_event(&key(KeyCode::Char(c), Modifiers::NONE));
+        }
+        assert_eq!(ta.keymap_mode(), Some(KeymapMode::Insert));
+        // Keys the keymap leaves alone use the default bindings.
+        ta.handle_event(&key(KeyCode::Home, Modifiers::NONE));
+        ta.handle_event(&key(KeyCode::Char('y'), Modifiers::NONE));
+        ta.handle_event(&key(KeyCode::Escape, Modifiers::NONE));
+        assert_eq!(ta.text(), "one\nytwox");
+        assert_eq!(ta.keymap_mode(), Some(KeymapMode::Normal));
+        let cloned = ta.clone();
+        assert_eq!(cloned.keymap_mode(), Some(KeymapMode::Normal));
+    }
+
+    #[test]
+    fn emacs_keymap_drives_textarea() {
+        use crate::keymap::EmacsKeymap;
+        let mut ta = TextArea::new()
+            .with_text("hello world")
+            .with_keymap(EmacsKeymap::new());
+        ta.handle_event(&key(KeyCode::Char('k'), Modifiers::CTRL));
+        assert_eq!(ta.text(), "");
+        ta.handle_event(&key(KeyCode::Char('y'), Modifiers::CTRL));
+        assert_eq!(ta.text(), "hello world");
+        ta.set_keymap(None);
+        assert!(ta.keymap().is_none());
+    }
+
+    #[test]
+    fn completion_in_textarea() {
+        use crate::completion::{Completer, StaticCompletions};
+        use ftui_render::grapheme_pool::GraphemePool;
+        let words = StaticCompletions::new(["println", "print"]);
+        let mut ta = TextArea::new()
+            .with_text("let x;\n")
+            .with_completion(Completer::new(words))
+            .with_focus(true);
+        ta.move_to_document_end();
+        for c in "prin".chars() {
+            ta.handle_event(&key(KeyCode::Char(c), Modifiers::NONE));
+        }
+        let mut pool = GraphemePool::new();
+        let mut frame = Frame::new(20, 4, &mut pool);
+        Widget::render(&ta, Rect::new(0, 0, 20, 4), &mut frame);
+        // Ghost text for the top candidate follows the cursor.
+        assert_eq!(frame.buffer.get(4, 1).unwrap().content.as_char(), Some('t'));
+        let popup = ta.completion_popup().unwrap();
+        assert_eq!(popup.area(Rect::new(0, 0, 20, 4)).y, 2);
+
+        ta.handle_event(&key(KeyCode::Enter, Modifiers::NONE));
+        assert_eq!(ta.text(), "let x;\nprint");
+        // Without candidates, Enter inserts a newline again.
+        ta.handle_event(&key(KeyCode::Enter, Modifiers::NONE));
+        assert_eq!(ta.text(), "let x;\nprint\n");
+    }
+
     mod proptests {
         use super::*;
         use proptest::prelude::*;
@@ -22,7 +22,8 @@
 use web_time::{Duration, Instant};
 
 use crate::{Widget, set_style_area};
-use ftui_core::geometry::Rect;
+use ftui_core::geometry::{Rect, Size};
+use ftui_layout::{AnchorAlign, place_within};
 use ftui_render::cell::Cell;
 use ftui_render::frame::Frame;
 use ftui_style::Style;
@@ -60,7 +61,9 @@ pub enum ToastPosition {
 impl ToastPosition {
     /// Calculate the toast's top-left position within a terminal area.
     ///
-    /// Returns `(x, y)` for the toast's origin given its dimensions.
+    /// Returns `(x, y)` for the toast's origin given its dimensions. The
+    /// margin shrinks when the terminal is too small, so the toast origin
+    /// never leaves the screen.
     pub fn calculate_position(
         self,
         terminal_width: u16,
@@ -69,22 +72,24 @@ impl ToastPosition {
         toast_height: u16,
         margin: u16,
     ) -> (u16, u16) {
-        let x = match self {
-            Self::TopLeft | Self::BottomLeft => margin,
-            Self::TopCenter | Self::BottomCenter => terminal_width.saturating_sub(toast_width) / 2,
-            Self::TopRight | Self::BottomRight => terminal_width
-                .saturating_sub(toast_width)
-                .saturating_sub(margin),
+        let horizontal = match self {
+            Self::TopLeft | Self::BottomLeft => AnchorAlign::Start,
+            Self::TopCenter | Self::BottomCenter => AnchorAlign::Center,
+            Self::TopRight | Self::BottomRight => AnchorAlign::End,
         };
-
-        let y = match self {
-            Self::TopLeft | Self::TopCenter | Self::TopRight => margin,
-            Self::BottomLeft | Self::BottomCenter | Self::BottomRight => terminal_height
-                .saturating_sub(toast_height)
-                .saturating_sub(margin),
+        let vertical = match self {
+            Self::TopLeft | Self::TopCenter | Self::TopRight => AnchorAlign::Start,
+            Self::BottomLeft | Self::BottomCenter | Self::BottomRight => AnchorAlign::End,
         };
+        let rect = place_within(
+            Rect::new(0, 0, terminal_width, terminal_height),
+            Size::new(toast_width, toast_height),
+            horizontal,
+            vertical,
+            margin,
+        );
 
-        (x, y)
+        (rect.x, rect.y)
     }
 }
 